@@ -1,17 +1,89 @@
 pub mod config;
+mod doc_code;
 pub(crate) mod node;
 pub mod printer;
 
-use ra_ap_syntax::{AstNode, Edition, SourceFile};
+use ra_ap_syntax::{AstNode, SourceFile};
+
+pub use config::{
+    Config, ConfigError, DeriveOrder, DocAttributeStyle, Edition, FloatLiteralTrailingZero,
+    HexLiteralCase, NewlineStyle, OptionValue, TrailingCommentAlignment,
+};
 
 /// Format Rust source code with canonical style.
 pub fn format_source(source: &str) -> String {
-    let parse = SourceFile::parse(source, Edition::CURRENT);
-    let root = parse.tree();
+    format_source_with_config(source, &Config::default())
+}
+
+/// Format Rust source code with canonical style, using the given options.
+pub fn format_source_with_config(source: &str, config: &Config) -> String {
+    config::scoped(config, || {
+        let parse = SourceFile::parse(source, config::edition());
+        let root = parse.tree();
+
+        let mut output = String::with_capacity(source.len());
+        node::format_node(root.syntax(), &mut output, 0);
+        let output = node::common::comments::align_trailing_comments(&output);
+        let output = node::common::comments::rewrite_comments(&output);
+        let output = node::rewrite_literals(&output);
+        apply_newline_style(source, output)
+    })
+}
+
+/// Write the line endings of `output` in the active newline style, where `Auto` follows the
+/// first line ending of `source`.
+fn apply_newline_style(source: &str, output: String) -> String {
+    let windows = match config::with(|config| config.newline_style) {
+        NewlineStyle::Auto => source
+            .find('\n')
+            .is_some_and(|end| source[..end].ends_with('\r')),
+        NewlineStyle::Native => cfg!(windows),
+        NewlineStyle::Unix => false,
+        NewlineStyle::Windows => true,
+    };
+    let output = if output.contains('\r') {
+        output.replace("\r\n", "\n")
+    } else {
+        output
+    };
+    if windows {
+        output.replace('\n', "\r\n")
+    } else {
+        output
+    }
+}
+
+/// A syntax error in parsed source, at a 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
 
-    let mut output = String::with_capacity(source.len());
-    node::format_node(root.syntax(), &mut output, 0);
-    output
+/// Find the syntax errors in Rust source code.
+///
+/// Source with errors is still formatted, but the code around them may be left as written.
+pub fn parse_diagnostics(source: &str) -> Vec<ParseDiagnostic> {
+    parse_diagnostics_with_config(source, &Config::default())
+}
+
+/// Find the syntax errors in Rust source code, parsed in the edition of the given options.
+pub fn parse_diagnostics_with_config(source: &str, config: &Config) -> Vec<ParseDiagnostic> {
+    let parse = config::scoped(config, || SourceFile::parse(source, config::edition()));
+    parse
+        .errors()
+        .iter()
+        .map(|error| {
+            let offset: usize = error.range().start().into();
+            let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+            ParseDiagnostic {
+                line: source[..offset].matches('\n').count() + 1,
+                column: source[line_start..offset].chars().count() + 1,
+                message: error.to_string(),
+            }
+        })
+        .collect()
 }
 
 /// Write indentation to buffer
@@ -20,3 +92,8 @@ pub(crate) fn write_indent(buf: &mut String, indent: usize) {
         buf.push(' ');
     }
 }
+
+/// Width of text in columns, counting each character as one column.
+pub(crate) fn text_width(text: &str) -> usize {
+    text.chars().count()
+}
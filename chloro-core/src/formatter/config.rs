@@ -1,4 +1,477 @@
 //! Configuration for chloro formatting behavior.
+//!
+//! Width heuristics other than the maximum width are fixed statics. Options live in [`Config`],
+//! which is made active for the duration of a
+//! [`format_source_with_config`](super::format_source_with_config) call so that node formatters
+//! can read it without threading it through every signature.
 
-/// Maximum line width before we wrap types onto new lines.
-pub(crate) static MAX_WIDTH: usize = 100;
+use std::cell::RefCell;
+use std::fmt;
+
+/// Maximum width of a method chain kept on a single line (rustfmt's `chain_width`).
+pub(crate) static CHAIN_WIDTH: usize = 60;
+
+/// Maximum width of the body of a struct literal kept on a single line (rustfmt's
+/// `struct_lit_width`).
+pub(crate) static STRUCT_LIT_WIDTH: usize = 18;
+
+/// Maximum width of the body of a record enum variant kept on a single line (rustfmt's
+/// `struct_variant_width`).
+pub(crate) static STRUCT_VARIANT_WIDTH: usize = 35;
+
+/// Formatting options. The defaults match rustfmt's defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Maximum line width before we wrap onto new lines (rustfmt's `max_width`).
+    pub max_width: usize,
+    /// Line endings of the output (rustfmt's `newline_style`).
+    pub newline_style: NewlineStyle,
+    /// Edition of Rust to parse the source as (rustfmt's `edition`). Unlike rustfmt, this
+    /// defaults to the latest edition.
+    pub edition: Edition,
+    /// Write `Foo { a: a }` as `Foo { a }` (rustfmt's `use_field_init_shorthand`).
+    pub use_field_init_shorthand: bool,
+    /// Vertically align the `=` of enum discriminants for variants whose names are at most
+    /// this long (rustfmt's `enum_discrim_align_threshold`). `0` disables alignment.
+    pub enum_discrim_align_threshold: usize,
+    /// Format fenced Rust code blocks in doc comments and `#[doc]` attributes (rustfmt's
+    /// `format_code_in_doc_comments`).
+    pub format_code_in_doc_comments: bool,
+    /// Reflow comments that are wider than `comment_width` (rustfmt's `wrap_comments`).
+    pub wrap_comments: bool,
+    /// Convert single-line `/* */` comments to `//` comments and put a space after the
+    /// comment marker (rustfmt's `normalize_comments`).
+    pub normalize_comments: bool,
+    /// Maximum width of a comment when wrapping (rustfmt's `comment_width`).
+    pub comment_width: usize,
+    /// Which form to write plain `#[doc = "..."]` attributes and doc comments in (a superset of
+    /// rustfmt's `normalize_doc_attributes`).
+    pub normalize_doc_attributes: DocAttributeStyle,
+    /// Combine adjacent `#[derive]` attributes of an item into one (rustfmt's `merge_derives`).
+    pub merge_derives: bool,
+    /// Sort the traits in `#[derive]` attributes.
+    pub sort_derives: DeriveOrder,
+    /// Case of the digits of hexadecimal literals (rustfmt's `hex_literal_case`).
+    pub hex_literal_case: HexLiteralCase,
+    /// Whether float literals have a zero after the decimal point (rustfmt's
+    /// `float_literal_trailing_zero`).
+    pub float_literal_trailing_zero: FloatLiteralTrailingZero,
+    /// Group the digits of long decimal literals with `_` separators, this many digits to a
+    /// group. `0` leaves them as written.
+    pub decimal_literal_group_size: usize,
+    /// Group the digits of long hexadecimal literals with `_` separators, this many digits to
+    /// a group. `0` leaves them as written.
+    pub hex_literal_group_size: usize,
+    /// Split string literals that run past the maximum width at spaces, with `\` line
+    /// continuations, and join split ones back up when they fit (rustfmt's `format_strings`).
+    pub format_strings: bool,
+    /// Spacing before comments at the end of a line of code.
+    pub trailing_comment_alignment: TrailingCommentAlignment,
+    /// Sort runs of `mod foo;` declarations by name (rustfmt's `reorder_modules`).
+    pub reorder_modules: bool,
+    /// Put the associated types and consts of impls before their functions (rustfmt's
+    /// `reorder_impl_items`).
+    pub reorder_impl_items: bool,
+    /// Experimental: order top-level items by kind, as uses, mods, consts, types, traits,
+    /// impls then functions. Items never move past macros or comments between items.
+    pub reorder_items_by_kind: bool,
+}
+
+/// Line endings, for [`Config::newline_style`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NewlineStyle {
+    /// Use the line ending of the first line of the source.
+    #[default]
+    Auto,
+    /// Use the line ending of the platform chloro runs on.
+    Native,
+    /// `\n`
+    Unix,
+    /// `\r\n`
+    Windows,
+}
+
+/// Editions of Rust, for [`Config::edition`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Edition {
+    Edition2015,
+    Edition2018,
+    Edition2021,
+    #[default]
+    Edition2024,
+}
+
+impl Edition {
+    /// The parser's edition.
+    pub(crate) fn syntax_edition(self) -> ra_ap_syntax::Edition {
+        match self {
+            Self::Edition2015 => ra_ap_syntax::Edition::Edition2015,
+            Self::Edition2018 => ra_ap_syntax::Edition::Edition2018,
+            Self::Edition2021 => ra_ap_syntax::Edition::Edition2021,
+            Self::Edition2024 => ra_ap_syntax::Edition::Edition2024,
+        }
+    }
+}
+
+/// The order of traits in `#[derive]` attributes, for [`Config::sort_derives`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeriveOrder {
+    /// Keep the traits in the order written.
+    #[default]
+    Preserve,
+    /// Sort the traits alphabetically.
+    Alphabetical,
+    /// Put the derivable traits of the standard library first, then sort each group
+    /// alphabetically.
+    StdFirst,
+}
+
+/// Spacing before trailing comments, for [`Config::trailing_comment_alignment`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrailingCommentAlignment {
+    /// Keep the spacing as written.
+    #[default]
+    Preserve,
+    /// Put a single space before each comment.
+    SingleSpace,
+    /// Line up the comments of consecutive lines at the same indentation, as long as they fit
+    /// within the maximum width.
+    AlignConsecutive,
+}
+
+/// Case of hexadecimal digits, for [`Config::hex_literal_case`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HexLiteralCase {
+    /// Keep the digits as written.
+    #[default]
+    Preserve,
+    /// `0xFF`
+    Upper,
+    /// `0xff`
+    Lower,
+}
+
+/// Trailing zeros of float literals, for [`Config::float_literal_trailing_zero`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FloatLiteralTrailingZero {
+    /// Keep the literal as written.
+    #[default]
+    Preserve,
+    /// `1.` and `1e5` become `1.0` and `1.0e5`.
+    Always,
+    /// Add the zero to literals without an exponent or suffix and remove it from the rest:
+    /// `1.` becomes `1.0` and `1.0e5` becomes `1e5`.
+    IfNoPostfix,
+    /// `1.0` and `1.0e5` become `1.` and `1e5`.
+    Never,
+}
+
+/// The form that docs are written in, for [`Config::normalize_doc_attributes`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DocAttributeStyle {
+    /// Keep each doc comment or attribute as written.
+    #[default]
+    Preserve,
+    /// Write `#[doc = "..."]` attributes with a string literal value as `///` or `//!` comments.
+    Comments,
+    /// Write `///` and `//!` comments as `#[doc = "..."]` attributes, as generated code often
+    /// does.
+    Attributes,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_width: 100,
+            newline_style: NewlineStyle::Auto,
+            edition: Edition::Edition2024,
+            use_field_init_shorthand: false,
+            enum_discrim_align_threshold: 0,
+            format_code_in_doc_comments: false,
+            wrap_comments: false,
+            normalize_comments: false,
+            comment_width: 80,
+            normalize_doc_attributes: DocAttributeStyle::Preserve,
+            merge_derives: false,
+            sort_derives: DeriveOrder::Preserve,
+            hex_literal_case: HexLiteralCase::Preserve,
+            float_literal_trailing_zero: FloatLiteralTrailingZero::Preserve,
+            decimal_literal_group_size: 0,
+            hex_literal_group_size: 0,
+            format_strings: false,
+            trailing_comment_alignment: TrailingCommentAlignment::Preserve,
+            reorder_modules: false,
+            reorder_impl_items: false,
+            reorder_items_by_kind: false,
+        }
+    }
+}
+
+/// A value of a configuration option, as written in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionValue {
+    Bool(bool),
+    Integer(usize),
+    /// A variant of an enum option, such as `"Upper"`.
+    Variant(&'static str),
+}
+
+impl fmt::Display for OptionValue {
+    /// Write the value as a TOML value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Variant(name) => write!(f, "\"{name}\""),
+        }
+    }
+}
+
+/// An error setting a configuration option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// There is no option with this name.
+    UnknownKey(String),
+    /// The value cannot be parsed for the option.
+    InvalidValue {
+        key: String,
+        value: String,
+        expected: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownKey(key) => write!(f, "unknown configuration option `{key}`"),
+            Self::InvalidValue {
+                key,
+                value,
+                expected,
+            } => write!(
+                f,
+                "invalid value `{value}` for `{key}` (expected {expected})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A type that an option of [`Config`] can have.
+trait ConfigValue: Sized {
+    /// Describes the accepted values, for error messages.
+    fn expected() -> String;
+    fn parse(value: &str) -> Option<Self>;
+    fn to_value(&self) -> OptionValue;
+}
+
+impl ConfigValue for bool {
+    fn expected() -> String {
+        "true or false".to_string()
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+
+    fn to_value(&self) -> OptionValue {
+        OptionValue::Bool(*self)
+    }
+}
+
+impl ConfigValue for usize {
+    fn expected() -> String {
+        "a non-negative integer".to_string()
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+
+    fn to_value(&self) -> OptionValue {
+        OptionValue::Integer(*self)
+    }
+}
+
+/// Implement [`ConfigValue`] for an enum option, spelling its variants as rustfmt does.
+macro_rules! variant_values {
+    ($ty:ty { $($variant:ident),+ $(,)? }) => {
+        impl ConfigValue for $ty {
+            fn expected() -> String {
+                [$(stringify!($variant)),+].join(", ")
+            }
+
+            fn parse(value: &str) -> Option<Self> {
+                match value {
+                    $(stringify!($variant) => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn to_value(&self) -> OptionValue {
+                match self {
+                    $(Self::$variant => OptionValue::Variant(stringify!($variant)),)+
+                }
+            }
+        }
+    };
+}
+
+variant_values!(DeriveOrder {
+    Preserve,
+    Alphabetical,
+    StdFirst
+});
+variant_values!(TrailingCommentAlignment {
+    Preserve,
+    SingleSpace,
+    AlignConsecutive
+});
+variant_values!(HexLiteralCase {
+    Preserve,
+    Upper,
+    Lower
+});
+variant_values!(FloatLiteralTrailingZero {
+    Preserve,
+    Always,
+    IfNoPostfix,
+    Never
+});
+variant_values!(DocAttributeStyle {
+    Preserve,
+    Comments,
+    Attributes
+});
+variant_values!(NewlineStyle {
+    Auto,
+    Native,
+    Unix,
+    Windows
+});
+
+impl ConfigValue for Edition {
+    fn expected() -> String {
+        "2015, 2018, 2021, 2024".to_string()
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "2015" => Some(Self::Edition2015),
+            "2018" => Some(Self::Edition2018),
+            "2021" => Some(Self::Edition2021),
+            "2024" => Some(Self::Edition2024),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> OptionValue {
+        OptionValue::Variant(match self {
+            Self::Edition2015 => "2015",
+            Self::Edition2018 => "2018",
+            Self::Edition2021 => "2021",
+            Self::Edition2024 => "2024",
+        })
+    }
+}
+
+/// Generate the by-name accessors of [`Config`] from its list of fields.
+macro_rules! config_options {
+    ($($field:ident),+ $(,)?) => {
+        impl Config {
+            /// The names of all options, in the order they are declared.
+            pub const OPTIONS: &[&str] = &[$(stringify!($field)),+];
+
+            /// Set the option named `key` from its value written as a string, such as `true`, `4`
+            /// or `Upper`.
+            pub fn set(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+                match key {
+                    $(stringify!($field) => {
+                        self.$field = parse_option(key, value)?;
+                    })+
+                    _ => return Err(ConfigError::UnknownKey(key.to_string())),
+                }
+                Ok(())
+            }
+
+            /// Get the value of the option named `key`.
+            pub fn get(&self, key: &str) -> Option<OptionValue> {
+                match key {
+                    $(stringify!($field) => Some(self.$field.to_value()),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+config_options!(
+    max_width,
+    newline_style,
+    edition,
+    use_field_init_shorthand,
+    enum_discrim_align_threshold,
+    format_code_in_doc_comments,
+    wrap_comments,
+    normalize_comments,
+    comment_width,
+    normalize_doc_attributes,
+    merge_derives,
+    sort_derives,
+    hex_literal_case,
+    float_literal_trailing_zero,
+    decimal_literal_group_size,
+    hex_literal_group_size,
+    format_strings,
+    trailing_comment_alignment,
+    reorder_modules,
+    reorder_impl_items,
+    reorder_items_by_kind,
+);
+
+fn parse_option<T: ConfigValue>(key: &str, value: &str) -> Result<T, ConfigError> {
+    T::parse(value).ok_or_else(|| ConfigError::InvalidValue {
+        key: key.to_string(),
+        value: value.to_string(),
+        expected: T::expected(),
+    })
+}
+
+thread_local! {
+    /// The configuration of the format call running on this thread.
+    static ACTIVE: RefCell<Config> = RefCell::new(Config::default());
+}
+
+/// Restores the previously active configuration when dropped, even on panic.
+struct RestoreOnDrop(Option<Config>);
+
+impl Drop for RestoreOnDrop {
+    fn drop(&mut self) {
+        if let Some(previous) = self.0.take() {
+            ACTIVE.with(|active| *active.borrow_mut() = previous);
+        }
+    }
+}
+
+/// Run `f` with `config` as the active configuration on this thread.
+pub(crate) fn scoped<R>(config: &Config, f: impl FnOnce() -> R) -> R {
+    let previous = ACTIVE.with(|active| active.replace(config.clone()));
+    let _restore = RestoreOnDrop(Some(previous));
+    f()
+}
+
+/// Read from the active configuration.
+pub(crate) fn with<R>(f: impl FnOnce(&Config) -> R) -> R {
+    ACTIVE.with(|active| f(&active.borrow()))
+}
+
+/// Maximum line width of the active configuration.
+pub(crate) fn max_width() -> usize {
+    with(|config| config.max_width)
+}
+
+/// Parser edition of the active configuration.
+pub(crate) fn edition() -> ra_ap_syntax::Edition {
+    with(|config| config.edition.syntax_edition())
+}
@@ -0,0 +1,393 @@
+//! Formatting of doc comments and doc attributes, and of the Rust code blocks inside them.
+//!
+//! Docs can be normalised between `///` comments and `#[doc = "..."]` attributes, keeping
+//! their place among the other attributes of an item.
+//!
+//! A run of doc lines, whether `///` comments, `//!` comments or `#[doc = "..."]` attributes,
+//! is scanned for fenced code blocks that rustdoc would compile as Rust. Each one is
+//! formatted as a standalone file, or as the body of `fn main` if it is a list of statements,
+//! and written back in place. Blocks that fail to parse are left untouched.
+
+use ra_ap_syntax::ast::{self, AstNode, AstToken};
+use ra_ap_syntax::{NodeOrToken, SourceFile, SyntaxNode};
+
+use super::config::{self, DocAttributeStyle};
+use super::node::common::attrs;
+
+/// Marker that rustdoc's hidden lines (`# let x = 1;`) are turned into while formatting, so
+/// that they survive as comments. The same marker as rustfmt.
+const HIDDEN_LINE_MARKER: &str = "// #### ";
+
+/// A line of formatted doc output.
+enum DocLine {
+    /// The input line at this index, unchanged.
+    Original(usize),
+    /// A new line of doc text, without any comment prefix or attribute syntax.
+    New(String),
+}
+
+/// Format the code blocks in a run of doc comments, given as their full text (`/// foo`).
+///
+/// Block doc comments (`/** */`) and lines of other runs are passed through unchanged.
+fn format_doc_comments(comments: Vec<String>) -> Vec<String> {
+    if !config::with(|config| config.format_code_in_doc_comments) {
+        return comments;
+    }
+
+    let mut output = Vec::with_capacity(comments.len());
+    let mut start = 0;
+    while start < comments.len() {
+        let Some(prefix) = line_doc_prefix(&comments[start]) else {
+            output.push(comments[start].clone());
+            start += 1;
+            continue;
+        };
+        let end = (start..comments.len())
+            .find(|&i| line_doc_prefix(&comments[i]) != Some(prefix))
+            .unwrap_or(comments.len());
+
+        let run = &comments[start..end];
+        let texts: Vec<&str> = run.iter().map(|comment| &comment[prefix.len()..]).collect();
+        for line in format_doc_lines(&texts) {
+            output.push(match line {
+                DocLine::Original(i) => run[i].clone(),
+                DocLine::New(text) => format!("{}{}", prefix, text),
+            });
+        }
+        start = end;
+    }
+    output
+}
+
+/// The prefix of a line doc comment, or `None` for block doc comments.
+fn line_doc_prefix(comment: &str) -> Option<&'static str> {
+    if comment.starts_with("///") && !comment.starts_with("////") {
+        Some("///")
+    } else if comment.starts_with("//!") {
+        Some("//!")
+    } else {
+        None
+    }
+}
+
+/// The text of a `#[doc = "..."]` attribute, if it is one with a single-line string value.
+fn doc_attr_text(attr: &ast::Attr) -> Option<String> {
+    if attr.simple_name()? != "doc" {
+        return None;
+    }
+    let ast::Expr::Literal(literal) = attr.expr()? else {
+        return None;
+    };
+    let ast::LiteralKind::String(string) = literal.kind() else {
+        return None;
+    };
+    let value = string.value().ok()?;
+    (!value.contains('\n')).then(|| value.into_owned())
+}
+
+/// Write doc text as a `#[doc = "..."]` (or `#![doc = "..."]`) attribute.
+fn doc_attr(text: &str, inner: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    let bang = if inner { "!" } else { "" };
+    format!("#{}[doc = \"{}\"]", bang, escaped)
+}
+
+/// A doc comment or attribute of an item.
+pub(crate) enum DocOrAttr {
+    /// The full text of a doc comment (`/// foo`).
+    Comment(String),
+    Attr(ast::Attr),
+}
+
+/// The doc comments and attributes that are children of a node, in source order.
+pub(crate) fn docs_and_attrs(node: &SyntaxNode) -> Vec<DocOrAttr> {
+    node.children_with_tokens()
+        .filter_map(|child| match child {
+            NodeOrToken::Token(token) => ast::Comment::cast(token)
+                .filter(ast::Comment::is_doc)
+                .map(|comment| DocOrAttr::Comment(comment.text().trim().to_string())),
+            NodeOrToken::Node(node) => ast::Attr::cast(node).map(DocOrAttr::Attr),
+        })
+        .collect()
+}
+
+/// A doc comment or attribute in the form it will be written.
+enum DocEntry {
+    Comment(String),
+    DocAttr {
+        source: String,
+        text: String,
+        inner: bool,
+    },
+    Other(String),
+}
+
+/// Write doc comments and attributes, given in source order, as lines.
+///
+/// Docs are converted to the form set by `normalize_doc_attributes` and have their code blocks
+/// formatted. Every entry stays in its place, so the order of docs and attributes is kept.
+pub(crate) fn format_docs_and_attrs(entries: &[DocOrAttr], indent: usize) -> Vec<String> {
+    let style = config::with(|config| config.normalize_doc_attributes);
+    let entries: Vec<DocEntry> = entries
+        .iter()
+        .map(|entry| normalize_entry(entry, style, indent))
+        .collect();
+
+    let mut output = Vec::with_capacity(entries.len());
+    let mut start = 0;
+    while start < entries.len() {
+        match &entries[start] {
+            DocEntry::Comment(_) => {
+                let comments: Vec<String> = entries[start..]
+                    .iter()
+                    .map_while(|entry| match entry {
+                        DocEntry::Comment(text) => Some(text.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                start += comments.len();
+                output.extend(format_doc_comments(comments));
+            }
+            DocEntry::DocAttr { inner, .. } => {
+                let inner = *inner;
+                let run: Vec<(&str, &str)> = entries[start..]
+                    .iter()
+                    .map_while(|entry| match entry {
+                        DocEntry::DocAttr {
+                            source,
+                            text,
+                            inner: entry_inner,
+                        } if *entry_inner == inner => Some((source.as_str(), text.as_str())),
+                        _ => None,
+                    })
+                    .collect();
+                start += run.len();
+                output.extend(format_doc_attrs(&run, inner));
+            }
+            DocEntry::Other(source) => {
+                output.push(source.clone());
+                start += 1;
+            }
+        }
+    }
+    output
+}
+
+/// Convert a doc comment or attribute to the form it should be written in.
+fn normalize_entry(entry: &DocOrAttr, style: DocAttributeStyle, indent: usize) -> DocEntry {
+    match entry {
+        DocOrAttr::Comment(comment) => match line_doc_prefix(comment) {
+            Some(prefix) if style == DocAttributeStyle::Attributes => {
+                let text = &comment[prefix.len()..];
+                let inner = prefix == "//!";
+                DocEntry::DocAttr {
+                    source: doc_attr(text, inner),
+                    text: text.to_string(),
+                    inner,
+                }
+            }
+            _ => DocEntry::Comment(comment.clone()),
+        },
+        DocOrAttr::Attr(attr) => {
+            let source = attrs::format_attr(attr, indent);
+            let Some(text) = doc_attr_text(attr) else {
+                return DocEntry::Other(source);
+            };
+            let inner = attr.excl_token().is_some();
+            let prefix = if inner { "//!" } else { "///" };
+            // `#[doc = "/ foo"]` would become `//// foo`, which is not a doc comment
+            let fits_comment = !text.contains('\r') && (inner || !text.starts_with('/'));
+            if style == DocAttributeStyle::Comments && fits_comment {
+                DocEntry::Comment(format!("{}{}", prefix, text).trim_end().to_string())
+            } else {
+                DocEntry::DocAttr {
+                    source,
+                    text,
+                    inner,
+                }
+            }
+        }
+    }
+}
+
+/// Format the code blocks in a run of doc attributes, given as their source and doc text,
+/// returning the attributes to write.
+fn format_doc_attrs(attrs: &[(&str, &str)], inner: bool) -> Vec<String> {
+    let originals = attrs.iter().map(|(source, _)| source.to_string());
+    if !config::with(|config| config.format_code_in_doc_comments) {
+        return originals.collect();
+    }
+    let originals: Vec<String> = originals.collect();
+    let texts: Vec<&str> = attrs.iter().map(|(_, text)| *text).collect();
+    format_doc_lines(&texts)
+        .into_iter()
+        .map(|line| match line {
+            DocLine::Original(i) => originals[i].clone(),
+            DocLine::New(text) => doc_attr(&text, inner),
+        })
+        .collect()
+}
+
+/// Format the code blocks in lines of doc text.
+fn format_doc_lines(texts: &[&str]) -> Vec<DocLine> {
+    let mut output = Vec::with_capacity(texts.len());
+    let mut i = 0;
+    while i < texts.len() {
+        let Some((indent, fence, info)) = open_fence(texts[i]) else {
+            output.push(DocLine::Original(i));
+            i += 1;
+            continue;
+        };
+        let Some(close) = (i + 1..texts.len()).find(|&j| is_close_fence(texts[j], fence)) else {
+            // An unclosed fence runs to the end of the docs; leave it alone
+            output.extend((i..texts.len()).map(DocLine::Original));
+            break;
+        };
+
+        let formatted = is_rust_block(info)
+            .then(|| format_code_block(&texts[i + 1..close], indent))
+            .flatten();
+        output.push(DocLine::Original(i));
+        match formatted {
+            Some(lines) => output.extend(lines.into_iter().map(DocLine::New)),
+            None => output.extend((i + 1..close).map(DocLine::Original)),
+        }
+        output.push(DocLine::Original(close));
+        i = close + 1;
+    }
+    output
+}
+
+/// Parse an opening code fence into its indentation, fence and info string.
+fn open_fence(text: &str) -> Option<(&str, &str, &str)> {
+    let rest = text.trim_start();
+    let indent = &text[..text.len() - rest.len()];
+    let fence_char = rest.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let fence_len = rest.chars().take_while(|&c| c == fence_char).count();
+    if fence_len < 3 {
+        return None;
+    }
+    let (fence, info) = rest.split_at(fence_len);
+    Some((indent, fence, info.trim()))
+}
+
+/// Whether a line closes the code block opened by `fence`.
+fn is_close_fence(text: &str, fence: &str) -> bool {
+    let rest = text.trim();
+    let fence_char = fence.chars().next().unwrap_or('`');
+    rest.len() >= fence.len() && rest.chars().all(|c| c == fence_char)
+}
+
+/// Whether rustdoc treats a code block with this info string as Rust that should compile.
+fn is_rust_block(info: &str) -> bool {
+    info.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|attr| !attr.is_empty())
+        .all(|attr| {
+            matches!(attr, "rust" | "should_panic" | "no_run" | "test_harness")
+                || attr.starts_with("edition")
+        })
+}
+
+/// Format the lines of a code block whose fence is indented by `indent`.
+///
+/// Returns `None` if the code doesn't parse, either as a file or as the body of a function.
+fn format_code_block(lines: &[&str], indent: &str) -> Option<Vec<String>> {
+    let mut source = String::new();
+    let mut hidden_lines = 0;
+    for line in lines {
+        let code = match line.strip_prefix(indent) {
+            Some(code) => code,
+            None if line.trim().is_empty() => "",
+            None => return None,
+        };
+        let trimmed = code.trim_start();
+        if trimmed == "#" || trimmed.starts_with("# ") {
+            hidden_lines += 1;
+            source.push_str(&code[..code.len() - trimmed.len()]);
+            source.push_str(HIDDEN_LINE_MARKER);
+            source.push_str(trimmed[1..].trim_start());
+        } else {
+            source.push_str(code);
+        }
+        source.push('\n');
+    }
+
+    let formatted = if parses(&source) {
+        let formatted = format_snippet(&source);
+        parses(&formatted).then_some(formatted)?
+    } else {
+        let wrapped = format!("fn main() {{\n{}}}\n", source);
+        if !parses(&wrapped) {
+            return None;
+        }
+        let formatted = format_snippet(&wrapped);
+        if !parses(&formatted) {
+            return None;
+        }
+        let body = formatted
+            .strip_prefix("fn main() {\n")?
+            .strip_suffix("}\n")?;
+        body.lines()
+            .map(|line| line.strip_prefix("    ").unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    // Give up rather than lose a hidden line
+    if formatted.matches(HIDDEN_LINE_MARKER.trim_end()).count() != hidden_lines {
+        return None;
+    }
+    let restored: Vec<String> = formatted.trim_end().lines().map(unhide_line).collect();
+
+    Some(
+        restored
+            .into_iter()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}{}", indent, line)
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Format a snippet with the active configuration.
+fn format_snippet(source: &str) -> String {
+    let config = config::with(Clone::clone);
+    super::format_source_with_config(source, &config)
+}
+
+/// Whether source text parses without errors.
+fn parses(source: &str) -> bool {
+    SourceFile::parse(source, config::edition())
+        .errors()
+        .is_empty()
+}
+
+/// Turn a hidden line marker back into rustdoc's `# ` prefix.
+fn unhide_line(line: &str) -> String {
+    let trimmed = line.trim_start();
+    match trimmed.strip_prefix(HIDDEN_LINE_MARKER.trim_end()) {
+        Some(rest) => {
+            let leading = &line[..line.len() - trimmed.len()];
+            let rest = rest.trim_start();
+            if rest.is_empty() {
+                format!("{}#", leading)
+            } else {
+                format!("{}# {}", leading, rest)
+            }
+        }
+        None => line.to_string(),
+    }
+}
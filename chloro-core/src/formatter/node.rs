@@ -16,12 +16,15 @@ mod traitdef;
 mod typealias;
 mod useitem;
 
-use ra_ap_syntax::ast::{Attr, Comment, Use};
+use std::cmp::Ordering;
+
+use ra_ap_syntax::ast::{Attr, Comment, HasAttrs, HasName, Module, Use};
 use ra_ap_syntax::{AstNode, AstToken, NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken};
 
 pub use block::{format_block, format_block_expr_contents, format_stmt_list};
 pub use const_static::format_const_or_static;
 pub use enumdef::format_enum;
+pub use expr::literal::rewrite_literals;
 pub use externblock::format_extern_block;
 pub use externcrate::format_extern_crate;
 pub use function::format_function;
@@ -33,6 +36,8 @@ pub use traitdef::format_trait;
 pub use typealias::format_type_alias;
 pub use useitem::format_use;
 
+use super::config;
+use super::doc_code::{self, DocOrAttr};
 use super::printer::Printer;
 
 /// Determine if a blank line should be added between two items
@@ -91,48 +96,125 @@ struct ItemWithComments {
     comments: Vec<Comment>,
     node: NodeOrToken<SyntaxNode, SyntaxToken>,
     blank_line_before: bool,
+    /// The comment on the same line after the item, which moves with it when items are sorted
+    trailing_comment: Option<SyntaxToken>,
+}
+
+impl ItemWithComments {
+    fn new(
+        comments: Vec<Comment>,
+        node: NodeOrToken<SyntaxNode, SyntaxToken>,
+        blank_line_before: bool,
+    ) -> Self {
+        let trailing_comment = node.as_node().and_then(common::comments::same_line_comment);
+        ItemWithComments {
+            comments,
+            node,
+            blank_line_before,
+            trailing_comment,
+        }
+    }
+
+    /// Whether `token` is the trailing comment of an item, so it is written with that item.
+    fn owns_comment(item: Option<&Self>, token: &SyntaxToken) -> bool {
+        item.and_then(|item| item.trailing_comment.as_ref()) == Some(token)
+    }
+
+    fn syntax(&self) -> Option<&SyntaxNode> {
+        match &self.node {
+            NodeOrToken::Node(n) => Some(n),
+            NodeOrToken::Token(_) => None,
+        }
+    }
 }
 
 fn sort_use_groups(items: &mut [ItemWithComments]) {
+    sort_use_runs(items, ItemWithComments::syntax, |item| {
+        &mut item.blank_line_before
+    });
+}
+
+/// Sort each run of adjacent `use` items, as split by blank lines, by import group and path.
+fn sort_use_runs<T>(
+    items: &mut [T],
+    node: impl Fn(&T) -> Option<&SyntaxNode>,
+    blank_line_before: impl Fn(&mut T) -> &mut bool,
+) {
+    sort_runs(
+        items,
+        node,
+        blank_line_before,
+        |n| n.kind() == SyntaxKind::USE,
+        |a, b| match (Use::cast(a.clone()), Use::cast(b.clone())) {
+            (Some(a), Some(b)) => {
+                let (group_a, path_a) = imports::classify_import(&a);
+                let (group_b, path_b) = imports::classify_import(&b);
+                group_a.cmp(&group_b).then_with(|| {
+                    useitem::sort::sort_key(&path_a).cmp(&useitem::sort::sort_key(&path_b))
+                })
+            }
+            _ => Ordering::Equal,
+        },
+    );
+}
+
+/// Sort each run of adjacent `mod foo;` declarations, as split by blank lines, by name.
+///
+/// `#[macro_use]` modules stay where they are, as the macros they define are only in scope
+/// after them.
+fn sort_mod_runs(items: &mut [ItemWithComments]) {
+    let name = |n: &SyntaxNode| {
+        Module::cast(n.clone())
+            .and_then(|m| m.name())
+            .map(|name| name.text().to_string())
+            .unwrap_or_default()
+    };
+    sort_runs(
+        items,
+        ItemWithComments::syntax,
+        |item| &mut item.blank_line_before,
+        |n| {
+            Module::cast(n.clone()).is_some_and(|m| {
+                m.item_list().is_none()
+                    && !m
+                        .attrs()
+                        .any(|attr| attr.simple_name().as_deref() == Some("macro_use"))
+            })
+        },
+        |a, b| useitem::sort::sort_key(&name(a)).cmp(&useitem::sort::sort_key(&name(b))),
+    );
+}
+
+/// Sort each run of adjacent items whose nodes are `in_run`, as split by blank lines.
+///
+/// Items carry whatever is attached to them (such as leading comments) as they move, but the
+/// blank line before a run stays at its start.
+fn sort_runs<T>(
+    items: &mut [T],
+    node: impl Fn(&T) -> Option<&SyntaxNode>,
+    blank_line_before: impl Fn(&mut T) -> &mut bool,
+    in_run: impl Fn(&SyntaxNode) -> bool,
+    compare: impl Fn(&SyntaxNode, &SyntaxNode) -> Ordering,
+) {
+    let is_member = |item: &T| node(item).is_some_and(&in_run);
     let mut i = 0;
     while i < items.len() {
-        if let NodeOrToken::Node(n) = &items[i].node
-            && n.kind() == SyntaxKind::USE
-        {
+        if is_member(&items[i]) {
             let start = i;
             let mut end = i + 1;
-            while end < items.len() {
-                if let NodeOrToken::Node(n) = &items[end].node
-                    && n.kind() == SyntaxKind::USE
-                    && !items[end].blank_line_before
-                {
-                    end += 1;
-                    continue;
-                }
-                break;
+            while end < items.len()
+                && is_member(&items[end])
+                && !*blank_line_before(&mut items[end])
+            {
+                end += 1;
             }
             if end > start + 1 {
-                items[start..end].sort_by(|a, b| {
-                    let use_a = match &a.node {
-                        NodeOrToken::Node(n) => Use::cast(n.clone()),
-                        _ => None,
-                    };
-                    let use_b = match &b.node {
-                        NodeOrToken::Node(n) => Use::cast(n.clone()),
-                        _ => None,
-                    };
-                    match (use_a, use_b) {
-                        (Some(a), Some(b)) => {
-                            let (group_a, path_a) = imports::classify_import(&a);
-                            let (group_b, path_b) = imports::classify_import(&b);
-                            group_a.cmp(&group_b).then_with(|| {
-                                useitem::sort::sort_key(&path_a)
-                                    .cmp(&useitem::sort::sort_key(&path_b))
-                            })
-                        }
-                        _ => std::cmp::Ordering::Equal,
-                    }
+                let blank_before_run = std::mem::take(blank_line_before(&mut items[start]));
+                items[start..end].sort_by(|a, b| match (node(a), node(b)) {
+                    (Some(a), Some(b)) => compare(a, b),
+                    _ => Ordering::Equal,
                 });
+                *blank_line_before(&mut items[start]) = blank_before_run;
             }
             i = end;
             continue;
@@ -141,6 +223,76 @@ fn sort_use_groups(items: &mut [ItemWithComments]) {
     }
 }
 
+/// The place of a top-level item in `reorder_items_by_kind` order, or `None` for an item that
+/// others may not move past.
+fn item_kind_rank(kind: SyntaxKind) -> Option<u8> {
+    match kind {
+        SyntaxKind::EXTERN_CRATE | SyntaxKind::USE => Some(0),
+        SyntaxKind::MODULE => Some(1),
+        SyntaxKind::CONST | SyntaxKind::STATIC => Some(2),
+        SyntaxKind::STRUCT | SyntaxKind::ENUM | SyntaxKind::UNION | SyntaxKind::TYPE_ALIAS => {
+            Some(3)
+        }
+        SyntaxKind::TRAIT => Some(4),
+        SyntaxKind::IMPL => Some(5),
+        SyntaxKind::FN => Some(6),
+        _ => None,
+    }
+}
+
+/// Order the items between each pair of macros or standalone comments by kind, keeping the
+/// source order among items of the same kind.
+///
+/// A blank line is put between items of different kinds, and the blank line before the
+/// first item stays at the start.
+fn order_items_by_kind(items: &mut [ItemWithComments]) {
+    let rank = |item: &ItemWithComments| item.syntax().and_then(|n| item_kind_rank(n.kind()));
+    for segment in items.split_mut(|item| rank(item).is_none()) {
+        let Some(first) = segment.first() else {
+            continue;
+        };
+        let blank_before_segment = first.blank_line_before;
+        segment.sort_by_key(rank);
+        for i in 0..segment.len() {
+            if i == 0 {
+                segment[i].blank_line_before = blank_before_segment;
+            } else if rank(&segment[i]) != rank(&segment[i - 1]) {
+                segment[i].blank_line_before = true;
+            }
+        }
+    }
+}
+
+/// Whether a node is an item, which may also appear among the statements of a block.
+fn is_item(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::FN
+            | SyntaxKind::STRUCT
+            | SyntaxKind::ENUM
+            | SyntaxKind::IMPL
+            | SyntaxKind::TRAIT
+            | SyntaxKind::USE
+            | SyntaxKind::MODULE
+            | SyntaxKind::TYPE_ALIAS
+            | SyntaxKind::CONST
+            | SyntaxKind::STATIC
+            | SyntaxKind::EXTERN_CRATE
+            | SyntaxKind::EXTERN_BLOCK
+            | SyntaxKind::MACRO_RULES
+            | SyntaxKind::MACRO_DEF
+    )
+}
+
+/// Format an item nested in a block at the block's indent, without the trailing newline.
+fn format_nested_item(node: &SyntaxNode, indent: usize) -> String {
+    let mut buf = String::new();
+    format_node(node, &mut buf, indent);
+    let len = buf.trim_end_matches('\n').len();
+    buf.truncate(len);
+    buf
+}
+
 /// Main node formatting dispatcher
 pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
     match node.kind() {
@@ -167,26 +319,29 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
                                     pending_blank_line_for_inner_attr = false;
                                     pending_blank_line = false;
                                 } else {
-                                    other_items.push(ItemWithComments {
-                                        comments: std::mem::take(&mut pending_comments),
-                                        node: NodeOrToken::Node(n.clone()),
-                                        blank_line_before: pending_blank_line,
-                                    });
+                                    other_items.push(ItemWithComments::new(
+                                        std::mem::take(&mut pending_comments),
+                                        NodeOrToken::Node(n.clone()),
+                                        pending_blank_line,
+                                    ));
                                     pending_blank_line = false;
                                 }
                             }
                         }
                         _ => {
-                            other_items.push(ItemWithComments {
-                                comments: std::mem::take(&mut pending_comments),
-                                node: NodeOrToken::Node(n.clone()),
-                                blank_line_before: pending_blank_line,
-                            });
+                            other_items.push(ItemWithComments::new(
+                                std::mem::take(&mut pending_comments),
+                                NodeOrToken::Node(n.clone()),
+                                pending_blank_line,
+                            ));
                             pending_blank_line = false;
                             pending_blank_line_for_inner_attr = false;
                         }
                     },
                     NodeOrToken::Token(t) => {
+                        if ItemWithComments::owns_comment(other_items.last(), t) {
+                            continue;
+                        }
                         if t.kind() == SyntaxKind::COMMENT {
                             if let Some(comment) = Comment::cast(t.clone()) {
                                 if comment.is_inner() && comment.kind().doc.is_some() {
@@ -201,13 +356,11 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
                             // Blank line encountered - flush pending comments as standalone
                             if !pending_comments.is_empty() {
                                 for comment in pending_comments.drain(..) {
-                                    other_items.push(ItemWithComments {
-                                        comments: vec![],
-                                        node: NodeOrToken::Token(SyntaxToken::from(
-                                            comment.syntax().clone(),
-                                        )),
-                                        blank_line_before: pending_blank_line,
-                                    });
+                                    other_items.push(ItemWithComments::new(
+                                        vec![],
+                                        NodeOrToken::Token(comment.syntax().clone()),
+                                        pending_blank_line,
+                                    ));
                                     pending_blank_line = false;
                                 }
                             }
@@ -219,30 +372,51 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
             }
             if !pending_comments.is_empty() {
                 for comment in pending_comments {
-                    other_items.push(ItemWithComments {
-                        comments: vec![],
-                        node: NodeOrToken::Token(SyntaxToken::from(comment.syntax().clone())),
-                        blank_line_before: pending_blank_line,
-                    });
+                    other_items.push(ItemWithComments::new(
+                        vec![],
+                        NodeOrToken::Token(comment.syntax().clone()),
+                        pending_blank_line,
+                    ));
                     pending_blank_line = false;
                 }
             }
+            let (by_kind, reorder_modules) =
+                config::with(|c| (c.reorder_items_by_kind, c.reorder_modules));
+            if by_kind {
+                order_items_by_kind(&mut other_items);
+            }
+            if reorder_modules {
+                sort_mod_runs(&mut other_items);
+            }
             sort_use_groups(&mut other_items);
-            for doc in &module_inner_docs {
-                buf.push_str(doc.text());
-                buf.push('\n');
+            let module_inner_docs: Vec<_> = module_inner_docs
+                .iter()
+                .map(|doc| DocOrAttr::Comment(doc.text().to_string()))
+                .collect();
+            for doc in doc_code::format_docs_and_attrs(&module_inner_docs, 0) {
+                buf.newline(&doc);
             }
             if !module_inner_docs.is_empty() && !inner_attrs.is_empty() {
                 buf.blank();
             }
+            // Attributes are written in runs, so that `#![doc]` runs are formatted together
+            let mut run = Vec::new();
             for (i, (comments, attr, blank_before)) in inner_attrs.iter().enumerate() {
+                if (i > 0 && *blank_before) || !comments.is_empty() {
+                    for line in doc_code::format_docs_and_attrs(&std::mem::take(&mut run), 0) {
+                        buf.newline(&line);
+                    }
+                }
                 if i > 0 && *blank_before {
                     buf.blank();
                 }
                 for comment in comments {
                     buf.newline(comment.text());
                 }
-                buf.newline(&attr.syntax().text().to_string());
+                run.push(DocOrAttr::Attr(attr.clone()));
+            }
+            for line in doc_code::format_docs_and_attrs(&run, 0) {
+                buf.newline(&line);
             }
             if (!inner_attrs.is_empty() || !module_inner_docs.is_empty()) && !other_items.is_empty()
             {
@@ -250,6 +424,10 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
             }
             let mut last_kind: Option<SyntaxKind> = None;
             let mut prev_was_standalone_comment = false;
+            let moved_comments = other_items
+                .iter()
+                .filter_map(|item| item.trailing_comment.clone())
+                .collect();
 
             for item in other_items {
                 // Output comments attached to this item
@@ -267,6 +445,14 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 match item.node {
                     NodeOrToken::Node(n) => {
                         let current_kind = n.kind();
+                        let mut formatted = String::new();
+                        format_node(&n, &mut formatted, indent);
+                        let formatted = common::comments::take_comment_trailing_prev(
+                            buf,
+                            &n,
+                            formatted,
+                            &moved_comments,
+                        );
                         // Add blank line if needed (only when no comments preceded this)
                         if item.comments.is_empty() {
                             let needs_blank = item.blank_line_before
@@ -278,7 +464,10 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
                                 buf.blank();
                             }
                         }
-                        format_node(&n, buf, indent);
+                        buf.push_str(&formatted);
+                        if let Some(comment) = &item.trailing_comment {
+                            common::comments::push_trailing_comment(buf, comment);
+                        }
                         last_kind = Some(current_kind);
                         prev_was_standalone_comment = false;
                     }
@@ -299,18 +488,41 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
         }
 
         SyntaxKind::FN => format_function(node, buf, indent),
-        SyntaxKind::STRUCT => format_struct(node, buf, indent),
-        SyntaxKind::ENUM => format_enum(node, buf, indent),
-        SyntaxKind::IMPL => format_impl(node, buf, indent),
-        SyntaxKind::TRAIT => format_trait(node, buf, indent),
+        SyntaxKind::STRUCT => {
+            leading_comments(node, buf, indent);
+            format_struct(node, buf, indent);
+        }
+        SyntaxKind::ENUM => {
+            leading_comments(node, buf, indent);
+            format_enum(node, buf, indent);
+        }
+        SyntaxKind::IMPL => {
+            leading_comments(node, buf, indent);
+            format_impl(node, buf, indent);
+        }
+        SyntaxKind::TRAIT => {
+            leading_comments(node, buf, indent);
+            format_trait(node, buf, indent);
+        }
         SyntaxKind::USE => format_use(node, buf, indent),
         SyntaxKind::MODULE => format_module(node, buf, indent),
-        SyntaxKind::TYPE_ALIAS => format_type_alias(node, buf, indent),
-        SyntaxKind::CONST => format_const_or_static(node, buf, indent),
-        SyntaxKind::STATIC => format_const_or_static(node, buf, indent),
+        SyntaxKind::TYPE_ALIAS => {
+            leading_comments(node, buf, indent);
+            format_type_alias(node, buf, indent);
+        }
+        SyntaxKind::CONST | SyntaxKind::STATIC => {
+            leading_comments(node, buf, indent);
+            format_const_or_static(node, buf, indent);
+        }
 
-        SyntaxKind::EXTERN_CRATE => format_extern_crate(node, buf, indent),
-        SyntaxKind::EXTERN_BLOCK => format_extern_block(node, buf, indent),
+        SyntaxKind::EXTERN_CRATE => {
+            leading_comments(node, buf, indent);
+            format_extern_crate(node, buf, indent);
+        }
+        SyntaxKind::EXTERN_BLOCK => {
+            leading_comments(node, buf, indent);
+            format_extern_block(node, buf, indent);
+        }
 
         SyntaxKind::BLOCK_EXPR => format_block(node, buf, indent),
         SyntaxKind::STMT_LIST => format_stmt_list(node, buf, indent),
@@ -327,7 +539,7 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
             // Handle standalone attributes
             if let Some(attr) = Attr::cast(node.clone()) {
                 crate::formatter::write_indent(buf, indent);
-                buf.push_str(attr.syntax().text().to_string().as_str());
+                buf.push_str(&common::attrs::format_attr(&attr, indent));
                 buf.push('\n');
             }
         }
@@ -344,6 +556,22 @@ pub fn format_node(node: &SyntaxNode, buf: &mut String, indent: usize) {
     }
 }
 
+/// Write the plain `//` comments that the parser places at the start of an item, before its
+/// docs, attributes and keywords, for the item formatters that do not write them themselves.
+fn leading_comments(node: &SyntaxNode, buf: &mut String, indent: usize) {
+    for child in node.children_with_tokens() {
+        match child {
+            NodeOrToken::Token(t) if t.kind() == SyntaxKind::COMMENT => {
+                if !common::comments::is_doc_comment(&t) {
+                    buf.line(indent, t.text());
+                }
+            }
+            NodeOrToken::Token(t) if t.kind() == SyntaxKind::WHITESPACE => {}
+            _ => break,
+        }
+    }
+}
+
 /// Format a single token (comments, whitespace, keywords, etc.)
 fn format_token(token: &SyntaxToken, buf: &mut String, _indent: usize) {
     match token.kind() {
@@ -1,8 +1,14 @@
-use crate::formatter::write_indent;
-use ra_ap_syntax::{NodeOrToken, SyntaxKind, SyntaxNode};
+use std::collections::HashSet;
+
+use crate::formatter::{text_width, write_indent};
+use ra_ap_syntax::ast::{self, AstNode};
+use ra_ap_syntax::{NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken};
 
 use super::common::comments;
+use super::expr::chain::{format_chain_at, is_method_chain, link_count};
+use super::expr::comments::keeps_comments;
 use super::expr::{FormatResult, try_format_expr};
+use super::{format_nested_item, is_item, sort_use_runs};
 
 pub fn format_block(node: &SyntaxNode, buf: &mut String, indent: usize) {
     buf.push_str("{\n");
@@ -17,6 +23,9 @@ struct StmtWithComments {
     node: SyntaxNode,
     blank_line_before: bool,
     is_last: bool,
+    /// The comment on the same line after the statement, which moves with it when `use` items
+    /// are sorted
+    trailing_comment: Option<SyntaxToken>,
 }
 
 pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
@@ -27,6 +36,7 @@ pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
     let mut pending_comments: Vec<String> = Vec::new();
     let mut pending_blank_line = false;
     let mut standalone_comments: Vec<(Vec<String>, bool)> = Vec::new(); // (comments, blank_line_before)
+    let mut opening_comment: Option<SyntaxToken> = None;
 
     // First pass: identify all nodes and their indices
     let node_indices: Vec<usize> = children
@@ -49,6 +59,7 @@ pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
                     node: n.clone(),
                     blank_line_before: pending_blank_line,
                     is_last,
+                    trailing_comment: comments::same_line_comment(n),
                 });
                 pending_blank_line = false;
             }
@@ -64,12 +75,12 @@ pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
                     // Otherwise it's a trailing comment for the previous node (handled during output)
                     if has_newline_before {
                         pending_comments.push(t.text().to_string());
+                    } else if items.is_empty() {
+                        opening_comment = Some(t.clone());
                     }
                 }
-                SyntaxKind::WHITESPACE => {
-                    if t.text().matches('\n').count() >= 2 {
-                        pending_blank_line = true;
-                    }
+                SyntaxKind::WHITESPACE if t.text().matches('\n').count() >= 2 => {
+                    pending_blank_line = true;
                 }
                 _ => {}
             },
@@ -81,6 +92,27 @@ pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
         standalone_comments.push((std::mem::take(&mut pending_comments), pending_blank_line));
     }
 
+    sort_use_runs(
+        &mut items,
+        |item| Some(&item.node),
+        |item| &mut item.blank_line_before,
+    );
+
+    let mut moved_comments: HashSet<_> = items
+        .iter()
+        .filter_map(|item| item.trailing_comment.clone())
+        .collect();
+    // A comment after the opening brace stays there wherever the first statement goes
+    if let Some(comment) = opening_comment.or_else(|| {
+        node_indices
+            .first()
+            .and_then(|&idx| children[idx].as_node())
+            .and_then(comments::comment_trailing_prev)
+    }) {
+        comments::push_trailing_comment(buf, &comment);
+        moved_comments.insert(comment);
+    }
+
     // Output items
     let mut prev_was_item = false;
 
@@ -97,6 +129,20 @@ pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
             buf.push('\n');
         }
 
+        // Nested items are formatted as they would be at module level
+        if is_item(item.node.kind()) {
+            let formatted = format_nested_item(&item.node, indent);
+            let formatted =
+                comments::take_comment_trailing_prev(buf, &item.node, formatted, &moved_comments);
+            buf.push_str(&formatted);
+            if let Some(comment) = &item.trailing_comment {
+                comments::push_trailing_comment(buf, comment);
+            }
+            buf.push('\n');
+            prev_was_item = true;
+            continue;
+        }
+
         // Output the statement
         write_indent(buf, indent);
         match try_format_expr(&item.node, indent) {
@@ -105,23 +151,18 @@ pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 if !item.is_last {
                     buf.push(';');
                 }
-                // Check for trailing comment on same line
-                if let Some((whitespace, comment)) =
-                    comments::get_trailing_comment_sibling(&item.node)
-                {
-                    buf.push_str(&whitespace);
-                    buf.push_str(&comment);
+                if let Some(comment) = &item.trailing_comment {
+                    comments::push_trailing_comment(buf, comment);
                 }
                 buf.push('\n');
             }
             FormatResult::Unsupported => {
-                buf.push_str(&item.node.text().to_string());
-                // Check for trailing comment on same line
-                if let Some((whitespace, comment)) =
-                    comments::get_trailing_comment_sibling(&item.node)
-                {
-                    buf.push_str(&whitespace);
-                    buf.push_str(&comment);
+                match format_chain_stmt(&item.node, indent) {
+                    Some(formatted) => buf.push_str(&formatted),
+                    None => buf.push_str(&item.node.text().to_string()),
+                }
+                if let Some(comment) = &item.trailing_comment {
+                    comments::push_trailing_comment(buf, comment);
                 }
                 buf.push('\n');
             }
@@ -143,12 +184,46 @@ pub fn format_stmt_list(node: &SyntaxNode, buf: &mut String, indent: usize) {
     }
 }
 
+/// Format a `let` or expression statement whose expression is a method chain of at least two
+/// links, laying out the chain as in tail position after the rest of the statement, which is
+/// kept as written.
+fn format_chain_stmt(node: &SyntaxNode, indent: usize) -> Option<String> {
+    let expr = match node.kind() {
+        SyntaxKind::LET_STMT => ast::LetStmt::cast(node.clone())?.initializer()?,
+        SyntaxKind::EXPR_STMT => ast::ExprStmt::cast(node.clone())?.expr()?,
+        _ => return None,
+    };
+    if !is_method_chain(expr.syntax()) || link_count(expr.syntax()) < 2 {
+        return None;
+    }
+    let text = node.text().to_string();
+    let node_start = node.text_range().start();
+    let range = expr.syntax().text_range();
+    let prefix = &text[..usize::from(range.start() - node_start)];
+    let suffix = &text[usize::from(range.end() - node_start)..];
+    if prefix.contains('\n') || suffix.contains('\n') {
+        return None;
+    }
+    let chain = format_chain_at(
+        expr.syntax(),
+        indent,
+        indent + text_width(prefix),
+        text_width(suffix),
+    )?;
+    let formatted = format!("{prefix}{chain}{suffix}");
+    keeps_comments(node, &formatted).then_some(formatted)
+}
+
 pub fn format_block_expr_contents(node: &SyntaxNode, buf: &mut String, indent: usize) {
     for child in node.children_with_tokens() {
         match child {
             NodeOrToken::Node(n) => match n.kind() {
                 SyntaxKind::STMT_LIST => format_stmt_list(&n, buf, indent),
                 SyntaxKind::WHITESPACE => continue,
+                kind if is_item(kind) => {
+                    buf.push_str(&format_nested_item(&n, indent));
+                    buf.push('\n');
+                }
                 _ => {
                     write_indent(buf, indent);
                     buf.push_str(&n.text().to_string());
@@ -1,5 +1,6 @@
 // src/formatter/node/common.rs
 // Shared helpers used by node formatters.
+pub mod attrs;
 pub mod comments;
 pub mod fields;
 pub mod header;
@@ -0,0 +1,244 @@
+use ra_ap_syntax::ast::{self, AstNode};
+use ra_ap_syntax::{NodeOrToken, SyntaxElement, SyntaxKind};
+
+use crate::formatter::config::max_width;
+use crate::formatter::write_indent;
+
+/// An item in the arguments of an attribute such as `#[derive(...)]` or `#[cfg(...)]`.
+enum MetaItem {
+    /// A path or a literal: `Debug`, `clippy::all`, `8`.
+    Word(String),
+    /// A name with a value: `feature = "std"`.
+    NameValue(String, String),
+    /// A name with a list of items: `all(unix, not(test))`.
+    List(String, Vec<MetaItem>),
+}
+
+impl MetaItem {
+    /// Write the item on a single line.
+    fn one_line(&self) -> String {
+        match self {
+            MetaItem::Word(word) => word.clone(),
+            MetaItem::NameValue(name, value) => format!("{} = {}", name, value),
+            MetaItem::List(name, items) => {
+                let items: Vec<String> = items.iter().map(MetaItem::one_line).collect();
+                format!("{}({})", name, items.join(", "))
+            }
+        }
+    }
+
+    /// Write the item starting at `column`, breaking lists that don't fit.
+    fn format(&self, indent: usize, column: usize, suffix_width: usize) -> String {
+        match self {
+            MetaItem::List(name, items) => format_list(name, items, indent, column, suffix_width),
+            item => item.one_line(),
+        }
+    }
+}
+
+/// Format an attribute written on its own line at `indent`, without the indentation of its
+/// first line.
+///
+/// Spacing is normalised, and argument lists too long for the line are broken one item per
+/// line with a trailing comma. Attributes whose arguments aren't meta items, such as
+/// `#[rustfmt::skip::macros(...)]` with arbitrary tokens, are written as they are.
+pub fn format_attr(attr: &ast::Attr, indent: usize) -> String {
+    let Some((open, meta)) = parse_attr(attr) else {
+        return attr.syntax().text().to_string();
+    };
+    format!("{}{}]", open, meta.format(indent, indent + open.len(), 1))
+}
+
+/// The traits of a `#[derive(...)]` attribute, if it is one that lists only paths.
+pub fn derive_paths(attr: &ast::Attr) -> Option<Vec<String>> {
+    if attr.excl_token().is_some() {
+        return None;
+    }
+    let (_, MetaItem::List(name, items)) = parse_attr(attr)? else {
+        return None;
+    };
+    if name != "derive" {
+        return None;
+    }
+    items
+        .into_iter()
+        .map(|item| match item {
+            MetaItem::Word(path) => Some(path),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Format a `#[derive(...)]` attribute of the given traits on its own line at `indent`.
+pub fn format_derive(paths: &[String], indent: usize) -> String {
+    let items: Vec<MetaItem> = paths.iter().cloned().map(MetaItem::Word).collect();
+    format!(
+        "#[{}]",
+        format_list("derive", &items, indent, indent + 2, 1)
+    )
+}
+
+/// Format an attribute written inline, before an expression, match arm or tuple field.
+pub fn format_attr_inline(attr: &ast::Attr) -> String {
+    match parse_attr(attr) {
+        Some((open, meta)) => format!("{}{}]", open, meta.one_line()),
+        None => attr.syntax().text().to_string(),
+    }
+}
+
+/// Split an attribute into its opening (`#[` or `#![`) and its meta item.
+fn parse_attr(attr: &ast::Attr) -> Option<(&'static str, MetaItem)> {
+    let has_comments = attr
+        .syntax()
+        .descendants_with_tokens()
+        .any(|element| element.kind() == SyntaxKind::COMMENT);
+    let meta = attr.meta()?;
+    if has_comments || meta.unsafe_token().is_some() {
+        return None;
+    }
+
+    let open = if attr.excl_token().is_some() {
+        "#!["
+    } else {
+        "#["
+    };
+    let path = meta.path()?.syntax().text().to_string();
+    let item = if let Some(token_tree) = meta.token_tree() {
+        MetaItem::List(path, parse_list(&token_tree)?)
+    } else if meta.eq_token().is_some() {
+        MetaItem::NameValue(path, meta.expr()?.syntax().text().to_string())
+    } else {
+        MetaItem::Word(path)
+    };
+    Some((open, item))
+}
+
+/// Parse a parenthesised token tree as a comma-separated list of meta items.
+fn parse_list(token_tree: &ast::TokenTree) -> Option<Vec<MetaItem>> {
+    let elements: Vec<SyntaxElement> = token_tree
+        .syntax()
+        .children_with_tokens()
+        .filter(|element| element.kind() != SyntaxKind::WHITESPACE)
+        .collect();
+    let (first, rest) = elements.split_first()?;
+    let (last, inner) = rest.split_last()?;
+    if first.kind() != SyntaxKind::L_PAREN || last.kind() != SyntaxKind::R_PAREN {
+        return None;
+    }
+
+    let mut items = Vec::new();
+    let mut segments = inner
+        .split(|element| element.kind() == SyntaxKind::COMMA)
+        .peekable();
+    while let Some(segment) = segments.next() {
+        // A trailing comma leaves an empty last segment
+        if segment.is_empty() && segments.peek().is_none() && !items.is_empty() {
+            break;
+        }
+        items.push(parse_item(segment)?);
+    }
+    Some(items)
+}
+
+/// Parse the tokens between two commas as a meta item.
+fn parse_item(elements: &[SyntaxElement]) -> Option<MetaItem> {
+    let (path, rest) = parse_path(elements);
+    let Some(path) = path else {
+        return match elements {
+            [NodeOrToken::Token(literal)] if literal.kind().is_literal() => {
+                Some(MetaItem::Word(literal.text().to_string()))
+            }
+            _ => None,
+        };
+    };
+
+    match rest {
+        [] => Some(MetaItem::Word(path)),
+        [NodeOrToken::Node(node)] => {
+            let token_tree = ast::TokenTree::cast(node.clone())?;
+            Some(MetaItem::List(path, parse_list(&token_tree)?))
+        }
+        [NodeOrToken::Token(eq), value @ ..] if eq.kind() == SyntaxKind::EQ => {
+            let value = match value {
+                [NodeOrToken::Token(literal)] if literal.kind().is_literal() => {
+                    literal.text().to_string()
+                }
+                [NodeOrToken::Token(minus), NodeOrToken::Token(literal)]
+                    if minus.kind() == SyntaxKind::MINUS && literal.kind().is_literal() =>
+                {
+                    format!("-{}", literal.text())
+                }
+                _ => match parse_path(value) {
+                    (Some(path), []) => path,
+                    _ => return None,
+                },
+            };
+            Some(MetaItem::NameValue(path, value))
+        }
+        _ => None,
+    }
+}
+
+/// Parse a path such as `clippy::all` from the start of a list of tokens, returning it and the
+/// remaining tokens.
+fn parse_path(elements: &[SyntaxElement]) -> (Option<String>, &[SyntaxElement]) {
+    let mut path = String::new();
+    let mut rest = elements;
+    loop {
+        if let [NodeOrToken::Token(a), NodeOrToken::Token(b), tail @ ..] = rest
+            && a.kind() == SyntaxKind::COLON
+            && b.kind() == SyntaxKind::COLON
+        {
+            path.push_str("::");
+            rest = tail;
+        } else if !path.is_empty() {
+            break;
+        }
+        match rest {
+            [NodeOrToken::Token(segment), tail @ ..] if segment.kind().is_any_identifier() => {
+                path.push_str(segment.text());
+                rest = tail;
+            }
+            _ => return (None, elements),
+        }
+    }
+    (Some(path), rest)
+}
+
+/// Format a list item starting at `column`, followed by `suffix_width` more characters on the
+/// line.
+fn format_list(
+    name: &str,
+    items: &[MetaItem],
+    indent: usize,
+    column: usize,
+    suffix_width: usize,
+) -> String {
+    let one_line: Vec<String> = items.iter().map(MetaItem::one_line).collect();
+    let one_line = format!("{}({})", name, one_line.join(", "));
+    if column + one_line.len() + suffix_width <= max_width() {
+        return one_line;
+    }
+
+    // A single nested list is broken in place: `#[cfg(any(` ... `))]`
+    if let [MetaItem::List(inner_name, inner_items)] = items {
+        let inner = format_list(
+            inner_name,
+            inner_items,
+            indent,
+            column + name.len() + 1,
+            suffix_width + 1,
+        );
+        return format!("{}({})", name, inner);
+    }
+
+    let mut buf = format!("{}(\n", name);
+    for item in items {
+        write_indent(&mut buf, indent + 4);
+        buf.push_str(&item.format(indent + 4, indent + 4, 1));
+        buf.push_str(",\n");
+    }
+    write_indent(&mut buf, indent);
+    buf.push(')');
+    buf
+}
@@ -1,40 +1,15 @@
-use ra_ap_syntax::{NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken};
+use std::collections::HashSet;
 
-/// Collect comments immediately before an item at the given index in a children list.
-///
-/// This includes ALL comment types (// and ///), but higher-level callers should
-/// skip doc comments if they are handled via HasDocComments.
-pub fn collect_preceding_comments_in_list(
-    children: &[NodeOrToken<SyntaxNode, SyntaxToken>],
-    item_idx: usize,
-) -> Vec<String> {
-    let mut comments = Vec::new();
+use ra_ap_syntax::{AstToken, NodeOrToken, SourceFile, SyntaxKind, SyntaxNode, SyntaxToken, ast};
 
-    for i in (0..item_idx).rev() {
-        match &children[i] {
-            NodeOrToken::Token(t) => {
-                if t.kind() == SyntaxKind::COMMENT {
-                    let text = t.text().to_string();
-                    // Skip doc comments (they're normally handled by HasDocComments at item-level)
-                    if !text.starts_with("///") && !text.starts_with("//!") {
-                        comments.push(text);
-                    }
-                } else if t.kind() == SyntaxKind::WHITESPACE {
-                    // Stop at blank lines (2+ newlines)
-                    if t.text().matches('\n').count() >= 2 {
-                        break;
-                    }
-                    // else continue
-                } else {
-                    break;
-                }
-            }
-            NodeOrToken::Node(_) => break,
-        }
-    }
+use crate::formatter::config::{self, TrailingCommentAlignment, max_width};
+use crate::formatter::text_width;
 
-    comments.reverse();
-    comments
+/// Whether a comment token is a doc comment (`///`, `//!`, `/** */` or `/*! */`).
+///
+/// Comments such as `////` and `/***` are plain comments, as in rustdoc.
+pub fn is_doc_comment(token: &SyntaxToken) -> bool {
+    ast::Comment::cast(token.clone()).is_some_and(|comment| comment.is_doc())
 }
 
 /// Check if there should be a blank line before a comment at the given index
@@ -107,7 +82,10 @@ pub fn get_trailing_comment_sibling(node: &SyntaxNode) -> Option<(String, String
         match &item {
             NodeOrToken::Token(t) => {
                 if t.kind() == SyntaxKind::COMMENT {
-                    return Some((whitespace_before, t.text().to_string()));
+                    return Some((
+                        trailing_comment_whitespace(&whitespace_before),
+                        t.text().to_string(),
+                    ));
                 } else if t.kind() == SyntaxKind::WHITESPACE {
                     if t.text().contains('\n') {
                         return None;
@@ -151,3 +129,496 @@ pub fn has_newline_before_node(node: &SyntaxNode) -> bool {
     }
     false
 }
+
+/// The whitespace to write before a trailing comment that had `original` before it.
+///
+/// Consecutive comments are lined up afterwards by `align_trailing_comments`.
+pub fn trailing_comment_whitespace(original: &str) -> String {
+    match config::with(|config| config.trailing_comment_alignment) {
+        TrailingCommentAlignment::Preserve if !original.is_empty() => original.to_string(),
+        _ => " ".to_string(),
+    }
+}
+
+/// The whitespace before a token on its line, or an empty string if there is none.
+pub fn whitespace_before_token(token: &SyntaxToken) -> String {
+    token
+        .prev_token()
+        .filter(|prev| prev.kind() == SyntaxKind::WHITESPACE && !prev.text().contains('\n'))
+        .map_or_else(String::new, |prev| prev.text().to_string())
+}
+
+/// The plain comment that the parser places at the start of `node` although it is on the
+/// same line as whatever comes before `node`, like the comment in `const N: usize = 3; // note`
+/// or one after the `{` of a block.
+pub fn comment_trailing_prev(node: &SyntaxNode) -> Option<SyntaxToken> {
+    let comment = node.first_token()?;
+    let is_plain_comment = comment.kind() == SyntaxKind::COMMENT && !is_doc_comment(&comment);
+    let follows_on_line = node.prev_sibling_or_token().is_some() && !has_newline_before_node(node);
+    (is_plain_comment && follows_on_line).then_some(comment)
+}
+
+/// The comment on the same line after `node`, either next to it in the list or at the start
+/// of the following node.
+///
+/// A formatter that moves `node` should move this comment with it.
+pub fn same_line_comment(node: &SyntaxNode) -> Option<SyntaxToken> {
+    let mut next = node.next_sibling_or_token();
+    while let Some(item) = next {
+        match &item {
+            NodeOrToken::Token(t) => match t.kind() {
+                SyntaxKind::COMMENT if !is_doc_comment(t) => return Some(t.clone()),
+                SyntaxKind::WHITESPACE if !t.text().contains('\n') => {}
+                SyntaxKind::COMMA | SyntaxKind::SEMICOLON => {}
+                _ => return None,
+            },
+            NodeOrToken::Node(n) => return comment_trailing_prev(n),
+        }
+        next = item.next_sibling_or_token();
+    }
+    None
+}
+
+/// Append a comment that trailed its item to the last line of `buf`.
+pub fn push_trailing_comment(buf: &mut String, comment: &SyntaxToken) {
+    let ends_line = buf.ends_with('\n');
+    if ends_line {
+        buf.pop();
+    }
+    buf.push_str(&trailing_comment_whitespace(&whitespace_before_token(
+        comment,
+    )));
+    buf.push_str(comment.text());
+    if ends_line {
+        buf.push('\n');
+    }
+}
+
+/// Leave the comment found by [`comment_trailing_prev`] out of `formatted`, the output for
+/// `node`, when it is written as its first line.
+pub fn strip_comment_trailing_prev(node: &SyntaxNode, formatted: String) -> String {
+    let Some(comment) = comment_trailing_prev(node) else {
+        return formatted;
+    };
+    match formatted.split_once('\n') {
+        Some((first_line, rest)) if first_line.trim() == comment.text() => rest.to_string(),
+        _ => formatted,
+    }
+}
+
+/// Move a comment on the same line as the previous item back to the end of that line.
+///
+/// The parser places a comment like the one in `const N: usize = 3; // note` inside the
+/// following item, whose formatter would write it as a leading comment. If `formatted`
+/// (the output for `node`) starts with such a comment, it is appended to the last line of
+/// `buf` and the rest of `formatted` is returned.
+pub fn reattach_trailing_comment(buf: &mut String, node: &SyntaxNode, formatted: String) -> String {
+    let Some(comment) = comment_trailing_prev(node) else {
+        return formatted;
+    };
+    if !buf.ends_with('\n') {
+        return formatted;
+    }
+    let original_len = formatted.len();
+    let rest = strip_comment_trailing_prev(node, formatted);
+    if rest.len() != original_len {
+        push_trailing_comment(buf, &comment);
+    }
+    rest
+}
+
+/// Leave the comment found by [`comment_trailing_prev`] out of `formatted`, the output for
+/// `node`, when it is one of `moved`, the comments that are written with the item they
+/// trail. Otherwise it is reattached to the end of `buf` as by [`reattach_trailing_comment`].
+pub fn take_comment_trailing_prev(
+    buf: &mut String,
+    node: &SyntaxNode,
+    formatted: String,
+    moved: &HashSet<SyntaxToken>,
+) -> String {
+    match comment_trailing_prev(node) {
+        Some(comment) if moved.contains(&comment) => strip_comment_trailing_prev(node, formatted),
+        _ => reattach_trailing_comment(buf, node, formatted),
+    }
+}
+
+/// Line up the trailing comments of formatted source, when `trailing_comment_alignment` is
+/// `AlignConsecutive`.
+///
+/// Trailing line comments on consecutive lines with the same indentation are moved to one
+/// column, one space after the longest line of code. A line that would then go past the
+/// maximum width starts a new group instead.
+pub fn align_trailing_comments(source: &str) -> String {
+    if config::with(|config| config.trailing_comment_alignment)
+        != TrailingCommentAlignment::AlignConsecutive
+    {
+        return source.to_string();
+    }
+
+    struct Trailing {
+        line: usize,
+        indent: usize,
+        /// Byte offset of the end of the code before the comment
+        code_end: usize,
+        /// Width of the line up to the end of the code
+        code_width: usize,
+        start: usize,
+        width: usize,
+    }
+
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let parse = SourceFile::parse(source, config::edition());
+    let trailing: Vec<Trailing> = parse
+        .syntax_node()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| {
+            token.kind() == SyntaxKind::COMMENT
+                && token.text().starts_with("//")
+                && !is_whole_line(source, token)
+        })
+        .map(|token| {
+            let start: usize = token.text_range().start().into();
+            let line = line_starts.partition_point(|&line_start| line_start <= start) - 1;
+            let line_text = &source[line_starts[line]..start];
+            let code_end = start - whitespace_before_token(&token).len();
+            Trailing {
+                line,
+                indent: line_text.len() - line_text.trim_start().len(),
+                code_end,
+                code_width: text_width(&source[line_starts[line]..code_end]),
+                start,
+                width: text_width(token.text()),
+            }
+        })
+        .collect();
+
+    // Group consecutive lines, then write each comment at its group's column
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+    let mut group: Vec<&Trailing> = Vec::new();
+    let mut flush = |group: &mut Vec<&Trailing>| {
+        let column = group.iter().map(|t| t.code_width + 1).max().unwrap_or(0);
+        for t in group.drain(..) {
+            let pad = column - t.code_width;
+            edits.push((t.code_end, t.start, " ".repeat(pad)));
+        }
+    };
+    for t in &trailing {
+        let continues = group
+            .last()
+            .is_some_and(|last| last.line + 1 == t.line && last.indent == t.indent);
+        let column = group
+            .iter()
+            .chain([&t])
+            .map(|member| member.code_width + 1)
+            .max()
+            .unwrap_or(0);
+        let fits = group
+            .iter()
+            .chain([&t])
+            .all(|member| column + member.width <= max_width());
+        if !continues || !fits {
+            flush(&mut group);
+        }
+        group.push(t);
+    }
+    flush(&mut group);
+
+    let mut output = source.to_string();
+    for (start, end, replacement) in edits.into_iter().rev() {
+        output.replace_range(start..end, &replacement);
+    }
+    output
+}
+
+/// Wrap and normalise the comments of formatted source, as set by `wrap_comments` and
+/// `normalize_comments`.
+///
+/// This runs over the finished output so that comments get the same treatment whichever
+/// formatter wrote them. Consecutive whole-line comments with the same marker and column
+/// are treated as one block of text; a trailing comment is a block of its own, and
+/// continues at its own column when wrapped.
+pub fn rewrite_comments(source: &str) -> String {
+    let (wrap, normalize, comment_width) = config::with(|config| {
+        (
+            config.wrap_comments,
+            config.normalize_comments,
+            config.comment_width,
+        )
+    });
+    if !wrap && !normalize {
+        return source.to_string();
+    }
+
+    let parse = SourceFile::parse(source, config::edition());
+    let comments: Vec<SyntaxToken> = parse
+        .syntax_node()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| token.kind() == SyntaxKind::COMMENT)
+        .collect();
+
+    // (start, end, replacement), in source order
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+    let mut i = 0;
+    while i < comments.len() {
+        let first = &comments[i];
+        let column = column_of(source, first);
+        let mut text = first.text().to_string();
+        if normalize {
+            text = normalize_comment(first, &text);
+        }
+        let Some(marker) = line_comment_marker(&text) else {
+            if text != first.text() {
+                let range = first.text_range();
+                edits.push((range.start().into(), range.end().into(), text));
+            }
+            i += 1;
+            continue;
+        };
+
+        // Extend the block over following whole-line comments with the same marker
+        let mut texts = vec![text];
+        let mut end = i + 1;
+        while end < comments.len() {
+            let next = &comments[end];
+            let mut next_text = next.text().to_string();
+            if normalize {
+                next_text = normalize_comment(next, &next_text);
+            }
+            let continues = is_whole_line(source, next)
+                && is_whole_line(source, first)
+                && column_of(source, next) == column
+                && line_comment_marker(&next_text) == Some(marker)
+                && next.prev_token().is_some_and(|ws| {
+                    ws.kind() == SyntaxKind::WHITESPACE && ws.text().matches('\n').count() == 1
+                })
+                && comments[end - 1].next_token().as_ref() == next.prev_token().as_ref();
+            if !continues {
+                break;
+            }
+            texts.push(next_text);
+            end += 1;
+        }
+
+        let lines = if wrap {
+            let limit = comment_width.min(max_width()).saturating_sub(column);
+            let bodies: Vec<&str> = texts
+                .iter()
+                .map(|text| {
+                    let body = &text[marker.len()..];
+                    body.strip_prefix(' ').unwrap_or(body)
+                })
+                .collect();
+            wrap_comment_text(&bodies, limit.saturating_sub(marker.len() + 1))
+                .into_iter()
+                .map(|body| {
+                    if body.is_empty() {
+                        marker.to_string()
+                    } else {
+                        format!("{} {}", marker, body)
+                    }
+                })
+                .collect()
+        } else {
+            texts
+        };
+
+        let original: Vec<&str> = comments[i..end].iter().map(|c| c.text()).collect();
+        if lines != original {
+            let start: usize = first.text_range().start().into();
+            let stop: usize = comments[end - 1].text_range().end().into();
+            let separator = format!("\n{}", " ".repeat(column));
+            edits.push((start, stop, lines.join(&separator)));
+        }
+        i = end;
+    }
+
+    let mut output = source.to_string();
+    for (start, end, replacement) in edits.into_iter().rev() {
+        output.replace_range(start..end, &replacement);
+    }
+    output
+}
+
+/// The column at which a token starts.
+fn column_of(source: &str, token: &SyntaxToken) -> usize {
+    let start: usize = token.text_range().start().into();
+    text_width(&source[source[..start].rfind('\n').map_or(0, |i| i + 1)..start])
+}
+
+/// Whether a token is the first thing on its line.
+fn is_whole_line(source: &str, token: &SyntaxToken) -> bool {
+    let start: usize = token.text_range().start().into();
+    let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    source[line_start..start].trim().is_empty()
+}
+
+/// The marker of a line comment (`//`, `///` or `//!`), or `None` for block comments.
+fn line_comment_marker(text: &str) -> Option<&'static str> {
+    if text.starts_with("////") {
+        Some("//")
+    } else if text.starts_with("///") {
+        Some("///")
+    } else if text.starts_with("//!") {
+        Some("//!")
+    } else if text.starts_with("//") {
+        Some("//")
+    } else {
+        None
+    }
+}
+
+/// Convert a single-line block comment that ends its line to a line comment, and put a space
+/// between a line comment's marker and its text.
+fn normalize_comment(token: &SyntaxToken, text: &str) -> String {
+    let ends_line = token
+        .next_token()
+        .is_none_or(|next| next.kind() == SyntaxKind::WHITESPACE && next.text().contains('\n'));
+
+    let mut text = text.to_string();
+    if ends_line
+        && !text.contains('\n')
+        && !text.starts_with("/***")
+        && let Some(inner) = text.strip_prefix("/*").and_then(|t| t.strip_suffix("*/"))
+        && !inner.contains("/*")
+        && !inner.contains("*/")
+    {
+        let (marker, body) = match inner.chars().next() {
+            Some('*') => ("///", &inner[1..]),
+            Some('!') => ("//!", &inner[1..]),
+            _ => ("//", inner),
+        };
+        let body = body.trim();
+        text = if body.is_empty() {
+            marker.to_string()
+        } else {
+            format!("{} {}", marker, body)
+        };
+    }
+
+    // `////` and longer are rules made of slashes, not a marker and text
+    if let Some(marker) = line_comment_marker(&text)
+        && !text.starts_with("////")
+        && let Some(first) = text[marker.len()..].chars().next()
+        && !first.is_whitespace()
+    {
+        text.insert(marker.len(), ' ');
+    }
+    text
+}
+
+/// Reflow the text of a block of comment lines (without their markers) to `width` columns.
+///
+/// Only lines that are too wide are broken, and the overflow is carried into the following
+/// line if it continues the same paragraph. Blank lines, code fences and their contents,
+/// tables, headings and lines with URLs are left as they are, and list items wrap with a
+/// hanging indent.
+fn wrap_comment_text(lines: &[&str], width: usize) -> Vec<String> {
+    let mut output = Vec::with_capacity(lines.len());
+    let mut in_fence = false;
+    // (indentation for continuation lines, text still to be written)
+    let mut carry: Option<(String, String)> = None;
+
+    for line in lines {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        let is_fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+
+        let verbatim = in_fence
+            || is_fence
+            || trimmed.is_empty()
+            || trimmed.starts_with('|')
+            || trimmed.starts_with('#')
+            || trimmed.contains("://");
+        if is_fence {
+            in_fence = !in_fence;
+        }
+
+        let (continuation, text) = match carry.take() {
+            Some((continuation, rest))
+                if !verbatim && indent == continuation && list_marker_len(trimmed).is_none() =>
+            {
+                (continuation, format!("{} {}", rest, trimmed))
+            }
+            pending => {
+                if let Some((continuation, rest)) = pending {
+                    output.extend(wrap_paragraph(&continuation, &rest, &continuation, width));
+                }
+                if verbatim {
+                    output.push(line.to_string());
+                    continue;
+                }
+                let hang = list_marker_len(trimmed).unwrap_or(0);
+                (
+                    format!("{}{}", indent, " ".repeat(hang)),
+                    trimmed.to_string(),
+                )
+            }
+        };
+
+        // A carried line has the continuation indent, so both keep the line's own indent
+        let first_indent = indent;
+        if text_width(first_indent) + text_width(&text) <= width {
+            output.push(format!("{}{}", first_indent, text));
+            continue;
+        }
+        match split_at_width(&text, width.saturating_sub(text_width(first_indent))) {
+            Some((head, rest)) => {
+                output.push(format!("{}{}", first_indent, head));
+                carry = Some((continuation, rest.to_string()));
+            }
+            None => output.push(format!("{}{}", first_indent, text)),
+        }
+    }
+
+    if let Some((continuation, rest)) = carry {
+        output.extend(wrap_paragraph(&continuation, &rest, &continuation, width));
+    }
+    output
+}
+
+/// Break text into lines of at most `width` columns (a single long word may exceed it).
+fn wrap_paragraph(first_indent: &str, text: &str, indent: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut prefix = first_indent;
+    let mut rest = text;
+    while let Some((head, tail)) = split_at_width(rest, width.saturating_sub(text_width(prefix))) {
+        lines.push(format!("{}{}", prefix, head));
+        prefix = indent;
+        rest = tail;
+    }
+    lines.push(format!("{}{}", prefix, rest));
+    lines
+}
+
+/// Split text at the last space that keeps the head within `width`, or at the first space if
+/// the first word is already too long. Returns `None` if the text fits or can't be split.
+fn split_at_width(text: &str, width: usize) -> Option<(&str, &str)> {
+    if text_width(text) <= width {
+        return None;
+    }
+    // The first `width + 1` characters, so that a space just past the limit can be split at
+    let end = text
+        .char_indices()
+        .nth(width + 1)
+        .map_or(text.len(), |(i, _)| i);
+    let split = text[..end]
+        .rfind(' ')
+        .filter(|&i| i > 0)
+        .or_else(|| text.find(' '))?;
+    let (head, tail) = (text[..split].trim_end(), text[split..].trim_start());
+    (!head.is_empty() && !tail.is_empty()).then_some((head, tail))
+}
+
+/// The width of a markdown list marker at the start of a line (`- `, `* `, `1. `), if any.
+fn list_marker_len(text: &str) -> Option<usize> {
+    if text.starts_with("- ") || text.starts_with("* ") || text.starts_with("+ ") {
+        return Some(2);
+    }
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    let after = &text[digits..];
+    (digits > 0 && (after.starts_with(". ") || after.starts_with(") "))).then_some(digits + 2)
+}
@@ -1,9 +1,10 @@
-use ra_ap_syntax::ast::{self, HasName};
-use ra_ap_syntax::{AstNode, NodeOrToken, SyntaxKind};
+use ra_ap_syntax::ast::{self, HasAttrs, HasName};
+use ra_ap_syntax::{AstNode, NodeOrToken, SyntaxKind, SyntaxNode};
 
+use crate::formatter::config::max_width;
 use crate::formatter::printer::Printer;
 
-use super::comments;
+use super::{attrs, comments};
 
 /// Collect inner comments, excluding any that should be trailing for the previous field
 fn collect_inner_comments_excluding_trailing(node: &ra_ap_syntax::SyntaxNode) -> Vec<String> {
@@ -16,7 +17,7 @@ fn collect_inner_comments_excluding_trailing(node: &ra_ap_syntax::SyntaxNode) ->
         .filter_map(|child| match child {
             NodeOrToken::Token(t) if t.kind() == SyntaxKind::COMMENT => {
                 let text = t.text().to_string();
-                if text.starts_with("///") || text.starts_with("//!") {
+                if comments::is_doc_comment(&t) {
                     return None;
                 }
                 // Skip first comment if it's trailing for previous (no newline before node)
@@ -48,8 +49,11 @@ fn get_trailing_comment_for_prev(node: &ra_ap_syntax::SyntaxNode) -> Option<(Str
             NodeOrToken::Token(t) => {
                 if t.kind() == SyntaxKind::COMMENT {
                     let text = t.text().to_string();
-                    if !text.starts_with("///") && !text.starts_with("//!") {
-                        return Some((whitespace_before, text));
+                    if !comments::is_doc_comment(&t) {
+                        return Some((
+                            comments::trailing_comment_whitespace(&whitespace_before),
+                            text,
+                        ));
                     }
                 } else if t.kind() == SyntaxKind::WHITESPACE && t.text().contains('\n') {
                     return None;
@@ -64,6 +68,36 @@ fn get_trailing_comment_for_prev(node: &ra_ap_syntax::SyntaxNode) -> Option<(Str
     None
 }
 
+/// Comments between the previous field and `node` that the parser left in the field list rather
+/// than attaching to `node`, as it does when a blank line follows them. A comment after the
+/// previous field's comma on the same line is that field's trailing comment, so it is skipped.
+fn detached_comments_before(node: &SyntaxNode) -> Vec<String> {
+    let mut found = Vec::new();
+    let mut current = node.prev_sibling_or_token();
+    while let Some(NodeOrToken::Token(t)) = current {
+        if t.kind() == SyntaxKind::COMMENT && !follows_comma_on_same_line(&t) {
+            found.push(t.text().to_string());
+        }
+        current = t.prev_sibling_or_token();
+    }
+    found.reverse();
+    found
+}
+
+/// Whether only spaces separate `token` from a comma before it.
+fn follows_comma_on_same_line(token: &ra_ap_syntax::SyntaxToken) -> bool {
+    let mut prev = token.prev_sibling_or_token();
+    while let Some(NodeOrToken::Token(t)) = prev {
+        match t.kind() {
+            SyntaxKind::COMMA => return true,
+            SyntaxKind::WHITESPACE if !t.text().contains('\n') => {}
+            _ => return false,
+        }
+        prev = t.prev_sibling_or_token();
+    }
+    false
+}
+
 /// Get whitespace immediately before a node (from siblings)
 fn get_whitespace_before_node(node: &ra_ap_syntax::SyntaxNode) -> String {
     let mut current = node.prev_sibling_or_token();
@@ -94,11 +128,15 @@ pub fn format_record_fields(fields: &ast::RecordFieldList, buf: &mut String, ind
         // Check if next field has a trailing comment for us
         let trailing_comment = if idx + 1 < field_list.len() {
             get_trailing_comment_for_prev(field_list[idx + 1].syntax())
+                .or_else(|| comments::get_trailing_comment_sibling(field.syntax()))
         } else {
             // Last field - check siblings
             comments::get_trailing_comment_sibling(field.syntax())
         };
 
+        for comment in detached_comments_before(field.syntax()) {
+            buf.line(indent, &comment);
+        }
         // Collect comments excluding any trailing for previous
         for comment in collect_inner_comments_excluding_trailing(field.syntax()) {
             buf.line(indent, &comment);
@@ -106,19 +144,7 @@ pub fn format_record_fields(fields: &ast::RecordFieldList, buf: &mut String, ind
         buf.doc_comments(field, indent);
         buf.attrs(field, indent);
         buf.indent(indent);
-        buf.visibility(field);
-        if let Some(name) = field.name() {
-            buf.push_str(&name.text());
-        }
-        buf.push_str(": ");
-        if let Some(ty) = field.ty() {
-            buf.push_str(&ty.syntax().text().to_string());
-        }
-
-        if let Some(expr) = field.expr() {
-            buf.push_str(" = ");
-            buf.push_str(&expr.syntax().text().to_string());
-        }
+        buf.push_str(&format_record_field(field));
 
         if let Some((ref whitespace, ref comment)) = trailing_comment {
             buf.push(',');
@@ -129,3 +155,138 @@ pub fn format_record_fields(fields: &ast::RecordFieldList, buf: &mut String, ind
         }
     }
 }
+
+/// Format a record field without its docs or attributes: `pub name: Type = default`.
+pub fn format_record_field(field: &ast::RecordField) -> String {
+    let mut buf = String::new();
+    buf.visibility(field);
+    if let Some(name) = field.name() {
+        buf.push_str(&name.text());
+    }
+    buf.push_str(": ");
+    if let Some(ty) = field.ty() {
+        buf.push_str(&ty.syntax().text().to_string());
+    }
+    if let Some(expr) = field.expr() {
+        buf.push_str(" = ");
+        buf.push_str(&expr.syntax().text().to_string());
+    }
+    buf
+}
+
+/// Format a tuple field without its docs, keeping attributes inline: `#[attr] pub Type`.
+pub fn format_tuple_field(field: &ast::TupleField) -> String {
+    let mut buf = String::new();
+    for attr in field.attrs() {
+        buf.push_str(&attrs::format_attr_inline(&attr));
+        buf.push(' ');
+    }
+    buf.visibility(field);
+    if let Some(ty) = field.ty() {
+        buf.push_str(&ty.syntax().text().to_string());
+    }
+    buf
+}
+
+/// Check whether a field list contains any comments, including doc comments.
+fn has_comments(node: &SyntaxNode) -> bool {
+    node.descendants_with_tokens()
+        .any(|child| matches!(child, NodeOrToken::Token(t) if t.kind() == SyntaxKind::COMMENT))
+}
+
+/// Format record fields on one line as `a: A, b: B`.
+///
+/// Returns `None` if any field has comments, docs or attributes, or if the result would be
+/// wider than `max_width`.
+pub fn format_record_fields_inline(
+    fields: &ast::RecordFieldList,
+    max_width: usize,
+) -> Option<String> {
+    if has_comments(fields.syntax()) || fields.fields().any(|f| f.attrs().next().is_some()) {
+        return None;
+    }
+    let body = fields
+        .fields()
+        .map(|field| format_record_field(&field))
+        .collect::<Vec<_>>()
+        .join(", ");
+    (!body.contains('\n') && body.len() <= max_width).then_some(body)
+}
+
+/// Format a tuple field list, including its parentheses.
+///
+/// `offset` is the column of the opening `(` and `suffix_width` the width of whatever
+/// follows the closing `)` on the same line, such as `;` or `,`. The fields stay on one
+/// line if they fit within the maximum width and carry no comments or docs; otherwise each goes on
+/// its own line at `indent + 4` with a trailing comma.
+pub fn format_tuple_fields(
+    fields: &ast::TupleFieldList,
+    indent: usize,
+    offset: usize,
+    suffix_width: usize,
+) -> String {
+    let field_list: Vec<_> = fields.fields().collect();
+
+    if !has_comments(fields.syntax()) {
+        let one_line = format!(
+            "({})",
+            field_list
+                .iter()
+                .map(format_tuple_field)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if field_list.is_empty()
+            || (!one_line.contains('\n') && offset + one_line.len() + suffix_width <= max_width())
+        {
+            return one_line;
+        }
+    }
+
+    let mut buf = String::from("(\n");
+    for (idx, field) in field_list.iter().enumerate() {
+        let trailing_comment = if idx + 1 < field_list.len() {
+            get_trailing_comment_for_prev(field_list[idx + 1].syntax())
+                .or_else(|| comments::get_trailing_comment_sibling(field.syntax()))
+        } else {
+            comments::get_trailing_comment_sibling(field.syntax())
+        };
+
+        for comment in detached_comments_before(field.syntax()) {
+            buf.line(indent + 4, &comment);
+        }
+        for comment in collect_inner_comments_excluding_trailing(field.syntax()) {
+            buf.line(indent + 4, &comment);
+        }
+        buf.doc_comments(field, indent + 4);
+        buf.indent(indent + 4);
+        buf.push_str(&format_tuple_field(field));
+        buf.push(',');
+        if let Some((whitespace, comment)) = trailing_comment {
+            buf.push_str(&whitespace);
+            buf.push_str(&comment);
+        }
+        buf.push('\n');
+    }
+
+    // Comments on their own lines after the last field
+    let after_last = fields.syntax().children_with_tokens().skip_while(|child| {
+        field_list
+            .last()
+            .is_some_and(|last| child.as_node() != Some(last.syntax()))
+    });
+    for child in after_last {
+        if let NodeOrToken::Token(t) = &child
+            && t.kind() == SyntaxKind::COMMENT
+            && t.prev_token().is_some_and(|prev| {
+                prev.kind() == SyntaxKind::WHITESPACE && prev.text().contains('\n')
+            })
+        {
+            buf.line(indent + 4, t.text());
+        }
+    }
+
+    buf.indent(indent);
+    buf.push(')');
+    buf
+}
@@ -12,6 +12,12 @@ pub fn format_const_or_static(node: &SyntaxNode, buf: &mut String, indent: usize
         format_name_type_body(buf, c.name(), c.ty(), c.body());
     } else if let Some(s) = ast::Static::cast(node.clone()) {
         buf.item_preamble(&s, indent);
+        // `safe` and `unsafe` only appear on statics in `extern` blocks
+        if s.unsafe_token().is_some() {
+            buf.push_str("unsafe ");
+        } else if s.safe_token().is_some() {
+            buf.push_str("safe ");
+        }
         if s.mut_token().is_some() {
             buf.push_str("static mut ");
         } else {
@@ -1,8 +1,9 @@
 use ra_ap_syntax::{
-    AstNode, AstToken, NodeOrToken, SyntaxKind, SyntaxNode,
-    ast::{self, HasAttrs, HasDocComments, HasName, HasVisibility},
+    AstNode, NodeOrToken, SyntaxKind, SyntaxNode,
+    ast::{self, HasName},
 };
 
+use crate::formatter::config::{self, STRUCT_VARIANT_WIDTH, max_width};
 use crate::formatter::node::common::{comments, fields, header};
 use crate::formatter::printer::Printer;
 
@@ -27,7 +28,7 @@ fn collect_variant_info(variants: &ast::VariantList) -> Vec<VariantInfo> {
 
     for (idx, variant) in variant_list.iter().enumerate() {
         let mut leading_comments = Vec::new();
-        let mut trailing_comment_for_prev: Option<String> = None;
+        let mut trailing_comment_for_prev: Option<(String, String)> = None;
         let mut seen_newline = false;
 
         // Check if there's a newline before this variant - if so, any comment
@@ -41,13 +42,17 @@ fn collect_variant_info(variants: &ast::VariantList) -> Vec<VariantInfo> {
                     if t.kind() == SyntaxKind::COMMENT {
                         let text = t.text().to_string();
                         // Skip doc comments (handled by HasDocComments)
-                        if !text.starts_with("///") && !text.starts_with("//!") {
+                        if !comments::is_doc_comment(&t) {
                             if !seen_newline
                                 && !newline_before_variant
                                 && trailing_comment_for_prev.is_none()
                             {
                                 // First comment before any newline AND no newline before variant - trailing for previous
-                                trailing_comment_for_prev = Some(text);
+                                let whitespace = comments::whitespace_before_token(&t);
+                                trailing_comment_for_prev = Some((
+                                    comments::trailing_comment_whitespace(&whitespace),
+                                    text,
+                                ));
                             } else {
                                 leading_comments.push(text);
                             }
@@ -67,9 +72,9 @@ fn collect_variant_info(variants: &ast::VariantList) -> Vec<VariantInfo> {
 
         // If we still have a trailing_comment_for_prev and there's a previous variant,
         // attach it to that variant
-        if let Some(comment) = trailing_comment_for_prev {
+        if let Some((whitespace, comment)) = trailing_comment_for_prev {
             if !result.is_empty() {
-                result.last_mut().unwrap().trailing_comment = Some((" ".to_string(), comment));
+                result.last_mut().unwrap().trailing_comment = Some((whitespace, comment));
             } else {
                 // No previous variant, treat as leading comment
                 leading_comments.insert(0, comment);
@@ -122,6 +127,71 @@ fn has_blank_line_before_variant(node: &SyntaxNode) -> bool {
     false
 }
 
+/// The width to pad variant names to so that their discriminants line up.
+///
+/// Only variants with a discriminant and a name no longer than `enum_discrim_align_threshold`
+/// take part, as in rustfmt; longer names are left unpadded.
+fn discriminant_alignment(variants: &ast::VariantList) -> usize {
+    let threshold = config::with(|config| config.enum_discrim_align_threshold);
+    variants
+        .variants()
+        .filter(|variant| variant.expr().is_some())
+        .filter_map(|variant| variant.name())
+        .map(|name| name.text().len())
+        .filter(|&len| len <= threshold)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Format a variant's name, fields and discriminant, starting at column `indent`.
+///
+/// Record variants go on one line when their fields fit within `STRUCT_VARIANT_WIDTH`, and
+/// tuple variants when they fit within the maximum width; otherwise fields go one per line.
+fn format_variant_body(variant: &ast::Variant, indent: usize, discrim_pad: usize) -> String {
+    let mut buf = variant
+        .name()
+        .map(|name| name.text().to_string())
+        .unwrap_or_default();
+    let discriminant = variant
+        .expr()
+        .map(|expr| format!(" = {}", expr.syntax().text()))
+        .unwrap_or_default();
+    // The discriminant and the comma follow the fields on the closing line
+    let suffix_width = discriminant.len() + 1;
+
+    match variant.field_list() {
+        Some(ast::FieldList::RecordFieldList(record_fields)) => {
+            let inline = fields::format_record_fields_inline(&record_fields, STRUCT_VARIANT_WIDTH)
+                .map(|body| format!(" {{ {} }}", body))
+                .filter(|inline| indent + buf.len() + inline.len() + suffix_width <= max_width());
+            match inline {
+                Some(inline) => buf.push_str(&inline),
+                None => {
+                    buf.open_brace();
+                    fields::format_record_fields(&record_fields, &mut buf, indent + 4);
+                    buf.close_brace(indent);
+                }
+            }
+        }
+        Some(ast::FieldList::TupleFieldList(tuple_fields)) => {
+            let offset = indent + buf.len();
+            buf.push_str(&fields::format_tuple_fields(
+                &tuple_fields,
+                indent,
+                offset,
+                suffix_width,
+            ));
+        }
+        None => {}
+    }
+
+    if !discriminant.is_empty() && !buf.contains('\n') {
+        buf = format!("{:<width$}", buf, width = discrim_pad);
+    }
+    buf.push_str(&discriminant);
+    buf
+}
+
 pub fn format_enum(node: &SyntaxNode, buf: &mut String, indent: usize) {
     let enum_ = match ast::Enum::cast(node.clone()) {
         Some(e) => e,
@@ -136,6 +206,7 @@ pub fn format_enum(node: &SyntaxNode, buf: &mut String, indent: usize) {
 
         // Pre-scan to correctly assign trailing comments
         let variant_infos = collect_variant_info(&variants);
+        let discrim_pad = discriminant_alignment(&variants);
 
         for (idx, info) in variant_infos.iter().enumerate() {
             let variant = &info.variant;
@@ -150,50 +221,11 @@ pub fn format_enum(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 buf.line(indent + 4, comment);
             }
 
-            // Variant doc comments (///)
-            for doc_comment in variant.doc_comments() {
-                buf.line(indent + 4, doc_comment.text().trim());
-            }
-
-            // Variant attributes
-            for attr in variant.attrs() {
-                buf.line(indent + 4, &attr.syntax().text().to_string());
-            }
+            buf.doc_comments(variant, indent + 4);
+            buf.attrs(variant, indent + 4);
 
             buf.indent(indent + 4);
-            if let Some(name) = variant.name() {
-                buf.push_str(&name.text());
-            }
-            if let Some(field_list) = variant.field_list() {
-                match field_list {
-                    ast::FieldList::RecordFieldList(record_fields) => {
-                        buf.open_brace();
-                        fields::format_record_fields(&record_fields, buf, indent + 8);
-                        buf.close_brace(indent + 4);
-                    }
-                    ast::FieldList::TupleFieldList(fields_tuple) => {
-                        buf.push('(');
-                        for (i, field) in fields_tuple.fields().enumerate() {
-                            if i > 0 {
-                                buf.push_str(", ");
-                            }
-                            if let Some(vis) = field.visibility() {
-                                buf.push_str(&vis.syntax().text().to_string());
-                                buf.push(' ');
-                            }
-                            if let Some(ty) = field.ty() {
-                                buf.push_str(&ty.syntax().text().to_string());
-                            }
-                        }
-                        buf.push(')');
-                    }
-                }
-            }
-
-            if let Some(expr) = variant.expr() {
-                buf.push_str(" = ");
-                buf.push_str(&expr.syntax().text().to_string());
-            }
+            buf.push_str(&format_variant_body(variant, indent + 4, discrim_pad));
 
             // Check for trailing comment on same line
             if let Some((ref whitespace, ref comment)) = info.trailing_comment {
@@ -4,9 +4,12 @@
 //! Internal helpers return `Option<String>` where `None` means "unsupported, use verbatim".
 //! The public interface uses `FormatResult` for clarity at call sites.
 
+pub mod chain;
 pub mod collections;
+pub mod comments;
 pub mod controlflow;
 pub mod jumps;
+pub mod literal;
 pub mod operators;
 pub mod simple;
 
@@ -38,11 +41,25 @@ pub fn try_format_expr(node: &SyntaxNode, indent: usize) -> FormatResult {
 }
 
 /// Inner implementation returning Option for easier chaining.
+///
+/// An expression whose formatting would lose one of its comments is left verbatim.
 pub fn try_format_expr_inner(node: &SyntaxNode, indent: usize) -> Option<String> {
+    let formatted = format_expr_kind(node, indent)?;
+    comments::keeps_comments(node, &formatted).then_some(formatted)
+}
+
+fn format_expr_kind(node: &SyntaxNode, indent: usize) -> Option<String> {
     match node.kind() {
         // === Simple / Pass-through ===
-        SyntaxKind::PATH_EXPR | SyntaxKind::LITERAL | SyntaxKind::UNDERSCORE_EXPR => {
-            Some(node.text().to_string())
+        SyntaxKind::PATH_EXPR | SyntaxKind::UNDERSCORE_EXPR => Some(node.text().to_string()),
+        SyntaxKind::LITERAL => literal::format_literal(node),
+
+        // === Method chains ===
+        SyntaxKind::METHOD_CALL_EXPR => chain::format_chain(node, indent),
+        SyntaxKind::TRY_EXPR | SyntaxKind::AWAIT_EXPR | SyntaxKind::FIELD_EXPR
+            if chain::is_method_chain(node) =>
+        {
+            chain::format_chain(node, indent)
         }
 
         // === Wrapping expressions ===
@@ -56,7 +73,6 @@ pub fn try_format_expr_inner(node: &SyntaxNode, indent: usize) -> Option<String>
         SyntaxKind::ARRAY_EXPR => collections::format_array_expr(node, indent),
         SyntaxKind::TUPLE_EXPR => collections::format_tuple_expr(node, indent),
         SyntaxKind::CALL_EXPR => collections::format_call_expr(node, indent),
-        SyntaxKind::METHOD_CALL_EXPR => collections::format_method_call_expr(node, indent),
         SyntaxKind::INDEX_EXPR => collections::format_index_expr(node, indent),
         SyntaxKind::RECORD_EXPR => collections::format_record_expr(node, indent),
 
@@ -0,0 +1,276 @@
+//! Method chain formatting.
+//!
+//! A chain such as `receiver.a().b?.c.await` is flattened into its root expression and a
+//! list of links (`.a()`, `.b?`, `.c`, `.await`), then laid out independently of how the
+//! source was written: on one line if it fits, otherwise with one link per line.
+
+use ra_ap_syntax::ast::{self, AstNode, HasArgList, HasGenericArgs};
+use ra_ap_syntax::{NodeOrToken, SyntaxKind, SyntaxNode};
+
+use crate::formatter::config::{CHAIN_WIDTH, max_width};
+use crate::formatter::printer::expr_attrs_prefix;
+use crate::formatter::write_indent;
+
+use super::collections::format_arg_list;
+use super::try_format_expr_inner;
+
+/// A root no longer than this absorbs the following link, e.g. `self.foo` or `x.iter()`.
+const ROOT_ABSORB_WIDTH: usize = 4;
+
+/// One link of a chain after the root.
+enum ChainElement {
+    /// `.field` (or `.0` for tuple fields)
+    Field(String),
+    /// `.method::<T>(args)`
+    Method {
+        name: String,
+        generic_args: String,
+        args: Vec<ast::Expr>,
+    },
+    /// `.await`
+    Await,
+}
+
+/// A chain element together with the `?` operators applied directly after it.
+struct ChainLink {
+    element: ChainElement,
+    tries: usize,
+}
+
+/// A flattened chain: the root expression, the `?`s applied to it, and the links.
+struct Chain {
+    root: ast::Expr,
+    root_tries: usize,
+    links: Vec<ChainLink>,
+}
+
+/// Whether this node is the top of a chain containing at least one method call.
+///
+/// Plain field accesses (`self.x`), `foo?` and `fut.await` are left to their own formatters.
+pub fn is_method_chain(node: &SyntaxNode) -> bool {
+    flatten(node).is_some_and(|chain| {
+        chain
+            .links
+            .iter()
+            .any(|link| matches!(link.element, ChainElement::Method { .. }))
+    })
+}
+
+/// The number of links after the root of the chain at `node`, such as 2 for `a.b().c`.
+pub fn link_count(node: &SyntaxNode) -> usize {
+    flatten(node).map_or(0, |chain| chain.links.len())
+}
+
+/// Check whether a chain node carries comments between its links.
+fn has_direct_comments(node: &SyntaxNode) -> bool {
+    node.children_with_tokens()
+        .any(|child| matches!(child, NodeOrToken::Token(t) if t.kind() == SyntaxKind::COMMENT))
+}
+
+/// Whether a chain node has attributes of its own.
+fn has_attrs(node: &SyntaxNode) -> bool {
+    node.children().any(|n| n.kind() == SyntaxKind::ATTR)
+}
+
+/// Flatten a chain, returning `None` if it has comments or attributes between its links,
+/// which we can't yet re-emit faithfully.
+fn flatten(node: &SyntaxNode) -> Option<Chain> {
+    let mut links = Vec::new();
+    let mut tries = 0;
+    let mut current = ast::Expr::cast(node.clone())?;
+
+    loop {
+        let syntax = current.syntax().clone();
+        let is_link = matches!(
+            current,
+            ast::Expr::TryExpr(_)
+                | ast::Expr::AwaitExpr(_)
+                | ast::Expr::FieldExpr(_)
+                | ast::Expr::MethodCallExpr(_)
+        );
+        if is_link && (has_direct_comments(&syntax) || (syntax != *node && has_attrs(&syntax))) {
+            return None;
+        }
+
+        current = match current {
+            ast::Expr::TryExpr(try_expr) => {
+                tries += 1;
+                try_expr.expr()?
+            }
+            ast::Expr::AwaitExpr(await_expr) => {
+                links.push(ChainLink {
+                    element: ChainElement::Await,
+                    tries: std::mem::take(&mut tries),
+                });
+                await_expr.expr()?
+            }
+            ast::Expr::FieldExpr(field) => {
+                links.push(ChainLink {
+                    element: ChainElement::Field(field.name_ref()?.text().to_string()),
+                    tries: std::mem::take(&mut tries),
+                });
+                field.expr()?
+            }
+            ast::Expr::MethodCallExpr(method) => {
+                links.push(ChainLink {
+                    element: ChainElement::Method {
+                        name: method.name_ref()?.text().to_string(),
+                        generic_args: method
+                            .generic_arg_list()
+                            .map(|g| g.syntax().text().to_string())
+                            .unwrap_or_default(),
+                        args: method.arg_list()?.args().collect(),
+                    },
+                    tries: std::mem::take(&mut tries),
+                });
+                method.receiver()?
+            }
+            root => {
+                links.reverse();
+                return Some(Chain {
+                    root,
+                    root_tries: tries,
+                    links,
+                });
+            }
+        };
+    }
+}
+
+/// Format a single link starting at column `offset`, with block indentation `indent`.
+fn format_link(link: &ChainLink, indent: usize, offset: usize) -> Option<String> {
+    let mut result = match &link.element {
+        ChainElement::Field(name) => format!(".{}", name),
+        ChainElement::Await => ".await".to_string(),
+        ChainElement::Method {
+            name,
+            generic_args,
+            args,
+        } => {
+            let head = format!(".{}{}", name, generic_args);
+            let args_str = format_arg_list(args, indent, offset + head.len())?;
+            head + &args_str
+        }
+    };
+    result.push_str(&"?".repeat(link.tries));
+    Some(result)
+}
+
+/// Whether the last line of a multi-line root is only closing delimiters, like `}` or `)`,
+/// and empty calls, like `})()`, in which case the links continue at the root's own
+/// indentation.
+fn ends_with_block(text: &str) -> bool {
+    text.contains('\n')
+        && text.lines().last().is_some_and(|line| {
+            line.trim()
+                .replace("()", "")
+                .chars()
+                .all(|c| matches!(c, ')' | ']' | '}' | '?' | '>'))
+        })
+}
+
+/// Format a method chain.
+///
+/// The chain is kept on one line when it fits within `CHAIN_WIDTH` (or the maximum width for a
+/// single link). A multi-line last call, such as one taking a closure with a block body,
+/// may hang off the end of an otherwise single-line chain. Otherwise every link goes on
+/// its own line at block indent:
+///
+/// ```text
+/// receiver
+///     .a()
+///     .b?
+///     .c
+///     .await
+/// ```
+pub fn format_chain(node: &SyntaxNode, indent: usize) -> Option<String> {
+    format_chain_at(node, indent, indent, 0)
+}
+
+/// Format a method chain that starts at column `start` rather than at the block indentation,
+/// as after `let x = `, and is followed on its last line by `suffix_width` columns, as of a
+/// statement's `;`.
+pub fn format_chain_at(
+    node: &SyntaxNode,
+    indent: usize,
+    start: usize,
+    suffix_width: usize,
+) -> Option<String> {
+    let Chain {
+        root,
+        root_tries,
+        mut links,
+    } = flatten(node)?;
+
+    let attrs = ast::AnyHasAttrs::cast(node.clone())
+        .map(|n| expr_attrs_prefix(&n))
+        .unwrap_or_default();
+
+    // A lone link may use the full width, measured before any are absorbed into the root
+    let lone_link = links.len() == 1;
+    let fits = |width: usize| start + width <= max_width() && (lone_link || width <= CHAIN_WIDTH);
+
+    let mut root_str = try_format_expr_inner(root.syntax(), indent)?;
+    root_str.push_str(&"?".repeat(root_tries));
+
+    // A short root is joined with the link that follows it, less so the further the chain
+    // starts past the indentation
+    let absorb_width = ROOT_ABSORB_WIDTH.saturating_sub(start - indent);
+    while !links.is_empty() && !root_str.contains('\n') && root_str.len() <= absorb_width {
+        let link = links.remove(0);
+        root_str.push_str(&format_link(&link, indent, start + root_str.len())?);
+    }
+
+    let Some(last) = links.pop() else {
+        return Some(format!("{}{}", attrs, root_str));
+    };
+
+    // Everything but the last link on one line
+    let mut one_line = root_str.clone();
+    let mut all_in_one_line = !root_str.contains('\n');
+    if all_in_one_line {
+        for link in &links {
+            let link_str = format_link(link, indent, start + one_line.len())?;
+            if link_str.contains('\n') {
+                all_in_one_line = false;
+                break;
+            }
+            one_line.push_str(&link_str);
+        }
+    }
+
+    if all_in_one_line && fits(one_line.len()) {
+        let last_str = format_link(&last, indent, start + one_line.len())?;
+        let first_line = last_str.lines().next().unwrap_or_default();
+        if fits(one_line.len() + first_line.len()) {
+            if !last_str.contains('\n') {
+                if start + one_line.len() + last_str.len() + suffix_width <= max_width() {
+                    return Some(format!("{}{}{}", attrs, one_line, last_str));
+                }
+            } else {
+                // The last link overflows: only hang it if stacking it wouldn't be shorter
+                let overflow_lines = last_str.lines().count();
+                let stacked = format_link(&last, indent + 4, indent + 4)?;
+                if overflow_lines >= 5 || stacked.lines().count() >= overflow_lines {
+                    return Some(format!("{}{}{}", attrs, one_line, last_str));
+                }
+            }
+        }
+    }
+
+    // One link per line
+    let child_indent = if ends_with_block(&root_str) {
+        indent
+    } else {
+        indent + 4
+    };
+
+    let mut buf = attrs;
+    buf.push_str(&root_str);
+    for link in links.iter().chain(std::iter::once(&last)) {
+        buf.push('\n');
+        write_indent(&mut buf, child_indent);
+        buf.push_str(&format_link(link, child_indent, child_indent)?);
+    }
+    Some(buf)
+}
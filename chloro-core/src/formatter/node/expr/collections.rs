@@ -1,55 +1,13 @@
-use crate::formatter::config::MAX_WIDTH;
+use crate::formatter::config::{self, STRUCT_LIT_WIDTH, max_width};
+use crate::formatter::node::common::comments;
+use crate::formatter::printer::expr_attrs_prefix;
 use crate::formatter::write_indent;
-use ra_ap_syntax::ast::{self, AstNode, HasArgList, HasGenericArgs};
+use ra_ap_syntax::ast::{self, AstNode, HasArgList};
 use ra_ap_syntax::{NodeOrToken, SyntaxKind, SyntaxNode};
 
+use super::comments::{Attached, Attachments, attach_comments};
 use super::try_format_expr_inner;
 
-/// Check if there's a newline after the opening paren in an argument list
-fn has_newline_after_open_paren(arg_list: &ast::ArgList) -> bool {
-    let mut after_lparen = false;
-    for child in arg_list.syntax().children_with_tokens() {
-        match &child {
-            NodeOrToken::Token(t) if t.kind() == SyntaxKind::L_PAREN => {
-                after_lparen = true;
-            }
-            NodeOrToken::Token(t) if after_lparen && t.kind() == SyntaxKind::WHITESPACE => {
-                return t.text().contains('\n');
-            }
-            NodeOrToken::Token(_) if after_lparen => {
-                return false;
-            }
-            NodeOrToken::Node(_) if after_lparen => {
-                return false;
-            }
-            _ => {}
-        }
-    }
-    false
-}
-
-/// Check if there's a newline between the receiver and the dot in a method call
-fn has_newline_before_dot(node: &SyntaxNode) -> bool {
-    let mut found_receiver = false;
-    for child in node.children_with_tokens() {
-        match &child {
-            NodeOrToken::Node(_) if !found_receiver => {
-                found_receiver = true;
-            }
-            NodeOrToken::Token(t) if found_receiver && t.kind() == SyntaxKind::WHITESPACE => {
-                if t.text().contains('\n') {
-                    return true;
-                }
-            }
-            NodeOrToken::Token(t) if t.kind() == SyntaxKind::DOT => {
-                return false;
-            }
-            _ => {}
-        }
-    }
-    false
-}
-
 pub fn format_array_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
     let array = ast::ArrayExpr::cast(node.clone())?;
 
@@ -90,141 +48,147 @@ pub fn format_call_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
     let args: Vec<_> = arg_list.args().collect();
 
     let callee_text = try_format_expr_inner(callee.syntax(), indent)?;
+    let offset = last_line_width(&callee_text, indent);
+    let args_text = format_arg_list(&args, indent, offset)?;
+
+    Some(format!("{}{}", callee_text, args_text))
+}
 
-    // No args - always single line
+/// Format a parenthesised argument list, including the parentheses.
+///
+/// `offset` is the column at which the opening paren will be written. The list is kept on
+/// one line if it fits, then we try overflowing a block-like last argument (a closure,
+/// struct literal, etc.) onto the following lines, and finally fall back to one argument
+/// per line at `indent + 4` with a trailing comma.
+pub(super) fn format_arg_list(args: &[ast::Expr], indent: usize, offset: usize) -> Option<String> {
     if args.is_empty() {
-        return Some(format!("{}()", callee_text));
+        return Some("()".to_string());
     }
 
-    // Try to format all args
-    let args_formatted: Option<Vec<_>> = args
+    let attachments = list_comments(args);
+    if !attachments.is_empty() {
+        return format_commented_list(args, &attachments, indent, offset, "(", ")");
+    }
+
+    let args_vec: Vec<_> = args
         .iter()
         .map(|a| try_format_expr_inner(a.syntax(), indent))
-        .collect();
-
-    let args_vec = args_formatted?;
+        .collect::<Option<_>>()?;
 
     // Try single-line
-    let single_line = format!("{}({})", callee_text, args_vec.join(", "));
-    if indent + single_line.len() <= MAX_WIDTH {
-        return Some(single_line);
+    if args_vec.iter().all(|a| !a.contains('\n')) {
+        let single_line = format!("({})", args_vec.join(", "));
+        if offset + single_line.len() <= max_width() {
+            return Some(single_line);
+        }
     }
 
-    // Single argument that's already multi-line: snug wrap
-    if args.len() == 1 && args_vec[0].contains('\n') {
-        return Some(format!("{}({})", callee_text, args_vec[0]));
+    // Overflow a block-like last argument, keeping the others on the first line
+    let (last, init) = args_vec.split_last()?;
+    if is_overflowable(args.last()?) && init.iter().all(|a| !a.contains('\n')) {
+        let mut overflowed = String::from("(");
+        for arg in init {
+            overflowed.push_str(arg);
+            overflowed.push_str(", ");
+        }
+        overflowed.push_str(last);
+        overflowed.push(')');
+        let first_line = overflowed.lines().next().unwrap_or_default();
+        if overflowed.contains('\n') && offset + first_line.len() <= max_width() {
+            return Some(overflowed);
+        }
     }
 
     // Multi-line with trailing commas
-    let mut buf = String::new();
-    buf.push_str(&callee_text);
-    buf.push_str("(\n");
-
-    for arg_str in &args_vec {
+    let mut buf = String::from("(\n");
+    for arg in args {
         write_indent(&mut buf, indent + 4);
-        buf.push_str(arg_str);
+        buf.push_str(&try_format_expr_inner(arg.syntax(), indent + 4)?);
         buf.push_str(",\n");
     }
-
     write_indent(&mut buf, indent);
     buf.push(')');
     Some(buf)
 }
 
-pub fn format_method_call_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
-    let method = ast::MethodCallExpr::cast(node.clone())?;
-
-    let receiver = method.receiver()?;
-    let is_chain = matches!(
-        receiver,
-        ast::Expr::MethodCallExpr(_) | ast::Expr::FieldExpr(_) | ast::Expr::AwaitExpr(_)
-    );
-
-    if is_chain {
-        return None;
+/// The comments between the elements of a list, attached to the elements.
+fn list_comments(elements: &[ast::Expr]) -> Attachments {
+    match elements.first().and_then(|e| e.syntax().parent()) {
+        Some(list) => attach_comments(&list),
+        None => Attachments::default(),
     }
+}
 
-    let name = method.name_ref()?;
-    let arg_list = method.arg_list()?;
-    let args: Vec<_> = arg_list.args().collect();
-
-    let receiver_str = try_format_expr_inner(receiver.syntax(), indent)?;
-
-    let generic_args = method
-        .generic_arg_list()
-        .map(|g| g.syntax().text().to_string())
-        .unwrap_or_default();
-
-    // Check if there's a newline before the dot
-    let newline_before_dot = has_newline_before_dot(node);
-
-    // Check if args were originally on separate lines
-    let multiline_args = has_newline_after_open_paren(&arg_list);
-
-    // Build the dot and method part
-    let dot_method = if newline_before_dot {
-        format!("\n{}.{}{}", " ".repeat(indent), name.text(), generic_args)
-    } else {
-        format!(".{}{}", name.text(), generic_args)
-    };
-
-    // No args
-    if args.is_empty() {
-        return Some(format!("{}{}()", receiver_str, dot_method));
-    }
-
-    // Format args
-    let args_formatted: Option<Vec<_>> = args
-        .iter()
-        .map(|a| try_format_expr_inner(a.syntax(), indent + 4))
-        .collect();
-
-    let args_vec = args_formatted?;
-
-    // If original had multiline args, preserve that
-    if multiline_args {
-        let mut buf = String::new();
-        buf.push_str(&receiver_str);
-        buf.push_str(&dot_method);
-        buf.push_str("(\n");
-
-        for arg_str in &args_vec {
-            write_indent(&mut buf, indent + 4);
-            buf.push_str(arg_str);
-            buf.push_str(",\n");
+/// Format a list whose elements have comments attached.
+///
+/// Block comments between elements stay inline when the list fits on one line. A line
+/// comment, or a comment on its own line, puts one element per line: leading comments on
+/// the lines before their element and trailing comments after its comma.
+fn format_commented_list(
+    elements: &[ast::Expr],
+    attachments: &Attachments,
+    indent: usize,
+    offset: usize,
+    open: &str,
+    close: &str,
+) -> Option<String> {
+    let empty = Attached::default();
+    let attached = |e: &ast::Expr| attachments.get(e.syntax()).unwrap_or(&empty);
+
+    if !attachments.forces_multi_line() {
+        let items: Vec<_> = elements
+            .iter()
+            .map(|e| Some(attached(e).inline(&try_format_expr_inner(e.syntax(), indent)?)))
+            .collect::<Option<_>>()?;
+        let single_line = format!("{}{}{}", open, items.join(", "), close);
+        if !single_line.contains('\n') && offset + single_line.len() <= max_width() {
+            return Some(single_line);
         }
-
-        write_indent(&mut buf, indent);
-        buf.push(')');
-        return Some(buf);
     }
 
-    // Try single-line
-    let single_line = format!("{}{}({})", receiver_str, dot_method, args_vec.join(", "));
-    if indent + single_line.len() <= MAX_WIDTH && !newline_before_dot {
-        return Some(single_line);
+    let mut buf = format!("{}\n", open);
+    for element in elements {
+        let comments = attached(element);
+        comments.write_leading_lines(&mut buf, indent + 4);
+        write_indent(&mut buf, indent + 4);
+        buf.push_str(&comments.leading_inline());
+        buf.push_str(&try_format_expr_inner(element.syntax(), indent + 4)?);
+        buf.push(',');
+        comments.write_trailing(&mut buf, indent + 4);
+        buf.push('\n');
     }
+    write_indent(&mut buf, indent);
+    buf.push_str(close);
+    Some(buf)
+}
 
-    // Single argument that's already multi-line: snug wrap
-    if args.len() == 1 && args_vec[0].contains('\n') {
-        return Some(format!("{}{}({})", receiver_str, dot_method, args_vec[0]));
+/// Whether an argument may hang off the end of a call, rustfmt's "overflow" of the last item.
+fn is_overflowable(expr: &ast::Expr) -> bool {
+    matches!(
+        expr,
+        ast::Expr::ClosureExpr(_)
+            | ast::Expr::RecordExpr(_)
+            | ast::Expr::BlockExpr(_)
+            | ast::Expr::MatchExpr(_)
+            | ast::Expr::IfExpr(_)
+            | ast::Expr::LoopExpr(_)
+            | ast::Expr::ArrayExpr(_)
+            | ast::Expr::CallExpr(_)
+            | ast::Expr::MacroExpr(_)
+    ) || match expr {
+        // `&mut |x| { ... }` overflows like the closure itself
+        ast::Expr::RefExpr(expr) => expr.expr().is_some_and(|expr| is_overflowable(&expr)),
+        ast::Expr::PrefixExpr(expr) => expr.expr().is_some_and(|expr| is_overflowable(&expr)),
+        _ => false,
     }
+}
 
-    // Multi-line args
-    let mut buf = String::new();
-    buf.push_str(&receiver_str);
-    buf.push_str(&dot_method);
-    buf.push_str("(\n");
-
-    for arg_str in &args_vec {
-        write_indent(&mut buf, indent + 4);
-        buf.push_str(arg_str);
-        buf.push_str(",\n");
+/// Width of the last line of `text`, which starts at column `indent` if single-line.
+pub(super) fn last_line_width(text: &str, indent: usize) -> usize {
+    match text.rsplit_once('\n') {
+        Some((_, last)) => last.len(),
+        None => indent + text.len(),
     }
-
-    write_indent(&mut buf, indent);
-    buf.push(')');
-    Some(buf)
 }
 
 pub fn format_index_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
@@ -238,40 +202,171 @@ pub fn format_index_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
     Some(format!("{}[{}]", base_str, idx_str))
 }
 
+/// A field of a struct literal, or its `..base` spread.
+enum RecordExprItem {
+    Field(ast::RecordExprField),
+    Spread(Option<ast::Expr>),
+}
+
+/// A struct literal item with the comments on the lines before it and after it.
+struct RecordExprEntry {
+    item: RecordExprItem,
+    leading_comments: Vec<String>,
+    trailing_comment: Option<(String, String)>, // (whitespace, comment)
+}
+
+impl RecordExprEntry {
+    fn format(&self, indent: usize) -> Option<String> {
+        match &self.item {
+            RecordExprItem::Field(field) => format_record_expr_field(field, indent),
+            RecordExprItem::Spread(Some(base)) => {
+                let base_str = try_format_expr_inner(base.syntax(), indent)
+                    .unwrap_or_else(|| base.syntax().text().to_string());
+                Some(format!("..{}", base_str))
+            }
+            RecordExprItem::Spread(None) => Some("..".to_string()),
+        }
+    }
+}
+
+/// Format `name: expr`, normalising `a: a` to `a` when `use_field_init_shorthand` is set.
+fn format_record_expr_field(field: &ast::RecordExprField, indent: usize) -> Option<String> {
+    let attrs = expr_attrs_prefix(field);
+    let expr = field.expr()?;
+    let expr_str = try_format_expr_inner(expr.syntax(), indent)
+        .unwrap_or_else(|| expr.syntax().text().to_string());
+
+    let Some(name) = field.name_ref() else {
+        // Already shorthand
+        return Some(format!("{}{}", attrs, expr_str));
+    };
+
+    let shorthand = config::with(|c| c.use_field_init_shorthand)
+        && matches!(expr, ast::Expr::PathExpr(_))
+        && expr_str == name.text();
+    if shorthand {
+        Some(format!("{}{}", attrs, expr_str))
+    } else {
+        Some(format!("{}{}: {}", attrs, name.text(), expr_str))
+    }
+}
+
+/// Collect the fields of a struct literal in order, with the spread moved last.
+///
+/// Comments on their own line lead the next item; a comment after an item's comma on the
+/// same line is that item's trailing comment. Also returns comments after the last item.
+fn collect_record_expr_entries(
+    field_list: &ast::RecordExprFieldList,
+) -> (Vec<RecordExprEntry>, Vec<String>) {
+    let children: Vec<_> = field_list.syntax().children_with_tokens().collect();
+    let mut entries: Vec<RecordExprEntry> = Vec::new();
+    let mut spread: Option<RecordExprEntry> = None;
+    let mut pending_comments: Vec<String> = Vec::new();
+
+    for (idx, child) in children.iter().enumerate() {
+        match child {
+            NodeOrToken::Node(n) => {
+                if let Some(field) = ast::RecordExprField::cast(n.clone()) {
+                    entries.push(RecordExprEntry {
+                        item: RecordExprItem::Field(field),
+                        leading_comments: std::mem::take(&mut pending_comments),
+                        trailing_comment: comments::get_trailing_comment_sibling(n),
+                    });
+                } else if let Some(entry) = spread.as_mut() {
+                    // The base expression following `..`
+                    entry.trailing_comment = comments::get_trailing_comment_sibling(n);
+                }
+            }
+            NodeOrToken::Token(t) => match t.kind() {
+                SyntaxKind::DOT2 => {
+                    spread = Some(RecordExprEntry {
+                        item: RecordExprItem::Spread(field_list.spread()),
+                        leading_comments: std::mem::take(&mut pending_comments),
+                        trailing_comment: None,
+                    });
+                }
+                SyntaxKind::COMMENT => {
+                    let has_newline_before = idx > 0
+                        && matches!(
+                            &children[idx - 1],
+                            NodeOrToken::Token(prev) if prev.kind() == SyntaxKind::WHITESPACE && prev.text().contains('\n')
+                        );
+                    let follows_item = entries.len() + usize::from(spread.is_some()) > 0;
+                    // Trailing comments were already taken from the previous item's siblings
+                    if has_newline_before || !follows_item {
+                        pending_comments.push(t.text().to_string());
+                    } else if let Some(entry) = spread.as_mut()
+                        && matches!(entry.item, RecordExprItem::Spread(None))
+                    {
+                        entry.trailing_comment = Some((" ".to_string(), t.text().to_string()));
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    entries.extend(spread);
+    (entries, pending_comments)
+}
+
 pub fn format_record_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
     let record = ast::RecordExpr::cast(node.clone())?;
-    let path = record.path()?;
+    let path = record.path()?.syntax().text().to_string();
     let field_list = record.record_expr_field_list()?;
-    let fields: Vec<_> = field_list.fields().collect();
 
-    // Single field or fewer: keep inline (return None to use verbatim)
-    if fields.len() < 2 {
-        return None;
+    let (entries, dangling_comments) = collect_record_expr_entries(&field_list);
+    if entries.is_empty() && dangling_comments.is_empty() {
+        return Some(format!("{} {{}}", path));
     }
 
-    // Check all fields are well-formed
-    for field in &fields {
-        if field.name_ref().is_none() || field.expr().is_none() {
-            return None;
+    let has_comments = !dangling_comments.is_empty()
+        || entries
+            .iter()
+            .any(|e| !e.leading_comments.is_empty() || e.trailing_comment.is_some());
+
+    // Single line if the body fits within `STRUCT_LIT_WIDTH`
+    if !has_comments {
+        let items: Vec<_> = entries
+            .iter()
+            .map(|e| e.format(indent))
+            .collect::<Option<_>>()?;
+        if items.iter().all(|item| !item.contains('\n')) {
+            let body = items.join(", ");
+            let single_line = format!("{} {{ {} }}", path, body);
+            if body.len() <= STRUCT_LIT_WIDTH && indent + single_line.len() <= max_width() {
+                return Some(single_line);
+            }
         }
     }
 
     let mut buf = String::new();
-    buf.push_str(&path.syntax().text().to_string());
+    buf.push_str(&path);
     buf.push_str(" {\n");
 
-    for field in fields {
+    for entry in &entries {
+        for comment in &entry.leading_comments {
+            write_indent(&mut buf, indent + 4);
+            buf.push_str(comment);
+            buf.push('\n');
+        }
         write_indent(&mut buf, indent + 4);
-        buf.push_str(&field.name_ref().unwrap().text());
-        buf.push_str(": ");
-
-        let field_expr = field.expr().unwrap();
-        match try_format_expr_inner(field_expr.syntax(), indent + 4) {
-            Some(s) => buf.push_str(&s),
-            None => buf.push_str(&field_expr.syntax().text().to_string()),
+        buf.push_str(&entry.format(indent + 4)?);
+        // No trailing comma after `..base`
+        if !matches!(entry.item, RecordExprItem::Spread(_)) {
+            buf.push(',');
         }
+        if let Some((whitespace, comment)) = &entry.trailing_comment {
+            buf.push_str(whitespace);
+            buf.push_str(comment);
+        }
+        buf.push('\n');
+    }
 
-        buf.push_str(",\n");
+    for comment in &dangling_comments {
+        write_indent(&mut buf, indent + 4);
+        buf.push_str(comment);
+        buf.push('\n');
     }
 
     write_indent(&mut buf, indent);
@@ -290,6 +385,11 @@ fn format_delimited_list(
         return Some(format!("{}{}", open, close));
     }
 
+    let attachments = list_comments(elements);
+    if !attachments.is_empty() {
+        return format_commented_list(elements, &attachments, indent, indent, open, close);
+    }
+
     let formatted: Option<Vec<_>> = elements
         .iter()
         .map(|e| try_format_expr_inner(e.syntax(), indent))
@@ -299,7 +399,7 @@ fn format_delimited_list(
 
     // Try single-line
     let single_line = format!("{}{}{}", open, items.join(", "), close);
-    if indent + single_line.len() <= MAX_WIDTH {
+    if indent + single_line.len() <= max_width() {
         return Some(single_line);
     }
 
@@ -0,0 +1,200 @@
+//! Comments between the parts of an expression.
+//!
+//! The parser keeps a comment such as the one in `foo(a, /* b */ b)` as a token of the
+//! enclosing node, between the child nodes. Each comment is attached here to a neighbouring
+//! child, so that a formatter that rebuilds the expression from its children can write the
+//! comments back next to the same code.
+
+use ra_ap_syntax::{NodeOrToken, SyntaxKind, SyntaxNode, SyntaxToken};
+
+use crate::formatter::write_indent;
+
+/// A comment attached to a part of an expression.
+pub struct ExprComment {
+    pub text: String,
+    /// Whether the comment started a line of its own in the input.
+    pub own_line: bool,
+    /// Whether the comment ended its line in the input.
+    pub ends_line: bool,
+}
+
+impl ExprComment {
+    /// Whether this is a `//` comment, which must be followed by a line break.
+    pub fn is_line(&self) -> bool {
+        self.text.starts_with("//")
+    }
+}
+
+/// The comments before and after one child node.
+#[derive(Default)]
+pub struct Attached {
+    pub leading: Vec<ExprComment>,
+    pub trailing: Vec<ExprComment>,
+}
+
+/// The comments among the children of a node, attached to the child nodes.
+#[derive(Default)]
+pub struct Attachments {
+    children: Vec<(SyntaxNode, Attached)>,
+    /// Comments of a node without child nodes to attach them to.
+    pub dangling: Vec<ExprComment>,
+}
+
+/// Attach each comment token among the children of `node` to a neighbouring child node.
+///
+/// A line comment after a child on the same line trails it. A block comment leads the next
+/// child if that directly follows it on the same line, and otherwise trails the previous
+/// child if it is on that child's line. Any other comment leads the next child, or trails
+/// the last child if there is no next one.
+pub fn attach_comments(node: &SyntaxNode) -> Attachments {
+    let mut children: Vec<(SyntaxNode, Attached)> = node
+        .children()
+        .map(|child| (child, Attached::default()))
+        .collect();
+    let mut dangling = Vec::new();
+
+    let mut next_child = 0;
+    for element in node.children_with_tokens() {
+        let token = match element {
+            NodeOrToken::Node(_) => {
+                next_child += 1;
+                continue;
+            }
+            NodeOrToken::Token(token) if token.kind() == SyntaxKind::COMMENT => token,
+            NodeOrToken::Token(_) => continue,
+        };
+        let comment = ExprComment {
+            text: token.text().to_string(),
+            own_line: newline_before(&token),
+            ends_line: newline_after(&token),
+        };
+        let after_prev = next_child > 0 && !comment.own_line;
+        let before_next = !comment.ends_line && next_node_follows(&token);
+
+        if children.is_empty() {
+            dangling.push(comment);
+        } else if before_next || (!after_prev && next_child < children.len()) {
+            children[next_child].1.leading.push(comment);
+        } else {
+            children[next_child.max(1) - 1].1.trailing.push(comment);
+        }
+    }
+
+    Attachments { children, dangling }
+}
+
+impl Attachments {
+    /// Whether there are no comments at all.
+    pub fn is_empty(&self) -> bool {
+        self.dangling.is_empty()
+            && self
+                .children
+                .iter()
+                .all(|(_, attached)| attached.leading.is_empty() && attached.trailing.is_empty())
+    }
+
+    /// Whether any comment is a line comment, or was on a line of its own, so that the
+    /// expression can't be written on one line.
+    pub fn forces_multi_line(&self) -> bool {
+        let all = self
+            .children
+            .iter()
+            .flat_map(|(_, attached)| attached.leading.iter().chain(&attached.trailing))
+            .chain(&self.dangling);
+        all.into_iter()
+            .any(|comment| comment.is_line() || comment.own_line)
+    }
+
+    /// The comments attached to a child node.
+    pub fn get(&self, node: &SyntaxNode) -> Option<&Attached> {
+        self.children
+            .iter()
+            .find(|(child, _)| child == node)
+            .map(|(_, attached)| attached)
+    }
+}
+
+impl Attached {
+    /// Write `text` with its comments on one line, for when none of them need a line break.
+    pub fn inline(&self, text: &str) -> String {
+        let mut buf = String::new();
+        for comment in &self.leading {
+            buf.push_str(&comment.text);
+            buf.push(' ');
+        }
+        buf.push_str(text);
+        for comment in &self.trailing {
+            buf.push(' ');
+            buf.push_str(&comment.text);
+        }
+        buf
+    }
+
+    /// Write the leading comments that go on lines of their own, at `indent`.
+    pub fn write_leading_lines(&self, buf: &mut String, indent: usize) {
+        for comment in self.leading.iter().filter(|c| c.ends_line) {
+            write_indent(buf, indent);
+            buf.push_str(&comment.text);
+            buf.push('\n');
+        }
+    }
+
+    /// The leading block comments that stay on the line of the code they precede.
+    pub fn leading_inline(&self) -> String {
+        self.leading
+            .iter()
+            .filter(|c| !c.ends_line)
+            .map(|c| format!("{} ", c.text))
+            .collect()
+    }
+
+    /// Write the trailing comments after code that ends the current line: the first on the
+    /// same line, and any that were on lines of their own below it at `indent`.
+    pub fn write_trailing(&self, buf: &mut String, indent: usize) {
+        for comment in &self.trailing {
+            if comment.own_line {
+                buf.push('\n');
+                write_indent(buf, indent);
+            } else {
+                buf.push(' ');
+            }
+            buf.push_str(&comment.text);
+        }
+    }
+}
+
+/// Whether the next element after a token, other than whitespace, is a node.
+fn next_node_follows(token: &SyntaxToken) -> bool {
+    std::iter::successors(token.next_sibling_or_token(), |e| e.next_sibling_or_token())
+        .find(|e| e.kind() != SyntaxKind::WHITESPACE)
+        .is_some_and(|e| e.as_node().is_some())
+}
+
+/// Whether a token starts its line.
+fn newline_before(token: &SyntaxToken) -> bool {
+    token
+        .prev_token()
+        .is_none_or(|prev| prev.kind() == SyntaxKind::WHITESPACE && prev.text().contains('\n'))
+}
+
+/// Whether a token ends its line.
+fn newline_after(token: &SyntaxToken) -> bool {
+    token
+        .next_token()
+        .is_none_or(|next| next.kind() == SyntaxKind::WHITESPACE && next.text().contains('\n'))
+}
+
+/// Whether `formatted` still contains every comment of `node`, in order.
+pub fn keeps_comments(node: &SyntaxNode, formatted: &str) -> bool {
+    let mut rest = formatted;
+    node.descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|token| token.kind() == SyntaxKind::COMMENT)
+        .all(|token| match rest.find(token.text()) {
+            Some(pos) => {
+                rest = &rest[pos + token.text().len()..];
+                true
+            }
+            None => false,
+        })
+}
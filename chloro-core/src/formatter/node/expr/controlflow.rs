@@ -4,7 +4,10 @@ use ra_ap_syntax::SyntaxNode;
 use ra_ap_syntax::ast::{self, AstNode, HasAttrs, HasLoopBody};
 use ra_ap_syntax::{NodeOrToken, SyntaxKind};
 
+use super::comments::{Attached, attach_comments};
 use super::try_format_expr_inner;
+use crate::formatter::node::common::attrs;
+use crate::formatter::node::format_stmt_list;
 
 /// Check if an expression is a let chain (BIN_EXPR with && containing LET_EXPR on both sides)
 fn is_let_chain(node: &SyntaxNode) -> bool {
@@ -63,7 +66,7 @@ fn format_let_chain(node: &SyntaxNode, indent: usize) -> Option<String> {
 fn format_expr_attrs(node: &impl HasAttrs) -> String {
     let mut result = String::new();
     for attr in node.attrs() {
-        result.push_str(&attr.syntax().text().to_string());
+        result.push_str(&attrs::format_attr_inline(&attr));
         result.push(' ');
     }
     result
@@ -72,118 +75,12 @@ fn format_expr_attrs(node: &impl HasAttrs) -> String {
 /// Format a block expression body, returning the contents between braces.
 fn format_block_contents(block: &ast::BlockExpr, indent: usize) -> String {
     let mut buf = String::new();
-
     if let Some(stmt_list) = block.stmt_list() {
-        let children: Vec<_> = stmt_list.syntax().children_with_tokens().collect();
-
-        // Find the last node index for tail expression handling
-        let last_node_idx = children
-            .iter()
-            .rposition(|child| matches!(child, NodeOrToken::Node(_)));
-
-        let mut prev_was_item = false;
-
-        for (idx, child) in children.iter().enumerate() {
-            match child {
-                NodeOrToken::Token(t) => {
-                    match t.kind() {
-                        SyntaxKind::COMMENT => {
-                            // Check if there's a newline before this comment
-                            let has_newline_before = idx > 0
-                                && matches!(
-                                    &children[idx - 1],
-                                    NodeOrToken::Token(prev) if prev.kind() == SyntaxKind::WHITESPACE && prev.text().contains('\n')
-                                );
-
-                            // Only output if it's a leading comment (has newline before)
-                            // Trailing comments are handled when outputting the node
-                            if has_newline_before {
-                                // Check for blank line before this comment
-                                if prev_was_item && has_blank_line_before(&children, idx) {
-                                    buf.push('\n');
-                                }
-                                write_indent(&mut buf, indent);
-                                buf.push_str(t.text());
-                                buf.push('\n');
-                            }
-                            prev_was_item = false;
-                        }
-                        SyntaxKind::L_CURLY | SyntaxKind::R_CURLY | SyntaxKind::WHITESPACE => {
-                            // Skip braces and whitespace
-                        }
-                        _ => {}
-                    }
-                }
-                NodeOrToken::Node(n) => {
-                    // Check for blank line before this node
-                    if prev_was_item && has_blank_line_before(&children, idx) {
-                        buf.push('\n');
-                    }
-
-                    let is_last = Some(idx) == last_node_idx;
-
-                    write_indent(&mut buf, indent);
-                    match try_format_expr_inner(n, indent) {
-                        Some(s) => {
-                            buf.push_str(&s);
-                            // Add semicolon for statements (not for tail expression)
-                            if !is_last && n.kind() != SyntaxKind::EXPR_STMT {
-                                // EXPR_STMT already includes semicolon in its text
-                            }
-                        }
-                        None => {
-                            buf.push_str(&n.text().to_string());
-                        }
-                    }
-
-                    // Check for trailing comment on same line
-                    if let Some((whitespace, comment)) = get_trailing_comment(&children, idx) {
-                        buf.push_str(&whitespace);
-                        buf.push_str(&comment);
-                    }
-
-                    buf.push('\n');
-                    prev_was_item = true;
-                }
-            }
-        }
+        format_stmt_list(stmt_list.syntax(), &mut buf, indent);
     }
-
     buf
 }
 
-/// Get trailing comment for node at given index in children list
-fn get_trailing_comment(
-    children: &[NodeOrToken<SyntaxNode, ra_ap_syntax::SyntaxToken>],
-    node_idx: usize,
-) -> Option<(String, String)> {
-    // Look for: WHITESPACE (no newline) followed by COMMENT
-    let mut i = node_idx + 1;
-    let mut whitespace = String::new();
-
-    while i < children.len() {
-        match &children[i] {
-            NodeOrToken::Token(t) => {
-                match t.kind() {
-                    SyntaxKind::WHITESPACE => {
-                        if t.text().contains('\n') {
-                            return None; // Newline means no trailing comment
-                        }
-                        whitespace = t.text().to_string();
-                    }
-                    SyntaxKind::COMMENT => {
-                        return Some((whitespace, t.text().to_string()));
-                    }
-                    _ => return None,
-                }
-            }
-            NodeOrToken::Node(_) => return None,
-        }
-        i += 1;
-    }
-    None
-}
-
 /// Format a block expression including braces.
 fn format_block_with_braces(block: &ast::BlockExpr, indent: usize) -> String {
     let mut buf = String::from("{\n");
@@ -223,7 +120,26 @@ pub fn format_if_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
 
     // Handle else branch
     if let Some(else_branch) = if_expr.else_branch() {
-        buf.push_str(" else ");
+        let else_node = match &else_branch {
+            ast::ElseBranch::IfExpr(else_if) => else_if.syntax().clone(),
+            ast::ElseBranch::Block(else_block) => else_block.syntax().clone(),
+        };
+        let attachments = attach_comments(node);
+        let empty = Attached::default();
+        let then_comments = attachments.get(then_branch.syntax()).unwrap_or(&empty);
+        let else_comments = attachments.get(&else_node).unwrap_or(&empty);
+
+        // A line comment after the then-block puts `else` on the next line
+        then_comments.write_trailing(&mut buf, indent);
+        if attachments.forces_multi_line() {
+            buf.push('\n');
+            else_comments.write_leading_lines(&mut buf, indent);
+            write_indent(&mut buf, indent);
+            buf.push_str("else ");
+        } else {
+            buf.push_str(" else ");
+        }
+        buf.push_str(&else_comments.leading_inline());
         match else_branch {
             ast::ElseBranch::IfExpr(else_if) => {
                 // Recursive: else if ...
@@ -309,7 +225,7 @@ pub fn format_match_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
 
                     // Arm attributes
                     for attr in arm.attrs() {
-                        buf.push_str(&attr.syntax().text().to_string());
+                        buf.push_str(&attrs::format_attr_inline(&attr));
                         buf.push(' ');
                     }
 
@@ -515,7 +431,7 @@ pub fn format_block_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
     Some(buf)
 }
 
-pub fn format_closure_expr(node: &SyntaxNode, _indent: usize) -> Option<String> {
+pub fn format_closure_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
     let closure = ast::ClosureExpr::cast(node.clone())?;
     let attrs = format_expr_attrs(&closure);
 
@@ -564,12 +480,21 @@ pub fn format_closure_expr(node: &SyntaxNode, _indent: usize) -> Option<String>
     // 1. Simple expression: |x| x + 1
     // 2. Block expression: |x| { ... }
     //
-    // For now, preserve the body verbatim to avoid breaking method chains.
-    // The issue is that when we format a closure's block body, it interacts
-    // badly with the parent call expression's formatting.
+    // Simple and single-line bodies are preserved verbatim. A multi-line block body is
+    // re-indented relative to the line the closure starts on, so that it can hang off the
+    // end of a call or method chain (`.map(|x| {`).
     if let Some(body) = closure.body() {
         buf.push(' ');
-        buf.push_str(&body.syntax().text().to_string());
+        let body_text = body.syntax().text().to_string();
+        match &body {
+            ast::Expr::BlockExpr(block) if body_text.contains('\n') => {
+                match try_format_expr_inner(block.syntax(), indent) {
+                    Some(s) => buf.push_str(&s),
+                    None => buf.push_str(&body_text),
+                }
+            }
+            _ => buf.push_str(&body_text),
+        }
     }
 
     Some(buf)
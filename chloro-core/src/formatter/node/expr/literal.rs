@@ -0,0 +1,333 @@
+use ra_ap_syntax::ast::{self, AstNode};
+use ra_ap_syntax::{NodeOrToken, SourceFile, SyntaxKind, SyntaxNode, SyntaxToken};
+
+use crate::formatter::config::{self, Config, FloatLiteralTrailingZero, HexLiteralCase, max_width};
+use crate::formatter::text_width;
+
+/// Literals with fewer digits than this get separators only if they already have some, in
+/// line with clippy's `unreadable_literal`.
+const MIN_GROUPED_DIGITS: usize = 6;
+
+/// Macros that read their string arguments as written, whose strings are never split.
+const VERBATIM_STRING_MACROS: &[&str] = &["concat", "include", "include_bytes", "include_str"];
+
+/// Format a literal expression, normalising number literals as configured.
+///
+/// String, byte string and character literals are written as they are, with long strings
+/// left to [`rewrite_literals`].
+pub fn format_literal(node: &SyntaxNode) -> Option<String> {
+    let literal = ast::Literal::cast(node.clone())?;
+    let token = literal.token();
+    Some(normalize_number(&token).unwrap_or_else(|| token.text().to_string()))
+}
+
+/// Normalise the number literals of formatted source, and split or join its long strings.
+///
+/// Statements that are written verbatim still get their literals normalised this way.
+pub fn rewrite_literals(source: &str) -> String {
+    let (numbers, strings) = config::with(|config| {
+        let numbers = config.hex_literal_case != HexLiteralCase::Preserve
+            || config.float_literal_trailing_zero != FloatLiteralTrailingZero::Preserve
+            || config.decimal_literal_group_size > 0
+            || config.hex_literal_group_size > 0;
+        (numbers, config.format_strings)
+    });
+    if !numbers && !strings {
+        return source.to_string();
+    }
+
+    let parse = SourceFile::parse(source, config::edition());
+    let root = parse.syntax_node();
+    let mut edits = Vec::new();
+    if numbers {
+        edits.extend(
+            root.descendants()
+                .filter_map(ast::Literal::cast)
+                .filter_map(|literal| {
+                    let token = literal.token();
+                    let text = normalize_number(&token)?;
+                    (text != token.text()).then(|| (token.text_range(), text))
+                }),
+        );
+    }
+    if strings {
+        edits.extend(
+            root.descendants_with_tokens()
+                .filter_map(NodeOrToken::into_token)
+                .filter_map(|token| {
+                    let text = format_string(source, &token)?;
+                    (text != token.text()).then(|| (token.text_range(), text))
+                }),
+        );
+    }
+    edits.sort_by_key(|(range, _)| range.start());
+
+    let mut output = source.to_string();
+    for (range, text) in edits.into_iter().rev() {
+        output.replace_range(std::ops::Range::<usize>::from(range), &text);
+    }
+    output
+}
+
+/// The normalised text of an integer or float literal token, or `None` for other literals.
+fn normalize_number(token: &SyntaxToken) -> Option<String> {
+    config::with(|config| match token.kind() {
+        SyntaxKind::INT_NUMBER => Some(normalize_int(token.text(), config)),
+        SyntaxKind::FLOAT_NUMBER => {
+            // `1.0.max(x)` can't lose its zero, as `1..max(x)` is a range
+            let before_dot = token.next_token().is_some_and(|next| {
+                matches!(
+                    next.kind(),
+                    SyntaxKind::DOT | SyntaxKind::DOT2 | SyntaxKind::DOT2EQ | SyntaxKind::DOT3
+                )
+            });
+            Some(normalize_float(token.text(), config, before_dot))
+        }
+        _ => None,
+    })
+}
+
+fn normalize_int(text: &str, config: &Config) -> String {
+    if let Some(body) = text.strip_prefix("0x") {
+        // Hex digits include `e` and `f`, so the suffix is found by its `u` or `i`
+        let suffix_start = body.find(['u', 'i']).unwrap_or(body.len());
+        let (digits, suffix) = body.split_at(suffix_start);
+        let digits = group_digits(digits, config.hex_literal_group_size);
+        let digits = match config.hex_literal_case {
+            HexLiteralCase::Preserve => digits,
+            HexLiteralCase::Upper => digits.to_ascii_uppercase(),
+            HexLiteralCase::Lower => digits.to_ascii_lowercase(),
+        };
+        return format!("0x{}{}", digits, suffix);
+    }
+    if text.starts_with("0o") || text.starts_with("0b") {
+        return text.to_string();
+    }
+    let (digits, suffix) = split_digits(text);
+    format!(
+        "{}{}",
+        group_digits(digits, config.decimal_literal_group_size),
+        suffix
+    )
+}
+
+fn normalize_float(text: &str, config: &Config, before_dot: bool) -> String {
+    let (int, rest) = split_digits(text);
+    let (fraction, rest) = match rest.strip_prefix('.') {
+        Some(rest) => {
+            let (fraction, rest) = split_digits(rest);
+            (Some(fraction), rest)
+        }
+        None => (None, rest),
+    };
+    let exponent_len = match rest.strip_prefix(['e', 'E']) {
+        Some(exponent) => {
+            let sign = usize::from(exponent.starts_with(['+', '-']));
+            1 + sign + split_digits(&exponent[sign..]).0.len()
+        }
+        None => 0,
+    };
+    let (exponent, suffix) = rest.split_at(exponent_len);
+
+    let has_postfix = !exponent.is_empty() || !suffix.is_empty();
+    let zero_fraction = fraction.is_some_and(|fraction| {
+        !fraction.is_empty() && fraction.chars().all(|c| matches!(c, '0' | '_'))
+    });
+    let fraction = match config.float_literal_trailing_zero {
+        FloatLiteralTrailingZero::Preserve => fraction,
+        FloatLiteralTrailingZero::Always => Some(fraction.filter(|f| !f.is_empty()).unwrap_or("0")),
+        FloatLiteralTrailingZero::IfNoPostfix if !has_postfix => {
+            Some(fraction.filter(|f| !f.is_empty()).unwrap_or("0"))
+        }
+        FloatLiteralTrailingZero::IfNoPostfix | FloatLiteralTrailingZero::Never => {
+            match fraction {
+                // `1.e5` isn't a literal, so the point goes too
+                Some(_) if zero_fraction && has_postfix => None,
+                Some(_) if zero_fraction && !before_dot => Some(""),
+                fraction => fraction,
+            }
+        }
+    };
+
+    let mut buf = group_digits(int, config.decimal_literal_group_size);
+    if let Some(fraction) = fraction {
+        buf.push('.');
+        buf.push_str(fraction);
+    }
+    buf.push_str(exponent);
+    buf.push_str(suffix);
+    buf
+}
+
+/// Split the leading decimal digits and separators off a literal.
+fn split_digits(text: &str) -> (&str, &str) {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit() && c != '_')
+        .unwrap_or(text.len());
+    text.split_at(end)
+}
+
+/// Group digits with `_` separators, counting from the right.
+///
+/// Digits that already have separators are regrouped if they are in the wrong places, and
+/// ones without are grouped if there are at least `MIN_GROUPED_DIGITS` of them. Separators
+/// before and after the digits, as in `0x_ff` or `1000_u32`, are kept.
+fn group_digits(digits: &str, size: usize) -> String {
+    let core = digits.trim_matches('_');
+    let plain: String = core.chars().filter(|&c| c != '_').collect();
+    if size == 0 || (!core.contains('_') && plain.len() < MIN_GROUPED_DIGITS) {
+        return digits.to_string();
+    }
+
+    let mut grouped = String::with_capacity(plain.len() + plain.len() / size);
+    for (i, c) in plain.chars().enumerate() {
+        if i > 0 && (plain.len() - i).is_multiple_of(size) {
+            grouped.push('_');
+        }
+        grouped.push(c);
+    }
+    let leading = &digits[..digits.len() - digits.trim_start_matches('_').len()];
+    let trailing = &digits[digits.trim_end_matches('_').len()..];
+    format!("{}{}{}", leading, grouped, trailing)
+}
+
+/// Split a plain string literal that runs past the maximum width across lines, or join one
+/// that was split with `\` continuations if it fits on one line.
+///
+/// Returns `None` for other tokens, for strings that are fine as they are, and for strings
+/// that may not be split: raw and byte strings, strings in attributes, strings with line
+/// breaks of their own, and arguments of `VERBATIM_STRING_MACROS`.
+fn format_string(source: &str, token: &SyntaxToken) -> Option<String> {
+    let text = token.text();
+    if token.kind() != SyntaxKind::STRING || !text.starts_with('"') || !text.ends_with('"') {
+        return None;
+    }
+    if token
+        .parent_ancestors()
+        .any(|node| node.kind() == SyntaxKind::ATTR)
+        || in_verbatim_string_macro(token)
+    {
+        return None;
+    }
+
+    let start: usize = token.text_range().start().into();
+    let end: usize = token.text_range().end().into();
+    let column = text_width(&source[source[..start].rfind('\n').map_or(0, |i| i + 1)..start]);
+    let rest = &source[end..source[end..].find('\n').map_or(source.len(), |i| end + i)];
+    // Another string later on the line would be moved by this one
+    if rest.contains('"') {
+        return None;
+    }
+
+    let rest_width = text_width(rest);
+    let content = join_continuations(&text[1..text.len() - 1])?;
+    if column + text_width(&content) + 2 + rest_width <= max_width() {
+        return Some(format!("\"{}\"", content));
+    }
+    if fits_lines(text, column, rest_width) {
+        return None;
+    }
+    split_string(&content, column, rest_width)
+}
+
+/// Remove the `\` line continuations of string contents, and the whitespace they skip.
+///
+/// Returns `None` if the contents have a line break that isn't escaped.
+fn join_continuations(content: &str) -> Option<String> {
+    let mut joined = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if matches!(chars.peek(), Some('\n' | '\r')) => {
+                while chars
+                    .next_if(|c| matches!(c, ' ' | '\t' | '\n' | '\r'))
+                    .is_some()
+                {}
+            }
+            '\\' => {
+                joined.push(c);
+                joined.extend(chars.next());
+            }
+            '\n' | '\r' => return None,
+            c => joined.push(c),
+        }
+    }
+    Some(joined)
+}
+
+/// Whether every line of a string literal starting at `column` fits in the maximum width,
+/// with `rest_width` more characters after its closing quote.
+fn fits_lines(text: &str, column: usize, rest_width: usize) -> bool {
+    let lines: Vec<_> = text.lines().collect();
+    lines.iter().enumerate().all(|(i, line)| {
+        let start = if i == 0 { column } else { 0 };
+        let end = if i == lines.len() - 1 { rest_width } else { 0 };
+        start + text_width(line) + end <= max_width()
+    })
+}
+
+/// Split string contents after runs of spaces into lines that fit, each but the last ending
+/// in a `\` continuation. The continuation lines line up with the first character after the
+/// opening quote, and start with a non-space so the continuation keeps the value the same.
+///
+/// Returns `None` if there is nowhere to split.
+fn split_string(content: &str, column: usize, rest_width: usize) -> Option<String> {
+    let mut words = Vec::new();
+    let mut word_start = 0;
+    for (i, c) in content.char_indices().skip(1) {
+        if c != ' ' && content[..i].ends_with(' ') {
+            words.push(&content[word_start..i]);
+            word_start = i;
+        }
+    }
+    words.push(&content[word_start..]);
+    if words.len() < 2 {
+        return None;
+    }
+
+    let mut lines = vec![String::new()];
+    for (i, word) in words.iter().enumerate() {
+        // A `\` after the word, or the closing quote and the rest of the line
+        let after = if i == words.len() - 1 {
+            1 + rest_width
+        } else {
+            1
+        };
+        let line = lines.last_mut()?;
+        if !line.is_empty()
+            && column + 1 + text_width(line) + text_width(word) + after > max_width()
+        {
+            lines.push(String::new());
+        }
+        lines.last_mut()?.push_str(word);
+    }
+
+    let separator = format!("\\\n{}", " ".repeat(column + 1));
+    Some(format!("\"{}\"", lines.join(&separator)))
+}
+
+/// Whether a token is in the arguments of one of the `VERBATIM_STRING_MACROS`.
+fn in_verbatim_string_macro(token: &SyntaxToken) -> bool {
+    token
+        .parent_ancestors()
+        .take_while(|node| node.kind() == SyntaxKind::TOKEN_TREE)
+        .filter_map(|tree| macro_name(&tree))
+        .any(|name| VERBATIM_STRING_MACROS.contains(&name.as_str()))
+}
+
+/// The name of the macro whose arguments are a token tree, as in `name!(...)`.
+///
+/// Macro calls nested in the arguments of another are plain tokens, so they are found by the
+/// `name` and `!` tokens before the tree.
+fn macro_name(tree: &SyntaxNode) -> Option<String> {
+    if let Some(call) = tree.parent().and_then(ast::MacroCall::cast) {
+        return Some(call.path()?.segment()?.name_ref()?.text().to_string());
+    }
+    let mut before =
+        std::iter::successors(tree.prev_sibling_or_token(), |e| e.prev_sibling_or_token())
+            .filter(|e| !e.kind().is_trivia());
+    let bang = before.next()?;
+    let name = before.next()?.into_token()?;
+    (bang.kind() == SyntaxKind::BANG && name.kind() == SyntaxKind::IDENT)
+        .then(|| name.text().to_string())
+}
@@ -1,19 +1,45 @@
 use crate::formatter::printer::expr_attrs_prefix;
+use crate::formatter::write_indent;
 use ra_ap_syntax::SyntaxNode;
 use ra_ap_syntax::ast::{self, AstNode, RangeItem};
 
+use super::comments::{Attached, attach_comments};
 use super::try_format_expr_inner;
 
 pub fn format_bin_expr(node: &SyntaxNode, indent: usize) -> Option<String> {
     let bin = ast::BinExpr::cast(node.clone())?;
-    let lhs = try_format_expr_inner(bin.lhs()?.syntax(), indent)?;
-    let rhs = try_format_expr_inner(bin.rhs()?.syntax(), indent)?;
+    let (lhs_node, rhs_node) = (bin.lhs()?, bin.rhs()?);
+    let lhs = try_format_expr_inner(lhs_node.syntax(), indent)?;
+    let rhs = try_format_expr_inner(rhs_node.syntax(), indent)?;
+    let op = bin.op_token()?;
+
+    let attachments = attach_comments(node);
+    let empty = Attached::default();
+    let lhs_comments = attachments.get(lhs_node.syntax()).unwrap_or(&empty);
+    let rhs_comments = attachments.get(rhs_node.syntax()).unwrap_or(&empty);
+
+    // A line comment between the operands puts the operator and rhs on the next line
+    if attachments.forces_multi_line() {
+        let mut buf = format!("{}{}", expr_attrs_prefix(&bin), lhs);
+        lhs_comments.write_trailing(&mut buf, indent + 4);
+        buf.push('\n');
+        rhs_comments.write_leading_lines(&mut buf, indent + 4);
+        write_indent(&mut buf, indent + 4);
+        buf.push_str(&format!(
+            "{} {}{}",
+            op.text(),
+            rhs_comments.leading_inline(),
+            rhs
+        ));
+        return Some(buf);
+    }
+
     Some(format!(
         "{}{} {} {}",
         expr_attrs_prefix(&bin),
-        lhs,
-        bin.op_token()?.text(),
-        rhs
+        lhs_comments.inline(&lhs),
+        op.text(),
+        rhs_comments.inline(&rhs)
     ))
 }
 
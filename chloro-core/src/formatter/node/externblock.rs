@@ -1,12 +1,29 @@
-use ra_ap_syntax::{AstNode, SyntaxNode, ast};
+use ra_ap_syntax::{
+    AstNode, NodeOrToken, SyntaxKind, SyntaxNode,
+    ast::{self, HasAttrs},
+};
 
+use crate::formatter::node::common::{attrs, comments};
 use crate::formatter::printer::Printer;
 
+use super::format_node;
+
+/// Item kinds allowed in an `extern` block.
+const EXTERN_ITEM_KINDS: &[SyntaxKind] = &[
+    SyntaxKind::FN,
+    SyntaxKind::STATIC,
+    SyntaxKind::TYPE_ALIAS,
+    SyntaxKind::MACRO_CALL,
+];
+
 pub fn format_extern_block(node: &SyntaxNode, buf: &mut String, indent: usize) {
     let Some(extern_block) = ast::ExternBlock::cast(node.clone()) else {
         return;
     };
 
+    // Extern blocks have no visibility, so this is `item_preamble` without it
+    buf.doc_comments(&extern_block, indent);
+    buf.attrs(&extern_block, indent);
     buf.indent(indent);
 
     if extern_block.unsafe_token().is_some() {
@@ -19,18 +36,74 @@ pub fn format_extern_block(node: &SyntaxNode, buf: &mut String, indent: usize) {
         buf.push_str("extern");
     }
 
-    if let Some(item_list) = extern_block.extern_item_list() {
-        let items: Vec<_> = item_list.extern_items().collect();
-        if items.is_empty() {
-            buf.newline(" {}");
-        } else {
-            buf.open_brace();
-            for item in items {
-                buf.line(indent + 4, item.syntax().text().to_string().trim());
+    let Some(item_list) = extern_block.extern_item_list() else {
+        buf.newline(" {}");
+        return;
+    };
+
+    let children: Vec<_> = item_list.syntax().children_with_tokens().collect();
+    let has_content = children.iter().any(|child| match child {
+        NodeOrToken::Node(_) => true,
+        NodeOrToken::Token(t) => t.kind() == SyntaxKind::COMMENT,
+    });
+    if !has_content {
+        buf.newline(" {}");
+        return;
+    }
+
+    buf.open_brace();
+
+    // Inner attributes such as `#![allow(...)]` come first
+    for attr in item_list.attrs() {
+        buf.line(indent + 4, &attrs::format_attr(&attr, indent + 4));
+    }
+
+    let mut first = true;
+    let mut blank_line_before = false;
+    let mut after_item = false;
+    for child in &children {
+        match child {
+            NodeOrToken::Node(n) if EXTERN_ITEM_KINDS.contains(&n.kind()) => {
+                // Keep the blank lines between declarations as written
+                if !first && blank_line_before {
+                    buf.blank();
+                }
+                let mut item = String::new();
+                format_node(n, &mut item, indent + 4);
+                let item = comments::reattach_trailing_comment(buf, n, item);
+                buf.push_str(&item);
+                first = false;
+                blank_line_before = false;
+                after_item = true;
             }
-            buf.close_brace_ln(indent);
+            NodeOrToken::Node(_) => {}
+            NodeOrToken::Token(t) => match t.kind() {
+                SyntaxKind::WHITESPACE => {
+                    blank_line_before |= t.text().matches('\n').count() >= 2;
+                    after_item &= !t.text().contains('\n');
+                }
+                SyntaxKind::COMMENT if after_item => {
+                    // A comment on the same line as the previous declaration
+                    let whitespace = t
+                        .prev_token()
+                        .filter(|prev| prev.kind() == SyntaxKind::WHITESPACE)
+                        .map_or_else(|| " ".to_string(), |prev| prev.text().to_string());
+                    buf.pop();
+                    buf.push_str(&whitespace);
+                    buf.newline(t.text());
+                }
+                SyntaxKind::COMMENT => {
+                    if !first && blank_line_before {
+                        buf.blank();
+                    }
+                    buf.line(indent + 4, t.text());
+                    first = false;
+                    blank_line_before = false;
+                }
+                _ => {}
+            },
         }
-    } else {
-        buf.newline(" {}");
     }
+
+    buf.close_brace_ln(indent);
 }
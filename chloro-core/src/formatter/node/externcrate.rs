@@ -15,11 +15,11 @@ pub fn format_extern_crate(node: &SyntaxNode, buf: &mut String, indent: usize) {
         buf.push_str(&name_ref.text());
     }
 
-    if let Some(rename) = extern_crate.rename() {
-        if let Some(name) = rename.name() {
-            buf.push_str(" as ");
-            buf.push_str(&name.text());
-        }
+    if let Some(rename) = extern_crate.rename()
+        && let Some(name) = rename.name()
+    {
+        buf.push_str(" as ");
+        buf.push_str(&name.text());
     }
 
     buf.push_str(";\n");
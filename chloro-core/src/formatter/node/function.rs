@@ -1,3 +1,4 @@
+use crate::formatter::node::common::comments;
 use crate::formatter::printer::Printer;
 use ra_ap_syntax::{
     AstNode, NodeOrToken, SyntaxKind, SyntaxNode,
@@ -6,6 +7,28 @@ use ra_ap_syntax::{
 
 use super::{format_block_expr_contents, format_stmt_list};
 
+/// The qualifiers before `fn`, each followed by a space: `const async unsafe extern "C" `.
+fn fn_qualifiers(func: &ast::Fn) -> String {
+    let mut qualifiers = String::new();
+    let keywords = [
+        func.default_token(),
+        func.const_token(),
+        func.async_token(),
+        func.gen_token(),
+        func.unsafe_token(),
+        func.safe_token(),
+    ];
+    for token in keywords.into_iter().flatten() {
+        qualifiers.push_str(token.text());
+        qualifiers.push(' ');
+    }
+    if let Some(abi) = func.abi() {
+        qualifiers.push_str(&abi.syntax().text().to_string());
+        qualifiers.push(' ');
+    }
+    qualifiers
+}
+
 pub fn format_function(node: &SyntaxNode, buf: &mut String, indent: usize) {
     let func = match ast::Fn::cast(node.clone()) {
         Some(f) => f,
@@ -20,7 +43,7 @@ pub fn format_function(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 if t.kind() == SyntaxKind::COMMENT {
                     let text = t.text();
                     // Skip doc comments - they're handled by doc_comments() below
-                    if !text.starts_with("///") && !text.starts_with("//!") {
+                    if !comments::is_doc_comment(&t) {
                         buf.line(indent, text);
                     }
                 } else if t.kind() != SyntaxKind::WHITESPACE {
@@ -47,15 +70,8 @@ pub fn format_function(node: &SyntaxNode, buf: &mut String, indent: usize) {
     buf.visibility(&func);
 
     // Modifiers
-    if func.const_token().is_some() {
-        buf.push_str("const ");
-    }
-    if func.async_token().is_some() {
-        buf.push_str("async ");
-    }
-    if func.unsafe_token().is_some() {
-        buf.push_str("unsafe ");
-    }
+    let qualifiers = fn_qualifiers(&func);
+    buf.push_str(&qualifiers);
 
     buf.push_str("fn ");
 
@@ -110,15 +126,7 @@ pub fn format_function(node: &SyntaxNode, buf: &mut String, indent: usize) {
             hypothetical_line_len += u32::from(vis.syntax().text().len()) as usize + 1;
         }
 
-        if func.const_token().is_some() {
-            hypothetical_line_len += 6;
-        }
-        if func.async_token().is_some() {
-            hypothetical_line_len += 6;
-        }
-        if func.unsafe_token().is_some() {
-            hypothetical_line_len += 7;
-        }
+        hypothetical_line_len += qualifiers.len();
 
         hypothetical_line_len += 3; // "fn "
 
@@ -141,7 +149,7 @@ pub fn format_function(node: &SyntaxNode, buf: &mut String, indent: usize) {
 
         hypothetical_line_len += 2;
 
-        let is_single_line = hypothetical_line_len < crate::formatter::config::MAX_WIDTH;
+        let is_single_line = hypothetical_line_len < crate::formatter::config::max_width();
 
         buf.push('(');
 
@@ -1,11 +1,14 @@
+use std::collections::HashSet;
+
+use crate::formatter::config;
 use crate::formatter::node::common::comments;
 use crate::formatter::printer::Printer;
 use ra_ap_syntax::{
-    AstNode, NodeOrToken, SyntaxKind, SyntaxNode,
+    AstNode, AstToken, NodeOrToken, SyntaxElement, SyntaxKind, SyntaxNode,
     ast::{self, HasGenericParams},
 };
 
-use super::format_node;
+use super::{ItemWithComments, format_node};
 
 pub fn format_impl(node: &SyntaxNode, buf: &mut String, indent: usize) {
     let impl_ = match ast::Impl::cast(node.clone()) {
@@ -59,9 +62,6 @@ pub fn format_impl(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 buf.open_brace();
             }
 
-            let mut first_item = true;
-            let children: Vec<_> = assoc_items.syntax().children_with_tokens().collect();
-
             const IMPL_ITEM_KINDS: &[SyntaxKind] = &[
                 SyntaxKind::FN,
                 SyntaxKind::TYPE_ALIAS,
@@ -69,45 +69,9 @@ pub fn format_impl(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 SyntaxKind::ASSOC_ITEM_LIST,
             ];
 
-            for (idx, child) in children.iter().enumerate() {
-                match child {
-                    NodeOrToken::Node(n) => {
-                        let is_item = IMPL_ITEM_KINDS.contains(&n.kind());
-
-                        if is_item {
-                            if !first_item {
-                                buf.blank();
-                            }
-                            first_item = false;
-
-                            let comments_before =
-                                comments::collect_preceding_comments_in_list(&children, idx);
-                            for comment in comments_before {
-                                buf.line(indent + 4, &comment);
-                            }
-
-                            format_node(n, buf, indent + 4);
-                        } else {
-                            format_node(n, buf, indent + 4);
-                        }
-                    }
-                    NodeOrToken::Token(t) => {
-                        if t.kind() == SyntaxKind::COMMENT {
-                            let is_attached = comments::is_comment_attached_to_next_item(
-                                &children,
-                                idx,
-                                IMPL_ITEM_KINDS,
-                            );
-                            if !is_attached {
-                                if comments::should_have_blank_line_before_comment(&children, idx) {
-                                    buf.blank();
-                                }
-                                buf.line(indent + 4, t.text());
-                            }
-                        }
-                    }
-                }
-            }
+            let children: Vec<_> = assoc_items.syntax().children_with_tokens().collect();
+            let reorder = config::with(|c| c.reorder_impl_items);
+            format_assoc_items(&children, buf, indent, IMPL_ITEM_KINDS, reorder);
 
             buf.close_brace_ln(indent);
         }
@@ -115,3 +79,113 @@ pub fn format_impl(node: &SyntaxNode, buf: &mut String, indent: usize) {
         buf.newline(" {}");
     }
 }
+
+/// Format the items of an impl or trait body, from the `children` of its item list.
+///
+/// Each item is kept together with its leading comments and the comment on its own line, so
+/// that they move with it when `reorder` puts the items in `reorder_impl_items` order.
+pub(super) fn format_assoc_items(
+    children: &[SyntaxElement],
+    buf: &mut String,
+    indent: usize,
+    item_kinds: &[SyntaxKind],
+    reorder: bool,
+) {
+    let mut items: Vec<ItemWithComments> = Vec::new();
+    let mut pending_comments = Vec::new();
+    for (idx, child) in children.iter().enumerate() {
+        match child {
+            NodeOrToken::Node(n) => items.push(ItemWithComments::new(
+                std::mem::take(&mut pending_comments),
+                NodeOrToken::Node(n.clone()),
+                false,
+            )),
+            NodeOrToken::Token(t) => {
+                if t.kind() != SyntaxKind::COMMENT
+                    || ItemWithComments::owns_comment(items.last(), t)
+                {
+                    continue;
+                }
+                if comments::is_comment_attached_to_next_item(children, idx, item_kinds) {
+                    // Doc comments are written by the item itself
+                    if !comments::is_doc_comment(t) {
+                        pending_comments.extend(ast::Comment::cast(t.clone()));
+                    }
+                } else {
+                    items.push(ItemWithComments::new(
+                        vec![],
+                        NodeOrToken::Token(t.clone()),
+                        comments::should_have_blank_line_before_comment(children, idx),
+                    ));
+                }
+            }
+        }
+    }
+    if reorder {
+        reorder_impl_items(&mut items, item_kinds);
+    }
+
+    let mut moved_comments: HashSet<_> = items
+        .iter()
+        .filter_map(|item| item.trailing_comment.clone())
+        .collect();
+    // A comment after the opening brace stays there wherever the first item goes
+    if let Some(comment) = children
+        .iter()
+        .find_map(|child| child.as_node())
+        .and_then(comments::comment_trailing_prev)
+    {
+        comments::push_trailing_comment(buf, &comment);
+        moved_comments.insert(comment);
+    }
+
+    let mut first_item = true;
+    for item in &items {
+        match &item.node {
+            NodeOrToken::Node(n) => {
+                if item_kinds.contains(&n.kind()) {
+                    if !first_item {
+                        buf.blank();
+                    }
+                    first_item = false;
+                    for comment in &item.comments {
+                        buf.line(indent + 4, comment.text());
+                    }
+                }
+                let mut formatted = String::new();
+                format_node(n, &mut formatted, indent + 4);
+                let formatted =
+                    comments::take_comment_trailing_prev(buf, n, formatted, &moved_comments);
+                buf.push_str(&formatted);
+                if let Some(comment) = &item.trailing_comment {
+                    comments::push_trailing_comment(buf, comment);
+                }
+            }
+            NodeOrToken::Token(t) => {
+                if item.blank_line_before {
+                    buf.blank();
+                }
+                buf.line(indent + 4, t.text());
+            }
+        }
+    }
+}
+
+/// Reorder impl items so that associated types come first, then consts, then functions,
+/// keeping the source order among items of the same kind.
+///
+/// Items don't move past macro calls or comments of their own.
+fn reorder_impl_items(items: &mut [ItemWithComments], item_kinds: &[SyntaxKind]) {
+    let rank = |item: &ItemWithComments| match item.syntax().map(SyntaxNode::kind) {
+        Some(SyntaxKind::TYPE_ALIAS) => 0,
+        Some(SyntaxKind::CONST) => 1,
+        _ => 2,
+    };
+    let is_barrier = |item: &ItemWithComments| {
+        item.syntax()
+            .is_none_or(|n| !item_kinds.contains(&n.kind()))
+    };
+    for segment in items.split_mut(is_barrier) {
+        segment.sort_by_key(rank);
+    }
+}
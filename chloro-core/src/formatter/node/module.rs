@@ -1,7 +1,9 @@
+use crate::formatter::doc_code::{DocOrAttr, format_docs_and_attrs};
+use crate::formatter::node::common::comments;
 use crate::formatter::printer::Printer;
 use ra_ap_syntax::{
-    AstNode, NodeOrToken, SyntaxKind, SyntaxNode,
-    ast::{self, HasName},
+    AstNode, AstToken, NodeOrToken, SyntaxKind, SyntaxNode,
+    ast::{self, Comment, HasName},
 };
 
 use super::format_node;
@@ -18,7 +20,7 @@ pub fn format_module(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 if t.kind() == SyntaxKind::COMMENT {
                     let text = t.text();
                     // Skip doc comments - they're handled by item_preamble via HasDocComments
-                    if !text.starts_with("///") && !text.starts_with("//!") {
+                    if !comments::is_doc_comment(t) {
                         buf.line(indent, text);
                     }
                 } else if t.kind() != SyntaxKind::WHITESPACE {
@@ -41,15 +43,35 @@ pub fn format_module(node: &SyntaxNode, buf: &mut String, indent: usize) {
 
     if let Some(item_list) = module.item_list() {
         buf.open_brace();
+        // Runs of `//!` docs are written together so their code blocks can be formatted
+        let mut inner_docs = Vec::new();
         for child in item_list.syntax().children_with_tokens() {
             match child {
-                NodeOrToken::Node(n) => format_node(&n, buf, indent + 4),
-                NodeOrToken::Token(t) if t.kind() == SyntaxKind::COMMENT => {
-                    buf.line(indent + 4, t.text());
+                NodeOrToken::Token(t) if t.kind() == SyntaxKind::WHITESPACE => {}
+                NodeOrToken::Token(t)
+                    if Comment::cast(t.clone())
+                        .is_some_and(|comment| comment.is_inner() && comment.is_doc()) =>
+                {
+                    inner_docs.push(DocOrAttr::Comment(t.text().to_string()));
+                }
+                child => {
+                    for line in format_docs_and_attrs(&std::mem::take(&mut inner_docs), indent + 4)
+                    {
+                        buf.line(indent + 4, &line);
+                    }
+                    match child {
+                        NodeOrToken::Node(n) => format_node(&n, buf, indent + 4),
+                        NodeOrToken::Token(t) if t.kind() == SyntaxKind::COMMENT => {
+                            buf.line(indent + 4, t.text());
+                        }
+                        _ => {}
+                    }
                 }
-                _ => {}
             }
         }
+        for line in format_docs_and_attrs(&inner_docs, indent + 4) {
+            buf.line(indent + 4, &line);
+        }
         buf.close_brace(indent);
     } else {
         buf.push(';');
@@ -1,7 +1,4 @@
-use ra_ap_syntax::{
-    AstNode, NodeOrToken, SyntaxKind, SyntaxNode,
-    ast::{self, HasVisibility},
-};
+use ra_ap_syntax::{AstNode, NodeOrToken, SyntaxKind, SyntaxNode, ast};
 
 use crate::formatter::node::common::{fields, header};
 use crate::formatter::printer::Printer;
@@ -40,10 +37,8 @@ pub fn format_struct(node: &SyntaxNode, buf: &mut String, indent: usize) {
 
                 // Single-line if has default initializers and no comments
                 if has_default_initializer && !has_comments {
-                    let fields_str: Vec<_> = fields_vec
-                        .iter()
-                        .map(|f| f.syntax().text().to_string())
-                        .collect();
+                    let fields_str: Vec<_> =
+                        fields_vec.iter().map(fields::format_record_field).collect();
                     buf.push_str(&format!(" {{ {} }}", fields_str.join(", ")));
                     buf.push('\n');
                     return;
@@ -55,20 +50,14 @@ pub fn format_struct(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 buf.close_brace_ln(indent);
             }
             ast::FieldList::TupleFieldList(tuple_fields) => {
-                buf.push('(');
-                for (idx, field) in tuple_fields.fields().enumerate() {
-                    if idx > 0 {
-                        buf.push_str(", ");
-                    }
-                    if let Some(vis) = field.visibility() {
-                        buf.push_str(&vis.syntax().text().to_string());
-                        buf.push(' ');
-                    }
-                    if let Some(ty) = field.ty() {
-                        buf.push_str(&ty.syntax().text().to_string());
-                    }
-                }
-                buf.newline(");");
+                let offset = buf.len() - buf.rfind('\n').map_or(0, |i| i + 1);
+                buf.push_str(&fields::format_tuple_fields(
+                    &tuple_fields,
+                    indent,
+                    offset,
+                    1,
+                ));
+                buf.newline(";");
             }
         }
     } else {
@@ -1,9 +1,10 @@
 use ra_ap_syntax::{
-    AstNode, AstToken, SyntaxNode,
-    ast::{self, HasAttrs, HasDocComments, HasGenericParams, HasName, HasVisibility, Type},
+    AstNode, SyntaxNode,
+    ast::{self, HasGenericParams, HasName, HasVisibility, Type},
 };
 
-use crate::formatter::config::MAX_WIDTH;
+use crate::formatter::config::max_width;
+use crate::formatter::printer::Printer;
 use crate::formatter::write_indent;
 
 enum TypeAliasRhsKind {
@@ -20,7 +21,7 @@ fn classify_type_alias_rhs(ty: &Type) -> TypeAliasRhsKind {
     if text.starts_with("fn(") || text.contains("for<") && text.contains("Fn") {
         return TypeAliasRhsKind::FunctionType;
     }
-    if text.len() > MAX_WIDTH {
+    if text.len() > max_width() {
         return TypeAliasRhsKind::GenericWrapped;
     }
     TypeAliasRhsKind::Simple
@@ -32,19 +33,8 @@ pub fn format_type_alias(node: &SyntaxNode, buf: &mut String, indent: usize) {
         None => return,
     };
 
-    // Format doc comments using HasDocComments trait
-    for doc_comment in type_alias.doc_comments() {
-        write_indent(buf, indent);
-        buf.push_str(doc_comment.text().trim());
-        buf.push('\n');
-    }
-
-    // Format attributes using HasAttrs trait
-    for attr in type_alias.attrs() {
-        write_indent(buf, indent);
-        buf.push_str(&attr.syntax().text().to_string());
-        buf.push('\n');
-    }
+    buf.doc_comments(&type_alias, indent);
+    buf.attrs(&type_alias, indent);
 
     write_indent(buf, indent);
 
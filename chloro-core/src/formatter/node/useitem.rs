@@ -6,7 +6,8 @@ use ra_ap_syntax::{
 pub mod grouping;
 pub mod sort;
 
-use crate::formatter::config::MAX_WIDTH;
+use crate::formatter::config::max_width;
+use crate::formatter::node::common::comments;
 use crate::formatter::printer::Printer;
 use crate::formatter::write_indent;
 
@@ -23,7 +24,7 @@ pub fn format_use(node: &SyntaxNode, buf: &mut String, indent: usize) {
                 if t.kind() == SyntaxKind::COMMENT {
                     let text = t.text();
                     // Skip doc comments - they're handled by doc_comments() below
-                    if !text.starts_with("///") && !text.starts_with("//!") {
+                    if !comments::is_doc_comment(&t) {
                         buf.line(indent, text);
                     }
                 } else if t.kind() != SyntaxKind::WHITESPACE {
@@ -80,7 +81,7 @@ pub fn format_use(node: &SyntaxNode, buf: &mut String, indent: usize) {
     };
 
     // If it fits on one line AND has no nested groups, write it directly
-    if single_line_len < MAX_WIDTH && !has_nested_groups {
+    if single_line_len < max_width() && !has_nested_groups {
         // NOTE: Should be <= but there's an off-by-one bug, so use <
         // See: https://github.com/rust-lang/rustfmt/issues/6727
         buf.push_str(&vis_text);
@@ -155,7 +156,7 @@ pub fn format_use(node: &SyntaxNode, buf: &mut String, indent: usize) {
 
                         if current_line.is_empty() {
                             current_line.push_str(&item_with_comma);
-                        } else if potential_line_len < MAX_WIDTH {
+                        } else if potential_line_len < max_width() {
                             current_line.push(' ');
                             current_line.push_str(&item_with_comma);
                         } else {
@@ -203,7 +204,7 @@ fn format_item_with_nested_braces(item: &str, buf: &mut String, indent: usize) {
 
             // Check if it fits on one line
             let single_line = format!("{}{{{}}}", prefix, inner_items.join(", "));
-            if indent + single_line.len() < MAX_WIDTH {
+            if indent + single_line.len() < max_width() {
                 // Note off-by-one error: max width=100 means at most 99 chars per line
                 buf.push_str(&single_line);
                 return;
@@ -221,7 +222,7 @@ fn format_item_with_nested_braces(item: &str, buf: &mut String, indent: usize) {
                 let item_with_comma = format!("{}, ", inner_item);
                 let potential_len = inner_indent + current_line.len() + item_with_comma.len();
 
-                if current_line.is_empty() || potential_len < MAX_WIDTH {
+                if current_line.is_empty() || potential_len < max_width() {
                     current_line.push_str(&item_with_comma);
                 } else {
                     write_indent(buf, inner_indent);
@@ -1,7 +1,10 @@
 //! A trait-based printer to reduce repetitive formatting patterns.
 
-use ra_ap_syntax::ast::{AstNode, AstToken, HasAttrs, HasDocComments, HasVisibility};
+use ra_ap_syntax::ast::{AstNode, HasAttrs, HasDocComments, HasVisibility};
 
+use crate::formatter::config::{self, DeriveOrder};
+use crate::formatter::doc_code::{self, DocOrAttr};
+use crate::formatter::node::common::attrs;
 use crate::formatter::write_indent;
 
 /// Extension trait for String buffers used in formatting.
@@ -18,10 +21,11 @@ pub trait Printer {
     /// Write a blank line.
     fn blank(&mut self);
 
-    /// Write doc comments from a node that implements HasDocComments.
+    /// Write the doc comments that come before the attributes of a node that implements
+    /// HasDocComments.
     fn doc_comments<T: HasDocComments>(&mut self, item: &T, indent: usize);
 
-    /// Write attributes from a node that implements HasAttrs.
+    /// Write attributes from a node that implements HasAttrs, with any doc comments among them.
     fn attrs<T: HasAttrs>(&mut self, item: &T, indent: usize);
 
     /// Write visibility if present, followed by a space.
@@ -68,14 +72,59 @@ impl Printer for String {
     }
 
     fn doc_comments<T: HasDocComments>(&mut self, item: &T, indent: usize) {
-        for doc in item.doc_comments() {
-            self.line(indent, doc.text().trim());
+        // Doc comments after the first attribute are written in their place by `attrs`
+        let docs: Vec<_> = doc_code::docs_and_attrs(item.syntax())
+            .into_iter()
+            .take_while(|entry| matches!(entry, DocOrAttr::Comment(_)))
+            .collect();
+        for line in doc_code::format_docs_and_attrs(&docs, indent) {
+            self.line(indent, &line);
         }
     }
 
     fn attrs<T: HasAttrs>(&mut self, item: &T, indent: usize) {
-        for attr in item.attrs() {
-            self.line(indent, &attr.syntax().text().to_string());
+        let attrs: Vec<_> = doc_code::docs_and_attrs(item.syntax())
+            .into_iter()
+            .skip_while(|entry| matches!(entry, DocOrAttr::Comment(_)))
+            .collect();
+        let (merge, order) = config::with(|config| (config.merge_derives, config.sort_derives));
+        if !merge && order == DeriveOrder::Preserve {
+            for line in doc_code::format_docs_and_attrs(&attrs, indent) {
+                self.line(indent, &line);
+            }
+            return;
+        }
+
+        // Runs of adjacent derives are split out and written separately
+        let mut start = 0;
+        while start < attrs.len() {
+            let derives: Vec<Vec<String>> = attrs[start..]
+                .iter()
+                .map_while(|entry| match entry {
+                    DocOrAttr::Attr(attr) => attrs::derive_paths(attr),
+                    DocOrAttr::Comment(_) => None,
+                })
+                .collect();
+            if derives.is_empty() {
+                let end = (start + 1..attrs.len())
+                    .find(|&i| matches!(&attrs[i], DocOrAttr::Attr(attr) if attrs::derive_paths(attr).is_some()))
+                    .unwrap_or(attrs.len());
+                for line in doc_code::format_docs_and_attrs(&attrs[start..end], indent) {
+                    self.line(indent, &line);
+                }
+                start = end;
+                continue;
+            }
+            start += derives.len();
+            let derives = if merge {
+                vec![derives.concat()]
+            } else {
+                derives
+            };
+            for mut paths in derives {
+                sort_derive_paths(&mut paths, order);
+                self.line(indent, &attrs::format_derive(&paths, indent));
+            }
         }
     }
 
@@ -118,11 +167,37 @@ impl Printer for String {
     }
 }
 
+/// Derivable traits of the standard library, which `DeriveOrder::StdFirst` puts first.
+const STD_DERIVES: &[&str] = &[
+    "Clone",
+    "Copy",
+    "Debug",
+    "Default",
+    "Eq",
+    "Hash",
+    "Ord",
+    "PartialEq",
+    "PartialOrd",
+];
+
+/// Sort the traits of a derive attribute by name, ignoring the paths they are written with.
+fn sort_derive_paths(paths: &mut [String], order: DeriveOrder) {
+    let name = |path: &String| path.rsplit("::").next().unwrap_or(path).to_string();
+    match order {
+        DeriveOrder::Preserve => {}
+        DeriveOrder::Alphabetical => paths.sort_by_cached_key(|path| (name(path), path.clone())),
+        DeriveOrder::StdFirst => paths.sort_by_cached_key(|path| {
+            let name = name(path);
+            (!STD_DERIVES.contains(&name.as_str()), name, path.clone())
+        }),
+    }
+}
+
 /// Collect attributes as a prefix string (for expressions).
 pub fn expr_attrs_prefix<T: HasAttrs>(node: &T) -> String {
     let mut result = String::new();
     for attr in node.attrs() {
-        result.push_str(&attr.syntax().text().to_string());
+        result.push_str(&attrs::format_attr_inline(&attr));
         result.push(' ');
     }
     result
@@ -2,7 +2,11 @@
 pub mod debug;
 pub mod formatter;
 
-pub use formatter::format_source;
+pub use formatter::{
+    Config, ConfigError, DeriveOrder, DocAttributeStyle, Edition, FloatLiteralTrailingZero,
+    HexLiteralCase, NewlineStyle, OptionValue, ParseDiagnostic, TrailingCommentAlignment,
+    format_source, format_source_with_config, parse_diagnostics, parse_diagnostics_with_config,
+};
 
 /// Macro for debug output in chloro.
 ///
@@ -2,12 +2,19 @@ use crate::format_source;
 
 mod attributes;
 mod comments;
+mod config_options;
 mod controlflow;
+mod doc_attributes;
+mod doc_code_blocks;
+mod expr_comments;
+mod extern_blocks;
 mod externcrate;
 mod functions;
 mod impl_blocks;
+mod literals;
 mod macros;
 mod method_chains;
+mod reorder_items;
 mod self_format;
 mod struct_literals;
 mod structs;
@@ -27,3 +34,25 @@ fn format_is_idempotent() {
     let twice = format_source(&once);
     assert_eq!(once, twice);
 }
+
+#[test]
+fn parse_diagnostics_locate_syntax_errors() {
+    assert!(crate::parse_diagnostics("fn main() {}\n").is_empty());
+
+    let diagnostics = crate::parse_diagnostics("fn main() {\n    let x = ;\n}\n");
+    let first = &diagnostics[0];
+    assert_eq!((first.line, first.column), (2, 13));
+    assert!(!first.message.is_empty());
+}
+
+#[test]
+fn parse_diagnostics_use_configured_edition() {
+    let source = "fn main() {\n    let async = 1;\n}\n";
+    assert!(!crate::parse_diagnostics(source).is_empty());
+
+    let config = crate::Config {
+        edition: crate::Edition::Edition2015,
+        ..Default::default()
+    };
+    assert!(crate::parse_diagnostics_with_config(source, &config).is_empty());
+}
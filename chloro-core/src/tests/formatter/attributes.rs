@@ -1,4 +1,5 @@
 use super::*;
+use crate::{Config, DeriveOrder, format_source_with_config};
 use insta::assert_snapshot;
 
 #[test]
@@ -112,3 +113,167 @@ fn preserve_multiple_inner_attributes_with_blank_line() {
     #![recursion_limit = "128"]
     "#);
 }
+
+#[test]
+fn normalize_attribute_spacing() {
+    let input = r#"#[ derive( Debug,Clone ) ]
+#[cfg_attr(feature="serde",derive(Serialize))]
+#[doc="Docs."]
+struct Foo;
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
+    #[doc = "Docs."]
+    struct Foo;
+    "#);
+}
+
+#[test]
+fn break_long_derive_list() {
+    let input = r#"#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, JsonSchema)]
+struct Foo;
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Default,
+        Serialize,
+        Deserialize,
+        JsonSchema,
+    )]
+    struct Foo;
+    ");
+}
+
+#[test]
+fn break_long_nested_cfg() {
+    let input = r#"mod foo {
+    #[cfg(all(any(target_os = "linux", target_os = "android", target_os = "freebsd"), not(feature = "no_std"), test))]
+    fn bar() {}
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+fn baz() {}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    mod foo {
+        #[cfg(all(
+            any(target_os = "linux", target_os = "android", target_os = "freebsd"),
+            not(feature = "no_std"),
+            test,
+        ))]
+        fn bar() {}
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))]
+    fn baz() {}
+    "#);
+}
+
+#[test]
+fn attributes_that_are_not_meta_items_are_untouched() {
+    let input = r#"#[my_attr(  x + 1 )]
+#[facet(default = vec![ "md".to_string() ])]
+#[unsafe( no_mangle )]
+fn foo() {}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    #[my_attr(  x + 1 )]
+    #[facet(default = vec![ "md".to_string() ])]
+    #[unsafe( no_mangle )]
+    fn foo() {}
+    "#);
+}
+
+#[test]
+fn derives_untouched_by_default() {
+    let input = r#"#[derive(Debug)]
+#[derive(Serialize, Clone)]
+struct Foo;
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    #[derive(Debug)]
+    #[derive(Serialize, Clone)]
+    struct Foo;
+    ");
+}
+
+#[test]
+fn merge_adjacent_derives() {
+    let input = r#"#[derive(Debug)]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(PartialEq)]
+/// Docs between derives.
+#[derive(Eq)]
+struct Foo;
+"#;
+    let config = Config {
+        merge_derives: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r#"
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
+    #[derive(PartialEq)]
+    /// Docs between derives.
+    #[derive(Eq)]
+    struct Foo;
+    "#);
+}
+
+#[test]
+fn sort_derives_alphabetically() {
+    let input = r#"#[derive(Serialize, Debug, Clone)]
+#[derive(PartialEq, Eq)]
+struct Foo;
+"#;
+    let config = Config {
+        sort_derives: DeriveOrder::Alphabetical,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    #[derive(Clone, Debug, Serialize)]
+    #[derive(Eq, PartialEq)]
+    struct Foo;
+    ");
+}
+
+#[test]
+fn merge_and_sort_derives_std_first() {
+    let input = r#"#[derive(Serialize, Debug, Deserialize, Clone)]
+#[derive(std::hash::Hash, JsonSchema)]
+struct Foo;
+"#;
+    let config = Config {
+        merge_derives: true,
+        sort_derives: DeriveOrder::StdFirst,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    #[derive(Clone, Debug, std::hash::Hash, Deserialize, JsonSchema, Serialize)]
+    struct Foo;
+    ");
+}
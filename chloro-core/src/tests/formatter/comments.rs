@@ -1,4 +1,5 @@
 use super::*;
+use crate::{Config, TrailingCommentAlignment, format_source_with_config};
 use insta::assert_snapshot;
 
 #[test]
@@ -190,3 +191,390 @@ fn important_function() {}
     fn important_function() {}
     "#);
 }
+
+#[test]
+fn comments_untouched_by_default() {
+    let input = r#"/* block */
+//no space
+// This comment line is far too long to fit within the default comment width of eighty columns.
+fn foo() {}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    /* block */
+    //no space
+    // This comment line is far too long to fit within the default comment width of eighty columns.
+    fn foo() {}
+    ");
+}
+
+#[test]
+fn wrap_comment_paragraphs() {
+    let input = r#"// This comment line is far too long to fit within the default comment width of eighty columns.
+// The next line continues the paragraph.
+//
+// A new paragraph.
+fn foo() {
+    /// A doc comment that is indented and so needs to be wrapped at an earlier column than usual.
+    let x = 1;
+}
+"#;
+    let config = Config {
+        wrap_comments: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    // This comment line is far too long to fit within the default comment width of
+    // eighty columns. The next line continues the paragraph.
+    //
+    // A new paragraph.
+    fn foo() {
+        /// A doc comment that is indented and so needs to be wrapped at an earlier
+        /// column than usual.
+        let x = 1;
+    }
+    ");
+}
+
+#[test]
+fn wrap_non_ascii_comments_by_characters() {
+    let input = r#"// Ça déménage très élégamment à côté de l'église où les élèves étudièrent l'été dernier.
+// 日本語 の コメント は 長く なる と 折り返さ れる べき です が 文字 の 境界 を 尊重 し なければ なりません ので 注意 し て ください
+fn foo() {}
+"#;
+    let config = Config {
+        wrap_comments: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    // Ça déménage très élégamment à côté de l'église où les élèves étudièrent l'été
+    // dernier. 日本語 の コメント は 長く なる と 折り返さ れる べき です が 文字 の 境界 を 尊重 し なければ なりません ので 注意
+    // し て ください
+    fn foo() {}
+    ");
+}
+
+#[test]
+fn wrap_comments_keeps_markdown_structure() {
+    let input = r#"/// Some items:
+///
+/// - A list item that is long enough that it has to be wrapped onto a second line here.
+/// - Short item.
+///
+/// | Column | Another column that makes this table row longer than the comment width |
+/// |--------|------|
+///
+/// ```
+/// let code = "a code block line that is long enough to go past the comment width limit";
+/// ```
+///
+/// See https://example.com/a/very/long/url/that/cannot/be/broken/without/breaking/the/link
+fn foo() {}
+"#;
+    let config = Config {
+        wrap_comments: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r#"
+    /// Some items:
+    ///
+    /// - A list item that is long enough that it has to be wrapped onto a second
+    ///   line here.
+    /// - Short item.
+    ///
+    /// | Column | Another column that makes this table row longer than the comment width |
+    /// |--------|------|
+    ///
+    /// ```
+    /// let code = "a code block line that is long enough to go past the comment width limit";
+    /// ```
+    ///
+    /// See https://example.com/a/very/long/url/that/cannot/be/broken/without/breaking/the/link
+    fn foo() {}
+    "#);
+}
+
+#[test]
+fn wrap_trailing_comment_at_its_column() {
+    let input = r#"struct Foo {
+    a: u32, // A trailing comment that is too long to fit within the comment width of 80.
+}
+"#;
+    let config = Config {
+        wrap_comments: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    struct Foo {
+        a: u32, // A trailing comment that is too long to fit within the comment
+                // width of 80.
+    }
+    ");
+}
+
+#[test]
+fn normalize_comment_markers() {
+    let input = r#"//!no space
+/*! inner doc */
+
+/* block comment */
+fn foo() {}
+
+/** block doc comment */
+fn bar() {}
+
+///no space
+////////////
+//no space
+fn baz() {
+    let x = /* not at the end of a line */ 1;
+}
+"#;
+    let config = Config {
+        normalize_comments: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    //! no space
+    //! inner doc
+
+    // block comment
+    fn foo() {}
+
+    /// block doc comment
+    fn bar() {}
+
+    ////////////
+    // no space
+    /// no space
+    fn baz() {
+        let x = /* not at the end of a line */ 1;
+    }
+    ");
+}
+
+#[test]
+fn wrap_comments_with_custom_width() {
+    let input = r#"// Wrapped at forty columns instead of the default eighty.
+fn foo() {}
+"#;
+    let config = Config {
+        wrap_comments: true,
+        comment_width: 40,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    // Wrapped at forty columns instead of
+    // the default eighty.
+    fn foo() {}
+    ");
+}
+
+const TRAILING_COMMENTS: &str = r#"struct Foo {
+    a: u32,    // first
+    bb: u32, // second
+    ccc: String,  // third
+}
+
+enum Bar {
+    A,      // first
+    Bbbbbb, // second
+}
+
+fn baz() {
+    let x = 1;   // first
+    let yyyyyy = 2; // second
+
+    let z = 3;  // after a blank line
+}
+"#;
+
+#[test]
+fn preserve_comments_before_items_in_function_body() {
+    let input = r#"fn f() {
+    // about S
+    struct S;
+    // hack
+    const X: u8 = 1;
+    let x = X;
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() {
+        // about S
+        struct S;
+        // hack
+        const X: u8 = 1;
+        let x = X;
+    }
+    ");
+}
+
+#[test]
+fn preserve_comments_before_module_items() {
+    let input = r#"// hack
+const X: u8 = 1;
+
+// about E
+#[derive(Debug)]
+enum E { A }
+
+// about T
+type T = u8;
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    // hack
+    const X: u8 = 1;
+
+    // about E
+    #[derive(Debug)]
+    enum E {
+        A,
+    }
+
+    // about T
+    type T = u8;
+    ");
+}
+
+#[test]
+fn trailing_comment_before_module_item_stays_on_its_line() {
+    let input = r#"const A: u8 = 1; // note
+const B: u8 = 2;
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    const A: u8 = 1; // note
+
+    const B: u8 = 2;
+    ");
+}
+
+#[test]
+fn trailing_comment_spacing_preserved_by_default() {
+    let output = format_source(TRAILING_COMMENTS);
+    assert_snapshot!(output, @r"
+    struct Foo {
+        a: u32,    // first
+        bb: u32, // second
+        ccc: String,  // third
+    }
+
+    enum Bar {
+        A,      // first
+        Bbbbbb, // second
+    }
+
+    fn baz() {
+        let x = 1;   // first
+        let yyyyyy = 2; // second
+
+        let z = 3;  // after a blank line
+    }
+    ");
+}
+
+#[test]
+fn trailing_comments_single_space() {
+    let config = Config {
+        trailing_comment_alignment: TrailingCommentAlignment::SingleSpace,
+        ..Config::default()
+    };
+    let output = format_source_with_config(TRAILING_COMMENTS, &config);
+    assert_snapshot!(output, @r"
+    struct Foo {
+        a: u32, // first
+        bb: u32, // second
+        ccc: String, // third
+    }
+
+    enum Bar {
+        A, // first
+        Bbbbbb, // second
+    }
+
+    fn baz() {
+        let x = 1; // first
+        let yyyyyy = 2; // second
+
+        let z = 3; // after a blank line
+    }
+    ");
+}
+
+#[test]
+fn align_consecutive_trailing_comments() {
+    let config = Config {
+        trailing_comment_alignment: TrailingCommentAlignment::AlignConsecutive,
+        ..Config::default()
+    };
+    let output = format_source_with_config(TRAILING_COMMENTS, &config);
+    assert_snapshot!(output, @r"
+    struct Foo {
+        a: u32,      // first
+        bb: u32,     // second
+        ccc: String, // third
+    }
+
+    enum Bar {
+        A,      // first
+        Bbbbbb, // second
+    }
+
+    fn baz() {
+        let x = 1;      // first
+        let yyyyyy = 2; // second
+
+        let z = 3; // after a blank line
+    }
+    ");
+}
+
+#[test]
+fn align_trailing_comments_within_width() {
+    let input = r#"fn foo() {
+    let a = 1; // short
+    let b = some_function_with_a_long_name(argument_one, argument_two, argument_three); // too wide to align
+    let c = 3; // short
+}
+"#;
+    let config = Config {
+        trailing_comment_alignment: TrailingCommentAlignment::AlignConsecutive,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    fn foo() {
+        let a = 1; // short
+        let b = some_function_with_a_long_name(argument_one, argument_two, argument_three); // too wide to align
+        let c = 3; // short
+    }
+    ");
+}
+
+#[test]
+fn align_trailing_comments_after_non_ascii_code() {
+    let input = r#"fn foo() {
+    let s = "héllo"; // first
+    let t = "ab"; // second
+}
+"#;
+    let config = Config {
+        trailing_comment_alignment: TrailingCommentAlignment::AlignConsecutive,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let s = "héllo"; // first
+        let t = "ab";    // second
+    }
+    "#);
+}
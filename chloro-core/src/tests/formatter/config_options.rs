@@ -0,0 +1,79 @@
+use crate::{
+    Config, ConfigError, HexLiteralCase, NewlineStyle, OptionValue, format_source,
+    format_source_with_config,
+};
+
+#[test]
+fn set_parses_options_by_name() {
+    let mut config = Config::default();
+    config.set("merge_derives", "true").unwrap();
+    config.set("comment_width", "100").unwrap();
+    config.set("hex_literal_case", "Upper").unwrap();
+    assert!(config.merge_derives);
+    assert_eq!(config.comment_width, 100);
+    assert_eq!(config.hex_literal_case, HexLiteralCase::Upper);
+}
+
+#[test]
+fn get_returns_every_option() {
+    let config = Config::default();
+    for key in Config::OPTIONS {
+        assert!(config.get(key).is_some(), "missing {key}");
+    }
+    assert_eq!(
+        config.get("hex_literal_case"),
+        Some(OptionValue::Variant("Preserve"))
+    );
+    assert_eq!(
+        config.get("comment_width").unwrap().to_string(),
+        "80".to_string()
+    );
+}
+
+#[test]
+fn set_rejects_unknown_keys_and_bad_values() {
+    let mut config = Config::default();
+    assert_eq!(
+        config.set("max_widht", "100"),
+        Err(ConfigError::UnknownKey("max_widht".to_string()))
+    );
+    let error = config.set("hex_literal_case", "upper").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid value `upper` for `hex_literal_case` (expected Preserve, Upper, Lower)"
+    );
+}
+
+#[test]
+fn max_width_sets_where_lines_wrap() {
+    let input = "fn long_name(first: u32, second: u32, third: u32) {}\n";
+    assert_eq!(format_source(input), input);
+    let config = Config {
+        max_width: 40,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert!(output.lines().all(|line| line.len() <= 40), "{output}");
+}
+
+#[test]
+fn newline_style_sets_line_endings() {
+    let crlf = "fn main() {\r\n    // hi\r\n}\r\n";
+    assert_eq!(format_source(crlf), crlf);
+    let unix = Config {
+        newline_style: NewlineStyle::Unix,
+        ..Config::default()
+    };
+    assert_eq!(
+        format_source_with_config(crlf, &unix),
+        "fn main() {\n    // hi\n}\n"
+    );
+    let windows = Config {
+        newline_style: NewlineStyle::Windows,
+        ..Config::default()
+    };
+    assert_eq!(
+        format_source_with_config("fn main() {}\n", &windows),
+        "fn main() {}\r\n"
+    );
+}
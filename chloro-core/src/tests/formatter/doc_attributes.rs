@@ -0,0 +1,83 @@
+use super::*;
+use crate::{Config, DocAttributeStyle, format_source_with_config};
+use insta::assert_snapshot;
+
+#[test]
+fn doc_attributes_preserved_by_default() {
+    let input = r#"/// A doc comment.
+#[doc = "A doc attribute."]
+#[derive(Debug)]
+/// After the derive.
+struct Foo;
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    /// A doc comment.
+    #[doc = "A doc attribute."]
+    #[derive(Debug)]
+    /// After the derive.
+    struct Foo;
+    "#);
+}
+
+#[test]
+fn normalize_doc_attributes_to_comments() {
+    let input = r#"#![doc = "Crate docs."]
+#![allow(dead_code)]
+
+#[doc = " Some \"quoted\" text."]
+#[derive(Debug)]
+#[doc = " After the derive."]
+#[doc(hidden)]
+#[doc = include_str!("README.md")]
+#[doc = "/ Not a doc comment if written as one."]
+struct Foo;
+"#;
+    let config = Config {
+        normalize_doc_attributes: DocAttributeStyle::Comments,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r#"
+    //!Crate docs.
+    #![allow(dead_code)]
+
+    /// Some "quoted" text.
+    #[derive(Debug)]
+    /// After the derive.
+    #[doc(hidden)]
+    #[doc = include_str!("README.md")]
+    #[doc = "/ Not a doc comment if written as one."]
+    struct Foo;
+    "#);
+}
+
+#[test]
+fn normalize_doc_comments_to_attributes() {
+    let input = r#"//! Crate docs with a "quote" and a \ backslash.
+
+/// Some docs.
+#[derive(Debug)]
+/// After the derive.
+struct Foo {
+    /// A field.
+    a: u32,
+}
+"#;
+    let config = Config {
+        normalize_doc_attributes: DocAttributeStyle::Attributes,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r#"
+    #![doc = " Crate docs with a \"quote\" and a \\ backslash."]
+
+    #[doc = " Some docs."]
+    #[derive(Debug)]
+    #[doc = " After the derive."]
+    struct Foo {
+        #[doc = " A field."]
+        a: u32,
+    }
+    "#);
+}
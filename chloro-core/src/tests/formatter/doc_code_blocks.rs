@@ -0,0 +1,160 @@
+use super::*;
+use crate::{Config, format_source_with_config};
+use insta::assert_snapshot;
+
+fn format_doc_code(input: &str) -> String {
+    let config = Config {
+        format_code_in_doc_comments: true,
+        ..Config::default()
+    };
+    format_source_with_config(input, &config)
+}
+
+#[test]
+fn doc_code_left_alone_by_default() {
+    let input = r#"/// ```
+/// fn f()->u8{ 1 }
+/// ```
+fn f() {}
+"#;
+    assert_snapshot!(format_source(input), @r"
+    /// ```
+    /// fn f()->u8{ 1 }
+    /// ```
+    fn f() {}
+    ");
+}
+
+#[test]
+fn format_doc_code_blocks_with_hidden_lines() {
+    let input = r#"/// Adds numbers.
+///
+/// ```rust,no_run
+/// # use foo::add;
+/// # fn main() {
+/// let y = add(1, 2);
+/// if y>2 {run()}
+/// # }
+/// ```
+///
+///   ```should_panic
+///   fn f()->u8{ 1 }
+///   ```
+pub fn add(a: u8, b: u8) -> u8 {
+    a + b
+}
+"#;
+    assert_snapshot!(format_doc_code(input), @r"
+    /// Adds numbers.
+    ///
+    /// ```rust,no_run
+    /// # use foo::add;
+    /// # fn main() {
+    /// let y = add(1, 2);
+    /// if y > 2 {
+    ///     run()
+    /// }
+    /// # }
+    /// ```
+    ///
+    ///   ```should_panic
+    ///   fn f() -> u8 {
+    ///       1
+    ///   }
+    ///   ```
+    pub fn add(a: u8, b: u8) -> u8 {
+        a + b
+    }
+    ");
+}
+
+#[test]
+fn format_doc_code_in_inner_doc_comments() {
+    let input = r#"//! ```
+//! struct P{x:u8}
+//! ```
+
+fn f() {}
+"#;
+    assert_snapshot!(format_doc_code(input), @r"
+    //! ```
+    //! struct P {
+    //!     x: u8,
+    //! }
+    //! ```
+
+    fn f() {}
+    ");
+}
+
+#[test]
+fn format_doc_code_in_inline_module_inner_docs() {
+    let input = r#"mod m {
+    //! ```
+    //! struct P{x:u8}
+    //! ```
+    fn f() {}
+}
+"#;
+    assert_snapshot!(format_doc_code(input), @r"
+    mod m {
+        //! ```
+        //! struct P {
+        //!     x: u8,
+        //! }
+        //! ```
+        fn f() {}
+    }
+    ");
+}
+
+#[test]
+fn format_doc_code_in_doc_attributes() {
+    let input = r#"#[doc = " ```"]
+#[doc = " struct S{a:u8, b: &'static str}"]
+#[doc = " ```"]
+#[inline]
+fn f() {}
+"#;
+    assert_snapshot!(format_doc_code(input), @r#"
+    #[doc = " ```"]
+    #[doc = " struct S {"]
+    #[doc = "     a: u8,"]
+    #[doc = "     b: &'static str,"]
+    #[doc = " }"]
+    #[doc = " ```"]
+    #[inline]
+    fn f() {}
+    "#);
+}
+
+#[test]
+fn doc_code_that_is_not_rust_is_untouched() {
+    let input = r#"/// ```text
+/// fn   f()
+/// ```
+///
+/// ```
+/// this is not rust {{{
+/// ```
+///
+/// ```ignore
+/// fn f()->u8{ 1 }
+/// ```
+fn f() {}
+"#;
+    assert_snapshot!(format_doc_code(input), @r"
+    /// ```text
+    /// fn   f()
+    /// ```
+    ///
+    /// ```
+    /// this is not rust {{{
+    /// ```
+    ///
+    /// ```ignore
+    /// fn f()->u8{ 1 }
+    /// ```
+    fn f() {}
+    ");
+}
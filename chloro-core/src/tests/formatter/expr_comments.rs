@@ -0,0 +1,164 @@
+use super::*;
+use insta::assert_snapshot;
+
+#[test]
+fn block_comments_between_call_args_stay_inline() {
+    let input = r#"fn f() {
+    foo(a,/* b */b,   c /* after c */)
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() {
+        foo(a, /* b */ b, c /* after c */)
+    }
+    ");
+}
+
+#[test]
+fn line_comments_in_call_args_force_one_per_line() {
+    let input = r#"fn f() {
+    x.foo(a, // why
+      b).bar()
+}
+
+fn g() {
+    bar(  a, // first
+        // before b
+        b,
+        /* inline */ c,
+        // trailing own line
+    )
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() {
+        x.foo(
+            a, // why
+            b,
+        )
+        .bar()
+    }
+
+    fn g() {
+        bar(
+            a, // first
+            // before b
+            b,
+            /* inline */ c,
+            // trailing own line
+        )
+    }
+    ");
+}
+
+#[test]
+fn line_comments_in_array_force_one_per_line() {
+    let input = r#"fn f() {
+    [1,   // one
+     2]
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() {
+        [
+            1, // one
+            2,
+        ]
+    }
+    ");
+}
+
+#[test]
+fn comments_between_binary_operands() {
+    let input = r#"fn f() {
+    a   // first
+        +   b // second
+        +   c
+}
+
+fn g() {
+    a +   /* the b */ b
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() {
+        a // first
+            + b // second
+            + c
+    }
+
+    fn g() {
+        a + /* the b */ b
+    }
+    ");
+}
+
+#[test]
+fn comments_before_else() {
+    let input = r#"fn f() {
+    if x {1}   /* note */ else {2}
+}
+
+fn g() {
+    if x {
+        1
+    } // after if
+    else if y {
+        2
+    }
+    // before else
+    else {
+        3
+    }
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() {
+        if x {
+            1
+        } /* note */ else {
+            2
+        }
+    }
+
+    fn g() {
+        if x {
+            1
+        } // after if
+        else if y {
+            2
+        }
+        // before else
+        else {
+            3
+        }
+    }
+    ");
+}
+
+#[test]
+fn unattached_comments_keep_the_expression_verbatim() {
+    let input = r#"fn f() {
+    foo(/* none */)
+}
+
+fn g() {
+    if x /* cond */ {1} else {2}
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() {
+        foo(/* none */)
+    }
+
+    fn g() {
+        if x /* cond */ {1} else {2}
+    }
+    ");
+}
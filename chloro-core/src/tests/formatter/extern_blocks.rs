@@ -0,0 +1,95 @@
+use super::*;
+use insta::assert_snapshot;
+
+#[test]
+fn extern_block_keeps_docs_and_attributes() {
+    let input = r#"/// Bindings to libfoo.
+#[link(name = "foo")]
+unsafe extern "C" {
+    #![allow(dead_code)]
+    pub safe fn abs(x: i32) -> i32;
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    /// Bindings to libfoo.
+    #[link(name = "foo")]
+    unsafe extern "C" {
+        #![allow(dead_code)]
+        pub safe fn abs(x: i32) -> i32;
+    }
+    "#);
+}
+
+#[test]
+fn extern_block_formats_long_declarations() {
+    let input = r#"extern "C" {
+    /// Does the thing.
+    #[link_name = "bar"]
+    fn very_long_function_name_here(first_argument: *const u8, second_argument: usize, third: i32) -> i32;
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    extern "C" {
+        /// Does the thing.
+        #[link_name = "bar"]
+        fn very_long_function_name_here(
+            first_argument: *const u8,
+            second_argument: usize,
+            third: i32,
+        ) -> i32;
+    }
+    "#);
+}
+
+#[test]
+fn extern_block_item_qualifiers() {
+    let input = r#"unsafe extern "C" {
+    pub unsafe fn strlen(p: *const c_char) -> usize;
+    pub safe static FOO: i32;
+    unsafe static mut BAR: u8;
+    type Opaque;
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    unsafe extern "C" {
+        pub unsafe fn strlen(p: *const c_char) -> usize;
+        pub safe static FOO: i32;
+        unsafe static mut BAR: u8;
+        type Opaque;
+    }
+    "#);
+}
+
+#[test]
+fn extern_block_preserves_comments_and_blank_lines() {
+    let input = r#"extern "C" {
+    fn abs(x: i32) -> i32; // trailing
+    fn labs(x: i64) -> i64;
+
+    // Strings
+    fn strlen(p: *const c_char) -> usize;
+    // dangling
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    extern "C" {
+        fn abs(x: i32) -> i32; // trailing
+        fn labs(x: i64) -> i64;
+
+        // Strings
+        fn strlen(p: *const c_char) -> usize;
+        // dangling
+    }
+    "#);
+}
+
+#[test]
+fn function_abi_is_kept() {
+    let input = r#"pub const unsafe extern "C" fn callback() {}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"pub const unsafe extern "C" fn callback() {}"#);
+}
@@ -59,3 +59,91 @@ fn format_very_short_function_single_line() {
     }
     ");
 }
+
+#[test]
+fn format_items_nested_in_function_body() {
+    let input = r#"#[test]
+fn check() {
+        struct Point { x: i32,
+              y: i32 }
+    impl Point {
+            fn new(x: i32, y: i32) -> Self { Self { x, y } }
+    }
+    const N:usize=3; // trailing
+    let p = Point::new(1, 2);
+    if true {
+        fn inner() {
+              bar();
+        }
+        inner();
+    }
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    #[test]
+    fn check() {
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+        impl Point {
+            fn new(x: i32, y: i32) -> Self {
+                Self { x, y }
+            }
+        }
+        const N: usize = 3; // trailing
+        let p = Point::new(1, 2);
+        if true {
+            fn inner() {
+                bar();
+            }
+            inner();
+        }
+    }
+    ");
+}
+
+#[test]
+fn sort_uses_nested_in_function_body() {
+    let input = r#"fn check() {
+    use std::fmt;
+    use crate::Foo;
+    use std::collections::HashMap;
+
+    use super::*;
+    run();
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn check() {
+        use crate::Foo;
+        use std::collections::HashMap;
+        use std::fmt;
+
+        use super::*;
+        run();
+    }
+    ");
+}
+
+#[test]
+fn sort_uses_nested_in_function_body_keeps_trailing_comments() {
+    let input = r#"fn f() { // open
+    use c; // trailing c
+    use b; // trailing b
+    use a;
+    let x = 1; // trailing x
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn f() { // open
+        use a;
+        use b; // trailing b
+        use c; // trailing c
+        let x = 1; // trailing x
+    }
+    ");
+}
@@ -0,0 +1,214 @@
+use super::*;
+use crate::{Config, FloatLiteralTrailingZero, HexLiteralCase, format_source_with_config};
+use insta::assert_snapshot;
+
+const INPUT: &str = r#"fn foo() {
+    let a = 0xdeadBEEFu32;
+    let b = 1.;
+    let c = 1.0e5f64;
+    let d = 1000000;
+    let e = 1_00_000_u64;
+    let f = 0xffffffff;
+    let g = 1.0.max(2.);
+    let h = 1234567.891;
+    let s = "tab\t and 0xff and 1.";
+    let r = r"raw \t 0xff";
+    let bytes = b"byte \x00 1.";
+    bar(0xAbC, 12345678, 2.)
+}
+"#;
+
+#[test]
+fn literals_untouched_by_default() {
+    let output = format_source(INPUT);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let a = 0xdeadBEEFu32;
+        let b = 1.;
+        let c = 1.0e5f64;
+        let d = 1000000;
+        let e = 1_00_000_u64;
+        let f = 0xffffffff;
+        let g = 1.0.max(2.);
+        let h = 1234567.891;
+        let s = "tab\t and 0xff and 1.";
+        let r = r"raw \t 0xff";
+        let bytes = b"byte \x00 1.";
+        bar(0xAbC, 12345678, 2.)
+    }
+    "#);
+}
+
+#[test]
+fn hex_literal_case_upper() {
+    let config = Config {
+        hex_literal_case: HexLiteralCase::Upper,
+        ..Config::default()
+    };
+    let output = format_source_with_config(INPUT, &config);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let a = 0xDEADBEEFu32;
+        let b = 1.;
+        let c = 1.0e5f64;
+        let d = 1000000;
+        let e = 1_00_000_u64;
+        let f = 0xFFFFFFFF;
+        let g = 1.0.max(2.);
+        let h = 1234567.891;
+        let s = "tab\t and 0xff and 1.";
+        let r = r"raw \t 0xff";
+        let bytes = b"byte \x00 1.";
+        bar(0xABC, 12345678, 2.)
+    }
+    "#);
+}
+
+#[test]
+fn float_literal_trailing_zero_always() {
+    let config = Config {
+        float_literal_trailing_zero: FloatLiteralTrailingZero::Always,
+        ..Config::default()
+    };
+    let output = format_source_with_config(INPUT, &config);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let a = 0xdeadBEEFu32;
+        let b = 1.0;
+        let c = 1.0e5f64;
+        let d = 1000000;
+        let e = 1_00_000_u64;
+        let f = 0xffffffff;
+        let g = 1.0.max(2.0);
+        let h = 1234567.891;
+        let s = "tab\t and 0xff and 1.";
+        let r = r"raw \t 0xff";
+        let bytes = b"byte \x00 1.";
+        bar(0xAbC, 12345678, 2.0)
+    }
+    "#);
+}
+
+#[test]
+fn float_literal_trailing_zero_never() {
+    let config = Config {
+        float_literal_trailing_zero: FloatLiteralTrailingZero::Never,
+        ..Config::default()
+    };
+    let output = format_source_with_config(INPUT, &config);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let a = 0xdeadBEEFu32;
+        let b = 1.;
+        let c = 1e5f64;
+        let d = 1000000;
+        let e = 1_00_000_u64;
+        let f = 0xffffffff;
+        let g = 1.0.max(2.);
+        let h = 1234567.891;
+        let s = "tab\t and 0xff and 1.";
+        let r = r"raw \t 0xff";
+        let bytes = b"byte \x00 1.";
+        bar(0xAbC, 12345678, 2.)
+    }
+    "#);
+}
+
+#[test]
+fn group_long_literal_digits() {
+    let config = Config {
+        decimal_literal_group_size: 3,
+        hex_literal_group_size: 4,
+        hex_literal_case: HexLiteralCase::Lower,
+        ..Config::default()
+    };
+    let output = format_source_with_config(INPUT, &config);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let a = 0xdead_beefu32;
+        let b = 1.;
+        let c = 1.0e5f64;
+        let d = 1_000_000;
+        let e = 100_000_u64;
+        let f = 0xffff_ffff;
+        let g = 1.0.max(2.);
+        let h = 1_234_567.891;
+        let s = "tab\t and 0xff and 1.";
+        let r = r"raw \t 0xff";
+        let bytes = b"byte \x00 1.";
+        bar(0xabc, 12_345_678, 2.)
+    }
+    "#);
+}
+
+#[test]
+fn format_strings_splits_long_strings_at_spaces() {
+    let input = r#"fn foo() {
+    println!("The quick brown fox jumps over the lazy dog, then runs off into the forest to find {} more dogs", count);
+    let message = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+}
+"#;
+    let config = Config {
+        format_strings: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        println!("The quick brown fox jumps over the lazy dog, then runs off into the forest to find \
+                  {} more dogs", count);
+        let message = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor \
+                       incididunt ut labore et dolore magna aliqua.";
+    }
+    "#);
+    assert_eq!(format_source_with_config(&output, &config), output);
+}
+
+#[test]
+fn format_strings_measures_width_in_characters() {
+    let input = r#"fn foo() {
+    let s = "élève été élève été élève été élève été élève été élève été élève été élève été";
+}
+"#;
+    let config = Config {
+        format_strings: true,
+        ..Config::default()
+    };
+    assert_eq!(format_source_with_config(input, &config), input);
+}
+
+#[test]
+fn format_strings_joins_split_strings_that_fit() {
+    let input = r#"fn foo() {
+    let message = "short and \
+                   split";
+}
+"#;
+    let config = Config {
+        format_strings: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let message = "short and split";
+    }
+    "#);
+}
+
+#[test]
+fn format_strings_leaves_raw_byte_and_macro_strings() {
+    let input = r##"fn foo() {
+    let raw = r"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut";
+    let bytes = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut";
+    let text = include_str!("some/very/long/path/with a space/that/goes/on/and/on/and/on/and/on/and/on.txt");
+    let joined = concat!("Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod", "x");
+}
+"##;
+    let config = Config {
+        format_strings: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_eq!(output, input);
+}
@@ -1,81 +1,13 @@
 use super::*;
 use insta::assert_snapshot;
 
-use ra_ap_syntax::ast::HasArgList;
-
-#[test]
-fn debug_method_call_arg_formatting() {
-    let input = r#"fn foo() {
-    acc.push(
-        UnresolvedModule {
-            decl: InFile::new(file_id, decl),
-            candidates: candidates.clone(),
-        }
-        .into(),
-    )
-}"#;
-
-    let parse = ra_ap_syntax::SourceFile::parse(input, ra_ap_syntax::Edition::CURRENT);
-    let root = parse.syntax_node();
-
-    use ra_ap_syntax::{AstNode, NodeOrToken, SyntaxKind, ast};
-
-    // Find acc.push()
-    for node in root.descendants() {
-        if node.kind() == SyntaxKind::METHOD_CALL_EXPR {
-            if let Some(method) = ast::MethodCallExpr::cast(node.clone()) {
-                if let Some(name) = method.name_ref() {
-                    if name.text() == "push" {
-                        eprintln!("=== acc.push() ===");
-
-                        if let Some(arg_list) = method.arg_list() {
-                            // Check if there's a newline after L_PAREN
-                            let mut after_lparen = false;
-                            for child in arg_list.syntax().children_with_tokens() {
-                                match &child {
-                                    NodeOrToken::Token(t) if t.kind() == SyntaxKind::L_PAREN => {
-                                        after_lparen = true;
-                                    }
-                                    NodeOrToken::Token(t)
-                                        if after_lparen && t.kind() == SyntaxKind::WHITESPACE =>
-                                    {
-                                        let has_newline = t.text().contains('\n');
-                                        eprintln!(
-                                            "Whitespace after L_PAREN: {:?}, has_newline: {}",
-                                            t.text(),
-                                            has_newline
-                                        );
-                                        break;
-                                    }
-                                    _ => {}
-                                }
-                            }
-                        }
-
-                        // What does format_method_call_expr return for this?
-                        // It should return None because the receiver (acc) is a PATH_EXPR
-                        // No wait, acc is PATH_EXPR which is NOT in the chain check
-
-                        let receiver = method.receiver();
-                        eprintln!(
-                            "Receiver: {:?}",
-                            receiver.as_ref().map(|r| r.syntax().kind())
-                        );
-                    }
-                }
-            }
-        }
-    }
-
-    panic!("Debug output above");
-}
-
 #[test]
 fn method_call_after_multiline_struct() {
     let input = r#"fn foo() {
     Foo {
-        a: 1,
-        b: 2,
+        alpha: 1,
+        beta: 2,
+        gamma: 3,
     }
     .bar()
 }"#;
@@ -83,8 +15,9 @@ fn method_call_after_multiline_struct() {
     assert_snapshot!(output, @r#"
     fn foo() {
         Foo {
-            a: 1,
-            b: 2,
+            alpha: 1,
+            beta: 2,
+            gamma: 3,
         }
         .bar()
     }
@@ -126,3 +59,164 @@ fn into_after_multiline_struct_in_arg() {
     }
     "#);
 }
+
+#[test]
+fn long_chain_written_on_one_line_is_broken() {
+    let input = r#"fn foo() -> Result<()> {
+    builder.name("some long name here").version("1.0.0").author("someone").about("does things").build()?
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() -> Result<()> {
+        builder
+            .name("some long name here")
+            .version("1.0.0")
+            .author("someone")
+            .about("does things")
+            .build()?
+    }
+    "#);
+}
+
+#[test]
+fn short_chain_written_vertically_is_joined() {
+    let input = r#"fn foo() {
+    self.items
+        .iter()
+        .count()
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        self.items.iter().count()
+    }
+    "#);
+}
+
+#[test]
+fn chain_with_try_and_await() {
+    let input = r#"fn foo() {
+    async_client_with_long_name.request(some_request_argument)?.send_all_the_things().await?.body
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        async_client_with_long_name
+            .request(some_request_argument)?
+            .send_all_the_things()
+            .await?
+            .body
+    }
+    "#);
+}
+
+#[test]
+fn chain_last_closure_overflows() {
+    let input = r#"fn foo() {
+    items.iter().map(|x| {
+            let y = x + 1;
+            y * 2
+        })
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        items.iter().map(|x| {
+            let y = x + 1;
+            y * 2
+        })
+    }
+    "#);
+}
+
+#[test]
+fn single_call_args_wrap_before_chain_breaks() {
+    let input = r#"fn foo() {
+    foo_bar.some_method_with_long_name(argument_number_one, argument_number_two, argument_three_long)
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        foo_bar.some_method_with_long_name(
+            argument_number_one,
+            argument_number_two,
+            argument_three_long,
+        )
+    }
+    "#);
+}
+
+#[test]
+fn chain_with_comment_between_links_is_preserved() {
+    let input = r#"fn foo() {
+    foo
+        // a comment
+        .bar()
+        .baz()
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        foo
+            // a comment
+            .bar()
+            .baz()
+    }
+    "#);
+}
+
+#[test]
+fn long_chain_in_let_statement_is_broken() {
+    let input = r#"fn foo() {
+    let values = self.items.iter().map(|item| item.value).filter(|value| *value > 0).collect::<Vec<_>>();
+    let short = a.b().c();
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        let values = self
+            .items
+            .iter()
+            .map(|item| item.value)
+            .filter(|value| *value > 0)
+            .collect::<Vec<_>>();
+        let short = a.b().c();
+    }
+    "#);
+}
+
+#[test]
+fn long_chain_in_expression_statement_is_broken() {
+    let input = r#"fn foo() {
+    self.builder.name("a long enough name").value(12345).flag(true).build();
+    bar();
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        self.builder
+            .name("a long enough name")
+            .value(12345)
+            .flag(true)
+            .build();
+        bar();
+    }
+    "#);
+}
+
+#[test]
+fn closure_behind_reference_overflows_last_call() {
+    let input = r#"fn foo() {
+    ctx.scope.process_all_names(&mut |_, def| {
+        defs.insert(def);
+    });
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r#"
+    fn foo() {
+        ctx.scope.process_all_names(&mut |_, def| {
+            defs.insert(def);
+        });
+    }
+    "#);
+}
@@ -0,0 +1,160 @@
+use crate::{Config, format_source_with_config};
+use insta::assert_snapshot;
+
+#[test]
+fn reorder_modules_sorts_mod_declarations() {
+    let input = r#"mod zebra;
+// The alpha module.
+mod alpha;
+#[macro_use]
+mod macros;
+mod beta;
+pub mod gamma;
+mod inline {
+    fn f() {}
+}
+
+mod delta;
+mod charlie;
+"#;
+    let config = Config {
+        reorder_modules: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    // The alpha module.
+    mod alpha;
+    mod zebra;
+    #[macro_use]
+    mod macros;
+    mod beta;
+    pub mod gamma;
+    mod inline {
+        fn f() {}
+    }
+
+    mod charlie;
+    mod delta;
+    ");
+}
+
+#[test]
+fn reorder_modules_moves_trailing_comments_with_their_mod() {
+    let input = r#"mod c; // trailing c
+mod b; // trailing b
+mod a; // trailing a
+"#;
+    let config = Config {
+        reorder_modules: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    mod a; // trailing a
+    mod b; // trailing b
+    mod c; // trailing c
+    ");
+}
+
+#[test]
+fn reorder_impl_items_puts_types_and_consts_first() {
+    let input = r#"impl Foo for Bar {
+    fn first(&self) {} // trailing first
+
+    /// The output.
+    type Output = u32; // trailing Output
+
+    // The limit.
+    const LIMIT: usize = 4; // trailing LIMIT
+
+    // Helpers
+
+    fn second(&self) {} // trailing second
+
+    const AFTER: usize = 5; // trailing AFTER
+}
+"#;
+    let config = Config {
+        reorder_impl_items: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    impl Foo for Bar {
+        /// The output.
+        type Output = u32; // trailing Output
+
+        // The limit.
+        const LIMIT: usize = 4; // trailing LIMIT
+
+        fn first(&self) {} // trailing first
+
+        // Helpers
+
+        const AFTER: usize = 5; // trailing AFTER
+
+        fn second(&self) {} // trailing second
+    }
+    ");
+}
+
+#[test]
+fn reorder_items_by_kind_orders_top_level_items() {
+    let input = r#"fn main() {}
+
+impl Foo {}
+
+/// A struct.
+struct Foo;
+
+use std::fmt;
+
+const MAX: u32 = 1;
+
+trait Bar {
+    fn bar(&self);
+}
+
+mod inner;
+
+macro_rules! m {
+    () => {};
+}
+
+fn after_macro() {}
+
+static S: u32 = 2;
+"#;
+    let config = Config {
+        reorder_items_by_kind: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    use std::fmt;
+
+    mod inner;
+
+    const MAX: u32 = 1;
+
+    /// A struct.
+    struct Foo;
+
+    trait Bar {
+        fn bar(&self);
+    }
+
+    impl Foo {}
+
+    fn main() {}
+
+    macro_rules! m {
+        () => {};
+    }
+
+    static S: u32 = 2;
+
+    fn after_macro() {}
+    ");
+}
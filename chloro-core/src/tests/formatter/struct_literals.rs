@@ -1,4 +1,6 @@
 use super::*;
+use crate::{Config, format_source_with_config};
+use insta::assert_snapshot;
 
 #[test]
 fn format_struct_literal_multiline() {
@@ -41,3 +43,89 @@ fn format_struct_literal_single_field_inline() {
 //     walk(&root, 0);
 //     assert!(false);
 // }
+
+#[test]
+fn format_struct_literal_within_struct_lit_width() {
+    let input = "fn foo() -> Point { Point {\n x: 1,\n y: 2,\n } }";
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn foo() -> Point {
+        Point { x: 1, y: 2 }
+    }
+    ");
+}
+
+#[test]
+fn format_struct_literal_base_last_without_comma() {
+    let input =
+        "fn foo() -> Config { Config { name: name, version: version, ..Default::default() } }";
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn foo() -> Config {
+        Config {
+            name: name,
+            version: version,
+            ..Default::default()
+        }
+    }
+    ");
+}
+
+#[test]
+fn format_struct_literal_field_init_shorthand() {
+    let input = "fn foo() -> Config { Config { name: name, version: other, ..base } }";
+    let config = Config {
+        use_field_init_shorthand: true,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    fn foo() -> Config {
+        Config {
+            name,
+            version: other,
+            ..base
+        }
+    }
+    ");
+}
+
+#[test]
+fn format_struct_literal_nested() {
+    let input = "fn foo() -> Outer { Outer { inner: Inner { alpha: first_value, beta: second_value }, other: Point { x: 1 } } }";
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn foo() -> Outer {
+        Outer {
+            inner: Inner {
+                alpha: first_value,
+                beta: second_value,
+            },
+            other: Point { x: 1 },
+        }
+    }
+    ");
+}
+
+#[test]
+fn format_struct_literal_preserves_comments() {
+    let input = r#"fn foo() -> Foo {
+    Foo { a: a, // trailing a
+        // leading b
+        b,
+        // before base
+        ..base }
+}"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    fn foo() -> Foo {
+        Foo {
+            a: a, // trailing a
+            // leading b
+            b,
+            // before base
+            ..base
+        }
+    }
+    ");
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::{Config, format_source_with_config};
 use insta::assert_snapshot;
 
 #[test]
@@ -65,3 +66,148 @@ fn preserve_enum_mixed_discriminants() {
     }
     "#);
 }
+
+#[test]
+fn align_enum_discriminants_under_threshold() {
+    let input = r#"enum E {
+    A = 1,
+    Bb = 2,
+    Ccc,
+    LongerName = 3,
+}
+"#;
+    let config = Config {
+        enum_discrim_align_threshold: 4,
+        ..Config::default()
+    };
+    let output = format_source_with_config(input, &config);
+    assert_snapshot!(output, @r"
+    enum E {
+        A  = 1,
+        Bb = 2,
+        Ccc,
+        LongerName = 3,
+    }
+    ");
+}
+
+#[test]
+fn short_record_variant_on_one_line() {
+    let input = r#"enum E {
+    Field {
+        ty: Type<'db>,
+        value: &'static str,
+    },
+    Long { first_field: SomeLongType, second: Other },
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    enum E {
+        Field { ty: Type<'db>, value: &'static str },
+        Long {
+            first_field: SomeLongType,
+            second: Other,
+        },
+    }
+    ");
+}
+
+#[test]
+fn long_tuple_variant_wraps_fields() {
+    let input = r#"enum E {
+    Short(u8, #[serde(skip)] pub String),
+    VeryLongTupleVariantName(SomeVeryLongTypeName<WithGenerics>, AnotherVeryLongTypeName, Third, Four),
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    enum E {
+        Short(u8, #[serde(skip)] pub String),
+        VeryLongTupleVariantName(
+            SomeVeryLongTypeName<WithGenerics>,
+            AnotherVeryLongTypeName,
+            Third,
+            Four,
+        ),
+    }
+    ");
+}
+
+#[test]
+fn tuple_variant_preserves_comments() {
+    let input = r#"enum E {
+    A(
+        // leading
+        u8, // trailing
+        /// doc
+        u16,
+        // dangling
+    ),
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    enum E {
+        A(
+            // leading
+            u8, // trailing
+            /// doc
+            u16,
+            // dangling
+        ),
+    }
+    ");
+}
+
+#[test]
+fn tuple_field_comment_before_blank_line_stays_with_next_field() {
+    let input = r#"struct T(
+    u8,
+    // about u16
+
+    u16,
+    // dangling
+);
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    struct T(
+        u8,
+        // about u16
+        u16,
+        // dangling
+    );
+    ");
+}
+
+#[test]
+fn record_field_comment_before_blank_line_is_kept() {
+    let input = r#"struct R {
+    a: u8,
+    // about b
+
+    b: u16,
+}
+"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    struct R {
+        a: u8,
+        // about b
+        b: u16,
+    }
+    ");
+}
+
+#[test]
+fn long_tuple_struct_wraps_fields() {
+    let input = r#"pub struct Pair(pub VeryLongTypeNameNumberOne<Generic>, pub VeryLongTypeNameNumberTwo<OtherGeneric>);"#;
+    let output = format_source(input);
+    assert_snapshot!(output, @r"
+    pub struct Pair(
+        pub VeryLongTypeNameNumberOne<Generic>,
+        pub VeryLongTypeNameNumberTwo<OtherGeneric>,
+    );
+    ");
+}
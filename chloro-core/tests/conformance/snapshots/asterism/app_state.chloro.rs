@@ -223,8 +223,7 @@ impl AppState {
 
         // Try to maintain current position by finding same section
         if let Some(current_section_idx) = self.get_current_section_index() {
-            if let Some(node_idx) = self
-                .tree_nodes
+            if let Some(node_idx) = self.tree_nodes
                 .iter()
                 .position(|n| n.section_index == Some(current_section_idx)) {
                 self.current_node_index = node_idx;
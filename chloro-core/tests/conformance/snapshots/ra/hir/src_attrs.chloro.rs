@@ -246,16 +246,12 @@ fn resolve_assoc_item<'db>(
     name: &Name,
     ns: Option<Namespace>,
 ) -> Option<DocLinkDef> {
-    ty.iterate_assoc_items(
-        db,
-        ty.krate(db),
-        move |assoc_item| {
+    ty.iterate_assoc_items(db, ty.krate(db), move |assoc_item| {
         if assoc_item.name(db)? != *name {
             return None;
         }
         as_module_def_if_namespace_matches(assoc_item, ns)
-    },
-    )
+    })
 }
 
 fn resolve_impl_trait_item<'db>(
@@ -307,7 +303,10 @@ fn resolve_field(
     if let Some(Namespace::Types | Namespace::Macros) = ns {
         return None;
     }
-    def.fields(db).into_iter().find(|f| f.name(db) == name).map(DocLinkDef::Field)
+    def.fields(db)
+        .into_iter()
+        .find(|f| f.name(db) == name)
+        .map(DocLinkDef::Field)
 }
 
 fn as_module_def_if_namespace_matches(
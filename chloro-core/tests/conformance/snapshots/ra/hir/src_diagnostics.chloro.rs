@@ -371,6 +371,7 @@ pub struct TraitImplOrphan {
     pub impl_: AstPtr<ast::Impl>,
 }
 
+// FIXME: Split this off into the corresponding 4 rustc errors
 #[derive(Debug, PartialEq, Eq)]
 pub struct TraitImplIncorrectSafety {
     pub file_id: HirFileId,
@@ -650,10 +651,20 @@ impl<'db> AnyDiagnostic<'db> {
                     ExprOrPatId::PatId(pat) => source_map.pat_field_syntax(pat),
                 };
                 let private = private.map(|id| Field { id, parent: variant.into() });
-                NoSuchField { field: expr_or_pat, private, variant }.into()
+                NoSuchField {
+                    field: expr_or_pat,
+                    private,
+                    variant,
+                }
+                .into()
             }
             &InferenceDiagnostic::MismatchedArgCount { call_expr, expected, found } => {
-                MismatchedArgCount { call_expr: expr_syntax(call_expr)?, expected, found }.into()
+                MismatchedArgCount {
+                    call_expr: expr_syntax(call_expr)?,
+                    expected,
+                    found,
+                }
+                .into()
             }
             &InferenceDiagnostic::PrivateField { expr, field } => {
                 let expr = expr_syntax(expr)?;
@@ -670,7 +681,8 @@ impl<'db> AnyDiagnostic<'db> {
                 ExpectedFunction {
                     call: call_expr,
                     found: Type::new(db, def, *found),
-                }.into()
+                }
+                .into()
             }
             InferenceDiagnostic::UnresolvedField {
                 expr,
@@ -722,11 +734,20 @@ impl<'db> AnyDiagnostic<'db> {
             }
             &InferenceDiagnostic::BreakOutsideOfLoop { expr, is_break, bad_value_break } => {
                 let expr = expr_syntax(expr)?;
-                BreakOutsideOfLoop { expr, is_break, bad_value_break }.into()
+                BreakOutsideOfLoop {
+                    expr,
+                    is_break,
+                    bad_value_break,
+                }
+                .into()
             }
             InferenceDiagnostic::TypedHole { expr, expected } => {
                 let expr = expr_syntax(*expr)?;
-                TypedHole { expr, expected: Type::new(db, def, *expected) }.into()
+                TypedHole {
+                    expr,
+                    expected: Type::new(db, def, *expected),
+                }
+                .into()
             }
             &InferenceDiagnostic::MismatchedTupleStructPatArgCount { pat, expected, found } => {
                 let expr_or_pat = match pat {
@@ -739,17 +760,32 @@ impl<'db> AnyDiagnostic<'db> {
                         InFile { file_id, value: ptr }
                     }
                 };
-                MismatchedTupleStructPatArgCount { expr_or_pat, expected, found }.into()
+                MismatchedTupleStructPatArgCount {
+                    expr_or_pat,
+                    expected,
+                    found,
+                }
+                .into()
             }
             InferenceDiagnostic::CastToUnsized { expr, cast_ty } => {
                 let expr = expr_syntax(*expr)?;
-                CastToUnsized { expr, cast_ty: Type::new(db, def, *cast_ty) }.into()
+                CastToUnsized {
+                    expr,
+                    cast_ty: Type::new(db, def, *cast_ty),
+                }
+                .into()
             }
             InferenceDiagnostic::InvalidCast { expr, error, expr_ty, cast_ty } => {
                 let expr = expr_syntax(*expr)?;
                 let expr_ty = Type::new(db, def, *expr_ty);
                 let cast_ty = Type::new(db, def, *cast_ty);
-                InvalidCast { expr, error: *error, expr_ty, cast_ty }.into()
+                InvalidCast {
+                    expr,
+                    error: *error,
+                    expr_ty,
+                    cast_ty,
+                }
+                .into()
             }
             InferenceDiagnostic::TyDiagnostic { source, diag } => {
                 let source_map = match source {
@@ -812,7 +848,11 @@ impl<'db> AnyDiagnostic<'db> {
                 let provided_arg = hir_generic_arg_to_ast(&generic_args, arg_idx, has_self_arg)?;
                 let provided_arg = InFile::new(file_id, AstPtr::new(&provided_arg));
                 let expected_kind = GenericArgKind::from_id(param_id);
-                IncorrectGenericsOrder { provided_arg, expected_kind }.into()
+                IncorrectGenericsOrder {
+                    provided_arg,
+                    expected_kind,
+                }
+                .into()
             }
         })
     }
@@ -880,15 +920,23 @@ impl<'db> AnyDiagnostic<'db> {
                 let provided_arg = hir_generic_arg_to_ast(&generic_args, arg_idx, has_self_arg)?;
                 let provided_arg = path.with_value(AstPtr::new(&provided_arg));
                 let expected_kind = GenericArgKind::from_id(param_id);
-                IncorrectGenericsOrder { provided_arg, expected_kind }.into()
+                IncorrectGenericsOrder {
+                    provided_arg,
+                    expected_kind,
+                }
+                .into()
             }
             PathLoweringDiagnostic::MissingLifetime { generics_source, expected_count, def }
             | PathLoweringDiagnostic::ElisionFailure { generics_source, expected_count, def } => {
                 let generics_or_segment =
                     path_generics_source_to_ast(&path.value, generics_source)?;
                 let generics_or_segment = path.with_value(AstPtr::new(&generics_or_segment));
-                MissingLifetime { generics_or_segment, expected: expected_count, def: def.into() }
-                    .into()
+                MissingLifetime {
+                    generics_or_segment,
+                    expected: expected_count,
+                    def: def.into(),
+                }
+                .into()
             }
             PathLoweringDiagnostic::ElidedLifetimesInPath {
                 generics_source,
@@ -204,10 +204,20 @@ impl HasSource for Macro {
     fn source(self, db: &dyn HirDatabase) -> Option<InFile<Self::Ast>> {
         match self.id {
             MacroId::Macro2Id(it) => {
-                Some(it.lookup(db).source(db).map(ast::Macro::MacroDef).map(Either::Left))
+                Some(
+                    it.lookup(db)
+                        .source(db)
+                        .map(ast::Macro::MacroDef)
+                        .map(Either::Left),
+                )
             }
             MacroId::MacroRulesId(it) => {
-                Some(it.lookup(db).source(db).map(ast::Macro::MacroRules).map(Either::Left))
+                Some(
+                    it.lookup(db)
+                        .source(db)
+                        .map(ast::Macro::MacroRules)
+                        .map(Either::Left),
+                )
             }
             MacroId::ProcMacroId(it) => Some(it.lookup(db).source(db).map(Either::Right)),
         }
@@ -227,7 +237,9 @@ impl HasSource for TypeOrConstParam {
 
     fn source(self, db: &dyn HirDatabase) -> Option<InFile<Self::Ast>> {
         let child_source = self.id.parent.child_source(db);
-        child_source.map(|it| it.get(self.id.local_id).cloned()).transpose()
+        child_source
+            .map(|it| it.get(self.id.local_id).cloned())
+            .transpose()
     }
 }
 
@@ -236,7 +248,9 @@ impl HasSource for LifetimeParam {
 
     fn source(self, db: &dyn HirDatabase) -> Option<InFile<Self::Ast>> {
         let child_source = self.id.parent.child_source(db);
-        child_source.map(|it| it.get(self.id.local_id).cloned()).transpose()
+        child_source
+            .map(|it| it.get(self.id.local_id).cloned())
+            .transpose()
     }
 }
 
@@ -265,9 +279,7 @@ impl HasSource for Param<'_> {
                 } else {
                     params.params().nth(self.idx).map(Either::Right)
                 }
-                .map(
-                    |value| InFile { file_id, value },
-                )
+                .map(|value| InFile { file_id, value })
             }
             Callee::Closure(closure, _) => {
                 let InternedClosure(owner, expr_id) = db.lookup_intern_closure(closure);
@@ -275,8 +287,7 @@ impl HasSource for Param<'_> {
                 let ast @ InFile { file_id, value } = source_map.expr_syntax(expr_id).ok()?;
                 let root = db.parse_or_expand(file_id);
                 match value.to_node(&root) {
-                    Either::Left(ast::Expr::ClosureExpr(it)) => it
-                        .param_list()?
+                    Either::Left(ast::Expr::ClosureExpr(it)) => it.param_list()?
                         .params()
                         .nth(self.idx)
                         .map(Either::Right)
@@ -222,16 +222,23 @@ impl Crate {
         self,
         db: &dyn HirDatabase,
     ) -> impl Iterator<Item = Crate> {
-        db.transitive_rev_deps(self.id).into_iter().map(|id| Crate { id })
+        db.transitive_rev_deps(self.id)
+            .into_iter()
+            .map(|id| Crate { id })
     }
 
     pub fn root_module(self) -> Module {
-        Module { id: CrateRootModuleId::from(self.id).into() }
+        Module {
+            id: CrateRootModuleId::from(self.id).into(),
+        }
     }
 
     pub fn modules(self, db: &dyn HirDatabase) -> Vec<Module> {
         let def_map = crate_def_map(db, self.id);
-        def_map.modules().map(|(id, _)| def_map.module_id(id).into()).collect()
+        def_map
+            .modules()
+            .map(|(id, _)| def_map.module_id(id).into())
+            .collect()
     }
 
     pub fn root_file(self, db: &dyn HirDatabase) -> FileId {
@@ -256,15 +263,15 @@ impl Crate {
         query: import_map::Query,
     ) -> impl Iterator<Item = (Either<ModuleDef, Macro>, Complete)> {
         let _p = tracing::info_span!("query_external_importables").entered();
-        import_map::search_dependencies(db, self.into(), &query).into_iter().map(
-            |(item, do_not_complete)| {
+        import_map::search_dependencies(db, self.into(), &query)
+            .into_iter()
+            .map(|(item, do_not_complete)| {
                 let item = match ItemInNs::from(item) {
                     ItemInNs::Types(mod_id) | ItemInNs::Values(mod_id) => Either::Left(mod_id),
                     ItemInNs::Macros(mac_id) => Either::Right(mac_id),
                 };
                 (item, do_not_complete)
-            },
-        )
+            })
     }
 
     pub fn all(db: &dyn HirDatabase) -> Vec<Crate> {
@@ -275,7 +282,9 @@ impl Crate {
     pub fn get_html_root_url(self: &Crate, db: &dyn HirDatabase) -> Option<String> {
         // Look for #![doc(html_root_url = "...")]
         let attrs = db.attrs(AttrDefId::ModuleId(self.root_module().into()));
-        let doc_url = attrs.by_key(sym::doc).find_string_value_in_tt(sym::html_root_url);
+        let doc_url = attrs
+            .by_key(sym::doc)
+            .find_string_value_in_tt(sym::html_root_url);
         doc_url.map(|s| s.trim_matches('"').trim_end_matches('/').to_owned() + "/")
     }
 
@@ -285,7 +294,9 @@ impl Crate {
 
     pub fn potential_cfg<'db>(&self, db: &'db dyn HirDatabase) -> &'db CfgOptions {
         let data = self.id.extra_data(db);
-        data.potential_cfg_options.as_ref().unwrap_or_else(|| self.id.cfg_options(db))
+        data.potential_cfg_options
+            .as_ref()
+            .unwrap_or_else(|| self.id.cfg_options(db))
     }
 
     pub fn to_display_target(self, db: &dyn HirDatabase) -> DisplayTarget {
@@ -378,7 +389,8 @@ impl ModuleDef {
         &self,
         db: &dyn HirDatabase,
     ) -> Option<impl Iterator<Item = Module>> {
-        self.module(db).map(|it| it.path_to_root(db).into_iter().rev())
+        self.module(db)
+            .map(|it| it.path_to_root(db).into_iter().rev())
     }
 
     pub fn name(self, db: &dyn HirDatabase) -> Option<Name> {
@@ -520,7 +532,9 @@ impl Module {
 
     /// Returns the crate this module is part of.
     pub fn krate(self) -> Crate {
-        Crate { id: self.id.krate() }
+        Crate {
+            id: self.id.krate(),
+        }
     }
 
     /// Topmost parent of this module. Every module has a `crate_root`, but some
@@ -528,7 +542,9 @@ impl Module {
     /// in the module tree of any target in `Cargo.toml`.
     pub fn crate_root(self, db: &dyn HirDatabase) -> Module {
         let def_map = crate_def_map(db, self.id.krate());
-        Module { id: def_map.crate_root().into() }
+        Module {
+            id: def_map.crate_root().into(),
+        }
     }
 
     pub fn is_crate_root(self) -> bool {
@@ -584,13 +600,19 @@ impl Module {
             .filter_map(|(name, def)| {
                 if let Some(m) = visible_from {
                     let filtered = def.filter_visibility(|vis| vis.is_visible_from(db, m.id));
-                    if filtered.is_none() && !def.is_none() { None } else { Some((name, filtered)) }
+                    if filtered.is_none() && !def.is_none() {
+                        None
+                    } else {
+                        Some((name, filtered))
+                    }
                 } else {
                     Some((name, def))
                 }
             })
             .flat_map(|(name, def)| {
-                ScopeDef::all_items(def).into_iter().map(move |item| (name.clone(), item))
+                ScopeDef::all_items(def)
+                    .into_iter()
+                    .map(move |item| (name.clone(), item))
             })
             .collect()
     }
@@ -731,7 +753,9 @@ impl Module {
                 _ => acc.extend(def.diagnostics(db, style_lints)),
             }
         }
-        self.legacy_macros(db).into_iter().for_each(|m| emit_macro_def_diagnostics(db, acc, m));
+        self.legacy_macros(db)
+            .into_iter()
+            .for_each(|m| emit_macro_def_diagnostics(db, acc, m));
 
         let inherent_impls = db.inherent_impls_in_crate(self.id.krate());
 
@@ -922,19 +946,31 @@ impl Module {
         scope
             .declarations()
             .map(ModuleDef::from)
-            .chain(scope.unnamed_consts().map(|id| ModuleDef::Const(Const::from(id))))
+            .chain(
+                scope
+                    .unnamed_consts()
+                    .map(|id| ModuleDef::Const(Const::from(id))),
+            )
             .collect()
     }
 
     pub fn legacy_macros(self, db: &dyn HirDatabase) -> Vec<Macro> {
         let def_map = self.id.def_map(db);
         let scope = &def_map[self.id.local_id].scope;
-        scope.legacy_macros().flat_map(|(_, it)| it).map(|&it| it.into()).collect()
+        scope
+            .legacy_macros()
+            .flat_map(|(_, it)| it)
+            .map(|&it| it.into())
+            .collect()
     }
 
     pub fn impl_defs(self, db: &dyn HirDatabase) -> Vec<Impl> {
         let def_map = self.id.def_map(db);
-        def_map[self.id.local_id].scope.impls().map(Impl::from).collect()
+        def_map[self.id.local_id]
+            .scope
+            .impls()
+            .map(Impl::from)
+            .collect()
     }
 
     /// Finds a path that can be used to refer to the given item from within
@@ -1002,8 +1038,12 @@ fn macro_call_diagnostics<'db>(
         let loc = db.lookup_intern_macro_call(macro_call_id);
         let (node, precise_location) = precise_macro_call_location(&loc.kind, db);
         acc.push(
-            MacroExpansionParseError { node, precise_location, errors: parse_errors.clone() }
-                .into(),
+            MacroExpansionParseError {
+                node,
+                precise_location,
+                errors: parse_errors.clone(),
+            }
+            .into(),
         )
     }
 }
@@ -1113,7 +1153,9 @@ fn emit_def_diagnostic_<'db>(
         DefDiagnosticKind::UnimplementedBuiltinMacro { ast } => {
             let node = ast.to_node(db);
             // Must have a name, otherwise we wouldn't emit it.
-            let name = node.name().expect("unimplemented builtin macro with no name");
+            let name = node
+                .name()
+                .expect("unimplemented builtin macro with no name");
             acc.push(
                 UnimplementedBuiltinMacro {
                     node: ast.with_value(SyntaxNodePtr::from(AstPtr::new(&name))),
@@ -1177,9 +1219,9 @@ fn precise_macro_call_location(
             (
                 ast_id.with_value(SyntaxNodePtr::from(AstPtr::new(&node))),
                 node.path()
-                    .and_then(|it| it.segment())
-                    .and_then(|it| it.name_ref())
-                    .map(|it| it.syntax().text_range()),
+                .and_then(|it| it.segment())
+                .and_then(|it| it.name_ref())
+                .map(|it| it.syntax().text_range()),
             )
         }
         MacroCallKind::Derive { ast_id, derive_attr_index, derive_index, .. } => {
@@ -1281,7 +1323,10 @@ impl TupleField {
             .get(self.index as usize)
             .copied()
             .unwrap_or_else(|| Ty::new_error(interner, ErrorGuaranteed));
-        Type { env: db.trait_environment_for_body(self.owner), ty }
+        Type {
+            env: db.trait_environment_for_body(self.owner),
+            ty,
+        }
     }
 }
 
@@ -1322,7 +1367,9 @@ impl AstNode for FieldSource {
 
 impl Field {
     pub fn name(&self, db: &dyn HirDatabase) -> Name {
-        VariantId::from(self.parent).fields(db).fields()[self.id].name.clone()
+        VariantId::from(self.parent).fields(db).fields()[self.id]
+            .name
+            .clone()
     }
 
     pub fn index(&self) -> usize {
@@ -1391,7 +1438,9 @@ pub struct Struct {
 
 impl Struct {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.lookup(db).container }
+        Module {
+            id: self.id.lookup(db).container,
+        }
     }
 
     pub fn name(self, db: &dyn HirDatabase) -> Name {
@@ -1493,7 +1542,9 @@ impl Union {
     }
 
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.lookup(db).container }
+        Module {
+            id: self.id.lookup(db).container,
+        }
     }
 
     pub fn ty(self, db: &dyn HirDatabase) -> Type<'_> {
@@ -1545,7 +1596,9 @@ pub struct Enum {
 
 impl Enum {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.lookup(db).container }
+        Module {
+            id: self.id.lookup(db).container,
+        }
     }
 
     pub fn name(self, db: &dyn HirDatabase) -> Name {
@@ -1553,7 +1606,12 @@ impl Enum {
     }
 
     pub fn variants(self, db: &dyn HirDatabase) -> Vec<Variant> {
-        self.id.enum_variants(db).variants.iter().map(|&(id, _, _)| Variant { id }).collect()
+        self.id
+            .enum_variants(db)
+            .variants
+            .iter()
+            .map(|&(id, _, _)| Variant { id })
+            .collect()
     }
 
     pub fn num_variants(self, db: &dyn HirDatabase) -> usize {
@@ -1583,26 +1641,20 @@ impl Enum {
                     false => Ty::new_uint(interner, rustc_type_ir::UintTy::Usize),
                 },
                 layout::IntegerType::Fixed(i, sign) => match sign {
-                    true => Ty::new_int(
-                        interner,
-                        match i {
-                            layout::Integer::I8 => rustc_type_ir::IntTy::I8,
-                            layout::Integer::I16 => rustc_type_ir::IntTy::I16,
-                            layout::Integer::I32 => rustc_type_ir::IntTy::I32,
-                            layout::Integer::I64 => rustc_type_ir::IntTy::I64,
-                            layout::Integer::I128 => rustc_type_ir::IntTy::I128,
-                        },
-                    ),
-                    false => Ty::new_uint(
-                        interner,
-                        match i {
-                            layout::Integer::I8 => rustc_type_ir::UintTy::U8,
-                            layout::Integer::I16 => rustc_type_ir::UintTy::U16,
-                            layout::Integer::I32 => rustc_type_ir::UintTy::U32,
-                            layout::Integer::I64 => rustc_type_ir::UintTy::U64,
-                            layout::Integer::I128 => rustc_type_ir::UintTy::U128,
-                        },
-                    ),
+                    true => Ty::new_int(interner, match i {
+                        layout::Integer::I8 => rustc_type_ir::IntTy::I8,
+                        layout::Integer::I16 => rustc_type_ir::IntTy::I16,
+                        layout::Integer::I32 => rustc_type_ir::IntTy::I32,
+                        layout::Integer::I64 => rustc_type_ir::IntTy::I64,
+                        layout::Integer::I128 => rustc_type_ir::IntTy::I128,
+                    }),
+                    false => Ty::new_uint(interner, match i {
+                        layout::Integer::I8 => rustc_type_ir::UintTy::U8,
+                        layout::Integer::I16 => rustc_type_ir::UintTy::U16,
+                        layout::Integer::I32 => rustc_type_ir::UintTy::U32,
+                        layout::Integer::I64 => rustc_type_ir::UintTy::U64,
+                        layout::Integer::I128 => rustc_type_ir::UintTy::U128,
+                    }),
                 },
             },
         )
@@ -1610,7 +1662,9 @@ impl Enum {
 
     /// Returns true if at least one variant of this enum is a non-unit variant.
     pub fn is_data_carrying(self, db: &dyn HirDatabase) -> bool {
-        self.variants(db).iter().any(|v| !matches!(v.kind(db), StructKind::Unit))
+        self.variants(db)
+            .iter()
+            .any(|v| !matches!(v.kind(db), StructKind::Unit))
     }
 
     pub fn layout(self, db: &dyn HirDatabase) -> Result<Layout, LayoutError> {
@@ -1652,6 +1706,7 @@ impl From<&Variant> for DefWithBodyId {
     }
 }
 
+// FIXME: Rename to `EnumVariant`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Variant {
     pub(crate) id: EnumVariantId,
@@ -1659,7 +1714,9 @@ pub struct Variant {
 
 impl Variant {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.module(db) }
+        Module {
+            id: self.id.module(db),
+        }
     }
 
     pub fn parent_enum(self, db: &dyn HirDatabase) -> Enum {
@@ -1673,7 +1730,9 @@ impl Variant {
     pub fn name(self, db: &dyn HirDatabase) -> Name {
         let lookup = self.id.lookup(db);
         let enum_ = lookup.parent;
-        enum_.enum_variants(db).variants[lookup.index as usize].1.clone()
+        enum_.enum_variants(db).variants[lookup.index as usize]
+            .1
+            .clone()
     }
 
     pub fn fields(self, db: &dyn HirDatabase) -> Vec<Field> {
@@ -1728,6 +1787,7 @@ impl Variant {
     }
 }
 
+// FIXME: Rename to `EnumVariant`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct InstantiatedVariant<'db> {
     pub(crate) inner: Variant,
@@ -2261,7 +2321,9 @@ impl Function {
         let resolver = self.id.resolver(db);
         let interner = DbInterner::new_with(db, None, None);
         // FIXME: This shouldn't be `instantiate_identity()`, we shouldn't leak `TyKind::Param`s.
-        let callable_sig = db.callable_item_signature(self.id.into()).instantiate_identity();
+        let callable_sig = db
+            .callable_item_signature(self.id.into())
+            .instantiate_identity();
         let ty = Ty::new_fn_ptr(interner, callable_sig);
         Type::new_with_resolver_inner(db, &resolver, ty)
     }
@@ -2325,7 +2387,8 @@ impl Function {
     }
 
     pub fn self_param(self, db: &dyn HirDatabase) -> Option<SelfParam> {
-        self.has_self_param(db).then_some(SelfParam { func: self.id })
+        self.has_self_param(db)
+            .then_some(SelfParam { func: self.id })
     }
 
     pub fn assoc_fn_params(self, db: &dyn HirDatabase) -> Vec<Param<'_>> {
@@ -2339,7 +2402,11 @@ impl Function {
             .enumerate()
             .map(|(idx, ty)| {
                 let ty = Type { env: environment.clone(), ty };
-                Param { func: Callee::Def(CallableDefId::FunctionId(self.id)), ty, idx }
+                Param {
+                    func: Callee::Def(CallableDefId::FunctionId(self.id)),
+                    ty,
+                    idx,
+                }
             })
             .collect()
     }
@@ -2366,7 +2433,11 @@ impl Function {
             .skip(skip)
             .map(|(idx, ty)| {
                 let ty = Type { env: environment.clone(), ty };
-                Param { func: Callee::Def(CallableDefId::FunctionId(self.id)), ty, idx }
+                Param {
+                    func: Callee::Def(CallableDefId::FunctionId(self.id)),
+                    ty,
+                    idx,
+                }
             })
             .collect()
     }
@@ -2390,7 +2461,11 @@ impl Function {
             .skip(skip)
             .map(|(idx, ty)| {
                 let ty = Type { env: environment.clone(), ty };
-                Param { func: Callee::Def(CallableDefId::FunctionId(self.id)), ty, idx }
+                Param {
+                    func: Callee::Def(CallableDefId::FunctionId(self.id)),
+                    ty,
+                    idx,
+                }
             })
             .collect()
     }
@@ -2448,8 +2523,7 @@ impl Function {
 
     /// is this a `fn main` or a function with an `export_name` of `main`?
     pub fn is_main(self, db: &dyn HirDatabase) -> bool {
-        db.attrs(self.id.into()).export_name() == Some(&sym::main)
-            || self.module(db).is_crate_root() && db.function_signature(self.id).name == sym::main
+        db.attrs(self.id.into()).export_name() == Some(&sym::main) || self.module(db).is_crate_root() && db.function_signature(self.id).name == sym::main
     }
 
     /// Is this a function with an `export_name` of `main`?
@@ -2521,7 +2595,9 @@ impl Function {
             return None;
         }
         let def_map = crate_def_map(db, HasModule::krate(&self.id, db));
-        def_map.fn_as_proc_macro(self.id).map(|id| Macro { id: id.into() })
+        def_map
+            .fn_as_proc_macro(self.id)
+            .map(|id| Macro { id: id.into() })
     }
 
     pub fn eval(
@@ -2563,6 +2639,7 @@ impl Function {
     }
 }
 
+// Note: logically, this belongs to `hir_ty`, but we are not using it there yet.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Access {
     Shared,
@@ -2619,10 +2696,15 @@ impl<'db> Param<'db> {
                 let parent = DefWithBodyId::FunctionId(it);
                 let body = db.body(parent);
                 if let Some(self_param) = body.self_param.filter(|_| self.idx == 0) {
-                    Some(Local { parent, binding_id: self_param })
-                } else if let Pat::Bind { id, .. } =
-                    &body[body.params[self.idx - body.self_param.is_some() as usize]] {
-                    Some(Local { parent, binding_id: *id })
+                    Some(Local {
+                        parent,
+                        binding_id: self_param,
+                    })
+                } else if let Pat::Bind { id, .. } = &body[body.params[self.idx - body.self_param.is_some() as usize]] {
+                    Some(Local {
+                        parent,
+                        binding_id: *id,
+                    })
                 } else {
                     None
                 }
@@ -2677,7 +2759,10 @@ impl SelfParam {
             db.callable_item_signature(self.func.into()).instantiate_identity().skip_binder();
         let environment = db.trait_environment(self.func.into());
         let ty = callable_sig.inputs().as_slice()[0];
-        Type { env: environment, ty }
+        Type {
+            env: environment,
+            ty,
+        }
     }
 
     // FIXME: Find better API to also handle const generics
@@ -2692,7 +2777,10 @@ impl SelfParam {
             db.callable_item_signature(self.func.into()).instantiate(interner, args).skip_binder();
         let environment = db.trait_environment(self.func.into());
         let ty = callable_sig.inputs().as_slice()[0];
-        Type { env: environment, ty }
+        Type {
+            env: environment,
+            ty,
+        }
     }
 }
 
@@ -2720,7 +2808,11 @@ impl ExternCrateDecl {
             Some(krate.into())
         } else {
             krate.data(db).dependencies.iter().find_map(|dep| {
-                if dep.name.symbol() == name.symbol() { Some(dep.crate_id.into()) } else { None }
+                if dep.name.symbol() == name.symbol() {
+                    Some(dep.crate_id.into())
+                } else {
+                    None
+                }
             })
         }
     }
@@ -2769,7 +2861,9 @@ pub struct Const {
 
 impl Const {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.module(db) }
+        Module {
+            id: self.id.module(db),
+        }
     }
 
     pub fn name(self, db: &dyn HirDatabase) -> Option<Name> {
@@ -2839,7 +2933,9 @@ pub struct Static {
 
 impl Static {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.module(db) }
+        Module {
+            id: self.id.module(db),
+        }
     }
 
     pub fn name(self, db: &dyn HirDatabase) -> Name {
@@ -2847,7 +2943,9 @@ impl Static {
     }
 
     pub fn is_mut(self, db: &dyn HirDatabase) -> bool {
-        db.static_signature(self.id).flags.contains(StaticFlags::MUTABLE)
+        db.static_signature(self.id)
+            .flags
+            .contains(StaticFlags::MUTABLE)
     }
 
     pub fn value(self, db: &dyn HirDatabase) -> Option<ast::Expr> {
@@ -2889,11 +2987,15 @@ pub struct Trait {
 
 impl Trait {
     pub fn lang(db: &dyn HirDatabase, krate: Crate, name: &Name) -> Option<Trait> {
-        LangItem::from_name(name)?.resolve_trait(db, krate.into()).map(Into::into)
+        LangItem::from_name(name)?
+            .resolve_trait(db, krate.into())
+            .map(Into::into)
     }
 
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.lookup(db).container }
+        Module {
+            id: self.id.lookup(db).container,
+        }
     }
 
     pub fn name(self, db: &dyn HirDatabase) -> Name {
@@ -2911,7 +3013,12 @@ impl Trait {
     }
 
     pub fn function(self, db: &dyn HirDatabase, name: impl PartialEq<Name>) -> Option<Function> {
-        self.id.trait_items(db).items.iter().find(|(n, _)| name == *n).and_then(|&(_, it)| match it
+        self.id
+            .trait_items(db)
+            .items
+            .iter()
+            .find(|(n, _)| name == *n)
+            .and_then(|&(_, it)| match it
         {
             AssocItemId::FunctionId(id) => Some(Function { id }),
             _ => None,
@@ -2919,11 +3026,19 @@ impl Trait {
     }
 
     pub fn items(self, db: &dyn HirDatabase) -> Vec<AssocItem> {
-        self.id.trait_items(db).items.iter().map(|(_name, it)| (*it).into()).collect()
+        self.id
+            .trait_items(db)
+            .items
+            .iter()
+            .map(|(_name, it)| (*it).into())
+            .collect()
     }
 
     pub fn items_with_supertraits(self, db: &dyn HirDatabase) -> Vec<AssocItem> {
-        self.all_supertraits(db).into_iter().flat_map(|tr| tr.items(db)).collect()
+        self.all_supertraits(db)
+            .into_iter()
+            .flat_map(|tr| tr.items(db))
+            .collect()
     }
 
     pub fn is_auto(self, db: &dyn HirDatabase) -> bool {
@@ -2931,7 +3046,9 @@ impl Trait {
     }
 
     pub fn is_unsafe(&self, db: &dyn HirDatabase) -> bool {
-        db.trait_signature(self.id).flags.contains(TraitFlags::UNSAFE)
+        db.trait_signature(self.id)
+            .flags
+            .contains(TraitFlags::UNSAFE)
     }
 
     pub fn type_or_const_param_count(
@@ -2941,7 +3058,9 @@ impl Trait {
     ) -> usize {
         db.generic_params(self.id.into())
             .iter_type_or_consts()
-            .filter(|(_, ty)| !matches!(ty, TypeOrConstParamData::TypeParamData(ty) if ty.provenance != TypeParamProvenance::TypeParamList))
+            .filter(
+                |(_, ty)| !matches!(ty, TypeOrConstParamData::TypeParamData(ty) if ty.provenance != TypeParamProvenance::TypeParamList),
+            )
             .filter(|(_, ty)| !count_required_only || !ty.has_default())
             .count()
     }
@@ -2967,7 +3086,11 @@ impl Trait {
     }
 
     fn all_macro_calls(&self, db: &dyn HirDatabase) -> Box<[(AstId<ast::Item>, MacroCallId)]> {
-        self.id.trait_items(db).macro_calls.to_vec().into_boxed_slice()
+        self.id
+            .trait_items(db)
+            .macro_calls
+            .to_vec()
+            .into_boxed_slice()
     }
 
     /// `#[rust_analyzer::completions(...)]` mode.
@@ -2995,7 +3118,9 @@ impl TypeAlias {
     }
 
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.module(db) }
+        Module {
+            id: self.id.module(db),
+        }
     }
 
     pub fn ty(self, db: &dyn HirDatabase) -> Type<'_> {
@@ -3024,7 +3149,9 @@ pub struct ExternBlock {
 
 impl ExternBlock {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.module(db) }
+        Module {
+            id: self.id.module(db),
+        }
     }
 }
 
@@ -3045,7 +3172,9 @@ pub struct BuiltinType {
 impl BuiltinType {
     // Constructors are added on demand, feel free to add more.
     pub fn str() -> BuiltinType {
-        BuiltinType { inner: hir_def::builtin_type::BuiltinType::Str }
+        BuiltinType {
+            inner: hir_def::builtin_type::BuiltinType::Str,
+        }
     }
 
     pub fn i32() -> BuiltinType {
@@ -3055,7 +3184,9 @@ impl BuiltinType {
     }
 
     pub fn ty<'db>(self, db: &'db dyn HirDatabase) -> Type<'db> {
-        let core = Crate::core(db).map(|core| core.id).unwrap_or_else(|| db.all_crates()[0]);
+        let core = Crate::core(db)
+            .map(|core| core.id)
+            .unwrap_or_else(|| db.all_crates()[0]);
         let interner = DbInterner::new_with(db, None, None);
         Type::new_for_crate(core, Ty::from_builtin_type(interner, self.inner))
     }
@@ -3124,7 +3255,9 @@ pub struct Macro {
 
 impl Macro {
     pub fn module(self, db: &dyn HirDatabase) -> Module {
-        Module { id: self.id.module(db) }
+        Module {
+            id: self.id.module(db),
+        }
     }
 
     pub fn name(self, db: &dyn HirDatabase) -> Name {
@@ -3143,8 +3276,7 @@ impl Macro {
                 let loc = id.lookup(db);
                 let source = loc.source(db);
                 match loc.kind {
-                    ProcMacroKind::CustomDerive => db
-                        .attrs(id.into())
+                    ProcMacroKind::CustomDerive => db.attrs(id.into())
                         .parse_proc_macro_derive()
                         .map_or_else(|| as_name_opt(source.value.name()), |(it, _)| it),
                     ProcMacroKind::Bang | ProcMacroKind::Attr => as_name_opt(source.value.name()),
@@ -3730,6 +3862,7 @@ impl GenericDef {
     }
 }
 
+// We cannot call this `Substitution` unfortunately...
 #[derive(Debug)]
 pub struct GenericSubstitution<'db> {
     def: GenericDefId,
@@ -3765,7 +3898,9 @@ impl<'db> GenericSubstitution<'db> {
                     .collect::<Vec<_>>()
             });
         let generics = db.generic_params(self.def);
-        let type_params = generics.iter_type_or_consts().filter_map(|param| match param.1 {
+        let type_params = generics
+            .iter_type_or_consts()
+            .filter_map(|param| match param.1 {
             TypeOrConstParamData::TypeParamData(param) => Some(param.name.clone()),
             TypeOrConstParamData::ConstParamData(_) => None,
         });
@@ -3785,7 +3920,15 @@ impl<'db> GenericSubstitution<'db> {
         container_params
             .chain(self_params)
             .filter_map(|(ty, name)| {
-                Some((name?.symbol().clone(), Type { ty, env: self.env.clone() }))
+                Some(
+                    (
+                        name?.symbol().clone(),
+                        Type {
+                        ty,
+                        env: self.env.clone(),
+                    },
+                    ),
+                )
             })
             .collect()
     }
@@ -3844,8 +3987,7 @@ impl Local {
         // FIXME: This parses!
         let src = self.primary_source(db);
         match src.source.value {
-            Either::Left(pat) => pat
-                .syntax()
+            Either::Left(pat) => pat.syntax()
                 .ancestors()
                 .map(|it| it.kind())
                 .take_while(|&kind| ast::Pat::can_cast(kind) || ast::Param::can_cast(kind))
@@ -3979,19 +4121,15 @@ impl DeriveHelper {
 
     pub fn name(&self, db: &dyn HirDatabase) -> Name {
         match self.derive {
-            makro @ MacroId::Macro2Id(_) => db
-                .attrs(makro.into())
+            makro @ MacroId::Macro2Id(_) => db.attrs(makro.into())
                 .parse_rustc_builtin_macro()
                 .and_then(|(_, helpers)| helpers.get(self.idx as usize).cloned()),
             MacroId::MacroRulesId(_) => None,
-            makro @ MacroId::ProcMacroId(_) => db
-                .attrs(makro.into())
+            makro @ MacroId::ProcMacroId(_) => db.attrs(makro.into())
                 .parse_proc_macro_derive()
                 .and_then(|(_, helpers)| helpers.get(self.idx as usize).cloned()),
         }
-        .unwrap_or_else(
-            Name::missing,
-        )
+        .unwrap_or_else(Name::missing)
     }
 }
 
@@ -4003,9 +4141,7 @@ pub struct BuiltinAttr {
 impl BuiltinAttr {
     fn builtin(name: &str) -> Option<Self> {
         hir_expand::inert_attr_macro::find_builtin_attr_idx(&Symbol::intern(name))
-        .map(
-            |idx| BuiltinAttr { idx: idx as u32 },
-        )
+            .map(|idx| BuiltinAttr { idx: idx as u32 })
     }
 
     pub fn name(&self) -> Name {
@@ -4316,10 +4452,14 @@ impl TypeOrConstParam {
         let params = db.generic_params(self.id.parent);
         match &params[self.id.local_id] {
             TypeOrConstParamData::TypeParamData(_) => {
-                Either::Right(TypeParam { id: TypeParamId::from_unchecked(self.id) })
+                Either::Right(TypeParam {
+                    id: TypeParamId::from_unchecked(self.id),
+                })
             }
             TypeOrConstParamData::ConstParamData(_) => {
-                Either::Left(ConstParam { id: ConstParamId::from_unchecked(self.id) })
+                Either::Left(ConstParam {
+                    id: ConstParamId::from_unchecked(self.id),
+                })
             }
         }
     }
@@ -4335,7 +4475,9 @@ impl TypeOrConstParam {
         let params = db.generic_params(self.id.parent);
         match &params[self.id.local_id] {
             TypeOrConstParamData::TypeParamData(_) => {
-                Some(TypeParam { id: TypeParamId::from_unchecked(self.id) })
+                Some(TypeParam {
+                    id: TypeParamId::from_unchecked(self.id),
+                })
             }
             TypeOrConstParamData::ConstParamData(_) => None,
         }
@@ -4346,7 +4488,9 @@ impl TypeOrConstParam {
         match &params[self.id.local_id] {
             TypeOrConstParamData::TypeParamData(_) => None,
             TypeOrConstParamData::ConstParamData(_) => {
-                Some(ConstParam { id: ConstParamId::from_unchecked(self.id) })
+                Some(ConstParam {
+                    id: ConstParamId::from_unchecked(self.id),
+                })
             }
         }
     }
@@ -4362,11 +4506,19 @@ impl Impl {
         let inherent = db.inherent_impls_in_crate(krate.id);
         let trait_ = db.trait_impls_in_crate(krate.id);
 
-        inherent.all_impls().chain(trait_.all_impls()).map(Self::from).collect()
+        inherent
+            .all_impls()
+            .chain(trait_.all_impls())
+            .map(Self::from)
+            .collect()
     }
 
     pub fn all_in_module(db: &dyn HirDatabase, module: Module) -> Vec<Impl> {
-        module.id.def_map(db)[module.id.local_id].scope.impls().map(Into::into).collect()
+        module.id.def_map(db)[module.id.local_id]
+            .scope
+            .impls()
+            .map(Into::into)
+            .collect()
     }
 
     pub fn all_for_type<'db>(db: &'db dyn HirDatabase, Type { ty, env }: Type<'db>) -> Vec<Impl> {
@@ -4467,11 +4619,18 @@ impl Impl {
     }
 
     pub fn items(self, db: &dyn HirDatabase) -> Vec<AssocItem> {
-        self.id.impl_items(db).items.iter().map(|&(_, it)| it.into()).collect()
+        self.id
+            .impl_items(db)
+            .items
+            .iter()
+            .map(|&(_, it)| it.into())
+            .collect()
     }
 
     pub fn is_negative(self, db: &dyn HirDatabase) -> bool {
-        db.impl_signature(self.id).flags.contains(ImplFlags::NEGATIVE)
+        db.impl_signature(self.id)
+            .flags
+            .contains(ImplFlags::NEGATIVE)
     }
 
     pub fn is_unsafe(self, db: &dyn HirDatabase) -> bool {
@@ -4519,7 +4678,11 @@ impl Impl {
     }
 
     fn all_macro_calls(&self, db: &dyn HirDatabase) -> Box<[(AstId<ast::Item>, MacroCallId)]> {
-        self.id.impl_items(db).macro_calls.to_vec().into_boxed_slice()
+        self.id
+            .impl_items(db)
+            .macro_calls
+            .to_vec()
+            .into_boxed_slice()
     }
 }
 
@@ -4542,12 +4705,17 @@ impl<'db> TraitRef<'db> {
     }
 
     pub fn trait_(&self) -> Trait {
-        Trait { id: self.trait_ref.def_id.0 }
+        Trait {
+            id: self.trait_ref.def_id.0,
+        }
     }
 
     pub fn self_ty(&self) -> TypeNs<'_> {
         let ty = self.trait_ref.self_ty();
-        TypeNs { env: self.env.clone(), ty }
+        TypeNs {
+            env: self.env.clone(),
+            ty,
+        }
     }
 
     /// Returns `idx`-th argument of this trait reference if it is a type argument. Note that the
@@ -4800,11 +4968,17 @@ impl<'db> Type<'db> {
         let environment = resolver
             .generic_def()
             .map_or_else(|| TraitEnvironment::empty(resolver.krate()), |d| db.trait_environment(d));
-        Type { env: environment, ty }
+        Type {
+            env: environment,
+            ty,
+        }
     }
 
     pub(crate) fn new_for_crate(krate: base_db::Crate, ty: Ty<'db>) -> Self {
-        Type { env: TraitEnvironment::empty(krate), ty }
+        Type {
+            env: TraitEnvironment::empty(krate),
+            ty,
+        }
     }
 
     fn new(db: &'db dyn HirDatabase, lexical_env: impl HasResolver, ty: Ty<'db>) -> Self {
@@ -4812,7 +4986,10 @@ impl<'db> Type<'db> {
         let environment = resolver
             .generic_def()
             .map_or_else(|| TraitEnvironment::empty(resolver.krate()), |d| db.trait_environment(d));
-        Type { env: environment, ty }
+        Type {
+            env: environment,
+            ty,
+        }
     }
 
     fn from_def(db: &'db dyn HirDatabase, def: impl Into<TyDefId> + HasResolver) -> Self {
@@ -4927,9 +5104,7 @@ impl<'db> Type<'db> {
                     TyKind::Ref(..) => ControlFlow::Break(()),
 
                     // For non-phantom_data adts we check variants/fields as well as generic parameters
-                    TyKind::Adt(adt_def, args)
-                        if !is_phantom_data(self.interner.db(), adt_def.def_id().0) =>
-                    {
+                    TyKind::Adt(adt_def, args) if !is_phantom_data(self.interner.db(), adt_def.def_id().0) => {
                         let _variant_id_to_fields = |id: VariantId| {
                             let variant_data = &id.fields(self.interner.db());
                             if variant_data.fields().is_empty() {
@@ -5095,13 +5270,13 @@ impl<'db> Type<'db> {
 
     /// Resolves the projection `<Self as IntoIterator>::IntoIter` and returns the resulting type
     pub fn into_iterator_iter(self, db: &'db dyn HirDatabase) -> Option<Type<'db>> {
-        let trait_ = LangItem::IntoIterIntoIter.resolve_function(db, self.env.krate).and_then(
-            |into_iter_fn| {
+        let trait_ = LangItem::IntoIterIntoIter
+            .resolve_function(db, self.env.krate)
+            .and_then(|into_iter_fn| {
                 let assoc_item = as_assoc_item(db, AssocItem::Function, into_iter_fn)?;
                 let into_iter_trait = assoc_item.container_or_implemented_trait(db)?;
                 Some(into_iter_trait.id)
-            },
-        )?;
+            })?;
 
         if !traits::implements_trait_unique(self.ty, db, self.env.clone(), trait_) {
             return None;
@@ -5199,7 +5374,12 @@ impl<'db> Type<'db> {
         };
 
         let sig = self.ty.callable_sig(interner)?;
-        Some(Callable { ty: self.clone(), sig, callee, is_bound_method: false })
+        Some(Callable {
+            ty: self.clone(),
+            sig,
+            callee,
+            is_bound_method: false,
+        })
     }
 
     pub fn is_closure(&self) -> bool {
@@ -5209,10 +5389,16 @@ impl<'db> Type<'db> {
     pub fn as_closure(&self) -> Option<Closure<'db>> {
         match self.ty.kind() {
             TyKind::Closure(id, subst) => {
-                Some(Closure { id: AnyClosureId::ClosureId(id.0), subst })
+                Some(Closure {
+                    id: AnyClosureId::ClosureId(id.0),
+                    subst,
+                })
             }
             TyKind::CoroutineClosure(id, subst) => {
-                Some(Closure { id: AnyClosureId::CoroutineClosureId(id.0), subst })
+                Some(Closure {
+                    id: AnyClosureId::CoroutineClosureId(id.0),
+                    subst,
+                })
             }
             _ => None,
         }
@@ -5687,7 +5873,8 @@ impl<'db> Type<'db> {
 
     pub fn as_impl_traits(&self, db: &'db dyn HirDatabase) -> Option<impl Iterator<Item = Trait>> {
         self.ty.impl_trait_bounds(db).map(|it| {
-            it.into_iter().filter_map(|pred| match pred.kind().skip_binder() {
+            it.into_iter()
+                .filter_map(|pred| match pred.kind().skip_binder() {
                 ClauseKind::Trait(trait_ref) => Some(Trait::from(trait_ref.def_id().0)),
                 _ => None,
             })
@@ -5703,7 +5890,10 @@ impl<'db> Type<'db> {
     }
 
     fn derived(&self, ty: Ty<'db>) -> Self {
-        Type { env: self.env.clone(), ty }
+        Type {
+            env: self.env.clone(),
+            ty,
+        }
     }
 
     /// Visits every type, including generic arguments, in this type. `callback` is called with type
@@ -5806,7 +5996,10 @@ impl<'db> TypeNs<'db> {
         let environment = resolver
             .generic_def()
             .map_or_else(|| TraitEnvironment::empty(resolver.krate()), |d| db.trait_environment(d));
-        TypeNs { env: environment, ty }
+        TypeNs {
+            env: environment,
+            ty,
+        }
     }
 
     pub fn to_type(&self, _db: &'db dyn HirDatabase) -> Type<'db> {
@@ -5865,6 +6058,7 @@ impl InlineAsmOperand {
     }
 }
 
+// FIXME: Document this
 #[derive(Debug)]
 pub struct Callable<'db> {
     ty: Type<'db>,
@@ -5901,10 +6095,16 @@ impl<'db> Callable<'db> {
                 CallableKind::TupleEnumVariant(it.into())
             }
             Callee::Closure(id, subst) => {
-                CallableKind::Closure(Closure { id: AnyClosureId::ClosureId(id), subst })
+                CallableKind::Closure(Closure {
+                    id: AnyClosureId::ClosureId(id),
+                    subst,
+                })
             }
             Callee::CoroutineClosure(id, subst) => {
-                CallableKind::Closure(Closure { id: AnyClosureId::CoroutineClosureId(id), subst })
+                CallableKind::Closure(Closure {
+                    id: AnyClosureId::CoroutineClosureId(id),
+                    subst,
+                })
             }
             Callee::FnPtr => CallableKind::FnPtr,
             Callee::FnImpl(fn_) => CallableKind::FnImpl(fn_),
@@ -5917,15 +6117,21 @@ impl<'db> Callable<'db> {
             _ => return None,
         };
         let func = Function { id: func };
-        Some((
-            func.self_param(db)?,
-            self.ty.derived(self.sig.skip_binder().inputs_and_output.inputs()[0]),
-        ))
+        Some(
+            (
+                func.self_param(db)?,
+                self.ty
+                .derived(self.sig.skip_binder().inputs_and_output.inputs()[0]),
+            ),
+        )
     }
 
     pub fn n_params(&self) -> usize {
-        self.sig.skip_binder().inputs_and_output.inputs().len()
-            - if self.is_bound_method { 1 } else { 0 }
+        self.sig.skip_binder().inputs_and_output.inputs().len() - if self.is_bound_method {
+            1
+        } else {
+            0
+        }
     }
 
     pub fn params(&self) -> Vec<Param<'db>> {
@@ -5935,7 +6141,11 @@ impl<'db> Callable<'db> {
             .inputs()
             .iter()
             .enumerate()
-            .skip(if self.is_bound_method { 1 } else { 0 })
+            .skip(if self.is_bound_method {
+                1
+            } else {
+                0
+            })
             .map(|(idx, ty)| (idx, self.ty.derived(*ty)))
             .map(|(idx, ty)| Param { func: self.callee.clone(), idx, ty })
             .collect()
@@ -6005,13 +6215,21 @@ impl Layout {
             layout::FieldsShape::Array { stride, count } => count.checked_sub(1).and_then(|tail| {
                 let tail_field_size = field_size(tail as usize)?;
                 let offset = stride.bytes() * tail;
-                self.0.size.bytes().checked_sub(offset)?.checked_sub(tail_field_size)
+                self.0
+                    .size
+                    .bytes()
+                    .checked_sub(offset)?
+                    .checked_sub(tail_field_size)
             }),
             layout::FieldsShape::Arbitrary { ref offsets, ref memory_index } => {
                 let tail = memory_index.last_index()?;
                 let tail_field_size = field_size(tail.0.into_raw().into_u32() as usize)?;
                 let offset = offsets.get(tail)?.bytes();
-                self.0.size.bytes().checked_sub(offset)?.checked_sub(tail_field_size)
+                self.0
+                    .size
+                    .bytes()
+                    .checked_sub(offset)?
+                    .checked_sub(tail_field_size)
             }
         }
     }
@@ -6310,7 +6528,9 @@ impl HasContainer for Module {
         // FIXME: handle block expressions as modules (their parent is in a different DefMap)
         let def_map = self.id.def_map(db);
         match def_map[self.id.local_id].parent {
-            Some(parent_id) => ItemContainer::Module(Module { id: def_map.module_id(parent_id) }),
+            Some(parent_id) => ItemContainer::Module(Module {
+                id: def_map.module_id(parent_id),
+            }),
             None => ItemContainer::Crate(def_map.krate().into()),
         }
     }
@@ -6324,19 +6544,25 @@ impl HasContainer for Function {
 
 impl HasContainer for Struct {
     fn container(&self, db: &dyn HirDatabase) -> ItemContainer {
-        ItemContainer::Module(Module { id: self.id.lookup(db).container })
+        ItemContainer::Module(Module {
+            id: self.id.lookup(db).container,
+        })
     }
 }
 
 impl HasContainer for Union {
     fn container(&self, db: &dyn HirDatabase) -> ItemContainer {
-        ItemContainer::Module(Module { id: self.id.lookup(db).container })
+        ItemContainer::Module(Module {
+            id: self.id.lookup(db).container,
+        })
     }
 }
 
 impl HasContainer for Enum {
     fn container(&self, db: &dyn HirDatabase) -> ItemContainer {
-        ItemContainer::Module(Module { id: self.id.lookup(db).container })
+        ItemContainer::Module(Module {
+            id: self.id.lookup(db).container,
+        })
     }
 }
 
@@ -6360,13 +6586,17 @@ impl HasContainer for Static {
 
 impl HasContainer for Trait {
     fn container(&self, db: &dyn HirDatabase) -> ItemContainer {
-        ItemContainer::Module(Module { id: self.id.lookup(db).container })
+        ItemContainer::Module(Module {
+            id: self.id.lookup(db).container,
+        })
     }
 }
 
 impl HasContainer for ExternBlock {
     fn container(&self, db: &dyn HirDatabase) -> ItemContainer {
-        ItemContainer::Module(Module { id: self.id.lookup(db).container })
+        ItemContainer::Module(Module {
+            id: self.id.lookup(db).container,
+        })
     }
 }
 
@@ -6504,10 +6734,7 @@ fn generic_args_from_tys<'db>(
     args: impl IntoIterator<Item = Ty<'db>>,
 ) -> GenericArgs<'db> {
     let mut args = args.into_iter();
-    GenericArgs::for_item(
-        interner,
-        def_id,
-        |_, id, _| {
+    GenericArgs::for_item(interner, def_id, |_, id, _| {
         if matches!(id, GenericParamId::TypeParamId(_))
             && let Some(arg) = args.next()
         {
@@ -6515,8 +6742,7 @@ fn generic_args_from_tys<'db>(
         } else {
             next_solver::GenericArg::error_from_id(interner, id)
         }
-    },
-    )
+    })
 }
 
 fn has_non_default_type_params(db: &dyn HirDatabase, generic_def: GenericDefId) -> bool {
@@ -117,7 +117,11 @@ impl PathResolutionPerNs {
         value_ns: Option<PathResolution>,
         macro_ns: Option<PathResolution>,
     ) -> Self {
-        PathResolutionPerNs { type_ns, value_ns, macro_ns }
+        PathResolutionPerNs {
+            type_ns,
+            value_ns,
+            macro_ns,
+        }
     }
 
     pub fn any(&self) -> Option<PathResolution> {
@@ -175,6 +179,9 @@ impl<'db, DB: ?Sized> ops::Deref for Semantics<'db, DB> {
     }
 }
 
+// Note: while this variant of `Semantics<'_, _>` might seem unused, as it does not
+// find actual use within the rust-analyzer project itself, it exists to enable the use
+// within e.g. tracked salsa functions in third-party crates that build upon `ra_ap_hir`.
 impl Semantics<'_, dyn HirDatabase> {
     /// Creates an instance that's weakly coupled to its underlying database type.
     pub fn new_dyn(db: &'_ dyn HirDatabase) -> Semantics<'_, dyn HirDatabase> {
@@ -191,6 +198,8 @@ impl<DB: HirDatabase> Semantics<'_, DB> {
     }
 }
 
+// Note: We take `DB` as `?Sized` here in order to support type-erased
+// use of `Semantics` via `Semantics<'_, dyn HirDatabase>`:
 impl<DB: HirDatabase + ?Sized> Semantics<'_, DB> {
     pub fn hir_file_for(&self, syntax_node: &SyntaxNode) -> HirFileId {
         self.imp.find_file(syntax_node).file_id
@@ -200,7 +209,10 @@ impl<DB: HirDatabase + ?Sized> Semantics<'_, DB> {
         &self,
         token: SyntaxToken,
     ) -> impl Iterator<Item = SyntaxNode> + '_ {
-        token.parent().into_iter().flat_map(move |it| self.ancestors_with_macros(it))
+        token
+            .parent()
+            .into_iter()
+            .flat_map(move |it| self.ancestors_with_macros(it))
     }
 
     /// Find an AstNode by offset inside SyntaxNode, if it is inside *Macrofile*,
@@ -210,7 +222,9 @@ impl<DB: HirDatabase + ?Sized> Semantics<'_, DB> {
         node: &SyntaxNode,
         offset: TextSize,
     ) -> Option<N> {
-        self.imp.ancestors_at_offset_with_macros(node, offset).find_map(N::cast)
+        self.imp
+            .ancestors_at_offset_with_macros(node, offset)
+            .find_map(N::cast)
     }
 
     // FIXME: Rethink this API
@@ -221,7 +235,10 @@ impl<DB: HirDatabase + ?Sized> Semantics<'_, DB> {
         node: &SyntaxNode,
         offset: TextSize,
     ) -> Option<N> {
-        self.imp.descend_node_at_offset(node, offset).flatten().find_map(N::cast)
+        self.imp
+            .descend_node_at_offset(node, offset)
+            .flatten()
+            .find_map(N::cast)
     }
 
     // FIXME: Rethink this API
@@ -232,7 +249,9 @@ impl<DB: HirDatabase + ?Sized> Semantics<'_, DB> {
         node: &SyntaxNode,
         offset: TextSize,
     ) -> impl Iterator<Item = N> + 'slf {
-        self.imp.descend_node_at_offset(node, offset).filter_map(|mut it| it.find_map(N::cast))
+        self.imp
+            .descend_node_at_offset(node, offset)
+            .filter_map(|mut it| it.find_map(N::cast))
     }
 
     // FIXME: Rethink this API
@@ -259,11 +278,15 @@ impl<DB: HirDatabase + ?Sized> Semantics<'_, DB> {
     }
 
     pub fn resolve_await_to_poll(&self, await_expr: &ast::AwaitExpr) -> Option<Function> {
-        self.imp.resolve_await_to_poll(await_expr).map(Function::from)
+        self.imp
+            .resolve_await_to_poll(await_expr)
+            .map(Function::from)
     }
 
     pub fn resolve_prefix_expr(&self, prefix_expr: &ast::PrefixExpr) -> Option<Function> {
-        self.imp.resolve_prefix_expr(prefix_expr).map(Function::from)
+        self.imp
+            .resolve_prefix_expr(prefix_expr)
+            .map(Function::from)
     }
 
     pub fn resolve_index_expr(&self, index_expr: &ast::IndexExpr) -> Option<Function> {
@@ -363,7 +386,11 @@ impl<DB: HirDatabase + ?Sized> Semantics<'_, DB> {
 
 impl<'db> SemanticsImpl<'db> {
     fn new(db: &'db dyn HirDatabase) -> Self {
-        SemanticsImpl { db, s2d_cache: Default::default(), macro_call_cache: Default::default() }
+        SemanticsImpl {
+            db,
+            s2d_cache: Default::default(),
+            macro_call_cache: Default::default(),
+        }
     }
 
     pub fn parse(&self, file_id: EditionedFileId) -> ast::SourceFile {
@@ -385,7 +412,10 @@ impl<'db> SemanticsImpl<'db> {
         Some(EditionedFileId::new(
             self.db,
             file,
-            self.file_to_module_defs(file).next()?.krate().edition(self.db),
+            self.file_to_module_defs(file)
+                .next()?
+                .krate()
+                .edition(self.db),
         ))
     }
 
@@ -427,7 +457,10 @@ impl<'db> SemanticsImpl<'db> {
                 }
             }
             HirFileId::MacroFile(macro_file) => {
-                let node = self.db.lookup_intern_macro_call(macro_file).to_node(self.db);
+                let node = self
+                    .db
+                    .lookup_intern_macro_call(macro_file)
+                    .to_node(self.db);
                 let root = find_root(&node.value);
                 self.cache(root, node.file_id);
                 Some(node)
@@ -439,7 +472,9 @@ impl<'db> SemanticsImpl<'db> {
     /// the `SyntaxNode` of the *definition* file, not of the *declaration*.
     pub fn module_definition_node(&self, module: Module) -> InFile<SyntaxNode> {
         let def_map = module.id.def_map(self.db);
-        let definition = def_map[module.id.local_id].origin.definition_source(self.db);
+        let definition = def_map[module.id.local_id]
+            .origin
+            .definition_source(self.db);
         let definition = definition.map(|it| it.node());
         let root_node = find_root(&definition.value);
         self.cache(root_node, definition.file_id);
@@ -453,7 +488,10 @@ impl<'db> SemanticsImpl<'db> {
     }
 
     pub fn expand(&self, file_id: MacroCallId) -> ExpandResult<SyntaxNode> {
-        let res = self.db.parse_macro_expansion(file_id).map(|it| it.0.syntax_node());
+        let res = self
+            .db
+            .parse_macro_expansion(file_id)
+            .map(|it| it.0.syntax_node());
         self.cache(res.value.clone(), file_id.into());
         res
     }
@@ -515,7 +553,10 @@ impl<'db> SemanticsImpl<'db> {
     pub fn expand_attr_macro(&self, item: &ast::Item) -> Option<ExpandResult<InFile<SyntaxNode>>> {
         let src = self.wrap_node_infile(item.clone());
         let macro_call_id = self.with_ctx(|ctx| ctx.item_to_macro_call(src.as_ref()))?;
-        Some(self.expand(macro_call_id).map(|it| InFile::new(macro_call_id.into(), it)))
+        Some(
+            self.expand(macro_call_id)
+                .map(|it| InFile::new(macro_call_id.into(), it)),
+        )
     }
 
     pub fn expand_derive_as_pseudo_attr_macro(&self, attr: &ast::Attr) -> Option<SyntaxNode> {
@@ -548,7 +589,10 @@ impl<'db> SemanticsImpl<'db> {
                 let ExpandResult { value, err } = self.db.parse_macro_expansion(file_id);
                 let root_node = value.0.syntax_node();
                 self.cache(root_node.clone(), file_id.into());
-                Some(ExpandResult { value: root_node, err })
+                Some(ExpandResult {
+                    value: root_node,
+                    err,
+                })
             })
             .collect();
         Some(res)
@@ -586,13 +630,20 @@ impl<'db> SemanticsImpl<'db> {
     }
 
     pub fn derive_helper(&self, attr: &ast::Attr) -> Option<Vec<(Macro, MacroCallId)>> {
-        let adt = attr.syntax().ancestors().find_map(ast::Item::cast).and_then(|it| match it {
+        let adt = attr
+            .syntax()
+            .ancestors()
+            .find_map(ast::Item::cast)
+            .and_then(|it| match it {
             ast::Item::Struct(it) => Some(ast::Adt::Struct(it)),
             ast::Item::Enum(it) => Some(ast::Adt::Enum(it)),
             ast::Item::Union(it) => Some(ast::Adt::Union(it)),
             _ => None,
         })?;
-        let attr_name = attr.path().and_then(|it| it.as_single_name_ref())?.as_name();
+        let attr_name = attr
+            .path()
+            .and_then(|it| it.as_single_name_ref())?
+            .as_name();
         let sa = self.analyze_no_infer(adt.syntax())?;
         let id = self.db.ast_id_map(sa.file_id).ast_id(&adt);
         let res: Vec<_> = sa
@@ -661,7 +712,10 @@ impl<'db> SemanticsImpl<'db> {
         token_to_map: SyntaxToken,
     ) -> Option<(SyntaxNode, Vec<(SyntaxToken, u8)>)> {
         let attr = self.wrap_node_infile(actual_macro_call.clone());
-        let adt = actual_macro_call.syntax().parent().and_then(ast::Adt::cast)?;
+        let adt = actual_macro_call
+            .syntax()
+            .parent()
+            .and_then(ast::Adt::cast)?;
         let macro_call_id = self.with_ctx(|ctx| {
             ctx.attr_to_derive_macro_call(attr.with_value(&adt), attr).map(|(_, it, _)| it)
         })?;
@@ -678,7 +732,9 @@ impl<'db> SemanticsImpl<'db> {
     pub fn rename_conflicts(&self, to_be_renamed: &Local, new_name: &Name) -> Vec<Local> {
         let body = self.db.body(to_be_renamed.parent);
         let resolver = to_be_renamed.parent.resolver(self.db);
-        let starting_expr = body.binding_owner(to_be_renamed.binding_id).unwrap_or(body.body_expr);
+        let starting_expr = body
+            .binding_owner(to_be_renamed.binding_id)
+            .unwrap_or(body.body_expr);
         let mut visitor = RenameConflictsVisitor {
             body: &body,
             conflicts: FxHashSet::default(),
@@ -704,9 +760,7 @@ impl<'db> SemanticsImpl<'db> {
     ) -> Option<Vec<(TextRange, Option<Either<PathResolution, InlineAsmOperand>>)>> {
         let string_start = string.syntax().text_range().start();
         let token = self.wrap_token_infile(string.syntax().clone());
-        self.descend_into_macros_breakable(
-            token,
-            |token, _| {
+        self.descend_into_macros_breakable(token, |token, _| {
             (|| {
                 let token = token.value;
                 let string = ast::String::cast(token)?;
@@ -741,8 +795,7 @@ impl<'db> SemanticsImpl<'db> {
                 }
             })()
             .map_or(ControlFlow::Continue(()), ControlFlow::Break)
-        },
-        )
+        })
     }
 
     // FIXME: Type the return type
@@ -792,19 +845,18 @@ impl<'db> SemanticsImpl<'db> {
             |token, _| {
                 (|| {
                     let token = token.map(ast::String::cast).transpose()?;
-                    self.resolve_offset_in_format_args(token.as_ref(), relative_offset).map(
-                        |(range, res)| {
+                    self.resolve_offset_in_format_args(token.as_ref(), relative_offset)
+                        .map(|(range, res)| {
                             (
                                 range + original_token.value.syntax().text_range().start(),
                                 HirFileRange {
-                                    file_id: token.file_id,
-                                    range: range + token.value.syntax().text_range().start(),
-                                },
+                                file_id: token.file_id,
+                                range: range + token.value.syntax().text_range().start(),
+                            },
                                 token.value,
                                 res,
                             )
-                        },
-                    )
+                        })
                 })()
                 .map_or(ControlFlow::Continue(()), ControlFlow::Break)
             },
@@ -817,7 +869,10 @@ impl<'db> SemanticsImpl<'db> {
         offset: TextSize,
     ) -> Option<(TextRange, Option<Either<PathResolution, InlineAsmOperand>>)> {
         debug_assert!(offset <= string.syntax().text_range().len());
-        let literal = string.syntax().parent().filter(|it| it.kind() == SyntaxKind::LITERAL)?;
+        let literal = string
+            .syntax()
+            .parent()
+            .filter(|it| it.kind() == SyntaxKind::LITERAL)?;
         let parent = literal.parent()?;
         if let Some(format_args) = ast::FormatArgsExpr::cast(parent.clone()) {
             let source_analyzer =
@@ -877,7 +932,10 @@ impl<'db> SemanticsImpl<'db> {
         else {
             return tok.into();
         };
-        let span = self.db.real_span_map(tok.file_id).span_for_range(tok.value.text_range());
+        let span = self
+            .db
+            .real_span_map(tok.file_id)
+            .span_for_range(tok.value.text_range());
         let Some(InMacroFile { file_id, value: mut mapped_tokens }) = self.with_ctx(|ctx| {
             Some(
                 ctx.cache
@@ -889,7 +947,9 @@ impl<'db> SemanticsImpl<'db> {
             return tok.into();
         };
         // We should only get one result at most
-        mapped_tokens.pop().map_or_else(|| tok.into(), |(tok, _)| InFile::new(file_id.into(), tok))
+        mapped_tokens
+            .pop()
+            .map_or_else(|| tok.into(), |(tok, _)| InFile::new(file_id.into(), tok))
     }
 
     /// Maps a node down by mapping its first and last token down.
@@ -1099,7 +1159,11 @@ impl<'db> SemanticsImpl<'db> {
                 let any_ident_match =
                     || kind.is_any_identifier() && value.kind().is_any_identifier();
                 let matches = (kind == mapped_kind || any_ident_match()) && text == value.text();
-                if matches { ControlFlow::Break(value) } else { ControlFlow::Continue(()) }
+                if matches {
+                    ControlFlow::Break(value)
+                } else {
+                    ControlFlow::Continue(())
+                }
             },
         )
         .unwrap_or(token)
@@ -1439,30 +1503,32 @@ impl<'db> SemanticsImpl<'db> {
     /// Attempts to map the node out of macro expanded files returning the original file range.
     pub fn original_range_opt(&self, node: &SyntaxNode) -> Option<FileRange> {
         let node = self.find_file(node);
-        node.original_file_range_opt(self.db).filter(|(_, ctx)| ctx.is_root()).map(TupleExt::head)
+        node.original_file_range_opt(self.db)
+            .filter(|(_, ctx)| ctx.is_root())
+            .map(TupleExt::head)
     }
 
     /// Attempts to map the node out of macro expanded files.
     /// This only work for attribute expansions, as other ones do not have nodes as input.
     pub fn original_ast_node<N: AstNode>(&self, node: N) -> Option<N> {
-        self.wrap_node_infile(node).original_ast_node_rooted(self.db).map(
-            |InRealFile { file_id, value }| {
+        self.wrap_node_infile(node)
+            .original_ast_node_rooted(self.db)
+            .map(|InRealFile { file_id, value }| {
                 self.cache(find_root(value.syntax()), file_id.into());
                 value
-            },
-        )
+            })
     }
 
     /// Attempts to map the node out of macro expanded files.
     /// This only work for attribute expansions, as other ones do not have nodes as input.
     pub fn original_syntax_node_rooted(&self, node: &SyntaxNode) -> Option<SyntaxNode> {
         let InFile { file_id, .. } = self.find_file(node);
-        InFile::new(file_id, node).original_syntax_node_rooted(self.db).map(
-            |InRealFile { file_id, value }| {
+        InFile::new(file_id, node)
+            .original_syntax_node_rooted(self.db)
+            .map(|InRealFile { file_id, value }| {
                 self.cache(find_root(&value), file_id.into());
                 value
-            },
-        )
+            })
     }
 
     pub fn diagnostics_display_range(
@@ -1472,14 +1538,20 @@ impl<'db> SemanticsImpl<'db> {
         let root = self.parse_or_expand(src.file_id);
         let node = src.map(|it| it.to_node(&root));
         let FileRange { file_id, range } = node.as_ref().original_file_range_rooted(self.db);
-        FileRangeWrapper { file_id: file_id.file_id(self.db), range }
+        FileRangeWrapper {
+            file_id: file_id.file_id(self.db),
+            range,
+        }
     }
 
     fn token_ancestors_with_macros(
         &self,
         token: SyntaxToken,
     ) -> impl Iterator<Item = SyntaxNode> + Clone + '_ {
-        token.parent().into_iter().flat_map(move |parent| self.ancestors_with_macros(parent))
+        token
+            .parent()
+            .into_iter()
+            .flat_map(move |parent| self.ancestors_with_macros(parent))
     }
 
     // FIXME: Replace with `ancestors_with_macros_file` when all usages are updated.
@@ -1489,7 +1561,8 @@ impl<'db> SemanticsImpl<'db> {
         node: SyntaxNode,
     ) -> impl Iterator<Item = SyntaxNode> + Clone + '_ {
         let node = self.find_file(&node);
-        self.ancestors_with_macros_file(node.cloned()).map(|it| it.value)
+        self.ancestors_with_macros_file(node.cloned())
+            .map(|it| it.value)
     }
 
     /// Iterates the ancestors of the given node, climbing up macro expansions while doing so.
@@ -1497,9 +1570,7 @@ impl<'db> SemanticsImpl<'db> {
         &self,
         node: InFile<SyntaxNode>,
     ) -> impl Iterator<Item = InFile<SyntaxNode>> + Clone + '_ {
-        iter::successors(
-            Some(node),
-            move |&InFile { file_id, ref value }| match value.parent() {
+        iter::successors(Some(node), move |&InFile { file_id, ref value }| match value.parent() {
             Some(parent) => Some(InFile::new(file_id, parent)),
             None => {
                 let macro_file = file_id.macro_file()?;
@@ -1509,8 +1580,7 @@ impl<'db> SemanticsImpl<'db> {
                     expansion_info.arg().map(|node| node?.parent()).transpose()
                 })
             }
-        },
-        )
+        })
     }
 
     pub fn ancestors_at_offset_with_macros(
@@ -1548,12 +1618,16 @@ impl<'db> SemanticsImpl<'db> {
     pub fn resolve_trait(&self, path: &ast::Path) -> Option<Trait> {
         let parent_ty = path.syntax().parent().and_then(ast::Type::cast)?;
         let analyze = self.analyze(path.syntax())?;
-        let ty = analyze.store_sm()?.node_type(InFile::new(analyze.file_id, &parent_ty))?;
+        let ty = analyze
+            .store_sm()?
+            .node_type(InFile::new(analyze.file_id, &parent_ty))?;
         let path = match &analyze.store()?.types[ty] {
             hir_def::type_ref::TypeRef::Path(path) => path,
             _ => return None,
         };
-        match analyze.resolver.resolve_path_in_type_ns_fully(self.db, path)? {
+        match analyze
+            .resolver
+            .resolve_path_in_type_ns_fully(self.db, path)? {
             TypeNs::TraitId(trait_id) => Some(trait_id.into()),
             _ => None,
         }
@@ -1596,7 +1670,11 @@ impl<'db> SemanticsImpl<'db> {
                     // Update `source_ty` for the next adjustment
                     let source = mem::replace(&mut source_ty, target.clone());
 
-                    Adjustment { source, target, kind }
+                    Adjustment {
+                        source,
+                        target,
+                        kind,
+                    }
                 })
                 .collect()
         })
@@ -1618,7 +1696,8 @@ impl<'db> SemanticsImpl<'db> {
     /// `let ref x @ Some(_) = None` the result of `type_of_pat` is `Option<T>` but the result
     /// of this function is `&mut Option<T>`
     pub fn type_of_binding_in_pat(&self, pat: &ast::IdentPat) -> Option<Type<'db>> {
-        self.analyze(pat.syntax())?.type_of_binding_in_pat(self.db, pat)
+        self.analyze(pat.syntax())?
+            .type_of_binding_in_pat(self.db, pat)
     }
 
     pub fn type_of_self(&self, param: &ast::SelfParam) -> Option<Type<'db>> {
@@ -1632,15 +1711,18 @@ impl<'db> SemanticsImpl<'db> {
     }
 
     pub fn binding_mode_of_pat(&self, pat: &ast::IdentPat) -> Option<BindingMode> {
-        self.analyze(pat.syntax())?.binding_mode_of_pat(self.db, pat)
+        self.analyze(pat.syntax())?
+            .binding_mode_of_pat(self.db, pat)
     }
 
     pub fn resolve_expr_as_callable(&self, call: &ast::Expr) -> Option<Callable<'db>> {
-        self.analyze(call.syntax())?.resolve_expr_as_callable(self.db, call)
+        self.analyze(call.syntax())?
+            .resolve_expr_as_callable(self.db, call)
     }
 
     pub fn resolve_method_call(&self, call: &ast::MethodCallExpr) -> Option<Function> {
-        self.analyze(call.syntax())?.resolve_method_call(self.db, call)
+        self.analyze(call.syntax())?
+            .resolve_method_call(self.db, call)
     }
 
     /// Attempts to resolve this call expression as a method call falling back to resolving it as a field.
@@ -1648,7 +1730,8 @@ impl<'db> SemanticsImpl<'db> {
         &self,
         call: &ast::MethodCallExpr,
     ) -> Option<(Either<Function, Field>, Option<GenericSubstitution<'db>>)> {
-        self.analyze(call.syntax())?.resolve_method_call_fallback(self.db, call)
+        self.analyze(call.syntax())?
+            .resolve_method_call_fallback(self.db, call)
     }
 
     // FIXME: better api for the trait environment
@@ -1668,35 +1751,47 @@ impl<'db> SemanticsImpl<'db> {
                 subst.next().expect("too few subst").ty.into()
             });
         assert!(subst.next().is_none(), "too many subst");
-        Some(self.db.lookup_impl_method(env.env, func.into(), substs).0.into())
+        Some(
+            self.db
+                .lookup_impl_method(env.env, func.into(), substs)
+                .0
+                .into(),
+        )
     }
 
     fn resolve_range_pat(&self, range_pat: &ast::RangePat) -> Option<StructId> {
-        self.analyze(range_pat.syntax())?.resolve_range_pat(self.db, range_pat)
+        self.analyze(range_pat.syntax())?
+            .resolve_range_pat(self.db, range_pat)
     }
 
     fn resolve_range_expr(&self, range_expr: &ast::RangeExpr) -> Option<StructId> {
-        self.analyze(range_expr.syntax())?.resolve_range_expr(self.db, range_expr)
+        self.analyze(range_expr.syntax())?
+            .resolve_range_expr(self.db, range_expr)
     }
 
     fn resolve_await_to_poll(&self, await_expr: &ast::AwaitExpr) -> Option<FunctionId> {
-        self.analyze(await_expr.syntax())?.resolve_await_to_poll(self.db, await_expr)
+        self.analyze(await_expr.syntax())?
+            .resolve_await_to_poll(self.db, await_expr)
     }
 
     fn resolve_prefix_expr(&self, prefix_expr: &ast::PrefixExpr) -> Option<FunctionId> {
-        self.analyze(prefix_expr.syntax())?.resolve_prefix_expr(self.db, prefix_expr)
+        self.analyze(prefix_expr.syntax())?
+            .resolve_prefix_expr(self.db, prefix_expr)
     }
 
     fn resolve_index_expr(&self, index_expr: &ast::IndexExpr) -> Option<FunctionId> {
-        self.analyze(index_expr.syntax())?.resolve_index_expr(self.db, index_expr)
+        self.analyze(index_expr.syntax())?
+            .resolve_index_expr(self.db, index_expr)
     }
 
     fn resolve_bin_expr(&self, bin_expr: &ast::BinExpr) -> Option<FunctionId> {
-        self.analyze(bin_expr.syntax())?.resolve_bin_expr(self.db, bin_expr)
+        self.analyze(bin_expr.syntax())?
+            .resolve_bin_expr(self.db, bin_expr)
     }
 
     fn resolve_try_expr(&self, try_expr: &ast::TryExpr) -> Option<FunctionId> {
-        self.analyze(try_expr.syntax())?.resolve_try_expr(self.db, try_expr)
+        self.analyze(try_expr.syntax())?
+            .resolve_try_expr(self.db, try_expr)
     }
 
     // This does not resolve the method call to the correct trait impl!
@@ -1705,7 +1800,8 @@ impl<'db> SemanticsImpl<'db> {
         &self,
         call: &ast::MethodCallExpr,
     ) -> Option<Callable<'db>> {
-        self.analyze(call.syntax())?.resolve_method_call_as_callable(self.db, call)
+        self.analyze(call.syntax())?
+            .resolve_method_call_as_callable(self.db, call)
     }
 
     pub fn resolve_field(&self, field: &ast::FieldExpr) -> Option<Either<Field, TupleField>> {
@@ -1716,7 +1812,8 @@ impl<'db> SemanticsImpl<'db> {
         &self,
         field: &ast::FieldExpr,
     ) -> Option<(Either<Either<Field, TupleField>, Function>, Option<GenericSubstitution<'db>>)> {
-        self.analyze(field.syntax())?.resolve_field_fallback(self.db, field)
+        self.analyze(field.syntax())?
+            .resolve_field_fallback(self.db, field)
     }
 
     pub fn resolve_record_field(
@@ -1731,21 +1828,24 @@ impl<'db> SemanticsImpl<'db> {
         &self,
         field: &ast::RecordExprField,
     ) -> Option<(Field, Option<Local>, Type<'db>, GenericSubstitution<'db>)> {
-        self.analyze(field.syntax())?.resolve_record_field(self.db, field)
+        self.analyze(field.syntax())?
+            .resolve_record_field(self.db, field)
     }
 
     pub fn resolve_record_pat_field(
         &self,
         field: &ast::RecordPatField,
     ) -> Option<(Field, Type<'db>)> {
-        self.resolve_record_pat_field_with_subst(field).map(|(field, ty, _)| (field, ty))
+        self.resolve_record_pat_field_with_subst(field)
+            .map(|(field, ty, _)| (field, ty))
     }
 
     pub fn resolve_record_pat_field_with_subst(
         &self,
         field: &ast::RecordPatField,
     ) -> Option<(Field, Type<'db>, GenericSubstitution<'db>)> {
-        self.analyze(field.syntax())?.resolve_record_pat_field(self.db, field)
+        self.analyze(field.syntax())?
+            .resolve_record_pat_field(self.db, field)
     }
 
     // FIXME: Replace this with `resolve_macro_call2`
@@ -1810,7 +1910,9 @@ impl<'db> SemanticsImpl<'db> {
 
         let Some(sa) = self.analyze(macro_call.syntax()) else { return false };
         let macro_call = self.find_file(macro_call.syntax()).with_value(macro_call);
-        match macro_call.map(|it| it.syntax().parent().and_then(ast::MacroExpr::cast)).transpose() {
+        match macro_call
+            .map(|it| it.syntax().parent().and_then(ast::MacroExpr::cast))
+            .transpose() {
             Some(it) => sa.is_unsafe_macro_call_expr(self.db, it.as_ref()),
             None => false,
         }
@@ -1830,7 +1932,8 @@ impl<'db> SemanticsImpl<'db> {
     }
 
     pub fn resolve_path_per_ns(&self, path: &ast::Path) -> Option<PathResolutionPerNs> {
-        self.analyze(path.syntax())?.resolve_hir_path_per_ns(self.db, path)
+        self.analyze(path.syntax())?
+            .resolve_hir_path_per_ns(self.db, path)
     }
 
     pub fn resolve_path_with_subst(
@@ -1848,7 +1951,8 @@ impl<'db> SemanticsImpl<'db> {
         &self,
         name_ref: &ast::NameRef,
     ) -> Option<(Either<Variant, Field>, GenericSubstitution<'db>)> {
-        self.analyze_no_infer(name_ref.syntax())?.resolve_offset_of_field(self.db, name_ref)
+        self.analyze_no_infer(name_ref.syntax())?
+            .resolve_offset_of_field(self.db, name_ref)
     }
 
     pub fn resolve_mod_path(
@@ -1862,11 +1966,13 @@ impl<'db> SemanticsImpl<'db> {
     }
 
     fn resolve_variant(&self, record_lit: ast::RecordExpr) -> Option<VariantId> {
-        self.analyze(record_lit.syntax())?.resolve_variant(record_lit)
+        self.analyze(record_lit.syntax())?
+            .resolve_variant(record_lit)
     }
 
     pub fn resolve_bind_pat_to_const(&self, pat: &ast::IdentPat) -> Option<ModuleDef> {
-        self.analyze(pat.syntax())?.resolve_bind_pat_to_const(self.db, pat)
+        self.analyze(pat.syntax())?
+            .resolve_bind_pat_to_const(self.db, pat)
     }
 
     pub fn record_literal_missing_fields(
@@ -1902,16 +2008,22 @@ impl<'db> SemanticsImpl<'db> {
     }
 
     fn file_to_module_defs(&self, file: FileId) -> impl Iterator<Item = Module> {
-        self.with_ctx(|ctx| ctx.file_to_def(file).to_owned()).into_iter().map(Module::from)
+        self.with_ctx(|ctx| ctx.file_to_def(file).to_owned())
+            .into_iter()
+            .map(Module::from)
     }
 
     fn hir_file_to_module_defs(&self, file: HirFileId) -> impl Iterator<Item = Module> {
         // FIXME: Do we need to care about inline modules for macro expansions?
-        self.file_to_module_defs(file.original_file_respecting_includes(self.db).file_id(self.db))
+        self.file_to_module_defs(
+            file.original_file_respecting_includes(self.db)
+                .file_id(self.db),
+        )
     }
 
     pub fn scope(&self, node: &SyntaxNode) -> Option<SemanticsScope<'db>> {
-        self.analyze_no_infer(node).map(|SourceAnalyzer { file_id, resolver, .. }| SemanticsScope {
+        self.analyze_no_infer(node)
+            .map(|SourceAnalyzer { file_id, resolver, .. }| SemanticsScope {
             db: self.db,
             file_id,
             resolver,
@@ -2084,7 +2196,9 @@ impl<'db> SemanticsImpl<'db> {
             Either::Right(it) => self.to_def(it).map(<_>::into).map(DefWithBodyId::VariantId),
         };
         let Some(def) = def else { return false };
-        let enclosing_node = enclosing_item.as_ref().either(|i| i.syntax(), |v| v.syntax());
+        let enclosing_node = enclosing_item
+            .as_ref()
+            .either(|i| i.syntax(), |v| v.syntax());
 
         let (body, source_map) = self.db.body_with_source_map(def);
 
@@ -2245,11 +2359,15 @@ impl<'db> SemanticsScope<'db> {
     }
 
     pub fn module(&self) -> Module {
-        Module { id: self.resolver.module() }
+        Module {
+            id: self.resolver.module(),
+        }
     }
 
     pub fn krate(&self) -> Crate {
-        Crate { id: self.resolver.krate() }
+        Crate {
+            id: self.resolver.krate(),
+        }
     }
 
     pub fn containing_function(&self) -> Option<Function> {
@@ -2366,7 +2484,9 @@ impl<'db> SemanticsScope<'db> {
     }
 
     pub fn extern_crates(&self) -> impl Iterator<Item = (Name, Module)> + '_ {
-        self.resolver.extern_crates_in_scope().map(|(name, id)| (name, Module { id }))
+        self.resolver
+            .extern_crates_in_scope()
+            .map(|(name, id)| (name, Module { id }))
     }
 
     pub fn extern_crate_decls(&self) -> impl Iterator<Item = Name> + '_ {
@@ -2417,13 +2537,13 @@ impl RenameConflictsVisitor<'_> {
                 }
             } else if *name.symbol() == self.old_name
                 && let Some(conflicting) = self.resolver.rename_will_conflict_with_another_variable(
-                    self.db,
-                    name,
-                    path,
-                    self.body.expr_or_pat_path_hygiene(node),
-                    &self.new_name,
-                    self.to_be_renamed,
-                )
+                self.db,
+                name,
+                path,
+                self.body.expr_or_pat_path_hygiene(node),
+                &self.new_name,
+                self.to_be_renamed,
+            )
             {
                 self.conflicts.insert(conflicting);
             }
@@ -2449,6 +2569,7 @@ impl RenameConflictsVisitor<'_> {
             _ => {}
         }
 
-        self.body.walk_child_exprs(expr, |expr| self.rename_conflicts(expr));
+        self.body
+            .walk_child_exprs(expr, |expr| self.rename_conflicts(expr));
     }
 }
@@ -35,50 +35,52 @@ impl ChildBySource for TraitId {
     fn child_by_source_to(&self, db: &dyn DefDatabase, res: &mut DynMap, file_id: HirFileId) {
         let data = self.trait_items(db);
 
-        data.macro_calls().filter(|(ast_id, _)| ast_id.file_id == file_id).for_each(
-            |(ast_id, call_id)| {
+        data.macro_calls()
+            .filter(|(ast_id, _)| ast_id.file_id == file_id)
+            .for_each(|(ast_id, call_id)| {
                 let ptr = ast_id.to_ptr(db);
                 if let Some(ptr) = ptr.cast::<ast::MacroCall>() {
                     res[keys::MACRO_CALL].insert(ptr, call_id);
                 } else {
                     res[keys::ATTR_MACRO_CALL].insert(ptr, call_id);
                 }
-            },
-        );
+            });
         data.items.iter().for_each(|&(_, item)| {
             add_assoc_item(db, res, file_id, item);
         });
         let (_, source_map) = db.trait_signature_with_source_map(*self);
-        source_map.expansions().filter(|(ast, _)| ast.file_id == file_id).for_each(
-            |(ast, &exp_id)| {
+        source_map
+            .expansions()
+            .filter(|(ast, _)| ast.file_id == file_id)
+            .for_each(|(ast, &exp_id)| {
                 res[keys::MACRO_CALL].insert(ast.value, exp_id);
-            },
-        );
+            });
     }
 }
 
 impl ChildBySource for ImplId {
     fn child_by_source_to(&self, db: &dyn DefDatabase, res: &mut DynMap, file_id: HirFileId) {
         let data = self.impl_items(db);
-        data.macro_calls().filter(|(ast_id, _)| ast_id.file_id == file_id).for_each(
-            |(ast_id, call_id)| {
+        data.macro_calls()
+            .filter(|(ast_id, _)| ast_id.file_id == file_id)
+            .for_each(|(ast_id, call_id)| {
                 let ptr = ast_id.to_ptr(db);
                 if let Some(ptr) = ptr.cast::<ast::MacroCall>() {
                     res[keys::MACRO_CALL].insert(ptr, call_id);
                 } else {
                     res[keys::ATTR_MACRO_CALL].insert(ptr, call_id);
                 }
-            },
-        );
+            });
         data.items.iter().for_each(|&(_, item)| {
             add_assoc_item(db, res, file_id, item);
         });
         let (_, source_map) = db.impl_signature_with_source_map(*self);
-        source_map.expansions().filter(|(ast, _)| ast.file_id == file_id).for_each(
-            |(ast, &exp_id)| {
+        source_map
+            .expansions()
+            .filter(|(ast, _)| ast.file_id == file_id)
+            .for_each(|(ast, &exp_id)| {
                 res[keys::MACRO_CALL].insert(ast.value, exp_id);
-            },
-        );
+            });
     }
 }
 
@@ -92,21 +94,24 @@ impl ChildBySource for ModuleId {
 
 impl ChildBySource for ItemScope {
     fn child_by_source_to(&self, db: &dyn DefDatabase, res: &mut DynMap, file_id: HirFileId) {
-        self.declarations().for_each(|item| add_module_def(db, res, file_id, item));
-        self.impls().for_each(|imp| insert_item_loc(db, res, file_id, imp, keys::IMPL));
+        self.declarations()
+            .for_each(|item| add_module_def(db, res, file_id, item));
+        self.impls()
+            .for_each(|imp| insert_item_loc(db, res, file_id, imp, keys::IMPL));
         self.extern_blocks().for_each(|extern_block| {
             insert_item_loc(db, res, file_id, extern_block, keys::EXTERN_BLOCK)
         });
         self.extern_crate_decls()
             .for_each(|ext| insert_item_loc(db, res, file_id, ext, keys::EXTERN_CRATE));
-        self.use_decls().for_each(|ext| insert_item_loc(db, res, file_id, ext, keys::USE));
+        self.use_decls()
+            .for_each(|ext| insert_item_loc(db, res, file_id, ext, keys::USE));
         self.unnamed_consts()
             .for_each(|konst| insert_item_loc(db, res, file_id, konst, keys::CONST));
-        self.attr_macro_invocs().filter(|(id, _)| id.file_id == file_id).for_each(
-            |(ast_id, call_id)| {
+        self.attr_macro_invocs()
+            .filter(|(id, _)| id.file_id == file_id)
+            .for_each(|(ast_id, call_id)| {
                 res[keys::ATTR_MACRO_CALL].insert(ast_id.to_ptr(db), call_id);
-            },
-        );
+            });
         self.legacy_macros().for_each(|(_, ids)| {
             ids.iter().for_each(|&id| {
                 if let MacroId::MacroRulesId(id) = id {
@@ -117,8 +122,9 @@ impl ChildBySource for ItemScope {
                 }
             })
         });
-        self.derive_macro_invocs().filter(|(id, _)| id.file_id == file_id).for_each(
-            |(ast_id, calls)| {
+        self.derive_macro_invocs()
+            .filter(|(id, _)| id.file_id == file_id)
+            .for_each(|(ast_id, calls)| {
                 let adt = ast_id.to_node(db);
                 calls.for_each(|(attr_id, call_id, calls)| {
                     if let Some((_, Either::Left(attr))) =
@@ -128,14 +134,13 @@ impl ChildBySource for ItemScope {
                             .insert(AstPtr::new(&attr), (attr_id, call_id, calls.into()));
                     }
                 });
-            },
-        );
-        self.iter_macro_invoc().filter(|(id, _)| id.file_id == file_id).for_each(
-            |(ast_id, &call)| {
+            });
+        self.iter_macro_invoc()
+            .filter(|(id, _)| id.file_id == file_id)
+            .for_each(|(ast_id, &call)| {
                 let ast = ast_id.to_ptr(db);
                 res[keys::MACRO_CALL].insert(ast, call);
-            },
-        );
+            });
         fn add_module_def(
             db: &dyn DefDatabase,
             map: &mut DynMap,
@@ -187,7 +192,8 @@ impl ChildBySource for VariantId {
             }
         }
         let (_, sm) = self.fields_with_source_map(db);
-        sm.expansions().for_each(|(ast, &exp_id)| res[keys::MACRO_CALL].insert(ast.value, exp_id));
+        sm.expansions()
+            .for_each(|(ast, &exp_id)| res[keys::MACRO_CALL].insert(ast.value, exp_id));
     }
 }
 
@@ -200,9 +206,12 @@ impl ChildBySource for EnumId {
 
         let ast_id_map = db.ast_id_map(loc.id.file_id);
 
-        self.enum_variants(db).variants.iter().for_each(|&(variant, _, _)| {
-            res[keys::ENUM_VARIANT].insert(ast_id_map.get(variant.lookup(db).id.value), variant);
-        });
+        self.enum_variants(db)
+            .variants
+            .iter()
+            .for_each(|&(variant, _, _)| {
+                res[keys::ENUM_VARIANT].insert(ast_id_map.get(variant.lookup(db).id.value), variant);
+            });
         let (_, source_map) = db.enum_signature_with_source_map(*self);
         source_map
             .expansions()
@@ -218,14 +227,18 @@ impl ChildBySource for DefWithBodyId {
             VariantId::EnumVariantId(v).child_by_source_to(db, res, file_id)
         }
 
-        sm.expansions().filter(|(ast, _)| ast.file_id == file_id).for_each(|(ast, &exp_id)| {
-            res[keys::MACRO_CALL].insert(ast.value, exp_id);
-        });
+        sm.expansions()
+            .filter(|(ast, _)| ast.file_id == file_id)
+            .for_each(|(ast, &exp_id)| {
+                res[keys::MACRO_CALL].insert(ast.value, exp_id);
+            });
 
         for (block, def_map) in body.blocks(db) {
             // All block expressions are merged into the same map, because they logically all add
             // inner items to the containing `DefWithBodyId`.
-            def_map[DefMap::ROOT].scope.child_by_source_to(db, res, file_id);
+            def_map[DefMap::ROOT]
+                .scope
+                .child_by_source_to(db, res, file_id);
             res[keys::BLOCK].insert(block.lookup(db).ast_id.to_ptr(db), block);
         }
     }
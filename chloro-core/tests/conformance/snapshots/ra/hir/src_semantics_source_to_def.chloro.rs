@@ -154,14 +154,16 @@ impl SourceToDefCache {
         db: &dyn HirDatabase,
         macro_file: MacroCallId,
     ) -> &ExpansionInfo {
-        self.expansion_info_cache.entry(macro_file).or_insert_with(|| {
-            let exp_info = macro_file.expansion_info(db);
+        self.expansion_info_cache
+            .entry(macro_file)
+            .or_insert_with(|| {
+                let exp_info = macro_file.expansion_info(db);
 
-            let InMacroFile { file_id, value } = exp_info.expanded();
-            Self::cache(&mut self.root_to_file_cache, value, file_id.into());
+                let InMacroFile { file_id, value } = exp_info.expanded();
+                Self::cache(&mut self.root_to_file_cache, value, file_id.into());
 
-            exp_info
-        })
+                exp_info
+            })
     }
 }
 
@@ -217,7 +219,9 @@ impl SourceToDefCtx<'_, '_> {
         let _p = tracing::info_span!("module_to_def").entered();
         let parent_declaration = self
             .parent_ancestors_with_macros(src.syntax_ref(), |_, ancestor, _| {
-                ancestor.map(Either::<ast::Module, ast::BlockExpr>::cast).transpose()
+                ancestor
+                    .map(Either::<ast::Module, ast::BlockExpr>::cast)
+                    .transpose()
             })
             .map(|it| it.transpose());
 
@@ -334,11 +338,14 @@ impl SourceToDefCtx<'_, '_> {
         InFile { file_id, value }: InFile<&ast::Adt>,
     ) -> Option<AdtId> {
         match value {
-            ast::Adt::Enum(it) => self.enum_to_def(InFile::new(file_id, it)).map(AdtId::EnumId),
+            ast::Adt::Enum(it) => self.enum_to_def(InFile::new(file_id, it))
+                .map(AdtId::EnumId),
             ast::Adt::Struct(it) => {
-                self.struct_to_def(InFile::new(file_id, it)).map(AdtId::StructId)
+                self.struct_to_def(InFile::new(file_id, it))
+                    .map(AdtId::StructId)
             }
-            ast::Adt::Union(it) => self.union_to_def(InFile::new(file_id, it)).map(AdtId::UnionId),
+            ast::Adt::Union(it) => self.union_to_def(InFile::new(file_id, it))
+                .map(AdtId::UnionId),
         }
     }
 
@@ -356,8 +363,14 @@ impl SourceToDefCtx<'_, '_> {
             .position(|it| it == *src.value)?;
         let container = self.find_pat_or_label_container(src.syntax_ref())?;
         let source_map = self.db.body_with_source_map(container).1;
-        let expr = source_map.node_expr(src.with_value(&ast::Expr::AsmExpr(asm)))?.as_expr()?;
-        Some(InlineAsmOperand { owner: container, expr, index })
+        let expr = source_map
+            .node_expr(src.with_value(&ast::Expr::AsmExpr(asm)))?
+            .as_expr()?;
+        Some(InlineAsmOperand {
+            owner: container,
+            expr,
+            index,
+        })
     }
 
     pub(super) fn bind_pat_to_def(
@@ -425,7 +438,9 @@ impl SourceToDefCtx<'_, '_> {
 
     // FIXME: Make this more fine grained! This should be a `adt_has_derives`!
     pub(super) fn file_of_adt_has_derives(&mut self, adt: InFile<&ast::Adt>) -> bool {
-        self.dyn_map(adt).as_ref().is_some_and(|map| !map[keys::DERIVE_MACRO_CALL].is_empty())
+        self.dyn_map(adt)
+            .as_ref()
+            .is_some_and(|map| !map[keys::DERIVE_MACRO_CALL].is_empty())
     }
 
     pub(super) fn derive_macro_calls<'slf>(
@@ -446,7 +461,9 @@ impl SourceToDefCtx<'_, '_> {
         src: InFile<&Ast>,
         key: Key<Ast, ID>,
     ) -> Option<ID> {
-        self.dyn_map(src)?[key].get(&AstPtr::new(src.value)).copied()
+        self.dyn_map(src)?[key]
+            .get(&AstPtr::new(src.value))
+            .copied()
     }
 
     fn dyn_map<Ast: AstNode + 'static>(&mut self, src: InFile<&Ast>) -> Option<&DynMap> {
@@ -491,7 +508,9 @@ impl SourceToDefCtx<'_, '_> {
     ) -> Option<LifetimeParamId> {
         let container: ChildContainer = self.find_generic_param_container(src.syntax_ref())?.into();
         let dyn_map = self.cache_for(container, src.file_id);
-        dyn_map[keys::LIFETIME_PARAM].get(&AstPtr::new(src.value)).copied()
+        dyn_map[keys::LIFETIME_PARAM]
+            .get(&AstPtr::new(src.value))
+            .copied()
     }
 
     pub(super) fn const_param_to_def(
@@ -512,13 +531,14 @@ impl SourceToDefCtx<'_, '_> {
     ) -> Option<GenericParamId> {
         match value {
             ast::GenericParam::ConstParam(it) => {
-                self.const_param_to_def(InFile::new(file_id, it)).map(GenericParamId::ConstParamId)
+                self.const_param_to_def(InFile::new(file_id, it))
+                    .map(GenericParamId::ConstParamId)
             }
-            ast::GenericParam::LifetimeParam(it) => self
-                .lifetime_param_to_def(InFile::new(file_id, it))
+            ast::GenericParam::LifetimeParam(it) => self.lifetime_param_to_def(InFile::new(file_id, it))
                 .map(GenericParamId::LifetimeParamId),
             ast::GenericParam::TypeParam(it) => {
-                self.type_param_to_def(InFile::new(file_id, it)).map(GenericParamId::TypeParamId)
+                self.type_param_to_def(InFile::new(file_id, it))
+                    .map(GenericParamId::TypeParamId)
             }
         }
     }
@@ -536,7 +556,10 @@ impl SourceToDefCtx<'_, '_> {
 
     pub(super) fn proc_macro_to_def(&mut self, src: InFile<&ast::Fn>) -> Option<MacroId> {
         self.dyn_map(src).and_then(|it| {
-            it[keys::PROC_MACRO].get(&AstPtr::new(src.value)).copied().map(MacroId::from)
+            it[keys::PROC_MACRO]
+                .get(&AstPtr::new(src.value))
+                .copied()
+                .map(MacroId::from)
         })
     }
 
@@ -557,9 +580,7 @@ impl SourceToDefCtx<'_, '_> {
     }
 
     fn find_generic_param_container(&mut self, src: InFile<&SyntaxNode>) -> Option<GenericDefId> {
-        self.parent_ancestors_with_macros(
-            src,
-            |this, InFile { file_id, value }, _| {
+        self.parent_ancestors_with_macros(src, |this, InFile { file_id, value }, _| {
             let item = ast::Item::cast(value)?;
             match &item {
                 ast::Item::Fn(it) => this.fn_to_def(InFile::new(file_id, it)).map(Into::into),
@@ -569,20 +590,18 @@ impl SourceToDefCtx<'_, '_> {
                 ast::Item::Enum(it) => this.enum_to_def(InFile::new(file_id, it)).map(Into::into),
                 ast::Item::Trait(it) => this.trait_to_def(InFile::new(file_id, it)).map(Into::into),
                 ast::Item::TypeAlias(it) => {
-                    this.type_alias_to_def(InFile::new(file_id, it)).map(Into::into)
+                    this.type_alias_to_def(InFile::new(file_id, it))
+                        .map(Into::into)
                 }
                 ast::Item::Impl(it) => this.impl_to_def(InFile::new(file_id, it)).map(Into::into),
                 _ => None,
             }
-        },
-        )
+        })
     }
 
     // FIXME: Remove this when we do inference in signatures
     fn find_pat_or_label_container(&mut self, src: InFile<&SyntaxNode>) -> Option<DefWithBodyId> {
-        self.parent_ancestors_with_macros(
-            src,
-            |this, InFile { file_id, value }, _| {
+        self.parent_ancestors_with_macros(src, |this, InFile { file_id, value }, _| {
             let item = match ast::Item::cast(value.clone()) {
                 Some(it) => it,
                 None => {
@@ -600,8 +619,7 @@ impl SourceToDefCtx<'_, '_> {
                 }
                 _ => None,
             }
-        },
-        )
+        })
     }
 
     /// Skips the attributed item that caused the macro invocation we are climbing up
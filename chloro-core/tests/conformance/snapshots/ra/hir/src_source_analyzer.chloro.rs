@@ -136,7 +136,12 @@ impl<'db> SourceAnalyzer<'db> {
         let resolver = resolver_for_scope(db, def, scope);
         SourceAnalyzer {
             resolver,
-            body_or_sig: Some(BodyOrSig::Body { def, body, source_map, infer }),
+            body_or_sig: Some(BodyOrSig::Body {
+                def,
+                body,
+                source_map,
+                infer,
+            }),
             file_id,
         }
     }
@@ -151,7 +156,11 @@ impl<'db> SourceAnalyzer<'db> {
         let resolver = def.resolver(db);
         SourceAnalyzer {
             resolver,
-            body_or_sig: Some(BodyOrSig::Sig { def, store, source_map }),
+            body_or_sig: Some(BodyOrSig::Sig {
+                def,
+                store,
+                source_map,
+            }),
             file_id,
         }
     }
@@ -179,7 +188,11 @@ impl<'db> SourceAnalyzer<'db> {
         resolver: Resolver<'db>,
         node: InFile<&SyntaxNode>,
     ) -> SourceAnalyzer<'db> {
-        SourceAnalyzer { resolver, body_or_sig: None, file_id: node.file_id }
+        SourceAnalyzer {
+            resolver,
+            body_or_sig: None,
+            file_id: node.file_id,
+        }
     }
 
     // FIXME: Remove this
@@ -402,7 +415,10 @@ impl<'db> SourceAnalyzer<'db> {
         let expr_id = self.expr_id(call.clone().into())?.as_expr()?;
         let (f_in_trait, substs) = self.infer()?.method_resolution(expr_id)?;
 
-        Some(self.resolve_impl_method_or_trait_def(db, f_in_trait, substs).into())
+        Some(
+            self.resolve_impl_method_or_trait_def(db, f_in_trait, substs)
+                .into(),
+        )
     }
 
     pub(crate) fn resolve_method_call_fallback(
@@ -416,15 +432,23 @@ impl<'db> SourceAnalyzer<'db> {
             Some((f_in_trait, substs)) => {
                 let (fn_, subst) =
                     self.resolve_impl_method_or_trait_def_with_subst(db, f_in_trait, substs);
-                Some((
-                    Either::Left(fn_.into()),
-                    Some(GenericSubstitution::new(fn_.into(), subst, self.trait_environment(db))),
-                ))
+                Some(
+                    (
+                        Either::Left(fn_.into()),
+                        Some(GenericSubstitution::new(fn_.into(), subst, self.trait_environment(db))),
+                    ),
+                )
             }
             None => {
-                inference_result.field_resolution(expr_id).and_then(Either::left).map(|field| {
-                    (Either::Right(field.into()), self.field_subst(expr_id, inference_result, db))
-                })
+                inference_result
+                    .field_resolution(expr_id)
+                    .and_then(Either::left)
+                    .map(|field| {
+                        (
+                            Either::Right(field.into()),
+                            self.field_subst(expr_id, inference_result, db),
+                        )
+                    })
             }
         }
     }
@@ -473,26 +497,32 @@ impl<'db> SourceAnalyzer<'db> {
         let inference_result = self.infer()?;
         match inference_result.field_resolution(expr_id) {
             Some(field) => match field {
-                Either::Left(field) => Some((
-                    Either::Left(Either::Left(field.into())),
-                    self.field_subst(expr_id, inference_result, db),
-                )),
-                Either::Right(field) => Some((
-                    Either::Left(Either::Right(TupleField {
-                    owner: def,
-                    tuple: field.tuple,
-                    index: field.index,
-                })),
-                    None,
-                )),
+                Either::Left(field) => Some(
+                    (
+                        Either::Left(Either::Left(field.into())),
+                        self.field_subst(expr_id, inference_result, db),
+                    ),
+                ),
+                Either::Right(field) => Some(
+                    (
+                        Either::Left(Either::Right(TupleField {
+                        owner: def,
+                        tuple: field.tuple,
+                        index: field.index,
+                    })),
+                        None,
+                    ),
+                ),
             },
-            None => inference_result.method_resolution(expr_id).map(|(f, substs)| {
-                let (f, subst) = self.resolve_impl_method_or_trait_def_with_subst(db, f, substs);
-                (
-                    Either::Right(f.into()),
-                    Some(GenericSubstitution::new(f.into(), subst, self.trait_environment(db))),
-                )
-            }),
+            None => inference_result
+                .method_resolution(expr_id)
+                .map(|(f, substs)| {
+                    let (f, subst) = self.resolve_impl_method_or_trait_def_with_subst(db, f, substs);
+                    (
+                        Either::Right(f.into()),
+                        Some(GenericSubstitution::new(f.into(), subst, self.trait_environment(db))),
+                    )
+                }),
         }
     }
 
@@ -635,7 +665,11 @@ impl<'db> SourceAnalyzer<'db> {
                     LangItem::IndexMut,
                     &Name::new_symbol_root(sym::index_mut),
                 )?;
-                if func == index_mut_fn { Some(index_mut_fn) } else { None }
+                if func == index_mut_fn {
+                    Some(index_mut_fn)
+                } else {
+                    None
+                }
             })
             .unwrap_or(index_fn);
         // HACK: subst for all methods coincides with that for their trait because the methods
@@ -687,7 +721,9 @@ impl<'db> SourceAnalyzer<'db> {
     ) -> Option<(Field, Option<Local>, Type<'db>, GenericSubstitution<'db>)> {
         let record_expr = ast::RecordExpr::cast(field.syntax().parent().and_then(|p| p.parent())?)?;
         let expr = ast::Expr::from(record_expr);
-        let expr_id = self.store_sm()?.node_expr(InFile::new(self.file_id, &expr))?;
+        let expr_id = self
+            .store_sm()?
+            .node_expr(InFile::new(self.file_id, &expr))?;
         let interner = DbInterner::new_with(db, None, None);
 
         let ast_name = field.field_name()?;
@@ -716,12 +752,14 @@ impl<'db> SourceAnalyzer<'db> {
         let variant_data = variant.fields(db);
         let field = FieldId { parent: variant, local_id: variant_data.field(&local_name)? };
         let field_ty = (*db.field_types(variant).get(field.local_id)?).instantiate(interner, subst);
-        Some((
-            field.into(),
-            local,
-            Type::new_with_resolver(db, &self.resolver, field_ty),
-            GenericSubstitution::new(adt.into(), subst, self.trait_environment(db)),
-        ))
+        Some(
+            (
+                field.into(),
+                local,
+                Type::new_with_resolver(db, &self.resolver, field_ty),
+                GenericSubstitution::new(adt.into(), subst, self.trait_environment(db)),
+            ),
+        )
     }
 
     pub(crate) fn resolve_record_pat_field(
@@ -738,11 +776,13 @@ impl<'db> SourceAnalyzer<'db> {
         let field = FieldId { parent: variant, local_id: variant_data.field(&field_name)? };
         let (adt, subst) = self.infer()?[pat_id.as_pat()?].as_adt()?;
         let field_ty = (*db.field_types(variant).get(field.local_id)?).instantiate(interner, subst);
-        Some((
-            field.into(),
-            Type::new_with_resolver(db, &self.resolver, field_ty),
-            GenericSubstitution::new(adt.into(), subst, self.trait_environment(db)),
-        ))
+        Some(
+            (
+                field.into(),
+                Type::new_with_resolver(db, &self.resolver, field_ty),
+                GenericSubstitution::new(adt.into(), subst, self.trait_environment(db)),
+            ),
+        )
     }
 
     pub(crate) fn resolve_bind_pat_to_const(
@@ -1307,21 +1347,23 @@ impl<'db> SourceAnalyzer<'db> {
         offset: TextSize,
     ) -> Option<(TextRange, Option<PathResolution>)> {
         let (hygiene, implicits) = self.store_sm()?.implicit_format_args(format_args)?;
-        implicits.iter().find(|(range, _)| range.contains_inclusive(offset)).map(|(range, name)| {
-            (
-                *range,
-                resolve_hir_value_path(
+        implicits
+            .iter()
+            .find(|(range, _)| range.contains_inclusive(offset))
+            .map(|(range, name)| {
+                (
+                    *range,
+                    resolve_hir_value_path(
                     db,
                     &self.resolver,
                     self.resolver.body_owner(),
-                    &Path::from_known_path_with_no_generic(ModPath::from_segments(
-                        PathKind::Plain,
-                        Some(name.clone()),
-                    )),
+                    &Path::from_known_path_with_no_generic(
+                        ModPath::from_segments(PathKind::Plain, Some(name.clone())),
+                    ),
                     hygiene,
                 ),
-            )
-        })
+                )
+            })
     }
 
     pub(crate) fn resolve_offset_in_asm_template(
@@ -1346,21 +1388,22 @@ impl<'db> SourceAnalyzer<'db> {
         format_args: InFile<&ast::FormatArgsExpr>,
     ) -> Option<impl Iterator<Item = (TextRange, Option<PathResolution>)> + 'a> {
         let (hygiene, names) = self.store_sm()?.implicit_format_args(format_args)?;
-        Some(names.iter().map(move |(range, name)| {
-            (
-                *range,
-                resolve_hir_value_path(
+        Some(
+            names.iter().map(move |(range, name)| {
+                (
+                    *range,
+                    resolve_hir_value_path(
                     db,
                     &self.resolver,
                     self.resolver.body_owner(),
-                    &Path::from_known_path_with_no_generic(ModPath::from_segments(
-                        PathKind::Plain,
-                        Some(name.clone()),
-                    )),
+                    &Path::from_known_path_with_no_generic(
+                        ModPath::from_segments(PathKind::Plain, Some(name.clone())),
+                    ),
                     hygiene,
                 ),
-            )
-        }))
+                )
+            }),
+        )
     }
 
     pub(crate) fn as_asm_parts(
@@ -1377,7 +1420,8 @@ impl<'db> SourceAnalyzer<'db> {
         func: FunctionId,
         substs: GenericArgs<'db>,
     ) -> FunctionId {
-        self.resolve_impl_method_or_trait_def_with_subst(db, func, substs).0
+        self.resolve_impl_method_or_trait_def_with_subst(db, func, substs)
+            .0
     }
 
     fn resolve_impl_method_or_trait_def_with_subst(
@@ -1435,10 +1479,7 @@ fn scope_for(
     node.ancestors_with_macros(db)
         .take_while(|it| {
             let kind = it.kind();
-            !ast::Item::can_cast(kind)
-                || ast::MacroCall::can_cast(kind)
-                || ast::Use::can_cast(kind)
-                || ast::AsmExpr::can_cast(kind)
+            !ast::Item::can_cast(kind) || ast::MacroCall::can_cast(kind) || ast::Use::can_cast(kind) || ast::AsmExpr::can_cast(kind)
         })
         .filter_map(|it| it.map(ast::Expr::cast).transpose())
         .filter_map(|it| source_map.node_expr(it.as_ref())?.as_expr())
@@ -1661,8 +1702,10 @@ fn resolve_hir_value_path(
     path: &Path,
     hygiene: HygieneId,
 ) -> Option<PathResolution> {
-    resolver.resolve_path_in_value_ns_fully(db, path, hygiene).and_then(|val| {
-        let res = match val {
+    resolver
+        .resolve_path_in_value_ns_fully(db, path, hygiene)
+        .and_then(|val| {
+            let res = match val {
             ValueNs::LocalBinding(binding_id) => {
                 let var = Local { parent: body_owner?, binding_id };
                 PathResolution::Local(var)
@@ -1675,8 +1718,8 @@ fn resolve_hir_value_path(
             ValueNs::ImplSelf(impl_id) => PathResolution::SelfType(impl_id.into()),
             ValueNs::GenericParam(id) => PathResolution::ConstParam(id.into()),
         };
-        Some(res)
-    })
+            Some(res)
+        })
 }
 
 /// Resolves a path where we know it is a qualifier of another path.
@@ -98,7 +98,10 @@ impl<'a> SymbolCollector<'a> {
 
         // The initial work is the root module we're collecting, additional work will
         // be populated as we traverse the module's definitions.
-        self.work.push(SymbolCollectorWork { module_id: module.into(), parent: None });
+        self.work.push(SymbolCollectorWork {
+            module_id: module.into(),
+            parent: None,
+        });
 
         while let Some(work) = self.work.pop() {
             self.do_work(work);
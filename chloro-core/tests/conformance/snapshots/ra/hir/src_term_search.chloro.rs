@@ -104,7 +104,8 @@ impl<'db> LookupTable<'db> {
     fn new(many_threshold: usize, goal: Type<'db>) -> Self {
         let mut res = Self { many_threshold, ..Default::default() };
         res.new_types.insert(NewTypesKey::ImplMethod, Vec::new());
-        res.new_types.insert(NewTypesKey::StructProjection, Vec::new());
+        res.new_types
+            .insert(NewTypesKey::StructProjection, Vec::new());
         res.types_wishlist.insert(goal);
         res
     }
@@ -149,7 +150,8 @@ impl<'db> LookupTable<'db> {
                 self.data
                     .iter()
                     .find(|(t, _)| {
-                        t.add_reference(Mutability::Shared).could_unify_with_deeply(db, ty)
+                        t.add_reference(Mutability::Shared)
+                            .could_unify_with_deeply(db, ty)
                     })
                     .map(|(t, it)| {
                         it.exprs(t)
@@ -187,7 +189,8 @@ impl<'db> LookupTable<'db> {
                 }
             }
             None => {
-                self.data.insert(ty.clone(), AlternativeExprs::new(self.many_threshold, exprs));
+                self.data
+                    .insert(ty.clone(), AlternativeExprs::new(self.many_threshold, exprs));
                 for it in self.new_types.values_mut() {
                     it.push(ty.clone());
                 }
@@ -307,5 +310,9 @@ pub fn term_search<'db, DB: HirDatabase>(ctx: &'db TermSearchCtx<'db, DB>) -> Ve
         solutions.extend(tactics::make_tuple(ctx, &defs, &mut lookup, should_continue));
     }
 
-    solutions.into_iter().filter(|it| !it.is_many()).unique().collect()
+    solutions
+        .into_iter()
+        .filter(|it| !it.is_many())
+        .unique()
+        .collect()
 }
@@ -69,10 +69,7 @@ pub enum Expr<'db> {
     /// Constant generic parameter
     ConstParam(ConstParam),
     /// Well known type (such as `true` for bool)
-    FamousType {
-        ty: Type<'db>,
-        value: &'static str,
-    },
+    FamousType { ty: Type<'db>, value: &'static str },
     /// Function call (does not take self param)
     Function {
         func: Function,
@@ -104,10 +101,7 @@ pub enum Expr<'db> {
         params: Vec<Expr<'db>>,
     },
     /// Struct field access
-    Field {
-        expr: Box<Expr<'db>>,
-        field: Field,
-    },
+    Field { expr: Box<Expr<'db>>, field: Field },
     /// Passing type as reference (with `&`)
     Reference(Box<Expr<'db>>),
     /// Indicates possibility of many different options that all evaluate to `ty`
@@ -184,7 +178,8 @@ impl<'db> Expr<'db> {
                     .into_iter()
                     .join(", ");
 
-                match func.as_assoc_item(db).and_then(|it| it.container_or_implemented_trait(db)) {
+                match func.as_assoc_item(db)
+                    .and_then(|it| it.container_or_implemented_trait(db)) {
                     Some(trait_) => {
                         let trait_name = mod_item_path_str(sema_scope, &ModuleDef::Trait(trait_))?;
                         let target = match self_param.access(db) {
@@ -336,7 +331,10 @@ impl<'db> Expr<'db> {
             }
             Expr::Method { func, generics, target, .. } => func.ret_type_with_args(
                 db,
-                target.ty(db).type_arguments().chain(generics.iter().cloned()),
+                target
+                    .ty(db)
+                    .type_arguments()
+                    .chain(generics.iter().cloned()),
             ),
             Expr::Variant { variant, generics, .. } => {
                 Adt::from(variant.parent_enum(db)).ty_with_args(db, generics.iter().cloned())
@@ -379,7 +377,8 @@ impl<'db> Expr<'db> {
     fn contains_many_in_illegal_pos(&self, db: &dyn HirDatabase) -> bool {
         match self {
             Expr::Method { target, func, .. } => {
-                match func.as_assoc_item(db).and_then(|it| it.container_or_implemented_trait(db)) {
+                match func.as_assoc_item(db)
+                    .and_then(|it| it.container_or_implemented_trait(db)) {
                     Some(_) => false,
                     None => target.is_many(),
                 }
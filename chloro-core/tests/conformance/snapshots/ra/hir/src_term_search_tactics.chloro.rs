@@ -447,7 +447,9 @@ pub(super) fn impl_method<'a, 'lt, 'db, DB: HirDatabase>(
         .filter(|ty| !ty.type_arguments().any(|it| it.contains_unknown()))
         .filter(|_| should_continue())
         .flat_map(|ty| {
-            Impl::all_for_type(db, ty.clone()).into_iter().map(move |imp| (ty.clone(), imp))
+            Impl::all_for_type(db, ty.clone())
+                .into_iter()
+                .map(move |imp| (ty.clone(), imp))
         })
         .flat_map(|(ty, imp)| imp.items(db).into_iter().map(move |item| (imp, ty.clone(), item)))
         .filter_map(|(imp, ty, it)| match it {
@@ -562,16 +564,18 @@ pub(super) fn struct_projection<'a, 'lt, 'db, DB: HirDatabase>(
         .map(|ty| (ty.clone(), lookup.find(db, &ty).expect("Expr not in lookup")))
         .filter(|_| should_continue())
         .flat_map(move |(ty, targets)| {
-            ty.fields(db).into_iter().filter_map(move |(field, filed_ty)| {
-                if !field.is_visible_from(db, module) {
+            ty.fields(db)
+                .into_iter()
+                .filter_map(move |(field, filed_ty)| {
+                    if !field.is_visible_from(db, module) {
                     return None;
                 }
-                let exprs = targets
-                    .clone()
-                    .into_iter()
-                    .map(move |target| Expr::Field { field, expr: Box::new(target) });
-                Some((filed_ty, exprs))
-            })
+                    let exprs = targets
+                        .clone()
+                        .into_iter()
+                        .map(move |target| Expr::Field { field, expr: Box::new(target) });
+                    Some((filed_ty, exprs))
+                })
         })
         .filter_map(|(ty, exprs)| ty.could_unify_with_deeply(db, &ctx.goal).then_some(exprs))
         .flatten()
@@ -601,9 +605,18 @@ pub(super) fn famous_types<'a, 'lt, 'db, DB: HirDatabase>(
     let bool_ty = Ty::new_bool(interner);
     let unit_ty = Ty::new_unit(interner);
     [
-        Expr::FamousType { ty: Type::new(db, module.id, bool_ty), value: "true" },
-        Expr::FamousType { ty: Type::new(db, module.id, bool_ty), value: "false" },
-        Expr::FamousType { ty: Type::new(db, module.id, unit_ty), value: "()" },
+        Expr::FamousType {
+        ty: Type::new(db, module.id, bool_ty),
+        value: "true",
+    },
+        Expr::FamousType {
+        ty: Type::new(db, module.id, bool_ty),
+        value: "false",
+    },
+        Expr::FamousType {
+        ty: Type::new(db, module.id, unit_ty),
+        value: "()",
+    },
     ]
     .into_iter()
     .inspect(|exprs| {
@@ -640,7 +653,9 @@ pub(super) fn impl_static_method<'a, 'lt, 'db, DB: HirDatabase>(
         .filter(|ty| !ty.type_arguments().any(|it| it.contains_unknown()))
         .filter(|_| should_continue())
         .flat_map(|ty| {
-            Impl::all_for_type(db, ty.clone()).into_iter().map(move |imp| (ty.clone(), imp))
+            Impl::all_for_type(db, ty.clone())
+                .into_iter()
+                .map(move |imp| (ty.clone(), imp))
         })
         .filter(|(_, imp)| !imp.is_unsafe(db))
         .flat_map(|(ty, imp)| imp.items(db).into_iter().map(move |item| (imp, ty.clone(), item)))
@@ -199,7 +199,11 @@ impl Attrs {
     #[inline]
     pub(crate) fn is_cfg_enabled(&self, cfg_options: &CfgOptions) -> Result<(), CfgExpr> {
         self.cfgs().try_for_each(|cfg| {
-            if cfg_options.check(&cfg) != Some(false) { Ok(()) } else { Err(cfg) }
+            if cfg_options.check(&cfg) != Some(false) {
+                Ok(())
+            } else {
+                Err(cfg)
+            }
         })
     }
 
@@ -210,22 +214,22 @@ impl Attrs {
 
     #[inline]
     pub fn lang_item(&self) -> Option<LangItem> {
-        self.by_key(sym::lang).string_value().and_then(LangItem::from_symbol)
+        self.by_key(sym::lang)
+            .string_value()
+            .and_then(LangItem::from_symbol)
     }
 
     #[inline]
     pub fn has_doc_hidden(&self) -> bool {
         self.by_key(sym::doc).tt_values().any(|tt| {
-            tt.top_subtree().delimiter.kind == DelimiterKind::Parenthesis &&
-                matches!(tt.token_trees().flat_tokens(), [tt::TokenTree::Leaf(tt::Leaf::Ident(ident))] if ident.sym == sym::hidden)
+            tt.top_subtree().delimiter.kind == DelimiterKind::Parenthesis && matches!(tt.token_trees().flat_tokens(), [tt::TokenTree::Leaf(tt::Leaf::Ident(ident))] if ident.sym == sym::hidden)
         })
     }
 
     #[inline]
     pub fn has_doc_notable_trait(&self) -> bool {
         self.by_key(sym::doc).tt_values().any(|tt| {
-            tt.top_subtree().delimiter.kind == DelimiterKind::Parenthesis &&
-                matches!(tt.token_trees().flat_tokens(), [tt::TokenTree::Leaf(tt::Leaf::Ident(ident))] if ident.sym == sym::notable_trait)
+            tt.top_subtree().delimiter.kind == DelimiterKind::Parenthesis && matches!(tt.token_trees().flat_tokens(), [tt::TokenTree::Leaf(tt::Leaf::Ident(ident))] if ident.sym == sym::notable_trait)
         })
     }
 
@@ -236,7 +240,8 @@ impl Attrs {
 
     #[inline]
     pub fn doc_aliases(&self) -> impl Iterator<Item = Symbol> + '_ {
-        self.doc_exprs().flat_map(|doc_expr| doc_expr.aliases().to_vec())
+        self.doc_exprs()
+            .flat_map(|doc_expr| doc_expr.aliases().to_vec())
     }
 
     #[inline]
@@ -298,12 +303,15 @@ impl Attrs {
 
     #[inline]
     pub fn repr(&self) -> Option<ReprOptions> {
-        self.by_key(sym::repr).tt_values().filter_map(parse_repr_tt).fold(None, |acc, repr| {
-            acc.map_or(Some(repr), |mut acc| {
-                merge_repr(&mut acc, repr);
-                Some(acc)
+        self.by_key(sym::repr)
+            .tt_values()
+            .filter_map(parse_repr_tt)
+            .fold(None, |acc, repr| {
+                acc.map_or(Some(repr), |mut acc| {
+                    merge_repr(&mut acc, repr);
+                    Some(acc)
+                })
             })
-        })
     }
 }
 
@@ -432,10 +440,7 @@ pub enum DocAtom {
     ///
     /// Note that a key can have multiple values that are all considered "active" at the same time.
     /// For example, `#[doc(alias = "x")]` and `#[doc(alias = "y")]`.
-    KeyValue {
-        key: Symbol,
-        value: Symbol,
-    },
+    KeyValue { key: Symbol, value: Symbol },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -514,7 +519,10 @@ fn parse_comma_sep<S>(iter: TtIter<'_, S>) -> Vec<Symbol> {
 
 impl AttrsWithOwner {
     pub fn new(db: &dyn DefDatabase, owner: AttrDefId) -> Self {
-        Self { attrs: db.attrs(owner), owner }
+        Self {
+            attrs: db.attrs(owner),
+            owner,
+        }
     }
 
     pub(crate) fn attrs_query(db: &dyn DefDatabase, def: AttrDefId) -> Attrs {
@@ -781,7 +789,9 @@ impl<'attr> AttrQuery<'attr> {
     #[inline]
     pub fn attrs(self) -> impl Iterator<Item = &'attr Attr> + Clone {
         let key = self.key;
-        self.attrs.iter().filter(move |attr| attr.path.as_ident().is_some_and(|s| *s == key))
+        self.attrs
+            .iter()
+            .filter(move |attr| attr.path.as_ident().is_some_and(|s| *s == key))
     }
 
     /// Find string value for a specific key inside token tree
@@ -793,13 +803,16 @@ impl<'attr> AttrQuery<'attr> {
     #[inline]
     pub fn find_string_value_in_tt(self, key: Symbol) -> Option<&'attr str> {
         self.tt_values().find_map(|tt| {
-            let name = tt.iter()
-                .skip_while(|tt| !matches!(tt, TtElement::Leaf(tt::Leaf::Ident(tt::Ident { sym, ..} )) if *sym == key))
+            let name = tt
+                .iter()
+                .skip_while(
+                    |tt| !matches!(tt, TtElement::Leaf(tt::Leaf::Ident(tt::Ident { sym, ..} )) if *sym == key),
+                )
                 .nth(2);
 
             match name {
                 Some(TtElement::Leaf(tt::Leaf::Literal(tt::Literal{  symbol: text, kind: tt::LitKind::Str | tt::LitKind::StrRaw(_) , ..}))) => Some(text.as_str()),
-                _ => None
+                _ => None,
             }
         })
     }
@@ -855,8 +868,14 @@ mod tests {
     use syntax_bridge::{DocCommentDesugarMode, syntax_node_to_token_tree};
     use crate::attr::{DocAtom, DocExpr};
     fn assert_parse_result(input: &str, expected: DocExpr) {
-        let source_file = ast::SourceFile::parse(input, span::Edition::CURRENT).ok().unwrap();
-        let tt = source_file.syntax().descendants().find_map(ast::TokenTree::cast).unwrap();
+        let source_file = ast::SourceFile::parse(input, span::Edition::CURRENT)
+            .ok()
+            .unwrap();
+        let tt = source_file
+            .syntax()
+            .descendants()
+            .find_map(ast::TokenTree::cast)
+            .unwrap();
         let map = SpanMap::RealSpanMap(Arc::new(RealSpanMap::absolute(
             EditionedFileId::current_edition(FileId::from_raw(0)),
         )));
@@ -366,8 +366,7 @@ fn macro_def(db: &dyn DefDatabase, id: MacroId) -> MacroDefId {
                 krate: loc.container.krate,
                 kind: kind(loc.expander, loc.id.file_id, loc.id.value.upcast()),
                 local_inner: loc.flags.contains(MacroRulesLocFlags::LOCAL_INNER),
-                allow_internal_unsafe: loc
-                    .flags
+                allow_internal_unsafe: loc.flags
                     .contains(MacroRulesLocFlags::ALLOW_INTERNAL_UNSAFE),
                 edition: loc.edition,
             }
@@ -94,7 +94,9 @@ pub mod keys {
         }
 
         fn is_empty(map: &DynMap) -> bool {
-            map.map.get::<FxHashMap<AstPtr<AST>, ID>>().is_none_or(|it| it.is_empty())
+            map.map
+                .get::<FxHashMap<AstPtr<AST>, ID>>()
+                .is_none_or(|it| it.is_empty())
         }
     }
 }
@@ -115,10 +117,12 @@ pub struct Key<K, V, P = (K, V)> {
 impl<K, V, P> Key<K, V, P> {
     #[allow(
         clippy::new_without_default,
-        reason = "this a const fn, so it can't be default yet. See <https://github.com/rust-lang/rust/issues/63065>"
+        reason = "this a const fn, so it can't be default yet. See <https://github.com/rust-lang/rust/issues/63065>",
     )]
     pub(crate) const fn new() -> Key<K, V, P> {
-        Key { _phantom: PhantomData }
+        Key {
+            _phantom: PhantomData,
+        }
     }
 }
 
@@ -148,7 +152,10 @@ impl<K: Hash + Eq + 'static, V: 'static> Policy for (K, V) {
     type V = V;
 
     fn insert(map: &mut DynMap, key: K, value: V) {
-        map.map.entry::<FxHashMap<K, V>>().or_insert_with(Default::default).insert(key, value);
+        map.map
+            .entry::<FxHashMap<K, V>>()
+            .or_insert_with(Default::default)
+            .insert(key, value);
     }
 
     fn get<'a>(map: &'a DynMap, key: &K) -> Option<&'a V> {
@@ -156,7 +163,9 @@ impl<K: Hash + Eq + 'static, V: 'static> Policy for (K, V) {
     }
 
     fn is_empty(map: &DynMap) -> bool {
-        map.map.get::<FxHashMap<K, V>>().is_none_or(|it| it.is_empty())
+        map.map
+            .get::<FxHashMap<K, V>>()
+            .is_none_or(|it| it.is_empty())
     }
 }
 
@@ -49,6 +49,7 @@ pub use self::lower::{
 pub struct HygieneId(span::SyntaxContext);
 
 impl HygieneId {
+    // The edition doesn't matter here, we only use this for comparisons and to lookup the macro.
     pub const ROOT: Self = Self(span::SyntaxContext::root(Edition::Edition2015));
 
     pub fn new(mut ctx: span::SyntaxContext) -> Self {
@@ -103,6 +104,8 @@ pub type LifetimePtr = AstPtr<ast::Lifetime>;
 
 pub type LifetimeSource = InFile<LifetimePtr>;
 
+// We split the store into types-only and expressions, because most stores (e.g. generics)
+// don't store any expressions and this saves memory. Same thing for the source map.
 #[derive(Debug, PartialEq, Eq)]
 struct ExpressionOnlyStore {
     exprs: Arena<Expr>,
@@ -187,7 +190,7 @@ pub struct ExpressionStoreSourceMap {
     lifetime_map_back: ArenaMap<LifetimeRefId, LifetimeSource>,
     #[expect(
         unused,
-        reason = "this is here for completeness, and maybe we'll need it in the future"
+        reason = "this is here for completeness, and maybe we'll need it in the future",
     )]
     lifetime_map: FxHashMap<LifetimeSource, LifetimeRefId>,
 }
@@ -399,8 +402,7 @@ impl ExpressionStoreBuilder {
 
 impl ExpressionStore {
     pub fn empty_singleton() -> (Arc<ExpressionStore>, Arc<ExpressionStoreSourceMap>) {
-        static EMPTY: LazyLock<(Arc<ExpressionStore>, Arc<ExpressionStoreSourceMap>)> =
-            LazyLock::new(|| {
+        static EMPTY: LazyLock<(Arc<ExpressionStore>, Arc<ExpressionStoreSourceMap>)> = LazyLock::new(|| {
                 let (store, source_map) = ExpressionStoreBuilder::default().finish();
                 (Arc::new(store), Arc::new(source_map))
             });
@@ -754,7 +756,9 @@ impl ExpressionStore {
     #[inline]
     #[track_caller]
     fn assert_expr_only(&self) -> &ExpressionOnlyStore {
-        self.expr_only.as_ref().expect("should have `ExpressionStore::expr_only`")
+        self.expr_only
+            .as_ref()
+            .expect("should have `ExpressionStore::expr_only`")
     }
 
     fn binding_hygiene(&self, binding: BindingId) -> HygieneId {
@@ -762,11 +766,19 @@ impl ExpressionStore {
     }
 
     pub fn expr_path_hygiene(&self, expr: ExprId) -> HygieneId {
-        self.assert_expr_only().ident_hygiene.get(&expr.into()).copied().unwrap_or(HygieneId::ROOT)
+        self.assert_expr_only()
+            .ident_hygiene
+            .get(&expr.into())
+            .copied()
+            .unwrap_or(HygieneId::ROOT)
     }
 
     pub fn pat_path_hygiene(&self, pat: PatId) -> HygieneId {
-        self.assert_expr_only().ident_hygiene.get(&pat.into()).copied().unwrap_or(HygieneId::ROOT)
+        self.assert_expr_only()
+            .ident_hygiene
+            .get(&pat.into())
+            .copied()
+            .unwrap_or(HygieneId::ROOT)
     }
 
     pub fn expr_or_pat_path_hygiene(&self, id: ExprOrPatId) -> HygieneId {
@@ -782,7 +794,8 @@ impl ExpressionStore {
             Some(it) => it.exprs.iter(),
             None => const {
                 &Arena::new()
-            }.iter(),
+            }
+            .iter(),
         }
     }
 
@@ -792,7 +805,8 @@ impl ExpressionStore {
             Some(it) => it.pats.iter(),
             None => const {
                 &Arena::new()
-            }.iter(),
+            }
+            .iter(),
         }
     }
 
@@ -802,7 +816,8 @@ impl ExpressionStore {
             Some(it) => it.bindings.iter(),
             None => const {
                 &Arena::new()
-            }.iter(),
+            }
+            .iter(),
         }
     }
 }
@@ -873,6 +888,8 @@ impl Index<PathId> for ExpressionStore {
     }
 }
 
+// FIXME: Change `node_` prefix to something more reasonable.
+// Perhaps `expr_syntax` and `expr_id`?
 impl ExpressionStoreSourceMap {
     pub fn expr_or_pat_syntax(&self, id: ExprOrPatId) -> Result<ExprOrPatSource, SyntheticSyntax> {
         match id {
@@ -894,11 +911,17 @@ impl ExpressionStoreSourceMap {
     #[inline]
     #[track_caller]
     fn assert_expr_only(&self) -> &ExpressionOnlySourceMap {
-        self.expr_only.as_ref().expect("should have `ExpressionStoreSourceMap::expr_only`")
+        self.expr_only
+            .as_ref()
+            .expect("should have `ExpressionStoreSourceMap::expr_only`")
     }
 
     pub fn expr_syntax(&self, expr: ExprId) -> Result<ExprOrPatSource, SyntheticSyntax> {
-        self.expr_or_synthetic()?.expr_map_back.get(expr).cloned().ok_or(SyntheticSyntax)
+        self.expr_or_synthetic()?
+            .expr_map_back
+            .get(expr)
+            .cloned()
+            .ok_or(SyntheticSyntax)
     }
 
     pub fn node_expr(&self, node: InFile<&ast::Expr>) -> Option<ExprOrPatId> {
@@ -912,15 +935,24 @@ impl ExpressionStoreSourceMap {
     }
 
     pub fn macro_calls(&self) -> impl Iterator<Item = (InFile<MacroCallPtr>, MacroCallId)> + '_ {
-        self.expr_only().into_iter().flat_map(|it| it.expansions.iter().map(|(&a, &b)| (a, b)))
+        self.expr_only()
+            .into_iter()
+            .flat_map(|it| it.expansions.iter().map(|(&a, &b)| (a, b)))
     }
 
     pub fn pat_syntax(&self, pat: PatId) -> Result<ExprOrPatSource, SyntheticSyntax> {
-        self.expr_or_synthetic()?.pat_map_back.get(pat).cloned().ok_or(SyntheticSyntax)
+        self.expr_or_synthetic()?
+            .pat_map_back
+            .get(pat)
+            .cloned()
+            .ok_or(SyntheticSyntax)
     }
 
     pub fn node_pat(&self, node: InFile<&ast::Pat>) -> Option<ExprOrPatId> {
-        self.expr_only()?.pat_map.get(&node.map(AstPtr::new)).cloned()
+        self.expr_only()?
+            .pat_map
+            .get(&node.map(AstPtr::new))
+            .cloned()
     }
 
     pub fn type_syntax(&self, id: TypeRefId) -> Result<TypeSource, SyntheticSyntax> {
@@ -936,7 +968,10 @@ impl ExpressionStoreSourceMap {
     }
 
     pub fn patterns_for_binding(&self, binding: BindingId) -> &[PatId] {
-        self.assert_expr_only().binding_definitions.get(binding).map_or(&[], Deref::deref)
+        self.assert_expr_only()
+            .binding_definitions
+            .get(binding)
+            .map_or(&[], Deref::deref)
     }
 
     pub fn node_label(&self, node: InFile<&ast::Label>) -> Option<LabelId> {
@@ -953,16 +988,24 @@ impl ExpressionStoreSourceMap {
     }
 
     pub fn macro_expansion_expr(&self, node: InFile<&ast::MacroExpr>) -> Option<ExprOrPatId> {
-        let src = node.map(AstPtr::new).map(AstPtr::upcast::<ast::MacroExpr>).map(AstPtr::upcast);
+        let src = node
+            .map(AstPtr::new)
+            .map(AstPtr::upcast::<ast::MacroExpr>)
+            .map(AstPtr::upcast);
         self.expr_only()?.expr_map.get(&src).copied()
     }
 
     pub fn expansions(&self) -> impl Iterator<Item = (&InFile<MacroCallPtr>, &MacroCallId)> {
-        self.expr_only().into_iter().flat_map(|it| it.expansions.iter())
+        self.expr_only()
+            .into_iter()
+            .flat_map(|it| it.expansions.iter())
     }
 
     pub fn expansion(&self, node: InFile<&ast::MacroCall>) -> Option<MacroCallId> {
-        self.expr_only()?.expansions.get(&node.map(AstPtr::new)).copied()
+        self.expr_only()?
+            .expansions
+            .get(&node.map(AstPtr::new))
+            .copied()
     }
 
     pub fn implicit_format_args(
@@ -999,12 +1042,19 @@ impl ExpressionStoreSourceMap {
         let src = node.map(AstPtr::new).map(AstPtr::upcast::<ast::Expr>);
         let expr = expr_only.expr_map.get(&src)?.as_expr()?;
         Some(expr).zip(
-            expr_only.template_map.as_ref()?.asm_to_captures.get(&expr).map(std::ops::Deref::deref),
+            expr_only
+                .template_map
+                .as_ref()?
+                .asm_to_captures
+                .get(&expr)
+                .map(std::ops::Deref::deref),
         )
     }
 
     /// Get a reference to the source map's diagnostics.
     pub fn diagnostics(&self) -> &[ExpressionStoreDiagnostics] {
-        self.expr_only().map(|it| &*it.diagnostics).unwrap_or_default()
+        self.expr_only()
+            .map(|it| &*it.diagnostics)
+            .unwrap_or_default()
     }
 }
@@ -62,7 +62,12 @@ impl Expander {
     pub(super) fn hygiene_for_range(&self, db: &dyn DefDatabase, range: TextRange) -> HygieneId {
         match self.span_map.as_ref() {
             hir_expand::span_map::SpanMapRef::ExpansionSpanMap(span_map) => {
-                HygieneId::new(span_map.span_at(range.start()).ctx.opaque_and_semitransparent(db))
+                HygieneId::new(
+                    span_map
+                        .span_at(range.start())
+                        .ctx
+                        .opaque_and_semitransparent(db),
+                )
             }
             hir_expand::span_map::SpanMapRef::RealSpanMap(_) => HygieneId::ROOT,
         }
@@ -164,7 +169,10 @@ impl Expander {
     }
 
     pub(super) fn in_file<T>(&self, value: T) -> InFile<T> {
-        InFile { file_id: self.current_file_id, value }
+        InFile {
+            file_id: self.current_file_id,
+            value,
+        }
     }
 
     pub(super) fn current_file_id(&self) -> HirFileId {
@@ -166,8 +166,16 @@ pub(super) fn lower_body(
 
     let (store, source_map) = collector.store.finish();
     (
-        Body { store, params: params.into_boxed_slice(), self_param, body_expr },
-        BodySourceMap { self_param: source_map_self_param, store: source_map },
+        Body {
+        store,
+        params: params.into_boxed_slice(),
+        self_param,
+        body_expr,
+    },
+        BodySourceMap {
+        self_param: source_map_self_param,
+        store: source_map,
+    },
     )
 }
 
@@ -881,16 +889,18 @@ impl ExprCollector<'_> {
 
     fn collect(&mut self, expr: Option<ast::Expr>, awaitable: Awaitable) -> ExprId {
         self.awaitable_context.replace(awaitable);
-        self.with_label_rib(
-            RibKind::Closure,
-            |this| {
+        self.with_label_rib(RibKind::Closure, |this| {
             if awaitable == Awaitable::Yes {
                 match expr {
                     Some(e) => {
                         let syntax_ptr = AstPtr::new(&e);
                         let expr = this.collect_expr(e);
                         this.alloc_expr_desugared_with_ptr(
-                            Expr::Async { id: None, statements: Box::new([]), tail: Some(expr) },
+                            Expr::Async {
+                                id: None,
+                                statements: Box::new([]),
+                                tail: Some(expr),
+                            },
                             syntax_ptr,
                         )
                     }
@@ -899,8 +909,7 @@ impl ExprCollector<'_> {
             } else {
                 this.collect_expr_opt(expr)
             }
-        },
-        )
+        })
     }
 
     fn type_bounds_from_ast(
@@ -910,7 +919,9 @@ impl ExprCollector<'_> {
     ) -> ThinVec<TypeBound> {
         if let Some(type_bounds) = type_bounds_opt {
             ThinVec::from_iter(Vec::from_iter(
-                type_bounds.bounds().map(|it| self.lower_type_bound(it, impl_trait_lower_fn)),
+                type_bounds
+                    .bounds()
+                    .map(|it| self.lower_type_bound(it, impl_trait_lower_fn)),
             ))
         } else {
             ThinVec::from_iter([])
@@ -972,15 +983,20 @@ impl ExprCollector<'_> {
     }
 
     fn lower_const_arg_opt(&mut self, arg: Option<ast::ConstArg>) -> ConstRef {
-        ConstRef { expr: self.collect_expr_opt(arg.and_then(|it| it.expr())) }
+        ConstRef {
+            expr: self.collect_expr_opt(arg.and_then(|it| it.expr())),
+        }
     }
 
     fn lower_const_arg(&mut self, arg: ast::ConstArg) -> ConstRef {
-        ConstRef { expr: self.collect_expr_opt(arg.expr()) }
+        ConstRef {
+            expr: self.collect_expr_opt(arg.expr()),
+        }
     }
 
     fn collect_expr(&mut self, expr: ast::Expr) -> ExprId {
-        self.maybe_collect_expr(expr).unwrap_or_else(|| self.missing_expr())
+        self.maybe_collect_expr(expr)
+            .unwrap_or_else(|| self.missing_expr())
     }
 
     /// Returns `None` if and only if the expression is `#[cfg]`d out.
@@ -1005,7 +1021,14 @@ impl ExprCollector<'_> {
 
                 let condition = self.collect_expr_opt(e.condition());
 
-                self.alloc_expr(Expr::If { condition, then_branch, else_branch }, syntax_ptr)
+                self.alloc_expr(
+                    Expr::If {
+                        condition,
+                        then_branch,
+                        else_branch,
+                    },
+                    syntax_ptr,
+                )
             }
             ast::Expr::LetExpr(e) => {
                 let pat = self.collect_pat_top(e.pat());
@@ -1015,35 +1038,26 @@ impl ExprCollector<'_> {
             ast::Expr::BlockExpr(e) => match e.modifier() {
                 Some(ast::BlockModifier::Try(_)) => self.desugar_try_block(e),
                 Some(ast::BlockModifier::Unsafe(_)) => {
-                    self.collect_block_(
-                        e,
-                        |id, statements, tail| Expr::Unsafe {
+                    self.collect_block_(e, |id, statements, tail| Expr::Unsafe {
                         id,
                         statements,
                         tail,
-                    },
-                    )
+                    })
                 }
                 Some(ast::BlockModifier::Label(label)) => {
                     let label_hygiene = self.hygiene_id_for(label.syntax().text_range());
                     let label_id = self.collect_label(label);
-                    self.with_labeled_rib(
-                        label_id,
-                        label_hygiene,
-                        |this| {
+                    self.with_labeled_rib(label_id, label_hygiene, |this| {
                         this.collect_block_(e, |id, statements, tail| Expr::Block {
                             id,
                             statements,
                             tail,
                             label: Some(label_id),
                         })
-                    },
-                    )
+                    })
                 }
                 Some(ast::BlockModifier::Async(_)) => {
-                    self.with_label_rib(
-                        RibKind::Closure,
-                        |this| {
+                    self.with_label_rib(RibKind::Closure, |this| {
                         this.with_awaitable_block(Awaitable::Yes, |this| {
                             this.collect_block_(e, |id, statements, tail| Expr::Async {
                                 id,
@@ -1051,13 +1065,10 @@ impl ExprCollector<'_> {
                                 tail,
                             })
                         })
-                    },
-                    )
+                    })
                 }
                 Some(ast::BlockModifier::Const(_)) => {
-                    self.with_label_rib(
-                        RibKind::Constant,
-                        |this| {
+                    self.with_label_rib(RibKind::Constant, |this| {
                         this.with_awaitable_block(Awaitable::No("constant block"), |this| {
                             let (result_expr_id, prev_binding_owner) =
                                 this.initialize_binding_owner(syntax_ptr);
@@ -1066,20 +1077,15 @@ impl ExprCollector<'_> {
                             this.current_binding_owner = prev_binding_owner;
                             result_expr_id
                         })
-                    },
-                    )
+                    })
                 }
                 // FIXME
                 Some(ast::BlockModifier::AsyncGen(_)) => {
                     self.with_awaitable_block(Awaitable::Yes, |this| this.collect_block(e))
                 }
-                Some(ast::BlockModifier::Gen(_)) => self
-                .with_awaitable_block(
-                    Awaitable::No("non-async gen block"),
-                    |this| {
-                        this.collect_block(e)
-                    },
-                ),
+                Some(ast::BlockModifier::Gen(_)) => self.with_awaitable_block(Awaitable::No("non-async gen block"), |this| {
+                    this.collect_block(e)
+                }),
                 None => self.collect_block(e),
             },
             ast::Expr::LoopExpr(e) => {
@@ -1087,7 +1093,13 @@ impl ExprCollector<'_> {
                     (self.hygiene_id_for(label.syntax().text_range()), self.collect_label(label))
                 });
                 let body = self.collect_labelled_block_opt(label, e.loop_body());
-                self.alloc_expr(Expr::Loop { body, label: label.map(|it| it.1) }, syntax_ptr)
+                self.alloc_expr(
+                    Expr::Loop {
+                        body,
+                        label: label.map(|it| it.1),
+                    },
+                    syntax_ptr,
+                )
             }
             ast::Expr::WhileExpr(e) => self.collect_while_loop(syntax_ptr, e),
             ast::Expr::ForExpr(e) => self.collect_for_loop(syntax_ptr, e),
@@ -1117,7 +1129,10 @@ impl ExprCollector<'_> {
                 } else {
                     Box::default()
                 };
-                let method_name = e.name_ref().map(|nr| nr.as_name()).unwrap_or_else(Name::missing);
+                let method_name = e
+                    .name_ref()
+                    .map(|nr| nr.as_name())
+                    .unwrap_or_else(Name::missing);
                 let generic_args = e
                     .generic_arg_list()
                     .and_then(|it| {
@@ -1125,7 +1140,12 @@ impl ExprCollector<'_> {
                     })
                     .map(Box::new);
                 self.alloc_expr(
-                    Expr::MethodCall { receiver, method_name, args, generic_args },
+                    Expr::MethodCall {
+                        receiver,
+                        method_name,
+                        args,
+                        generic_args,
+                    },
                     syntax_ptr,
                 )
             }
@@ -1269,7 +1289,14 @@ impl ExprCollector<'_> {
                     Mutability::from_mutable(e.mut_token().is_some())
                 };
                 let rawness = Rawness::from_raw(raw_tok);
-                self.alloc_expr(Expr::Ref { expr, rawness, mutability }, syntax_ptr)
+                self.alloc_expr(
+                    Expr::Ref {
+                        expr,
+                        rawness,
+                        mutability,
+                    },
+                    syntax_ptr,
+                )
             }
             ast::Expr::PrefixExpr(e) => {
                 let expr = self.collect_expr_opt(e.expr());
@@ -1278,9 +1305,7 @@ impl ExprCollector<'_> {
                     None => self.alloc_expr(Expr::Missing, syntax_ptr),
                 }
             }
-            ast::Expr::ClosureExpr(e) => self.with_label_rib(
-                RibKind::Closure,
-                |this| {
+            ast::Expr::ClosureExpr(e) => self.with_label_rib(RibKind::Closure, |this| {
                 let (result_expr_id, prev_binding_owner) =
                     this.initialize_binding_owner(syntax_ptr);
                 let mut args = Vec::new();
@@ -1339,8 +1364,7 @@ impl ExprCollector<'_> {
                     capture_by,
                 };
                 result_expr_id
-            },
-            ),
+            }),
             ast::Expr::BinExpr(e) => {
                 let op = e.op_kind();
                 if let Some(ast::BinaryOp::Assignment { op: None }) = op {
@@ -1361,7 +1385,12 @@ impl ExprCollector<'_> {
                     exprs.insert(0, self.missing_expr());
                 }
 
-                self.alloc_expr(Expr::Tuple { exprs: exprs.into_boxed_slice() }, syntax_ptr)
+                self.alloc_expr(
+                    Expr::Tuple {
+                        exprs: exprs.into_boxed_slice(),
+                    },
+                    syntax_ptr,
+                )
             }
             ast::Expr::ArrayExpr(e) => {
                 let kind = e.kind();
@@ -1385,7 +1414,10 @@ impl ExprCollector<'_> {
                             }
                         });
                         self.alloc_expr(
-                            Expr::Array(Array::Repeat { initializer, repeat }),
+                            Expr::Array(Array::Repeat {
+                                initializer,
+                                repeat,
+                            }),
                             syntax_ptr,
                         )
                     }
@@ -1403,7 +1435,14 @@ impl ExprCollector<'_> {
                 let rhs = e.end().map(|rhs| self.collect_expr(rhs));
                 match e.op_kind() {
                     Some(range_type) => {
-                        self.alloc_expr(Expr::Range { lhs, rhs, range_type }, syntax_ptr)
+                        self.alloc_expr(
+                            Expr::Range {
+                                lhs,
+                                rhs,
+                                range_type,
+                            },
+                            syntax_ptr,
+                        )
                     }
                     None => self.alloc_expr(Expr::Missing, syntax_ptr),
                 }
@@ -1931,9 +1970,12 @@ impl ExprCollector<'_> {
         let macro_call_ptr = self.expander.in_file(syntax_ptr);
         let module = self.module.local_id;
 
-        let block_call = self.def_map.modules[self.module.local_id].scope.macro_invoc(
-            self.expander.in_file(self.expander.ast_id_map().ast_id_for_ptr(syntax_ptr)),
-        );
+        let block_call = self.def_map.modules[self.module.local_id]
+            .scope
+            .macro_invoc(
+                self.expander
+                    .in_file(self.expander.ast_id_map().ast_id_for_ptr(syntax_ptr)),
+            );
         let res = match block_call {
             // fast path, macro call is in a block module
             Some(call) => Ok(self.expander.enter_expand_id(self.db, call)),
@@ -2038,7 +2080,9 @@ impl ExprCollector<'_> {
                     return;
                 }
                 let pat = self.collect_pat_top(stmt.pat());
-                let type_ref = stmt.ty().map(|it| self.lower_type_ref_disallow_impl_trait(it));
+                let type_ref = stmt
+                    .ty()
+                    .map(|it| self.lower_type_ref_disallow_impl_trait(it));
                 let initializer = stmt.initializer().map(|e| self.collect_expr(e));
                 let else_branch = stmt
                     .let_else()
@@ -2072,7 +2116,10 @@ impl ExprCollector<'_> {
                     return;
                 };
                 let name = name.as_name();
-                let macro_id = self.def_map.modules[DefMap::ROOT].scope.get(&name).take_macros();
+                let macro_id = self.def_map.modules[DefMap::ROOT]
+                    .scope
+                    .get(&name)
+                    .take_macros();
                 self.collect_macro_def(statements, macro_id);
             }
             ast::Stmt::Item(ast::Item::MacroRules(macro_)) => {
@@ -2106,19 +2153,17 @@ impl ExprCollector<'_> {
         };
         let macro_id = self.db.macro_def(macro_id);
         statements.push(Statement::Item(Item::MacroDef(Box::new(macro_id))));
-        self.label_ribs.push(LabelRib::new(RibKind::MacroDef(Box::new(macro_id))));
+        self.label_ribs
+            .push(LabelRib::new(RibKind::MacroDef(Box::new(macro_id))));
     }
 
     fn collect_block(&mut self, block: ast::BlockExpr) -> ExprId {
-        self.collect_block_(
-            block,
-            |id, statements, tail| Expr::Block {
+        self.collect_block_(block, |id, statements, tail| Expr::Block {
             id,
             statements,
             tail,
             label: None,
-        },
-        )
+        })
     }
 
     fn collect_block_(
@@ -2126,10 +2171,17 @@ impl ExprCollector<'_> {
         block: ast::BlockExpr,
         mk_block: impl FnOnce(Option<BlockId>, Box<[Statement]>, Option<ExprId>) -> Expr,
     ) -> ExprId {
-        let block_id = self.expander.ast_id_map().ast_id_for_block(&block).map(|file_local_id| {
-            let ast_id = self.expander.in_file(file_local_id);
-            self.db.intern_block(BlockLoc { ast_id, module: self.module })
-        });
+        let block_id = self
+            .expander
+            .ast_id_map()
+            .ast_id_for_block(&block)
+            .map(|file_local_id| {
+                let ast_id = self.expander.in_file(file_local_id);
+                self.db.intern_block(BlockLoc {
+                    ast_id,
+                    module: self.module,
+                })
+            });
 
         let (module, def_map) =
             match block_id.map(|block_id| (block_def_map(self.db, block_id), block_id)) {
@@ -2144,7 +2196,9 @@ impl ExprCollector<'_> {
         let prev_legacy_macros_count = mem::take(&mut self.current_block_legacy_macro_defs_count);
 
         let mut statements = Vec::new();
-        block.statements().for_each(|s| self.collect_stmt(&mut statements, s));
+        block
+            .statements()
+            .for_each(|s| self.collect_stmt(&mut statements, s));
         let tail = block.tail_expr().and_then(|e| match e {
             ast::Expr::MacroExpr(mac) => self.collect_macro_as_stmt(&mut statements, mac),
             expr => self.maybe_collect_expr(expr),
@@ -2457,7 +2511,9 @@ impl ExprCollector<'_> {
         has_leading_comma: bool,
         binding_list: &mut BindingList,
     ) -> (Box<[PatId]>, Option<u32>) {
-        let args: Vec<_> = args.map(|p| self.collect_pat_possibly_rest(p, binding_list)).collect();
+        let args: Vec<_> = args
+            .map(|p| self.collect_pat_possibly_rest(p, binding_list))
+            .collect();
         // Find the location of the `..`, if there is one. Note that we do not
         // consider the possibility of there being multiple `..` here.
         let ellipsis = args.iter().position(|p| p.is_right()).map(|it| it as u32);
@@ -2513,22 +2569,30 @@ impl ExprCollector<'_> {
     /// Returns `None` (and emits diagnostics) when `owner` if `#[cfg]`d out, and `Some(())` when
     /// not.
     fn check_cfg(&mut self, owner: &dyn ast::HasAttrs) -> bool {
-        let enabled = self.expander.is_cfg_enabled(self.db, owner, self.cfg_options);
+        let enabled = self
+            .expander
+            .is_cfg_enabled(self.db, owner, self.cfg_options);
         match enabled {
             Ok(()) => true,
             Err(cfg) => {
-                self.store.diagnostics.push(ExpressionStoreDiagnostics::InactiveCode {
-                    node: self.expander.in_file(SyntaxNodePtr::new(owner.syntax())),
-                    cfg,
-                    opts: self.cfg_options.clone(),
-                });
+                self.store
+                    .diagnostics
+                    .push(ExpressionStoreDiagnostics::InactiveCode {
+                        node: self.expander.in_file(SyntaxNodePtr::new(owner.syntax())),
+                        cfg,
+                        opts: self.cfg_options.clone(),
+                    });
                 false
             }
         }
     }
 
     fn add_definition_to_binding(&mut self, binding_id: BindingId, pat_id: PatId) {
-        self.store.binding_definitions.entry(binding_id).or_default().push(pat_id);
+        self.store
+            .binding_definitions
+            .entry(binding_id)
+            .or_default()
+            .push(pat_id);
     }
 
     // region: labels
@@ -2600,7 +2664,9 @@ impl ExprCollector<'_> {
     }
 
     fn is_label_valid_from_rib(&self, rib_index: usize) -> bool {
-        !self.label_ribs[rib_index + 1..].iter().any(|rib| rib.kind.is_label_barrier())
+        !self.label_ribs[rib_index + 1..]
+            .iter()
+            .any(|rib| rib.kind.is_label_barrier())
     }
 
     fn pop_label_rib(&mut self) {
@@ -2623,11 +2689,9 @@ impl ExprCollector<'_> {
         hygiene: HygieneId,
         f: impl FnOnce(&mut Self) -> T,
     ) -> T {
-        self.label_ribs.push(LabelRib::new(RibKind::Normal(
-            self.store.labels[label].name.clone(),
-            label,
-            hygiene,
-        )));
+        self.label_ribs.push(
+            LabelRib::new(RibKind::Normal(self.store.labels[label].name.clone(), label, hygiene)),
+        );
         let res = f(self);
         self.pop_label_rib();
         res
@@ -2757,11 +2821,12 @@ impl ExprCollector<'_> {
                     }
                     &FormatArgsPiece::Placeholder(_) => {
                         // Inject empty string before placeholders when not already preceded by a literal piece.
-                        if i == 0 || matches!(fmt.template[i - 1], FormatArgsPiece::Placeholder(_))
-                        {
-                            Some(self.alloc_expr_desugared(Expr::Literal(Literal::String(
-                                Symbol::empty(),
-                            ))))
+                        if i == 0 || matches!(fmt.template[i - 1], FormatArgsPiece::Placeholder(_)) {
+                            Some(
+                                self.alloc_expr_desugared(
+                                    Expr::Literal(Literal::String(Symbol::empty())),
+                                ),
+                            )
                         } else {
                             None
                         }
@@ -3182,10 +3247,12 @@ impl ExprCollector<'_> {
             let width =
                 RecordLitField { name: Name::new_symbol_root(sym::width), expr: width_expr };
             self.alloc_expr_desugared(Expr::RecordLit {
-                    path: LangItem::FormatPlaceholder.path(self.db, self.module.krate()).map(Box::new),
-                    fields: Box::new([position, flags, precision, width]),
-                    spread: None,
-                })
+                path: LangItem::FormatPlaceholder
+                    .path(self.db, self.module.krate())
+                    .map(Box::new),
+                fields: Box::new([position, flags, precision, width]),
+                spread: None,
+            })
         } else {
             let format_placeholder_new = {
                 let format_placeholder_new = LangItem::FormatPlaceholder.ty_rel_path(
@@ -3227,9 +3294,9 @@ impl ExprCollector<'_> {
                 }
             };
             self.alloc_expr_desugared(Expr::Call {
-                    callee: format_placeholder_new,
-                    args: Box::new([position, fill, align, flags, precision_expr, width_expr]),
-                })
+                callee: format_placeholder_new,
+                args: Box::new([position, fill, align, flags, precision_expr, width_expr]),
+            })
         }
     }
 
@@ -3273,9 +3340,9 @@ impl ExprCollector<'_> {
                     None => self.missing_expr(),
                 };
                 self.alloc_expr_desugared(Expr::Call {
-                        callee: count_is,
-                        args: Box::new([args]),
-                    })
+                    callee: count_is,
+                    args: Box::new([args]),
+                })
             }
             Some(FormatCount::Argument(arg)) => {
                 if let Ok(arg_index) = arg.index {
@@ -3294,9 +3361,9 @@ impl ExprCollector<'_> {
                         None => self.missing_expr(),
                     };
                     self.alloc_expr_desugared(Expr::Call {
-                            callee: count_param,
-                            args: Box::new([args]),
-                        })
+                        callee: count_param,
+                        args: Box::new([args]),
+                    })
                 } else {
                     // FIXME: This drops arg causing it to potentially not be resolved/type checked
                     // when typing?
@@ -3345,9 +3412,9 @@ impl ExprCollector<'_> {
             None => self.missing_expr(),
         };
         self.alloc_expr_desugared(Expr::Call {
-                callee: new_fn,
-                args: Box::new([arg]),
-            })
+            callee: new_fn,
+            args: Box::new([arg]),
+        })
     }
 
     // endregion: format
@@ -3370,7 +3437,9 @@ impl ExprCollector<'_> {
     fn alloc_expr(&mut self, expr: Expr, ptr: ExprPtr) -> ExprId {
         let src = self.expander.in_file(ptr);
         let id = self.store.exprs.alloc(expr);
-        self.store.expr_map_back.insert(id, src.map(AstPtr::wrap_left));
+        self.store
+            .expr_map_back
+            .insert(id, src.map(AstPtr::wrap_left));
         self.store.expr_map.insert(src, id.into());
         id
     }
@@ -3384,7 +3453,9 @@ impl ExprCollector<'_> {
     fn alloc_expr_desugared_with_ptr(&mut self, expr: Expr, ptr: ExprPtr) -> ExprId {
         let src = self.expander.in_file(ptr);
         let id = self.store.exprs.alloc(expr);
-        self.store.expr_map_back.insert(id, src.map(AstPtr::wrap_left));
+        self.store
+            .expr_map_back
+            .insert(id, src.map(AstPtr::wrap_left));
         // We intentionally don't fill this as it could overwrite a non-desugared entry
         // self.store.expr_map.insert(src, id);
         id
@@ -3400,7 +3471,12 @@ impl ExprCollector<'_> {
         mode: BindingAnnotation,
         hygiene: HygieneId,
     ) -> BindingId {
-        let binding = self.store.bindings.alloc(Binding { name, mode, problems: None, hygiene });
+        let binding = self.store.bindings.alloc(Binding {
+            name,
+            mode,
+            problems: None,
+            hygiene,
+        });
         if let Some(owner) = self.current_binding_owner {
             self.store.binding_owners.insert(binding, owner);
         }
@@ -3411,7 +3487,9 @@ impl ExprCollector<'_> {
         let src = self.expander.in_file(ptr);
         let id = self.store.pats.alloc(pat);
         self.store.expr_map.insert(src, id.into());
-        self.store.pat_map_back.insert(id, src.map(AstPtr::wrap_left));
+        self.store
+            .pat_map_back
+            .insert(id, src.map(AstPtr::wrap_left));
         id
     }
 
@@ -3419,14 +3497,18 @@ impl ExprCollector<'_> {
         let src = self.expander.in_file(ptr);
         let id = self.store.exprs.alloc(expr);
         self.store.pat_map.insert(src, id.into());
-        self.store.expr_map_back.insert(id, src.map(AstPtr::wrap_right));
+        self.store
+            .expr_map_back
+            .insert(id, src.map(AstPtr::wrap_right));
         id
     }
 
     fn alloc_pat(&mut self, pat: Pat, ptr: PatPtr) -> PatId {
         let src = self.expander.in_file(ptr);
         let id = self.store.pats.alloc(pat);
-        self.store.pat_map_back.insert(id, src.map(AstPtr::wrap_right));
+        self.store
+            .pat_map_back
+            .insert(id, src.map(AstPtr::wrap_right));
         self.store.pat_map.insert(src, id.into());
         id
     }
@@ -3454,7 +3536,9 @@ impl ExprCollector<'_> {
     }
 
     fn is_lowering_awaitable_block(&self) -> &Awaitable {
-        self.awaitable_context.as_ref().unwrap_or(&Awaitable::No("unknown"))
+        self.awaitable_context
+            .as_ref()
+            .unwrap_or(&Awaitable::No("unknown"))
     }
 
     fn with_awaitable_block<T>(
@@ -3475,9 +3559,7 @@ impl ExprCollector<'_> {
 
 fn comma_follows_token(t: Option<syntax::SyntaxToken>) -> bool {
     (|| syntax::algo::skip_trivia_token(t?.next_token()?, syntax::Direction::Next))()
-    .is_some_and(
-        |it| it.kind() == syntax::T![,],
-    )
+        .is_some_and(|it| it.kind() == syntax::T![,])
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
@@ -75,14 +75,11 @@ impl GenericParamsCollector {
         ec: &mut ExprCollector<'_>,
         cb: impl FnOnce(&mut ExprCollector<'_>, ImplTraitLowerFn<'_>) -> R,
     ) -> R {
-        cb(
-            ec,
-            &mut Self::lower_argument_impl_trait(
+        cb(ec, &mut Self::lower_argument_impl_trait(
             &mut self.type_or_consts,
             &mut self.where_predicates,
             self.parent,
-        ),
-        )
+        ))
     }
 
     pub(crate) fn finish(self) -> Arc<GenericParams> {
@@ -118,7 +115,9 @@ impl GenericParamsCollector {
 
             match generic_param {
                 ast::GenericParam::TypeParam(type_param) => {
-                    let name = type_param.name().map_or_else(Name::missing, |it| it.as_name());
+                    let name = type_param
+                        .name()
+                        .map_or_else(Name::missing, |it| it.as_name());
                     let default = type_param.default_type().map(|it| {
                         ec.lower_type_ref(it, &mut ExprCollector::impl_trait_error_allocator)
                     });
@@ -137,7 +136,9 @@ impl GenericParamsCollector {
                     self.lower_bounds(ec, type_param.type_bound_list(), Either::Left(type_ref));
                 }
                 ast::GenericParam::ConstParam(const_param) => {
-                    let name = const_param.name().map_or_else(Name::missing, |it| it.as_name());
+                    let name = const_param
+                        .name()
+                        .map_or_else(Name::missing, |it| it.as_name());
                     let ty = ec.lower_type_ref_opt(
                         const_param.ty(),
                         &mut ExprCollector::impl_trait_error_allocator,
@@ -205,7 +206,9 @@ impl GenericParamsCollector {
         type_bounds: Option<ast::TypeBoundList>,
         target: Either<TypeRefId, LifetimeRefId>,
     ) {
-        for bound in type_bounds.iter().flat_map(|type_bound_list| type_bound_list.bounds()) {
+        for bound in type_bounds
+            .iter()
+            .flat_map(|type_bound_list| type_bound_list.bounds()) {
             self.lower_type_bound_as_predicate(ec, bound, None, target);
         }
     }
@@ -26,6 +26,7 @@ pub enum Path {
     LangItem(LangItemTarget, Option<Name>),
 }
 
+// This type is being used a lot, make sure it doesn't grow unintentionally.
 #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 const () = {
     assert!(size_of::<Path>() == 24);
@@ -169,7 +170,9 @@ impl Path {
                 }
                 Some(Path::BarePath(Interned::new(ModPath::from_segments(
                     mod_path.kind,
-                    mod_path.segments()[..mod_path.segments().len() - 1].iter().cloned(),
+                    mod_path.segments()[..mod_path.segments().len() - 1]
+                        .iter()
+                        .cloned(),
                 ))))
             }
             Path::Normal(path) => {
@@ -202,9 +205,7 @@ impl Path {
         match self {
             Path::BarePath(mod_path) => mod_path.is_Self(),
             Path::Normal(path) => {
-                path.type_anchor.is_none()
-                    && path.mod_path.is_Self()
-                    && path.generic_args.iter().all(|args| args.is_none())
+                path.type_anchor.is_none() && path.mod_path.is_Self() && path.generic_args.iter().all(|args| args.is_none())
             }
             Path::LangItem(..) => false,
         }
@@ -271,15 +272,17 @@ impl<'a> PathSegments<'a> {
     pub fn strip_last(&self) -> PathSegments<'a> {
         PathSegments {
             segments: self.segments.split_last().map_or(&[], |it| it.1),
-            generic_args: self.generic_args.map(|it| it.split_last().map_or(&[][..], |it| it.1)),
+            generic_args: self.generic_args
+                .map(|it| it.split_last().map_or(&[][..], |it| it.1)),
         }
     }
 
     pub fn strip_last_two(&self) -> PathSegments<'a> {
         PathSegments {
-            segments: self.segments.get(..self.segments.len().saturating_sub(2)).unwrap_or(&[]),
-            generic_args: self
-                .generic_args
+            segments: self.segments
+                .get(..self.segments.len().saturating_sub(2))
+                .unwrap_or(&[]),
+            generic_args: self.generic_args
                 .map(|it| it.get(..it.len().saturating_sub(2)).unwrap_or(&[])),
         }
     }
@@ -287,7 +290,12 @@ impl<'a> PathSegments<'a> {
     pub fn iter(&self) -> impl Iterator<Item = PathSegment<'a>> {
         self.segments
             .iter()
-            .zip(self.generic_args.into_iter().flatten().chain(iter::repeat(&None)))
+            .zip(
+                self.generic_args
+                    .into_iter()
+                    .flatten()
+                    .chain(iter::repeat(&None)),
+            )
             .map(|(name, args)| PathSegment { name, args_and_bindings: args.as_ref() })
     }
 }
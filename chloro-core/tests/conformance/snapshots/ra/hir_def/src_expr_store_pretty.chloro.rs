@@ -11,9 +11,9 @@ use hir_expand::{Lookup, mod_path::PathKind};
 use itertools::Itertools;
 use span::Edition;
 use syntax::ast::HasName;
+
 use crate::{LifetimeParamId, signatures::StructFlags};
 use crate::{item_tree::FieldsShape, signatures::FieldData};
-
 use crate::{
     AdtId, DefWithBodyId, GenericDefId, TypeParamId, VariantId,
     expr_store::path::{GenericArg, GenericArgs},
@@ -59,7 +59,10 @@ where
 {
     let loc = id.lookup(db);
     let source = loc.source(db);
-    source.value.name().map_or_else(|| default.to_owned(), |name| name.to_string())
+    source
+        .value
+        .name()
+        .map_or_else(|| default.to_owned(), |name| name.to_string())
 }
 
 pub fn print_body_hir(
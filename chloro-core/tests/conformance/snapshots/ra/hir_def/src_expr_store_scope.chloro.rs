@@ -183,7 +183,11 @@ impl ExprScopes {
         hygiene: HygieneId,
     ) {
         let Binding { name, .. } = &store[binding];
-        let entry = self.scope_entries.alloc(ScopeEntry { name: name.clone(), binding, hygiene });
+        let entry = self.scope_entries.alloc(ScopeEntry {
+            name: name.clone(),
+            binding,
+            hygiene,
+        });
         self.scopes[scope].entries =
             IdxRange::new_inclusive(self.scopes[scope].entries.start()..=entry);
     }
@@ -198,7 +202,9 @@ impl ExprScopes {
     }
 
     fn add_params_bindings(&mut self, store: &ExpressionStore, scope: ScopeId, params: &[PatId]) {
-        params.iter().for_each(|pat| self.add_pat_bindings(store, scope, *pat));
+        params
+            .iter()
+            .for_each(|pat| self.add_pat_bindings(store, scope, *pat));
     }
 
     fn set_scope(&mut self, node: ExprId, scope: ScopeId) {
@@ -365,7 +371,10 @@ mod tests {
         let (_body, source_map) = db.body_with_source_map(function.into());
 
         let expr_id = source_map
-            .node_expr(InFile { file_id: editioned_file_id.into(), value: &marker.into() })
+            .node_expr(InFile {
+                file_id: editioned_file_id.into(),
+                value: &marker.into(),
+            })
             .unwrap()
             .as_expr()
             .unwrap();
@@ -523,7 +532,9 @@ fn foo() {
             scopes.scope_for(expr_id).unwrap()
         };
 
-        let resolved = scopes.resolve_name_in_scope(expr_scope, &name_ref.as_name()).unwrap();
+        let resolved = scopes
+            .resolve_name_in_scope(expr_scope, &name_ref.as_name())
+            .unwrap();
         let pat_src =
             source_map.pat_syntax(source_map.patterns_for_binding(resolved.binding())[0]).unwrap();
 
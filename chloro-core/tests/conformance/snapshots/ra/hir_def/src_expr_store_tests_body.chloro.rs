@@ -174,9 +174,7 @@ fn main() {
                 },
             }
         }"#]]
-    .assert_eq(
-        &body.pretty_print(&db, def, Edition::CURRENT),
-    )
+        .assert_eq(&body.pretty_print(&db, def, Edition::CURRENT))
 }
 
 #[test]
@@ -259,9 +257,7 @@ fn main() {
                 },
             );
         }"#]]
-    .assert_eq(
-        &body.pretty_print(&db, def, Edition::CURRENT),
-    )
+        .assert_eq(&body.pretty_print(&db, def, Edition::CURRENT))
 }
 
 #[test]
@@ -344,9 +340,7 @@ fn main() {
                 }
             };
         }"#]]
-    .assert_eq(
-        &body.pretty_print(&db, def, Edition::CURRENT),
-    )
+        .assert_eq(&body.pretty_print(&db, def, Edition::CURRENT))
 }
 
 #[test]
@@ -414,9 +408,7 @@ impl SsrError {
                 },
             );
         }"#]]
-    .assert_eq(
-        &body.pretty_print(&db, def, Edition::CURRENT),
-    )
+        .assert_eq(&body.pretty_print(&db, def, Edition::CURRENT))
 }
 
 #[test]
@@ -464,9 +456,7 @@ fn f(a: i32, b: u32) -> String {
                 );
             };
         }"#]]
-    .assert_eq(
-        &body.pretty_print(&db, def, Edition::CURRENT),
-    )
+        .assert_eq(&body.pretty_print(&db, def, Edition::CURRENT))
 }
 
 #[test]
@@ -499,9 +489,7 @@ fn foo() {
         fn foo() {
             Bar() = Bar();
         }"#]]
-    .assert_eq(
-        &body.pretty_print(&db, def, Edition::CURRENT),
-    )
+        .assert_eq(&body.pretty_print(&db, def, Edition::CURRENT))
 }
 
 #[test]
@@ -260,15 +260,11 @@ fn find_in_scope(
     ignore_local_imports: bool,
 ) -> Option<Name> {
     // FIXME: We could have multiple applicable names here, but we currently only return the first
-    def_map.with_ancestor_maps(
-        db,
-        from.local_id,
-        &mut |def_map, local_id| {
+    def_map.with_ancestor_maps(db, from.local_id, &mut |def_map, local_id| {
         def_map[local_id].scope.names_of(item, |name, _, declared| {
             (declared || !ignore_local_imports).then(|| name.clone())
         })
-    },
-    )
+    })
 }
 
 /// Returns single-segment path (i.e. without any prefix) if `item` is found in prelude and its
@@ -369,9 +365,14 @@ fn calculate_best_path(
         // Item was defined in some upstream crate. This means that it must be exported from one,
         // too (unless we can't name it at all). It could *also* be (re)exported by the same crate
         // that wants to import it here, but we always prefer to use the external path here.
-        ctx.from.krate.data(ctx.db).dependencies.iter().for_each(|dep| {
-            find_in_dep(ctx, visited_modules, item, max_len, best_choice, dep.crate_id)
-        });
+        ctx.from
+            .krate
+            .data(ctx.db)
+            .dependencies
+            .iter()
+            .for_each(|dep| {
+                find_in_dep(ctx, visited_modules, item, max_len, best_choice, dep.crate_id)
+            });
     }
 }
 
@@ -653,9 +654,12 @@ fn find_local_import_locations(
             }
         }
     }
-    worklist.into_iter().filter(|&(_, processed)| processed).for_each(|(module, _)| {
-        visited_modules.remove(&(item, module));
-    });
+    worklist
+        .into_iter()
+        .filter(|&(_, processed)| processed)
+        .for_each(|(module, _)| {
+            visited_modules.remove(&(item, module));
+        });
 }
 
 #[cfg(test)]
@@ -40,6 +40,8 @@ pub type ExprId = Idx<Expr>;
 
 pub type PatId = Idx<Pat>;
 
+// FIXME: Encode this as a single u32, we won't ever reach all 32 bits especially given these counts
+// are local to the body.
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum ExprOrPatId {
     ExprId(ExprId),
@@ -79,9 +81,13 @@ pub struct Label {
 
 pub type LabelId = Idx<Label>;
 
+// We leave float values as a string to avoid double rounding.
+// For PartialEq, string comparison should work, as ordering is not important
+// https://github.com/rust-lang/rust-analyzer/issues/12380#issuecomment-1137284360
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct FloatTypeWrapper(Symbol);
 
+// FIXME(#17451): Use builtin types once stabilised.
 impl FloatTypeWrapper {
     pub fn new(sym: Symbol) -> Self {
         Self(sym)
@@ -125,8 +131,8 @@ pub enum Literal {
     Float(FloatTypeWrapper, Option<BuiltinFloat>),
 }
 
-/// Used in range patterns.
 #[derive(Debug, Clone, Eq, PartialEq)]
+/// Used in range patterns.
 pub enum LiteralOrConst {
     Literal(Literal),
     Const(PatId),
@@ -168,7 +174,9 @@ impl From<ast::LiteralKind> for Literal {
                 Literal::ByteString(text)
             }
             LiteralKind::String(s) => {
-                let text = s.value().map_or_else(|_| Symbol::empty(), |it| Symbol::intern(&it));
+                let text = s
+                    .value()
+                    .map_or_else(|_| Symbol::empty(), |it| Symbol::intern(&it));
                 Literal::String(text)
             }
             LiteralKind::CString(s) => {
@@ -194,10 +202,7 @@ pub enum Expr {
         then_branch: ExprId,
         else_branch: Option<ExprId>,
     },
-    Let {
-        pat: PatId,
-        expr: ExprId,
-    },
+    Let { pat: PatId, expr: ExprId },
     Block {
         id: Option<BlockId>,
         statements: Box<[Statement]>,
@@ -220,67 +225,38 @@ pub enum Expr {
         body: ExprId,
         label: Option<LabelId>,
     },
-    Call {
-        callee: ExprId,
-        args: Box<[ExprId]>,
-    },
+    Call { callee: ExprId, args: Box<[ExprId]> },
     MethodCall {
         receiver: ExprId,
         method_name: Name,
         args: Box<[ExprId]>,
         generic_args: Option<Box<GenericArgs>>,
     },
-    Match {
-        expr: ExprId,
-        arms: Box<[MatchArm]>,
-    },
-    Continue {
-        label: Option<LabelId>,
-    },
+    Match { expr: ExprId, arms: Box<[MatchArm]> },
+    Continue { label: Option<LabelId> },
     Break {
         expr: Option<ExprId>,
         label: Option<LabelId>,
     },
-    Return {
-        expr: Option<ExprId>,
-    },
-    Become {
-        expr: ExprId,
-    },
-    Yield {
-        expr: Option<ExprId>,
-    },
-    Yeet {
-        expr: Option<ExprId>,
-    },
+    Return { expr: Option<ExprId> },
+    Become { expr: ExprId },
+    Yield { expr: Option<ExprId> },
+    Yeet { expr: Option<ExprId> },
     RecordLit {
         path: Option<Box<Path>>,
         fields: Box<[RecordLitField]>,
         spread: Option<ExprId>,
     },
-    Field {
-        expr: ExprId,
-        name: Name,
-    },
-    Await {
-        expr: ExprId,
-    },
-    Cast {
-        expr: ExprId,
-        type_ref: TypeRefId,
-    },
+    Field { expr: ExprId, name: Name },
+    Await { expr: ExprId },
+    Cast { expr: ExprId, type_ref: TypeRefId },
     Ref {
         expr: ExprId,
         rawness: Rawness,
         mutability: Mutability,
     },
-    Box {
-        expr: ExprId,
-    },
-    UnaryOp {
-        expr: ExprId,
-        op: UnaryOp,
-    },
+    Box { expr: ExprId },
+    UnaryOp { expr: ExprId, op: UnaryOp },
     /// `op` cannot be bare `=` (but can be `op=`), these are lowered to `Assignment` instead.
     BinaryOp {
         lhs: ExprId,
@@ -288,19 +264,13 @@ pub enum Expr {
         op: Option<BinaryOp>,
     },
     // Assignments need a special treatment because of destructuring assignment.
-    Assignment {
-        target: PatId,
-        value: ExprId,
-    },
+    Assignment { target: PatId, value: ExprId },
     Range {
         lhs: Option<ExprId>,
         rhs: Option<ExprId>,
         range_type: RangeOp,
     },
-    Index {
-        base: ExprId,
-        index: ExprId,
-    },
+    Index { base: ExprId, index: ExprId },
     Closure {
         args: Box<[PatId]>,
         arg_types: Box<[Option<TypeRefId>]>,
@@ -309,9 +279,7 @@ pub enum Expr {
         closure_kind: ClosureKind,
         capture_by: CaptureBy,
     },
-    Tuple {
-        exprs: Box<[ExprId]>,
-    },
+    Tuple { exprs: Box<[ExprId]> },
     Array(Array),
     Literal(Literal),
     Underscore,
@@ -479,13 +447,8 @@ pub enum Movability {
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Array {
-    ElementList {
-        elements: Box<[ExprId]>,
-    },
-    Repeat {
-        initializer: ExprId,
-        repeat: ExprId,
-    },
+    ElementList { elements: Box<[ExprId]> },
+    Repeat { initializer: ExprId, repeat: ExprId },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -509,10 +472,7 @@ pub enum Statement {
         initializer: Option<ExprId>,
         else_branch: Option<ExprId>,
     },
-    Expr {
-        expr: ExprId,
-        has_semi: bool,
-    },
+    Expr { expr: ExprId, has_semi: bool },
     Item(Item),
 }
 
@@ -617,13 +577,8 @@ pub enum Pat {
         args: Box<[PatId]>,
         ellipsis: Option<u32>,
     },
-    Ref {
-        pat: PatId,
-        mutability: Mutability,
-    },
-    Box {
-        inner: PatId,
-    },
+    Ref { pat: PatId, mutability: Mutability },
+    Box { inner: PatId },
     ConstBlock(ExprId),
     /// An expression inside a pattern. That can only occur inside assignments.
     ///
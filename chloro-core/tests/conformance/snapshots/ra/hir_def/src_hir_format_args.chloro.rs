@@ -159,6 +159,8 @@ pub enum FormatArgumentKind {
     Captured(Name),
 }
 
+// Only used in parse_args and report_invalid_references,
+// to indicate how a referred argument was used.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum PositionUsedAs {
     Placeholder(Option<TextRange>),
@@ -174,10 +174,7 @@ impl ops::Index<LocalLifetimeParamId> for GenericParams {
 /// associated type bindings like `Iterator<Item = u32>`.
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum WherePredicate {
-    TypeBound {
-        target: TypeRefId,
-        bound: TypeBound,
-    },
+    TypeBound { target: TypeRefId, bound: TypeBound },
     Lifetime {
         target: LifetimeRefId,
         bound: LifetimeRefId,
@@ -218,7 +215,9 @@ impl GenericParams {
             GenericDefId::StaticId(_) => EMPTY.clone(),
             GenericDefId::TraitId(trait_id) => db.trait_signature(trait_id).generic_params.clone(),
             GenericDefId::TypeAliasId(type_alias_id) => {
-                db.type_alias_signature(type_alias_id).generic_params.clone()
+                db.type_alias_signature(type_alias_id)
+                    .generic_params
+                    .clone()
             }
         }
     }
@@ -361,7 +360,10 @@ impl GenericParams {
     pub fn find_type_by_name(&self, name: &Name, parent: GenericDefId) -> Option<TypeParamId> {
         self.type_or_consts.iter().find_map(|(id, p)| {
             if p.name().as_ref() == Some(&name) && p.type_param().is_some() {
-                Some(TypeParamId::from_unchecked(TypeOrConstParamId { local_id: id, parent }))
+                Some(TypeParamId::from_unchecked(TypeOrConstParamId {
+                    local_id: id,
+                    parent,
+                }))
             } else {
                 None
             }
@@ -371,7 +373,10 @@ impl GenericParams {
     pub fn find_const_by_name(&self, name: &Name, parent: GenericDefId) -> Option<ConstParamId> {
         self.type_or_consts.iter().find_map(|(id, p)| {
             if p.name().as_ref() == Some(&name) && p.const_param().is_some() {
-                Some(ConstParamId::from_unchecked(TypeOrConstParamId { local_id: id, parent }))
+                Some(ConstParamId::from_unchecked(TypeOrConstParamId {
+                    local_id: id,
+                    parent,
+                }))
             } else {
                 None
             }
@@ -390,9 +395,7 @@ impl GenericParams {
                 ..
             })
         )
-        .then(
-            || Self::SELF_PARAM_ID_IN_SELF,
-        )
+        .then(|| Self::SELF_PARAM_ID_IN_SELF)
     }
 
     pub fn find_lifetime_by_name(
@@ -401,7 +404,14 @@ impl GenericParams {
         parent: GenericDefId,
     ) -> Option<LifetimeParamId> {
         self.lifetimes.iter().find_map(|(id, p)| {
-            if &p.name == name { Some(LifetimeParamId { local_id: id, parent }) } else { None }
+            if &p.name == name {
+                Some(LifetimeParamId {
+                    local_id: id,
+                    parent,
+                })
+            } else {
+                None
+            }
         })
     }
 }
@@ -81,9 +81,9 @@ impl Rawness {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 /// A `TypeRefId` that is guaranteed to always be `TypeRef::Path`. We use this for things like
 /// impl's trait, that are always paths but need to be traced back to source code.
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct PathId(TypeRefId);
 
 impl PathId {
@@ -114,7 +114,10 @@ pub struct FnType {
 impl FnType {
     #[inline]
     pub fn split_params_and_ret(&self) -> (&[(Option<Name>, TypeRefId)], TypeRefId) {
-        let (ret, params) = self.params.split_last().expect("should have at least return type");
+        let (ret, params) = self
+            .params
+            .split_last()
+            .expect("should have at least return type");
         (params, ret.1)
     }
 }
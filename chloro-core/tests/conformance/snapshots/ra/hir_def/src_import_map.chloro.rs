@@ -118,8 +118,15 @@ impl ImportMap {
             insert(last_name, last, importables.len());
         }
 
-        let importables = importables.into_iter().map(|(item, _, idx)| (item, idx)).collect();
-        Arc::new(ImportMap { item_to_info_map: map, fst: builder.into_map(), importables })
+        let importables = importables
+            .into_iter()
+            .map(|(item, _, idx)| (item, idx))
+            .collect();
+        Arc::new(ImportMap {
+            item_to_info_map: map,
+            fst: builder.into_map(),
+            importables,
+        })
     }
 
     pub fn import_info_for(&self, item: ItemInNs) -> Option<&[ImportInfo]> {
@@ -370,15 +377,24 @@ impl Query {
 
     /// Fuzzy finds items instead of exact matching.
     pub fn fuzzy(self) -> Self {
-        Self { search_mode: SearchMode::Fuzzy, ..self }
+        Self {
+            search_mode: SearchMode::Fuzzy,
+            ..self
+        }
     }
 
     pub fn prefix(self) -> Self {
-        Self { search_mode: SearchMode::Prefix, ..self }
+        Self {
+            search_mode: SearchMode::Prefix,
+            ..self
+        }
     }
 
     pub fn exact(self) -> Self {
-        Self { search_mode: SearchMode::Exact, ..self }
+        Self {
+            search_mode: SearchMode::Exact,
+            ..self
+        }
     }
 
     /// Specifies whether we want to include associated items in the result.
@@ -388,7 +404,10 @@ impl Query {
 
     /// Respect casing of the query string when matching.
     pub fn case_sensitive(self) -> Self {
-        Self { case_sensitive: true, ..self }
+        Self {
+            case_sensitive: true,
+            ..self
+        }
     }
 
     fn matches_assoc_mode(&self, is_trait_assoc_item: IsTraitAssocItem) -> bool {
@@ -623,7 +642,11 @@ mod tests {
             module = parent;
         }
 
-        segments.iter().rev().map(|it| it.display(db, Edition::CURRENT)).join("::")
+        segments
+            .iter()
+            .rev()
+            .map(|it| it.display(db, Edition::CURRENT))
+            .join("::")
     }
     #[test]
     fn smoke() {
@@ -334,11 +334,16 @@ impl ItemScope {
     }
 
     pub fn all_macro_calls(&self) -> impl Iterator<Item = MacroCallId> + '_ {
-        self.macro_invocations.values().copied().chain(self.attr_macros.values().copied()).chain(
-            self.derive_macros.values().flat_map(|it| {
-                it.iter().flat_map(|it| it.derive_call_ids.iter().copied().flatten())
-            }),
-        )
+        self.macro_invocations
+            .values()
+            .copied()
+            .chain(self.attr_macros.values().copied())
+            .chain(
+                self.derive_macros.values().flat_map(|it| {
+                    it.iter()
+                        .flat_map(|it| it.derive_call_ids.iter().copied().flatten())
+                }),
+            )
     }
 
     pub(crate) fn modules_in_scope(&self) -> impl Iterator<Item = (ModuleId, Visibility)> + '_ {
@@ -395,37 +400,25 @@ impl ItemScope {
         mut cb: impl FnMut(&Name, Visibility, /*declared*/ bool) -> Option<T>,
     ) -> Option<T> {
         match item {
-            ItemInNs::Macros(def) => self
-                .macros
+            ItemInNs::Macros(def) => self.macros
                 .iter()
                 .filter_map(|(name, other_def)| {
-                    (other_def.def == def).then_some((
-                        name,
-                        other_def.vis,
-                        other_def.import.is_none(),
-                    ))
+                    (other_def.def == def)
+                        .then_some((name, other_def.vis, other_def.import.is_none()))
                 })
                 .find_map(|(a, b, c)| cb(a, b, c)),
-            ItemInNs::Types(def) => self
-                .types
+            ItemInNs::Types(def) => self.types
                 .iter()
                 .filter_map(|(name, other_def)| {
-                    (other_def.def == def).then_some((
-                        name,
-                        other_def.vis,
-                        other_def.import.is_none(),
-                    ))
+                    (other_def.def == def)
+                        .then_some((name, other_def.vis, other_def.import.is_none()))
                 })
                 .find_map(|(a, b, c)| cb(a, b, c)),
-            ItemInNs::Values(def) => self
-                .values
+            ItemInNs::Values(def) => self.values
                 .iter()
                 .filter_map(|(name, other_def)| {
-                    (other_def.def == def).then_some((
-                        name,
-                        other_def.vis,
-                        other_def.import.is_none(),
-                    ))
+                    (other_def.def == def)
+                        .then_some((name, other_def.vis, other_def.import.is_none()))
                 })
                 .find_map(|(a, b, c)| cb(a, b, c)),
         }
@@ -442,18 +435,20 @@ impl ItemScope {
     }
 
     pub(crate) fn resolutions(&self) -> impl Iterator<Item = (Option<Name>, PerNs)> + '_ {
-        self.entries().map(|(name, res)| (Some(name.clone()), res)).chain(
-            self.unnamed_trait_imports.iter().map(|(tr, trait_)| {
-                (
-                    None,
-                    PerNs::types(
+        self.entries()
+            .map(|(name, res)| (Some(name.clone()), res))
+            .chain(
+                self.unnamed_trait_imports.iter().map(|(tr, trait_)| {
+                    (
+                        None,
+                        PerNs::types(
                         ModuleDefId::TraitId(*tr),
                         trait_.vis,
                         trait_.import.map(ImportOrExternCrate::Import),
                     ),
-                )
-            }),
-        )
+                    )
+                }),
+            )
     }
 
     pub fn macro_invoc(&self, call: AstId<ast::MacroCall>) -> Option<MacroCallId> {
@@ -516,8 +511,9 @@ impl ItemScope {
         idx: usize,
     ) {
         if let Some(derives) = self.derive_macros.get_mut(&adt)
-            && let Some(DeriveMacroInvocation { derive_call_ids, .. }) =
-                derives.iter_mut().find(|&&mut DeriveMacroInvocation { attr_id, .. }| id == attr_id)
+            && let Some(DeriveMacroInvocation { derive_call_ids, .. }) = derives
+            .iter_mut()
+            .find(|&&mut DeriveMacroInvocation { attr_id, .. }| id == attr_id)
         {
             derive_call_ids[idx] = Some(call);
         }
@@ -533,11 +529,14 @@ impl ItemScope {
         attr_call_id: MacroCallId,
         len: usize,
     ) {
-        self.derive_macros.entry(adt).or_default().push(DeriveMacroInvocation {
-            attr_id,
-            attr_call_id,
-            derive_call_ids: smallvec![None; len],
-        });
+        self.derive_macros
+            .entry(adt)
+            .or_default()
+            .push(DeriveMacroInvocation {
+                attr_id,
+                attr_call_id,
+                derive_call_ids: smallvec![None; len],
+            });
     }
 
     pub fn derive_macro_invocs(
@@ -551,7 +550,8 @@ impl ItemScope {
         self.derive_macros.iter().map(|(k, v)| {
             (
                 *k,
-                v.iter().map(|DeriveMacroInvocation { attr_id, attr_call_id, derive_call_ids }| {
+                v.iter()
+                .map(|DeriveMacroInvocation { attr_id, attr_call_id, derive_call_ids }| {
                     (*attr_id, *attr_call_id, &**derive_call_ids)
                 }),
             )
@@ -563,12 +563,21 @@ impl ItemScope {
         ast_id: AstId<ast::Adt>,
         attr_id: AttrId,
     ) -> Option<MacroCallId> {
-        Some(self.derive_macros.get(&ast_id)?.iter().find(|it| it.attr_id == attr_id)?.attr_call_id)
+        Some(
+            self.derive_macros
+                .get(&ast_id)?
+                .iter()
+                .find(|it| it.attr_id == attr_id)?
+                .attr_call_id,
+        )
     }
 
     // FIXME: This is only used in collection, we should move the relevant parts of it out of ItemScope
     pub(crate) fn unnamed_trait_vis(&self, tr: TraitId) -> Option<Visibility> {
-        self.unnamed_trait_imports.iter().find(|&&(t, _)| t == tr).map(|(_, trait_)| trait_.vis)
+        self.unnamed_trait_imports
+            .iter()
+            .find(|&&(t, _)| t == tr)
+            .map(|(_, trait_)| trait_.vis)
     }
 
     pub(crate) fn push_unnamed_trait(
@@ -577,7 +586,16 @@ impl ItemScope {
         vis: Visibility,
         import: Option<ImportId>,
     ) {
-        self.unnamed_trait_imports.push((tr, Item { def: (), vis, import }));
+        self.unnamed_trait_imports.push(
+            (
+                tr,
+                Item {
+                def: (),
+                vis,
+                import,
+            },
+            ),
+        );
     }
 
     pub(crate) fn push_res_with_import(
@@ -728,7 +746,11 @@ impl ItemScope {
             .values_mut()
             .map(|def| &mut def.vis)
             .chain(self.values.values_mut().map(|def| &mut def.vis))
-            .chain(self.unnamed_trait_imports.iter_mut().map(|(_, def)| &mut def.vis))
+            .chain(
+                self.unnamed_trait_imports
+                    .iter_mut()
+                    .map(|(_, def)| &mut def.vis),
+            )
             .for_each(|vis| *vis = Visibility::PubCrate(krate));
 
         for mac in self.macros.values_mut() {
@@ -827,6 +849,7 @@ impl ItemScope {
     }
 }
 
+// These methods are a temporary measure only meant to be used by `DefCollector::push_res_and_update_glob_vis()`.
 impl ItemScope {
     pub(crate) fn update_visibility_types(&mut self, name: &Name, vis: Visibility) {
         let res =
@@ -121,12 +121,7 @@ pub(crate) fn file_item_tree_query(db: &dyn DefDatabase, file_id: HirFileId) ->
         }
     };
     let ItemTree { top_level, top_attrs, attrs, vis, big_data, small_data } = &item_tree;
-    if small_data.is_empty()
-        && big_data.is_empty()
-        && top_level.is_empty()
-        && attrs.is_empty()
-        && top_attrs.is_empty()
-        && vis.arena.is_empty() {
+    if small_data.is_empty() && big_data.is_empty() && top_level.is_empty() && attrs.is_empty() && top_attrs.is_empty() && vis.arena.is_empty() {
         EMPTY
             .get_or_init(|| {
                 Arc::new(ItemTree {
@@ -135,7 +130,9 @@ pub(crate) fn file_item_tree_query(db: &dyn DefDatabase, file_id: HirFileId) ->
                     small_data: FxHashMap::default(),
                     big_data: FxHashMap::default(),
                     top_attrs: RawAttrs::EMPTY,
-                    vis: ItemVisibilities { arena: ThinVec::new() },
+                    vis: ItemVisibilities {
+                        arena: ThinVec::new(),
+                    },
                 })
             })
             .clone()
@@ -156,12 +153,7 @@ pub(crate) fn block_item_tree_query(db: &dyn DefDatabase, block: BlockId) -> Arc
     let ctx = lower::Ctx::new(db, loc.ast_id.file_id);
     let mut item_tree = ctx.lower_block(&block);
     let ItemTree { top_level, top_attrs, attrs, vis, big_data, small_data } = &item_tree;
-    if small_data.is_empty()
-        && big_data.is_empty()
-        && top_level.is_empty()
-        && attrs.is_empty()
-        && top_attrs.is_empty()
-        && vis.arena.is_empty() {
+    if small_data.is_empty() && big_data.is_empty() && top_level.is_empty() && attrs.is_empty() && top_attrs.is_empty() && vis.arena.is_empty() {
         EMPTY
             .get_or_init(|| {
                 Arc::new(ItemTree {
@@ -170,7 +162,9 @@ pub(crate) fn block_item_tree_query(db: &dyn DefDatabase, block: BlockId) -> Arc
                     small_data: FxHashMap::default(),
                     big_data: FxHashMap::default(),
                     top_attrs: RawAttrs::EMPTY,
-                    vis: ItemVisibilities { arena: ThinVec::new() },
+                    vis: ItemVisibilities {
+                        arena: ThinVec::new(),
+                    },
                 })
             })
             .clone()
@@ -245,7 +239,13 @@ impl ItemTree {
                 _ => {}
             }
         }
-        ItemTreeDataStats { traits, impls, mods, macro_calls, macro_rules }
+        ItemTreeDataStats {
+            traits,
+            impls,
+            mods,
+            macro_calls,
+            macro_rules,
+        }
     }
 
     pub fn pretty_print(&self, db: &dyn DefDatabase, edition: Edition) -> String {
@@ -289,6 +289,9 @@ enum BigModItem {
     Use(Use),
 }
 
+// `ModItem` is stored a bunch in `ItemTree`'s so we pay the max for each item. It should stay as
+// small as possible which is why we split them in two, most common ones are 3 usize but some rarer
+// ones are 5.
 #[cfg(target_pointer_width = "64")]
 const [(); std::mem::size_of::<BigModItem>()] = [(); std::mem::size_of::<[usize; 5]>()];
 
@@ -413,10 +416,8 @@ impl Index<RawVisibilityId> for ItemTree {
 
     fn index(&self, index: RawVisibilityId) -> &Self::Output {
         static VIS_PUB: RawVisibility = RawVisibility::Public;
-        static VIS_PRIV_IMPLICIT: RawVisibility =
-            RawVisibility::PubSelf(VisibilityExplicitness::Implicit);
-        static VIS_PRIV_EXPLICIT: RawVisibility =
-            RawVisibility::PubSelf(VisibilityExplicitness::Explicit);
+        static VIS_PRIV_IMPLICIT: RawVisibility = RawVisibility::PubSelf(VisibilityExplicitness::Implicit);
+        static VIS_PRIV_EXPLICIT: RawVisibility = RawVisibility::PubSelf(VisibilityExplicitness::Explicit);
         static VIS_PUB_CRATE: RawVisibility = RawVisibility::PubCrate;
 
         match index {
@@ -440,6 +441,8 @@ pub struct UseTree {
     kind: UseTreeKind,
 }
 
+// FIXME: Would be nice to encode `None` into this
+// We could just use a `Name` where `_` well means `_` ..
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ImportAlias {
     /// Unnamed alias, as in `use Foo as _;`
@@ -450,7 +453,10 @@ pub enum ImportAlias {
 
 impl ImportAlias {
     pub fn display(&self, edition: Edition) -> impl fmt::Display + '_ {
-        ImportAliasDisplay { value: self, edition }
+        ImportAliasDisplay {
+            value: self,
+            edition,
+        }
     }
 }
 
@@ -484,9 +490,7 @@ pub enum UseTreeKind {
     /// use *;  // (invalid, but can occur in nested tree)
     /// use path::*;
     /// ```
-    Glob {
-        path: Option<Interned<ModPath>>,
-    },
+    Glob { path: Option<Interned<ModPath>> },
 
     /// ```ignore
     /// use prefix::{self, Item, ...};
@@ -608,9 +612,7 @@ pub struct Mod {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum ModKind {
     /// `mod m { ... }`
-    Inline {
-        items: Box<[ModItemId]>,
-    },
+    Inline { items: Box<[ModItemId]> },
     /// `mod m;`
     Outline,
 }
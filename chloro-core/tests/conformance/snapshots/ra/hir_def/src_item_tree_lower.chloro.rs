@@ -52,7 +52,9 @@ impl<'a> Ctx<'a> {
     }
 
     pub(super) fn span_map(&self) -> SpanMapRef<'_> {
-        self.span_map.get_or_init(|| self.db.span_map(self.file)).as_ref()
+        self.span_map
+            .get_or_init(|| self.db.span_map(self.file))
+            .as_ref()
     }
 
     pub(super) fn lower_module_items(mut self, item_owner: &dyn HasModuleItem) -> ItemTree {
@@ -169,7 +171,9 @@ impl<'a> Ctx<'a> {
         let ast_id = self.source_ast_id_map.ast_id(strukt);
         let shape = adt_shape(strukt.kind());
         let res = Struct { name, visibility, shape };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Struct(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Struct(res));
 
         Some(ast_id)
     }
@@ -179,7 +183,9 @@ impl<'a> Ctx<'a> {
         let name = union.name()?.as_name();
         let ast_id = self.source_ast_id_map.ast_id(union);
         let res = Union { name, visibility };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Union(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Union(res));
         Some(ast_id)
     }
 
@@ -188,7 +194,9 @@ impl<'a> Ctx<'a> {
         let name = enum_.name()?.as_name();
         let ast_id = self.source_ast_id_map.ast_id(enum_);
         let res = Enum { name, visibility };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Enum(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Enum(res));
         Some(ast_id)
     }
 
@@ -200,7 +208,9 @@ impl<'a> Ctx<'a> {
 
         let res = Function { name, visibility };
 
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Function(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Function(res));
         Some(ast_id)
     }
 
@@ -212,7 +222,9 @@ impl<'a> Ctx<'a> {
         let visibility = self.lower_visibility(type_alias);
         let ast_id = self.source_ast_id_map.ast_id(type_alias);
         let res = TypeAlias { name, visibility };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::TypeAlias(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::TypeAlias(res));
         Some(ast_id)
     }
 
@@ -221,7 +233,9 @@ impl<'a> Ctx<'a> {
         let visibility = self.lower_visibility(static_);
         let ast_id = self.source_ast_id_map.ast_id(static_);
         let res = Static { name, visibility };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Static(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Static(res));
         Some(ast_id)
     }
 
@@ -230,7 +244,9 @@ impl<'a> Ctx<'a> {
         let visibility = self.lower_visibility(konst);
         let ast_id = self.source_ast_id_map.ast_id(konst);
         let res = Const { name, visibility };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Const(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Const(res));
         ast_id
     }
 
@@ -252,7 +268,9 @@ impl<'a> Ctx<'a> {
         };
         let ast_id = self.source_ast_id_map.ast_id(module);
         let res = Mod { name, visibility, kind };
-        self.tree.big_data.insert(ast_id.upcast(), BigModItem::Mod(res));
+        self.tree
+            .big_data
+            .insert(ast_id.upcast(), BigModItem::Mod(res));
         Some(ast_id)
     }
 
@@ -262,7 +280,9 @@ impl<'a> Ctx<'a> {
         let ast_id = self.source_ast_id_map.ast_id(trait_def);
 
         let def = Trait { name, visibility };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Trait(def));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Trait(def));
         Some(ast_id)
     }
 
@@ -271,7 +291,9 @@ impl<'a> Ctx<'a> {
         // Note that trait impls don't get implicit `Self` unlike traits, because here they are a
         // type alias rather than a type parameter, so this is handled by the resolver.
         let res = Impl {};
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Impl(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Impl(res));
         ast_id
     }
 
@@ -283,7 +305,9 @@ impl<'a> Ctx<'a> {
         })?;
 
         let res = Use { visibility, use_tree };
-        self.tree.big_data.insert(ast_id.upcast(), BigModItem::Use(res));
+        self.tree
+            .big_data
+            .insert(ast_id.upcast(), BigModItem::Use(res));
         Some(ast_id)
     }
 
@@ -293,13 +317,17 @@ impl<'a> Ctx<'a> {
     ) -> Option<ItemTreeAstId<ExternCrate>> {
         let name = extern_crate.name_ref()?.as_name();
         let alias = extern_crate.rename().map(|a| {
-            a.name().map(|it| it.as_name()).map_or(ImportAlias::Underscore, ImportAlias::Alias)
+            a.name()
+                .map(|it| it.as_name())
+                .map_or(ImportAlias::Underscore, ImportAlias::Alias)
         });
         let visibility = self.lower_visibility(extern_crate);
         let ast_id = self.source_ast_id_map.ast_id(extern_crate);
 
         let res = ExternCrate { name, alias, visibility };
-        self.tree.big_data.insert(ast_id.upcast(), BigModItem::ExternCrate(res));
+        self.tree
+            .big_data
+            .insert(ast_id.upcast(), BigModItem::ExternCrate(res));
         Some(ast_id)
     }
 
@@ -313,7 +341,9 @@ impl<'a> Ctx<'a> {
         let ast_id = self.source_ast_id_map.ast_id(m);
         let expand_to = hir_expand::ExpandTo::from_call_site(m);
         let res = MacroCall { path, expand_to, ctxt: span_map.span_for_range(range).ctx };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::MacroCall(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::MacroCall(res));
         Some(ast_id)
     }
 
@@ -322,7 +352,9 @@ impl<'a> Ctx<'a> {
         let ast_id = self.source_ast_id_map.ast_id(m);
 
         let res = MacroRules { name: name.as_name() };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::MacroRules(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::MacroRules(res));
         Some(ast_id)
     }
 
@@ -333,7 +365,9 @@ impl<'a> Ctx<'a> {
         let visibility = self.lower_visibility(m);
 
         let res = Macro2 { name: name.as_name(), visibility };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::Macro2(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::Macro2(res));
         Some(ast_id)
     }
 
@@ -360,7 +394,9 @@ impl<'a> Ctx<'a> {
         });
 
         let res = ExternBlock { children };
-        self.tree.small_data.insert(ast_id.upcast(), SmallModItem::ExternBlock(res));
+        self.tree
+            .small_data
+            .insert(ast_id.upcast(), SmallModItem::ExternBlock(res));
         ast_id
     }
 
@@ -414,7 +450,10 @@ impl UseTreeLowering<'_> {
                 .collect();
 
             Some(UseTree {
-                kind: UseTreeKind::Prefixed { prefix: prefix.map(Interned::new), list },
+                kind: UseTreeKind::Prefixed {
+                    prefix: prefix.map(Interned::new),
+                    list,
+                },
             })
         } else {
             let is_glob = tree.star_token().is_some();
@@ -423,7 +462,9 @@ impl UseTreeLowering<'_> {
                 None => None,
             };
             let alias = tree.rename().map(|a| {
-                a.name().map(|it| it.as_name()).map_or(ImportAlias::Underscore, ImportAlias::Alias)
+                a.name()
+                    .map(|it| it.as_name())
+                    .map_or(ImportAlias::Underscore, ImportAlias::Alias)
             });
             if alias.is_some() && is_glob {
                 return None;
@@ -435,7 +476,11 @@ impl UseTreeLowering<'_> {
                         cov_mark::hit!(glob_enum_group);
                     }
                     self.mapping.alloc(tree.clone());
-                    Some(UseTree { kind: UseTreeKind::Glob { path: path.map(Interned::new) } })
+                    Some(UseTree {
+                        kind: UseTreeKind::Glob {
+                            path: path.map(Interned::new),
+                        },
+                    })
                 }
                 // Globs can't be renamed
                 (_, Some(_), true) | (None, None, false) => None,
@@ -443,7 +488,12 @@ impl UseTreeLowering<'_> {
                 (None, Some(_), false) => None,
                 (Some(path), alias, false) => {
                     self.mapping.alloc(tree.clone());
-                    Some(UseTree { kind: UseTreeKind::Single { path: Interned::new(path), alias } })
+                    Some(UseTree {
+                        kind: UseTreeKind::Single {
+                            path: Interned::new(path),
+                            alias,
+                        },
+                    })
                 }
             }
         }
@@ -192,11 +192,18 @@ pub fn lang_item(
     // while nameres.
     //
     // See https://github.com/rust-lang/rust-analyzer/pull/20475 for details.
-    crate_local_def_map(db, start_crate).local(db).extern_prelude().find_map(|(_, (krate, _))| {
-        // Some crates declares themselves as extern crate like `extern crate self as core`.
-        // Ignore these to prevent cycles.
-        if krate.krate == start_crate { None } else { lang_item(db, krate.krate, item) }
-    })
+    crate_local_def_map(db, start_crate)
+        .local(db)
+        .extern_prelude()
+        .find_map(|(_, (krate, _))| {
+            // Some crates declares themselves as extern crate like `extern crate self as core`.
+            // Ignore these to prevent cycles.
+            if krate.krate == start_crate {
+                None
+            } else {
+                lang_item(db, krate.krate, item)
+            }
+        })
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
@@ -220,7 +227,9 @@ impl LangItems {
     {
         let _p = tracing::info_span!("collect_lang_item").entered();
         if let Some(lang_item) = lang_attr(db, item.into()) {
-            self.items.entry(lang_item).or_insert_with(|| constructor(item));
+            self.items
+                .entry(lang_item)
+                .or_insert_with(|| constructor(item));
         }
     }
 }
@@ -232,7 +241,9 @@ pub(crate) fn lang_attr(db: &dyn DefDatabase, item: AttrDefId) -> Option<LangIte
 pub(crate) fn notable_traits_in_deps(db: &dyn DefDatabase, krate: Crate) -> Arc<[Arc<[TraitId]>]> {
     let _p = tracing::info_span!("notable_traits_in_deps", ?krate).entered();
     Arc::from_iter(
-        db.transitive_deps(krate).into_iter().filter_map(|krate| db.crate_notable_traits(krate)),
+        db.transitive_deps(krate)
+            .into_iter()
+            .filter_map(|krate| db.crate_notable_traits(krate)),
     )
 }
 
@@ -512,7 +512,11 @@ impl PartialEq<CrateRootModuleId> for ModuleId {
 
 impl From<CrateRootModuleId> for ModuleId {
     fn from(CrateRootModuleId { krate }: CrateRootModuleId) -> Self {
-        ModuleId { krate, block: None, local_id: DefMap::ROOT }
+        ModuleId {
+            krate,
+            block: None,
+            local_id: DefMap::ROOT,
+        }
     }
 }
 
@@ -584,9 +588,16 @@ impl ModuleId {
     pub fn name(self, db: &dyn DefDatabase) -> Option<Name> {
         let def_map = self.def_map(db);
         let parent = def_map[self.local_id].parent?;
-        def_map[parent].children.iter().find_map(|(name, module_id)| {
-            if *module_id == self.local_id { Some(name.clone()) } else { None }
-        })
+        def_map[parent]
+            .children
+            .iter()
+            .find_map(|(name, module_id)| {
+                if *module_id == self.local_id {
+                    Some(name.clone())
+                } else {
+                    None
+                }
+            })
     }
 
     /// Returns the module containing `self`, either the parent `mod`, or the module (or block) containing
@@ -818,7 +829,10 @@ impl GeneralConstId {
     pub fn name(self, db: &dyn DefDatabase) -> String {
         match self {
             GeneralConstId::StaticId(it) => {
-                db.static_signature(it).name.display(db, Edition::CURRENT).to_string()
+                db.static_signature(it)
+                    .name
+                    .display(db, Edition::CURRENT)
+                    .to_string()
             }
             GeneralConstId::ConstId(const_id) => {
                 db.const_signature(const_id).name.as_ref().map_or_else(
@@ -1319,9 +1333,7 @@ impl HasModule for AttrDefId {
                 GenericParamId::ConstParamId(it) => it.parent(),
                 GenericParamId::LifetimeParamId(it) => it.parent,
             }
-            .module(
-                db,
-            ),
+            .module(db),
             AttrDefId::MacroId(it) => it.module(db),
             AttrDefId::ExternCrateId(it) => it.module(db),
             AttrDefId::UseId(it) => it.module(db),
@@ -1358,7 +1370,10 @@ struct AstIdWithPath<T: AstIdNode> {
 
 impl<T: AstIdNode> AstIdWithPath<T> {
     fn new(file_id: HirFileId, ast_id: FileAstId<T>, path: Interned<ModPath>) -> AstIdWithPath<T> {
-        AstIdWithPath { ast_id: AstId::new(file_id, ast_id), path }
+        AstIdWithPath {
+            ast_id: AstId::new(file_id, ast_id),
+            path,
+        }
     }
 }
 
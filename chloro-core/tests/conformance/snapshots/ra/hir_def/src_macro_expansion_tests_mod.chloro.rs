@@ -352,6 +352,9 @@ fn pretty_print_macro_expansion(
     res
 }
 
+// Identity mapping, but only works when the input is syntactically valid. This
+// simulates common proc macros that unnecessarily parse their input and return
+// compile errors.
 #[derive(Debug)]
 struct IdentityWhenValidProcMacroExpander;
 
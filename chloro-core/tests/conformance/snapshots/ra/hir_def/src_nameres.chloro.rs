@@ -203,7 +203,10 @@ impl DefMapCrateData {
         Self {
             exported_derives: FxHashMap::default(),
             fn_proc_macro_mapping: FxHashMap::default(),
-            registered_tools: PREDEFINED_TOOLS.iter().map(|it| Symbol::intern(it)).collect(),
+            registered_tools: PREDEFINED_TOOLS
+                .iter()
+                .map(|it| Symbol::intern(it))
+                .collect(),
             unstable_features: FxHashSet::default(),
             rustc_coherence_is_core: false,
             no_core: false,
@@ -253,7 +256,11 @@ impl BlockRelativeModuleId {
     }
 
     fn into_module(self, krate: Crate) -> ModuleId {
-        ModuleId { krate, block: self.block, local_id: self.local_id }
+        ModuleId {
+            krate,
+            block: self.block,
+            local_id: self.local_id,
+        }
     }
 
     fn is_block_module(self) -> bool {
@@ -271,9 +278,7 @@ impl std::ops::Index<LocalModuleId> for DefMap {
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum ModuleOrigin {
-    CrateRoot {
-        definition: EditionedFileId,
-    },
+    CrateRoot { definition: EditionedFileId },
     /// Note that non-inline modules, by definition, live inside non-macro file.
     File {
         is_mod_rs: bool,
@@ -332,7 +337,9 @@ impl ModuleOrigin {
             }
             &ModuleOrigin::Inline { definition, definition_tree_id } => InFile::new(
                 definition_tree_id.file_id(),
-                ModuleSource::Module(AstId::new(definition_tree_id.file_id(), definition).to_node(db)),
+                ModuleSource::Module(
+                    AstId::new(definition_tree_id.file_id(), definition).to_node(db),
+                ),
             ),
             ModuleOrigin::BlockExpr { block, .. } => {
                 InFile::new(block.file_id, ModuleSource::BlockExpr(block.to_node(db)))
@@ -510,7 +517,9 @@ impl DefMap {
         &self,
         id: AstId<ast::Adt>,
     ) -> Option<&[(Name, MacroId, MacroCallId)]> {
-        self.derive_helpers_in_scope.get(&id.map(|it| it.upcast())).map(Deref::deref)
+        self.derive_helpers_in_scope
+            .get(&id.map(|it| it.upcast()))
+            .map(Deref::deref)
     }
 
     pub fn registered_tools(&self) -> &[Symbol] {
@@ -543,7 +552,11 @@ impl DefMap {
 
     pub fn module_id(&self, local_id: LocalModuleId) -> ModuleId {
         let block = self.block.map(|b| b.block);
-        ModuleId { krate: self.krate, local_id, block }
+        ModuleId {
+            krate: self.krate,
+            local_id,
+            block,
+        }
     }
 
     pub fn crate_root(&self) -> CrateRootModuleId {
@@ -560,7 +573,11 @@ impl DefMap {
     /// might again be a block, or a module inside a block).
     pub fn parent(&self) -> Option<ModuleId> {
         let BlockRelativeModuleId { block, local_id } = self.block?.parent;
-        Some(ModuleId { krate: self.krate, block, local_id })
+        Some(ModuleId {
+            krate: self.krate,
+            block,
+            local_id,
+        })
     }
 
     /// Returns the module containing `local_mod`, either the parent `mod`, or the module (or block) containing
@@ -571,7 +588,11 @@ impl DefMap {
             None => {
                 self.block.map(
                     |BlockInfo { parent: BlockRelativeModuleId { block, local_id }, .. }| {
-                        ModuleId { krate: self.krate, block, local_id }
+                        ModuleId {
+                            krate: self.krate,
+                            block,
+                            local_id,
+                        }
                     },
                 )
             }
@@ -614,9 +635,10 @@ impl DefMap {
 
             map.modules[module].scope.dump(db, buf);
 
-            for (name, child) in
-                map.modules[module].children.iter().sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
-            {
+            for (name, child) in map.modules[module]
+                .children
+                .iter()
+                .sorted_by(|a, b| Ord::cmp(&a.0, &b.0)) {
                 let path = format!("{path}::{}", name.display(db, Edition::LATEST));
                 buf.push('\n');
                 go(buf, db, map, &path, *child);
@@ -769,7 +791,10 @@ impl ModuleData {
     pub fn declaration_source(&self, db: &dyn DefDatabase) -> Option<InFile<ast::Module>> {
         let decl = self.origin.declaration()?;
         let value = decl.to_node(db);
-        Some(InFile { file_id: decl.file_id, value })
+        Some(InFile {
+            file_id: decl.file_id,
+            value,
+        })
     }
 
     /// Returns the range which declares this module, either a `mod foo;` or a `mod foo {}`.
@@ -89,7 +89,9 @@ impl TraitItems {
     }
 
     pub fn assoc_item_by_name(&self, name: &Name) -> Option<AssocItemId> {
-        self.items.iter().find_map(|&(ref item_name, item)| match item {
+        self.items
+            .iter()
+            .find_map(|&(ref item_name, item)| match item {
             AssocItemId::FunctionId(_) if item_name == name => Some(item),
             AssocItemId::TypeAliasId(_) if item_name == name => Some(item),
             AssocItemId::ConstId(_) if item_name == name => Some(item),
@@ -118,7 +120,9 @@ impl ImplItems {
 
         let collector =
             AssocItemCollector::new(db, module_id, ItemContainerId::ImplId(id), ast_id.file_id);
-        let source = ast_id.with_value(collector.ast_id_map.get(ast_id.value)).to_node(db);
+        let source = ast_id
+            .with_value(collector.ast_id_map.get(ast_id.value))
+            .to_node(db);
         let (items, macro_calls, diagnostics) = collector.collect(source.assoc_item_list());
 
         (ImplItems { items, macro_calls }, DefDiagnostics::new(diagnostics))
@@ -166,7 +170,6 @@ impl<'a> AssocItemCollector<'a> {
             file_id,
             container,
             items: Vec::new(),
-
             depth: 0,
             macro_calls: ThinVec::new(),
             diagnostics: Vec::new(),
@@ -313,7 +316,8 @@ impl<'a> AssocItemCollector<'a> {
                     self.module_id.krate(),
                     resolver,
                     &mut |ptr, call_id| {
-                        self.macro_calls.push((ptr.map(|(_, it)| it.upcast()), call_id))
+                        self.macro_calls
+                            .push((ptr.map(|(_, it)| it.upcast()), call_id))
                     },
                 ) {
                     // FIXME: Expansion error?
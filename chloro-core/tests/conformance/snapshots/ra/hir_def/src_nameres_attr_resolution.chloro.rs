@@ -64,7 +64,9 @@ impl DefMap {
         };
 
         Ok(
-            ResolvedAttr::Macro(attr_macro_as_call_id(db, &ast_id, attr, self.krate, db.macro_def(def))),
+            ResolvedAttr::Macro(
+                attr_macro_as_call_id(db, &ast_id, attr, self.krate, db.macro_def(def)),
+            ),
         )
     }
 
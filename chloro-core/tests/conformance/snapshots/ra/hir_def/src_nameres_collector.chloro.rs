@@ -355,7 +355,9 @@ impl<'db> DefCollector<'db> {
             mod_dir: ModDir::root(),
         }
         .collect_in_top_module(item_tree.top_level_items());
-        Arc::get_mut(&mut self.def_map.data).unwrap().shrink_to_fit();
+        Arc::get_mut(&mut self.def_map.data)
+            .unwrap()
+            .shrink_to_fit();
     }
 
     fn seed_with_inner(&mut self, tree_id: TreeId) {
@@ -599,13 +601,17 @@ impl<'db> DefCollector<'db> {
         }
         .intern(self.db);
 
-        self.def_map.macro_def_to_macro_id.insert(ast_id.erase(), proc_macro_id.into());
+        self.def_map
+            .macro_def_to_macro_id
+            .insert(ast_id.erase(), proc_macro_id.into());
         self.define_proc_macro(def.name.clone(), proc_macro_id);
         let crate_data = Arc::get_mut(&mut self.def_map.data).unwrap();
         if let ProcMacroKind::Derive { helpers } = def.kind {
             crate_data.exported_derives.insert(proc_macro_id.into(), helpers);
         }
-        crate_data.fn_proc_macro_mapping.insert(fn_id, proc_macro_id);
+        crate_data
+            .fn_proc_macro_mapping
+            .insert(fn_id, proc_macro_id);
     }
 
     /// Define a macro with `macro_rules`.
@@ -667,7 +673,9 @@ impl<'db> DefCollector<'db> {
     /// current legacy scope, with possible shadowing.
     fn define_legacy_macro(&mut self, module_id: LocalModuleId, name: Name, mac: MacroId) {
         // Always shadowing
-        self.def_map.modules[module_id].scope.define_legacy_macro(name, mac);
+        self.def_map.modules[module_id]
+            .scope
+            .define_legacy_macro(name, mac);
     }
 
     /// Define a macro 2.0 macro
@@ -735,13 +743,17 @@ impl<'db> DefCollector<'db> {
                 for name in names {
                     // FIXME: Report diagnostic on 404.
                     if let Some(def) = root_scope.get(&name).take_macros() {
-                        self.def_map.macro_use_prelude.insert(name, (def, extern_crate));
+                        self.def_map
+                            .macro_use_prelude
+                            .insert(name, (def, extern_crate));
                     }
                 }
             }
             None => {
                 for (name, it) in root_scope.macros() {
-                    self.def_map.macro_use_prelude.insert(name.clone(), (it.def, extern_crate));
+                    self.def_map
+                        .macro_use_prelude
+                        .insert(name.clone(), (it.def, extern_crate));
                 }
             }
         }
@@ -976,7 +988,9 @@ impl<'db> DefCollector<'db> {
                             );
                             // record the glob import in case we add further items
                             let glob_imports = self.glob_imports.entry(m.local_id).or_default();
-                            match glob_imports.iter_mut().find(|(mid, _, _)| *mid == module_id) {
+                            match glob_imports
+                                .iter_mut()
+                                .find(|(mid, _, _)| *mid == module_id) {
                                 None => glob_imports.push((module_id, vis, glob)),
                                 Some((_, old_vis, _)) => {
                                     if let Some(new_vis) = old_vis.max(vis, &self.def_map) {
@@ -1140,7 +1154,9 @@ impl<'db> DefCollector<'db> {
             .collect::<Vec<_>>();
 
         for (glob_importing_module, glob_import_vis, glob) in glob_imports {
-            let vis = glob_import_vis.min(vis, &self.def_map).unwrap_or(glob_import_vis);
+            let vis = glob_import_vis
+                .min(vis, &self.def_map)
+                .unwrap_or(glob_import_vis);
             self.update_recursive(
                 glob_importing_module,
                 resolutions,
@@ -1671,7 +1687,9 @@ impl ModCollector<'_, '_> {
 
         // Note: don't assert that inserted value is fresh: it's simply not true
         // for macros.
-        self.def_collector.mod_dirs.insert(self.module_id, self.mod_dir.clone());
+        self.def_collector
+            .mod_dirs
+            .insert(self.module_id, self.mod_dir.clone());
 
         // Prelude module is always considered to be `#[macro_use]`.
         if let Some((prelude_module, _use)) = self.def_collector.def_map.prelude {
@@ -2122,7 +2140,9 @@ impl ModCollector<'_, '_> {
         let def_map = &mut self.def_collector.def_map;
         let vis = def_map
             .resolve_visibility(
-                self.def_collector.crate_local_def_map.unwrap_or(&self.def_collector.local_def_map),
+                self.def_collector
+                    .crate_local_def_map
+                    .unwrap_or(&self.def_collector.local_def_map),
                 self.def_collector.db,
                 self.module_id,
                 visibility,
@@ -2238,7 +2258,9 @@ impl ModCollector<'_, '_> {
     fn collect_macro_rules(&mut self, ast_id: ItemTreeAstId<MacroRules>, module: ModuleId) {
         let krate = self.def_collector.def_map.krate;
         let mac = &self.item_tree[ast_id];
-        let attrs = self.item_tree.attrs(self.def_collector.db, krate, ast_id.upcast());
+        let attrs = self
+            .item_tree
+            .attrs(self.def_collector.db, krate, ast_id.upcast());
         let f_ast_id = InFile::new(self.file_id(), ast_id.upcast());
 
         let export_attr = || attrs.by_key(sym::macro_export);
@@ -2308,19 +2330,20 @@ impl ModCollector<'_, '_> {
             edition: self.def_collector.def_map.data.edition,
         }
         .intern(self.def_collector.db);
-        self.def_collector.def_map.macro_def_to_macro_id.insert(f_ast_id.erase(), macro_id.into());
-        self.def_collector.define_macro_rules(
-            self.module_id,
-            mac.name.clone(),
-            macro_id,
-            is_export,
-        );
+        self.def_collector
+            .def_map
+            .macro_def_to_macro_id
+            .insert(f_ast_id.erase(), macro_id.into());
+        self.def_collector
+            .define_macro_rules(self.module_id, mac.name.clone(), macro_id, is_export);
     }
 
     fn collect_macro_def(&mut self, ast_id: ItemTreeAstId<Macro2>, module: ModuleId) {
         let krate = self.def_collector.def_map.krate;
         let mac = &self.item_tree[ast_id];
-        let attrs = self.item_tree.attrs(self.def_collector.db, krate, ast_id.upcast());
+        let attrs = self
+            .item_tree
+            .attrs(self.def_collector.db, krate, ast_id.upcast());
         let f_ast_id = InFile::new(self.file_id(), ast_id.upcast());
 
         // Case 1: builtin macros
@@ -2373,7 +2396,10 @@ impl ModCollector<'_, '_> {
             edition: self.def_collector.def_map.data.edition,
         }
         .intern(self.def_collector.db);
-        self.def_collector.def_map.macro_def_to_macro_id.insert(f_ast_id.erase(), macro_id.into());
+        self.def_collector
+            .def_map
+            .macro_def_to_macro_id
+            .insert(f_ast_id.erase(), macro_id.into());
         self.def_collector.define_macro_def(
             self.module_id,
             mac.name.clone(),
@@ -2461,7 +2487,11 @@ impl ModCollector<'_, '_> {
         self.def_collector.unresolved_macros.push(MacroDirective {
             module_id: self.module_id,
             depth: self.macro_depth + 1,
-            kind: MacroDirectiveKind::FnLike { ast_id, expand_to, ctxt },
+            kind: MacroDirectiveKind::FnLike {
+                ast_id,
+                expand_to,
+                ctxt,
+            },
             container,
         });
     }
@@ -2510,12 +2540,15 @@ impl ModCollector<'_, '_> {
     }
 
     fn emit_unconfigured_diagnostic(&mut self, ast_id: ErasedAstId, cfg: &CfgExpr) {
-        self.def_collector.def_map.diagnostics.push(DefDiagnostic::unconfigured_code(
-            self.module_id,
-            ast_id,
-            cfg.clone(),
-            self.def_collector.cfg_options.clone(),
-        ));
+        self.def_collector
+            .def_map
+            .diagnostics
+            .push(DefDiagnostic::unconfigured_code(
+                self.module_id,
+                ast_id,
+                cfg.clone(),
+                self.def_collector.cfg_options.clone(),
+            ));
     }
 
     #[inline]
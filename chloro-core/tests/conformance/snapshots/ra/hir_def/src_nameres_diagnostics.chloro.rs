@@ -15,9 +15,7 @@ pub enum DefDiagnosticKind {
         ast: AstId<ast::Module>,
         candidates: Box<[String]>,
     },
-    UnresolvedExternCrate {
-        ast: AstId<ast::ExternCrate>,
-    },
+    UnresolvedExternCrate { ast: AstId<ast::ExternCrate> },
     UnresolvedImport {
         id: AstId<ast::Use>,
         index: Idx<ast::UseTree>,
@@ -27,21 +25,10 @@ pub enum DefDiagnosticKind {
         cfg: CfgExpr,
         opts: CfgOptions,
     },
-    UnresolvedMacroCall {
-        ast: MacroCallKind,
-        path: ModPath,
-    },
-    UnimplementedBuiltinMacro {
-        ast: AstId<ast::Macro>,
-    },
-    InvalidDeriveTarget {
-        ast: AstId<ast::Item>,
-        id: usize,
-    },
-    MalformedDerive {
-        ast: AstId<ast::Adt>,
-        id: usize,
-    },
+    UnresolvedMacroCall { ast: MacroCallKind, path: ModPath },
+    UnimplementedBuiltinMacro { ast: AstId<ast::Macro> },
+    InvalidDeriveTarget { ast: AstId<ast::Item>, id: usize },
+    MalformedDerive { ast: AstId<ast::Adt>, id: usize },
     MacroDefError {
         ast: AstId<ast::Macro>,
         message: String,
@@ -85,7 +72,10 @@ impl DefDiagnostic {
     ) -> Self {
         Self {
             in_module: container,
-            kind: DefDiagnosticKind::UnresolvedModule { ast: declaration, candidates },
+            kind: DefDiagnosticKind::UnresolvedModule {
+                ast: declaration,
+                candidates,
+            },
         }
     }
 
@@ -163,7 +153,10 @@ impl DefDiagnostic {
     ) -> Self {
         Self {
             in_module: container,
-            kind: DefDiagnosticKind::InvalidDeriveTarget { ast, id: id.ast_index() },
+            kind: DefDiagnosticKind::InvalidDeriveTarget {
+                ast,
+                id: id.ast_index(),
+            },
         }
     }
 
@@ -174,7 +167,10 @@ impl DefDiagnostic {
     ) -> Self {
         Self {
             in_module: container,
-            kind: DefDiagnosticKind::MalformedDerive { ast, id: id.ast_index() },
+            kind: DefDiagnosticKind::MalformedDerive {
+                ast,
+                id: id.ast_index(),
+            },
         }
     }
 }
@@ -58,7 +58,11 @@ impl ModDir {
             cov_mark::hit!(circular_mods);
             return None;
         }
-        Some(ModDir { dir_path, root_non_dir_owner, depth })
+        Some(ModDir {
+            dir_path,
+            root_non_dir_owner,
+            depth,
+        })
     }
 
     pub(super) fn resolve_declaration(
@@ -75,7 +75,12 @@ impl ResolvePathResult {
         segment_index: Option<usize>,
         prefix_info: ResolvePathResultPrefixInfo,
     ) -> ResolvePathResult {
-        ResolvePathResult { resolved_def, segment_index, reached_fixedpoint, prefix_info }
+        ResolvePathResult {
+            resolved_def,
+            segment_index,
+            reached_fixedpoint,
+            prefix_info,
+        }
     }
 }
 
@@ -666,7 +671,10 @@ impl DefMap {
                 sub_namespace_match(Some(MacroSubNs::from_id(db, id)), expected_macro_subns)
             })
             .map_or_else(PerNs::none, |m| PerNs::macros(m, Visibility::Public, None));
-        let from_scope = self[module].scope.get(name).filter_macro(db, expected_macro_subns);
+        let from_scope = self[module]
+            .scope
+            .get(name)
+            .filter_macro(db, expected_macro_subns);
         let from_builtin = match self.block {
             Some(_) => {
                 // Only resolve to builtins in the root `DefMap`.
@@ -722,23 +730,28 @@ impl DefMap {
     }
 
     fn resolve_name_in_extern_prelude(&self, local_def_map: &LocalDefMap, name: &Name) -> PerNs {
-        local_def_map.extern_prelude.get(name).map_or(PerNs::none(), |&(it, extern_crate)| {
-            PerNs::types(
-                it.into(),
-                Visibility::Public,
-                extern_crate.map(ImportOrExternCrate::ExternCrate),
-            )
-        })
+        local_def_map
+            .extern_prelude
+            .get(name)
+            .map_or(PerNs::none(), |&(it, extern_crate)| {
+                PerNs::types(
+                    it.into(),
+                    Visibility::Public,
+                    extern_crate.map(ImportOrExternCrate::ExternCrate),
+                )
+            })
     }
 
     fn resolve_in_macro_use_prelude(&self, name: &Name) -> PerNs {
-        self.macro_use_prelude.get(name).map_or(PerNs::none(), |&(it, extern_crate)| {
-            PerNs::macros(
-                it,
-                Visibility::Public,
-                extern_crate.map(ImportOrExternCrate::ExternCrate),
-            )
-        })
+        self.macro_use_prelude
+            .get(name)
+            .map_or(PerNs::none(), |&(it, extern_crate)| {
+                PerNs::macros(
+                    it,
+                    Visibility::Public,
+                    extern_crate.map(ImportOrExternCrate::ExternCrate),
+                )
+            })
     }
 
     fn resolve_name_in_crate_root_or_extern_prelude(
@@ -14,9 +14,7 @@ pub struct ProcMacroDef {
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ProcMacroKind {
-    Derive {
-        helpers: Box<[Name]>,
-    },
+    Derive { helpers: Box<[Name]> },
     Bang,
     Attr,
 }
@@ -47,11 +45,16 @@ impl Attrs {
             let derive = self.parse_proc_macro_derive();
             Some(match derive {
                 Some((name, helpers)) => {
-                    ProcMacroDef { name, kind: ProcMacroKind::Derive { helpers } }
+                    ProcMacroDef {
+                        name,
+                        kind: ProcMacroKind::Derive { helpers },
+                    }
                 }
                 None => ProcMacroDef {
                     name: func_name.clone(),
-                    kind: ProcMacroKind::Derive { helpers: Box::default() },
+                    kind: ProcMacroKind::Derive {
+                        helpers: Box::default(),
+                    },
                 },
             })
         } else {
@@ -39,6 +39,7 @@ pub type TypesItem = Item<ModuleDefId, ImportOrExternCrate>;
 
 pub type ValuesItem = Item<ModuleDefId, ImportOrGlob>;
 
+// May be Externcrate for `[macro_use]`'d macros
 pub type MacrosItem = Item<MacroId, ImportOrExternCrate>;
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
@@ -88,7 +89,11 @@ impl PerNs {
         import: Option<ImportOrExternCrate>,
     ) -> PerNs {
         PerNs {
-            types: Some(Item { def: types, vis, import }),
+            types: Some(Item {
+                def: types,
+                vis,
+                import,
+            }),
             values: Some(Item {
                 def: values,
                 vis,
@@ -54,7 +54,9 @@ struct ModuleItemMap<'db> {
 
 impl fmt::Debug for ModuleItemMap<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("ModuleItemMap").field("module_id", &self.module_id).finish()
+        f.debug_struct("ModuleItemMap")
+            .field("module_id", &self.module_id)
+            .finish()
     }
 }
 
@@ -133,7 +135,9 @@ pub enum LifetimeNs {
 impl<'db> Resolver<'db> {
     /// Resolve known trait from std, like `std::futures::Future`
     pub fn resolve_known_trait(&self, db: &dyn DefDatabase, path: &ModPath) -> Option<TraitId> {
-        let res = self.resolve_module_path(db, path, BuiltinShadowMode::Other).take_types()?;
+        let res = self
+            .resolve_module_path(db, path, BuiltinShadowMode::Other)
+            .take_types()?;
         match res {
             ModuleDefId::TraitId(it) => Some(it),
             _ => None,
@@ -142,7 +146,9 @@ impl<'db> Resolver<'db> {
 
     /// Resolve known struct from std, like `std::boxed::Box`
     pub fn resolve_known_struct(&self, db: &dyn DefDatabase, path: &ModPath) -> Option<StructId> {
-        let res = self.resolve_module_path(db, path, BuiltinShadowMode::Other).take_types()?;
+        let res = self
+            .resolve_module_path(db, path, BuiltinShadowMode::Other)
+            .take_types()?;
         match res {
             ModuleDefId::AdtId(AdtId::StructId(it)) => Some(it),
             _ => None,
@@ -151,7 +157,9 @@ impl<'db> Resolver<'db> {
 
     /// Resolve known enum from std, like `std::result::Result`
     pub fn resolve_known_enum(&self, db: &dyn DefDatabase, path: &ModPath) -> Option<EnumId> {
-        let res = self.resolve_module_path(db, path, BuiltinShadowMode::Other).take_types()?;
+        let res = self
+            .resolve_module_path(db, path, BuiltinShadowMode::Other)
+            .take_types()?;
         match res {
             ModuleDefId::AdtId(AdtId::EnumId(it)) => Some(it),
             _ => None,
@@ -314,7 +322,8 @@ impl<'db> Resolver<'db> {
         path: &Path,
         hygiene_id: HygieneId,
     ) -> Option<ResolveValueResult> {
-        self.resolve_path_in_value_ns_with_prefix_info(db, path, hygiene_id).map(|(it, _)| it)
+        self.resolve_path_in_value_ns_with_prefix_info(db, path, hygiene_id)
+            .map(|(it, _)| it)
     }
 
     pub fn resolve_path_in_value_ns_with_prefix_info(
@@ -515,7 +524,8 @@ impl<'db> Resolver<'db> {
         path: &ModPath,
         expected_macro_kind: Option<MacroSubNs>,
     ) -> Option<MacroDefId> {
-        self.resolve_path_as_macro(db, path, expected_macro_kind).map(|(it, _)| db.macro_def(it))
+        self.resolve_path_as_macro(db, path, expected_macro_kind)
+            .map(|(it, _)| db.macro_def(it))
     }
 
     pub fn resolve_lifetime(&self, lifetime: &LifetimeRef) -> Option<LifetimeNs> {
@@ -593,19 +603,26 @@ impl<'db> Resolver<'db> {
             res.add_per_ns(name, def);
         });
 
-        def_map[module_id].scope.legacy_macros().for_each(|(name, macs)| {
-            macs.iter().for_each(|&mac| {
-                res.add(name, ScopeDef::ModuleDef(ModuleDefId::MacroId(mac)));
-            })
-        });
-        def_map.macro_use_prelude().iter().sorted_by_key(|&(k, _)| k.clone()).for_each(
-            |(name, &(def, _extern_crate))| {
+        def_map[module_id]
+            .scope
+            .legacy_macros()
+            .for_each(|(name, macs)| {
+                macs.iter().for_each(|&mac| {
+                    res.add(name, ScopeDef::ModuleDef(ModuleDefId::MacroId(mac)));
+                })
+            });
+        def_map
+            .macro_use_prelude()
+            .iter()
+            .sorted_by_key(|&(k, _)| k.clone())
+            .for_each(|(name, &(def, _extern_crate))| {
                 res.add(name, ScopeDef::ModuleDef(def.into()));
-            },
-        );
-        local_def_map.extern_prelude().for_each(|(name, (def, _extern_crate))| {
-            res.add(name, ScopeDef::ModuleDef(ModuleDefId::ModuleId(def.into())));
-        });
+            });
+        local_def_map
+            .extern_prelude()
+            .for_each(|(name, (def, _extern_crate))| {
+                res.add(name, ScopeDef::ModuleDef(ModuleDefId::ModuleId(def.into())));
+            });
         BUILTIN_SCOPE.iter().for_each(|(name, &def)| {
             res.add_per_ns(name, def);
         });
@@ -877,7 +894,9 @@ impl<'db> Resolver<'db> {
         }
 
         let start = self.scopes.len();
-        let innermost_scope = self.scopes().find(|scope| !matches!(scope, Scope::MacroDefScope(_)));
+        let innermost_scope = self
+            .scopes()
+            .find(|scope| !matches!(scope, Scope::MacroDefScope(_)));
         match innermost_scope {
             Some(&Scope::ExprScope(ExprScope { scope_id, ref expr_scopes, owner })) => {
                 let expr_scopes = expr_scopes.clone();
@@ -973,11 +992,13 @@ impl<'db> Resolver<'db> {
                 Scope::BlockScope(m) => Some((m.def_map, m.local_def_map, m.module_id)),
                 _ => None,
             })
-            .unwrap_or((
-                self.module_scope.def_map,
-                self.module_scope.local_def_map,
-                self.module_scope.module_id,
-            ))
+            .unwrap_or(
+                (
+                    self.module_scope.def_map,
+                    self.module_scope.local_def_map,
+                    self.module_scope.module_id,
+                ),
+            )
     }
 }
 
@@ -996,14 +1017,20 @@ impl<'db> Scope<'db> {
     fn process_names(&self, acc: &mut ScopeNames, db: &'db dyn DefDatabase) {
         match self {
             Scope::BlockScope(m) => {
-                m.def_map[m.module_id].scope.entries().for_each(|(name, def)| {
-                    acc.add_per_ns(name, def);
-                });
-                m.def_map[m.module_id].scope.legacy_macros().for_each(|(name, macs)| {
-                    macs.iter().for_each(|&mac| {
-                        acc.add(name, ScopeDef::ModuleDef(ModuleDefId::MacroId(mac)));
-                    })
-                });
+                m.def_map[m.module_id]
+                    .scope
+                    .entries()
+                    .for_each(|(name, def)| {
+                        acc.add_per_ns(name, def);
+                    });
+                m.def_map[m.module_id]
+                    .scope
+                    .legacy_macros()
+                    .for_each(|(name, macs)| {
+                        macs.iter().for_each(|&mac| {
+                            acc.add(name, ScopeDef::ModuleDef(ModuleDefId::MacroId(mac)));
+                        })
+                    });
             }
             &Scope::GenericParams { ref params, def: parent } => {
                 if let GenericDefId::ImplId(impl_) = parent {
@@ -1038,9 +1065,13 @@ impl<'db> Scope<'db> {
                 if let Some((label, name)) = scope.expr_scopes.label(scope.scope_id) {
                     acc.add(&name, ScopeDef::Label(label))
                 }
-                scope.expr_scopes.entries(scope.scope_id).iter().for_each(|e| {
-                    acc.add_local(e.name(), e.binding());
-                });
+                scope
+                    .expr_scopes
+                    .entries(scope.scope_id)
+                    .iter()
+                    .for_each(|e| {
+                        acc.add_local(e.name(), e.binding());
+                    });
             }
             Scope::MacroDefScope(_) => {}
         }
@@ -1108,7 +1139,11 @@ impl<'db> Resolver<'db> {
         local_def_map: &'db LocalDefMap,
         module_id: LocalModuleId,
     ) -> Resolver<'db> {
-        self.push_scope(Scope::BlockScope(ModuleItemMap { def_map, local_def_map, module_id }))
+        self.push_scope(Scope::BlockScope(ModuleItemMap {
+            def_map,
+            local_def_map,
+            module_id,
+        }))
     }
 
     fn push_expr_scope(
@@ -1117,7 +1152,11 @@ impl<'db> Resolver<'db> {
         expr_scopes: Arc<ExprScopes>,
         scope_id: ScopeId,
     ) -> Resolver<'db> {
-        self.push_scope(Scope::ExprScope(ExprScope { owner, expr_scopes, scope_id }))
+        self.push_scope(Scope::ExprScope(ExprScope {
+            owner,
+            expr_scopes,
+            scope_id,
+        }))
     }
 }
 
@@ -1301,7 +1340,11 @@ impl HasResolver for CrateRootModuleId {
         let (def_map, local_def_map) = self.local_def_map(db);
         Resolver {
             scopes: vec![],
-            module_scope: ModuleItemMap { def_map, local_def_map, module_id: DefMap::ROOT },
+            module_scope: ModuleItemMap {
+                def_map,
+                local_def_map,
+                module_id: DefMap::ROOT,
+            },
         }
     }
 }
@@ -1315,7 +1358,9 @@ impl HasResolver for TraitId {
 impl<T: Into<AdtId> + Copy> HasResolver for T {
     fn resolver(self, db: &dyn DefDatabase) -> Resolver<'_> {
         let def = self.into();
-        def.module(db).resolver(db).push_generic_params_scope(db, def.into())
+        def.module(db)
+            .resolver(db)
+            .push_generic_params_scope(db, def.into())
     }
 }
 
@@ -1345,7 +1390,10 @@ impl HasResolver for TypeAliasId {
 
 impl HasResolver for ImplId {
     fn resolver(self, db: &dyn DefDatabase) -> Resolver<'_> {
-        self.lookup(db).container.resolver(db).push_generic_params_scope(db, self.into())
+        self.lookup(db)
+            .container
+            .resolver(db)
+            .push_generic_params_scope(db, self.into())
     }
 }
 
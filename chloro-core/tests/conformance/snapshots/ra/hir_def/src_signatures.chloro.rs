@@ -110,13 +110,13 @@ impl StructSignature {
         );
         (
             Arc::new(StructSignature {
-                generic_params,
-                store,
-                flags,
-                shape,
-                name: as_name_opt(source.name()),
-                repr,
-            }),
+            generic_params,
+            store,
+            flags,
+            shape,
+            name: as_name_opt(source.name()),
+            repr,
+        }),
             Arc::new(source_map),
         )
     }
@@ -165,12 +165,12 @@ impl UnionSignature {
         );
         (
             Arc::new(UnionSignature {
-                generic_params,
-                store,
-                flags,
-                repr,
-                name: as_name_opt(source.name()),
-            }),
+            generic_params,
+            store,
+            flags,
+            repr,
+            name: as_name_opt(source.name()),
+        }),
             Arc::new(source_map),
         )
     }
@@ -215,12 +215,12 @@ impl EnumSignature {
 
         (
             Arc::new(EnumSignature {
-                generic_params,
-                store,
-                flags,
-                repr,
-                name: as_name_opt(source.name()),
-            }),
+            generic_params,
+            store,
+            flags,
+            repr,
+            name: as_name_opt(source.name()),
+        }),
             Arc::new(source_map),
         )
     }
@@ -270,11 +270,11 @@ impl ConstSignature {
 
         (
             Arc::new(ConstSignature {
-                store: Arc::new(store),
-                type_ref,
-                flags,
-                name: source.value.name().map(|it| it.as_name()),
-            }),
+            store: Arc::new(store),
+            type_ref,
+            flags,
+            name: source.value.name().map(|it| it.as_name()),
+        }),
             Arc::new(source_map),
         )
     }
@@ -342,11 +342,11 @@ impl StaticSignature {
 
         (
             Arc::new(StaticSignature {
-                store: Arc::new(store),
-                type_ref,
-                flags,
-                name: as_name_opt(source.value.name()),
-            }),
+            store: Arc::new(store),
+            type_ref,
+            flags,
+            name: as_name_opt(source.value.name()),
+        }),
             Arc::new(source_map),
         )
     }
@@ -391,12 +391,12 @@ impl ImplSignature {
 
         (
             Arc::new(ImplSignature {
-                store: Arc::new(store),
-                generic_params,
-                self_ty,
-                target_trait,
-                flags,
-            }),
+            store: Arc::new(store),
+            generic_params,
+            self_ty,
+            target_trait,
+            flags,
+        }),
             Arc::new(source_map),
         )
     }
@@ -486,7 +486,12 @@ impl TraitSignature {
         let (store, source_map, generic_params) = lower_trait(db, loc.container, source, id);
 
         (
-            Arc::new(TraitSignature { store: Arc::new(store), generic_params, flags, name }),
+            Arc::new(TraitSignature {
+            store: Arc::new(store),
+            generic_params,
+            flags,
+            name,
+        }),
             Arc::new(source_map),
         )
     }
@@ -576,7 +581,8 @@ impl FunctionSignature {
 
         let name = as_name_opt(source.value.name());
         let abi = source.value.abi().map(|abi| {
-            abi.abi_string().map_or_else(|| sym::C, |it| Symbol::intern(it.text_without_quotes()))
+            abi.abi_string()
+                .map_or_else(|| sym::C, |it| Symbol::intern(it.text_without_quotes()))
         });
         let (store, source_map, generic_params, params, ret_type, self_param, variadic) =
             lower_function(db, module, source, id);
@@ -588,15 +594,15 @@ impl FunctionSignature {
         }
         (
             Arc::new(FunctionSignature {
-                generic_params,
-                store: Arc::new(store),
-                params,
-                ret_type,
-                abi,
-                flags,
-                legacy_const_generics_indices,
-                name,
-            }),
+            generic_params,
+            store: Arc::new(store),
+            params,
+            ret_type,
+            abi,
+            flags,
+            legacy_const_generics_indices,
+            name,
+        }),
             Arc::new(source_map),
         )
     }
@@ -648,14 +654,14 @@ impl FunctionSignature {
         data.flags.contains(FnFlags::RUSTC_INTRINSIC)
             // Keep this around for a bit until extern "rustc-intrinsic" abis are no longer used
             || match &data.abi {
-                Some(abi) => *abi == sym::rust_dash_intrinsic,
-                None => match id.lookup(db).container {
-                    ItemContainerId::ExternBlockId(block) => {
-                        block.abi(db) == Some(sym::rust_dash_intrinsic)
-                    }
-                    _ => false,
-                },
-            }
+            Some(abi) => *abi == sym::rust_dash_intrinsic,
+            None => match id.lookup(db).container {
+                ItemContainerId::ExternBlockId(block) => {
+                    block.abi(db) == Some(sym::rust_dash_intrinsic)
+                }
+                _ => false,
+            },
+        }
     }
 }
 
@@ -703,13 +709,13 @@ impl TypeAliasSignature {
 
         (
             Arc::new(TypeAliasSignature {
-                store: Arc::new(store),
-                generic_params,
-                flags,
-                bounds,
-                name,
-                ty,
-            }),
+            store: Arc::new(store),
+            generic_params,
+            flags,
+            bounds,
+            name,
+            ty,
+        }),
             Arc::new(source_map),
         )
     }
@@ -800,12 +806,23 @@ impl VariantFields {
         };
         match result {
             Some((fields, store, source_map)) => (
-                Arc::new(VariantFields { fields, store: Arc::new(store), shape }),
+                Arc::new(VariantFields {
+                fields,
+                store: Arc::new(store),
+                shape,
+            }),
                 Arc::new(source_map),
             ),
             None => {
                 let (store, source_map) = ExpressionStore::empty_singleton();
-                (Arc::new(VariantFields { fields: Arena::default(), store, shape }), source_map)
+                (
+                    Arc::new(VariantFields {
+                    fields: Arena::default(),
+                    store,
+                    shape,
+                }),
+                    source_map,
+                )
             }
         }
     }
@@ -826,7 +843,9 @@ impl VariantFields {
     }
 
     pub fn field(&self, name: &Name) -> Option<LocalFieldId> {
-        self.fields().iter().find_map(|(id, data)| if &data.name == name { Some(id) } else { None })
+        self.fields()
+            .iter()
+            .find_map(|(id, data)| if &data.name == name { Some(id) } else { None })
     }
 }
 
@@ -978,7 +997,9 @@ impl EnumVariants {
 
 impl EnumVariants {
     pub fn variant(&self, name: &Name) -> Option<EnumVariantId> {
-        self.variants.iter().find_map(|(v, n, _)| if n == name { Some(*v) } else { None })
+        self.variants
+            .iter()
+            .find_map(|(v, n, _)| if n == name { Some(*v) } else { None })
     }
 
     pub fn variant_name_by_id(&self, variant_id: EnumVariantId) -> Option<Name> {
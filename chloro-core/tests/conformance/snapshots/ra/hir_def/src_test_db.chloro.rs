@@ -143,8 +143,11 @@ impl TestDB {
             .iter()
             .copied()
             .find(|&krate| {
-                krate.extra_data(self).display_name.as_ref().map(|it| it.canonical_name().as_str())
-                    == Some("ra_test_fixture")
+                krate
+                    .extra_data(self)
+                    .display_name
+                    .as_ref()
+                    .map(|it| it.canonical_name().as_str()) == Some("ra_test_fixture")
             })
             .unwrap_or(*all_crates.last().unwrap())
     }
@@ -33,7 +33,9 @@ impl Visibility {
         raw_vis: &RawVisibility,
     ) -> Self {
         // we fall back to public visibility (i.e. fail open) if the path can't be resolved
-        resolver.resolve_visibility(db, raw_vis).unwrap_or(Visibility::Public)
+        resolver
+            .resolve_visibility(db, raw_vis)
+            .unwrap_or(Visibility::Public)
     }
 
     pub(crate) fn is_visible_from_other_crate(self) -> bool {
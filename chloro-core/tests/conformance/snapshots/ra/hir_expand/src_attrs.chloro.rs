@@ -115,30 +115,34 @@ impl RawAttrs {
         cfg_options: &CfgOptions,
     ) -> impl Iterator<Item = Attr> {
         Self::attrs_iter::<DESUGAR_COMMENTS>(db, owner, span_map)
-        .flat_map(
-            |attr| attr.expand_cfg_attr(db, cfg_options),
-        )
+            .flat_map(|attr| attr.expand_cfg_attr(db, cfg_options))
     }
 
     pub fn merge(&self, other: Self) -> Self {
         match (&self.entries, other.entries) {
             (None, None) => Self::EMPTY,
             (None, entries @ Some(_)) => Self { entries },
-            (Some(entries), None) => Self { entries: Some(entries.clone()) },
+            (Some(entries), None) => Self {
+                entries: Some(entries.clone()),
+            },
             (Some(a), Some(b)) => {
                 let last_ast_index = a.slice.last().map_or(0, |it| it.id.ast_index() + 1);
                 let items = a
                     .slice
                     .iter()
                     .cloned()
-                    .chain(b.slice.iter().map(|it| {
-                        let mut it = it.clone();
-                        let id = it.id.ast_index() + last_ast_index;
-                        it.id = AttrId::new(id, it.id.is_inner_attr());
-                        it
-                    }))
+                    .chain(
+                        b.slice.iter().map(|it| {
+                            let mut it = it.clone();
+                            let id = it.id.ast_index() + last_ast_index;
+                            it.id = AttrId::new(id, it.id.is_inner_attr());
+                            it
+                        }),
+                    )
                     .collect::<Vec<_>>();
-                Self { entries: Some(ThinArc::from_header_and_iter((), items.into_iter())) }
+                Self {
+                    entries: Some(ThinArc::from_header_and_iter((), items.into_iter())),
+                }
             }
         }
     }
@@ -175,13 +179,21 @@ pub struct AttrId {
     id: u32,
 }
 
+// FIXME: This only handles a single level of cfg_attr nesting
+// that is `#[cfg_attr(all(), cfg_attr(all(), cfg(any())))]` breaks again
 impl AttrId {
     const INNER_ATTR_SET_BIT: u32 = 1 << 31;
 
     pub fn new(id: usize, is_inner: bool) -> Self {
         assert!(id <= !Self::INNER_ATTR_SET_BIT as usize);
         let id = id as u32;
-        Self { id: if is_inner { id | Self::INNER_ATTR_SET_BIT } else { id } }
+        Self {
+            id: if is_inner {
+                id | Self::INNER_ATTR_SET_BIT
+            } else {
+                id
+            },
+        }
     }
 
     pub fn ast_index(&self) -> usize {
@@ -245,7 +257,12 @@ impl Attr {
         } else {
             None
         };
-        Some(Attr { id, path, input, ctxt: span.ctx })
+        Some(Attr {
+            id,
+            path,
+            input,
+            ctxt: span.ctx,
+        })
     }
 
     fn from_tt(
@@ -302,7 +319,12 @@ impl Attr {
             }
             _ => None,
         };
-        Some(Attr { id, path, input, ctxt })
+        Some(Attr {
+            id,
+            path,
+            input,
+            ctxt,
+        })
     }
 
     pub fn path(&self) -> &ModPath {
@@ -314,7 +336,10 @@ impl Attr {
         db: &dyn ExpandDatabase,
         cfg_options: &CfgOptions,
     ) -> impl IntoIterator<Item = Self> {
-        let is_cfg_attr = self.path.as_ident().is_some_and(|name| *name == sym::cfg_attr);
+        let is_cfg_attr = self
+            .path
+            .as_ident()
+            .is_some_and(|name| *name == sym::cfg_attr);
         if !is_cfg_attr {
             return smallvec![self];
         }
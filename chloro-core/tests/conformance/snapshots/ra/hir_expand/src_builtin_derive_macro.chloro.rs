@@ -177,7 +177,10 @@ impl AdtShape {
                 vec![s.field_names(span)]
             }
             AdtShape::Enum { variants, .. } => {
-                variants.iter().map(|(_, fields)| fields.field_names(span)).collect()
+                variants
+                    .iter()
+                    .map(|(_, fields)| fields.field_names(span))
+                    .collect()
             }
             AdtShape::Union => {
                 never!("using fields of union in derive is always wrong");
@@ -334,7 +337,11 @@ fn parse_adt_from_syntax(
             } else {
                 None
             };
-            AdtParam { name, const_ty, bounds }
+            AdtParam {
+                name,
+                const_ty,
+                bounds,
+            }
         })
         .collect();
 
@@ -386,7 +393,13 @@ fn parse_adt_from_syntax(
         })
         .collect();
     let name_token = name_to_token(call_site, tm, name)?;
-    Ok(BasicAdtInfo { name: name_token, shape, param_types, where_clause, associated_types })
+    Ok(BasicAdtInfo {
+        name: name_token,
+        shape,
+        param_types,
+        where_clause,
+        associated_types,
+    })
 }
 
 fn to_adt_syntax(
@@ -560,13 +573,7 @@ fn clone_expand(
     tt: &tt::TopSubtree,
 ) -> ExpandResult<tt::TopSubtree> {
     let krate = dollar_crate(span);
-    expand_simple_derive(
-        db,
-        span,
-        tt,
-        quote! {span => #krate::clone::Clone },
-        true,
-        |adt| {
+    expand_simple_derive(db, span, tt, quote! {span => #krate::clone::Clone }, true, |adt| {
         if matches!(adt.shape, AdtShape::Union) {
             let star = tt::Punct { char: '*', spacing: ::tt::Spacing::Alone, span };
             return quote! {span =>
@@ -585,7 +592,9 @@ fn clone_expand(
         }
         let name = &adt.name;
         let patterns = adt.shape.as_pattern(span, name);
-        let exprs = adt.shape.as_pattern_map(name, |it| quote! {span => #it .clone() }, span);
+        let exprs = adt
+            .shape
+            .as_pattern_map(name, |it| quote! {span => #it .clone() }, span);
         let arms = patterns.into_iter().zip(exprs).map(|(pat, expr)| {
             let fat_arrow = fat_arrow(span);
             quote! {span =>
@@ -600,8 +609,7 @@ fn clone_expand(
                 }
             }
         }
-    },
-    )
+    })
 }
 
 /// This function exists since `quote! {span => => }` doesn't work.
@@ -687,13 +695,7 @@ fn debug_expand(
     tt: &tt::TopSubtree,
 ) -> ExpandResult<tt::TopSubtree> {
     let krate = &dollar_crate(span);
-    expand_simple_derive(
-        db,
-        span,
-        tt,
-        quote! {span => #krate::fmt::Debug },
-        false,
-        |adt| {
+    expand_simple_derive(db, span, tt, quote! {span => #krate::fmt::Debug }, false, |adt| {
         let for_variant = |name: String, v: &VariantShape| match v {
             VariantShape::Struct(fields) => {
                 let for_fields = fields.iter().map(|it| {
@@ -757,8 +759,7 @@ fn debug_expand(
                 }
             }
         }
-    },
-    )
+    })
 }
 
 fn hash_expand(
@@ -767,13 +768,7 @@ fn hash_expand(
     tt: &tt::TopSubtree,
 ) -> ExpandResult<tt::TopSubtree> {
     let krate = &dollar_crate(span);
-    expand_simple_derive(
-        db,
-        span,
-        tt,
-        quote! {span => #krate::hash::Hash },
-        false,
-        |adt| {
+    expand_simple_derive(db, span, tt, quote! {span => #krate::hash::Hash }, false, |adt| {
         if matches!(&adt.shape, AdtShape::Enum { variants, .. } if variants.is_empty()) {
             let star = tt::Punct { char: '*', spacing: ::tt::Spacing::Alone, span };
             return quote! {span =>
@@ -811,8 +806,7 @@ fn hash_expand(
                 }
             }
         }
-    },
-    )
+    })
 }
 
 fn eq_expand(
@@ -837,13 +831,7 @@ fn partial_eq_expand(
     tt: &tt::TopSubtree,
 ) -> ExpandResult<tt::TopSubtree> {
     let krate = dollar_crate(span);
-    expand_simple_derive(
-        db,
-        span,
-        tt,
-        quote! {span => #krate::cmp::PartialEq },
-        false,
-        |adt| {
+    expand_simple_derive(db, span, tt, quote! {span => #krate::cmp::PartialEq }, false, |adt| {
         let name = &adt.name;
 
         let (self_patterns, other_patterns) = self_and_other_patterns(adt, name, span);
@@ -882,8 +870,7 @@ fn partial_eq_expand(
                 }
             }
         }
-    },
-    )
+    })
 }
 
 fn self_and_other_patterns(
@@ -916,13 +903,7 @@ fn ord_expand(
     tt: &tt::TopSubtree,
 ) -> ExpandResult<tt::TopSubtree> {
     let krate = &dollar_crate(span);
-    expand_simple_derive(
-        db,
-        span,
-        tt,
-        quote! {span => #krate::cmp::Ord },
-        false,
-        |adt| {
+    expand_simple_derive(db, span, tt, quote! {span => #krate::cmp::Ord }, false, |adt| {
         fn compare(
             krate: &tt::Ident,
             left: tt::TopSubtree,
@@ -971,8 +952,7 @@ fn ord_expand(
                 #body
             }
         }
-    },
-    )
+    })
 }
 
 fn partial_ord_expand(
@@ -981,13 +961,7 @@ fn partial_ord_expand(
     tt: &tt::TopSubtree,
 ) -> ExpandResult<tt::TopSubtree> {
     let krate = &dollar_crate(span);
-    expand_simple_derive(
-        db,
-        span,
-        tt,
-        quote! {span => #krate::cmp::PartialOrd },
-        false,
-        |adt| {
+    expand_simple_derive(db, span, tt, quote! {span => #krate::cmp::PartialOrd }, false, |adt| {
         fn compare(
             krate: &tt::Ident,
             left: tt::TopSubtree,
@@ -1041,8 +1015,7 @@ fn partial_ord_expand(
                 #body
             }
         }
-    },
-    )
+    })
 }
 
 fn coerce_pointee_expand(
@@ -1081,12 +1054,11 @@ fn coerce_pointee_expand(
     }
     let is_repr_transparent = strukt.attrs().any(|attr| {
         attr.as_simple_call().is_some_and(|(name, tt)| {
-            name == "repr"
-                && tt.syntax().children_with_tokens().any(|it| {
-                    it.into_token().is_some_and(|it| {
-                        it.kind() == SyntaxKind::IDENT && it.text() == "transparent"
-                    })
+            name == "repr" && tt.syntax().children_with_tokens().any(|it| {
+                it.into_token().is_some_and(|it| {
+                    it.kind() == SyntaxKind::IDENT && it.text() == "transparent"
                 })
+            })
         })
     });
     if !is_repr_transparent {
@@ -1449,11 +1421,15 @@ fn coerce_pointee_expand(
             || segments_eq(&path, &["std", "marker", "Sized"]);
 
         fn segments_eq(path: &ast::Path, expected: &[&str]) -> bool {
-            path.segments().zip_longest(expected.iter().copied()).all(|value| {
-                value.both().is_some_and(|(segment, expected)| {
-                    segment.name_ref().is_some_and(|name| name.text() == expected)
+            path.segments()
+                .zip_longest(expected.iter().copied())
+                .all(|value| {
+                    value.both().is_some_and(|(segment, expected)| {
+                        segment
+                            .name_ref()
+                            .is_some_and(|name| name.text() == expected)
+                    })
                 })
-            })
         }
     }
 
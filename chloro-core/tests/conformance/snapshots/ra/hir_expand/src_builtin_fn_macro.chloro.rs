@@ -148,7 +148,11 @@ register_builtin! {
 }
 
 fn mk_pound(span: Span) -> tt::Leaf {
-    crate::tt::Leaf::Punct(crate::tt::Punct { char: '#', spacing: crate::tt::Spacing::Alone, span })
+    crate::tt::Leaf::Punct(crate::tt::Punct {
+        char: '#',
+        spacing: crate::tt::Spacing::Alone,
+        span,
+    })
 }
 
 fn module_path_expand(
@@ -172,15 +176,14 @@ fn line_expand(
     // dummy implementation for type-checking purposes
     // Note that `line!` and `column!` will never be implemented properly, as they are by definition
     // not incremental
-    ExpandResult::ok(tt::TopSubtree::invisible_from_leaves(
+    ExpandResult::ok(tt::TopSubtree::invisible_from_leaves(span, [
+        tt::Leaf::Literal(tt::Literal {
+        symbol: sym::INTEGER_0,
         span,
-        [tt::Leaf::Literal(tt::Literal {
-            symbol: sym::INTEGER_0,
-            span,
-            kind: tt::LitKind::Integer,
-            suffix: Some(sym::u32),
-        })],
-    ))
+        kind: tt::LitKind::Integer,
+        suffix: Some(sym::u32),
+    }),
+    ]))
 }
 
 fn log_syntax_expand(
@@ -410,7 +413,10 @@ fn cfg_select_expand(
         }
         None => ExpandResult::new(
             tt::TopSubtree::empty(tt::DelimSpan::from_single(span)),
-            ExpandError::other(span, "none of the predicates in this `cfg_select` evaluated to true"),
+            ExpandError::other(
+                span,
+                "none of the predicates in this `cfg_select` evaluated to true",
+            ),
         ),
     }
 }
@@ -635,7 +641,10 @@ fn concat_expand(
         i += 1;
     }
     let span = span.unwrap_or_else(|| tt.top_subtree().delimiter.open);
-    ExpandResult { value: quote!(span =>#text), err }
+    ExpandResult {
+        value: quote!(span =>#text),
+        err,
+    }
 }
 
 fn concat_bytes_expand(
@@ -700,15 +709,14 @@ fn concat_bytes_expand(
     }
     let span = span.unwrap_or(tt.top_subtree().delimiter.open);
     ExpandResult {
-        value: tt::TopSubtree::invisible_from_leaves(
+        value: tt::TopSubtree::invisible_from_leaves(span, [
+            tt::Leaf::Literal(tt::Literal {
+            symbol: Symbol::intern(&bytes),
             span,
-            [tt::Leaf::Literal(tt::Literal {
-                symbol: Symbol::intern(&bytes),
-                span,
-                kind: tt::LitKind::ByteStr,
-                suffix: None,
-            })],
-        ),
+            kind: tt::LitKind::ByteStr,
+            suffix: None,
+        }),
+        ]),
         err,
     }
 }
@@ -760,7 +768,11 @@ fn relative_file(
     err_span: Span,
 ) -> Result<EditionedFileId, ExpandError> {
     let lookup = db.lookup_intern_macro_call(call_id);
-    let call_site = lookup.kind.file_id().original_file_respecting_includes(db).file_id(db);
+    let call_site = lookup
+        .kind
+        .file_id()
+        .original_file_respecting_includes(db)
+        .file_id(db);
     let path = AnchoredPath { anchor: call_site, path: path_str };
     let res: FileId = db
         .resolve_path(path)
@@ -803,9 +815,7 @@ fn parse_string(tt: &tt::TopSubtree) -> Result<(Symbol, Span), ExpandError> {
             TtElement::Subtree(tt, _) => Err(tt.delimiter.open.cover(tt.delimiter.close)),
         }
     })()
-    .map_err(
-        |span| ExpandError::other(span, "expected string literal"),
-    )
+    .map_err(|span| ExpandError::other(span, "expected string literal"))
 }
 
 fn include_expand(
@@ -934,7 +944,10 @@ fn env_expand(
     });
     let expanded = quote! {span => #s };
 
-    ExpandResult { value: expanded, err }
+    ExpandResult {
+        value: expanded,
+        err,
+    }
 }
 
 fn option_env_expand(
@@ -972,9 +985,9 @@ fn quote_expand(
 ) -> ExpandResult<tt::TopSubtree> {
     ExpandResult::new(
         tt::TopSubtree::empty(tt::DelimSpan {
-        open: span,
-        close: span,
-    }),
+            open: span,
+            close: span,
+        }),
         ExpandError::other(span, "quote! is not implemented"),
     )
 }
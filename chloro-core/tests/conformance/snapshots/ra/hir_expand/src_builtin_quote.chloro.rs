@@ -10,7 +10,11 @@ use tt::IdentIsRaw;
 use crate::{name::Name, tt::TopSubtreeBuilder};
 
 pub(crate) fn dollar_crate(span: Span) -> tt::Ident<Span> {
-    tt::Ident { sym: sym::dollar_crate, span, is_raw: tt::IdentIsRaw::No }
+    tt::Ident {
+        sym: sym::dollar_crate,
+        span,
+        is_raw: tt::IdentIsRaw::No,
+    }
 }
 
 // A helper macro quote macro
@@ -261,7 +265,11 @@ mod tests {
     }
     fn mk_ident(name: &str) -> crate::tt::Ident {
         let (is_raw, s) = IdentIsRaw::split_from_symbol(name);
-        crate::tt::Ident { sym: Symbol::intern(s), span: DUMMY, is_raw }
+        crate::tt::Ident {
+            sym: Symbol::intern(s),
+            span: DUMMY,
+            is_raw,
+        }
     }
     #[test]
     fn test_quote_hash_token_tree() {
@@ -298,7 +306,9 @@ mod tests {
         // }
         let struct_name = mk_ident("Foo");
         let fields = [mk_ident("name"), mk_ident("id")];
-        let fields = fields.iter().map(|it| quote!(DUMMY =>#it: self.#it.clone(), ));
+        let fields = fields
+            .iter()
+            .map(|it| quote!(DUMMY =>#it: self.#it.clone(), ));
 
         let mut builder = tt::TopSubtreeBuilder::new(crate::tt::Delimiter {
             kind: crate::tt::DelimiterKind::Brace,
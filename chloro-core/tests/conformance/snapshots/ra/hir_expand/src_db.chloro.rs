@@ -377,7 +377,10 @@ fn parse_macro_expansion(
     );
     rev_token_map.matched_arm = matched_arm;
 
-    ExpandResult { value: (parse, Arc::new(rev_token_map)), err }
+    ExpandResult {
+        value: (parse, Arc::new(rev_token_map)),
+        err,
+    }
 }
 
 fn parse_macro_expansion_error(
@@ -587,7 +590,9 @@ fn censor_derive_input(derive_attr_index: AttrId, node: &ast::Adt) -> FxHashSet<
 fn attr_source(invoc_attr_index: AttrId, node: &ast::Item) -> Option<ast::Attr> {
     // FIXME: handle `cfg_attr`
     cov_mark::hit!(attribute_macro_attr_censoring);
-    collect_attrs(node).nth(invoc_attr_index.ast_index()).and_then(|(_, attr)| Either::left(attr))
+    collect_attrs(node)
+        .nth(invoc_attr_index.ast_index())
+        .and_then(|(_, attr)| Either::left(attr))
 }
 
 impl TokenExpander {
@@ -682,7 +687,10 @@ fn macro_expand(
         }
     }
 
-    ExpandResult { value: (CowArc::Owned(tt), matched_arm), err }
+    ExpandResult {
+        value: (CowArc::Owned(tt), matched_arm),
+        err,
+    }
 }
 
 fn proc_macro_span(db: &dyn ExpandDatabase, ast: AstId<ast::Fn>) -> Span {
@@ -734,7 +742,10 @@ fn expand_proc_macro(
 
     fixup::reverse_fixups(&mut tt, &undo_info);
 
-    ExpandResult { value: Arc::new(tt), err }
+    ExpandResult {
+        value: Arc::new(tt),
+        err,
+    }
 }
 
 pub(crate) fn token_tree_to_syntax_node(
@@ -761,12 +772,9 @@ fn check_tt_count(tt: &tt::TopSubtree) -> Result<(), ExpandResult<()>> {
     } else {
         Err(ExpandResult {
             value: (),
-            err: Some(ExpandError::other(
-                tt.delimiter.open,
-                format!(
+            err: Some(ExpandError::other(tt.delimiter.open, format!(
                     "macro invocation exceeds token limit: produced {count} tokens, limit is {TOKEN_LIMIT}",
-                ),
-            )),
+                ))),
         })
     }
 }
@@ -36,21 +36,19 @@ impl DeclarativeMacroExpander {
         match self.mac.err() {
             Some(_) => ExpandResult::new(
                 (
-                tt::TopSubtree::empty(tt::DelimSpan {
-                open: span,
-                close: span,
-            }),
-                None,
-            ),
+                    tt::TopSubtree::empty(tt::DelimSpan {
+                    open: span,
+                    close: span,
+                }),
+                    None,
+                ),
                 ExpandError::new(span, ExpandErrorKind::MacroDefinition),
             ),
-            None => self
-                .mac
+            None => self.mac
                 .expand(
                     &tt,
                     |s| {
-                        s.ctx =
-                            apply_mark(db, s.ctx, call_id.into(), self.transparency, self.edition)
+                        s.ctx = apply_mark(db, s.ctx, call_id.into(), self.transparency, self.edition)
                     },
                     span,
                     loc.def.edition,
@@ -68,13 +66,12 @@ impl DeclarativeMacroExpander {
         match self.mac.err() {
             Some(_) => ExpandResult::new(
                 tt::TopSubtree::empty(tt::DelimSpan {
-                open: call_site,
-                close: call_site,
-            }),
+                    open: call_site,
+                    close: call_site,
+                }),
                 ExpandError::new(call_site, ExpandErrorKind::MacroDefinition),
             ),
-            None => self
-                .mac
+            None => self.mac
                 .expand(&tt, |_| (), call_site, def_site_edition)
                 .map(TupleExt::head)
                 .map_err(Into::into),
@@ -177,6 +174,10 @@ impl DeclarativeMacroExpander {
             HirFileId::MacroFile(macro_file) => macro_file.lookup(db).ctxt,
             HirFileId::FileId(file) => SyntaxContext::root(file.edition(db)),
         });
-        Arc::new(DeclarativeMacroExpander { mac, transparency, edition })
+        Arc::new(DeclarativeMacroExpander {
+            mac,
+            transparency,
+            edition,
+        })
     }
 }
@@ -116,7 +116,10 @@ pub fn expand_eager_macro_input(
         ctxt: call_site,
     };
 
-    ExpandResult { value: Some(db.intern_macro_call(loc)), err }
+    ExpandResult {
+        value: Some(db.intern_macro_call(loc)),
+        err,
+    }
 }
 
 fn lazy_expand(
@@ -137,7 +140,8 @@ fn lazy_expand(
     );
     eager_callback(ast_id.map(|ast_id| (AstPtr::new(macro_call), ast_id)), id);
 
-    db.parse_macro_expansion(id).map(|parse| (InFile::new(id.into(), parse.0), parse.1))
+    db.parse_macro_expansion(id)
+        .map(|parse| (InFile::new(id.into(), parse.0), parse.1))
 }
 
 fn eager_macro_recur(
@@ -290,7 +294,10 @@ fn eager_macro_recur(
         }
     }
 
-    replacements.into_iter().rev().for_each(|(old, new)| ted::replace(old.syntax(), new));
+    replacements
+        .into_iter()
+        .rev()
+        .for_each(|(old, new)| ted::replace(old.syntax(), new));
     ExpandResult {
         value: Some((original, offset)),
         err: error,
@@ -221,13 +221,19 @@ impl<FileKind: Copy, T: Clone> InFileWrapper<FileKind, &T> {
 
 impl<T> From<InMacroFile<T>> for InFile<T> {
     fn from(InMacroFile { file_id, value }: InMacroFile<T>) -> Self {
-        InFile { file_id: file_id.into(), value }
+        InFile {
+            file_id: file_id.into(),
+            value,
+        }
     }
 }
 
 impl<T> From<InRealFile<T>> for InFile<T> {
     fn from(InRealFile { file_id, value }: InRealFile<T>) -> Self {
-        InFile { file_id: file_id.into(), value }
+        InFile {
+            file_id: file_id.into(),
+            value,
+        }
     }
 }
 
@@ -306,6 +312,7 @@ impl<FileId: Copy, N: AstNode> InFileWrapper<FileId, &N> {
     }
 }
 
+// region:specific impls
 impl<FileId: Copy, SN: Borrow<SyntaxNode>> InFileWrapper<FileId, SN> {
     pub fn file_range(&self) -> FileRangeWrapper<FileId> {
         FileRangeWrapper {
@@ -363,7 +370,9 @@ impl<SN: Borrow<SyntaxNode>> InFile<SN> {
     /// For attributes and derives, this will point back to the attribute only.
     /// For the entire item use [`InFile::original_file_range_full`].
     pub fn original_file_range_rooted(self, db: &dyn db::ExpandDatabase) -> FileRange {
-        self.borrow().map(SyntaxNode::text_range).original_node_file_range_rooted(db)
+        self.borrow()
+            .map(SyntaxNode::text_range)
+            .original_node_file_range_rooted(db)
     }
 
     /// Falls back to the macro call range if the node cannot be mapped up fully.
@@ -371,7 +380,9 @@ impl<SN: Borrow<SyntaxNode>> InFile<SN> {
         self,
         db: &dyn db::ExpandDatabase,
     ) -> FileRange {
-        self.borrow().map(SyntaxNode::text_range).original_node_file_range_with_macro_call_input(db)
+        self.borrow()
+            .map(SyntaxNode::text_range)
+            .original_node_file_range_with_macro_call_input(db)
     }
 
     pub fn original_syntax_node_rooted(
@@ -416,7 +427,9 @@ impl InFile<&SyntaxNode> {
         self,
         db: &dyn db::ExpandDatabase,
     ) -> Option<(FileRange, SyntaxContext)> {
-        self.borrow().map(SyntaxNode::text_range).original_node_file_range_opt(db)
+        self.borrow()
+            .map(SyntaxNode::text_range)
+            .original_node_file_range_opt(db)
     }
 }
 
@@ -425,7 +438,9 @@ impl InMacroFile<SyntaxToken> {
         self,
         db: &dyn db::ExpandDatabase,
     ) -> InFile<smallvec::SmallVec<[TextRange; 1]>> {
-        self.file_id.expansion_info(db).map_range_up_once(db, self.value.text_range())
+        self.file_id
+            .expansion_info(db)
+            .map_range_up_once(db, self.value.text_range())
     }
 }
 
@@ -433,7 +448,10 @@ impl InFile<SyntaxToken> {
     /// Falls back to the macro call range if the node cannot be mapped up fully.
     pub fn original_file_range(self, db: &dyn db::ExpandDatabase) -> FileRange {
         match self.file_id {
-            HirFileId::FileId(file_id) => FileRange { file_id, range: self.value.text_range() },
+            HirFileId::FileId(file_id) => FileRange {
+                file_id,
+                range: self.value.text_range(),
+            },
             HirFileId::MacroFile(mac_file) => {
                 let (range, ctxt) = span_for_offset(
                     db,
@@ -458,7 +476,10 @@ impl InFile<SyntaxToken> {
     pub fn original_file_range_opt(self, db: &dyn db::ExpandDatabase) -> Option<FileRange> {
         match self.file_id {
             HirFileId::FileId(file_id) => {
-                Some(FileRange { file_id, range: self.value.text_range() })
+                Some(FileRange {
+                    file_id,
+                    range: self.value.text_range(),
+                })
             }
             HirFileId::MacroFile(mac_file) => {
                 let (range, ctxt) = span_for_offset(
@@ -492,7 +513,13 @@ impl InFile<TextRange> {
     ) -> (FileRange, SyntaxContext) {
         match self.file_id {
             HirFileId::FileId(file_id) => {
-                (FileRange { file_id, range: self.value }, SyntaxContext::root(file_id.edition(db)))
+                (
+                    FileRange {
+                    file_id,
+                    range: self.value,
+                },
+                    SyntaxContext::root(file_id.edition(db)),
+                )
             }
             HirFileId::MacroFile(mac_file) => {
                 match map_node_range_up(db, &db.expansion_span_map(mac_file), self.value) {
@@ -508,7 +535,10 @@ impl InFile<TextRange> {
 
     pub fn original_node_file_range_rooted(self, db: &dyn db::ExpandDatabase) -> FileRange {
         match self.file_id {
-            HirFileId::FileId(file_id) => FileRange { file_id, range: self.value },
+            HirFileId::FileId(file_id) => FileRange {
+                file_id,
+                range: self.value,
+            },
             HirFileId::MacroFile(mac_file) => {
                 match map_node_range_up_rooted(db, &db.expansion_span_map(mac_file), self.value) {
                     Some(it) => it,
@@ -526,7 +556,10 @@ impl InFile<TextRange> {
         db: &dyn db::ExpandDatabase,
     ) -> FileRange {
         match self.file_id {
-            HirFileId::FileId(file_id) => FileRange { file_id, range: self.value },
+            HirFileId::FileId(file_id) => FileRange {
+                file_id,
+                range: self.value,
+            },
             HirFileId::MacroFile(mac_file) => {
                 match map_node_range_up_rooted(db, &db.expansion_span_map(mac_file), self.value) {
                     Some(it) => it,
@@ -544,10 +577,15 @@ impl InFile<TextRange> {
         db: &dyn db::ExpandDatabase,
     ) -> Option<(FileRange, SyntaxContext)> {
         match self.file_id {
-            HirFileId::FileId(file_id) => Some((
-                FileRange { file_id, range: self.value },
-                SyntaxContext::root(file_id.edition(db)),
-            )),
+            HirFileId::FileId(file_id) => Some(
+                (
+                    FileRange {
+                    file_id,
+                    range: self.value,
+                },
+                    SyntaxContext::root(file_id.edition(db)),
+                ),
+            ),
             HirFileId::MacroFile(mac_file) => {
                 map_node_range_up(db, &db.expansion_span_map(mac_file), self.value)
             }
@@ -559,7 +597,10 @@ impl InFile<TextRange> {
         db: &dyn db::ExpandDatabase,
     ) -> Option<FileRange> {
         match self.file_id {
-            HirFileId::FileId(file_id) => Some(FileRange { file_id, range: self.value }),
+            HirFileId::FileId(file_id) => Some(FileRange {
+                file_id,
+                range: self.value,
+            }),
             HirFileId::MacroFile(mac_file) => {
                 map_node_range_up_rooted(db, &db.expansion_span_map(mac_file), self.value)
             }
@@ -588,7 +629,10 @@ impl<N: AstNode> InFile<N> {
         )?;
 
         // FIXME: This heuristic is brittle and with the right macro may select completely unrelated nodes?
-        let anc = db.parse(editioned_file_id).syntax_node().covering_element(range);
+        let anc = db
+            .parse(editioned_file_id)
+            .syntax_node()
+            .covering_element(range);
         let value = anc.ancestors().find_map(N::cast)?;
         Some(InRealFile::new(editioned_file_id, value))
     }
@@ -597,7 +641,10 @@ impl<N: AstNode> InFile<N> {
 impl<T> InFile<T> {
     pub fn into_real_file(self) -> Result<InRealFile<T>, InFile<T>> {
         match self.file_id {
-            HirFileId::FileId(file_id) => Ok(InRealFile { file_id, value: self.value }),
+            HirFileId::FileId(file_id) => Ok(InRealFile {
+                file_id,
+                value: self.value,
+            }),
             HirFileId::MacroFile(_) => Err(self),
         }
     }
@@ -43,10 +43,13 @@ impl SyntaxFixupUndoInfo {
     pub(crate) const NONE: Self = SyntaxFixupUndoInfo { original: None };
 }
 
+// We mark spans with `FIXUP_DUMMY_AST_ID` to indicate that they are fake.
 const FIXUP_DUMMY_AST_ID: ErasedFileAstId = FIXUP_ERASED_FILE_AST_ID_MARKER;
 
 const FIXUP_DUMMY_RANGE: TextRange = TextRange::empty(TextSize::new(0));
 
+// If the fake span has this range end, that means that the range start is an index into the
+// `original` list in `SyntaxFixupUndoInfo`.
 const FIXUP_DUMMY_RANGE_END: TextSize = TextSize::new(!0);
 
 pub(crate) fn fixup_syntax(
@@ -336,7 +339,8 @@ fn can_handle_error(node: &SyntaxNode) -> bool {
 }
 
 fn has_error_to_handle(node: &SyntaxNode) -> bool {
-    has_error(node) || node.children().any(|c| !can_handle_error(&c) && has_error_to_handle(&c))
+    has_error(node) || node.children()
+        .any(|c| !can_handle_error(&c) && has_error_to_handle(&c))
 }
 
 pub(crate) fn reverse_fixups(tt: &mut TopSubtree, undo_info: &SyntaxFixupUndoInfo) {
@@ -565,23 +569,29 @@ mod tests {
     }
     #[test]
     fn just_for_token() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     for
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {for _ in __ra_fixup {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn for_no_iter_pattern() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     for {}
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {for _ in __ra_fixup {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn for_no_body() {
@@ -599,37 +609,46 @@ fn foo () {for bar in qux {}}
     // FIXME: https://github.com/rust-lang/rust-analyzer/pull/12937#discussion_r937633695
     #[test]
     fn for_no_pat() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     for in qux {
 
     }
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {__ra_fixup}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn match_no_expr_no_arms() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     match
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {match __ra_fixup {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn match_expr_no_arms() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     match it {
 
     }
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {match it {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn match_no_expr() {
@@ -648,23 +667,29 @@ fn foo () {match __ra_fixup {}}
     }
     #[test]
     fn incomplete_field_expr_1() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     a.
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {a . __ra_fixup}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn incomplete_field_expr_2() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     a.;
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {a . __ra_fixup ;}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn incomplete_field_expr_3() {
@@ -682,13 +707,16 @@ fn foo () {a . __ra_fixup ; bar () ;}
     }
     #[test]
     fn incomplete_let() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     let it = a
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {let it = a ;}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn incomplete_field_expr_in_let() {
@@ -720,113 +748,146 @@ fn foo () {a . b ; bar () ;}
     }
     #[test]
     fn extraneous_comma() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     bar(,);
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {__ra_fixup ;}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_if_1() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     if a
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {if a {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_if_2() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     if
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {if __ra_fixup {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_if_3() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     if {}
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {if __ra_fixup {} {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_while_1() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     while
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {while __ra_fixup {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_while_2() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     while foo
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {while foo {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_while_3() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     while {}
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {while __ra_fixup {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_loop() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     loop
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {loop {}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_path() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     path::
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {path :: __ra_fixup}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_record_ctor_field() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     R { f: }
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {R {f : __ra_fixup}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn no_fixup_record_ctor_field() {
-        check(r#"
+        check(
+            r#"
 fn foo() {
     R { f: a }
 }
-"#, expect![[r#"
+"#,
+            expect![[r#"
 fn foo () {R {f : a}}
-"#]])
+"#]],
+        )
     }
     #[test]
     fn fixup_arg_list() {
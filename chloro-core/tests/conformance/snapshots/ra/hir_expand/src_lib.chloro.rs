@@ -104,6 +104,7 @@ macro_rules! impl_intern_lookup {
     };
 }
 
+// ideally these would be defined in base-db, but the orphan rule doesn't let us
 pub trait Intern {
     type Database;
 
@@ -137,11 +138,15 @@ pub struct ExpandError {
 
 impl ExpandError {
     pub fn new(span: Span, kind: ExpandErrorKind) -> Self {
-        ExpandError { inner: Arc::new((kind, span)) }
+        ExpandError {
+            inner: Arc::new((kind, span)),
+        }
     }
 
     pub fn other(span: Span, msg: impl Into<Box<str>>) -> Self {
-        ExpandError { inner: Arc::new((ExpandErrorKind::Other(msg.into()), span)) }
+        ExpandError {
+            inner: Arc::new((ExpandErrorKind::Other(msg.into()), span)),
+        }
     }
 
     pub fn kind(&self) -> &ExpandErrorKind {
@@ -206,7 +211,9 @@ impl ExpandErrorKind {
                 kind: RenderedExpandError::DISABLED,
             },
             &ExpandErrorKind::MissingProcMacroExpander(def_crate) => {
-                match db.proc_macros_for_crate(def_crate).as_ref().and_then(|it| it.get_error()) {
+                match db.proc_macros_for_crate(def_crate)
+                    .as_ref()
+                    .and_then(|it| it.get_error()) {
                     Some(e) => RenderedExpandError {
                         message: e.to_string(),
                         error: e.is_hard_error(),
@@ -252,7 +259,9 @@ impl ExpandErrorKind {
 
 impl From<mbe::ExpandError> for ExpandError {
     fn from(mbe: mbe::ExpandError) -> Self {
-        ExpandError { inner: Arc::new((ExpandErrorKind::Mbe(mbe.inner.1.clone()), mbe.inner.0)) }
+        ExpandError {
+            inner: Arc::new((ExpandErrorKind::Mbe(mbe.inner.1.clone()), mbe.inner.0)),
+        }
     }
 }
 
@@ -521,7 +530,12 @@ impl MacroDefId {
         kind: MacroCallKind,
         ctxt: SyntaxContext,
     ) -> MacroCallId {
-        db.intern_macro_call(MacroCallLoc { def: self, krate, kind, ctxt })
+        db.intern_macro_call(MacroCallLoc {
+            def: self,
+            krate,
+            kind,
+            ctxt,
+        })
     }
 
     pub fn definition_range(&self, db: &dyn ExpandDatabase) -> InFile<TextRange> {
@@ -779,7 +793,9 @@ impl MacroCallKind {
     fn arg(&self, db: &dyn ExpandDatabase) -> InFile<Option<SyntaxNode>> {
         match self {
             MacroCallKind::FnLike { ast_id, .. } => {
-                ast_id.to_in_file_node(db).map(|it| Some(it.token_tree()?.syntax().clone()))
+                ast_id
+                    .to_in_file_node(db)
+                    .map(|it| Some(it.token_tree()?.syntax().clone()))
             }
             MacroCallKind::Derive { ast_id, .. } => {
                 ast_id.to_in_file_node(db).syntax().cloned().map(Some)
@@ -791,6 +807,8 @@ impl MacroCallKind {
     }
 }
 
+// FIXME: can be expensive to create, we should check the use sites and maybe replace them with
+// simpler function calls if the map is only used once
 /// ExpansionInfo mainly describes how to map text range between src and expanded macro
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ExpansionInfo {
@@ -831,9 +849,16 @@ impl ExpansionInfo {
         &self,
         span: Span,
     ) -> Option<InMacroFile<impl Iterator<Item = (SyntaxToken, SyntaxContext)> + '_>> {
-        let tokens = self.exp_map.ranges_with_span_exact(span).flat_map(move |(range, ctx)| {
-            self.expanded.value.covering_element(range).into_token().zip(Some(ctx))
-        });
+        let tokens = self
+            .exp_map
+            .ranges_with_span_exact(span)
+            .flat_map(move |(range, ctx)| {
+                self.expanded
+                    .value
+                    .covering_element(range)
+                    .into_token()
+                    .zip(Some(ctx))
+            });
 
         Some(InMacroFile::new(self.expanded.file_id, tokens))
     }
@@ -846,9 +871,16 @@ impl ExpansionInfo {
         &self,
         span: Span,
     ) -> Option<InMacroFile<impl Iterator<Item = (SyntaxToken, SyntaxContext)> + '_>> {
-        let tokens = self.exp_map.ranges_with_span(span).flat_map(move |(range, ctx)| {
-            self.expanded.value.covering_element(range).into_token().zip(Some(ctx))
-        });
+        let tokens = self
+            .exp_map
+            .ranges_with_span(span)
+            .flat_map(move |(range, ctx)| {
+                self.expanded
+                    .value
+                    .covering_element(range)
+                    .into_token()
+                    .zip(Some(ctx))
+            });
 
         Some(InMacroFile::new(self.expanded.file_id, tokens))
     }
@@ -889,7 +921,10 @@ impl ExpansionInfo {
                 let file_id = EditionedFileId::from_span(db, span.anchor.file_id).into();
                 let anchor_offset =
                     db.ast_id_map(file_id).get_erased(span.anchor.ast_id).text_range().start();
-                InFile { file_id, value: smallvec::smallvec![span.range + anchor_offset] }
+                InFile {
+                    file_id,
+                    value: smallvec::smallvec![span.range + anchor_offset],
+                }
             }
             SpanMap::ExpansionSpanMap(arg_map) => {
                 let Some(arg_node) = &self.arg.value else {
@@ -918,7 +953,13 @@ impl ExpansionInfo {
         let (parse, exp_map) = db.parse_macro_expansion(macro_file).value;
         let expanded = InMacroFile { file_id: macro_file, value: parse.syntax_node() };
 
-        ExpansionInfo { expanded, loc, arg: arg_tt, exp_map, arg_map }
+        ExpansionInfo {
+            expanded,
+            loc,
+            arg: arg_tt,
+            exp_map,
+            arg_map,
+        }
     }
 }
 
@@ -930,7 +971,9 @@ pub fn map_node_range_up_rooted(
     exp_map: &ExpansionSpanMap,
     range: TextRange,
 ) -> Option<FileRange> {
-    let mut spans = exp_map.spans_for_range(range).filter(|span| span.ctx.is_root());
+    let mut spans = exp_map
+        .spans_for_range(range)
+        .filter(|span| span.ctx.is_root());
     let Span { range, anchor, ctx: _ } = spans.next()?;
     let mut start = range.start();
     let mut end = range.end();
@@ -945,7 +988,10 @@ pub fn map_node_range_up_rooted(
     let file_id = EditionedFileId::from_span(db, anchor.file_id);
     let anchor_offset =
         db.ast_id_map(file_id.into()).get_erased(anchor.ast_id).text_range().start();
-    Some(FileRange { file_id, range: TextRange::new(start, end) + anchor_offset })
+    Some(FileRange {
+        file_id,
+        range: TextRange::new(start, end) + anchor_offset,
+    })
 }
 
 /// Maps up the text range out of the expansion hierarchy back into the original file its from.
@@ -971,7 +1017,15 @@ pub fn map_node_range_up(
     let file_id = EditionedFileId::from_span(db, anchor.file_id);
     let anchor_offset =
         db.ast_id_map(file_id.into()).get_erased(anchor.ast_id).text_range().start();
-    Some((FileRange { file_id, range: TextRange::new(start, end) + anchor_offset }, ctx))
+    Some(
+        (
+            FileRange {
+            file_id,
+            range: TextRange::new(start, end) + anchor_offset,
+        },
+            ctx,
+        ),
+    )
 }
 
 /// Maps up the text range out of the expansion hierarchy back into the original file its from.
@@ -1008,7 +1062,10 @@ pub fn span_for_offset(
     let file_id = EditionedFileId::from_span(db, span.anchor.file_id);
     let anchor_offset =
         db.ast_id_map(file_id.into()).get_erased(span.anchor.ast_id).text_range().start();
-    (FileRange { file_id, range: span.range + anchor_offset }, span.ctx)
+    (FileRange {
+        file_id,
+        range: span.range + anchor_offset,
+    }, span.ctx)
 }
 
 /// In Rust, macros expand token trees to token trees. When we want to turn a
@@ -61,7 +61,10 @@ impl ModPath {
 
     /// Creates a `ModPath` from a `PathKind`, with no extra path segments.
     pub const fn from_kind(kind: PathKind) -> ModPath {
-        ModPath { kind, segments: SmallVec::new_const() }
+        ModPath {
+            kind,
+            segments: SmallVec::new_const(),
+        }
     }
 
     pub fn segments(&self) -> &[Name] {
@@ -79,14 +82,13 @@ impl ModPath {
     /// Returns the number of segments in the path (counting special segments like `$crate` and
     /// `super`).
     pub fn len(&self) -> usize {
-        self.segments.len()
-            + match self.kind {
-                PathKind::Plain => 0,
-                PathKind::Super(i) => i as usize,
-                PathKind::Crate => 1,
-                PathKind::Abs => 0,
-                PathKind::DollarCrate(_) => 1,
-            }
+        self.segments.len() + match self.kind {
+            PathKind::Plain => 0,
+            PathKind::Super(i) => i as usize,
+            PathKind::Crate => 1,
+            PathKind::Abs => 0,
+            PathKind::DollarCrate(_) => 1,
+        }
     }
 
     pub fn textual_len(&self) -> usize {
@@ -98,7 +100,10 @@ impl ModPath {
             PathKind::Abs => 0,
             PathKind::DollarCrate(_) => "$crate".len(),
         };
-        self.segments().iter().map(|segment| segment.as_str().len()).fold(base, core::ops::Add::add)
+        self.segments()
+            .iter()
+            .map(|segment| segment.as_str().len())
+            .fold(base, core::ops::Add::add)
     }
 
     pub fn is_ident(&self) -> bool {
@@ -130,7 +135,11 @@ impl ModPath {
         &'a self,
         db: &'a dyn crate::db::ExpandDatabase,
     ) -> impl fmt::Display + 'a {
-        Display { db, path: self, edition: None }
+        Display {
+            db,
+            path: self,
+            edition: None,
+        }
     }
 
     pub fn display<'a>(
@@ -138,7 +147,11 @@ impl ModPath {
         db: &'a dyn crate::db::ExpandDatabase,
         edition: Edition,
     ) -> impl fmt::Display + 'a {
-        Display { db, path: self, edition: Some(edition) }
+        Display {
+            db,
+            path: self,
+            edition: Some(edition),
+        }
     }
 }
 
@@ -23,7 +23,9 @@ pub fn prettify_macro_expansion(
     syntax_bridge::prettify_macro_expansion::prettify_macro_expansion(
         syn,
         &mut |dollar_crate| {
-            let ctx = span_map.span_at(dollar_crate.text_range().start() + span_offset).ctx;
+            let ctx = span_map
+                .span_at(dollar_crate.text_range().start() + span_offset)
+                .ctx;
             let replacement =
                 syntax_ctx_id_to_dollar_crate_replacement.entry(ctx).or_insert_with(|| {
                     let macro_call_id = ctx
@@ -54,7 +56,11 @@ pub fn prettify_macro_expansion(
                 return None;
             }
             // We need to `clone_subtree()` but rowan doesn't provide such operation for tokens.
-            let parent = replacement.parent().unwrap().clone_subtree().clone_for_update();
+            let parent = replacement
+                .parent()
+                .unwrap()
+                .clone_subtree()
+                .clone_for_update();
             parent
                 .children_with_tokens()
                 .filter_map(NodeOrToken::into_token)
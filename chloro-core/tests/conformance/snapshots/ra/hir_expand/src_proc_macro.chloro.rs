@@ -74,13 +74,10 @@ impl ProcMacrosBuilder {
                     .cmp(&(proc_macro2.name.as_str(), proc_macro2.kind))
             });
         }
-        self.0.insert(
-            proc_macros_crate,
-            match proc_macro {
-                Ok(it) => Arc::new(CrateProcMacros(Ok(it.into_boxed_slice()))),
-                Err(e) => Arc::new(CrateProcMacros(Err(e))),
-            },
-        );
+        self.0.insert(proc_macros_crate, match proc_macro {
+            Ok(it) => Arc::new(CrateProcMacros(Ok(it.into_boxed_slice()))),
+            Err(e) => Arc::new(CrateProcMacros(Err(e))),
+        });
     }
 
     pub(crate) fn build(self, crates_id_map: &CratesIdMap) -> ProcMacros {
@@ -128,15 +125,12 @@ impl CrateProcMacros {
             }
         };
         proc_macros.get(idx as usize).ok_or_else(|| {
-                ExpandError::other(err_span,
-                    format!(
+            ExpandError::other(err_span, format!(
                         "internal error: proc-macro index out of bounds: the length is {} but the index is {}",
                         proc_macros.len(),
                         idx
-                    )
-                )
-            }
-        )
+                    ))
+        })
     }
 
     pub fn get_error(&self) -> Option<&ProcMacroLoadingError> {
@@ -177,6 +171,7 @@ pub struct ProcMacro {
     pub disabled: bool,
 }
 
+// `#[derive(PartialEq)]` generates a strange "cannot move" error.
 impl PartialEq for ProcMacro {
     fn eq(&self, other: &Self) -> bool {
         let Self { name, kind, expander, disabled } = self;
@@ -212,18 +207,24 @@ impl CustomProcMacroExpander {
 
     /// An expander that always errors due to the actual proc-macro expander missing.
     pub const fn missing_expander() -> Self {
-        Self { proc_macro_id: Self::MISSING_EXPANDER }
+        Self {
+            proc_macro_id: Self::MISSING_EXPANDER,
+        }
     }
 
     /// A dummy expander that always errors. This expander is used for macros that have been disabled.
     pub const fn disabled() -> Self {
-        Self { proc_macro_id: Self::DISABLED_ID }
+        Self {
+            proc_macro_id: Self::DISABLED_ID,
+        }
     }
 
     /// A dummy expander that always errors. This expander is used for attribute macros when
     /// proc-macro attribute expansion is disabled.
     pub const fn disabled_proc_attr() -> Self {
-        Self { proc_macro_id: Self::PROC_MACRO_ATTR_DISABLED }
+        Self {
+            proc_macro_id: Self::PROC_MACRO_ATTR_DISABLED,
+        }
     }
 
     /// The macro-expander is missing or has yet to be build.
@@ -264,23 +265,23 @@ impl CustomProcMacroExpander {
         match self.proc_macro_id {
             Self::PROC_MACRO_ATTR_DISABLED => ExpandResult::new(
                 tt::TopSubtree::empty(tt::DelimSpan {
-                open: call_site,
-                close: call_site,
-            }),
+                    open: call_site,
+                    close: call_site,
+                }),
                 ExpandError::new(call_site, ExpandErrorKind::ProcMacroAttrExpansionDisabled),
             ),
             Self::MISSING_EXPANDER => ExpandResult::new(
                 tt::TopSubtree::empty(tt::DelimSpan {
-                open: call_site,
-                close: call_site,
-            }),
+                    open: call_site,
+                    close: call_site,
+                }),
                 ExpandError::new(call_site, ExpandErrorKind::MissingProcMacroExpander(def_crate)),
             ),
             Self::DISABLED_ID => ExpandResult::new(
                 tt::TopSubtree::empty(tt::DelimSpan {
-                open: call_site,
-                close: call_site,
-            }),
+                    open: call_site,
+                    close: call_site,
+                }),
                 ExpandError::new(call_site, ExpandErrorKind::MacroDisabled),
             ),
             id => {
@@ -338,13 +339,13 @@ impl CustomProcMacroExpander {
                         ProcMacroExpansionError::System(text)
                         | ProcMacroExpansionError::Panic(text) => ExpandResult::new(
                             tt::TopSubtree::empty(tt::DelimSpan {
-                            open: call_site,
-                            close: call_site,
-                        }),
+                                open: call_site,
+                                close: call_site,
+                            }),
                             ExpandError::new(
-                            call_site,
-                            ExpandErrorKind::ProcMacroPanic(text.into_boxed_str()),
-                        ),
+                                call_site,
+                                ExpandErrorKind::ProcMacroPanic(text.into_boxed_str()),
+                            ),
                         ),
                     },
                 }
@@ -273,7 +273,11 @@ impl<'a, 'db, Steps: TrackAutoderefSteps<'db>> Autoderef<'a, 'db, Steps> {
         debug!("overloaded_deref_ty({:?}) = ({:?}, {:?})", ty, normalized_ty, obligations);
         self.state.obligations.extend(obligations);
 
-        Some(self.table.infer_ctxt.resolve_vars_if_possible(normalized_ty))
+        Some(
+            self.table
+                .infer_ctxt
+                .resolve_vars_if_possible(normalized_ty),
+        )
     }
 
     /// Returns the final type we ended up with, which may be an unresolved
@@ -348,5 +352,8 @@ pub(crate) fn overloaded_deref_ty<'db>(
     let (normalized_ty, obligations) =
         structurally_normalize_ty(table, Ty::new_projection(interner, trait_target.into(), [ty]))?;
 
-    Some(InferOk { value: normalized_ty, obligations })
+    Some(InferOk {
+        value: normalized_ty,
+        obligations,
+    })
 }
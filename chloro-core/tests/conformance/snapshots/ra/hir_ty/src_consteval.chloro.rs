@@ -203,7 +203,9 @@ pub(crate) fn const_eval_discriminant_variant<'db>(
     }
 
     let repr = db.enum_signature(loc.parent).repr;
-    let is_signed = repr.and_then(|repr| repr.int).is_none_or(|int| int.is_signed());
+    let is_signed = repr
+        .and_then(|repr| repr.int)
+        .is_none_or(|int| int.is_signed());
 
     let mir_body = db.monomorphized_mir_body(
         def,
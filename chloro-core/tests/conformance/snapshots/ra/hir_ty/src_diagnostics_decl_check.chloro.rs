@@ -126,7 +126,10 @@ struct Replacement {
 
 impl<'a> DeclValidator<'a> {
     pub(super) fn new(db: &'a dyn HirDatabase) -> DeclValidator<'a> {
-        DeclValidator { db, sink: Vec::new() }
+        DeclValidator {
+            db,
+            sink: Vec::new(),
+        }
     }
 
     pub(super) fn validate_item(&mut self, item: ModuleDefId) {
@@ -46,9 +46,7 @@ pub enum BodyValidationDiagnostic {
         variant: VariantId,
         missed_fields: Vec<LocalFieldId>,
     },
-    ReplaceFilterMapNextWithFindMap {
-        method_call_expr: ExprId,
-    },
+    ReplaceFilterMapNextWithFindMap { method_call_expr: ExprId },
     MissingMatchArms {
         match_expr: ExprId,
         uncovered_patterns: String,
@@ -57,12 +55,8 @@ pub enum BodyValidationDiagnostic {
         pat: PatId,
         uncovered_patterns: String,
     },
-    RemoveTrailingReturn {
-        return_expr: ExprId,
-    },
-    RemoveUnnecessaryElse {
-        if_expr: ExprId,
-    },
+    RemoveTrailingReturn { return_expr: ExprId },
+    RemoveUnnecessaryElse { if_expr: ExprId },
 }
 
 impl BodyValidationDiagnostic {
@@ -151,11 +145,12 @@ impl<'db> ExprValidator<'db> {
 
         for (id, pat) in body.pats() {
             if let Some((variant, missed_fields, true)) = record_pattern_missing_fields(db, &self.infer, id, pat) {
-                self.diagnostics.push(BodyValidationDiagnostic::RecordMissingFields {
-                    record: Either::Right(id),
-                    variant,
-                    missed_fields,
-                });
+                self.diagnostics
+                    .push(BodyValidationDiagnostic::RecordMissingFields {
+                        record: Either::Right(id),
+                        variant,
+                        missed_fields,
+                    });
             }
         }
     }
@@ -266,16 +261,17 @@ impl<'db> ExprValidator<'db> {
         // https://github.com/rust-lang/rust/blob/f31622a50/compiler/rustc_mir_build/src/thir/pattern/check_match.rs#L200
         let witnesses = report.non_exhaustiveness_witnesses;
         if !witnesses.is_empty() {
-            self.diagnostics.push(BodyValidationDiagnostic::MissingMatchArms {
-                match_expr,
-                uncovered_patterns: missing_match_arms(
-                    &cx,
-                    scrut_ty,
-                    witnesses,
-                    m_arms.is_empty(),
-                    self.owner.krate(self.db()),
-                ),
-            });
+            self.diagnostics
+                .push(BodyValidationDiagnostic::MissingMatchArms {
+                    match_expr,
+                    uncovered_patterns: missing_match_arms(
+                        &cx,
+                        scrut_ty,
+                        witnesses,
+                        m_arms.is_empty(),
+                        self.owner.krate(self.db()),
+                    ),
+                });
         }
     }
 
@@ -361,16 +357,11 @@ impl<'db> ExprValidator<'db> {
             };
             let witnesses = report.non_exhaustiveness_witnesses;
             if !witnesses.is_empty() {
-                self.diagnostics.push(BodyValidationDiagnostic::NonExhaustiveLet {
-                    pat,
-                    uncovered_patterns: missing_match_arms(
-                        &cx,
-                        ty,
-                        witnesses,
-                        false,
-                        self.owner.krate(self.db()),
-                    ),
-                });
+                self.diagnostics
+                    .push(BodyValidationDiagnostic::NonExhaustiveLet {
+                        pat,
+                        uncovered_patterns: missing_match_arms(&cx, ty, witnesses, false, self.owner.krate(self.db())),
+                    });
             }
         }
     }
@@ -417,9 +408,10 @@ impl<'db> ExprValidator<'db> {
                 }
             }
             Expr::Return { .. } => {
-                self.diagnostics.push(BodyValidationDiagnostic::RemoveTrailingReturn {
-                    return_expr: body_expr,
-                });
+                self.diagnostics
+                    .push(BodyValidationDiagnostic::RemoveTrailingReturn {
+                        return_expr: body_expr,
+                    });
             }
             _ => (),
         }
@@ -439,8 +431,7 @@ impl<'db> ExprValidator<'db> {
                     _ => None,
                 });
                 if let Some(last_then_expr) = last_then_expr
-                    && let Some(last_then_expr_ty) =
-                        self.infer.type_of_expr_with_adjust(last_then_expr)
+                    && let Some(last_then_expr_ty) = self.infer.type_of_expr_with_adjust(last_then_expr)
                     && last_then_expr_ty.is_never()
                 {
                     // Only look at sources if the then branch diverges and we have an else branch.
@@ -73,25 +73,17 @@ pub(crate) enum PatKind<'db> {
 
     /// `(...)`, `Foo(...)`, `Foo{...}`, or `Foo`, where `Foo` is a variant name from an ADT with
     /// a single variant.
-    Leaf {
-        subpatterns: Vec<FieldPat<'db>>,
-    },
+    Leaf { subpatterns: Vec<FieldPat<'db>> },
 
     /// `&P`, `&mut P`, etc.
-    Deref {
-        subpattern: Pat<'db>,
-    },
+    Deref { subpattern: Pat<'db> },
 
     // FIXME: for now, only bool literals are implemented
-    LiteralBool {
-        value: bool,
-    },
+    LiteralBool { value: bool },
 
     /// An or-pattern, e.g. `p | q`.
     /// Invariant: `pats.len() >= 2`.
-    Or {
-        pats: Vec<Pat<'db>>,
-    },
+    Or { pats: Vec<Pat<'db>> },
 }
 
 pub(crate) struct PatCtxt<'a, 'db> {
@@ -107,7 +99,12 @@ impl<'a, 'db> PatCtxt<'a, 'db> {
         infer: &'a InferenceResult<'db>,
         body: &'a Body,
     ) -> Self {
-        Self { db, infer, body, errors: Vec::new() }
+        Self {
+            db,
+            infer,
+            body,
+            errors: Vec::new(),
+        }
     }
 
     pub(crate) fn lower_pattern(&mut self, pat: PatId) -> Pat<'db> {
@@ -117,10 +114,17 @@ impl<'a, 'db> PatCtxt<'a, 'db> {
         // Pattern adjustment is part of RFC 2005-match-ergonomics.
         // More info https://github.com/rust-lang/rust/issues/42640#issuecomment-313535089
         let unadjusted_pat = self.lower_pattern_unadjusted(pat);
-        self.infer.pat_adjustments.get(&pat).map(|it| &**it).unwrap_or_default().iter().rev().fold(
-            unadjusted_pat,
-            |subpattern, ref_ty| Pat { ty: *ref_ty, kind: Box::new(PatKind::Deref { subpattern }) },
-        )
+        self.infer
+            .pat_adjustments
+            .get(&pat)
+            .map(|it| &**it)
+            .unwrap_or_default()
+            .iter()
+            .rev()
+            .fold(
+                unadjusted_pat,
+                |subpattern, ref_ty| Pat { ty: *ref_ty, kind: Box::new(PatKind::Deref { subpattern }) },
+            )
     }
 
     fn lower_pattern_unadjusted(&mut self, pat: PatId) -> Pat<'db> {
@@ -208,7 +212,10 @@ impl<'a, 'db> PatCtxt<'a, 'db> {
             }
         };
 
-        Pat { ty, kind: Box::new(kind) }
+        Pat {
+            ty,
+            kind: Box::new(kind),
+        }
     }
 
     fn lower_tuple_subpats(
@@ -260,7 +267,11 @@ impl<'a, 'db> PatCtxt<'a, 'db> {
                             return PatKind::Wild;
                         }
                     };
-                    PatKind::Variant { substs, enum_variant, subpatterns }
+                    PatKind::Variant {
+                        substs,
+                        enum_variant,
+                        subpatterns,
+                    }
                 } else {
                     PatKind::Leaf { subpatterns }
                 }
@@ -25,6 +25,7 @@ use super::{FieldPat, Pat, PatKind};
 
 use Constructor::*;
 
+// Re-export r-a-specific versions of all these types.
 pub(crate) type DeconstructedPat<'a, 'db> = rustc_pattern_analysis::pat::DeconstructedPat<MatchCheckCtx<'a, 'db>>;
 
 pub(crate) type MatchArm<'a, 'b, 'db> = rustc_pattern_analysis::MatchArm<'b, MatchCheckCtx<'a, 'db>>;
@@ -83,7 +84,13 @@ impl<'a, 'db> MatchCheckCtx<'a, 'db> {
         let db = infcx.interner.db;
         let def_map = module.crate_def_map(db);
         let exhaustive_patterns = def_map.is_unstable_feature_enabled(&sym::exhaustive_patterns);
-        Self { module, db, exhaustive_patterns, env, infcx }
+        Self {
+            module,
+            db,
+            exhaustive_patterns,
+            env,
+            infcx,
+        }
     }
 
     pub(crate) fn compute_match_usefulness<'b>(
@@ -114,7 +121,10 @@ impl<'a, 'db> MatchCheckCtx<'a, 'db> {
     /// Returns whether the given ADT is from another crate declared `#[non_exhaustive]`.
     fn is_foreign_non_exhaustive(&self, adt: hir_def::AdtId) -> bool {
         let is_local = adt.krate(self.db) == self.module.krate();
-        !is_local && self.db.attrs(adt.into()).by_key(sym::non_exhaustive).exists()
+        !is_local && self.db
+            .attrs(adt.into())
+            .by_key(sym::non_exhaustive)
+            .exists()
     }
 
     fn variant_id_for_adt(
@@ -149,15 +159,17 @@ impl<'a, 'db> MatchCheckCtx<'a, 'db> {
         let field_tys = self.db.field_types(variant);
         let fields_len = variant.fields(self.db).fields().len() as u32;
 
-        (0..fields_len).map(|idx| LocalFieldId::from_raw(idx.into())).map(move |fid| {
-            let ty = field_tys[fid].instantiate(self.infcx.interner, substs);
-            let ty = self
-                .infcx
-                .at(&ObligationCause::dummy(), self.env.env)
-                .deeply_normalize(ty)
-                .unwrap_or(ty);
-            (fid, ty)
-        })
+        (0..fields_len)
+            .map(|idx| LocalFieldId::from_raw(idx.into()))
+            .map(move |fid| {
+                let ty = field_tys[fid].instantiate(self.infcx.interner, substs);
+                let ty = self
+                    .infcx
+                    .at(&ObligationCause::dummy(), self.env.env)
+                    .deeply_normalize(ty)
+                    .unwrap_or(ty);
+                (fid, ty)
+            })
     }
 
     pub(crate) fn lower_pat(&self, pat: &Pat<'db>) -> DeconstructedPat<'a, 'db> {
@@ -470,11 +482,15 @@ impl<'a, 'db> PatCx for MatchCheckCtx<'a, 'db> {
                     }
                     hir_def::AdtId::UnionId(_) => ConstructorSet::Union,
                     hir_def::AdtId::StructId(_) => {
-                        ConstructorSet::Struct { empty: cx.is_uninhabited(*ty) }
+                        ConstructorSet::Struct {
+                            empty: cx.is_uninhabited(*ty),
+                        }
                     }
                 }
             }
-            TyKind::Tuple(..) => ConstructorSet::Struct { empty: cx.is_uninhabited(*ty) },
+            TyKind::Tuple(..) => ConstructorSet::Struct {
+                empty: cx.is_uninhabited(*ty),
+            },
             TyKind::Ref(..) => ConstructorSet::Ref,
             TyKind::Never => ConstructorSet::NoConstructors,
             // This type is one for which we cannot list constructors, like `str` or `f64`.
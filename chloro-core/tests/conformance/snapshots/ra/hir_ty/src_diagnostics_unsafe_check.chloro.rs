@@ -232,7 +232,9 @@ impl<'db> UnsafeVisitor<'db> {
     }
 
     fn walk_pats_top(&mut self, pats: impl Iterator<Item = PatId>, parent_expr: ExprId) {
-        let guard = self.resolver.update_to_inner_scope(self.db, self.def, parent_expr);
+        let guard = self
+            .resolver
+            .update_to_inner_scope(self.db, self.def, parent_expr);
         pats.for_each(|pat| self.walk_pat(pat));
         self.resolver.reset_to_guard(guard);
     }
@@ -281,7 +283,8 @@ impl<'db> UnsafeVisitor<'db> {
             _ => {}
         }
 
-        self.body.walk_pats_shallow(current, |pat| self.walk_pat(pat));
+        self.body
+            .walk_pats_shallow(current, |pat| self.walk_pat(pat));
     }
 
     fn walk_expr(&mut self, current: ExprId) {
@@ -427,18 +430,20 @@ impl<'db> UnsafeVisitor<'db> {
             _ => {}
         }
 
-        self.body.walk_child_exprs_without_pats(current, |child| self.walk_expr(child));
+        self.body
+            .walk_child_exprs_without_pats(current, |child| self.walk_expr(child));
     }
 
     fn mark_unsafe_path(&mut self, node: ExprOrPatId, path: &Path) {
         let hygiene = self.body.expr_or_pat_path_hygiene(node);
-        let value_or_partial = self.resolver.resolve_path_in_value_ns(self.db, path, hygiene);
+        let value_or_partial = self
+            .resolver
+            .resolve_path_in_value_ns(self.db, path, hygiene);
         if let Some(ResolveValueResult::ValueNs(ValueNs::StaticId(id), _)) = value_or_partial {
             let static_data = self.db.static_signature(id);
             if static_data.flags.contains(StaticFlags::MUTABLE) {
                 self.on_unsafe_op(node, UnsafetyReason::MutableStatic);
-            } else if static_data.flags.contains(StaticFlags::EXTERN)
-                && !static_data.flags.contains(StaticFlags::EXPLICIT_SAFE) {
+            } else if static_data.flags.contains(StaticFlags::EXTERN) && !static_data.flags.contains(StaticFlags::EXPLICIT_SAFE) {
                 self.on_unsafe_op(node, UnsafetyReason::ExternStatic);
             }
         }
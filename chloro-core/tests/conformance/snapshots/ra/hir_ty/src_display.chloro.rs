@@ -69,8 +69,10 @@ pub trait HirWrite {
     fn end_location_link(&mut self) {}
 }
 
+// String will ignore link metadata
 impl HirWrite for String {}
 
+// `core::Formatter` will ignore metadata
 impl HirWrite for fmt::Formatter<'_> {}
 
 pub struct HirFormatter<'a, 'db> {
@@ -98,6 +100,11 @@ pub struct HirFormatter<'a, 'db> {
     bounds_formatting_ctx: BoundsFormattingCtx<'db>,
 }
 
+// FIXME: To consider, ref and dyn trait lifetimes can be omitted if they are `'_`, path args should
+// not be when in signatures
+// So this enum does not encode this well enough
+// Also 'static can be omitted for ref and dyn trait lifetimes in static/const item types
+// FIXME: Also named lifetimes may be rendered in places where their name is not in scope?
 #[derive(Copy, Clone)]
 pub enum DisplayLifetime {
     Always,
@@ -722,7 +729,10 @@ fn render_const_scalar<'db>(
 ) -> Result<(), HirDisplayError> {
     let trait_env = TraitEnvironment::empty(f.krate());
     let infcx = f.interner.infer_ctxt().build(TypingMode::PostAnalysis);
-    let ty = infcx.at(&ObligationCause::new(), trait_env.env).deeply_normalize(ty).unwrap_or(ty);
+    let ty = infcx
+        .at(&ObligationCause::new(), trait_env.env)
+        .deeply_normalize(ty)
+        .unwrap_or(ty);
     render_const_scalar_inner(f, b, memory_map, ty, trait_env)
 }
 
@@ -756,7 +766,10 @@ fn render_const_scalar_inner<'db>(
                 // FIXME(#17451): Replace with builtins once they are stabilised.
                 let it = f16::from_bits(u16::from_le_bytes(b.try_into().unwrap()).into());
                 let s = it.to_string();
-                if s.strip_prefix('-').unwrap_or(&s).chars().all(|c| c.is_ascii_digit()) {
+                if s.strip_prefix('-')
+                    .unwrap_or(&s)
+                    .chars()
+                    .all(|c| c.is_ascii_digit()) {
                     // Match Rust debug formatting
                     write!(f, "{s}.0")
                 } else {
@@ -775,7 +788,10 @@ fn render_const_scalar_inner<'db>(
                 // FIXME(#17451): Replace with builtins once they are stabilised.
                 let it = f128::from_bits(u128::from_le_bytes(b.try_into().unwrap()));
                 let s = it.to_string();
-                if s.strip_prefix('-').unwrap_or(&s).chars().all(|c| c.is_ascii_digit()) {
+                if s.strip_prefix('-')
+                    .unwrap_or(&s)
+                    .chars()
+                    .all(|c| c.is_ascii_digit()) {
                     // Match Rust debug formatting
                     write!(f, "{s}.0")
                 } else {
@@ -1838,9 +1854,7 @@ fn fn_traits(db: &dyn DefDatabase, trait_: TraitId) -> impl Iterator<Item = Trai
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum SizedByDefault {
     NotSized,
-    Sized {
-        anchor: Crate,
-    },
+    Sized { anchor: Crate },
 }
 
 impl SizedByDefault {
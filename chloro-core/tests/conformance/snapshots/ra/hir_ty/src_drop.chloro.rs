@@ -31,7 +31,10 @@ fn has_destructor(db: &dyn HirDatabase, adt: AdtId) -> bool {
         },
         None => db.trait_impls_in_crate(module.krate()),
     };
-    impls.for_trait_and_self_ty(drop_trait, TyFingerprint::Adt(adt)).next().is_some()
+    impls
+        .for_trait_and_self_ty(drop_trait, TyFingerprint::Adt(adt))
+        .next()
+        .is_some()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -60,7 +63,9 @@ fn has_drop_glue_impl<'db>(
     visited: &mut FxHashSet<Ty<'db>>,
 ) -> DropGlue {
     let mut ocx = ObligationCtxt::new(infcx);
-    let ty = ocx.structurally_normalize_ty(&ObligationCause::dummy(), env.env, ty).unwrap_or(ty);
+    let ty = ocx
+        .structurally_normalize_ty(&ObligationCause::dummy(), env.env, ty)
+        .unwrap_or(ty);
 
     if !visited.insert(ty) {
         // Recursive type.
@@ -98,8 +103,7 @@ fn has_drop_glue_impl<'db>(
                 }
                 // Unions cannot have fields with destructors.
                 AdtId::UnionId(_) => DropGlue::None,
-                AdtId::EnumId(id) => id
-                    .enum_variants(db)
+                AdtId::EnumId(id) => id.enum_variants(db)
                     .variants
                     .iter()
                     .map(|&(variant, _, _)| {
@@ -120,8 +124,7 @@ fn has_drop_glue_impl<'db>(
                     .unwrap_or(DropGlue::None),
             }
         }
-        TyKind::Tuple(tys) => tys
-            .iter()
+        TyKind::Tuple(tys) => tys.iter()
             .map(|ty| has_drop_glue_impl(infcx, ty, env.clone(), visited))
             .max()
             .unwrap_or(DropGlue::None),
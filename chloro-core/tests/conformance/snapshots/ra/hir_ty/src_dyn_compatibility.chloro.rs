@@ -144,8 +144,7 @@ pub fn generics_require_sized_self(db: &dyn HirDatabase, def: GenericDefId) -> b
         match pred.kind().skip_binder() {
             ClauseKind::Trait(trait_pred) => {
                 if sized == trait_pred.def_id().0
-                    && let rustc_type_ir::TyKind::Param(param_ty) =
-                        trait_pred.trait_ref.self_ty().kind()
+                    && let rustc_type_ir::TyKind::Param(param_ty) = trait_pred.trait_ref.self_ty().kind()
                     && param_ty.index == 0
                 {
                     true
@@ -178,7 +177,10 @@ fn bounds_reference_self(db: &dyn HirDatabase, trait_: TraitId) -> bool {
             _ => None,
         })
         .any(|bounds| {
-            bounds.skip_binder().iter().any(|pred| match pred.skip_binder() {
+            bounds
+                .skip_binder()
+                .iter()
+                .any(|pred| match pred.skip_binder() {
                 rustc_type_ir::ExistentialPredicate::Trait(it) => it.args.iter().any(|arg| {
                     contains_illegal_self_type_reference(db, trait_, &arg, AllowSelfProjection::Yes)
                 }),
@@ -238,9 +240,7 @@ fn contains_illegal_self_type_reference<'db, T: rustc_type_ir::TypeVisitable<DbI
             match ty.kind() {
                 rustc_type_ir::TyKind::Param(param) if param.index == 0 => ControlFlow::Break(()),
                 rustc_type_ir::TyKind::Param(_) => ControlFlow::Continue(()),
-                rustc_type_ir::TyKind::Alias(AliasTyKind::Projection, proj) => match self
-                    .allow_self_projection
-                {
+                rustc_type_ir::TyKind::Alias(AliasTyKind::Projection, proj) => match self.allow_self_projection {
                     AllowSelfProjection::Yes => {
                         let trait_ = proj.trait_def_id(DbInterner::new_with(self.db, None, None));
                         let trait_ = match trait_ {
@@ -254,7 +254,9 @@ fn contains_illegal_self_type_reference<'db, T: rustc_type_ir::TypeVisitable<DbI
                                     .collect(),
                             )
                         }
-                        if self.super_traits.as_ref().is_some_and(|s| s.contains(&trait_)) {
+                        if self.super_traits
+                            .as_ref()
+                            .is_some_and(|s| s.contains(&trait_)) {
                             ControlFlow::Continue(())
                         } else {
                             ty.super_visit_with(self)
@@ -290,14 +292,9 @@ where
     match item {
         AssocItemId::ConstId(it) => cb(DynCompatibilityViolation::AssocConst(it)),
         AssocItemId::FunctionId(it) => {
-            virtual_call_violations_for_method(
-                db,
-                trait_,
-                it,
-                &mut |mvc| {
+            virtual_call_violations_for_method(db, trait_, it, &mut |mvc| {
                 cb(DynCompatibilityViolation::Method(it, mvc))
-            },
-            )
+            })
         }
         AssocItemId::TypeAliasId(it) => {
             let def_map = CrateRootModuleId::from(trait_.krate(db)).def_map(db);
@@ -27,7 +27,13 @@ pub fn generics(db: &dyn DefDatabase, def: GenericDefId) -> Generics {
     let parent_generics = parent_generic_def(db, def).map(|def| Box::new(generics(db, def)));
     let (params, store) = db.generic_params_and_store(def);
     let has_trait_self_param = params.trait_self_param().is_some();
-    Generics { def, params, parent_generics, has_trait_self_param, store }
+    Generics {
+        def,
+        params,
+        parent_generics,
+        has_trait_self_param,
+        store,
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -64,12 +70,15 @@ impl Generics {
     }
 
     pub(crate) fn has_no_predicates(&self) -> bool {
-        self.params.has_no_predicates()
-            && self.parent_generics.as_ref().is_none_or(|g| g.params.has_no_predicates())
+        self.params.has_no_predicates() && self.parent_generics
+            .as_ref()
+            .is_none_or(|g| g.params.has_no_predicates())
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.params.is_empty() && self.parent_generics.as_ref().is_none_or(|g| g.params.is_empty())
+        self.params.is_empty() && self.parent_generics
+            .as_ref()
+            .is_none_or(|g| g.params.is_empty())
     }
 
     pub(crate) fn iter_id(&self) -> impl Iterator<Item = GenericParamId> + '_ {
@@ -102,8 +111,11 @@ impl Generics {
     pub(crate) fn iter_parents_with_store(
         &self,
     ) -> impl Iterator<Item = ((GenericParamId, GenericParamDataRef<'_>), &ExpressionStore)> + '_ {
-        self.iter_parent()
-            .zip(self.parent_generics().into_iter().flat_map(|it| std::iter::repeat(&*it.store)))
+        self.iter_parent().zip(
+            self.parent_generics()
+                .into_iter()
+                .flat_map(|it| std::iter::repeat(&*it.store)),
+        )
     }
 
     /// Iterate over the params without parent params.
@@ -153,7 +165,9 @@ impl Generics {
         let mut type_params = 0;
         let mut impl_trait_params = 0;
         let mut const_params = 0;
-        self.params.iter_type_or_consts().for_each(|(_, data)| match data {
+        self.params
+            .iter_type_or_consts()
+            .for_each(|(_, data)| match data {
             TypeOrConstParamData::TypeParamData(p) => match p.provenance {
                 TypeParamProvenance::TypeParamList => type_params += 1,
                 TypeParamProvenance::TraitSelf => self_param |= true,
@@ -202,7 +216,8 @@ impl Generics {
             Some(self.parent_generics().map_or(0, |g| g.len()) + self.params.len_lifetimes() + idx)
         } else {
             debug_assert_eq!(self.parent_generics().map(|it| it.def), Some(param.parent));
-            self.parent_generics().and_then(|g| g.find_type_or_const_param(param))
+            self.parent_generics()
+                .and_then(|g| g.find_type_or_const_param(param))
         }
     }
 
@@ -215,13 +230,12 @@ impl Generics {
             let idx = lifetime.local_id.into_raw().into_u32() as usize;
             debug_assert!(idx <= self.params.len_lifetimes());
             Some(
-                self.parent_generics().map_or(0, |g| g.len())
-                    + self.params.trait_self_param().is_some() as usize
-                    + idx,
+                self.parent_generics().map_or(0, |g| g.len()) + self.params.trait_self_param().is_some() as usize + idx,
             )
         } else {
             debug_assert_eq!(self.parent_generics().map(|it| it.def), Some(lifetime.parent));
-            self.parent_generics().and_then(|g| g.find_lifetime(lifetime))
+            self.parent_generics()
+                .and_then(|g| g.find_lifetime(lifetime))
         }
     }
 
@@ -234,7 +248,9 @@ pub(crate) fn trait_self_param_idx(db: &dyn DefDatabase, def: GenericDefId) -> O
     match def {
         GenericDefId::TraitId(_) => {
             let params = db.generic_params(def);
-            params.trait_self_param().map(|idx| idx.into_raw().into_u32() as usize)
+            params
+                .trait_self_param()
+                .map(|idx| idx.into_raw().into_u32() as usize)
         }
         GenericDefId::ImplId(_) => None,
         _ => {
@@ -289,7 +305,10 @@ fn from_lt_id<'a>(
 ) -> impl Fn((LocalLifetimeParamId, &'a LifetimeParamData)) -> (GenericParamId, GenericParamDataRef<'a>) {
     move |(local_id, p): (_, _)| {
         (
-            GenericParamId::LifetimeParamId(LifetimeParamId { parent: it.def, local_id }),
+            GenericParamId::LifetimeParamId(LifetimeParamId {
+            parent: it.def,
+            local_id,
+        }),
             GenericParamDataRef::LifetimeParamData(p),
         )
     }
@@ -198,14 +198,8 @@ pub enum InferenceDiagnostic<'db> {
         private: Option<LocalFieldId>,
         variant: VariantId,
     },
-    PrivateField {
-        expr: ExprId,
-        field: FieldId,
-    },
-    PrivateAssocItem {
-        id: ExprOrPatId,
-        item: AssocItemId,
-    },
+    PrivateField { expr: ExprId, field: FieldId },
+    PrivateAssocItem { id: ExprOrPatId, item: AssocItemId },
     UnresolvedField {
         expr: ExprId,
         receiver: Ty<'db>,
@@ -220,12 +214,8 @@ pub enum InferenceDiagnostic<'db> {
         field_with_same_name: Option<Ty<'db>>,
         assoc_func_with_same_name: Option<FunctionId>,
     },
-    UnresolvedAssocItem {
-        id: ExprOrPatId,
-    },
-    UnresolvedIdent {
-        id: ExprOrPatId,
-    },
+    UnresolvedAssocItem { id: ExprOrPatId },
+    UnresolvedIdent { id: ExprOrPatId },
     // FIXME: This should be emitted in body lowering
     BreakOutsideOfLoop {
         expr: ExprId,
@@ -242,18 +232,9 @@ pub enum InferenceDiagnostic<'db> {
         expected: usize,
         found: usize,
     },
-    ExpectedFunction {
-        call_expr: ExprId,
-        found: Ty<'db>,
-    },
-    TypedHole {
-        expr: ExprId,
-        expected: Ty<'db>,
-    },
-    CastToUnsized {
-        expr: ExprId,
-        cast_ty: Ty<'db>,
-    },
+    ExpectedFunction { call_expr: ExprId, found: Ty<'db> },
+    TypedHole { expr: ExprId, expected: Ty<'db> },
+    CastToUnsized { expr: ExprId, cast_ty: Ty<'db> },
     InvalidCast {
         expr: ExprId,
         error: CastError,
@@ -415,8 +396,8 @@ pub enum PointerCast {
     /// Go from a mut raw pointer to a const raw pointer.
     MutToConstPointer,
 
-    /// Go from `*const [T; N]` to `*const T`
     #[allow(dead_code)]
+    /// Go from `*const [T; N]` to `*const T`
     ArrayToPointer,
 
     /// Unsize a pointer/reference value, e.g., `&[T; n]` to
@@ -571,11 +552,15 @@ impl<'db> InferenceResult<'db> {
     }
 
     pub fn type_mismatches(&self) -> impl Iterator<Item = (ExprOrPatId, &TypeMismatch<'db>)> {
-        self.type_mismatches.iter().map(|(expr_or_pat, mismatch)| (*expr_or_pat, mismatch))
+        self.type_mismatches
+            .iter()
+            .map(|(expr_or_pat, mismatch)| (*expr_or_pat, mismatch))
     }
 
     pub fn expr_type_mismatches(&self) -> impl Iterator<Item = (ExprId, &TypeMismatch<'db>)> {
-        self.type_mismatches.iter().filter_map(|(expr_or_pat, mismatch)| match *expr_or_pat {
+        self.type_mismatches
+            .iter()
+            .filter_map(|(expr_or_pat, mismatch)| match *expr_or_pat {
             ExprOrPatId::ExprId(expr) => Some((expr, mismatch)),
             _ => None,
         })
@@ -614,7 +599,9 @@ impl<'db> InferenceResult<'db> {
     }
 
     pub fn type_of_pat_with_adjust(&self, id: PatId) -> Option<Ty<'db>> {
-        match self.pat_adjustments.get(&id).and_then(|adjustments| adjustments.last()) {
+        match self.pat_adjustments
+            .get(&id)
+            .and_then(|adjustments| adjustments.last()) {
             Some(adjusted) => Some(*adjusted),
             None => self.type_of_pat.get(id).copied(),
         }
@@ -766,11 +753,9 @@ impl<'db> InternedStandardTypes<'db> {
             f128: Ty::new_float(interner, rustc_type_ir::FloatTy::F128),
             static_str_ref: Ty::new_ref(interner, re_static, str, Mutability::Not),
             error: Ty::new_error(interner, ErrorGuaranteed),
-
             re_static,
             re_error: Region::error(interner),
             re_erased: Region::new_erased(interner),
-
             empty_args: GenericArgs::new_from_iter(interner, []),
             empty_tys: Tys::new_from_iter(interner, []),
         }
@@ -1139,7 +1124,9 @@ impl<'body, 'db> InferenceContext<'body, 'db> {
 
             param_tys.push(va_list_ty);
         }
-        let mut param_tys = param_tys.into_iter().chain(iter::repeat(self.table.next_ty_var()));
+        let mut param_tys = param_tys
+            .into_iter()
+            .chain(iter::repeat(self.table.next_ty_var()));
         if let Some(self_param) = self.body.self_param
             && let Some(ty) = param_tys.next()
         {
@@ -1222,7 +1209,11 @@ impl<'body, 'db> InferenceContext<'body, 'db> {
         if adjustments.is_empty() {
             return;
         }
-        self.result.pat_adjustments.entry(pat).or_default().extend(adjustments);
+        self.result
+            .pat_adjustments
+            .entry(pat)
+            .or_default()
+            .extend(adjustments);
     }
 
     fn write_method_resolution(
@@ -1683,7 +1674,9 @@ impl<'body, 'db> InferenceContext<'body, 'db> {
         unresolved: Option<usize>,
         path: &ModPath,
     ) -> (Ty<'db>, Option<VariantId>) {
-        let remaining = unresolved.map(|it| path.segments()[it..].len()).filter(|it| it > &0);
+        let remaining = unresolved
+            .map(|it| path.segments()[it..].len())
+            .filter(|it| it > &0);
         let ty = self.table.try_structurally_resolve_type(ty);
         match remaining {
             None => {
@@ -1722,7 +1715,9 @@ impl<'body, 'db> InferenceContext<'body, 'db> {
     }
 
     fn resolve_output_on(&self, trait_: TraitId) -> Option<TypeAliasId> {
-        trait_.trait_items(self.db).associated_type_by_name(&Name::new_symbol_root(sym::Output))
+        trait_
+            .trait_items(self.db)
+            .associated_type_by_name(&Name::new_symbol_root(sym::Output))
     }
 
     fn resolve_lang_trait(&self, lang: LangItem) -> Option<TraitId> {
@@ -1765,7 +1760,9 @@ impl<'body, 'db> InferenceContext<'body, 'db> {
     }
 
     fn resolve_range_inclusive(&self) -> Option<AdtId> {
-        let struct_ = self.resolve_lang_item(LangItem::RangeInclusiveStruct)?.as_struct()?;
+        let struct_ = self
+            .resolve_lang_item(LangItem::RangeInclusiveStruct)?
+            .as_struct()?;
         Some(struct_.into())
     }
 
@@ -1780,7 +1777,9 @@ impl<'body, 'db> InferenceContext<'body, 'db> {
     }
 
     fn resolve_range_to_inclusive(&self) -> Option<AdtId> {
-        let struct_ = self.resolve_lang_item(LangItem::RangeToInclusive)?.as_struct()?;
+        let struct_ = self
+            .resolve_lang_item(LangItem::RangeToInclusive)?
+            .as_struct()?;
         Some(struct_.into())
     }
 
@@ -1892,7 +1891,8 @@ impl<'db> Expectation<'db> {
     }
 
     fn coercion_target_type(&self, table: &mut unify::InferenceTable<'db>) -> Ty<'db> {
-        self.only_has_type(table).unwrap_or_else(|| table.next_ty_var())
+        self.only_has_type(table)
+            .unwrap_or_else(|| table.next_ty_var())
     }
 
     /// Comment copied from rustc:
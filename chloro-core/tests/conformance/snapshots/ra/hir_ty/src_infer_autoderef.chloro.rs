@@ -30,7 +30,11 @@ impl<'db> Autoderef<'_, 'db> {
             return InferOk { obligations: PredicateObligations::new(), value: vec![] };
         }
 
-        let targets = steps.iter().skip(1).map(|&(ty, _)| ty).chain(iter::once(self.final_ty()));
+        let targets = steps
+            .iter()
+            .skip(1)
+            .map(|&(ty, _)| ty)
+            .chain(iter::once(self.final_ty()));
         let steps: Vec<_> = steps
             .iter()
             .map(|&(_source, kind)| {
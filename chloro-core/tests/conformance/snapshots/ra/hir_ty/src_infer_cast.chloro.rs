@@ -84,7 +84,12 @@ impl CastError {
         expr_ty: Ty<'db>,
         cast_ty: Ty<'db>,
     ) -> InferenceDiagnostic<'db> {
-        InferenceDiagnostic::InvalidCast { expr, error: self, expr_ty, cast_ty }
+        InferenceDiagnostic::InvalidCast {
+            expr,
+            error: self,
+            expr_ty,
+            cast_ty,
+        }
     }
 }
 
@@ -103,7 +108,12 @@ impl<'db> CastCheck<'db> {
         expr_ty: Ty<'db>,
         cast_ty: Ty<'db>,
     ) -> Self {
-        Self { expr, source_expr, expr_ty, cast_ty }
+        Self {
+            expr,
+            source_expr,
+            expr_ty,
+            cast_ty,
+        }
     }
 
     pub(super) fn check(
@@ -148,7 +158,8 @@ impl<'db> CastCheck<'db> {
             return Ok(());
         }
 
-        self.do_check(ctx).map_err(|e| e.into_diagnostic(self.expr, self.expr_ty, self.cast_ty))
+        self.do_check(ctx)
+            .map_err(|e| e.into_diagnostic(self.expr, self.expr_ty, self.cast_ty))
     }
 
     fn do_check(&self, ctx: &mut InferenceContext<'_, 'db>) -> Result<(), CastError> {
@@ -296,8 +307,7 @@ impl<'db> CastCheck<'db> {
                         }
                         let src_principal = ctx.db.trait_signature(src_principal.0);
                         let dst_principal = ctx.db.trait_signature(dst_principal.0);
-                        if src_principal.flags.contains(TraitFlags::AUTO)
-                            && dst_principal.flags.contains(TraitFlags::AUTO) {
+                        if src_principal.flags.contains(TraitFlags::AUTO) && dst_principal.flags.contains(TraitFlags::AUTO) {
                             Ok(())
                         } else {
                             Err(CastError::DifferingKinds)
@@ -250,14 +250,13 @@ impl<'db> InferenceContext<'_, 'db> {
         closure_kind: ClosureKind,
     ) -> (Option<PolyFnSig<'db>>, Option<rustc_type_ir::ClosureKind>) {
         match expected_ty.kind() {
-            TyKind::Alias(rustc_type_ir::Opaque, AliasTy { def_id, args, .. }) => self
-                .deduce_closure_signature_from_predicates(
-                    expected_ty,
-                    closure_kind,
-                    explicit_item_bounds(self.interner(), def_id)
-                        .iter_instantiated(self.interner(), args)
-                        .map(|clause| clause.as_predicate()),
-                ),
+            TyKind::Alias(rustc_type_ir::Opaque, AliasTy { def_id, args, .. }) => self.deduce_closure_signature_from_predicates(
+                expected_ty,
+                closure_kind,
+                explicit_item_bounds(self.interner(), def_id)
+                    .iter_instantiated(self.interner(), args)
+                    .map(|clause| clause.as_predicate()),
+            ),
             TyKind::Dynamic(object_type, ..) => {
                 let sig = object_type.projection_bounds().into_iter().find_map(|pb| {
                     let pb = pb.with_self_ty(self.interner(), Ty::new_unit(self.interner()));
@@ -268,12 +267,14 @@ impl<'db> InferenceContext<'_, 'db> {
                     .and_then(|did| self.fn_trait_kind_from_def_id(did.0));
                 (sig, kind)
             }
-            TyKind::Infer(rustc_type_ir::TyVar(vid)) => self
-                .deduce_closure_signature_from_predicates(
-                    Ty::new_var(self.interner(), self.table.infer_ctxt.root_var(vid)),
-                    closure_kind,
-                    self.table.obligations_for_self_ty(vid).into_iter().map(|obl| obl.predicate),
-                ),
+            TyKind::Infer(rustc_type_ir::TyVar(vid)) => self.deduce_closure_signature_from_predicates(
+                Ty::new_var(self.interner(), self.table.infer_ctxt.root_var(vid)),
+                closure_kind,
+                self.table
+                    .obligations_for_self_ty(vid)
+                    .into_iter()
+                    .map(|obl| obl.predicate),
+            ),
             TyKind::FnPtr(sig_tys, hdr) => match closure_kind {
                 ClosureKind::Closure => {
                     let expected_sig = sig_tys.with(hdr);
@@ -733,10 +734,9 @@ impl<'db> InferenceContext<'_, 'db> {
         // [c2]: https://github.com/rust-lang/rust/pull/45072#issuecomment-341096796
         self.table.commit_if_ok(|table| {
             let mut all_obligations = PredicateObligations::new();
-            let supplied_sig = table.infer_ctxt.instantiate_binder_with_fresh_vars(
-                BoundRegionConversionTime::FnCall,
-                supplied_sig,
-            );
+            let supplied_sig = table
+                .infer_ctxt
+                .instantiate_binder_with_fresh_vars(BoundRegionConversionTime::FnCall, supplied_sig);
 
             // The liberated version of this signature should be a subtype
             // of the liberated form of the expectation.
@@ -773,7 +773,10 @@ impl<'db> InferenceContext<'_, 'db> {
                 FnAbi::RustCall,
             );
 
-            Ok(InferOk { value: expected_sigs, obligations: all_obligations })
+            Ok(InferOk {
+                value: expected_sigs,
+                obligations: all_obligations,
+            })
         })
     }
 
@@ -804,13 +807,15 @@ impl<'db> InferenceContext<'_, 'db> {
             None => self.table.next_ty_var(),
         });
 
-        Binder::dummy(interner.mk_fn_sig(
-            supplied_arguments,
-            supplied_return,
-            false,
-            Safety::Safe,
-            FnAbi::RustCall,
-        ))
+        Binder::dummy(
+            interner.mk_fn_sig(
+                supplied_arguments,
+                supplied_return,
+                false,
+                Safety::Safe,
+                FnAbi::RustCall,
+            ),
+        )
     }
 
     /// Converts the types that the user supplied, in case that doing
@@ -851,6 +856,9 @@ impl<'db> InferenceContext<'_, 'db> {
     fn closure_sigs(&self, bound_sig: PolyFnSig<'db>) -> ClosureSignatures<'db> {
         let liberated_sig = bound_sig.skip_binder();
         // FIXME: When we lower HRTB we'll need to actually liberate regions here.
-        ClosureSignatures { bound_sig, liberated_sig }
+        ClosureSignatures {
+            bound_sig,
+            liberated_sig,
+        }
     }
 }
@@ -100,12 +100,16 @@ impl<'db> CapturedItem<'db> {
 
     /// Returns whether this place has any field (aka. non-deref) projections.
     pub fn has_field_projections(&self) -> bool {
-        self.place.projections.iter().any(|it| !matches!(it, ProjectionElem::Deref))
+        self.place
+            .projections
+            .iter()
+            .any(|it| !matches!(it, ProjectionElem::Deref))
     }
 
     pub fn ty(&self, db: &'db dyn HirDatabase, subst: GenericArgs<'db>) -> Ty<'db> {
         let interner = DbInterner::new_with(db, None, None);
-        self.ty.instantiate(interner, subst.split_closure_args_untupled().parent_args)
+        self.ty
+            .instantiate(interner, subst.split_closure_args_untupled().parent_args)
     }
 
     pub fn kind(&self) -> CaptureKind {
@@ -113,7 +117,10 @@ impl<'db> CapturedItem<'db> {
     }
 
     pub fn spans(&self) -> SmallVec<[MirSpan; 3]> {
-        self.span_stacks.iter().map(|stack| *stack.last().expect("empty span stack")).collect()
+        self.span_stacks
+            .iter()
+            .map(|stack| *stack.last().expect("empty span stack"))
+            .collect()
     }
 
     /// Converts the place to a name that can be inserted into source code.
@@ -317,19 +324,24 @@ impl<'db> InferenceContext<'_, 'db> {
             return None;
         }
         let hygiene = self.body.expr_or_pat_path_hygiene(id);
-        self.resolver.resolve_path_in_value_ns_fully(self.db, path, hygiene).and_then(|result| {
-            match result {
-                ValueNs::LocalBinding(binding) => {
-                    let mir_span = match id {
+        self.resolver
+            .resolve_path_in_value_ns_fully(self.db, path, hygiene)
+            .and_then(|result| {
+                match result {
+                    ValueNs::LocalBinding(binding) => {
+                        let mir_span = match id {
                         ExprOrPatId::ExprId(id) => MirSpan::ExprId(id),
                         ExprOrPatId::PatId(id) => MirSpan::PatId(id),
                     };
-                    self.current_capture_span_stack.push(mir_span);
-                    Some(HirPlace { local: binding, projections: Vec::new() })
+                        self.current_capture_span_stack.push(mir_span);
+                        Some(HirPlace {
+                            local: binding,
+                            projections: Vec::new(),
+                        })
+                    }
+                    _ => None,
                 }
-                _ => None,
-            }
-        })
+            })
     }
 
     /// Changes `current_capture_span_stack` to contain the stack of spans for this expr.
@@ -653,7 +665,8 @@ impl<'db> InferenceContext<'_, 'db> {
             Expr::Ref { expr, rawness: _, mutability } => {
                 // We need to do this before we push the span so the order will be correct.
                 let place = self.place_of_expr(*expr);
-                self.current_capture_span_stack.push(MirSpan::ExprId(tgt_expr));
+                self.current_capture_span_stack
+                    .push(MirSpan::ExprId(tgt_expr));
                 match mutability {
                     hir_def::type_ref::Mutability::Shared => self.ref_expr(*expr, place),
                     hir_def::type_ref::Mutability::Mut => self.mutate_expr(*expr, place),
@@ -803,7 +816,8 @@ impl<'db> InferenceContext<'_, 'db> {
         if self.result.pat_adjustments.get(&p).is_some_and(|it| !it.is_empty()) {
             for_mut = BorrowKind::Mut { kind: MutBorrowKind::ClosureCapture };
         }
-        self.body.walk_pats_shallow(p, |p| self.walk_pat_inner(p, update_result, for_mut));
+        self.body
+            .walk_pats_shallow(p, |p| self.walk_pat_inner(p, update_result, for_mut));
     }
 
     fn expr_ty(&self, expr: ExprId) -> Ty<'db> {
@@ -895,7 +909,8 @@ impl<'db> InferenceContext<'_, 'db> {
     }
 
     fn minimize_captures(&mut self) {
-        self.current_captures.sort_unstable_by_key(|it| it.place.projections.len());
+        self.current_captures
+            .sort_unstable_by_key(|it| it.place.projections.len());
         let mut hash_map = FxHashMap::<HirPlace<'db>, usize>::default();
         let result = mem::take(&mut self.current_captures);
         for mut item in result {
@@ -916,7 +931,9 @@ impl<'db> InferenceContext<'_, 'db> {
                 Some(p) => {
                     let prev_projections_len = self.current_captures[p].place.projections.len();
                     self.truncate_capture_spans(&mut item, prev_projections_len);
-                    self.current_captures[p].span_stacks.extend(item.span_stacks);
+                    self.current_captures[p]
+                        .span_stacks
+                        .extend(item.span_stacks);
                     let len = self.current_captures[p].place.projections.len();
                     let kind_after_truncate =
                         item.place.capture_kind_of_truncated_place(item.kind, len);
@@ -934,7 +951,9 @@ impl<'db> InferenceContext<'_, 'db> {
     fn consume_with_pat(&mut self, mut place: HirPlace<'db>, tgt_pat: PatId) {
         let adjustments_count =
             self.result.pat_adjustments.get(&tgt_pat).map(|it| it.len()).unwrap_or_default();
-        place.projections.extend((0..adjustments_count).map(|_| ProjectionElem::Deref));
+        place
+            .projections
+            .extend((0..adjustments_count).map(|_| ProjectionElem::Deref));
         self.current_capture_span_stack
             .extend((0..adjustments_count).map(|_| MirSpan::PatId(tgt_pat)));
         'reset_span_stack: {
@@ -1125,8 +1144,13 @@ impl<'db> InferenceContext<'_, 'db> {
         self.minimize_captures();
         self.strip_captures_ref_span();
         let result = mem::take(&mut self.current_captures);
-        let captures = result.into_iter().map(|it| it.with_ty(self)).collect::<Vec<_>>();
-        self.result.closure_info.insert(closure, (captures, closure_kind));
+        let captures = result
+            .into_iter()
+            .map(|it| it.with_ty(self))
+            .collect::<Vec<_>>();
+        self.result
+            .closure_info
+            .insert(closure, (captures, closure_kind));
         closure_kind
     }
 
@@ -1163,7 +1187,9 @@ impl<'db> InferenceContext<'_, 'db> {
                         &params,
                         expr,
                     );
-                    self.result.expr_adjustments.insert(callee, adjustments.into_boxed_slice());
+                    self.result
+                        .expr_adjustments
+                        .insert(callee, adjustments.into_boxed_slice());
                 }
             }
         }
@@ -110,7 +110,10 @@ fn success<'db>(
     target: Ty<'db>,
     obligations: PredicateObligations<'db>,
 ) -> CoerceResult<'db> {
-    Ok(InferOk { value: (adj, target), obligations })
+    Ok(InferOk {
+        value: (adj, target),
+        obligations,
+    })
 }
 
 impl<'a, 'b, 'db> Coerce<'a, 'b, 'db> {
@@ -164,7 +167,10 @@ impl<'a, 'b, 'db> Coerce<'a, 'b, 'db> {
                     let mut ocx = ObligationCtxt::new(this.infer_ctxt());
                     ocx.register_obligations(obligations);
                     if ocx.try_evaluate_obligations().is_empty() {
-                        Ok(InferOk { value, obligations: ocx.into_pending_obligations() })
+                        Ok(InferOk {
+                            value,
+                            obligations: ocx.into_pending_obligations(),
+                        })
                     } else {
                         Err(TypeError::Mismatch)
                     }
@@ -188,16 +194,20 @@ impl<'a, 'b, 'db> Coerce<'a, 'b, 'db> {
         adjustments: impl IntoIterator<Item = Adjustment<'db>>,
         final_adjustment: Adjust<'db>,
     ) -> CoerceResult<'db> {
-        self.unify_raw(a, b).and_then(|InferOk { value: ty, obligations }| {
-            success(
-                adjustments
-                    .into_iter()
-                    .chain(std::iter::once(Adjustment { target: ty, kind: final_adjustment }))
-                    .collect(),
-                ty,
-                obligations,
-            )
-        })
+        self.unify_raw(a, b)
+            .and_then(|InferOk { value: ty, obligations }| {
+                success(
+                    adjustments
+                        .into_iter()
+                        .chain(std::iter::once(Adjustment {
+                            target: ty,
+                            kind: final_adjustment,
+                        }))
+                        .collect(),
+                    ty,
+                    obligations,
+                )
+            })
     }
 
     #[instrument(skip(self))]
@@ -904,7 +914,12 @@ impl<'a, 'b, 'db> Coerce<'a, 'b, 'db> {
             //
             // All we care here is if any variable is being captured and not the exact paths,
             // so we check `upvars_mentioned` for root variables being captured.
-            TyKind::FnPtr(_, hdr) => {
+            TyKind::FnPtr(_, hdr) =>
+            // if self
+            //     .db
+            //     .upvars_mentioned(closure_def_id_a.expect_local())
+            //     .is_none_or(|u| u.is_empty()) =>
+            {
                 // We coerce the closure, which has fn type
                 //     `extern "rust-call" fn((arg0,arg1,...)) -> _`
                 // to
@@ -1206,15 +1221,16 @@ impl<'db> InferenceContext<'_, 'db> {
                 if let Some(e) = first_error {
                     Err(e)
                 } else {
-                    Err(self
-                        .table
-                        .commit_if_ok(|table| {
-                            table
-                                .infer_ctxt
-                                .at(&ObligationCause::new(), table.trait_env.env)
-                                .lub(prev_ty, new_ty)
-                        })
-                        .unwrap_err())
+                    Err(
+                        self.table
+                            .commit_if_ok(|table| {
+                                table
+                                    .infer_ctxt
+                                    .at(&ObligationCause::new(), table.trait_env.env)
+                                    .lub(prev_ty, new_ty)
+                            })
+                            .unwrap_err(),
+                    )
                 }
             }
             Ok(ok) => {
@@ -1312,7 +1328,12 @@ impl<'db, 'exprs> CoerceMany<'db, 'exprs> {
     }
 
     fn make(expected_ty: Ty<'db>, expressions: Expressions<'exprs>) -> Self {
-        CoerceMany { expected_ty, final_ty: None, expressions, pushed: 0 }
+        CoerceMany {
+            expected_ty,
+            final_ty: None,
+            expressions,
+            pushed: 0,
+        }
     }
 
     /// Returns the "expected type" with which this coercion was
@@ -19,6 +19,10 @@ use crate::{
     lower::{LifetimeElisionKind, TyLoweringContext},
 };
 
+// Unfortunately, this struct needs to use interior mutability (but we encapsulate it)
+// because when lowering types and paths we hold a `TyLoweringContext` that holds a reference
+// to our resolver and so we cannot have mutable reference, but we really want to have
+// ability to dispatch diagnostics during this work otherwise the code becomes a complete mess.
 #[derive(Debug, Default, Clone)]
 pub(super) struct Diagnostics<'db>(RefCell<Vec<InferenceDiagnostic<'db>>>);
 
@@ -33,7 +37,9 @@ impl<'db> Diagnostics<'db> {
         diagnostics: Vec<TyLoweringDiagnostic>,
     ) {
         self.0.borrow_mut().extend(
-            diagnostics.into_iter().map(|diag| InferenceDiagnostic::TyDiagnostic { source, diag }),
+            diagnostics
+                .into_iter()
+                .map(|diag| InferenceDiagnostic::TyDiagnostic { source, diag }),
         );
     }
 
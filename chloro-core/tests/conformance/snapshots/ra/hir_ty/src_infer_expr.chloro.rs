@@ -111,7 +111,10 @@ impl<'db> InferenceContext<'_, 'db> {
                 Err(_) => {
                     self.result
                         .type_mismatches
-                        .insert(expr.into(), TypeMismatch { expected: target, actual: ty });
+                        .insert(expr.into(), TypeMismatch {
+                            expected: target,
+                            actual: ty,
+                        });
                     target
                 }
             }
@@ -178,7 +181,9 @@ impl<'db> InferenceContext<'_, 'db> {
             // It is however fine to be restrictive here; only returning `true`
             // can lead to unsoundness.
             Pat::Or(subpats) => {
-                subpats.iter().all(|pat| self.pat_guaranteed_to_constitute_read_for_never(*pat))
+                subpats
+                    .iter()
+                    .all(|pat| self.pat_guaranteed_to_constitute_read_for_never(*pat))
             }
 
             // All of these constitute a read, or match on something that isn't `!`,
@@ -206,8 +211,7 @@ impl<'db> InferenceContext<'_, 'db> {
             // Lang item paths cannot currently be local variables or statics.
             Expr::Path(Path::LangItem(_, _)) => false,
             Expr::Path(Path::Normal(path)) => path.type_anchor.is_none(),
-            Expr::Path(path) => self
-                .resolver
+            Expr::Path(path) => self.resolver
                 .resolve_path_in_value_ns_fully(self.db, path, self.body.expr_path_hygiene(expr))
                 .is_none_or(|res| matches!(res, ValueNs::LocalBinding(_) | ValueNs::StaticId(_))),
             Expr::Underscore => true,
@@ -1072,7 +1076,9 @@ impl<'db> InferenceContext<'_, 'db> {
     }
 
     fn infer_expr_path(&mut self, path: &Path, id: ExprOrPatId, scope_id: ExprId) -> Ty<'db> {
-        let g = self.resolver.update_to_inner_scope(self.db, self.owner, scope_id);
+        let g = self
+            .resolver
+            .update_to_inner_scope(self.db, self.owner, scope_id);
         let ty = match self.infer_path(path, id) {
             Some(ty) => ty,
             None => {
@@ -1137,20 +1143,16 @@ impl<'db> InferenceContext<'_, 'db> {
         Ty::new_coroutine(
             self.interner(),
             coroutine_id,
-            CoroutineArgs::new(
-                self.interner(),
-                CoroutineArgsParts {
-                    parent_args,
-                    kind_ty: self.types.unit,
-                    // rustc uses a special lang item type for the resume ty. I don't believe this can cause us problems.
-                    resume_ty: self.types.unit,
-                    yield_ty: self.types.unit,
-                    return_ty: inner_ty,
-                    // FIXME: Infer upvars.
-                    tupled_upvars_ty: self.types.unit,
-                },
-            )
-            .args,
+            CoroutineArgs::new(self.interner(), CoroutineArgsParts {
+                parent_args,
+                kind_ty: self.types.unit,
+                // rustc uses a special lang item type for the resume ty. I don't believe this can cause us problems.
+                resume_ty: self.types.unit,
+                yield_ty: self.types.unit,
+                return_ty: inner_ty,
+                // FIXME: Infer upvars.
+                tupled_upvars_ty: self.types.unit,
+            }).args,
         )
     }
 
@@ -1333,16 +1335,12 @@ impl<'db> InferenceContext<'_, 'db> {
                 .unwrap_or_else(Expectation::none);
 
             let inner_ty = self.infer_expr_inner(inner_expr, &inner_exp, ExprIsRead::Yes);
-            Ty::new_adt(
-                self.interner(),
-                box_id,
-                GenericArgs::fill_with_defaults(
+            Ty::new_adt(self.interner(), box_id, GenericArgs::fill_with_defaults(
                 self.interner(),
                 box_id.into(),
                 [inner_ty.into()],
                 |_, id, _| self.table.next_var_for_param(id),
-            ),
-            )
+            ))
         } else {
             self.err_ty()
         }
@@ -1396,7 +1394,11 @@ impl<'db> InferenceContext<'_, 'db> {
 
         self.write_method_resolution(tgt_expr, func, args);
 
-        let method_ty = self.db.value_ty(func.into()).unwrap().instantiate(self.interner(), args);
+        let method_ty = self
+            .db
+            .value_ty(func.into())
+            .unwrap()
+            .instantiate(self.interner(), args);
         self.register_obligations_for_call(method_ty);
 
         self.infer_expr_coerce(rhs, &Expectation::has_type(rhs_ty), ExprIsRead::Yes);
@@ -1455,7 +1457,9 @@ impl<'db> InferenceContext<'_, 'db> {
         expected: &Expectation<'db>,
     ) -> Ty<'db> {
         let coerce_ty = expected.coercion_target_type(&mut self.table);
-        let g = self.resolver.update_to_inner_scope(self.db, self.owner, expr);
+        let g = self
+            .resolver
+            .update_to_inner_scope(self.db, self.owner, expr);
         let prev_state = block_id.map(|block_id| {
             let prev_env = self.table.trait_env.clone();
             TraitEnvironment::with_block(&mut self.table.trait_env, block_id);
@@ -2218,13 +2222,14 @@ impl<'db> InferenceContext<'_, 'db> {
                 expected_count: u32,
                 kind: IncorrectGenericsLenKind,
             ) {
-                self.ctx.push_diagnostic(InferenceDiagnostic::MethodCallIncorrectGenericsLen {
-                    expr: self.expr,
-                    provided_count,
-                    expected_count,
-                    kind,
-                    def,
-                });
+                self.ctx
+                    .push_diagnostic(InferenceDiagnostic::MethodCallIncorrectGenericsLen {
+                        expr: self.expr,
+                        provided_count,
+                        expected_count,
+                        kind,
+                        def,
+                    });
             }
 
             fn report_arg_mismatch(
@@ -2233,12 +2238,13 @@ impl<'db> InferenceContext<'_, 'db> {
                 arg_idx: u32,
                 has_self_arg: bool,
             ) {
-                self.ctx.push_diagnostic(InferenceDiagnostic::MethodCallIncorrectGenericsOrder {
-                    expr: self.expr,
-                    param_id,
-                    arg_idx,
-                    has_self_arg,
-                });
+                self.ctx
+                    .push_diagnostic(InferenceDiagnostic::MethodCallIncorrectGenericsOrder {
+                        expr: self.expr,
+                        param_id,
+                        arg_idx,
+                        has_self_arg,
+                    });
             }
 
             fn provided_kind(
@@ -2535,7 +2541,12 @@ impl<'db> InferenceContext<'_, 'db> {
         cb: impl FnOnce(&mut Self) -> T,
     ) -> (Option<Ty<'db>>, T) {
         self.breakables.push({
-            BreakableContext { kind, may_break: false, coerce: ty.map(CoerceMany::new), label }
+            BreakableContext {
+                kind,
+                may_break: false,
+                coerce: ty.map(CoerceMany::new),
+                label,
+            }
         });
         let res = cb(self);
         let ctx = self.breakables.pop().expect("breakable stack broken");
@@ -221,7 +221,8 @@ impl<'db> InferenceContext<'_, 'db> {
                 self.infer_mut_expr(*rhs, Mutability::Not);
             }
             &Expr::Assignment { target, value } => {
-                self.body.walk_pats(target, &mut |pat| match self.body[pat] {
+                self.body
+                    .walk_pats(target, &mut |pat| match self.body[pat] {
                     Pat::Expr(expr) => self.infer_mut_expr(expr, Mutability::Mut),
                     Pat::ConstBlock(block) => self.infer_mut_expr(block, Mutability::Not),
                     _ => {}
@@ -412,8 +412,7 @@ impl<'db> InferenceContext<'_, 'db> {
     }
 
     fn pat_ty_after_adjustment(&self, pat: PatId) -> Ty<'db> {
-        *self
-            .result
+        *self.result
             .pat_adjustments
             .get(&pat)
             .and_then(|it| it.first())
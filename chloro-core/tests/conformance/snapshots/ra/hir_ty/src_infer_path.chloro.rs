@@ -34,7 +34,10 @@ impl<'db> InferenceContext<'_, 'db> {
 
         self.add_required_obligations_for_value_path(generic_def, args);
 
-        let ty = self.db.value_ty(value_def)?.instantiate(self.interner(), args);
+        let ty = self
+            .db
+            .value_ty(value_def)?
+            .instantiate(self.interner(), args);
         let ty = self.process_remote_user_written_ty(ty);
         Some(ty)
     }
@@ -237,18 +240,17 @@ impl<'db> InferenceContext<'_, 'db> {
         };
 
         if let ItemContainerId::TraitId(trait_) = container {
-            let parent_len = generics(self.db, def).parent_generics().map_or(0, |g| g.len_self());
+            let parent_len = generics(self.db, def)
+                .parent_generics()
+                .map_or(0, |g| g.len_self());
             let parent_subst = GenericArgs::new_from_iter(
                 interner,
                 subst.as_slice()[..parent_len].iter().copied(),
             );
             let trait_ref = TraitRef::new(interner, trait_.into(), parent_subst);
-            self.table.register_predicate(Obligation::new(
-                interner,
-                ObligationCause::new(),
-                param_env,
-                trait_ref,
-            ));
+            self.table.register_predicate(
+                Obligation::new(interner, ObligationCause::new(), param_env, trait_ref),
+            );
         }
     }
 
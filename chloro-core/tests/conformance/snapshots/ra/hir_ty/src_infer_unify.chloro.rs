@@ -198,19 +198,22 @@ impl<'db> InferenceTable<'db> {
     }
 
     pub(crate) fn type_is_copy_modulo_regions(&self, ty: Ty<'db>) -> bool {
-        self.infer_ctxt.type_is_copy_modulo_regions(self.trait_env.env, ty)
+        self.infer_ctxt
+            .type_is_copy_modulo_regions(self.trait_env.env, ty)
     }
 
     pub(crate) fn type_var_is_sized(&self, self_ty: TyVid) -> bool {
         let Some(sized_did) = LangItem::Sized.resolve_trait(self.db, self.trait_env.krate) else {
             return true;
         };
-        self.obligations_for_self_ty(self_ty).into_iter().any(|obligation| {
-            match obligation.predicate.kind().skip_binder() {
-                PredicateKind::Clause(ClauseKind::Trait(data)) => data.def_id().0 == sized_did,
-                _ => false,
-            }
-        })
+        self.obligations_for_self_ty(self_ty)
+            .into_iter()
+            .any(|obligation| {
+                match obligation.predicate.kind().skip_binder() {
+                    PredicateKind::Clause(ClauseKind::Trait(data)) => data.def_id().0 == sized_did,
+                    _ => false,
+                }
+            })
     }
 
     pub(super) fn obligations_for_self_ty(
@@ -455,13 +458,17 @@ impl<'db> InferenceTable<'db> {
 
     /// Unify two relatable values (e.g. `Ty`) and register new trait goals that arise from that.
     pub(crate) fn unify<T: ToTrace<'db>>(&mut self, ty1: T, ty2: T) -> bool {
-        self.try_unify(ty1, ty2).map(|infer_ok| self.register_infer_ok(infer_ok)).is_ok()
+        self.try_unify(ty1, ty2)
+            .map(|infer_ok| self.register_infer_ok(infer_ok))
+            .is_ok()
     }
 
     /// Unify two relatable values (e.g. `Ty`) and return new trait goals arising from it, so the
     /// caller needs to deal with them.
     pub(crate) fn try_unify<T: ToTrace<'db>>(&mut self, t1: T, t2: T) -> InferResult<'db, ()> {
-        self.infer_ctxt.at(&ObligationCause::new(), self.trait_env.env).eq(t1, t2)
+        self.infer_ctxt
+            .at(&ObligationCause::new(), self.trait_env.env)
+            .eq(t1, t2)
     }
 
     pub(crate) fn shallow_resolve(&self, ty: Ty<'db>) -> Ty<'db> {
@@ -531,7 +538,10 @@ impl<'db> InferenceTable<'db> {
     pub(crate) fn snapshot(&mut self) -> InferenceTableSnapshot<'db> {
         let ctxt_snapshot = self.infer_ctxt.start_snapshot();
         let obligations = self.fulfillment_cx.clone();
-        InferenceTableSnapshot { ctxt_snapshot, obligations }
+        InferenceTableSnapshot {
+            ctxt_snapshot,
+            obligations,
+        }
     }
 
     #[tracing::instrument(skip_all)]
@@ -593,15 +603,13 @@ impl<'db> InferenceTable<'db> {
             Ok((_, Certainty::Yes)) => {}
             Err(rustc_type_ir::solve::NoSolution) => {}
             Ok((_, Certainty::Maybe { .. })) => {
-                self.fulfillment_cx.register_predicate_obligation(
-                    &self.infer_ctxt,
-                    Obligation::new(
+                self.fulfillment_cx
+                    .register_predicate_obligation(&self.infer_ctxt, Obligation::new(
                         self.interner(),
                         ObligationCause::new(),
                         goal.param_env,
                         goal.predicate,
-                    ),
-                );
+                    ));
             }
         }
     }
@@ -613,7 +621,8 @@ impl<'db> InferenceTable<'db> {
     }
 
     pub(crate) fn select_obligations_where_possible(&mut self) {
-        self.fulfillment_cx.try_evaluate_obligations(&self.infer_ctxt);
+        self.fulfillment_cx
+            .try_evaluate_obligations(&self.infer_ctxt);
     }
 
     pub(super) fn register_predicate(&mut self, obligation: PredicateObligation<'db>) {
@@ -621,7 +630,8 @@ impl<'db> InferenceTable<'db> {
             panic!("escaping bound vars in predicate {:?}", obligation);
         }
 
-        self.fulfillment_cx.register_predicate_obligation(&self.infer_ctxt, obligation);
+        self.fulfillment_cx
+            .register_predicate_obligation(&self.infer_ctxt, obligation);
     }
 
     pub(super) fn register_predicates<I>(&mut self, obligations: I)
@@ -849,7 +859,11 @@ mod resolve_completely {
             should_normalize: bool,
             nested_goals: &'a mut Vec<Goal<'db, Predicate<'db>>>,
         ) -> Resolver<'a, 'db> {
-            Resolver { ctx, nested_goals, should_normalize }
+            Resolver {
+                ctx,
+                nested_goals,
+                should_normalize,
+            }
         }
 
         fn handle_term<T>(
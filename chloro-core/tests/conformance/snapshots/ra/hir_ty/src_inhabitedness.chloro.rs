@@ -108,7 +108,13 @@ impl<'a, 'db> UninhabitedFrom<'a, 'db> {
         target_mod: ModuleId,
         env: Arc<TraitEnvironment<'db>>,
     ) -> Self {
-        Self { target_mod, recursive_ty: FxHashSet::default(), max_depth: 500, infcx, env }
+        Self {
+            target_mod,
+            recursive_ty: FxHashSet::default(),
+            max_depth: 500,
+            infcx,
+            env,
+        }
     }
 
     #[inline]
@@ -123,7 +123,9 @@ struct LayoutCx<'a> {
 
 impl<'a> LayoutCx<'a> {
     fn new(target: &'a TargetDataLayout) -> Self {
-        Self { calc: LayoutCalculator::new(target) }
+        Self {
+            calc: LayoutCalculator::new(target),
+        }
     }
 }
 
@@ -172,7 +174,10 @@ pub fn layout_of_ty_query<'db>(
     let cx = LayoutCx::new(dl);
     let infer_ctxt = interner.infer_ctxt().build(TypingMode::PostAnalysis);
     let cause = ObligationCause::dummy();
-    let ty = infer_ctxt.at(&cause, ParamEnv::empty()).deeply_normalize(ty).unwrap_or(ty);
+    let ty = infer_ctxt
+        .at(&cause, ParamEnv::empty())
+        .deeply_normalize(ty)
+        .unwrap_or(ty);
     let result = match ty.kind() {
         TyKind::Adt(def, args) => {
             match def.inner().id {
@@ -408,7 +413,10 @@ fn field_ty<'a>(
 }
 
 fn scalar_unit(dl: &TargetDataLayout, value: Primitive) -> Scalar {
-    Scalar::Initialized { value, valid_range: WrappingRange::full(value.size(dl)) }
+    Scalar::Initialized {
+        value,
+        valid_range: WrappingRange::full(value.size(dl)),
+    }
 }
 
 #[cfg(test)]
@@ -15,15 +15,16 @@ pub fn target_data_layout_query(
         Ok(target) => match TargetDataLayout::parse_from_llvm_datalayout_string(&target.data_layout, AddressSpace::ZERO) {
             Ok(it) => Ok(Arc::new(it)),
             Err(e) => {
-                Err(match e {
-                    TargetDataLayoutErrors::InvalidAddressSpace { addr_space, cause, err } => {
-                        format!(
+                Err(
+                    match e {
+                        TargetDataLayoutErrors::InvalidAddressSpace { addr_space, cause, err } => {
+                            format!(
                             r#"invalid address space `{addr_space}` for `{cause}` in "data-layout": {err}"#
                         )
-                    }
-                    TargetDataLayoutErrors::InvalidBits { kind, bit, cause, err } => format!(r#"invalid {kind} `{bit}` for `{cause}` in "data-layout": {err}"#),
-                    TargetDataLayoutErrors::MissingAlignment { cause } => format!(r#"missing alignment for `{cause}` in "data-layout""#),
-                    TargetDataLayoutErrors::InvalidAlignment { cause, err } => format!(
+                        }
+                        TargetDataLayoutErrors::InvalidBits { kind, bit, cause, err } => format!(r#"invalid {kind} `{bit}` for `{cause}` in "data-layout": {err}"#),
+                        TargetDataLayoutErrors::MissingAlignment { cause } => format!(r#"missing alignment for `{cause}` in "data-layout""#),
+                        TargetDataLayoutErrors::InvalidAlignment { cause, err } => format!(
                         r#"invalid alignment for `{cause}` in "data-layout": `{align}` is {err_kind}"#,
                         align = err.align(),
                         err_kind = match err {
@@ -31,16 +32,18 @@ pub fn target_data_layout_query(
                             AlignFromBytesError::TooLarge(_) => "too large",
                         }
                     ),
-                    TargetDataLayoutErrors::InconsistentTargetArchitecture { dl, target } => {
-                        format!(r#"inconsistent target specification: "data-layout" claims architecture is {dl}-endian, while "target-endian" is `{target}`"#)
-                    }
-                    TargetDataLayoutErrors::InconsistentTargetPointerWidth {
+                        TargetDataLayoutErrors::InconsistentTargetArchitecture { dl, target } => {
+                            format!(r#"inconsistent target specification: "data-layout" claims architecture is {dl}-endian, while "target-endian" is `{target}`"#)
+                        }
+                        TargetDataLayoutErrors::InconsistentTargetPointerWidth {
                         pointer_size,
                         target,
                     } => format!(r#"inconsistent target specification: "data-layout" claims pointers are {pointer_size}-bit, while "target-pointer-width" is `{target}`"#),
-                    TargetDataLayoutErrors::InvalidBitsSize { err } => err,
-                    TargetDataLayoutErrors::UnknownPointerSpecification { err } => format!(r#"use of unknown pointer specifier in "data-layout": {err}"#),
-                }.into())
+                        TargetDataLayoutErrors::InvalidBitsSize { err } => err,
+                        TargetDataLayoutErrors::UnknownPointerSpecification { err } => format!(r#"use of unknown pointer specifier in "data-layout": {err}"#),
+                    }
+                    .into(),
+                )
             }
         },
         Err(e) => Err(e.clone()),
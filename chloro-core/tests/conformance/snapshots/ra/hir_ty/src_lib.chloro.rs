@@ -165,7 +165,10 @@ impl<'db> MemoryMap<'db> {
                 map
             }),
             MemoryMap::Complex(cm) => {
-                cm.memory.iter().map(|(addr, val)| transform((addr, val))).collect()
+                cm.memory
+                    .iter()
+                    .map(|(addr, val)| transform((addr, val)))
+                    .collect()
             }
         }
     }
@@ -357,6 +360,7 @@ where
     impl<'db> FallibleTypeFolder<DbInterner<'db>> for ErrorReplacer<'db> {
         #[cfg(debug_assertions)]
         type Error = ();
+
         #[cfg(not(debug_assertions))]
         type Error = std::convert::Infallible;
 
@@ -364,7 +368,10 @@ where
             self.interner
         }
 
-        fn try_fold_binder<T>(&mut self, t: Binder<'db, T>) -> Result<Binder<'db, T>, Self::Error>
+        fn try_fold_binder<T>(
+            &mut self,
+            t: Binder<'db, T>,
+        ) -> Result<Binder<'db, T>, Self::Error>
         where
             T: rustc_type_ir::TypeFoldable<DbInterner<'db>>,
         {
@@ -396,11 +403,10 @@ where
                         ui: rustc_type_ir::UniverseIndex::ZERO,
                         sub_root: var,
                     });
-                    Ok(Ty::new_bound(
-                        self.interner,
-                        self.binder,
-                        BoundTy { var, kind: BoundTyKind::Anon },
-                    ))
+                    Ok(Ty::new_bound(self.interner, self.binder, BoundTy {
+                        var,
+                        kind: BoundTyKind::Anon,
+                    }))
                 }
                 TyKind::Infer(_) => error(),
                 TyKind::Bound(BoundVarIndexKind::Bound(index), _) if index > self.binder => error(),
@@ -426,7 +432,8 @@ where
             match ct.kind() {
                 ConstKind::Error(_) => {
                     let var = rustc_type_ir::BoundVar::from_usize(self.vars.len());
-                    self.vars.push(CanonicalVarKind::Const(rustc_type_ir::UniverseIndex::ZERO));
+                    self.vars
+                        .push(CanonicalVarKind::Const(rustc_type_ir::UniverseIndex::ZERO));
                     Ok(Const::new_bound(self.interner, self.binder, BoundConst { var }))
                 }
                 ConstKind::Infer(_) => error(),
@@ -446,12 +453,12 @@ where
             match region.kind() {
                 RegionKind::ReError(_) => {
                     let var = rustc_type_ir::BoundVar::from_usize(self.vars.len());
-                    self.vars.push(CanonicalVarKind::Region(rustc_type_ir::UniverseIndex::ZERO));
-                    Ok(Region::new_bound(
-                        self.interner,
-                        self.binder,
-                        BoundRegion { var, kind: BoundRegionKind::Anon },
-                    ))
+                    self.vars
+                        .push(CanonicalVarKind::Region(rustc_type_ir::UniverseIndex::ZERO));
+                    Ok(Region::new_bound(self.interner, self.binder, BoundRegion {
+                        var,
+                        kind: BoundRegionKind::Anon,
+                    }))
                 }
                 RegionKind::ReVar(_) => error(),
                 RegionKind::ReBound(BoundVarIndexKind::Bound(index), _) if index > self.binder => {
@@ -585,9 +592,7 @@ pub fn known_const_to_ast<'db>(
 pub(crate) enum DeclOrigin {
     LetExpr,
     /// from `let x = ..`
-    LocalDecl {
-        has_else: bool,
-    },
+    LocalDecl { has_else: bool },
 }
 
 /// Provides context for checking patterns in declarations. More specifically this
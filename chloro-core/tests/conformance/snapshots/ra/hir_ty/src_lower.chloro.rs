@@ -91,7 +91,10 @@ struct ImplTraitLoweringState<'db> {
 
 impl<'db> ImplTraitLoweringState<'db> {
     fn new(mode: ImplTraitLoweringMode) -> ImplTraitLoweringState<'db> {
-        Self { mode, opaque_type_data: Arena::new() }
+        Self {
+            mode,
+            opaque_type_data: Arena::new(),
+        }
     }
 }
 
@@ -111,9 +114,7 @@ pub enum LifetimeElisionKind<'db> {
     /// struct Foo<'a> { x: &'a () }
     /// async fn foo(Foo { x: _ }: Foo<'_>) {}
     /// ```
-    AnonymousCreateParameter {
-        report_in_path: bool,
-    },
+    AnonymousCreateParameter { report_in_path: bool },
 
     /// Replace all anonymous lifetimes by provided lifetime.
     Elided(Region<'db>),
@@ -126,9 +127,7 @@ pub enum LifetimeElisionKind<'db> {
     /// Resolves elided lifetimes to `'static` if there are no other lifetimes in scope,
     /// otherwise give a warning that the previous behavior of introducing a new early-bound
     /// lifetime is a bug and will be removed (if `only_lint` is enabled).
-    StaticIfNoLifetimeInScope {
-        only_lint: bool,
-    },
+    StaticIfNoLifetimeInScope { only_lint: bool },
 
     /// Signal we cannot find which should be the anonymous lifetime.
     ElisionFailure,
@@ -158,7 +157,9 @@ impl<'db> LifetimeElisionKind<'db> {
 
     #[inline]
     pub(crate) fn for_fn_params(data: &FunctionSignature) -> LifetimeElisionKind<'db> {
-        LifetimeElisionKind::AnonymousCreateParameter { report_in_path: data.is_async() }
+        LifetimeElisionKind::AnonymousCreateParameter {
+            report_in_path: data.is_async(),
+        }
     }
 
     #[inline]
@@ -237,7 +238,10 @@ impl<'db, 'a> TyLoweringContext<'db, 'a> {
     }
 
     pub(crate) fn with_impl_trait_mode(self, impl_trait_mode: ImplTraitLoweringMode) -> Self {
-        Self { impl_trait_mode: ImplTraitLoweringState::new(impl_trait_mode), ..self }
+        Self {
+            impl_trait_mode: ImplTraitLoweringState::new(impl_trait_mode),
+            ..self
+        }
     }
 
     pub(crate) fn impl_trait_mode(&mut self, impl_trait_mode: ImplTraitLoweringMode) -> &mut Self {
@@ -250,7 +254,10 @@ impl<'db, 'a> TyLoweringContext<'db, 'a> {
     }
 
     pub(crate) fn push_diagnostic(&mut self, type_ref: TypeRefId, kind: TyLoweringDiagnosticKind) {
-        self.diagnostics.push(TyLoweringDiagnostic { source: type_ref, kind });
+        self.diagnostics.push(TyLoweringDiagnostic {
+            source: type_ref,
+            kind,
+        });
     }
 }
 
@@ -275,7 +282,8 @@ impl<'db, 'a> TyLoweringContext<'db, 'a> {
         let const_ref = &self.store[const_ref.expr];
         match const_ref {
             hir_def::hir::Expr::Path(path) => {
-                self.path_to_const(path).unwrap_or_else(|| unknown_const(const_type))
+                self.path_to_const(path)
+                    .unwrap_or_else(|| unknown_const(const_type))
             }
             hir_def::hir::Expr::Literal(literal) => intern_const_ref(
                 self.db,
@@ -320,7 +328,8 @@ impl<'db, 'a> TyLoweringContext<'db, 'a> {
     }
 
     pub(crate) fn path_to_const(&mut self, path: &Path) -> Option<Const<'db>> {
-        match self.resolver.resolve_path_in_value_ns_fully(self.db, path, HygieneId::ROOT) {
+        match self.resolver
+            .resolve_path_in_value_ns_fully(self.db, path, HygieneId::ROOT) {
             Some(ValueNs::GenericParam(p)) => {
                 let args = self.generics();
                 match args.type_or_const_param_idx(p.into()) {
@@ -338,19 +347,17 @@ impl<'db, 'a> TyLoweringContext<'db, 'a> {
             }
             Some(ValueNs::ConstId(c)) => {
                 let args = GenericArgs::new_from_iter(self.interner, []);
-                Some(Const::new(
-                    self.interner,
-                    rustc_type_ir::ConstKind::Unevaluated(
+                Some(Const::new(self.interner, rustc_type_ir::ConstKind::Unevaluated(
                     UnevaluatedConst::new(SolverDefId::ConstId(c), args),
-                ),
-                ))
+                )))
             }
             _ => None,
         }
     }
 
     pub(crate) fn lower_path_as_const(&mut self, path: &Path, const_type: Ty<'db>) -> Const<'db> {
-        self.path_to_const(path).unwrap_or_else(|| unknown_const(const_type))
+        self.path_to_const(path)
+            .unwrap_or_else(|| unknown_const(const_type))
     }
 
     fn generics(&self) -> &Generics {
@@ -610,7 +617,8 @@ impl<'db, 'a> TyLoweringContext<'db, 'a> {
         trait_ref: &HirTraitRef,
         explicit_self_ty: Ty<'db>,
     ) -> Option<TraitRef<'db>> {
-        self.lower_trait_ref_from_path(trait_ref.path, explicit_self_ty).map(|it| it.0)
+        self.lower_trait_ref_from_path(trait_ref.path, explicit_self_ty)
+            .map(|it| it.0)
     }
 
     pub(crate) fn lower_where_predicate<'b>(
@@ -646,12 +654,11 @@ impl<'db, 'a> TyLoweringContext<'db, 'a> {
                 Either::Left(Either::Right(self.lower_type_bound(bound, self_ty, ignore_bindings)))
             }
             &WherePredicate::Lifetime { bound, target } => {
-                Either::Right(iter::once(Clause(Predicate::new(
-                    self.interner,
-                    Binder::dummy(rustc_type_ir::PredicateKind::Clause(rustc_type_ir::ClauseKind::RegionOutlives(
-                    OutlivesPredicate(self.lower_lifetime(bound), self.lower_lifetime(target)),
-                ))),
-                ))))
+                Either::Right(iter::once(Clause(Predicate::new(self.interner, Binder::dummy(
+                    rustc_type_ir::PredicateKind::Clause(rustc_type_ir::ClauseKind::RegionOutlives(
+                        OutlivesPredicate(self.lower_lifetime(bound), self.lower_lifetime(target)),
+                    )),
+                )))))
             }
         }
         .into_iter()
@@ -1741,11 +1748,13 @@ fn implicitly_sized_clauses<'a, 'subst, 'db>(
     Some(
         args.iter()
             .enumerate()
-            .filter_map(
-                move |(idx, generic_arg)| {
-                    if Some(idx) == trait_self_idx { None } else { Some(generic_arg) }
-                },
-            )
+            .filter_map(move |(idx, generic_arg)| {
+                if Some(idx) == trait_self_idx {
+                    None
+                } else {
+                    Some(generic_arg)
+                }
+            })
             .filter_map(|generic_arg| generic_arg.as_type())
             .filter(move |self_ty| !explicitly_unsized_tys.contains(self_ty))
             .map(move |self_ty| {
@@ -1754,15 +1763,12 @@ fn implicitly_sized_clauses<'a, 'subst, 'db>(
                     sized_trait.into(),
                     GenericArgs::new_from_iter(interner, [self_ty.into()]),
                 );
-                Clause(Predicate::new(
-                    interner,
-                    Binder::dummy(rustc_type_ir::PredicateKind::Clause(
-                        rustc_type_ir::ClauseKind::Trait(TraitPredicate {
-                            trait_ref,
-                            polarity: rustc_type_ir::PredicatePolarity::Positive,
-                        }),
-                    )),
-                ))
+                Clause(Predicate::new(interner, Binder::dummy(rustc_type_ir::PredicateKind::Clause(
+                    rustc_type_ir::ClauseKind::Trait(TraitPredicate {
+                        trait_ref,
+                        polarity: rustc_type_ir::PredicatePolarity::Positive,
+                    }),
+                ))))
             }),
     )
 }
@@ -1911,7 +1917,11 @@ fn fn_sig_for_fn<'db>(
     EarlyBinder::bind(rustc_type_ir::Binder::dummy(FnSig {
         abi: data.abi.as_ref().map_or(FnAbi::Rust, FnAbi::from_symbol),
         c_variadic: data.is_varargs(),
-        safety: if data.is_unsafe() { Safety::Unsafe } else { Safety::Safe },
+        safety: if data.is_unsafe() {
+            Safety::Unsafe
+        } else {
+            Safety::Safe
+        },
         inputs_and_output,
     }))
 }
@@ -2061,15 +2071,9 @@ pub fn associated_type_shorthand_candidates(
     mut cb: impl FnMut(&Name, TypeAliasId) -> bool,
 ) -> Option<TypeAliasId> {
     let interner = DbInterner::new_with(db, None, None);
-    named_associated_type_shorthand_candidates(
-        interner,
-        def,
-        res,
-        None,
-        |name, _, id| {
+    named_associated_type_shorthand_candidates(interner, def, res, None, |name, _, id| {
         cb(name, id).then_some(id)
-    },
-    )
+    })
 }
 
 #[tracing::instrument(skip(interner, check_alias))]
@@ -36,10 +36,7 @@ pub enum PathGenericsSource {
     /// Generic arguments directly on the segment.
     Segment(u32),
     /// Generic arguments on an associated type, e.g. `Foo<Assoc<A, B> = C>` or `Foo<Assoc<A, B>: Bound>`.
-    AssocType {
-        segment: u32,
-        assoc_type: u32,
-    },
+    AssocType { segment: u32, assoc_type: u32 },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -48,9 +45,7 @@ pub enum PathLoweringDiagnostic {
         segment: u32,
         reason: GenericArgsProhibitedReason,
     },
-    ParenthesizedGenericArgsWithoutFnTrait {
-        segment: u32,
-    },
+    ParenthesizedGenericArgsWithoutFnTrait { segment: u32 },
     /// The expected lifetimes & types and consts counts can be found by inspecting the `GenericDefId`.
     IncorrectGenericsLen {
         generics_source: PathGenericsSource,
@@ -50,6 +50,8 @@ type CallbackData<'a, 'db> =
     crate::infer::diagnostics::PathDiagnosticCallbackData<'a, 'db>,
 >;
 
+// We cannot use `&mut dyn FnMut()` because of lifetime issues, and we don't want to use `Box<dyn FnMut()>`
+// because of the allocation, so we create a lifetime-less callback, tailored for our needs.
 pub(crate) struct PathDiagnosticCallback<'a, 'db> {
     pub(crate) data: CallbackData<'a, 'db>,
     pub(crate) callback: fn(&CallbackData<'_, 'db>, &mut TyLoweringContext<'db, '_>, PathLoweringDiagnostic),
@@ -514,9 +516,7 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
             Some(assoc_name.clone()),
             check_alias,
         )
-        .unwrap_or_else(
-            || Ty::new_error(interner, ErrorGuaranteed),
-        )
+        .unwrap_or_else(|| Ty::new_error(interner, ErrorGuaranteed))
     }
 
     fn lower_path_inner(&mut self, typeable: TyDefId, infer_args: bool) -> Ty<'db> {
@@ -665,13 +665,14 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
                 expected_count: u32,
                 kind: IncorrectGenericsLenKind,
             ) {
-                self.ctx.on_diagnostic(PathLoweringDiagnostic::IncorrectGenericsLen {
-                    generics_source: self.generics_source,
-                    provided_count,
-                    expected_count,
-                    kind,
-                    def,
-                });
+                self.ctx
+                    .on_diagnostic(PathLoweringDiagnostic::IncorrectGenericsLen {
+                        generics_source: self.generics_source,
+                        provided_count,
+                        expected_count,
+                        kind,
+                        def,
+                    });
             }
 
             fn report_arg_mismatch(
@@ -680,12 +681,13 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
                 arg_idx: u32,
                 has_self_arg: bool,
             ) {
-                self.ctx.on_diagnostic(PathLoweringDiagnostic::IncorrectGenericsOrder {
-                    generics_source: self.generics_source,
-                    param_id,
-                    arg_idx,
-                    has_self_arg,
-                });
+                self.ctx
+                    .on_diagnostic(PathLoweringDiagnostic::IncorrectGenericsOrder {
+                        generics_source: self.generics_source,
+                        param_id,
+                        arg_idx,
+                        has_self_arg,
+                    });
             }
 
             fn provided_kind(
@@ -790,28 +792,31 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
                 expected_count: u32,
                 hard_error: bool,
             ) {
-                self.ctx.on_diagnostic(PathLoweringDiagnostic::ElidedLifetimesInPath {
-                    generics_source: self.generics_source,
-                    def,
-                    expected_count,
-                    hard_error,
-                });
+                self.ctx
+                    .on_diagnostic(PathLoweringDiagnostic::ElidedLifetimesInPath {
+                        generics_source: self.generics_source,
+                        def,
+                        expected_count,
+                        hard_error,
+                    });
             }
 
             fn report_elision_failure(&mut self, def: GenericDefId, expected_count: u32) {
-                self.ctx.on_diagnostic(PathLoweringDiagnostic::ElisionFailure {
-                    generics_source: self.generics_source,
-                    def,
-                    expected_count,
-                });
+                self.ctx
+                    .on_diagnostic(PathLoweringDiagnostic::ElisionFailure {
+                        generics_source: self.generics_source,
+                        def,
+                        expected_count,
+                    });
             }
 
             fn report_missing_lifetime(&mut self, def: GenericDefId, expected_count: u32) {
-                self.ctx.on_diagnostic(PathLoweringDiagnostic::MissingLifetime {
-                    generics_source: self.generics_source,
-                    def,
-                    expected_count,
-                });
+                self.ctx
+                    .on_diagnostic(PathLoweringDiagnostic::MissingLifetime {
+                        generics_source: self.generics_source,
+                        def,
+                        expected_count,
+                    });
             }
         }
 
@@ -824,7 +829,10 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
             lifetime_elision,
             lowering_assoc_type_generics,
             explicit_self_ty,
-            &mut LowererCtx { ctx: self, generics_source },
+            &mut LowererCtx {
+                ctx: self,
+                generics_source,
+            },
         )
     }
 
@@ -852,18 +860,21 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
         trait_ref: TraitRef<'db>,
     ) -> Option<impl Iterator<Item = Clause<'db>> + use<'a, 'b, 'c, 'db>> {
         let interner = self.ctx.interner;
-        self.current_or_prev_segment.args_and_bindings.map(|args_and_bindings| {
-            args_and_bindings.bindings.iter().enumerate().flat_map(move |(binding_idx, binding)| {
-                let found = associated_type_by_name_including_super_traits(
+        self.current_or_prev_segment
+            .args_and_bindings
+            .map(|args_and_bindings| {
+                args_and_bindings.bindings.iter().enumerate().flat_map(
+                    move |(binding_idx, binding)| {
+                        let found = associated_type_by_name_including_super_traits(
                     self.ctx.db,
                     trait_ref,
                     &binding.name,
                 );
-                let (super_trait_ref, associated_ty) = match found {
+                        let (super_trait_ref, associated_ty) = match found {
                     None => return SmallVec::new(),
                     Some(t) => t,
                 };
-                let args =
+                        let args =
                     self.with_lifetime_elision(LifetimeElisionKind::AnonymousReportError, |this| {
                         // FIXME: `substs_from_path_segment()` pushes `TyKind::Error` for every parent
                         // generic params. It's inefficient to splice the `Substitution`s, so we may want
@@ -882,16 +893,16 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
                             this.ctx.lifetime_elision.clone(),
                         )
                     });
-                let args = GenericArgs::new_from_iter(
+                        let args = GenericArgs::new_from_iter(
                     interner,
                     super_trait_ref.args.iter().chain(args.iter().skip(super_trait_ref.args.len())),
                 );
-                let projection_term =
+                        let projection_term =
                     AliasTerm::new_from_args(interner, associated_ty.into(), args);
-                let mut predicates: SmallVec<[_; 1]> = SmallVec::with_capacity(
+                        let mut predicates: SmallVec<[_; 1]> = SmallVec::with_capacity(
                     binding.type_ref.as_ref().map_or(0, |_| 1) + binding.bounds.len(),
                 );
-                if let Some(type_ref) = binding.type_ref {
+                        if let Some(type_ref) = binding.type_ref {
                     let lifetime_elision =
                         if args_and_bindings.parenthesized == GenericArgsParentheses::ParenSugar {
                             // `Fn()`-style generics are elided like functions. This is `Output` (we lower to it in hir-def).
@@ -923,7 +934,7 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
                         }
                     })
                 }
-                for bound in binding.bounds.iter() {
+                        for bound in binding.bounds.iter() {
                     predicates.extend(self.ctx.lower_type_bound(
                         bound,
                         Ty::new_alias(
@@ -934,9 +945,10 @@ impl<'a, 'b, 'db> PathLoweringContext<'a, 'b, 'db> {
                         false,
                     ));
                 }
-                predicates
+                        predicates
+                    },
+                )
             })
-        })
     }
 }
 
@@ -212,14 +212,15 @@ impl TraitImpls {
     ) -> Arc<[Arc<Self>]> {
         let _p = tracing::info_span!("trait_impls_in_deps_query", ?krate).entered();
         Arc::from_iter(
-            db.transitive_deps(krate).into_iter().map(|krate| db.trait_impls_in_crate(krate)),
+            db.transitive_deps(krate)
+                .into_iter()
+                .map(|krate| db.trait_impls_in_crate(krate)),
         )
     }
 
     fn finish(map: TraitFpMapCollector) -> TraitImpls {
         TraitImpls {
-            map: map
-                .into_iter()
+            map: map.into_iter()
                 .map(|(k, v)| (k, v.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()))
                 .collect(),
         }
@@ -299,7 +300,9 @@ impl TraitImpls {
     }
 
     pub fn all_impls(&self) -> impl Iterator<Item = ImplId> + '_ {
-        self.map.values().flat_map(|map| map.values().flat_map(|v| v.iter().copied()))
+        self.map
+            .values()
+            .flat_map(|map| map.values().flat_map(|v| v.iter().copied()))
     }
 }
 
@@ -449,10 +452,9 @@ pub fn def_crates<'db>(
         }
         TyKind::Foreign(alias) => {
             let alias = alias.0;
-            Some(if db
-                    .type_alias_signature(alias)
-                    .flags
-                    .contains(TypeAliasFlags::RUSTC_HAS_INCOHERENT_INHERENT_IMPL) {
+            Some(if db.type_alias_signature(alias)
+                .flags
+                .contains(TypeAliasFlags::RUSTC_HAS_INCOHERENT_INHERENT_IMPL) {
                 db.incoherent_inherent_impl_crates(cur_crate, TyFingerprint::ForeignType(alias))
             } else {
                 smallvec![alias.module(db).krate()]
@@ -460,10 +462,9 @@ pub fn def_crates<'db>(
         }
         TyKind::Dynamic(bounds, _) => {
             let trait_id = bounds.principal_def_id()?.0;
-            Some(if db
-                    .trait_signature(trait_id)
-                    .flags
-                    .contains(TraitFlags::RUSTC_HAS_INCOHERENT_INHERENT_IMPLS) {
+            Some(if db.trait_signature(trait_id)
+                .flags
+                .contains(TraitFlags::RUSTC_HAS_INCOHERENT_INHERENT_IMPLS) {
                 db.incoherent_inherent_impl_crates(cur_crate, TyFingerprint::Dyn(trait_id))
             } else {
                 smallvec![trait_id.module(db).krate()]
@@ -480,10 +481,12 @@ pub fn def_crates<'db>(
         | TyKind::Str
         | TyKind::Slice(_)
         | TyKind::Array(..)
-        | TyKind::RawPtr(..) => Some(db.incoherent_inherent_impl_crates(
-            cur_crate,
-            TyFingerprint::for_inherent_impl(ty).expect("fingerprint for primitive"),
-        )),
+        | TyKind::RawPtr(..) => Some(
+            db.incoherent_inherent_impl_crates(
+                cur_crate,
+                TyFingerprint::for_inherent_impl(ty).expect("fingerprint for primitive"),
+            ),
+        ),
         _ => None,
     }
 }
@@ -646,7 +649,10 @@ impl ReceiverAdjustments {
     }
 
     fn with_autoref(&self, a: AutorefOrPtrAdjustment) -> ReceiverAdjustments {
-        Self { autoref: Some(a), ..*self }
+        Self {
+            autoref: Some(a),
+            ..*self
+        }
     }
 }
 
@@ -778,9 +784,9 @@ pub(crate) fn lookup_impl_method_query<'db>(
     (
         impl_fn,
         GenericArgs::new_from_iter(
-            interner,
-            impl_subst.iter().chain(fn_subst.iter().skip(trait_params)),
-        ),
+        interner,
+        impl_subst.iter().chain(fn_subst.iter().skip(trait_params)),
+    ),
     )
 }
 
@@ -902,9 +908,7 @@ fn is_inherent_impl_coherent<'db>(
             _ => false,
         };
         let items = impl_id.impl_items(db);
-        rustc_has_incoherent_inherent_impls
-            && !items.items.is_empty()
-            && items.items.iter().all(|&(_, assoc)| match assoc {
+        rustc_has_incoherent_inherent_impls && !items.items.is_empty() && items.items.iter().all(|&(_, assoc)| match assoc {
                 AssocItemId::FunctionId(it) => {
                     db.function_signature(it).flags.contains(FnFlags::RUSTC_ALLOW_INCOHERENT_IMPL)
                 }
@@ -970,7 +974,10 @@ pub fn check_orphan_rules<'db>(db: &'db dyn HirDatabase, impl_: ImplId) -> bool
     //   - At least one of the types `T0..=Tn`` must be a local type. Let `Ti`` be the first such type.
     // FIXME: param coverage
     //   - No uncovered type parameters `P1..=Pn` may appear in `T0..Ti`` (excluding `Ti`)
-    let is_not_orphan = trait_ref.args.types().any(|ty| match unwrap_fundamental(ty).kind() {
+    let is_not_orphan = trait_ref
+        .args
+        .types()
+        .any(|ty| match unwrap_fundamental(ty).kind() {
         TyKind::Adt(adt_def, _) => is_local(adt_def.def_id().0.module(db).krate()),
         TyKind::Error(_) => true,
         TyKind::Dynamic(it, _) => {
@@ -1000,6 +1007,7 @@ pub fn iterate_path_candidates<'db>(
         visible_from_module,
         name,
         LookupMode::Path,
+        // the adjustments are not relevant for path lookup
         callback,
     )
 }
@@ -1722,7 +1730,9 @@ fn is_valid_impl_fn_candidate<'db>(
     table.run_in_snapshot(|table| {
         let _p = tracing::info_span!("subst_for_def").entered();
         let impl_subst = table.infer_ctxt.fresh_args_for_item(impl_id.into());
-        let expect_self_ty = db.impl_self_ty(impl_id).instantiate(table.interner(), &impl_subst);
+        let expect_self_ty = db
+            .impl_self_ty(impl_id)
+            .instantiate(table.interner(), &impl_subst);
 
         check_that!(table.unify(expect_self_ty, self_ty));
 
@@ -1774,7 +1784,9 @@ fn generic_implements_goal_ns<'db>(
     trait_: TraitId,
     self_ty: Canonical<'db, Ty<'db>>,
 ) -> Canonical<'db, Goal<'db, Predicate<'db>>> {
-    let args = table.infer_ctxt.fresh_args_for_item(SolverDefId::TraitId(trait_));
+    let args = table
+        .infer_ctxt
+        .fresh_args_for_item(SolverDefId::TraitId(trait_));
     let self_ty = table.instantiate_canonical(self_ty);
     let trait_ref =
         rustc_type_ir::TraitRef::new_from_args(table.infer_ctxt.interner, trait_.into(), args)
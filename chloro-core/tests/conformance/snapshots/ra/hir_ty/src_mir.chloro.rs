@@ -104,10 +104,7 @@ pub enum OperandKind<'db> {
     /// [UCG#188]: https://github.com/rust-lang/unsafe-code-guidelines/issues/188
     Move(Place<'db>),
     /// Constants are already semantically values, and remain unchanged.
-    Constant {
-        konst: Const<'db>,
-        ty: Ty<'db>,
-    },
+    Constant { konst: Const<'db>, ty: Ty<'db> },
     /// NON STANDARD: This kind of operand returns an immutable reference to that static memory. Rustc
     /// handles it with the `Constant` variant somehow.
     Static(StaticId),
@@ -151,14 +148,8 @@ pub enum ProjectionElem<V, T> {
     // FIXME: get rid of this, and use FieldId for tuples and closures
     ClosureField(usize),
     Index(V),
-    ConstantIndex {
-        offset: u64,
-        from_end: bool,
-    },
-    Subslice {
-        from: u64,
-        to: u64,
-    },
+    ConstantIndex { offset: u64, from_end: bool },
+    Subslice { from: u64, to: u64 },
     //Downcast(Option<Symbol>, VariantIdx),
     OpaqueCast(T),
 }
@@ -214,10 +205,14 @@ impl<V, T> ProjectionElem<V, T> {
             },
             ProjectionElem::Field(Either::Right(f)) => match base.kind() {
                 TyKind::Tuple(subst) => {
-                    subst.as_slice().get(f.index as usize).copied().unwrap_or_else(|| {
-                        never!("Out of bound tuple field");
-                        Ty::new_error(interner, ErrorGuaranteed)
-                    })
+                    subst
+                        .as_slice()
+                        .get(f.index as usize)
+                        .copied()
+                        .unwrap_or_else(|| {
+                            never!("Out of bound tuple field");
+                            Ty::new_error(interner, ErrorGuaranteed)
+                        })
                 }
                 ty => {
                     never!("Only tuple has tuple field: {:?}", ty);
@@ -338,8 +333,10 @@ pub struct Place<'db> {
 
 impl<'db> Place<'db> {
     fn is_parent(&self, child: &Place<'db>, store: &ProjectionStore<'db>) -> bool {
-        self.local == child.local
-            && child.projection.lookup(store).starts_with(self.projection.lookup(store))
+        self.local == child.local && child
+            .projection
+            .lookup(store)
+            .starts_with(self.projection.lookup(store))
     }
 
     /// The place itself is not included
@@ -348,9 +345,14 @@ impl<'db> Place<'db> {
         store: &'a ProjectionStore<'db>,
     ) -> impl Iterator<Item = Place<'db>> + 'a {
         let projection = self.projection.lookup(store);
-        (0..projection.len()).map(|x| &projection[0..x]).filter_map(move |x| {
-            Some(Place { local: self.local, projection: store.intern_if_exist(x)? })
-        })
+        (0..projection.len())
+            .map(|x| &projection[0..x])
+            .filter_map(move |x| {
+                Some(Place {
+                    local: self.local,
+                    projection: store.intern_if_exist(x)?,
+                })
+            })
     }
 
     fn project(&self, projection: PlaceElem<'db>, store: &mut ProjectionStore<'db>) -> Place<'db> {
@@ -363,7 +365,10 @@ impl<'db> Place<'db> {
 
 impl<'db> From<LocalId<'db>> for Place<'db> {
     fn from(local: LocalId<'db>) -> Self {
-        Self { local, projection: ProjectionId::EMPTY }
+        Self {
+            local,
+            projection: ProjectionId::EMPTY,
+        }
     }
 }
 
@@ -445,7 +450,9 @@ impl<'db> SwitchTargets<'db> {
     /// specific value. This cannot fail, as it'll return the `otherwise`
     /// branch if there's not a specific match for the value.
     pub fn target_for_value(&self, value: u128) -> BasicBlockId<'db> {
-        self.iter().find_map(|(v, t)| (v == value).then_some(t)).unwrap_or_else(|| self.otherwise())
+        self.iter()
+            .find_map(|(v, t)| (v == value).then_some(t))
+            .unwrap_or_else(|| self.otherwise())
     }
 }
 
@@ -458,9 +465,7 @@ pub struct Terminator<'db> {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum TerminatorKind<'db> {
     /// Block has one successor; we continue execution there.
-    Goto {
-        target: BasicBlockId<'db>,
-    },
+    Goto { target: BasicBlockId<'db> },
 
     /// Switches based on the computed value.
     ///
@@ -679,6 +684,7 @@ pub enum TerminatorKind<'db> {
     },
 }
 
+// Order of variants in this enum matter: they are used to compare borrow kinds.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
 pub enum BorrowKind {
     /// Data must be immutable and is aliasable.
@@ -706,11 +712,10 @@ pub enum BorrowKind {
     Shallow,
 
     /// Data is mutable and not aliasable.
-    Mut {
-        kind: MutBorrowKind,
-    },
+    Mut { kind: MutBorrowKind },
 }
 
+// Order of variants in this enum matter: they are used to compare borrow kinds.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
 pub enum MutBorrowKind {
     /// Data must be immutable but not aliasable. This kind of borrow cannot currently
@@ -726,14 +731,18 @@ impl BorrowKind {
     fn from_hir(m: hir_def::type_ref::Mutability) -> Self {
         match m {
             hir_def::type_ref::Mutability::Shared => BorrowKind::Shared,
-            hir_def::type_ref::Mutability::Mut => BorrowKind::Mut { kind: MutBorrowKind::Default },
+            hir_def::type_ref::Mutability::Mut => BorrowKind::Mut {
+                kind: MutBorrowKind::Default,
+            },
         }
     }
 
     fn from_rustc(m: rustc_ast_ir::Mutability) -> Self {
         match m {
             rustc_ast_ir::Mutability::Not => BorrowKind::Shared,
-            rustc_ast_ir::Mutability::Mut => BorrowKind::Mut { kind: MutBorrowKind::Default },
+            rustc_ast_ir::Mutability::Mut => BorrowKind::Mut {
+                kind: MutBorrowKind::Default,
+            },
         }
     }
 }
@@ -811,24 +820,24 @@ impl BinOp {
 impl Display for BinOp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
-                BinOp::Add => "+",
-                BinOp::Sub => "-",
-                BinOp::Mul => "*",
-                BinOp::Div => "/",
-                BinOp::Rem => "%",
-                BinOp::BitXor => "^",
-                BinOp::BitAnd => "&",
-                BinOp::BitOr => "|",
-                BinOp::Shl => "<<",
-                BinOp::Shr => ">>",
-                BinOp::Eq => "==",
-                BinOp::Lt => "<",
-                BinOp::Le => "<=",
-                BinOp::Ne => "!=",
-                BinOp::Ge => ">=",
-                BinOp::Gt => ">",
-                BinOp::Offset => "`offset`",
-            })
+            BinOp::Add => "+",
+            BinOp::Sub => "-",
+            BinOp::Mul => "*",
+            BinOp::Div => "/",
+            BinOp::Rem => "%",
+            BinOp::BitXor => "^",
+            BinOp::BitAnd => "&",
+            BinOp::BitOr => "|",
+            BinOp::Shl => "<<",
+            BinOp::Shr => ">>",
+            BinOp::Eq => "==",
+            BinOp::Lt => "<",
+            BinOp::Le => "<=",
+            BinOp::Ne => "!=",
+            BinOp::Ge => ">=",
+            BinOp::Gt => ">",
+            BinOp::Offset => "`offset`",
+        })
     }
 }
 
@@ -24,12 +24,10 @@ use super::{
     Place, ProjectionElem, Rvalue, StatementKind, TerminatorKind,
 };
 
-/// Stores spans which implies that the local should be mutable.
 #[derive(Debug, Clone, PartialEq, Eq)]
+/// Stores spans which implies that the local should be mutable.
 pub enum MutabilityReason {
-    Mut {
-        spans: Vec<MirSpan>,
-    },
+    Mut { spans: Vec<MirSpan> },
     Not,
     Unused,
 }
@@ -76,7 +74,9 @@ fn all_mir_bodies<'db>(
         match db.mir_body_for_closure(c) {
             Ok(body) => {
                 cb(body.clone());
-                body.closures.iter().try_for_each(|&it| for_closure(db, it, cb))
+                body.closures
+                    .iter()
+                    .try_for_each(|&it| for_closure(db, it, cb))
             }
             Err(e) => Err(e),
         }
@@ -84,7 +84,9 @@ fn all_mir_bodies<'db>(
     match db.mir_body(def) {
         Ok(body) => {
             cb(body.clone());
-            body.closures.iter().try_for_each(|&it| for_closure(db, it, &mut cb))
+            body.closures
+                .iter()
+                .try_for_each(|&it| for_closure(db, it, &mut cb))
         }
         Err(e) => Err(e),
     }
@@ -124,7 +126,11 @@ fn make_fetch_closure_field<'db>(
         let (captures, _) = infer.closure_info(c);
         let parent_subst = subst.split_closure_args_untupled().parent_args;
         let interner = DbInterner::new_with(db, None, None);
-        captures.get(f).expect("broken closure field").ty.instantiate(interner, parent_subst)
+        captures
+            .get(f)
+            .expect("broken closure field")
+            .ty
+            .instantiate(interner, parent_subst)
     }
 }
 
@@ -459,7 +465,10 @@ fn ever_initialized_map<'db>(
             match &terminator.kind {
                 TerminatorKind::Goto { target } => process(*target, is_ever_initialized),
                 TerminatorKind::SwitchInt { targets, .. } => {
-                    targets.all_targets().iter().for_each(|&it| process(it, is_ever_initialized));
+                    targets
+                        .all_targets()
+                        .iter()
+                        .for_each(|&it| process(it, is_ever_initialized));
                 }
                 TerminatorKind::UnwindResume
                 | TerminatorKind::Abort
@@ -471,7 +480,10 @@ fn ever_initialized_map<'db>(
                     {
                         is_ever_initialized = true;
                     }
-                    target.iter().chain(cleanup).for_each(|&it| process(it, is_ever_initialized));
+                    target
+                        .iter()
+                        .chain(cleanup)
+                        .for_each(|&it| process(it, is_ever_initialized));
                 }
                 TerminatorKind::Drop { target, unwind, place: _ } => {
                     iter::once(target)
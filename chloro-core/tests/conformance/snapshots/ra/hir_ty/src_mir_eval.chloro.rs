@@ -90,8 +90,7 @@ pub struct VTableMap<'db> {
 }
 
 impl<'db> VTableMap<'db> {
-    const OFFSET: usize = 1000;
-    // We should add some offset to ids to make 0 (null) an invalid id.
+    const OFFSET: usize = 1000; // We should add some offset to ids to make 0 (null) an invalid id.
 
     fn id(&mut self, ty: Ty<'db>) -> usize {
         if let Some(it) = self.ty_to_id.get(&ty) {
@@ -268,7 +267,10 @@ impl<'db> IntervalAndTy<'db> {
         locals: &Locals<'db>,
     ) -> Result<'db, IntervalAndTy<'db>> {
         let size = evaluator.size_of_sized(ty, locals, "type of interval")?;
-        Ok(IntervalAndTy { interval: Interval { addr, size }, ty })
+        Ok(IntervalAndTy {
+            interval: Interval { addr, size },
+            ty,
+        })
     }
 }
 
@@ -360,7 +362,10 @@ pub enum MirEvalError<'db> {
     TypeIsUnsized(Ty<'db>, &'static str),
     NotSupported(String),
     InvalidConst(Const<'db>),
-    InFunction(Box<MirEvalError<'db>>, Vec<(Either<FunctionId, InternedClosureId>, MirSpan, DefWithBodyId)>),
+    InFunction(
+        Box<MirEvalError<'db>>,
+        Vec<(Either<FunctionId, InternedClosureId>, MirSpan, DefWithBodyId)>,
+    ),
     ExecutionLimitExceeded,
     StackOverflow,
     /// FIXME: Fold this into InternalError
@@ -502,26 +507,40 @@ impl std::fmt::Debug for MirEvalError<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::ConstEvalError(arg0, arg1) => {
-                f.debug_tuple("ConstEvalError").field(arg0).field(arg1).finish()
+                f.debug_tuple("ConstEvalError")
+                    .field(arg0)
+                    .field(arg1)
+                    .finish()
             }
             Self::LayoutError(arg0, arg1) => {
-                f.debug_tuple("LayoutError").field(arg0).field(arg1).finish()
+                f.debug_tuple("LayoutError")
+                    .field(arg0)
+                    .field(arg1)
+                    .finish()
             }
             Self::UndefinedBehavior(arg0) => {
                 f.debug_tuple("UndefinedBehavior").field(arg0).finish()
             }
             Self::Panic(msg) => write!(f, "Panic with message:\n{msg:?}"),
             Self::TargetDataLayoutNotAvailable(arg0) => {
-                f.debug_tuple("TargetDataLayoutNotAvailable").field(arg0).finish()
+                f.debug_tuple("TargetDataLayoutNotAvailable")
+                    .field(arg0)
+                    .finish()
             }
             Self::TypeIsUnsized(ty, it) => write!(f, "{ty:?} is unsized. {it} should be sized."),
             Self::ExecutionLimitExceeded => write!(f, "execution limit exceeded"),
             Self::StackOverflow => write!(f, "stack overflow"),
             Self::MirLowerError(arg0, arg1) => {
-                f.debug_tuple("MirLowerError").field(arg0).field(arg1).finish()
+                f.debug_tuple("MirLowerError")
+                    .field(arg0)
+                    .field(arg1)
+                    .finish()
             }
             Self::MirLowerErrorForClosure(arg0, arg1) => {
-                f.debug_tuple("MirLowerError").field(arg0).field(arg1).finish()
+                f.debug_tuple("MirLowerError")
+                    .field(arg0)
+                    .field(arg1)
+                    .finish()
             }
             Self::CoerceUnsizedError(arg0) => {
                 f.debug_tuple("CoerceUnsizedError").field(arg0).finish()
@@ -531,7 +550,10 @@ impl std::fmt::Debug for MirEvalError<'_> {
             Self::NotSupported(arg0) => f.debug_tuple("NotSupported").field(arg0).finish(),
             Self::InvalidConst(arg0) => f.debug_tuple("InvalidConst").field(&arg0).finish(),
             Self::InFunction(e, stack) => {
-                f.debug_struct("WithStack").field("error", e).field("stack", &stack).finish()
+                f.debug_struct("WithStack")
+                    .field("error", e)
+                    .field("stack", &stack)
+                    .finish()
             }
         }
     }
@@ -618,10 +640,15 @@ pub fn interpret_mir<'db>(
         };
         Ok(Const::new_valtree(evaluator.interner(), ty, bytes, memory_map))
     })();
-    Ok((it, MirOutput {
-        stdout: evaluator.stdout,
-        stderr: evaluator.stderr,
-    }))
+    Ok(
+        (
+            it,
+            MirOutput {
+            stdout: evaluator.stdout,
+            stderr: evaluator.stderr,
+        },
+        ),
+    )
 }
 
 #[cfg(test)]
@@ -674,10 +701,12 @@ impl<'db> Evaluator<'db> {
                 .resolve_trait(db, crate_id)
                 .and_then(|x| x.trait_items(db).method_by_name(&Name::new_symbol_root(sym::call))),
             cached_fn_mut_trait_func: LangItem::FnMut.resolve_trait(db, crate_id).and_then(|x| {
-                x.trait_items(db).method_by_name(&Name::new_symbol_root(sym::call_mut))
+                x.trait_items(db)
+                    .method_by_name(&Name::new_symbol_root(sym::call_mut))
             }),
             cached_fn_once_trait_func: LangItem::FnOnce.resolve_trait(db, crate_id).and_then(|x| {
-                x.trait_items(db).method_by_name(&Name::new_symbol_root(sym::call_once))
+                x.trait_items(db)
+                    .method_by_name(&Name::new_symbol_root(sym::call_once))
             }),
             infcx,
         })
@@ -917,7 +946,12 @@ impl<'db> Evaluator<'db> {
         self.fill_locals_for_body(&body, &mut locals, args)?;
         let prev_code_stack = mem::take(&mut self.code_stack);
         let span = (MirSpan::Unknown, body.owner);
-        self.code_stack.push(StackFrame { locals, destination: None, prev_stack_ptr, span });
+        self.code_stack.push(StackFrame {
+            locals,
+            destination: None,
+            prev_stack_ptr,
+            span,
+        });
         'stack: loop {
             let Some(mut my_stack_frame) = self.code_stack.pop() else {
                 not_supported!("missing stack frame");
@@ -1470,12 +1504,14 @@ impl<'db> Evaluator<'db> {
                     }
                     AggregateKind::Tuple(ty) => {
                         let layout = self.layout(*ty)?;
-                        Owned(self.construct_with_layout(
-                            layout.size.bytes_usize(),
-                            &layout,
-                            None,
-                            values.iter().map(|&it| it.into()),
-                        )?)
+                        Owned(
+                            self.construct_with_layout(
+                                layout.size.bytes_usize(),
+                                &layout,
+                                None,
+                                values.iter().map(|&it| it.into()),
+                            )?,
+                        )
                     }
                     AggregateKind::Union(it, f) => {
                         let layout = self.layout_adt(
@@ -1494,21 +1530,25 @@ impl<'db> Evaluator<'db> {
                     AggregateKind::Adt(it, subst) => {
                         let (size, variant_layout, tag) =
                             self.layout_of_variant(*it, *subst, locals)?;
-                        Owned(self.construct_with_layout(
-                            size,
-                            &variant_layout,
-                            tag,
-                            values.iter().map(|&it| it.into()),
-                        )?)
+                        Owned(
+                            self.construct_with_layout(
+                                size,
+                                &variant_layout,
+                                tag,
+                                values.iter().map(|&it| it.into()),
+                            )?,
+                        )
                     }
                     AggregateKind::Closure(ty) => {
                         let layout = self.layout(*ty)?;
-                        Owned(self.construct_with_layout(
-                            layout.size.bytes_usize(),
-                            &layout,
-                            None,
-                            values.iter().map(|&it| it.into()),
-                        )?)
+                        Owned(
+                            self.construct_with_layout(
+                                layout.size.bytes_usize(),
+                                &layout,
+                                None,
+                                values.iter().map(|&it| it.into()),
+                            )?,
+                        )
                     }
                 }
             }
@@ -1537,7 +1577,10 @@ impl<'db> Evaluator<'db> {
                     }
                     PointerCast::ArrayToPointer => {
                         // We should remove the metadata part if the current type is slice
-                        Borrowed(self.eval_operand(operand, locals)?.slice(0..self.ptr_size()))
+                        Borrowed(
+                            self.eval_operand(operand, locals)?
+                                .slice(0..self.ptr_size()),
+                        )
                     }
                 },
                 CastKind::DynStar => not_supported!("dyn star cast"),
@@ -1844,14 +1887,16 @@ impl<'db> Evaluator<'db> {
                     layout.size.bytes_usize(),
                     Arc::new(variant_layout),
                     if have_tag {
-                        Some((
+                    Some(
+                        (
                             layout.fields.offset(0).bytes_usize(),
                             tag.size(&*self.target_data_layout).bytes_usize(),
                             discriminant,
-                        ))
-                    } else {
-                        None
-                    },
+                        ),
+                    )
+                } else {
+                    None
+                },
                 )
             }
         })
@@ -1900,7 +1945,9 @@ impl<'db> Evaluator<'db> {
     ) -> Result<'db, Interval> {
         Ok(match &it.kind {
             OperandKind::Copy(p) | OperandKind::Move(p) => {
-                locals.drop_flags.remove_place(p, &locals.body.projection_store);
+                locals
+                    .drop_flags
+                    .remove_place(p, &locals.body.projection_store);
                 self.eval_place(p, locals)?
             }
             OperandKind::Static(st) => {
@@ -2026,7 +2073,8 @@ impl<'db> Evaluator<'db> {
         if r.is_empty() {
             return Ok(());
         }
-        self.write_memory_using_ref(addr, r.len())?.copy_from_slice(r);
+        self.write_memory_using_ref(addr, r.len())?
+            .copy_from_slice(r);
         Ok(())
     }
 
@@ -2108,7 +2156,11 @@ impl<'db> Evaluator<'db> {
             return Ok(Some((0, 1)));
         }
         let layout = layout?;
-        Ok(layout.is_sized().then(|| (layout.size.bytes_usize(), layout.align.bytes() as usize)))
+        Ok(
+            layout
+                .is_sized()
+                .then(|| (layout.size.bytes_usize(), layout.align.bytes() as usize)),
+        )
     }
 
     /// A version of `self.size_of` which returns error if the type is unsized. `what` argument should
@@ -2617,7 +2669,9 @@ impl<'db> Evaluator<'db> {
                 })?;
             MirOrDynIndex::Mir(mir_body)
         };
-        self.mir_or_dyn_index_cache.borrow_mut().insert((def, generic_args), r.clone());
+        self.mir_or_dyn_index_cache
+            .borrow_mut()
+            .insert((def, generic_args), r.clone());
         Ok(r)
     }
 
@@ -2678,15 +2732,7 @@ impl<'db> Evaluator<'db> {
                     span,
                 )
             }
-            MirOrDynIndex::Mir(body) => self.exec_looked_up_function(
-                body,
-                locals,
-                def,
-                arg_bytes,
-                span,
-                destination,
-                target_bb,
-            ),
+            MirOrDynIndex::Mir(body) => self.exec_looked_up_function(body, locals, def, arg_bytes, span, destination, target_bb),
         }
     }
 
@@ -2705,7 +2751,12 @@ impl<'db> Evaluator<'db> {
                 self.create_locals_for_body(&mir_body, Some(destination))?;
             self.fill_locals_for_body(&mir_body, &mut locals, arg_bytes.into_iter())?;
             let span = (span, locals.body.owner);
-            Some(StackFrame { locals, destination: Some(target_bb), prev_stack_ptr, span })
+            Some(StackFrame {
+                locals,
+                destination: Some(target_bb),
+                prev_stack_ptr,
+                span,
+            })
         } else {
             let result = self.interpret_mir(mir_body, arg_bytes).map_err(|e| {
                 MirEvalError::InFunction(
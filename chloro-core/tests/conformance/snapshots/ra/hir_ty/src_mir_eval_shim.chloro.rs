@@ -320,9 +320,9 @@ impl<'db> Evaluator<'db> {
             BeginPanic => {
                 let mut arg = args
                     .next()
-                    .ok_or(MirEvalError::InternalError(
-                        "argument of BeginPanic is not provided".into(),
-                    ))?
+                    .ok_or(
+                        MirEvalError::InternalError("argument of BeginPanic is not provided".into()),
+                    )?
                     .clone();
                 while let TyKind::Ref(_, ty, _) = arg.ty.kind() {
                     if ty.is_str() {
@@ -349,22 +349,24 @@ impl<'db> Evaluator<'db> {
                 Err(MirEvalError::Panic(format!("unknown-panic-payload: {:?}", arg.ty.kind())))
             }
             SliceLen => {
-                let arg = args.next().ok_or(MirEvalError::InternalError(
-                    "argument of <[T]>::len() is not provided".into(),
-                ))?;
+                let arg = args.next().ok_or(
+                    MirEvalError::InternalError("argument of <[T]>::len() is not provided".into()),
+                )?;
                 let arg = arg.get(self)?;
                 let ptr_size = arg.len() / 2;
                 Ok(arg[ptr_size..].into())
             }
             DropInPlace => {
-                let ty = generic_args.as_slice().first().and_then(|it| it.ty()).ok_or(
-                    MirEvalError::InternalError(
+                let ty = generic_args
+                    .as_slice()
+                    .first()
+                    .and_then(|it| it.ty())
+                    .ok_or(MirEvalError::InternalError(
                         "generic argument of drop_in_place is not provided".into(),
-                    ),
+                    ))?;
+                let arg = args.next().ok_or(
+                    MirEvalError::InternalError("argument of drop_in_place is not provided".into()),
                 )?;
-                let arg = args.next().ok_or(MirEvalError::InternalError(
-                    "argument of drop_in_place is not provided".into(),
-                ))?;
                 let arg = arg.interval.get(self)?.to_owned();
                 self.run_drop_glue_deep(
                     ty,
@@ -534,7 +536,8 @@ impl<'db> Evaluator<'db> {
                 // Only enable core 0 (we are single threaded anyway), which is bitset 0x0000001
                 self.write_memory(set, &[1])?;
                 // return 0 as success
-                self.write_memory_using_ref(destination.addr, destination.size)?.fill(0);
+                self.write_memory_using_ref(destination.addr, destination.size)?
+                    .fill(0);
                 Ok(())
             }
             "getenv" => {
@@ -823,7 +826,9 @@ impl<'db> Evaluator<'db> {
                 let len = ty_name.len();
                 let addr = self.heap_allocate(len, 1)?;
                 self.write_memory(addr, ty_name.as_bytes())?;
-                destination.slice(0..self.ptr_size()).write_from_bytes(self, &addr.to_bytes())?;
+                destination
+                    .slice(0..self.ptr_size())
+                    .write_from_bytes(self, &addr.to_bytes())?;
                 destination
                     .slice(self.ptr_size()..2 * self.ptr_size())
                     .write_from_bytes(self, &len.to_le_bytes())
@@ -1101,10 +1106,7 @@ impl<'db> Evaluator<'db> {
                 };
                 let result = u128::from_le_bytes(pad16(arg.get(self)?, false)).count_ones();
                 destination
-                .write_from_bytes(
-                    self,
-                    &(result as u128).to_le_bytes()[0..destination.size],
-                )
+                    .write_from_bytes(self, &(result as u128).to_le_bytes()[0..destination.size])
             }
             "ctlz" | "ctlz_nonzero" => {
                 let [arg] = args else {
@@ -1114,10 +1116,7 @@ impl<'db> Evaluator<'db> {
                     u128::from_le_bytes(pad16(arg.get(self)?, false)).leading_zeros() as usize;
                 let result = result - (128 - arg.interval.size * 8);
                 destination
-                .write_from_bytes(
-                    self,
-                    &(result as u128).to_le_bytes()[0..destination.size],
-                )
+                    .write_from_bytes(self, &(result as u128).to_le_bytes()[0..destination.size])
             }
             "cttz" | "cttz_nonzero" => {
                 let [arg] = args else {
@@ -1125,10 +1124,7 @@ impl<'db> Evaluator<'db> {
                 };
                 let result = u128::from_le_bytes(pad16(arg.get(self)?, false)).trailing_zeros();
                 destination
-                .write_from_bytes(
-                    self,
-                    &(result as u128).to_le_bytes()[0..destination.size],
-                )
+                    .write_from_bytes(self, &(result as u128).to_le_bytes()[0..destination.size])
             }
             "rotate_left" => {
                 let [lhs, rhs] = args else {
@@ -1255,7 +1251,10 @@ impl<'db> Evaluator<'db> {
                     ));
                 };
                 let addr = Address::from_bytes(arg.interval.get(self)?)?;
-                destination.write_from_interval(self, Interval { addr, size: destination.size })
+                destination.write_from_interval(self, Interval {
+                    addr,
+                    size: destination.size,
+                })
             }
             "write_via_move" => {
                 let [ptr, val] = args else {
@@ -1365,9 +1364,7 @@ impl<'db> Evaluator<'db> {
             _ if needs_override => not_supported!("intrinsic {name} is not implemented"),
             _ => return Ok(false),
         }
-        .map(
-            |()| true,
-        )
+        .map(|()| true)
     }
 
     fn size_align_of_unsized(
@@ -99,7 +99,10 @@ impl<'db> Evaluator<'db> {
                 let size = left.interval.size / len;
                 let dest_size = destination.size / len;
                 let mut destination_bytes = vec![];
-                let vector = left.get(self)?.chunks(size).zip(right.get(self)?.chunks(size));
+                let vector = left
+                    .get(self)?
+                    .chunks(size)
+                    .zip(right.get(self)?.chunks(size));
                 for (l, r) in vector {
                     let mut result = Ordering::Equal;
                     for (l, r) in l.iter().zip(r).rev() {
@@ -16,9 +16,7 @@ use crate::{
 use super::{MirEvalError, interpret_mir};
 
 fn eval_main(db: &TestDB, file_id: EditionedFileId) -> Result<(String, String), MirEvalError<'_>> {
-    crate::attach_db(
-        db,
-        || {
+    crate::attach_db(db, || {
         let interner = DbInterner::new_with(db, None, None);
         let module_id = db.module_for_file(file_id.file_id(db));
         let def_map = module_id.def_map(db);
@@ -49,8 +47,7 @@ fn eval_main(db: &TestDB, file_id: EditionedFileId) -> Result<(String, String),
         let (result, output) = interpret_mir(db, body, false, None)?;
         result?;
         Ok((output.stdout().into_owned(), output.stderr().into_owned()))
-    },
-    )
+    })
 }
 
 fn check_pass(#[rust_analyzer::rust_fixture] ra_fixture: &str) {
@@ -64,9 +61,7 @@ fn check_pass_and_stdio(
 ) {
     let _tracing = setup_tracing();
     let (db, file_ids) = TestDB::with_many_files(ra_fixture);
-    crate::attach_db(
-        &db,
-        || {
+    crate::attach_db(&db, || {
         let file_id = *file_ids.last().unwrap();
         let x = eval_main(&db, file_id);
         match x {
@@ -93,13 +88,8 @@ fn check_pass_and_stdio(
                     )
                 };
                 let krate = db.module_for_file(file_id.file_id(&db)).krate();
-                e.pretty_print(
-                    &mut err,
-                    &db,
-                    span_formatter,
-                    DisplayTarget::from_crate(&db, krate),
-                )
-                .unwrap();
+                e.pretty_print(&mut err, &db, span_formatter, DisplayTarget::from_crate(&db, krate))
+                    .unwrap();
                 panic!("Error in interpreting: {err}");
             }
             Ok((stdout, stderr)) => {
@@ -107,23 +97,19 @@ fn check_pass_and_stdio(
                 assert_eq!(stderr, expected_stderr);
             }
         }
-    },
-    )
+    })
 }
 
 fn check_panic(#[rust_analyzer::rust_fixture] ra_fixture: &str, expected_panic: &str) {
     let (db, file_ids) = TestDB::with_many_files(ra_fixture);
-    crate::attach_db(
-        &db,
-        || {
+    crate::attach_db(&db, || {
         let file_id = *file_ids.last().unwrap();
         let e = eval_main(&db, file_id).unwrap_err();
         assert_eq!(
             e.is_panic().unwrap_or_else(|| panic!("unexpected error: {e:?}")),
             expected_panic
         );
-    },
-    )
+    })
 }
 
 fn check_error_with(
@@ -131,14 +117,11 @@ fn check_error_with(
     expect_err: impl FnOnce(MirEvalError<'_>) -> bool,
 ) {
     let (db, file_ids) = TestDB::with_many_files(ra_fixture);
-    crate::attach_db(
-        &db,
-        || {
+    crate::attach_db(&db, || {
         let file_id = *file_ids.last().unwrap();
         let e = eval_main(&db, file_id).unwrap_err();
         assert!(expect_err(e));
-    },
-    )
+    })
 }
 
 #[test]
@@ -811,7 +794,8 @@ fn main() {
 
 #[test]
 fn syscalls() {
-    check_pass(r#"
+    check_pass(
+        r#"
 //- minicore: option
 
 extern "C" {
@@ -832,7 +816,8 @@ fn main() {
     }
 }
 
-"#)
+"#,
+    )
 }
 
 #[test]
@@ -84,6 +84,7 @@ struct MirLowerCtx<'a, 'db> {
     infcx: InferCtxt<'db>,
 }
 
+// FIXME: Make this smaller, its stored in database queries
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MirLowerError<'db> {
     ConstEvalError(Box<str>, Box<ConstEvalError<'db>>),
@@ -267,7 +268,9 @@ impl MirLowerError<'_> {
         store: &ExpressionStore,
     ) -> Self {
         Self::UnresolvedName(
-            hir_display_with_store(p, store).display(db, display_target).to_string(),
+            hir_display_with_store(p, store)
+                .display(db, display_target)
+                .to_string(),
         )
     }
 }
@@ -354,13 +357,15 @@ impl<'a, 'db> MirLowerCtx<'a, 'db> {
         let Some((p, current)) = self.lower_expr_as_place(current, expr_id, true)? else {
             return Ok(None);
         };
-        Ok(Some((
-            Operand {
-            kind: OperandKind::Copy(p),
-            span: Some(expr_id.into()),
-        },
-            current,
-        )))
+        Ok(Some(
+            (
+                Operand {
+                kind: OperandKind::Copy(p),
+                span: Some(expr_id.into()),
+            },
+                current,
+            ),
+        ))
     }
 
     fn lower_expr_to_place_with_adjust(
@@ -634,12 +639,7 @@ impl<'a, 'db> MirLowerCtx<'a, 'db> {
             }
             Expr::Block { id: _, statements, tail, label } => {
                 if let Some(label) = label {
-                    self.lower_loop(
-                        current,
-                        place,
-                        Some(*label),
-                        expr_id.into(),
-                        |this, begin| {
+                    self.lower_loop(current, place, Some(*label), expr_id.into(), |this, begin| {
                         if let Some(current) = this.lower_block_to_place(
                             statements,
                             begin,
@@ -651,19 +651,13 @@ impl<'a, 'db> MirLowerCtx<'a, 'db> {
                             this.set_goto(current, end, expr_id.into());
                         }
                         Ok(())
-                    },
-                    )
+                    })
                 } else {
                     self.lower_block_to_place(statements, current, *tail, place, expr_id.into())
                 }
             }
             Expr::Loop { body, label } => {
-                self.lower_loop(
-                    current,
-                    place,
-                    *label,
-                    expr_id.into(),
-                    |this, begin| {
+                self.lower_loop(current, place, *label, expr_id.into(), |this, begin| {
                     let scope = this.push_drop_scope();
                     if let Some((_, mut current)) = this.lower_expr_as_place(begin, *body, true)? {
                         current = scope.pop_and_drop(this, current, body.into());
@@ -672,8 +666,7 @@ impl<'a, 'db> MirLowerCtx<'a, 'db> {
                         scope.pop_assume_dropped(this);
                     }
                     Ok(())
-                },
-                )
+                })
             }
             Expr::Call { callee, args, .. } => {
                 if let Some((func_id, generic_args)) = self.infer.method_resolution(expr_id) {
@@ -1740,7 +1733,11 @@ impl<'a, 'db> MirLowerCtx<'a, 'db> {
     }
 
     fn has_adjustments(&self, expr_id: ExprId) -> bool {
-        !self.infer.expr_adjustments.get(&expr_id).map(|it| it.is_empty()).unwrap_or(true)
+        !self.infer
+            .expr_adjustments
+            .get(&expr_id)
+            .map(|it| it.is_empty())
+            .unwrap_or(true)
     }
 
     fn merge_blocks(
@@ -1912,10 +1909,11 @@ impl<'a, 'db> MirLowerCtx<'a, 'db> {
             }
             None => None,
         };
-        self.result.param_locals.extend(params.clone().map(|(it, ty)| {
-            let local_id = self.result.locals.alloc(Local { ty });
-            self.drop_scopes.last_mut().unwrap().locals.push(local_id);
-            if let Pat::Bind { id, subpat: None } = self.body[it]
+        self.result.param_locals.extend(
+            params.clone().map(|(it, ty)| {
+                let local_id = self.result.locals.alloc(Local { ty });
+                self.drop_scopes.last_mut().unwrap().locals.push(local_id);
+                if let Pat::Bind { id, subpat: None } = self.body[it]
                 && matches!(
                     self.body[id].mode,
                     BindingAnnotation::Unannotated | BindingAnnotation::Mutable
@@ -1923,8 +1921,9 @@ impl<'a, 'db> MirLowerCtx<'a, 'db> {
             {
                 self.result.binding_locals.insert(id, local_id);
             }
-            local_id
-        }));
+                local_id
+            }),
+        );
         // and then rest of bindings
         for (id, _) in self.body.bindings() {
             if !pick_binding(id) {
@@ -2121,18 +2120,8 @@ pub fn mir_body_for_closure_query<'db>(
     let closure_local = ctx.result.locals.alloc(Local {
         ty: match kind {
             FnTrait::FnOnce | FnTrait::AsyncFnOnce => infer[expr],
-            FnTrait::FnMut | FnTrait::AsyncFnMut => Ty::new_ref(
-                ctx.interner(),
-                Region::error(ctx.interner()),
-                infer[expr],
-                Mutability::Mut,
-            ),
-            FnTrait::Fn | FnTrait::AsyncFn => Ty::new_ref(
-                ctx.interner(),
-                Region::error(ctx.interner()),
-                infer[expr],
-                Mutability::Not,
-            ),
+            FnTrait::FnMut | FnTrait::AsyncFnMut => Ty::new_ref(ctx.interner(), Region::error(ctx.interner()), infer[expr], Mutability::Mut),
+            FnTrait::Fn | FnTrait::AsyncFn => Ty::new_ref(ctx.interner(), Region::error(ctx.interner()), infer[expr], Mutability::Not),
         },
     });
     ctx.result.param_locals.push(closure_local);
@@ -2270,7 +2259,9 @@ pub fn lower_to_mir<'db>(
     }
     let mut ctx = MirLowerCtx::new(db, owner, body, infer);
     // 0 is return local
-    ctx.result.locals.alloc(Local { ty: ctx.expr_ty_after_adjustments(root_expr) });
+    ctx.result.locals.alloc(Local {
+        ty: ctx.expr_ty_after_adjustments(root_expr),
+    });
     let binding_picker = |b: BindingId| {
         let owner = ctx.body.binding_owner(b);
         if root_expr == body.body_expr { owner.is_none() } else { owner == Some(root_expr) }
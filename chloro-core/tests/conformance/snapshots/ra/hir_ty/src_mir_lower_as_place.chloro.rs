@@ -142,7 +142,9 @@ impl<'db> MirLowerCtx<'_, 'db> {
                 let resolver_guard =
                     self.resolver.update_to_inner_scope(self.db, self.owner, expr_id);
                 let hygiene = self.body.expr_path_hygiene(expr_id);
-                let resolved = self.resolver.resolve_path_in_value_ns_fully(self.db, p, hygiene);
+                let resolved = self
+                    .resolver
+                    .resolve_path_in_value_ns_fully(self.db, p, hygiene);
                 self.resolver.reset_to_guard(resolver_guard);
                 let Some(pr) = resolved else {
                     return try_rvalue(self);
@@ -166,10 +168,12 @@ impl<'db> MirLowerCtx<'_, 'db> {
                             Operand { kind: OperandKind::Static(s), span: None }.into(),
                             expr_id.into(),
                         );
-                        Ok(Some((
-                            temp.project(ProjectionElem::Deref, &mut self.result.projection_store),
-                            current,
-                        )))
+                        Ok(Some(
+                            (
+                                temp.project(ProjectionElem::Deref, &mut self.result.projection_store),
+                                current,
+                            ),
+                        ))
                     }
                     _ => try_rvalue(self),
                 }
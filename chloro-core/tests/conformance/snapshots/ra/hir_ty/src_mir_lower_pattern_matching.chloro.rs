@@ -28,9 +28,7 @@ pub(super) enum AdtPatternShape<'a> {
         args: &'a [PatId],
         ellipsis: Option<u32>,
     },
-    Record {
-        args: &'a [RecordFieldPat],
-    },
+    Record { args: &'a [RecordFieldPat] },
     Unit,
 }
 
@@ -125,7 +123,12 @@ impl<'db> MirLowerCtx<'_, 'db> {
         pattern: PatId,
         mode: MatchingMode,
     ) -> Result<'db, (BasicBlockId<'db>, Option<BasicBlockId<'db>>)> {
-        let cnt = self.infer.pat_adjustments.get(&pattern).map(|x| x.len()).unwrap_or_default();
+        let cnt = self
+            .infer
+            .pat_adjustments
+            .get(&pattern)
+            .map(|x| x.len())
+            .unwrap_or_default();
         cond_place.projection = self.result.projection_store.intern(
             cond_place
                 .projection
@@ -476,7 +479,10 @@ impl<'db> MirLowerCtx<'_, 'db> {
                     current,
                     pattern.into(),
                     current_else,
-                    AdtPatternShape::Tuple { args, ellipsis: *ellipsis },
+                    AdtPatternShape::Tuple {
+                        args,
+                        ellipsis: *ellipsis,
+                    },
                     mode,
                 )?
             }
@@ -652,20 +658,25 @@ impl<'db> MirLowerCtx<'_, 'db> {
                     .map(|x| {
                         let field_id =
                             variant_data.field(&x.name).ok_or(MirLowerError::UnresolvedField)?;
-                        Ok((
-                            PlaceElem::Field(Either::Left(FieldId {
+                        Ok(
+                            (
+                                PlaceElem::Field(Either::Left(FieldId {
                                 parent: v,
                                 local_id: field_id,
                             })),
-                            x.pat,
-                        ))
+                                x.pat,
+                            ),
+                        )
                     })
                     .collect::<Result<'db, Vec<_>>>()?;
                 self.pattern_match_adt(current, current_else, it.into_iter(), cond_place, mode)?
             }
             AdtPatternShape::Tuple { args, ellipsis } => {
                 let fields = variant_data.fields().iter().map(|(x, _)| {
-                    PlaceElem::Field(Either::Left(FieldId { parent: v, local_id: x }))
+                    PlaceElem::Field(Either::Left(FieldId {
+                        parent: v,
+                        local_id: x,
+                    }))
                 });
                 self.pattern_match_tuple_like(
                     current,
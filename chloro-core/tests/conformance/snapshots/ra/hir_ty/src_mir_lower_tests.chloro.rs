@@ -5,9 +5,7 @@ use crate::{db::HirDatabase, setup_tracing, test_db::TestDB};
 fn lower_mir(#[rust_analyzer::rust_fixture] ra_fixture: &str) {
     let _tracing = setup_tracing();
     let (db, file_ids) = TestDB::with_many_files(ra_fixture);
-    crate::attach_db(
-        &db,
-        || {
+    crate::attach_db(&db, || {
         let file_id = *file_ids.last().unwrap();
         let module_id = db.module_for_file(file_id.file_id(&db));
         let def_map = module_id.def_map(&db);
@@ -19,8 +17,7 @@ fn lower_mir(#[rust_analyzer::rust_fixture] ra_fixture: &str) {
         for func in funcs {
             _ = db.mir_body(func.into());
         }
-    },
-    )
+    })
 }
 
 #[test]
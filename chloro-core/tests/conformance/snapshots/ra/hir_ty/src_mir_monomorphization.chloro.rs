@@ -56,14 +56,15 @@ impl<'db> FallibleTypeFolder<DbInterner<'db>> for Filler<'db> {
                     .map_err(|_| MirLowerError::NotSupported("can't normalize alias".to_owned()))?;
                 ty.try_super_fold_with(self)
             }
-            TyKind::Param(param) => Ok(self
-                .subst
-                .as_slice()
-                .get(param.index as usize)
-                .and_then(|arg| arg.ty())
-                .ok_or_else(|| {
-                    MirLowerError::GenericArgNotProvided(param.id.into(), self.subst)
-                })?),
+            TyKind::Param(param) => Ok(
+                self.subst
+                    .as_slice()
+                    .get(param.index as usize)
+                    .and_then(|arg| arg.ty())
+                    .ok_or_else(|| {
+                        MirLowerError::GenericArgNotProvided(param.id.into(), self.subst)
+                    })?,
+            ),
             _ => ty.try_super_fold_with(self),
         }
     }
@@ -99,7 +100,11 @@ impl<'db> Filler<'db> {
     ) -> Self {
         let interner = DbInterner::new_with(db, Some(env.krate), env.block);
         let infcx = interner.infer_ctxt().build(TypingMode::PostAnalysis);
-        Self { infcx, trait_env: env, subst }
+        Self {
+            infcx,
+            trait_env: env,
+            subst,
+        }
     }
 
     fn fill<T: TypeFoldable<DbInterner<'db>> + Copy>(
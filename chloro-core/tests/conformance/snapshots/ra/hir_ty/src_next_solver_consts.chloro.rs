@@ -46,7 +46,9 @@ impl<'db> Const<'db> {
             let inner = &self.kind_(db).0;
             // SAFETY: The caller already has access to a `Const<'db>`, so borrowchecking will
             // make sure that our returned value is valid for the lifetime `'db`.
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 
@@ -72,13 +74,10 @@ impl<'db> Const<'db> {
         memory: Box<[u8]>,
         memory_map: MemoryMap<'db>,
     ) -> Self {
-        Const::new(
-            interner,
-            ConstKind::Value(ValueConst {
-                ty,
-                value: Valtree::new(ConstBytes { memory, memory_map }),
-            }),
-        )
+        Const::new(interner, ConstKind::Value(ValueConst {
+            ty,
+            value: Valtree::new(ConstBytes { memory, memory_map }),
+        }))
     }
 
     pub fn is_ct_infer(&self) -> bool {
@@ -222,7 +221,9 @@ impl<'db> Valtree<'db> {
             let inner = self.bytes_(db);
             // SAFETY: The caller already has access to a `Valtree<'db>`, so borrowchecking will
             // make sure that our returned value is valid for the lifetime `'db`.
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 }
@@ -40,30 +40,45 @@ impl std::fmt::Debug for SolverDefId {
         let db = interner.db;
         match *self {
             SolverDefId::AdtId(AdtId::StructId(id)) => {
-                f.debug_tuple("AdtId").field(&db.struct_signature(id).name.as_str()).finish()
+                f.debug_tuple("AdtId")
+                    .field(&db.struct_signature(id).name.as_str())
+                    .finish()
             }
             SolverDefId::AdtId(AdtId::EnumId(id)) => {
-                f.debug_tuple("AdtId").field(&db.enum_signature(id).name.as_str()).finish()
+                f.debug_tuple("AdtId")
+                    .field(&db.enum_signature(id).name.as_str())
+                    .finish()
             }
             SolverDefId::AdtId(AdtId::UnionId(id)) => {
-                f.debug_tuple("AdtId").field(&db.union_signature(id).name.as_str()).finish()
+                f.debug_tuple("AdtId")
+                    .field(&db.union_signature(id).name.as_str())
+                    .finish()
             }
-            SolverDefId::ConstId(id) => f
-                .debug_tuple("ConstId")
-                .field(&db.const_signature(id).name.as_ref().map_or("_", |name| name.as_str()))
+            SolverDefId::ConstId(id) => f.debug_tuple("ConstId")
+                .field(
+                    &db.const_signature(id)
+                        .name
+                        .as_ref()
+                        .map_or("_", |name| name.as_str()),
+                )
                 .finish(),
             SolverDefId::FunctionId(id) => {
-                f.debug_tuple("FunctionId").field(&db.function_signature(id).name.as_str()).finish()
+                f.debug_tuple("FunctionId")
+                    .field(&db.function_signature(id).name.as_str())
+                    .finish()
             }
             SolverDefId::ImplId(id) => f.debug_tuple("ImplId").field(&id).finish(),
             SolverDefId::StaticId(id) => {
-                f.debug_tuple("StaticId").field(&db.static_signature(id).name.as_str()).finish()
+                f.debug_tuple("StaticId")
+                    .field(&db.static_signature(id).name.as_str())
+                    .finish()
             }
             SolverDefId::TraitId(id) => {
-                f.debug_tuple("TraitId").field(&db.trait_signature(id).name.as_str()).finish()
+                f.debug_tuple("TraitId")
+                    .field(&db.trait_signature(id).name.as_str())
+                    .finish()
             }
-            SolverDefId::TypeAliasId(id) => f
-                .debug_tuple("TypeAliasId")
+            SolverDefId::TypeAliasId(id) => f.debug_tuple("TypeAliasId")
                 .field(&db.type_alias_signature(id).name.as_str())
                 .finish(),
             SolverDefId::InternedClosureId(id) => {
@@ -86,7 +101,9 @@ impl std::fmt::Debug for SolverDefId {
                     .finish()
             }
             SolverDefId::Ctor(Ctor::Struct(id)) => {
-                f.debug_tuple("Ctor").field(&db.struct_signature(id).name.as_str()).finish()
+                f.debug_tuple("Ctor")
+                    .field(&db.struct_signature(id).name.as_str())
+                    .finish()
             }
             SolverDefId::Ctor(Ctor::Enum(id)) => {
                 let parent_enum = id.loc(db).parent;
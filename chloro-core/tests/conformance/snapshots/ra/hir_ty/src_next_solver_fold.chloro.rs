@@ -58,7 +58,11 @@ pub(crate) struct BoundVarReplacer<'db, D> {
 
 impl<'db, D: BoundVarReplacerDelegate<'db>> BoundVarReplacer<'db, D> {
     pub(crate) fn new(tcx: DbInterner<'db>, delegate: D) -> Self {
-        BoundVarReplacer { interner: tcx, current_index: DebruijnIndex::ZERO, delegate }
+        BoundVarReplacer {
+            interner: tcx,
+            current_index: DebruijnIndex::ZERO,
+            delegate,
+        }
     }
 }
 
@@ -135,7 +135,9 @@ impl<'db> FulfillmentCtxt<'db> {
     ) {
         // FIXME: See the comment in `try_evaluate_obligations()`.
         // assert_eq!(self.usable_in_snapshot, infcx.num_open_snapshots());
-        obligations.into_iter().for_each(|obligation| self.obligations.register(obligation, None));
+        obligations
+            .into_iter()
+            .for_each(|obligation| self.obligations.register(obligation, None));
     }
 
     pub(crate) fn collect_remaining_errors(
@@ -146,7 +148,12 @@ impl<'db> FulfillmentCtxt<'db> {
             .pending
             .drain(..)
             .map(|(obligation, _)| NextSolverError::Ambiguity(obligation))
-            .chain(self.obligations.overflowed.drain(..).map(NextSolverError::Overflow))
+            .chain(
+                self.obligations
+                    .overflowed
+                    .drain(..)
+                    .map(NextSolverError::Overflow),
+            )
             .collect()
     }
 
@@ -254,13 +261,10 @@ impl<'db> FulfillmentCtxt<'db> {
             .drain_pending(|obl| {
                 infcx.probe(|_| {
                     infcx
-                        .visit_proof_tree(
-                            obl.as_goal(),
-                            &mut StalledOnCoroutines {
-                                stalled_coroutines,
-                                cache: Default::default(),
-                            },
-                        )
+                        .visit_proof_tree(obl.as_goal(), &mut StalledOnCoroutines {
+                            stalled_coroutines,
+                            cache: Default::default(),
+                        })
                         .is_break()
                 })
             })
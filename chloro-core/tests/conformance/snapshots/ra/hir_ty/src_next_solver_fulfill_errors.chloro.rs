@@ -43,7 +43,11 @@ impl<'db> FulfillmentError<'db> {
         code: FulfillmentErrorCode<'db>,
         root_obligation: PredicateObligation<'db>,
     ) -> FulfillmentError<'db> {
-        FulfillmentError { obligation, code, root_obligation }
+        FulfillmentError {
+            obligation,
+            code,
+            root_obligation,
+        }
     }
 
     pub fn is_true_error(&self) -> bool {
@@ -139,7 +143,11 @@ pub(super) fn fulfillment_error_for_no_solution<'db>(
         }
     };
 
-    FulfillmentError { obligation, code, root_obligation }
+    FulfillmentError {
+        obligation,
+        code,
+        root_obligation,
+    }
 }
 
 pub(super) fn fulfillment_error_for_stalled<'db>(
@@ -206,7 +214,9 @@ pub(super) fn fulfillment_error_for_overflow<'db>(
 ) -> FulfillmentError<'db> {
     FulfillmentError {
         obligation: find_best_leaf_obligation(infcx, &root_obligation, true),
-        code: FulfillmentErrorCode::Ambiguity { overflow: Some(true) },
+        code: FulfillmentErrorCode::Ambiguity {
+            overflow: Some(true),
+        },
         root_obligation,
     }
 }
@@ -226,10 +236,10 @@ fn find_best_leaf_obligation<'db>(
     let obligation = infcx
         .fudge_inference_if_ok(|| {
             infcx
-                .visit_proof_tree(
-                    obligation.as_goal(),
-                    &mut BestObligation { obligation: obligation.clone(), consider_ambiguities },
-                )
+                .visit_proof_tree(obligation.as_goal(), &mut BestObligation {
+                    obligation: obligation.clone(),
+                    consider_ambiguities,
+                })
                 .break_value()
                 .ok_or(())
         })
@@ -349,16 +359,13 @@ impl<'db> BestObligation<'db> {
                 goal.goal().param_env,
                 pred,
             );
-            self.with_derived_obligation(
-                obligation,
-                |this| {
+            self.with_derived_obligation(obligation, |this| {
                 goal.infcx().visit_proof_tree_at_depth(
                     goal.goal().with(interner, pred),
                     goal.depth() + 1,
                     this,
                 )
-            },
-            )
+            })
         } else {
             ControlFlow::Continue(())
         }
@@ -386,16 +393,13 @@ impl<'db> BestObligation<'db> {
                 goal.goal().param_env,
                 deeply_normalize_for_diagnostics(goal.infcx(), goal.goal().param_env, pred),
             );
-            self.with_derived_obligation(
-                obligation,
-                |this| {
+            self.with_derived_obligation(obligation, |this| {
                 goal.infcx().visit_proof_tree_at_depth(
                     goal.goal().with(interner, pred),
                     goal.depth() + 1,
                     this,
                 )
-            },
-            )
+            })
         } else {
             ControlFlow::Continue(())
         }
@@ -419,16 +423,13 @@ impl<'db> BestObligation<'db> {
             goal.goal().param_env,
             alias.trait_ref(interner),
         );
-        self.with_derived_obligation(
-            obligation,
-            |this| {
+        self.with_derived_obligation(obligation, |this| {
             goal.infcx().visit_proof_tree_at_depth(
                 goal.goal().with(interner, alias.trait_ref(interner)),
                 goal.depth() + 1,
                 this,
             )
-        },
-        )
+        })
     }
 
     /// If we have no candidates, then it's likely that there is a
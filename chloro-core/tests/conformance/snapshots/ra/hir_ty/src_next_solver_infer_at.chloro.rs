@@ -55,7 +55,11 @@ pub struct At<'a, 'db> {
 impl<'db> InferCtxt<'db> {
     #[inline]
     pub fn at<'a>(&'a self, cause: &'a ObligationCause, param_env: ParamEnv<'db>) -> At<'a, 'db> {
-        At { infcx: self, cause, param_env }
+        At {
+            infcx: self,
+            cause,
+            param_env,
+        }
     }
 
     /// Forks the inference context, creating a new inference context with the same inference
@@ -108,9 +112,7 @@ impl<'a, 'db> At<'a, 'db> {
             actual,
             Span::dummy(),
         )
-        .map(
-            |goals| self.goals_to_obligations(goals),
-        )
+        .map(|goals| self.goals_to_obligations(goals))
     }
 
     /// Makes `expected <: actual`.
@@ -126,9 +128,7 @@ impl<'a, 'db> At<'a, 'db> {
             actual,
             Span::dummy(),
         )
-        .map(
-            |goals| self.goals_to_obligations(goals),
-        )
+        .map(|goals| self.goals_to_obligations(goals))
     }
 
     /// Makes `expected == actual`.
@@ -144,9 +144,7 @@ impl<'a, 'db> At<'a, 'db> {
             actual,
             Span::dummy(),
         )
-        .map(
-            |goals| self.goals_to_obligations(goals),
-        )
+        .map(|goals| self.goals_to_obligations(goals))
     }
 
     pub fn relate<T>(self, expected: T, variance: Variance, actual: T) -> InferResult<'db, ()>
@@ -192,7 +190,10 @@ impl<'a, 'db> At<'a, 'db> {
             LatticeOpKind::Lub,
         );
         let value = op.relate(expected, actual)?;
-        Ok(InferOk { value, obligations: op.into_obligations() })
+        Ok(InferOk {
+            value,
+            obligations: op.into_obligations(),
+        })
     }
 
     fn goals_to_obligations(&self, goals: Vec<Goal<'db, Predicate<'db>>>) -> InferOk<'db, ()> {
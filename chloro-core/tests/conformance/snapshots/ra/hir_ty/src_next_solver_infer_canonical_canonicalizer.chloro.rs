@@ -43,7 +43,10 @@ impl<'db> Default for OriginalQueryValues<'db> {
         let mut universe_map = SmallVec::default();
         universe_map.push(UniverseIndex::ROOT);
 
-        Self { universe_map, var_values: SmallVec::default() }
+        Self {
+            universe_map,
+            var_values: SmallVec::default(),
+        }
     }
 }
 
@@ -91,7 +94,10 @@ impl<'db> InferCtxt<'db> {
             query_state,
         )
         .unchecked_map(|(param_env, value)| ParamEnvAnd { param_env, value });
-        CanonicalQueryInput { canonical, typing_mode: self.typing_mode() }
+        CanonicalQueryInput {
+            canonical,
+            typing_mode: self.typing_mode(),
+        }
     }
 
     /// Canonicalizes a query *response* `V`. When we canonicalize a
@@ -198,10 +204,7 @@ impl CanonicalizeMode for CanonicalizeQueryResponse {
             | RegionKind::ReError(..) => r,
 
             RegionKind::RePlaceholder(placeholder) => canonicalizer
-            .canonical_var_for_region(
-                CanonicalVarKind::PlaceholderRegion(placeholder),
-                r,
-            ),
+                .canonical_var_for_region(CanonicalVarKind::PlaceholderRegion(placeholder), r),
 
             RegionKind::ReVar(vid) => {
                 let universe = infcx
@@ -548,9 +551,7 @@ impl<'cx, 'db> Canonicalizer<'cx, 'db> {
             canonicalize_region_mode,
             query_state,
         )
-        .unchecked_map(
-            |((), val)| val,
-        )
+        .unchecked_map(|((), val)| val)
     }
 
     fn canonicalize_with_base<U, V>(
@@ -611,7 +612,11 @@ impl<'cx, 'db> Canonicalizer<'cx, 'db> {
             .max()
             .unwrap_or(UniverseIndex::ROOT);
 
-        Canonical { max_universe, variables: canonical_variables, value: (base.value, out_value) }
+        Canonical {
+            max_universe,
+            variables: canonical_variables,
+            value: (base.value, out_value),
+        }
     }
 
     /// Creates a canonical variable replacing `kind` from the input,
@@ -43,7 +43,11 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
     }
 
     fn universe_of_lt(&self, lt: RegionVid) -> Option<UniverseIndex> {
-        self.inner.borrow_mut().unwrap_region_constraints().probe_value(lt).err()
+        self.inner
+            .borrow_mut()
+            .unwrap_region_constraints()
+            .probe_value(lt)
+            .err()
     }
 
     fn universe_of_ct(&self, ct: ConstVid) -> Option<UniverseIndex> {
@@ -100,7 +104,8 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
                 if let TyKind::Infer(infer_ty) = ty.kind() {
                     match infer_ty {
                         InferTy::TyVar(vid) => {
-                            !self.probe_ty_var(vid).is_err_and(|_| self.root_var(vid) == vid)
+                            !self.probe_ty_var(vid)
+                                .is_err_and(|_| self.root_var(vid) == vid)
                         }
                         InferTy::IntVar(vid) => {
                             let mut inner = self.inner.borrow_mut();
@@ -129,8 +134,7 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
             GenericArgKind::Const(ct) => {
                 if let ConstKind::Infer(infer_ct) = ct.kind() {
                     match infer_ct {
-                        InferConst::Var(vid) => !self
-                            .probe_const_var(vid)
+                        InferConst::Var(vid) => !self.probe_const_var(vid)
                             .is_err_and(|_| self.root_const_var(vid) == vid),
                         InferConst::Fresh(_) => true,
                     }
@@ -181,11 +185,17 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
     }
 
     fn equate_float_vids_raw(&self, a: rustc_type_ir::FloatVid, b: rustc_type_ir::FloatVid) {
-        self.inner.borrow_mut().float_unification_table().union(a, b);
+        self.inner
+            .borrow_mut()
+            .float_unification_table()
+            .union(a, b);
     }
 
     fn equate_const_vids_raw(&self, a: rustc_type_ir::ConstVid, b: rustc_type_ir::ConstVid) {
-        self.inner.borrow_mut().const_unification_table().union(a, b);
+        self.inner
+            .borrow_mut()
+            .const_unification_table()
+            .union(a, b);
     }
 
     fn instantiate_ty_var_raw<R: PredicateEmittingRelation<Self>>(
@@ -210,7 +220,10 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
         vid: rustc_type_ir::IntVid,
         value: rustc_type_ir::IntVarValue,
     ) {
-        self.inner.borrow_mut().int_unification_table().union_value(vid, value);
+        self.inner
+            .borrow_mut()
+            .int_unification_table()
+            .union_value(vid, value);
     }
 
     fn instantiate_float_var_raw(
@@ -218,7 +231,10 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
         vid: rustc_type_ir::FloatVid,
         value: rustc_type_ir::FloatVarValue,
     ) {
-        self.inner.borrow_mut().float_unification_table().union_value(vid, value);
+        self.inner
+            .borrow_mut()
+            .float_unification_table()
+            .union_value(vid, value);
     }
 
     fn instantiate_const_var_raw<R: PredicateEmittingRelation<Self>>(
@@ -255,11 +271,17 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
     }
 
     fn sub_regions(&self, sub: Region<'db>, sup: Region<'db>, _span: Span) {
-        self.inner.borrow_mut().unwrap_region_constraints().make_subregion(sub, sup);
+        self.inner
+            .borrow_mut()
+            .unwrap_region_constraints()
+            .make_subregion(sub, sup);
     }
 
     fn equate_regions(&self, a: Region<'db>, b: Region<'db>, _span: Span) {
-        self.inner.borrow_mut().unwrap_region_constraints().make_eqregion(a, b);
+        self.inner
+            .borrow_mut()
+            .unwrap_region_constraints()
+            .make_eqregion(a, b);
     }
 
     fn register_ty_outlives(&self, _ty: Ty<'db>, _r: Region<'db>, _span: Span) {
@@ -273,7 +295,12 @@ impl<'db> rustc_type_ir::InferCtxtLike for InferCtxt<'db> {
     }
 
     fn clone_opaque_types_lookup_table(&self) -> Vec<(OpaqueTypeKey<'db>, Ty<'db>)> {
-        self.inner.borrow_mut().opaque_types().iter_lookup_table().map(|(k, h)| (k, h.ty)).collect()
+        self.inner
+            .borrow_mut()
+            .opaque_types()
+            .iter_lookup_table()
+            .map(|(k, h)| (k, h.ty))
+            .collect()
     }
 
     fn clone_duplicate_opaque_types(&self) -> Vec<(OpaqueTypeKey<'db>, Ty<'db>)> {
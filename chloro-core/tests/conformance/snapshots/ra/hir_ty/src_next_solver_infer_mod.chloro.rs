@@ -168,7 +168,9 @@ impl<'db> InferCtxtInner<'db> {
     ) -> Option<&type_variable::TypeVariableValue<'db>> {
         // Uses a read-only view of the unification table, this way we don't
         // need an undo log.
-        self.type_variable_storage.eq_relations_ref().try_probe_value(vid)
+        self.type_variable_storage
+            .eq_relations_ref()
+            .try_probe_value(vid)
     }
 
     #[inline]
@@ -405,7 +407,11 @@ impl<'db> InferCtxt<'db> {
                     return ty;
                 }
 
-                if ty.is_ty_error() { self.infcx.next_ty_var() } else { ty.super_fold_with(self) }
+                if ty.is_ty_error() {
+                    self.infcx.next_ty_var()
+                } else {
+                    ty.super_fold_with(self)
+                }
             }
 
             fn fold_const(&mut self, ct: Const<'db>) -> Const<'db> {
@@ -421,7 +427,11 @@ impl<'db> InferCtxt<'db> {
             }
 
             fn fold_region(&mut self, r: Region<'db>) -> Region<'db> {
-                if r.is_error() { self.infcx.next_region_var() } else { r }
+                if r.is_error() {
+                    self.infcx.next_region_var()
+                } else {
+                    r
+                }
             }
         }
 
@@ -460,7 +470,8 @@ impl<'db> InferCtxt<'db> {
         &self,
         obligation: &PredicateObligation<'db>,
     ) -> bool {
-        self.evaluate_obligation(obligation).must_apply_considering_regions()
+        self.evaluate_obligation(obligation)
+            .must_apply_considering_regions()
     }
 
     /// Evaluates whether the predicate can be satisfied in the given
@@ -470,7 +481,8 @@ impl<'db> InferCtxt<'db> {
     /// This version ignores all outlives constraints.
     #[expect(dead_code, reason = "this is used in rustc")]
     fn predicate_must_hold_modulo_regions(&self, obligation: &PredicateObligation<'db>) -> bool {
-        self.evaluate_obligation(obligation).must_apply_modulo_regions()
+        self.evaluate_obligation(obligation)
+            .must_apply_modulo_regions()
     }
 
     /// Evaluate a given predicate, capturing overflow and propagating it back.
@@ -536,7 +548,10 @@ impl<'db> InferCtxt<'db> {
 
     #[instrument(skip(self), level = "debug")]
     pub fn sub_regions(&self, a: Region<'db>, b: Region<'db>) {
-        self.inner.borrow_mut().unwrap_region_constraints().make_subregion(a, b);
+        self.inner
+            .borrow_mut()
+            .unwrap_region_constraints()
+            .make_subregion(a, b);
     }
 
     /// Processes a `Coerce` predicate from the fulfillment context.
@@ -596,16 +611,13 @@ impl<'db> InferCtxt<'db> {
             _ => {}
         }
 
-        self.enter_forall(
-            predicate,
-            |SubtypePredicate { a_is_expected, a, b }| {
+        self.enter_forall(predicate, |SubtypePredicate { a_is_expected, a, b }| {
             if a_is_expected {
                 Ok(self.at(cause, param_env).sub(a, b))
             } else {
                 Ok(self.at(cause, param_env).sup(b, a))
             }
-        },
-        )
+        })
     }
 
     pub fn region_outlives_predicate(
@@ -613,12 +625,9 @@ impl<'db> InferCtxt<'db> {
         _cause: &traits::ObligationCause,
         predicate: PolyRegionOutlivesPredicate<'db>,
     ) {
-        self.enter_forall(
-            predicate,
-            |OutlivesPredicate(r_a, r_b)| {
+        self.enter_forall(predicate, |OutlivesPredicate(r_a, r_b)| {
             self.sub_regions(r_b, r_a); // `b : a` ==> `a <= b`
-        },
-        )
+        })
     }
 
     /// Number of type variables created so far.
@@ -638,13 +647,20 @@ impl<'db> InferCtxt<'db> {
     }
 
     pub fn next_ty_var_with_origin(&self, origin: TypeVariableOrigin) -> Ty<'db> {
-        let vid = self.inner.borrow_mut().type_variables().new_var(self.universe(), origin);
+        let vid = self
+            .inner
+            .borrow_mut()
+            .type_variables()
+            .new_var(self.universe(), origin);
         Ty::new_var(self.interner, vid)
     }
 
     pub fn next_ty_var_id_in_universe(&self, universe: UniverseIndex) -> TyVid {
         let origin = TypeVariableOrigin { param_def_id: None };
-        self.inner.borrow_mut().type_variables().new_var(universe, origin)
+        self.inner
+            .borrow_mut()
+            .type_variables()
+            .new_var(universe, origin)
     }
 
     pub fn next_ty_var_in_universe(&self, universe: UniverseIndex) -> Ty<'db> {
@@ -672,7 +688,10 @@ impl<'db> InferCtxt<'db> {
             .inner
             .borrow_mut()
             .const_unification_table()
-            .new_key(ConstVariableValue::Unknown { origin, universe: self.universe() })
+            .new_key(ConstVariableValue::Unknown {
+                origin,
+                universe: self.universe(),
+            })
             .vid;
         Const::new_var(self.interner, vid)
     }
@@ -695,7 +714,10 @@ impl<'db> InferCtxt<'db> {
     }
 
     pub fn next_int_vid(&self) -> IntVid {
-        self.inner.borrow_mut().int_unification_table().new_key(IntVarValue::Unknown)
+        self.inner
+            .borrow_mut()
+            .int_unification_table()
+            .new_key(IntVarValue::Unknown)
     }
 
     pub fn next_float_var(&self) -> Ty<'db> {
@@ -703,7 +725,10 @@ impl<'db> InferCtxt<'db> {
     }
 
     pub fn next_float_vid(&self) -> FloatVid {
-        self.inner.borrow_mut().float_unification_table().new_key(FloatVarValue::Unknown)
+        self.inner
+            .borrow_mut()
+            .float_unification_table()
+            .new_key(FloatVarValue::Unknown)
     }
 
     /// Creates a fresh region variable with the next available index.
@@ -714,7 +739,10 @@ impl<'db> InferCtxt<'db> {
     }
 
     pub fn next_region_vid(&self) -> RegionVid {
-        self.inner.borrow_mut().unwrap_region_constraints().new_region_var(self.universe())
+        self.inner
+            .borrow_mut()
+            .unwrap_region_constraints()
+            .new_region_var(self.universe())
     }
 
     /// Creates a fresh region variable with the next available index
@@ -739,12 +767,18 @@ impl<'db> InferCtxt<'db> {
     /// placeholders, however, it will return the universe which they
     /// are associated.
     pub fn universe_of_region(&self, r: Region<'db>) -> UniverseIndex {
-        self.inner.borrow_mut().unwrap_region_constraints().universe(r)
+        self.inner
+            .borrow_mut()
+            .unwrap_region_constraints()
+            .universe(r)
     }
 
     /// Number of region variables created so far.
     pub fn num_region_vars(&self) -> usize {
-        self.inner.borrow_mut().unwrap_region_constraints().num_region_vars()
+        self.inner
+            .borrow_mut()
+            .unwrap_region_constraints()
+            .num_region_vars()
     }
 
     /// Just a convenient wrapper of `next_region_var` for using during NLL.
@@ -789,7 +823,10 @@ impl<'db> InferCtxt<'db> {
                     .inner
                     .borrow_mut()
                     .const_unification_table()
-                    .new_key(ConstVariableValue::Unknown { origin, universe: self.universe() })
+                    .new_key(ConstVariableValue::Unknown {
+                        origin,
+                        universe: self.universe(),
+                    })
                     .vid;
                 Const::new_var(self.interner, const_var_id).into()
             }
@@ -808,14 +845,9 @@ impl<'db> InferCtxt<'db> {
         def_id: SolverDefId,
         first: impl IntoIterator<Item = GenericArg<'db>>,
     ) -> GenericArgs<'db> {
-        GenericArgs::fill_rest(
-            self.interner,
-            def_id,
-            first,
-            |_index, kind, _| {
+        GenericArgs::fill_rest(self.interner, def_id, first, |_index, kind, _| {
             self.var_for_def(kind)
-        },
-        )
+        })
     }
 
     /// Returns `true` if errors have been reported since this infcx was
@@ -837,12 +869,20 @@ impl<'db> InferCtxt<'db> {
 
     #[instrument(level = "debug", skip(self), ret)]
     pub fn take_opaque_types(&self) -> Vec<(OpaqueTypeKey<'db>, OpaqueHiddenType<'db>)> {
-        self.inner.borrow_mut().opaque_type_storage.take_opaque_types().collect()
+        self.inner
+            .borrow_mut()
+            .opaque_type_storage
+            .take_opaque_types()
+            .collect()
     }
 
     #[instrument(level = "debug", skip(self), ret)]
     pub fn clone_opaque_types(&self) -> Vec<(OpaqueTypeKey<'db>, OpaqueHiddenType<'db>)> {
-        self.inner.borrow_mut().opaque_type_storage.iter_opaque_types().collect()
+        self.inner
+            .borrow_mut()
+            .opaque_type_storage
+            .iter_opaque_types()
+            .collect()
     }
 
     #[inline(always)]
@@ -889,7 +929,10 @@ impl<'db> InferCtxt<'db> {
                 }
 
                 InferTy::IntVar(v) => {
-                    match self.inner.borrow_mut().int_unification_table().probe_value(v) {
+                    match self.inner
+                        .borrow_mut()
+                        .int_unification_table()
+                        .probe_value(v) {
                         IntVarValue::IntType(ty) => Ty::new_int(self.interner, ty),
                         IntVarValue::UintType(ty) => Ty::new_uint(self.interner, ty),
                         IntVarValue::Unknown => ty,
@@ -897,7 +940,10 @@ impl<'db> InferCtxt<'db> {
                 }
 
                 InferTy::FloatVar(v) => {
-                    match self.inner.borrow_mut().float_unification_table().probe_value(v) {
+                    match self.inner
+                        .borrow_mut()
+                        .float_unification_table()
+                        .probe_value(v) {
                         FloatVarValue::Known(ty) => Ty::new_float(self.interner, ty),
                         FloatVarValue::Unknown => ty,
                     }
@@ -913,8 +959,7 @@ impl<'db> InferCtxt<'db> {
     pub fn shallow_resolve_const(&self, ct: Const<'db>) -> Const<'db> {
         match ct.kind() {
             ConstKind::Infer(infer_ct) => match infer_ct {
-                InferConst::Var(vid) => self
-                    .inner
+                InferConst::Var(vid) => self.inner
                     .borrow_mut()
                     .const_unification_table()
                     .probe_value(vid)
@@ -937,7 +982,11 @@ impl<'db> InferCtxt<'db> {
     }
 
     pub fn root_const_var(&self, var: ConstVid) -> ConstVid {
-        self.inner.borrow_mut().const_unification_table().find(var).vid
+        self.inner
+            .borrow_mut()
+            .const_unification_table()
+            .find(var)
+            .vid
     }
 
     /// Resolves an int var to a rigid int type, if it was constrained to one,
@@ -1007,7 +1056,10 @@ impl<'db> InferCtxt<'db> {
     }
 
     pub fn probe_const_var(&self, vid: ConstVid) -> Result<Const<'db>, UniverseIndex> {
-        match self.inner.borrow_mut().const_unification_table().probe_value(vid) {
+        match self.inner
+            .borrow_mut()
+            .const_unification_table()
+            .probe_value(vid) {
             ConstVariableValue::Known { value } => Ok(value),
             ConstVariableValue::Unknown { origin: _, universe } => Err(universe),
         }
@@ -1052,9 +1104,11 @@ impl<'db> InferCtxt<'db> {
             fn replace_region(&mut self, br: BoundRegion) -> Region<'db> {
                 self.args[br.var.index()].expect_region()
             }
+
             fn replace_ty(&mut self, bt: BoundTy) -> Ty<'db> {
                 self.args[bt.var.index()].expect_ty()
             }
+
             fn replace_const(&mut self, bv: BoundConst) -> Const<'db> {
                 self.args[bv.var.index()].expect_const()
             }
@@ -1138,7 +1192,11 @@ impl<'db> InferCtxt<'db> {
                 // If `inlined_probe_value` returns a value it's always a
                 // `Int(_)` or `UInt(_)`, which never matches a
                 // `Infer(_)`.
-                self.inner.borrow_mut().int_unification_table().inlined_probe_value(v).is_known()
+                self.inner
+                    .borrow_mut()
+                    .int_unification_table()
+                    .inlined_probe_value(v)
+                    .is_known()
             }
 
             TyOrConstInferVar::TyFloat(v) => {
@@ -1146,7 +1204,11 @@ impl<'db> InferCtxt<'db> {
                 // `Float(_)`, which never matches a `Infer(_)`.
                 //
                 // Not `inlined_probe_value(v)` because this call site is colder.
-                self.inner.borrow_mut().float_unification_table().probe_value(v).is_known()
+                self.inner
+                    .borrow_mut()
+                    .float_unification_table()
+                    .probe_value(v)
+                    .is_known()
             }
 
             TyOrConstInferVar::Const(v) => {
@@ -1154,7 +1216,10 @@ impl<'db> InferCtxt<'db> {
                 // `ConstKind::Infer(InferConst::Var(v))`.
                 //
                 // Not `inlined_probe_value(v)` because this call site is colder.
-                match self.inner.borrow_mut().const_unification_table().probe_value(v) {
+                match self.inner
+                    .borrow_mut()
+                    .const_unification_table()
+                    .probe_value(v) {
                     ConstVariableValue::Unknown { .. } => false,
                     ConstVariableValue::Known { .. } => true,
                 }
@@ -1163,7 +1228,10 @@ impl<'db> InferCtxt<'db> {
     }
 
     fn sub_unification_table_root_var(&self, var: rustc_type_ir::TyVid) -> rustc_type_ir::TyVid {
-        self.inner.borrow_mut().type_variables().sub_unification_table_root_var(var)
+        self.inner
+            .borrow_mut()
+            .type_variables()
+            .sub_unification_table_root_var(var)
     }
 
     fn sub_unify_ty_vids_raw(&self, a: rustc_type_ir::TyVid, b: rustc_type_ir::TyVid) {
@@ -63,7 +63,9 @@ impl<'db> OpaqueTypeStorage<'db> {
         &mut self,
     ) -> impl Iterator<Item = (OpaqueTypeKey<'db>, OpaqueHiddenType<'db>)> {
         let OpaqueTypeStorage { opaque_types, duplicate_entries } = self;
-        std::mem::take(opaque_types).into_iter().chain(std::mem::take(duplicate_entries))
+        std::mem::take(opaque_types)
+            .into_iter()
+            .chain(std::mem::take(duplicate_entries))
     }
 
     pub(crate) fn num_entries(&self) -> OpaqueTypeStorageEntries {
@@ -81,7 +83,12 @@ impl<'db> OpaqueTypeStorage<'db> {
             .iter()
             .skip(prev_entries.opaque_types)
             .map(|(k, v)| (*k, *v))
-            .chain(self.duplicate_entries.iter().skip(prev_entries.duplicate_entries).copied())
+            .chain(
+                self.duplicate_entries
+                    .iter()
+                    .skip(prev_entries.duplicate_entries)
+                    .copied(),
+            )
     }
 
     /// Only returns the opaque types from the lookup table. These are used
@@ -110,7 +117,10 @@ impl<'db> OpaqueTypeStorage<'db> {
         &self,
     ) -> impl Iterator<Item = (OpaqueTypeKey<'db>, OpaqueHiddenType<'db>)> {
         let OpaqueTypeStorage { opaque_types, duplicate_entries } = self;
-        opaque_types.iter().map(|(k, v)| (*k, *v)).chain(duplicate_entries.iter().copied())
+        opaque_types
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .chain(duplicate_entries.iter().copied())
     }
 
     #[inline]
@@ -118,7 +128,10 @@ impl<'db> OpaqueTypeStorage<'db> {
         &'a mut self,
         undo_log: &'a mut InferCtxtUndoLogs<'db>,
     ) -> OpaqueTypeTable<'a, 'db> {
-        OpaqueTypeTable { storage: self, undo_log }
+        OpaqueTypeTable {
+            storage: self,
+            undo_log,
+        }
     }
 }
 
@@ -285,7 +285,10 @@ impl<'db> RegionConstraintStorage<'db> {
         &'a mut self,
         undo_log: &'a mut InferCtxtUndoLogs<'db>,
     ) -> RegionConstraintCollector<'db, 'a> {
-        RegionConstraintCollector { storage: self, undo_log }
+        RegionConstraintCollector {
+            storage: self,
+            undo_log,
+        }
     }
 }
 
@@ -356,7 +359,9 @@ impl<'db> RegionConstraintCollector<'db, '_> {
 
     pub(super) fn start_snapshot(&self) -> RegionSnapshot {
         debug!("RegionConstraintCollector: start_snapshot");
-        RegionSnapshot { any_unifications: self.storage.any_unifications }
+        RegionSnapshot {
+            any_unifications: self.storage.any_unifications,
+        }
     }
 
     pub(super) fn rollback_to(&mut self, snapshot: RegionSnapshot) {
@@ -367,7 +372,9 @@ impl<'db> RegionConstraintCollector<'db, '_> {
     pub(super) fn new_region_var(&mut self, universe: UniverseIndex) -> RegionVid {
         let vid = self.storage.var_infos.push(RegionVariableInfo { universe });
 
-        let u_vid = self.unification_table_mut().new_key(RegionVariableValue::Unknown { universe });
+        let u_vid = self
+            .unification_table_mut()
+            .new_key(RegionVariableValue::Unknown { universe });
         assert_eq!(vid, u_vid.vid);
         self.undo_log.push(AddVar(vid));
         debug!("created new region variable {:?} in {:?}", vid, universe);
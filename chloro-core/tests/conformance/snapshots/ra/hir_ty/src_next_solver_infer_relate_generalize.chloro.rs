@@ -193,7 +193,9 @@ impl<'db> InferCtxt<'db> {
         self.inner
             .borrow_mut()
             .const_unification_table()
-            .union_value(target_vid, ConstVariableValue::Known { value: generalized_ct });
+            .union_value(target_vid, ConstVariableValue::Known {
+                value: generalized_ct,
+            });
 
         // Make sure that the order is correct when relating the
         // generalized const and the source.
@@ -250,7 +252,10 @@ impl<'db> InferCtxt<'db> {
 
         let value_may_be_infer = generalizer.relate(source_term, source_term)?;
         let has_unconstrained_ty_var = generalizer.has_unconstrained_ty_var;
-        Ok(Generalization { value_may_be_infer, has_unconstrained_ty_var })
+        Ok(Generalization {
+            value_may_be_infer,
+            has_unconstrained_ty_var,
+        })
     }
 }
 
@@ -534,7 +539,8 @@ impl<'db> TypeRelation<DbInterner<'db>> for Generalizer<'_, 'db> {
             _ => relate::structurally_relate_tys(self, t, t),
         }?;
 
-        self.cache.insert((t, self.ambient_variance, self.in_alias), g);
+        self.cache
+            .insert((t, self.ambient_variance, self.in_alias), g);
         Ok(g)
     }
 
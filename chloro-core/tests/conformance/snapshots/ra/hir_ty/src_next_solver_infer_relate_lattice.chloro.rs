@@ -65,7 +65,13 @@ impl<'infcx, 'db> LatticeOp<'infcx, 'db> {
         param_env: ParamEnv<'db>,
         kind: LatticeOpKind,
     ) -> LatticeOp<'infcx, 'db> {
-        LatticeOp { infcx, trace, param_env, kind, obligations: PredicateObligations::new() }
+        LatticeOp {
+            infcx,
+            trace,
+            param_env,
+            kind,
+            obligations: PredicateObligations::new(),
+        }
     }
 
     pub(crate) fn into_obligations(self) -> PredicateObligations<'db> {
@@ -88,7 +94,10 @@ impl<'db> TypeRelation<DbInterner<'db>> for LatticeOp<'_, 'db> {
         match variance {
             Variance::Invariant => {
                 self.obligations.extend(
-                    self.infcx.at(&self.trace.cause, self.param_env).eq(a, b)?.into_obligations(),
+                    self.infcx
+                        .at(&self.trace.cause, self.param_env)
+                        .eq(a, b)?
+                        .into_obligations(),
                 );
                 Ok(a)
             }
@@ -235,20 +244,24 @@ impl<'db> PredicateEmittingRelation<InferCtxt<'db>> for LatticeOp<'_, 'db> {
         &mut self,
         preds: impl IntoIterator<Item: Upcast<DbInterner<'db>, Predicate<'db>>>,
     ) {
-        self.obligations.extend(preds.into_iter().map(|pred| {
-            Obligation::new(self.infcx.interner, self.trace.cause.clone(), self.param_env, pred)
-        }))
+        self.obligations.extend(
+            preds.into_iter().map(|pred| {
+                Obligation::new(self.infcx.interner, self.trace.cause.clone(), self.param_env, pred)
+            }),
+        )
     }
 
     fn register_goals(&mut self, goals: impl IntoIterator<Item = Goal<'db, Predicate<'db>>>) {
-        self.obligations.extend(goals.into_iter().map(|goal| {
-            Obligation::new(
-                self.infcx.interner,
-                self.trace.cause.clone(),
-                goal.param_env,
-                goal.predicate,
-            )
-        }))
+        self.obligations.extend(
+            goals.into_iter().map(|goal| {
+                Obligation::new(
+                    self.infcx.interner,
+                    self.trace.cause.clone(),
+                    goal.param_env,
+                    goal.predicate,
+                )
+            }),
+        )
     }
 
     fn register_alias_relate_predicate(&mut self, a: Ty<'db>, b: Ty<'db>) {
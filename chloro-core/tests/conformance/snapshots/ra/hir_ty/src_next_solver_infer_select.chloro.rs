@@ -366,7 +366,11 @@ fn candidate_should_be_dropped_in_favor_of<'db>(
 
         // Prefer specializing candidates over specialized candidates.
         (CandidateSource::Impl(victim_def_id), CandidateSource::Impl(other_def_id)) => {
-            victim.goal().infcx().interner.impl_specializes(other_def_id, victim_def_id)
+            victim
+                .goal()
+                .infcx()
+                .interner
+                .impl_specializes(other_def_id, victim_def_id)
         }
 
         _ => false,
@@ -39,11 +39,11 @@ fn const_vars_since_snapshot<'db>(
     (
         range.clone(),
         iter_idx_range(range)
-            .map(|index| match table.probe_value(index) {
+        .map(|index| match table.probe_value(index) {
                 ConstVariableValue::Known { value: _ } => ConstVariableOrigin {},
                 ConstVariableValue::Unknown { origin, universe: _ } => origin,
             })
-            .collect(),
+        .collect(),
     )
 }
 
@@ -121,7 +121,10 @@ impl<'db> InferCtxt<'db> {
         if snapshot_vars.is_empty() {
             value
         } else {
-            value.fold_with(&mut InferenceFudger { infcx: self, snapshot_vars })
+            value.fold_with(&mut InferenceFudger {
+                infcx: self,
+                snapshot_vars,
+            })
         }
     }
 }
@@ -140,7 +143,9 @@ impl SnapshotVarData {
         let region_vars = inner
             .unwrap_region_constraints()
             .vars_since_snapshot(vars_pre_snapshot.region_constraints_len);
-        let type_vars = inner.type_variables().vars_since_snapshot(vars_pre_snapshot.type_var_len);
+        let type_vars = inner
+            .type_variables()
+            .vars_since_snapshot(vars_pre_snapshot.type_var_len);
         let int_vars =
             vars_since_snapshot(&inner.int_unification_table(), vars_pre_snapshot.int_var_len);
         let float_vars =
@@ -150,16 +155,18 @@ impl SnapshotVarData {
             &mut inner.const_unification_table(),
             vars_pre_snapshot.const_var_len,
         );
-        SnapshotVarData { region_vars, type_vars, int_vars, float_vars, const_vars }
+        SnapshotVarData {
+            region_vars,
+            type_vars,
+            int_vars,
+            float_vars,
+            const_vars,
+        }
     }
 
     fn is_empty(&self) -> bool {
         let SnapshotVarData { region_vars, type_vars, int_vars, float_vars, const_vars } = self;
-        region_vars.is_empty()
-            && type_vars.0.is_empty()
-            && int_vars.is_empty()
-            && float_vars.is_empty()
-            && const_vars.0.is_empty()
+        region_vars.is_empty() && type_vars.0.is_empty() && int_vars.is_empty() && float_vars.is_empty() && const_vars.0.is_empty()
     }
 }
 
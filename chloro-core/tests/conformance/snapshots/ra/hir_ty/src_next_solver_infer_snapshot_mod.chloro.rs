@@ -67,7 +67,9 @@ impl<'db> InferCtxt<'db> {
 
         let mut inner = self.inner.borrow_mut();
         inner.rollback_to(undo_snapshot);
-        inner.unwrap_region_constraints().rollback_to(region_constraints_snapshot);
+        inner
+            .unwrap_region_constraints()
+            .rollback_to(region_constraints_snapshot);
     }
 
     #[instrument(skip(self, snapshot), level = "debug")]
@@ -120,6 +122,9 @@ impl<'db> InferCtxt<'db> {
     }
 
     pub fn opaque_types_added_in_snapshot(&self, snapshot: &CombinedSnapshot) -> bool {
-        self.inner.borrow().undo_log.opaque_types_in_snapshot(&snapshot.undo_snapshot)
+        self.inner
+            .borrow()
+            .undo_log
+            .opaque_types_in_snapshot(&snapshot.undo_snapshot)
     }
 }
@@ -69,10 +69,17 @@ impl<'db> Rollback<UndoLog<'db>> for InferCtxtInner<'db> {
             UndoLog::IntUnificationTable(undo) => self.int_unification_storage.reverse(undo),
             UndoLog::FloatUnificationTable(undo) => self.float_unification_storage.reverse(undo),
             UndoLog::RegionConstraintCollector(undo) => {
-                self.region_constraint_storage.as_mut().unwrap().reverse(undo)
+                self.region_constraint_storage
+                    .as_mut()
+                    .unwrap()
+                    .reverse(undo)
             }
             UndoLog::RegionUnificationTable(undo) => {
-                self.region_constraint_storage.as_mut().unwrap().unification_table.reverse(undo)
+                self.region_constraint_storage
+                    .as_mut()
+                    .unwrap()
+                    .unification_table
+                    .reverse(undo)
             }
             UndoLog::PushRegionObligation => {
                 self.region_obligations.pop();
@@ -162,7 +169,9 @@ impl<'db> InferCtxtInner<'db> {
 impl<'db> InferCtxtUndoLogs<'db> {
     pub(crate) fn start_snapshot(&mut self) -> Snapshot {
         self.num_open_snapshots += 1;
-        Snapshot { undo_len: self.logs.len() }
+        Snapshot {
+            undo_len: self.logs.len(),
+        }
     }
 
     pub(crate) fn region_constraints_in_snapshot(
@@ -176,7 +185,9 @@ impl<'db> InferCtxtUndoLogs<'db> {
     }
 
     pub(crate) fn opaque_types_in_snapshot(&self, s: &Snapshot) -> bool {
-        self.logs[s.undo_len..].iter().any(|log| matches!(log, UndoLog::OpaqueTypes(..)))
+        self.logs[s.undo_len..]
+            .iter()
+            .any(|log| matches!(log, UndoLog::OpaqueTypes(..)))
     }
 
     fn assert_open_snapshot(&self, snapshot: &Snapshot) {
@@ -38,7 +38,7 @@ pub struct ObligationCause {
 impl ObligationCause {
     #[expect(
         clippy::new_without_default,
-        reason = "`new` is temporary, eventually we will provide span etc. here"
+        reason = "`new` is temporary, eventually we will provide span etc. here",
     )]
     #[inline]
     pub fn new() -> ObligationCause {
@@ -64,9 +64,9 @@ impl ObligationCause {
 /// scope. The eventual result is usually a `Selection` (defined below).
 #[derive(Clone, Debug, TypeVisitable, TypeFoldable)]
 pub struct Obligation<'db, T> {
-    /// The reason we have to prove this thing.
     #[type_foldable(identity)]
     #[type_visitable(ignore)]
+    /// The reason we have to prove this thing.
     pub cause: ObligationCause,
     /// The environment in which we should prove this thing.
     pub param_env: ParamEnv<'db>,
@@ -198,7 +198,12 @@ impl<'db, O> Obligation<'db, O> {
         predicate: impl Upcast<DbInterner<'db>, O>,
     ) -> Obligation<'db, O> {
         let predicate = predicate.upcast(tcx);
-        Obligation { cause, param_env, recursion_depth, predicate }
+        Obligation {
+            cause,
+            param_env,
+            recursion_depth,
+            predicate,
+        }
     }
 
     pub fn with<P>(
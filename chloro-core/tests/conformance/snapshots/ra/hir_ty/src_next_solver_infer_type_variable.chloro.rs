@@ -108,12 +108,8 @@ pub(crate) struct TypeVariableData {
 
 #[derive(Clone, Debug)]
 pub(crate) enum TypeVariableValue<'db> {
-    Known {
-        value: Ty<'db>,
-    },
-    Unknown {
-        universe: UniverseIndex,
-    },
+    Known { value: Ty<'db> },
+    Unknown { universe: UniverseIndex },
 }
 
 impl<'db> TypeVariableValue<'db> {
@@ -140,7 +136,10 @@ impl<'db> TypeVariableStorage<'db> {
         &'a mut self,
         undo_log: &'a mut InferCtxtUndoLogs<'db>,
     ) -> TypeVariableTable<'a, 'db> {
-        TypeVariableTable { storage: self, undo_log }
+        TypeVariableTable {
+            storage: self,
+            undo_log,
+        }
     }
 
     #[inline]
@@ -195,7 +194,8 @@ impl<'db> TypeVariableTable<'_, 'db> {
             "instantiating type variable `{vid:?}` twice: new-value = {ty:?}, old-value={:?}",
             self.eq_relations().probe_value(vid)
         );
-        self.eq_relations().union_value(vid, TypeVariableValue::Known { value: ty });
+        self.eq_relations()
+            .union_value(vid, TypeVariableValue::Known { value: ty });
     }
 
     /// Creates a new type variable.
@@ -213,7 +213,9 @@ impl<'db> TypeVariableTable<'_, 'db> {
         universe: UniverseIndex,
         origin: TypeVariableOrigin,
     ) -> TyVid {
-        let eq_key = self.eq_relations().new_key(TypeVariableValue::Unknown { universe });
+        let eq_key = self
+            .eq_relations()
+            .new_key(TypeVariableValue::Unknown { universe });
 
         let sub_key = self.sub_unification_table().new_key(());
         debug_assert_eq!(eq_key.vid, sub_key.vid);
@@ -280,7 +282,12 @@ impl<'db> TypeVariableTable<'_, 'db> {
         value_count: usize,
     ) -> (Range<TyVid>, Vec<TypeVariableOrigin>) {
         let range = TyVid::from_usize(value_count)..TyVid::from_usize(self.num_vars());
-        (range.clone(), iter_idx_range(range).map(|index| self.var_origin(index)).collect())
+        (
+            range.clone(),
+            iter_idx_range(range)
+            .map(|index| self.var_origin(index))
+            .collect(),
+        )
     }
 
     /// Returns indices of all variables that are not yet
@@ -313,7 +320,10 @@ pub(crate) struct TyVidEqKey<'db> {
 impl<'db> From<TyVid> for TyVidEqKey<'db> {
     #[inline]
     fn from(vid: TyVid) -> Self {
-        TyVidEqKey { vid, phantom: PhantomData }
+        TyVidEqKey {
+            vid,
+            phantom: PhantomData,
+        }
     }
 }
 
@@ -365,7 +375,9 @@ impl ut::UnifyKey for TyVidSubKey {
 
     #[inline]
     fn from_index(i: u32) -> TyVidSubKey {
-        TyVidSubKey { vid: TyVid::from_u32(i) }
+        TyVidSubKey {
+            vid: TyVid::from_u32(i),
+        }
     }
 
     fn tag() -> &'static str {
@@ -10,12 +10,8 @@ use crate::next_solver::{Const, Region};
 
 #[derive(Clone, Debug)]
 pub(crate) enum RegionVariableValue<'db> {
-    Known {
-        value: Region<'db>,
-    },
-    Unknown {
-        universe: UniverseIndex,
-    },
+    Known { value: Region<'db> },
+    Unknown { universe: UniverseIndex },
 }
 
 #[derive(PartialEq, Copy, Clone, Debug)]
@@ -26,7 +22,10 @@ pub(crate) struct RegionVidKey<'db> {
 
 impl<'db> From<RegionVid> for RegionVidKey<'db> {
     fn from(vid: RegionVid) -> Self {
-        RegionVidKey { vid, phantom: PhantomData }
+        RegionVidKey {
+            vid,
+            phantom: PhantomData,
+        }
     }
 }
 
@@ -89,7 +88,9 @@ impl<'db> UnifyValue for RegionVariableValue<'db> {
                 // be nameable by both universes. Therefore, the resulting
                 // universe is the minimum of the two universes, because that is
                 // the one which contains the fewest names in scope.
-                Ok(RegionVariableValue::Unknown { universe: (*a).min(*b) })
+                Ok(RegionVariableValue::Unknown {
+                    universe: (*a).min(*b),
+                })
             }
         }
     }
@@ -103,9 +104,7 @@ pub struct ConstVariableOrigin {
 
 #[derive(Clone, Debug)]
 pub(crate) enum ConstVariableValue<'db> {
-    Known {
-        value: Const<'db>,
-    },
+    Known { value: Const<'db> },
     Unknown {
         origin: ConstVariableOrigin,
         universe: UniverseIndex,
@@ -131,7 +130,10 @@ pub(crate) struct ConstVidKey<'db> {
 
 impl<'db> From<ConstVid> for ConstVidKey<'db> {
     fn from(vid: ConstVid) -> Self {
-        ConstVidKey { vid, phantom: PhantomData }
+        ConstVidKey {
+            vid,
+            phantom: PhantomData,
+        }
     }
 }
 
@@ -189,7 +191,10 @@ impl<'db> UnifyValue for ConstVariableValue<'db> {
                 // universe is the minimum of the two universes, because that is
                 // the one which contains the fewest names in scope.
                 let universe = cmp::min(*universe1, *universe2);
-                Ok(ConstVariableValue::Unknown { origin: *origin, universe })
+                Ok(ConstVariableValue::Unknown {
+                    origin: *origin,
+                    universe,
+                })
             }
         }
     }
@@ -92,7 +92,9 @@ impl<'db> NormalizesToTermHack<'db> {
         let errors = ocx.evaluate_obligations_error_on_ambiguity();
         if errors.is_empty() {
             Ok(Certainty::Yes)
-        } else if errors.iter().all(|e| !matches!(e, NextSolverError::TrueError(_))) {
+        } else if errors
+            .iter()
+            .all(|e| !matches!(e, NextSolverError::TrueError(_))) {
             Ok(Certainty::AMBIGUOUS)
         } else {
             Err(NoSolution)
@@ -312,7 +314,9 @@ impl<'a, 'db> InspectCandidate<'a, 'db> {
         &self,
         visitor: &mut V,
     ) -> V::Result {
-        self.goal.infcx.probe(|_| self.visit_nested_no_probe(visitor))
+        self.goal
+            .infcx
+            .probe(|_| self.visit_nested_no_probe(visitor))
     }
 }
 
@@ -268,6 +268,7 @@ pub struct DbInterner<'db> {
     pub(crate) block: Option<BlockId>,
 }
 
+// FIXME: very wrong, see https://github.com/rust-lang/rust/pull/144808
 unsafe impl Send for DbInterner<'_> {}
 
 unsafe impl Sync for DbInterner<'_> {}
@@ -296,6 +297,7 @@ impl<'db> DbInterner<'db> {
     }
 }
 
+// This is intentionally left as `()`
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct Span(());
 
@@ -368,6 +370,7 @@ interned_vec_nolifetime_salsa!(VariancesOf, Variance, nofold);
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct VariantIdx(usize);
 
+// FIXME: could/should store actual data?
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum VariantDef {
     Struct(StructId),
@@ -390,7 +393,11 @@ impl VariantDef {
             VariantDef::Union(it) => (*it).into(),
             VariantDef::Enum(it) => (*it).into(),
         };
-        id.fields(db).fields().iter().map(|(id, data)| (id, data.clone())).collect()
+        id.fields(db)
+            .fields()
+            .iter()
+            .map(|(id, data)| (id, data.clone()))
+            .collect()
     }
 }
 
@@ -436,6 +443,9 @@ pub struct AdtDefInner {
     repr: ReprOptions,
 }
 
+// We're gonna cheat a little bit and implement `Hash` on only the `DefId` and
+// accept there might be collisions for def ids from different crates (or across
+// different tests, oh my).
 impl std::hash::Hash for AdtDefInner {
     #[inline]
     fn hash<H: std::hash::Hasher>(&self, s: &mut H) {
@@ -564,14 +574,21 @@ impl AdtDef {
             }
         };
 
-        AdtDef::new_(db, AdtDefInner { id: def_id, variants, flags, repr })
+        AdtDef::new_(db, AdtDefInner {
+            id: def_id,
+            variants,
+            flags,
+            repr,
+        })
     }
 
     pub fn inner(&self) -> &AdtDefInner {
         crate::with_attached_db(|db| {
             let inner = self.data_(db);
             // SAFETY: ¯\_(ツ)_/¯
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 
@@ -651,7 +668,11 @@ impl<'db> inherent::AdtDef<DbInterner<'db>> for AdtDef {
         sizedness: SizedTraitKind,
     ) -> Option<EarlyBinder<DbInterner<'db>, Ty<'db>>> {
         if self.is_struct() {
-            let tail_ty = self.all_field_tys(interner).skip_binder().into_iter().last()?;
+            let tail_ty = self
+                .all_field_tys(interner)
+                .skip_binder()
+                .into_iter()
+                .last()?;
 
             let constraint_ty = sizedness_constraint_for_ty(interner, sizedness, tail_ty)?;
 
@@ -749,7 +770,9 @@ impl<'db> Pattern<'db> {
             let inner = &self.kind_(db).0;
             // SAFETY: The caller already has access to a `Ty<'db>`, so borrowchecking will
             // make sure that our returned value is valid for the lifetime `'db`.
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 }
@@ -773,7 +796,9 @@ impl<'db> Flags for Pattern<'db> {
     fn outer_exclusive_binder(&self) -> rustc_type_ir::DebruijnIndex {
         match self.inner() {
             PatternKind::Range { start, end } => {
-                start.outer_exclusive_binder().max(end.outer_exclusive_binder())
+                start
+                    .outer_exclusive_binder()
+                    .max(end.outer_exclusive_binder())
             }
             PatternKind::Or(pats) => {
                 let mut idx = pats.as_slice()[0].outer_exclusive_binder();
@@ -998,12 +1023,9 @@ impl<'db> Interner for DbInterner<'db> {
         I: Iterator<Item = T>,
         T: rustc_type_ir::CollectAndApply<Self::GenericArg, Self::GenericArgs>,
     {
-        CollectAndApply::collect_and_apply(
-            args,
-            |g| {
+        CollectAndApply::collect_and_apply(args, |g| {
             GenericArgs::new_from_iter(self, g.iter().cloned())
-        },
-        )
+        })
     }
 
     type UnsizingParams = UnsizingParams;
@@ -1105,7 +1127,9 @@ impl<'db> Interner for DbInterner<'db> {
                     Ctor::Struct(id) => id.into(),
                     Ctor::Enum(id) => id.into(),
                 };
-                self.db.value_ty(id).expect("`SolverDefId::Ctor` should have a function-like ctor")
+                self.db
+                    .value_ty(id)
+                    .expect("`SolverDefId::Ctor` should have a function-like ctor")
             }
             _ => panic!("Unexpected def_id `{def_id:?}` provided for `type_of`"),
         }
@@ -1316,7 +1340,9 @@ impl<'db> Interner for DbInterner<'db> {
         explicit_item_bounds(self, def_id).map_bound(|bounds| {
             Clauses::new_from_iter(
                 self,
-                elaborate(self, bounds).filter_only_self().collect::<Vec<_>>(),
+                elaborate(self, bounds)
+                    .filter_only_self()
+                    .collect::<Vec<_>>(),
             )
         })
     }
@@ -1331,10 +1357,9 @@ impl<'db> Interner for DbInterner<'db> {
         if all_bounds.len() == own_bounds.len() {
             EarlyBinder::bind(Clauses::new_from_iter(self, []))
         } else {
-            EarlyBinder::bind(Clauses::new_from_iter(
-                self,
-                all_bounds.difference(&own_bounds).cloned(),
-            ))
+            EarlyBinder::bind(
+                Clauses::new_from_iter(self, all_bounds.difference(&own_bounds).cloned()),
+            )
         }
     }
 
@@ -1353,7 +1378,9 @@ impl<'db> Interner for DbInterner<'db> {
         self,
         def_id: Self::DefId,
     ) -> EarlyBinder<Self, impl IntoIterator<Item = Self::Clause>> {
-        let predicates = self.db().generic_predicates_without_parent(def_id.try_into().unwrap());
+        let predicates = self
+            .db()
+            .generic_predicates_without_parent(def_id.try_into().unwrap());
         let predicates: Vec<_> = predicates.iter().cloned().collect();
         EarlyBinder::bind(predicates.into_iter())
     }
@@ -1427,7 +1454,10 @@ impl<'db> Interner for DbInterner<'db> {
         self,
         impl_id: Self::ImplId,
     ) -> EarlyBinder<Self, impl IntoIterator<Item = Self::Clause>> {
-        let trait_ref = self.db().impl_trait(impl_id.0).expect("expected an impl of trait");
+        let trait_ref = self
+            .db()
+            .impl_trait(impl_id.0)
+            .expect("expected an impl of trait");
         trait_ref.map_bound(|trait_ref| {
             let clause: Clause<'_> = trait_ref.upcast(self);
             Clauses::new_from_iter(
@@ -1641,7 +1671,10 @@ impl<'db> Interner for DbInterner<'db> {
             SolverDefId::TraitId(id) => id,
             _ => unreachable!(),
         };
-        trait_.trait_items(self.db()).associated_types().map(|id| id.into())
+        trait_
+            .trait_items(self.db())
+            .associated_types()
+            .map(|id| id.into())
     }
 
     fn for_each_relevant_impl(
@@ -1883,6 +1916,7 @@ impl<'db> Interner for DbInterner<'db> {
                 let br = BoundRegion { var, kind };
                 Region::new_bound(self.interner, DebruijnIndex::ZERO, br)
             }
+
             fn replace_ty(&mut self, bt: BoundTy) -> Ty<'db> {
                 let entry = self.map.entry(bt.var);
                 let index = entry.index();
@@ -1891,6 +1925,7 @@ impl<'db> Interner for DbInterner<'db> {
                     (*entry.or_insert_with(|| BoundVarKind::Ty(BoundTyKind::Anon))).expect_ty();
                 Ty::new_bound(self.interner, DebruijnIndex::ZERO, BoundTy { var, kind })
             }
+
             fn replace_const(&mut self, bv: BoundConst) -> Const<'db> {
                 let entry = self.map.entry(bv.var);
                 let index = entry.index();
@@ -1971,7 +2006,10 @@ impl<'db> Interner for DbInterner<'db> {
             CallableDefId::FunctionId(id) => id,
             _ => return false,
         };
-        self.db().function_signature(id).flags.contains(FnFlags::CONST)
+        self.db()
+            .function_signature(id)
+            .flags
+            .contains(FnFlags::CONST)
     }
 
     fn impl_is_const(self, _def_id: Self::ImplId) -> bool {
@@ -2015,15 +2053,22 @@ impl<'db> Interner for DbInterner<'db> {
     }
 
     fn is_default_trait(self, def_id: Self::TraitId) -> bool {
-        self.as_trait_lang_item(def_id).map_or(false, |l| matches!(l, SolverTraitLangItem::Sized))
+        self.as_trait_lang_item(def_id)
+            .map_or(false, |l| matches!(l, SolverTraitLangItem::Sized))
     }
 
     fn trait_is_coinductive(self, trait_: Self::TraitId) -> bool {
-        self.db().trait_signature(trait_.0).flags.contains(TraitFlags::COINDUCTIVE)
+        self.db()
+            .trait_signature(trait_.0)
+            .flags
+            .contains(TraitFlags::COINDUCTIVE)
     }
 
     fn trait_is_unsafe(self, trait_: Self::TraitId) -> bool {
-        self.db().trait_signature(trait_.0).flags.contains(TraitFlags::UNSAFE)
+        self.db()
+            .trait_signature(trait_.0)
+            .flags
+            .contains(TraitFlags::UNSAFE)
     }
 
     fn impl_self_is_guaranteed_unsized(self, _def_id: Self::ImplId) -> bool {
@@ -2059,10 +2104,7 @@ impl<'db> Interner for DbInterner<'db> {
         rustc_next_trait_solver::solve::evaluate_root_goal_for_proof_tree_raw_provider::<
             SolverContext<'db>,
             Self,
-        >(
-            self,
-            canonical_goal,
-        )
+        >(self, canonical_goal)
     }
 
     fn is_sizedness_trait(self, def_id: Self::TraitId) -> bool {
@@ -2079,28 +2121,25 @@ impl<'db> DbInterner<'db> {
         T: rustc_type_ir::TypeFoldable<Self>,
     {
         let shift_bv = |bv: BoundVar| BoundVar::from_usize(bv.as_usize() + bound_vars);
-        self.replace_escaping_bound_vars_uncached(
-            value,
-            FnMutDelegate {
-                regions: &mut |r: BoundRegion| {
-                    Region::new_bound(
-                        self,
-                        DebruijnIndex::ZERO,
-                        BoundRegion { var: shift_bv(r.var), kind: r.kind },
-                    )
-                },
-                types: &mut |t: BoundTy| {
-                    Ty::new_bound(
-                        self,
-                        DebruijnIndex::ZERO,
-                        BoundTy { var: shift_bv(t.var), kind: t.kind },
-                    )
-                },
-                consts: &mut |c| {
-                    Const::new_bound(self, DebruijnIndex::ZERO, BoundConst { var: shift_bv(c.var) })
-                },
+        self.replace_escaping_bound_vars_uncached(value, FnMutDelegate {
+            regions: &mut |r: BoundRegion| {
+                Region::new_bound(self, DebruijnIndex::ZERO, BoundRegion {
+                    var: shift_bv(r.var),
+                    kind: r.kind,
+                })
             },
-        )
+            types: &mut |t: BoundTy| {
+                Ty::new_bound(self, DebruijnIndex::ZERO, BoundTy {
+                    var: shift_bv(t.var),
+                    kind: t.kind,
+                })
+            },
+            consts: &mut |c| {
+                Const::new_bound(self, DebruijnIndex::ZERO, BoundConst {
+                    var: shift_bv(c.var),
+                })
+            },
+        })
     }
 
     pub fn replace_escaping_bound_vars_uncached<T: rustc_type_ir::TypeFoldable<DbInterner<'db>>>(
@@ -2136,10 +2175,7 @@ impl<'db> DbInterner<'db> {
         I: IntoIterator<Item = Ty<'db>>,
     {
         FnSig {
-            inputs_and_output: Tys::new_from_iter(
-                self,
-                inputs.into_iter().chain(std::iter::once(output)),
-            ),
+            inputs_and_output: Tys::new_from_iter(self, inputs.into_iter().chain(std::iter::once(output))),
             c_variadic,
             safety,
             abi,
@@ -2233,7 +2269,9 @@ mod tls_db {
                         None => {
                             // Otherwise, set the database.
                             attached.database.set(Some(NonNull::from(db)));
-                            Self { state: Some(attached) }
+                            Self {
+                                state: Some(attached),
+                            }
                         }
                     }
                 }
@@ -2264,7 +2302,10 @@ mod tls_db {
                 #[inline]
                 fn new(attached: &'s Attached, db: &dyn HirDatabase) -> Self {
                     let prev = attached.database.replace(Some(NonNull::from(db)));
-                    Self { state: attached, prev }
+                    Self {
+                        state: attached,
+                        prev,
+                    }
                 }
             }
 
@@ -2281,7 +2322,10 @@ mod tls_db {
 
         #[inline]
         fn with<R>(&self, op: impl FnOnce(&dyn HirDatabase) -> R) -> R {
-            let db = self.database.get().expect("Try to use attached db, but not db is attached");
+            let db = self
+                .database
+                .get()
+                .expect("Try to use attached db, but not db is attached");
 
             // SAFETY: The db is attached, so it must be valid.
             op(unsafe {
@@ -2302,9 +2346,7 @@ mod tls_db {
     }
     #[inline]
     pub fn with_attached_db<R>(op: impl FnOnce(&dyn HirDatabase) -> R) -> R {
-        GLOBAL_DB.with(
-            #[inline] |a| a.with(op),
-        )
+        GLOBAL_DB.with(#[inline] |a| a.with(op))
     }
 }
 
@@ -75,7 +75,9 @@ where
         stalled_coroutine_goals: vec![],
     };
     let value = value.try_fold_with(&mut folder)?;
-    let errors = folder.fulfill_cx.evaluate_obligations_error_on_ambiguity(at.infcx);
+    let errors = folder
+        .fulfill_cx
+        .evaluate_obligations_error_on_ambiguity(at.infcx);
     if errors.is_empty() {
         Ok((value, folder.stalled_coroutine_goals))
     } else {
@@ -121,7 +123,8 @@ impl<'db> NormalizationFolder<'_, 'db> {
             return Err(vec![NextSolverError::Overflow(obligation)]);
         }
 
-        self.fulfill_cx.register_predicate_obligation(infcx, obligation);
+        self.fulfill_cx
+            .register_predicate_obligation(infcx, obligation);
         self.select_all_and_stall_coroutine_predicates()?;
 
         // Alias is guaranteed to be fully structurally resolved,
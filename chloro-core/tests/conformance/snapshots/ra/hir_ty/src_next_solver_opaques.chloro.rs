@@ -30,7 +30,9 @@ impl<'db> ExternalConstraints<'db> {
         crate::with_attached_db(|db| {
             let inner = self.kind_(db);
             // SAFETY: ¯\_(ツ)_/¯
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 }
@@ -59,40 +61,28 @@ impl<'db> rustc_type_ir::TypeFoldable<DbInterner<'db>> for ExternalConstraints<'
         self,
         folder: &mut F,
     ) -> Result<Self, F::Error> {
-        Ok(ExternalConstraints::new(
-            folder.cx(),
-            ExternalConstraintsData {
+        Ok(ExternalConstraints::new(folder.cx(), ExternalConstraintsData {
             region_constraints: self.region_constraints.clone().try_fold_with(folder)?,
-            opaque_types: self
-                    .opaque_types
-                    .iter()
-                    .cloned()
-                    .map(|opaque| opaque.try_fold_with(folder))
-                    .collect::<Result<_, F::Error>>()?,
-            normalization_nested_goals: self
-                    .normalization_nested_goals
-                    .clone()
-                    .try_fold_with(folder)?,
-        },
-        ))
+            opaque_types: self.opaque_types
+                .iter()
+                .cloned()
+                .map(|opaque| opaque.try_fold_with(folder))
+                .collect::<Result<_, F::Error>>()?,
+            normalization_nested_goals: self.normalization_nested_goals
+                .clone()
+                .try_fold_with(folder)?,
+        }))
     }
 
     fn fold_with<F: rustc_type_ir::TypeFolder<DbInterner<'db>>>(self, folder: &mut F) -> Self {
-        ExternalConstraints::new(
-            folder.cx(),
-            ExternalConstraintsData {
+        ExternalConstraints::new(folder.cx(), ExternalConstraintsData {
             region_constraints: self.region_constraints.clone().fold_with(folder),
-            opaque_types: self
-                    .opaque_types
-                    .iter()
-                    .cloned()
-                    .map(|opaque| opaque.fold_with(folder))
-                    .collect(),
-            normalization_nested_goals: self
-                    .normalization_nested_goals
-                    .clone()
-                    .fold_with(folder),
-        },
-        )
+            opaque_types: self.opaque_types
+                .iter()
+                .cloned()
+                .map(|opaque| opaque.fold_with(folder))
+                .collect(),
+            normalization_nested_goals: self.normalization_nested_goals.clone().fold_with(folder),
+        })
     }
 }
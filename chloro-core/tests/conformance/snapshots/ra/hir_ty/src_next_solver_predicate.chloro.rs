@@ -96,7 +96,8 @@ interned_vec_db!(BoundExistentialPredicates, BoundExistentialPredicate);
 
 impl<'db> rustc_type_ir::inherent::BoundExistentialPredicates<DbInterner<'db>> for BoundExistentialPredicates<'db> {
     fn principal_def_id(self) -> Option<TraitIdWrapper> {
-        self.principal().map(|trait_ref| trait_ref.skip_binder().def_id)
+        self.principal()
+            .map(|trait_ref| trait_ref.skip_binder().def_id)
     }
 
     fn principal(
@@ -113,7 +114,8 @@ impl<'db> rustc_type_ir::inherent::BoundExistentialPredicates<DbInterner<'db>> f
     }
 
     fn auto_traits(self) -> impl IntoIterator<Item = TraitIdWrapper> {
-        self.iter().filter_map(|predicate| match predicate.skip_binder() {
+        self.iter()
+            .filter_map(|predicate| match predicate.skip_binder() {
             ExistentialPredicate::AutoTrait(did) => Some(did),
             _ => None,
         })
@@ -188,12 +190,9 @@ impl<'db> rustc_type_ir::relate::Relate<DbInterner<'db>> for BoundExistentialPre
             },
         );
 
-        CollectAndApply::collect_and_apply(
-            v,
-            |g| {
+        CollectAndApply::collect_and_apply(v, |g| {
             BoundExistentialPredicates::new_from_iter(interner, g.iter().cloned())
-        },
-        )
+        })
     }
 }
 
@@ -255,7 +254,9 @@ impl<'db> Predicate<'db> {
             let inner = &self.kind_(db).0;
             // SAFETY: The caller already has access to a `Predicate<'db>`, so borrowchecking will
             // make sure that our returned value is valid for the lifetime `'db`.
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 
@@ -323,7 +324,9 @@ impl<'db> Clauses<'db> {
             let inner = self.inner_(db);
             // SAFETY: The caller already has access to a `Clauses<'db>`, so borrowchecking will
             // make sure that our returned value is valid for the lifetime `'db`.
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 }
@@ -442,6 +445,7 @@ impl<'db> rustc_type_ir::TypeSuperVisitable<DbInterner<'db>> for Clauses<'db> {
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct Clause<'db>(pub(crate) Predicate<'db>);
 
+// We could cram the reveal into the clauses like rustc does, probably
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, TypeVisitable, TypeFoldable)]
 pub struct ParamEnv<'db> {
     pub(crate) clauses: Clauses<'db>,
@@ -449,7 +453,9 @@ pub struct ParamEnv<'db> {
 
 impl<'db> ParamEnv<'db> {
     pub fn empty() -> Self {
-        ParamEnv { clauses: Clauses::new_from_iter(DbInterner::conjure(), []) }
+        ParamEnv {
+            clauses: Clauses::new_from_iter(DbInterner::conjure(), []),
+        }
     }
 }
 
@@ -627,7 +633,8 @@ impl<'db> UpcastFrom<DbInterner<'db>, Binder<'db, ty::TraitPredicate<DbInterner<
         from: Binder<'db, ty::TraitPredicate<DbInterner<'db>>>,
         interner: DbInterner<'db>,
     ) -> Self {
-        from.map_bound(|it| PredicateKind::Clause(ClauseKind::Trait(it))).upcast(interner)
+        from.map_bound(|it| PredicateKind::Clause(ClauseKind::Trait(it)))
+            .upcast(interner)
     }
 }
 
@@ -636,7 +643,8 @@ impl<'db> UpcastFrom<DbInterner<'db>, Binder<'db, ProjectionPredicate<'db>>> for
         from: Binder<'db, ProjectionPredicate<'db>>,
         interner: DbInterner<'db>,
     ) -> Self {
-        from.map_bound(|it| PredicateKind::Clause(ClauseKind::Projection(it))).upcast(interner)
+        from.map_bound(|it| PredicateKind::Clause(ClauseKind::Projection(it)))
+            .upcast(interner)
     }
 }
 
@@ -690,7 +698,8 @@ impl<'db> UpcastFrom<DbInterner<'db>, ty::OutlivesPredicate<DbInterner<'db>, Reg
 
 impl<'db> UpcastFrom<DbInterner<'db>, PolyRegionOutlivesPredicate<'db>> for Predicate<'db> {
     fn upcast_from(from: PolyRegionOutlivesPredicate<'db>, tcx: DbInterner<'db>) -> Self {
-        from.map_bound(|p| PredicateKind::Clause(ClauseKind::RegionOutlives(p))).upcast(tcx)
+        from.map_bound(|p| PredicateKind::Clause(ClauseKind::RegionOutlives(p)))
+            .upcast(tcx)
     }
 }
 
@@ -778,7 +787,11 @@ impl<'db> TypeFoldable<DbInterner<'db>> for Clause<'db> {
         self,
         folder: &mut F,
     ) -> Result<Self, F::Error> {
-        Ok(folder.try_fold_predicate(self.as_predicate())?.expect_clause())
+        Ok(
+            folder
+                .try_fold_predicate(self.as_predicate())?
+                .expect_clause(),
+        )
     }
 
     fn fold_with<F: rustc_type_ir::TypeFolder<DbInterner<'db>>>(self, folder: &mut F) -> Self {
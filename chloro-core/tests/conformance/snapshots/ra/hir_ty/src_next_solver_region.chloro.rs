@@ -40,7 +40,9 @@ impl<'db> Region<'db> {
             let inner = self.kind_(db);
             // SAFETY: The caller already has access to a `Region<'db>`, so borrowchecking will
             // make sure that our returned value is valid for the lifetime `'db`.
-            unsafe { std::mem::transmute::<&RegionKind<'_>, &RegionKind<'db>>(inner) }
+            unsafe {
+                std::mem::transmute::<&RegionKind<'_>, &RegionKind<'db>>(inner)
+            }
         })
     }
 
@@ -146,6 +148,7 @@ pub struct EarlyParamRegion {
     pub index: u32,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 /// The parameter representation of late-bound function parameters, "some region
 /// at least as big as the scope `fr.scope`".
 ///
@@ -154,7 +157,6 @@ pub struct EarlyParamRegion {
 /// between others we use the `DefId` of the parameter. For this reason the `bound_region` field
 /// should basically always be `BoundRegionKind::Named` as otherwise there is no way of telling
 /// different parameters apart.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct LateParamRegion {
     pub scope: SolverDefId,
     pub bound_region: BoundRegionKind,
@@ -319,23 +321,17 @@ impl<'db> rustc_type_ir::inherent::Region<DbInterner<'db>> for Region<'db> {
         debruijn: rustc_type_ir::DebruijnIndex,
         var: rustc_type_ir::BoundVar,
     ) -> Self {
-        Region::new(
-            interner,
-            RegionKind::ReBound(
-                BoundVarIndexKind::Bound(debruijn),
-                BoundRegion { var, kind: BoundRegionKind::Anon },
-            ),
-        )
+        Region::new(interner, RegionKind::ReBound(BoundVarIndexKind::Bound(debruijn), BoundRegion {
+            var,
+            kind: BoundRegionKind::Anon,
+        }))
     }
 
     fn new_canonical_bound(interner: DbInterner<'db>, var: rustc_type_ir::BoundVar) -> Self {
-        Region::new(
-            interner,
-            RegionKind::ReBound(
-                BoundVarIndexKind::Canonical,
-                BoundRegion { var, kind: BoundRegionKind::Anon },
-            ),
-        )
+        Region::new(interner, RegionKind::ReBound(BoundVarIndexKind::Canonical, BoundRegion {
+            var,
+            kind: BoundRegionKind::Anon,
+        }))
     }
 
     fn new_static(interner: DbInterner<'db>) -> Self {
@@ -369,13 +365,19 @@ impl<'db> PlaceholderLike<DbInterner<'db>> for PlaceholderRegion {
     }
 
     fn new(ui: rustc_type_ir::UniverseIndex, bound: Self::Bound) -> Self {
-        Placeholder { universe: ui, bound }
+        Placeholder {
+            universe: ui,
+            bound,
+        }
     }
 
     fn new_anon(ui: rustc_type_ir::UniverseIndex, var: rustc_type_ir::BoundVar) -> Self {
         Placeholder {
             universe: ui,
-            bound: BoundRegion { var, kind: BoundRegionKind::Anon },
+            bound: BoundRegion {
+                var,
+                kind: BoundRegionKind::Anon,
+            },
         }
     }
 }
@@ -1,8 +1,8 @@
 //! Things related to tys in the next-trait-solver.
 
 use std::ops::ControlFlow;
-use hir_def::{TraitId, type_ref::Rawness};
 
+use hir_def::{TraitId, type_ref::Rawness};
 use hir_def::{
     AdtId, HasModule, TypeParamId,
     hir::generics::{TypeOrConstParamData, TypeParamProvenance},
@@ -70,7 +70,9 @@ impl<'db> Ty<'db> {
             let inner = &self.kind_(db).0;
             // SAFETY: The caller already has access to a `Ty<'db>`, so borrowchecking will
             // make sure that our returned value is valid for the lifetime `'db`.
-            unsafe { std::mem::transmute(inner) }
+            unsafe {
+                std::mem::transmute(inner)
+            }
         })
     }
 
@@ -192,11 +194,11 @@ impl<'db> Ty<'db> {
             },
 
             TyKind::Tuple(tys) => {
-                tys.last().is_none_or(|ty| ty.has_trivial_sizedness(tcx, sizedness))
+                tys.last()
+                    .is_none_or(|ty| ty.has_trivial_sizedness(tcx, sizedness))
             }
 
-            TyKind::Adt(def, args) => def
-                .sizedness_constraint(tcx, sizedness)
+            TyKind::Adt(def, args) => def.sizedness_constraint(tcx, sizedness)
                 .is_none_or(|ty| ty.instantiate(tcx, args).has_trivial_sizedness(tcx, sizedness)),
 
             TyKind::Alias(..) | TyKind::Param(_) | TyKind::Placeholder(..) | TyKind::Bound(..) => {
@@ -293,7 +295,11 @@ impl<'db> Ty<'db> {
             TyKind::RawPtr(ty, _) => ty.is_trivially_wf(tcx),
 
             TyKind::FnPtr(sig_tys, _) => {
-                sig_tys.skip_binder().inputs_and_output.iter().all(|ty| ty.is_trivially_wf(tcx))
+                sig_tys
+                    .skip_binder()
+                    .inputs_and_output
+                    .iter()
+                    .all(|ty| ty.is_trivially_wf(tcx))
             }
             TyKind::Ref(_, ty, _) => ty.is_global() && ty.is_trivially_wf(tcx),
 
@@ -346,7 +352,8 @@ impl<'db> Ty<'db> {
     }
 
     pub fn is_union(self) -> bool {
-        self.as_adt().is_some_and(|(adt, _)| matches!(adt, AdtId::UnionId(_)))
+        self.as_adt()
+            .is_some_and(|(adt, _)| matches!(adt, AdtId::UnionId(_)))
     }
 
     #[inline]
@@ -404,9 +411,12 @@ impl<'db> Ty<'db> {
                 .closure_sig_as_fn_ptr_ty
                 .callable_sig(interner),
             TyKind::CoroutineClosure(coroutine_id, args) => {
-                Some(args.as_coroutine_closure().coroutine_closure_sig().map_bound(|sig| {
-                    let unit_ty = Ty::new_unit(interner);
-                    let return_ty = Ty::new_coroutine(
+                Some(
+                    args.as_coroutine_closure()
+                        .coroutine_closure_sig()
+                        .map_bound(|sig| {
+                            let unit_ty = Ty::new_unit(interner);
+                            let return_ty = Ty::new_coroutine(
                         interner,
                         coroutine_id,
                         CoroutineArgs::new(
@@ -423,19 +433,20 @@ impl<'db> Ty<'db> {
                         )
                         .args,
                     );
-                    FnSig {
-                        inputs_and_output: Tys::new_from_iter(
-                            interner,
-                            sig.tupled_inputs_ty
-                                .tuple_fields()
-                                .iter()
-                                .chain(std::iter::once(return_ty)),
-                        ),
-                        c_variadic: sig.c_variadic,
-                        safety: sig.safety,
-                        abi: sig.abi,
-                    }
-                }))
+                            FnSig {
+                                inputs_and_output: Tys::new_from_iter(
+                                    interner,
+                                    sig.tupled_inputs_ty
+                                        .tuple_fields()
+                                        .iter()
+                                        .chain(std::iter::once(return_ty)),
+                                ),
+                                c_variadic: sig.c_variadic,
+                                safety: sig.safety,
+                                abi: sig.abi,
+                            }
+                        }),
+                )
             }
             _ => None,
         }
@@ -950,20 +961,17 @@ impl<'db> rustc_type_ir::inherent::Ty<DbInterner<'db>> for Ty<'db> {
     }
 
     fn new_anon_bound(interner: DbInterner<'db>, debruijn: DebruijnIndex, var: BoundVar) -> Self {
-        Ty::new(
-            interner,
-            TyKind::Bound(
-                BoundVarIndexKind::Bound(debruijn),
-                BoundTy { var, kind: BoundTyKind::Anon },
-            ),
-        )
+        Ty::new(interner, TyKind::Bound(BoundVarIndexKind::Bound(debruijn), BoundTy {
+            var,
+            kind: BoundTyKind::Anon,
+        }))
     }
 
     fn new_canonical_bound(interner: DbInterner<'db>, var: BoundVar) -> Self {
-        Ty::new(
-            interner,
-            TyKind::Bound(BoundVarIndexKind::Canonical, BoundTy { var, kind: BoundTyKind::Anon }),
-        )
+        Ty::new(interner, TyKind::Bound(BoundVarIndexKind::Canonical, BoundTy {
+            var,
+            kind: BoundTyKind::Anon,
+        }))
     }
 
     fn new_alias(interner: DbInterner<'db>, kind: AliasTyKind, alias_ty: AliasTy<'db>) -> Self {
@@ -1352,13 +1360,19 @@ impl<'db> PlaceholderLike<DbInterner<'db>> for PlaceholderTy {
     }
 
     fn new(ui: rustc_type_ir::UniverseIndex, bound: BoundTy) -> Self {
-        Placeholder { universe: ui, bound }
+        Placeholder {
+            universe: ui,
+            bound,
+        }
     }
 
     fn new_anon(ui: rustc_type_ir::UniverseIndex, var: rustc_type_ir::BoundVar) -> Self {
         Placeholder {
             universe: ui,
-            bound: BoundTy { var, kind: BoundTyKind::Anon },
+            bound: BoundTy {
+                var,
+                kind: BoundTyKind::Anon,
+            },
         }
     }
 }
@@ -269,13 +269,10 @@ impl PrimitiveExt for Primitive {
         match *self {
             Primitive::Int(i, signed) => i.to_ty(interner, signed),
             Primitive::Float(f) => f.to_ty(interner),
-            Primitive::Pointer(_) => Ty::new(
-                interner,
-                TyKind::RawPtr(
+            Primitive::Pointer(_) => Ty::new(interner, TyKind::RawPtr(
                 Ty::new(interner, TyKind::Tuple(Default::default())),
                 rustc_ast_ir::Mutability::Mut,
-            ),
-            ),
+            )),
         }
     }
 
@@ -287,7 +284,10 @@ impl PrimitiveExt for Primitive {
             Primitive::Int(i, signed) => i.to_ty(interner, signed),
             Primitive::Pointer(_) => {
                 let signed = false;
-                interner.data_layout().ptr_sized_integer().to_ty(interner, signed)
+                interner
+                    .data_layout()
+                    .ptr_sized_integer()
+                    .to_ty(interner, signed)
             }
             Primitive::Float(_) => panic!("floats do not have an int type"),
         }
@@ -325,7 +325,9 @@ impl Default for MaxUniverse {
 
 impl MaxUniverse {
     pub fn new() -> Self {
-        MaxUniverse { max_universe: UniverseIndex::ROOT }
+        MaxUniverse {
+            max_universe: UniverseIndex::ROOT,
+        }
     }
 
     pub fn max_universe(self) -> UniverseIndex {
@@ -485,8 +487,7 @@ pub fn sizedness_constraint_for_ty<'db>(
         // Recursive cases
         Pat(ty, _) => sizedness_constraint_for_ty(interner, sizedness, ty),
 
-        Tuple(tys) => tys
-            .into_iter()
+        Tuple(tys) => tys.into_iter()
             .next_back()
             .and_then(|ty| sizedness_constraint_for_ty(interner, sizedness, ty)),
 
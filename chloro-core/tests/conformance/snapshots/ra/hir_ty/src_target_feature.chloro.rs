@@ -78,6 +78,7 @@ static TARGET_FEATURE_IMPLICATIONS: LazyLock<FxHashMap<Symbol, Box<[Symbol]>>> =
         result
     });
 
+// spellchecker:off
 const TARGET_FEATURE_IMPLICATIONS_RAW: &[(&str, &[&str])] = &[
     // Arm
     ("aes", &["neon"]),
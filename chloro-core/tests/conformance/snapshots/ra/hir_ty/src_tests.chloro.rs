@@ -283,9 +283,7 @@ fn infer_with_mismatches(content: &str, include_mismatches: bool) -> String {
     let _tracing = setup_tracing();
     let (db, file_id) = TestDB::with_single_file(content);
 
-    crate::attach_db(
-        &db,
-        || {
+    crate::attach_db(&db, || {
         let mut buf = String::new();
 
         let mut infer_def = |inference_result: Arc<InferenceResult<'_>>,
@@ -418,8 +416,7 @@ fn infer_with_mismatches(content: &str, include_mismatches: bool) -> String {
 
         buf.truncate(buf.trim_end().len());
         buf
-    },
-    )
+    })
 }
 
 pub(crate) fn visit_module(
@@ -472,11 +469,14 @@ pub(crate) fn visit_module(
                     visit_body(db, &body, cb);
                 }
                 ModuleDefId::AdtId(hir_def::AdtId::EnumId(it)) => {
-                    it.enum_variants(db).variants.iter().for_each(|&(it, _, _)| {
-                        let body = db.body(it.into());
-                        cb(it.into());
-                        visit_body(db, &body, cb);
-                    });
+                    it.enum_variants(db)
+                        .variants
+                        .iter()
+                        .for_each(|&(it, _, _)| {
+                            let body = db.body(it.into());
+                            cb(it.into());
+                            visit_body(db, &body, cb);
+                        });
                 }
                 ModuleDefId::TraitId(it) => {
                     let trait_data = it.trait_items(db);
@@ -602,9 +602,7 @@ fn salsa_bug() {
 
     db.set_file_text(pos.file_id.file_id(&db), new_text);
 
-    crate::attach_db(
-        &db,
-        || {
+    crate::attach_db(&db, || {
         let module = db.module_for_file(pos.file_id.file_id(&db));
         let crate_def_map = module.def_map(&db);
         visit_module(&db, crate_def_map, module.local_id, &mut |def| {
@@ -616,6 +614,5 @@ fn salsa_bug() {
                 _ => return,
             });
         });
-    },
-    )
+    })
 }
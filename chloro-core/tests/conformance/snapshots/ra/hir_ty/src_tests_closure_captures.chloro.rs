@@ -18,9 +18,7 @@ use super::{setup_tracing, visit_module};
 fn check_closure_captures(#[rust_analyzer::rust_fixture] ra_fixture: &str, expect: Expect) {
     let _tracing = setup_tracing();
     let (db, file_id) = TestDB::with_single_file(ra_fixture);
-    crate::attach_db(
-        &db,
-        || {
+    crate::attach_db(&db, || {
         let module = db.module_for_file(file_id.file_id(&db));
         let def_map = module.def_map(&db);
 
@@ -119,17 +117,16 @@ fn check_closure_captures(#[rust_analyzer::rust_fixture] ra_fixture: &str, expec
         });
 
         let rendered = captures_info
-        .iter()
-        .map(|(closure_text_range, local_text_range, spans, place, capture_ty, capture_kind)| {
-            format!(
+            .iter()
+            .map(|(closure_text_range, local_text_range, spans, place, capture_ty, capture_kind)| {
+                format!(
                 "{closure_text_range:?};{local_text_range};{spans} {capture_kind:?} {place} {capture_ty}"
             )
-        })
-        .join("\n");
+            })
+            .join("\n");
 
         expect.assert_eq(&rendered);
-    },
-    )
+    })
 }
 
 #[test]
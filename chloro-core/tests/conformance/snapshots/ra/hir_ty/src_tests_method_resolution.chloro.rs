@@ -1345,7 +1345,8 @@ fn foo<T: Trait>(a: &T) {
 
 #[test]
 fn autoderef_visibility_field() {
-    check(r#"
+    check(
+        r#"
 //- minicore: receiver
 mod a {
     pub struct Foo(pub char);
@@ -1369,13 +1370,15 @@ mod b {
              // ^^^^^^^^^^^^^^^^^^^^^^ type: char
     }
 }
-"#)
+"#,
+    )
 }
 
 #[test]
 fn autoderef_visibility_method() {
     cov_mark::check!(autoderef_candidate_not_visible);
-    check(r#"
+    check(
+        r#"
 //- minicore: receiver
 mod a {
     pub struct Foo(pub char);
@@ -1406,13 +1409,15 @@ mod b {
              // ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ type: char
     }
 }
-"#)
+"#,
+    )
 }
 
 #[test]
 fn trait_vs_private_inherent_const() {
     cov_mark::check!(const_candidate_not_visible);
-    check(r#"
+    check(
+        r#"
 mod a {
     pub struct Foo;
     impl Foo {
@@ -1435,7 +1440,8 @@ fn foo() {
     let x = a::Foo::VALUE;
          // ^^^^^^^^^^^^^ type: usize
 }
-"#)
+"#,
+    )
 }
 
 #[test]
@@ -1962,7 +1968,8 @@ fn foo() {
 
 #[test]
 fn primitive_assoc_fn_shadowed_by_use() {
-    check_types(r#"
+    check_types(
+        r#"
 //- /lib.rs crate:lib deps:core
 use core::u16;
 
@@ -1978,7 +1985,8 @@ pub mod u16 {}
 impl u16 {
     pub fn from_le_bytes() -> Self { 0 }
 }
-        "#)
+        "#,
+    )
 }
 
 #[test]
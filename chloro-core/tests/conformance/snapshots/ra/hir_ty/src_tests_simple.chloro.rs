@@ -2155,7 +2155,8 @@ async fn main() {
 
 #[test]
 fn async_fn_and_try_operator() {
-    check_no_mismatches(r#"
+    check_no_mismatches(
+        r#"
 //- minicore: future, result, fn, try, from
 async fn foo() -> Result<(), ()> {
     Ok(())
@@ -2165,7 +2166,8 @@ async fn bar() -> Result<(), ()> {
     let x = foo().await?;
     Ok(x)
 }
-        "#)
+        "#,
+    )
 }
 
 #[test]
@@ -2753,7 +2755,8 @@ impl B for Astruct {}
 
 #[test]
 fn capture_kinds_simple() {
-    check_types(r#"
+    check_types(
+        r#"
 struct S;
 
 impl S {
@@ -2785,7 +2788,8 @@ fn f() {
     let c3 = || { let t = x; t };
       //^^ impl FnOnce() -> S
 }
-    "#)
+    "#,
+    )
 }
 
 #[test]
@@ -2851,7 +2855,8 @@ fn test() {
 
 #[test]
 fn capture_kinds_with_copy_types() {
-    check_types(r#"
+    check_types(
+        r#"
 //- minicore: copy, clone, derive
 #[derive(Clone, Copy)]
 struct Copy;
@@ -2887,12 +2892,14 @@ fn f() {
     let c3 = || e;
       //^^ impl FnOnce() -> AssocGeneric<Copy>
 }
-    "#)
+    "#,
+    )
 }
 
 #[test]
 fn closure_kind_with_predicates() {
-    check_types(r#"
+    check_types(
+        r#"
 //- minicore: fn
 #![feature(unboxed_closures)]
 
@@ -2922,12 +2929,14 @@ fn test {
     f2(|| { 0 });
     // ^^^^^^^^ impl FnOnce() -> i32
 }
-    "#)
+    "#,
+    )
 }
 
 #[test]
 fn derive_macro_should_work_for_associated_type() {
-    check_types(r#"
+    check_types(
+        r#"
 //- minicore: copy, clone, derive
 #[derive(Clone)]
 struct X;
@@ -2950,12 +2959,14 @@ fn f() {
     let e_clone = e.clone();
       //^^^^^^^ AssocGeneric<X>
 }
-    "#)
+    "#,
+    )
 }
 
 #[test]
 fn cfgd_out_assoc_items() {
-    check_types(r#"
+    check_types(
+        r#"
 struct S;
 
 impl S {
@@ -2967,7 +2978,8 @@ fn f() {
     S::C;
   //^^^^ {unknown}
 }
-    "#)
+    "#,
+    )
 }
 
 #[test]
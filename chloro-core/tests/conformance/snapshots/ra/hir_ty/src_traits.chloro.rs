@@ -54,7 +54,12 @@ impl<'db> TraitEnvironment<'db> {
         traits_from_clauses: Box<[(Ty<'db>, TraitId)]>,
         env: ParamEnv<'db>,
     ) -> Arc<Self> {
-        Arc::new(TraitEnvironment { krate, block, traits_from_clauses, env })
+        Arc::new(TraitEnvironment {
+            krate,
+            block,
+            traits_from_clauses,
+            env,
+        })
     }
 
     // pub fn with_block(self: &mut Arc<Self>, block: BlockId) {
@@ -77,7 +82,9 @@ pub fn structurally_normalize_ty<'db>(
 ) -> Ty<'db> {
     let TyKind::Alias(..) = ty.kind() else { return ty };
     let mut ocx = ObligationCtxt::new(infcx);
-    let ty = ocx.structurally_normalize_ty(&ObligationCause::dummy(), env.env, ty).unwrap_or(ty);
+    let ty = ocx
+        .structurally_normalize_ty(&ObligationCause::dummy(), env.env, ty)
+        .unwrap_or(ty);
     ty.replace_infer_with_error(infcx.interner)
 }
 
@@ -230,14 +237,9 @@ pub fn implements_trait_unique<'db>(
     env: Arc<TraitEnvironment<'db>>,
     trait_: TraitId,
 ) -> bool {
-    implements_trait_unique_impl(
-        db,
-        env,
-        trait_,
-        &mut |infcx| {
+    implements_trait_unique_impl(db, env, trait_, &mut |infcx| {
         infcx.fill_rest_fresh_args(trait_.into(), [ty.into()])
-    },
-    )
+    })
 }
 
 /// This should not be used in `hir-ty`, only in `hir`.
@@ -848,9 +848,7 @@ struct FixedPoint<T, U, V>(&'static FixedPoint<(), T, U>, V);
         // ));
         let (db, file_id) = TestDB::with_single_file(ra_fixture);
 
-        crate::attach_db(
-            &db,
-            || {
+        crate::attach_db(&db, || {
             let mut defs: Vec<GenericDefId> = Vec::new();
             let module = db.module_for_file_opt(file_id.file_id(&db)).unwrap();
             let def_map = module.def_map(&db);
@@ -867,9 +865,10 @@ struct FixedPoint<T, U, V>(&'static FixedPoint<(), T, U>, V);
             let defs = defs
                 .into_iter()
                 .filter_map(|def| {
-                    Some((
-                        def,
-                        match def {
+                    Some(
+                        (
+                            def,
+                            match def {
                             GenericDefId::FunctionId(it) => {
                                 let loc = it.lookup(&db);
                                 loc.source(&db).value.name().unwrap()
@@ -892,7 +891,8 @@ struct FixedPoint<T, U, V>(&'static FixedPoint<(), T, U>, V);
                             | GenericDefId::ConstId(_)
                             | GenericDefId::StaticId(_) => return None,
                         },
-                    ))
+                        ),
+                    )
                 })
                 .sorted_by_key(|(_, n)| n.syntax().text_range().start());
             let mut res = String::new();
@@ -931,7 +931,6 @@ struct FixedPoint<T, U, V>(&'static FixedPoint<(), T, U>, V);
             }
 
             expected.assert_eq(&res);
-        },
-        )
+        })
     }
 }
@@ -14,7 +14,11 @@ pub(super) fn find_all_methods(
 ) -> Vec<(TextRange, Option<TextRange>)> {
     let sema = Semantics::new(db);
     let source_file = sema.parse_guess_edition(file_id);
-    source_file.syntax().descendants().filter_map(method_range).collect()
+    source_file
+        .syntax()
+        .descendants()
+        .filter_map(method_range)
+        .collect()
 }
 
 fn method_range(item: SyntaxNode) -> Option<(TextRange, Option<TextRange>)> {
@@ -22,10 +26,9 @@ fn method_range(item: SyntaxNode) -> Option<(TextRange, Option<TextRange>)> {
         if test_related_attribute_syn(&fn_def).is_some() {
             None
         } else {
-            Some((
-                fn_def.syntax().text_range(),
-                fn_def.name().map(|name| name.syntax().text_range()),
-            ))
+            Some(
+                (fn_def.syntax().text_range(), fn_def.name().map(|name| name.syntax().text_range())),
+            )
         }
     })
 }
@@ -33,11 +33,9 @@ pub(crate) fn call_hierarchy(
     position: FilePosition,
     config: &CallHierarchyConfig<'_>,
 ) -> Option<RangeInfo<Vec<NavigationTarget>>> {
-    goto_definition::goto_definition(
-        db,
-        position,
-        &GotoDefinitionConfig { minicore: config.minicore },
-    )
+    goto_definition::goto_definition(db, position, &GotoDefinitionConfig {
+        minicore: config.minicore,
+    })
 }
 
 pub(crate) fn incoming_calls(
@@ -167,7 +165,10 @@ impl CallLocations {
     }
 
     fn into_items(self) -> Vec<CallItem> {
-        self.funcs.into_iter().map(|(target, ranges)| CallItem { target, ranges }).collect()
+        self.funcs
+            .into_iter()
+            .map(|(target, ranges)| CallItem { target, ranges })
+            .collect()
     }
 }
 
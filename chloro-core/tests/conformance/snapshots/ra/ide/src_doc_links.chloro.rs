@@ -6,8 +6,8 @@ mod tests;
 mod intra_doc_links;
 
 use std::ops::Range;
-use pulldown_cmark_to_cmark::{Options as CMarkOptions, cmark_resume_with_options};
 
+use pulldown_cmark_to_cmark::{Options as CMarkOptions, cmark_resume_with_options};
 use pulldown_cmark::{BrokenLink, CowStr, Event, InlineStr, LinkType, Options, Parser, Tag};
 use stdx::format_to;
 use url::Url;
@@ -242,9 +242,7 @@ pub(crate) fn resolve_doc_path_for_def(
         | Definition::InlineAsmRegOrRegClass(_)
         | Definition::InlineAsmOperand(_) => None,
     }
-    .map(
-        Definition::from,
-    )
+    .map(Definition::from)
 }
 
 pub(crate) fn doc_attributes(
@@ -293,9 +291,8 @@ pub(crate) fn token_as_doc_comment(doc_token: &SyntaxToken) -> Option<DocComment
             },
             _ => None,
         }
-    }).map(
-        |prefix_len| DocCommentToken { prefix_len, doc_token: doc_token.clone() },
-    )
+    })
+    .map(|prefix_len| DocCommentToken { prefix_len, doc_token: doc_token.clone() })
 }
 
 impl DocCommentToken {
@@ -310,8 +307,10 @@ impl DocCommentToken {
         let original_start = doc_token.text_range().start();
         let relative_comment_offset = offset - original_start - prefix_len;
 
-        sema.descend_into_macros(doc_token).into_iter().find_map(|t| {
-            let (node, descended_prefix_len, is_inner) = match_ast!{
+        sema.descend_into_macros(doc_token)
+            .into_iter()
+            .find_map(|t| {
+                let (node, descended_prefix_len, is_inner) = match_ast!{
                 match t {
                     ast::Comment(comment) => {
                         (t.parent()?, TextSize::try_from(comment.prefix().len()).ok()?, comment.is_inner())
@@ -324,22 +323,22 @@ impl DocCommentToken {
                     _ => return None,
                 }
             };
-            let token_start = t.text_range().start();
-            let abs_in_expansion_offset = token_start + relative_comment_offset + descended_prefix_len;
-            let (attributes, def) = Self::doc_attributes(sema, &node, is_inner)?;
-            let (docs, doc_mapping) = docs_with_rangemap(sema.db, &attributes)?;
-            let (in_expansion_range, link, ns, is_inner) =
+                let token_start = t.text_range().start();
+                let abs_in_expansion_offset = token_start + relative_comment_offset + descended_prefix_len;
+                let (attributes, def) = Self::doc_attributes(sema, &node, is_inner)?;
+                let (docs, doc_mapping) = docs_with_rangemap(sema.db, &attributes)?;
+                let (in_expansion_range, link, ns, is_inner) =
                 extract_definitions_from_docs(&docs).into_iter().find_map(|(range, link, ns)| {
                     let (mapped, idx) = doc_mapping.map(range)?;
                     (mapped.value.contains(abs_in_expansion_offset)).then_some((mapped.value, link, ns, idx.is_inner_attr()))
                 })?;
-            // get the relative range to the doc/attribute in the expansion
-            let in_expansion_relative_range = in_expansion_range - descended_prefix_len - token_start;
-            // Apply relative range to the original input comment
-            let absolute_range = in_expansion_relative_range + original_start + prefix_len;
-            let def = resolve_doc_path_for_def(sema.db, def, &link, ns, is_inner)?;
-            cb(def, node, absolute_range)
-        })
+                // get the relative range to the doc/attribute in the expansion
+                let in_expansion_relative_range = in_expansion_range - descended_prefix_len - token_start;
+                // Apply relative range to the original input comment
+                let absolute_range = in_expansion_relative_range + original_start + prefix_len;
+                let def = resolve_doc_path_for_def(sema.db, def, &link, ns, is_inner)?;
+                cb(def, node, absolute_range)
+            })
     }
 
     /// When we hover a inner doc item, this find a attached definition.
@@ -480,7 +479,8 @@ fn rewrite_url_link(db: &RootDatabase, def: Definition, target: &str) -> Option<
 fn mod_path_of_def(db: &RootDatabase, def: Definition) -> Option<String> {
     def.canonical_module_path(db).map(|it| {
         let mut path = String::new();
-        it.flat_map(|it| it.name(db)).for_each(|name| format_to!(path, "{}/", name.as_str()));
+        it.flat_map(|it| it.name(db))
+            .for_each(|name| format_to!(path, "{}/", name.as_str()));
         path
     })
 }
@@ -24,12 +24,15 @@ pub(super) fn parse_intra_doc_link(s: &str) -> (&str, Option<hir::Namespace>) {
     .into_iter()
     .find_map(|(ns, (prefixes, suffixes))| {
         if let Some(prefix) = prefixes.iter().find(|&&prefix| {
-            s.starts_with(prefix)
-                && s.chars().nth(prefix.len()).is_some_and(|c| c == '@' || c == ' ')
+            s.starts_with(prefix) && s.chars()
+                .nth(prefix.len())
+                .is_some_and(|c| c == '@' || c == ' ')
         }) {
             Some((&s[prefix.len() + 1..], ns))
         } else {
-            suffixes.iter().find_map(|&suffix| s.strip_suffix(suffix).zip(Some(ns)))
+            suffixes
+                .iter()
+                .find_map(|&suffix| s.strip_suffix(suffix).zip(Some(ns)))
         }
     })
     .map_or((s, None), |(s, ns)| (s, Some(ns)))
@@ -40,8 +43,9 @@ pub(super) fn strip_prefixes_suffixes(s: &str) -> &str {
         .into_iter()
         .find_map(|(prefixes, suffixes)| {
             if let Some(prefix) = prefixes.iter().find(|&&prefix| {
-                s.starts_with(prefix)
-                    && s.chars().nth(prefix.len()).is_some_and(|c| c == '@' || c == ' ')
+                s.starts_with(prefix) && s.chars()
+                    .nth(prefix.len())
+                    .is_some_and(|c| c == '@' || c == ' ')
             }) {
                 Some(&s[prefix.len() + 1..])
             } else {
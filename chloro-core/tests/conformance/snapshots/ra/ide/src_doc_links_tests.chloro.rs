@@ -24,7 +24,9 @@ fn check_external_docs(
     sysroot: Option<&str>,
 ) {
     let (analysis, position) = fixture::position(ra_fixture);
-    let links = analysis.external_docs(position, target_dir, sysroot).unwrap();
+    let links = analysis
+        .external_docs(position, target_dir, sysroot)
+        .unwrap();
 
     let web_url = links.web_url;
     let local_url = links.local_url;
@@ -63,12 +65,17 @@ fn check_doc_links(#[rust_analyzer::rust_fixture] ra_fixture: &str) {
         .into_iter()
         .flat_map(|(text_range, link, ns)| {
             let attr = range.map(text_range);
-            let is_inner_attr = attr.map(|(_file, attr)| attr.is_inner_attr()).unwrap_or(false);
+            let is_inner_attr = attr
+                .map(|(_file, attr)| attr.is_inner_attr())
+                .unwrap_or(false);
             let def = hir::attach_db(sema.db, || {
                 resolve_doc_path_for_def(sema.db, cursor_def, &link, ns, is_inner_attr)
                     .unwrap_or_else(|| panic!("Failed to resolve {link}"))
             });
-            def.try_to_nav(sema).unwrap().into_iter().zip(iter::repeat(link))
+            def.try_to_nav(sema)
+                .unwrap()
+                .into_iter()
+                .zip(iter::repeat(link))
         })
         .map(|(nav_target, link)| {
             let range =
@@ -443,7 +450,8 @@ fn main() {
 
 #[test]
 fn doc_links_items_simple() {
-    check_doc_links(r#"
+    check_doc_links(
+        r#"
 //- /main.rs crate:main deps:krate
 /// [`krate`]
 //! [`Trait`]
@@ -479,12 +487,14 @@ mod module {}
 //- /krate.rs crate:krate
 // empty
 //^file krate
-"#)
+"#,
+    )
 }
 
 #[test]
 fn doc_links_inherent_impl_items() {
-    check_doc_links(r#"
+    check_doc_links(
+        r#"
 /// [`Struct::CONST`]
 /// [`Struct::function`]
 struct Struct$0;
@@ -495,12 +505,14 @@ impl Struct {
     fn function() {}
     // ^^^^^^^^ Struct::function
 }
-"#)
+"#,
+    )
 }
 
 #[test]
 fn doc_links_trait_impl_items() {
-    check_doc_links(r#"
+    check_doc_links(
+        r#"
 trait Trait {
     type Type;
     const CONST: usize;
@@ -518,12 +530,14 @@ impl Trait for Struct {
     const CONST: () = ();
     fn function() {}
 }
-"#)
+"#,
+    )
 }
 
 #[test]
 fn doc_links_trait_items() {
-    check_doc_links(r#"
+    check_doc_links(
+        r#"
 /// [`Trait`]
 /// [`Trait::Type`]
 /// [`Trait::CONST`]
@@ -537,7 +551,8 @@ const CONST: usize;
 fn function();
 // ^^^^^^^^ Trait::function
 }
-    "#)
+    "#,
+    )
 }
 
 #[test]
@@ -42,30 +42,40 @@ pub(crate) fn expand_macro(db: &RootDatabase, position: FilePosition) -> Option<
     // #[derive($0Foo)]
     // struct Bar;
     // ```
-    let derive = sema.descend_into_macros_exact(tok.clone()).into_iter().find_map(|descended| {
-        let macro_file = sema.hir_file_for(&descended.parent()?).macro_file()?;
-        if !macro_file.is_derive_attr_pseudo_expansion(db) {
+    let derive = sema
+        .descend_into_macros_exact(tok.clone())
+        .into_iter()
+        .find_map(|descended| {
+            let macro_file = sema.hir_file_for(&descended.parent()?).macro_file()?;
+            if !macro_file.is_derive_attr_pseudo_expansion(db) {
             return None;
         }
 
-        let name = descended.parent_ancestors().filter_map(ast::Path::cast).last()?.to_string();
-        // up map out of the #[derive] expansion
-        let InFile { file_id, value: tokens } =
+            let name = descended
+                .parent_ancestors()
+                .filter_map(ast::Path::cast)
+                .last()?
+                .to_string();
+            // up map out of the #[derive] expansion
+            let InFile { file_id, value: tokens } =
             hir::InMacroFile::new(macro_file, descended).upmap_once(db);
-        let token = sema.parse_or_expand(file_id).covering_element(tokens[0]).into_token()?;
-        let attr = token.parent_ancestors().find_map(ast::Attr::cast)?;
-        let expansions = sema.expand_derive_macro(&attr)?;
-        let idx = attr
-            .token_tree()?
-            .token_trees_and_tokens()
-            .filter_map(NodeOrToken::into_token)
-            .take_while(|it| it != &token)
-            .filter(|it| it.kind() == T![,])
-            .count();
-        let ExpandResult { err, value: expansion } = expansions.get(idx)?.clone();
-        let expansion_file_id = sema.hir_file_for(&expansion).macro_file()?;
-        let expansion_span_map = db.expansion_span_map(expansion_file_id);
-        let mut expansion = format(
+            let token = sema
+                .parse_or_expand(file_id)
+                .covering_element(tokens[0])
+                .into_token()?;
+            let attr = token.parent_ancestors().find_map(ast::Attr::cast)?;
+            let expansions = sema.expand_derive_macro(&attr)?;
+            let idx = attr
+                .token_tree()?
+                .token_trees_and_tokens()
+                .filter_map(NodeOrToken::into_token)
+                .take_while(|it| it != &token)
+                .filter(|it| it.kind() == T![,])
+                .count();
+            let ExpandResult { err, value: expansion } = expansions.get(idx)?.clone();
+            let expansion_file_id = sema.hir_file_for(&expansion).macro_file()?;
+            let expansion_span_map = db.expansion_span_map(expansion_file_id);
+            let mut expansion = format(
             db,
             SyntaxKind::MACRO_ITEMS,
             position.file_id,
@@ -73,14 +83,14 @@ pub(crate) fn expand_macro(db: &RootDatabase, position: FilePosition) -> Option<
             &expansion_span_map,
             krate,
         );
-        if let Some(err) = err {
+            if let Some(err) = err {
             expansion.insert_str(
                 0,
                 &format!("Expansion had errors: {}\n\n", err.render_to_string(sema.db)),
             );
         }
-        Some(ExpandedMacro { name, expansion })
-    });
+            Some(ExpandedMacro { name, expansion })
+        });
 
     if derive.is_some() {
         return derive;
@@ -198,7 +208,10 @@ fn expand(
         }
     }
 
-    replacements.into_iter().rev().for_each(|(old, new)| ted::replace(old.syntax(), new));
+    replacements
+        .into_iter()
+        .rev()
+        .for_each(|(old, new)| ted::replace(old.syntax(), new));
     expanded
 }
 
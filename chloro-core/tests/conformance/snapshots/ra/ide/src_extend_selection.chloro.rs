@@ -186,7 +186,10 @@ fn extend_tokens_from_range(
 
 /// Find the shallowest node with same range, which allows us to traverse siblings.
 fn shallowest_node(node: &SyntaxNode) -> SyntaxNode {
-    node.ancestors().take_while(|n| n.text_range() == node.text_range()).last().unwrap()
+    node.ancestors()
+        .take_while(|n| n.text_range() == node.text_range())
+        .last()
+        .unwrap()
 }
 
 fn extend_single_word_in_comment_or_string(
@@ -208,7 +211,9 @@ fn extend_single_word_in_comment_or_string(
     // FIXME: use `ceil_char_boundary` from `std::str` when it gets stable
     // https://github.com/rust-lang/rust/issues/93743
     fn ceil_char_boundary(text: &str, index: u32) -> u32 {
-        (index..).find(|&index| text.is_char_boundary(index as usize)).unwrap_or(text.len() as u32)
+        (index..)
+            .find(|&index| text.is_char_boundary(index as usize))
+            .unwrap_or(text.len() as u32)
     }
 
     let from: TextSize = ceil_char_boundary(text, start_idx + 1).into();
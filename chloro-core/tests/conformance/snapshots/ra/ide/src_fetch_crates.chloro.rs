@@ -35,9 +35,15 @@ fn crate_info(
 ) -> CrateInfo {
     let crate_name = crate_name(extra_data);
     let version = extra_data.version.clone();
-    CrateInfo { name: crate_name, version, root_file_id: data.root_file_id }
+    CrateInfo {
+        name: crate_name,
+        version,
+        root_file_id: data.root_file_id,
+    }
 }
 
 fn crate_name(data: &ide_db::base_db::ExtraCrateData) -> Option<String> {
-    data.display_name.as_ref().map(|it| it.canonical_name().as_str().to_owned())
+    data.display_name
+        .as_ref()
+        .map(|it| it.canonical_name().as_str().to_owned())
 }
@@ -111,7 +111,9 @@ fn structure_node(node: &SyntaxNode, config: &FileStructureConfig) -> Option<Str
             node_range: node.syntax().text_range(),
             kind,
             detail,
-            deprecated: node.attrs().filter_map(|x| x.simple_name()).any(|x| x == "deprecated"),
+            deprecated: node.attrs()
+                .filter_map(|x| x.simple_name())
+                .any(|x| x == "deprecated"),
         })
     }
 
@@ -271,7 +273,9 @@ mod tests {
         config: &FileStructureConfig,
         expect: Expect,
     ) {
-        let file = SourceFile::parse(ra_fixture, span::Edition::CURRENT).ok().unwrap();
+        let file = SourceFile::parse(ra_fixture, span::Edition::CURRENT)
+            .ok()
+            .unwrap();
         let structure = file_structure(&file, config);
         expect.assert_debug_eq(&structure)
     }
@@ -22,9 +22,17 @@ pub(crate) fn position(
     let change_fixture = ChangeFixture::parse(&host.db, ra_fixture);
     host.db.enable_proc_attr_macros();
     host.db.apply_change(change_fixture.change);
-    let (file_id, range_or_offset) = change_fixture.file_position.expect("expected a marker ($0)");
+    let (file_id, range_or_offset) = change_fixture
+        .file_position
+        .expect("expected a marker ($0)");
     let offset = range_or_offset.expect_offset();
-    (host.analysis(), FilePosition { file_id: file_id.file_id(&host.db), offset })
+    (
+        host.analysis(),
+        FilePosition {
+        file_id: file_id.file_id(&host.db),
+        offset,
+    },
+    )
 }
 
 /// Creates analysis for a single file, returns range marked with a pair of $0.
@@ -33,9 +41,14 @@ pub(crate) fn range(#[rust_analyzer::rust_fixture] ra_fixture: &str) -> (Analysi
     let change_fixture = ChangeFixture::parse(&host.db, ra_fixture);
     host.db.enable_proc_attr_macros();
     host.db.apply_change(change_fixture.change);
-    let (file_id, range_or_offset) = change_fixture.file_position.expect("expected a marker ($0)");
+    let (file_id, range_or_offset) = change_fixture
+        .file_position
+        .expect("expected a marker ($0)");
     let range = range_or_offset.expect_range();
-    (host.analysis(), FileRange { file_id: file_id.file_id(&host.db), range })
+    (host.analysis(), FileRange {
+        file_id: file_id.file_id(&host.db),
+        range,
+    })
 }
 
 /// Creates analysis for a single file, returns range marked with a pair of $0 or a position marked with $0.
@@ -46,7 +59,9 @@ pub(crate) fn range_or_position(
     let change_fixture = ChangeFixture::parse(&host.db, ra_fixture);
     host.db.enable_proc_attr_macros();
     host.db.apply_change(change_fixture.change);
-    let (file_id, range_or_offset) = change_fixture.file_position.expect("expected a marker ($0)");
+    let (file_id, range_or_offset) = change_fixture
+        .file_position
+        .expect("expected a marker ($0)");
     (host.analysis(), file_id.file_id(&host.db), range_or_offset)
 }
 
@@ -58,7 +73,9 @@ pub(crate) fn annotations(
     let change_fixture = ChangeFixture::parse(&host.db, ra_fixture);
     host.db.enable_proc_attr_macros();
     host.db.apply_change(change_fixture.change);
-    let (file_id, range_or_offset) = change_fixture.file_position.expect("expected a marker ($0)");
+    let (file_id, range_or_offset) = change_fixture
+        .file_position
+        .expect("expected a marker ($0)");
     let offset = range_or_offset.expect_offset();
 
     let db = &host.db;
@@ -66,14 +83,24 @@ pub(crate) fn annotations(
         .files
         .iter()
         .flat_map(|&file_id| {
-            let file_text = host.analysis().file_text(file_id.file_id(&host.db)).unwrap();
+            let file_text = host
+                .analysis()
+                .file_text(file_id.file_id(&host.db))
+                .unwrap();
             let annotations = extract_annotations(&file_text);
             annotations
                 .into_iter()
                 .map(move |(range, data)| (FileRange { file_id: file_id.file_id(db), range }, data))
         })
         .collect();
-    (host.analysis(), FilePosition { file_id: file_id.file_id(&host.db), offset }, annotations)
+    (
+        host.analysis(),
+        FilePosition {
+        file_id: file_id.file_id(&host.db),
+        offset,
+    },
+        annotations,
+    )
 }
 
 /// Creates analysis from a multi-file fixture with annotations without $0
@@ -509,7 +509,8 @@ fn main() <fold block>{
     }
     #[test]
     fn test_fold_multiline_non_block_match_arm() {
-        check(r#"
+        check(
+            r#"
             fn main() <fold block>{
                 match foo <fold block>{
                     block => <fold block>{
@@ -532,11 +533,13 @@ fn main() <fold block>{
                     }</fold></fold>,
                 }</fold>
             }</fold>
-            "#)
+            "#,
+        )
     }
     #[test]
     fn fold_big_calls() {
-        check(r#"
+        check(
+            r#"
 fn main() <fold block>{
     frobnicate<fold arglist>(
         1,
@@ -544,39 +547,47 @@ fn main() <fold block>{
         3,
     )</fold>
 }</fold>
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_record_literals() {
-        check(r#"
+        check(
+            r#"
 const _: S = S <fold block>{
 
 }</fold>;
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_multiline_params() {
-        check(r#"
+        check(
+            r#"
 <fold function>fn foo<fold arglist>(
     x: i32,
     y: String,
 )</fold> {}</fold>
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_multiline_array() {
-        check(r#"
+        check(
+            r#"
 const FOO: [usize; 4] = <fold array>[
     1,
     2,
     3,
     4,
 ]</fold>;
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_region() {
-        check(r#"
+        check(
+            r#"
 // 1. some normal comment
 <fold region>// region: test
 // 2. some normal comment
@@ -585,25 +596,31 @@ fn f() {}
 // endregion</fold>
 fn f2() {}
 // endregion: test</fold>
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_consecutive_const() {
-        check(r#"
+        check(
+            r#"
 <fold consts>const FIRST_CONST: &str = "first";
 const SECOND_CONST: &str = "second";</fold>
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_consecutive_static() {
-        check(r#"
+        check(
+            r#"
 <fold statics>static FIRST_STATIC: &str = "first";
 static SECOND_STATIC: &str = "second";</fold>
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_where_clause() {
-        check(r#"
+        check(
+            r#"
 fn foo()
 <fold whereclause>where
     A: Foo,
@@ -614,27 +631,32 @@ fn foo()
 fn bar()
 <fold whereclause>where
     A: Bar,</fold> {}
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_return_type() {
-        check(r#"
+        check(
+            r#"
 fn foo()<fold returntype>-> (
     bool,
     bool,
 )</fold> { (true, true) }
 
 fn bar() -> (bool, bool) { (true, true) }
-"#)
+"#,
+        )
     }
     #[test]
     fn fold_generics() {
-        check(r#"
+        check(
+            r#"
 type Foo<T, U> = foo<fold arglist><
     T,
     U,
 ></fold>;
-"#)
+"#,
+        )
     }
     #[test]
     fn test_fold_doc_comments_with_multiline_paramlist_function() {
@@ -109,22 +109,26 @@ mod tests {
     }
     #[test]
     fn goto_decl_module_outline() {
-        check(r#"
+        check(
+            r#"
 //- /main.rs
 mod foo;
  // ^^^
 //- /foo.rs
 use self$0;
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_decl_module_inline() {
-        check(r#"
+        check(
+            r#"
 mod foo {
  // ^^^
     use self$0;
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_decl_goto_def_fallback() {
@@ -208,22 +212,26 @@ fn main() {
     }
     #[test]
     fn goto_decl_for_extern_crate() {
-        check(r#"
+        check(
+            r#"
 //- /main.rs crate:main deps:std
 extern crate std$0;
          /// ^^^
 //- /std/lib.rs crate:std
 // empty
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_decl_for_renamed_extern_crate() {
-        check(r#"
+        check(
+            r#"
 //- /main.rs crate:main deps:std
 extern crate std as abc$0;
                 /// ^^^
 //- /std/lib.rs crate:std
 // empty
-"#)
+"#,
+        )
     }
 }
@@ -240,8 +240,17 @@ fn try_lookup_include_path(
     }
     let path = token.value.value().ok()?;
 
-    let file_id = sema.db.resolve_path(AnchoredPath { anchor: file_id, path: &path })?;
-    let size = sema.db.file_text(file_id).text(sema.db).len().try_into().ok()?;
+    let file_id = sema.db.resolve_path(AnchoredPath {
+        anchor: file_id,
+        path: &path,
+    })?;
+    let size = sema
+        .db
+        .file_text(file_id)
+        .text(sema.db)
+        .len()
+        .try_into()
+        .ok()?;
     Some(NavigationTarget {
         file_id,
         full_range: TextRange::new(0.into(), size),
@@ -259,7 +268,10 @@ fn try_lookup_macro_def_in_macro_use(
     sema: &Semantics<'_, RootDatabase>,
     token: SyntaxToken,
 ) -> Option<NavigationTarget> {
-    let extern_crate = token.parent()?.ancestors().find_map(ast::ExternCrate::cast)?;
+    let extern_crate = token
+        .parent()?
+        .ancestors()
+        .find_map(ast::ExternCrate::cast)?;
     let extern_crate = sema.to_def(&extern_crate)?;
     let krate = extern_crate.resolved_crate(sema.db)?;
 
@@ -349,7 +361,10 @@ pub(crate) fn find_fn_or_blocks(
         None
     };
 
-    sema.descend_into_macros(token.clone()).into_iter().filter_map(find_ancestors).collect_vec()
+    sema.descend_into_macros(token.clone())
+        .into_iter()
+        .filter_map(find_ancestors)
+        .collect_vec()
 }
 
 fn nav_for_exit_points(
@@ -585,7 +600,9 @@ fn nav_for_break_points(
 }
 
 fn def_to_nav(sema: &Semantics<'_, RootDatabase>, def: Definition) -> Vec<NavigationTarget> {
-    def.try_to_nav(sema).map(|it| it.collect()).unwrap_or_default()
+    def.try_to_nav(sema)
+        .map(|it| it.collect())
+        .unwrap_or_default()
 }
 
 fn expr_to_nav(
@@ -911,7 +928,8 @@ impl Trait for T {
     }
     #[test]
     fn goto_def_in_mac_call_in_attr_invoc() {
-        check(r#"
+        check(
+            r#"
 //- proc_macros: identity
 pub struct Struct {
         // ^^^^^^
@@ -927,27 +945,32 @@ fn function() {
     identity!(Struct$0 { field: 0 });
 }
 
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_for_extern_crate() {
-        check(r#"
+        check(
+            r#"
 //- /main.rs crate:main deps:std
 extern crate std$0;
 //- /std/lib.rs crate:std
 // empty
 //^file
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_for_renamed_extern_crate() {
-        check(r#"
+        check(
+            r#"
 //- /main.rs crate:main deps:std
 extern crate std as abc$0;
 //- /std/lib.rs crate:std
 // empty
 //^file
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_in_items() {
@@ -1625,7 +1648,8 @@ fn bar() {
     }
     #[test]
     fn goto_def_for_field_init_shorthand() {
-        check(r#"
+        check(
+            r#"
 struct Foo { x: i32 }
            //^
 fn main() {
@@ -1633,7 +1657,8 @@ fn main() {
       //^
     Foo { x$0 };
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_for_enum_variant_field() {
@@ -1706,10 +1731,12 @@ impl Foo {
     }
     #[test]
     fn goto_def_for_type_alias_generic_parameter() {
-        check(r#"
+        check(
+            r#"
 type Alias<T> = T$0;
          //^
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_for_macro_container() {
@@ -1758,7 +1785,8 @@ fn f() -> impl Sub<Item$0 = u8> {}
     }
     #[test]
     fn goto_def_for_module_declaration_in_path_if_types_and_values_same_name() {
-        check(r#"
+        check(
+            r#"
 mod bar {
     pub struct Foo {}
              //^^^
@@ -1768,14 +1796,17 @@ mod bar {
 fn baz() {
     let _foo_enum: bar::Foo$0 = bar::Foo {};
 }
-        "#)
+        "#,
+        )
     }
     #[test]
     fn unknown_assoc_ty() {
-        check_unresolved(r#"
+        check_unresolved(
+            r#"
 trait Iterator { type Item; }
 fn f() -> impl Iterator<Invalid$0 = u8> {}
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_for_assoc_ty_in_path_multiple() {
@@ -1842,7 +1873,8 @@ fn g() -> <() as Iterator<A = (), B$0 = u8>>::A {}
     }
     #[test]
     fn goto_self_param_ty_specified() {
-        check(r#"
+        check(
+            r#"
 struct Foo {}
 
 impl Foo {
@@ -1850,40 +1882,49 @@ impl Foo {
          //^^^^
         let foo = sel$0f;
     }
-}"#)
+}"#,
+        )
     }
     #[test]
     fn goto_self_param_on_decl() {
-        check(r#"
+        check(
+            r#"
 struct Foo {}
 
 impl Foo {
     fn bar(&self$0) {
           //^^^^
     }
-}"#)
+}"#,
+        )
     }
     #[test]
     fn goto_lifetime_param_on_decl() {
-        check(r#"
+        check(
+            r#"
 fn foo<'foobar$0>(_: &'foobar ()) {
      //^^^^^^^
-}"#)
+}"#,
+        )
     }
     #[test]
     fn goto_lifetime_param_decl() {
-        check(r#"
+        check(
+            r#"
 fn foo<'foobar>(_: &'foobar$0 ()) {
      //^^^^^^^
-}"#)
+}"#,
+        )
     }
     #[test]
     fn goto_lifetime_param_decl_nested() {
-        check(r#"
+        check(
+            r#"
 fn foo<'foobar>(_: &'foobar ()) {
     fn foo<'foobar>(_: &'foobar$0 ()) {}
          //^^^^^^^
-}"#)
+}"#,
+        )
     }
     #[test]
     fn goto_lifetime_hrtb() {
@@ -1915,7 +1956,8 @@ fn foo<T>() where T: for<'a> Foo<&'a$0 (u8, u16)>, {}
     }
     #[test]
     fn goto_label() {
-        check(r#"
+        check(
+            r#"
 fn foo<'foo>(_: &'foo ()) {
     'foo: {
   //^^^^
@@ -1923,11 +1965,13 @@ fn foo<'foo>(_: &'foo ()) {
             break 'foo$0;
         }
     }
-}"#)
+}"#,
+        )
     }
     #[test]
     fn goto_def_for_intra_doc_link_same_file() {
-        check(r#"
+        check(
+            r#"
 /// Blah, [`bar`](bar) .. [`foo`](foo$0) has [`bar`](bar)
 pub fn bar() { }
 
@@ -1935,7 +1979,8 @@ pub fn bar() { }
 pub fn foo() { }
      //^^^
 
-}"#)
+}"#,
+        )
     }
     #[test]
     fn goto_def_for_intra_doc_link_outer_same_file() {
@@ -2005,7 +2050,8 @@ struct S;
     }
     #[test]
     fn goto_def_for_intra_doc_link_inner() {
-        check(r#"
+        check(
+            r#"
 //- /main.rs
 mod m;
 struct S;
@@ -2013,19 +2059,23 @@ struct S;
 
 //- /m.rs
 //! [`super::S$0`]
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_incomplete_field() {
-        check(r#"
+        check(
+            r#"
 struct A { a: u32 }
          //^
 fn foo() { A { a$0: }; }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_proc_macro() {
-        check(r#"
+        check(
+            r#"
 //- /main.rs crate:main deps:mac
 use mac::fn_macro;
 
@@ -2036,7 +2086,8 @@ fn_macro$0!();
 #[proc_macro]
 fn fn_macro() {}
  //^^^^^^^^
-            "#)
+            "#,
+        )
     }
     #[test]
     fn goto_intra_doc_links() {
@@ -2708,7 +2759,8 @@ fn main() {
 "#,
         );
 
-        check(r#"
+        check(
+            r#"
 trait Trait<T> {
     fn f(_: T);
 }
@@ -2722,7 +2774,8 @@ impl Trait<i64> for usize {
 fn main() {
     usize::f$0(0i64);
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn query_impls_in_nearest_block() {
@@ -2867,7 +2920,8 @@ pub mod prelude {
     }
     #[test]
     fn goto_def_on_return_kw() {
-        check(r#"
+        check(
+            r#"
 macro_rules! N {
     ($i:ident, $x:expr, $blk:expr) => {
         for $i in 0..$x {
@@ -2892,11 +2946,13 @@ fn main() {
         })();
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_return_kw_in_closure() {
-        check(r#"
+        check(
+            r#"
 macro_rules! N {
     ($i:ident, $x:expr, $blk:expr) => {
         for $i in 0..$x {
@@ -2921,40 +2977,47 @@ fn main() {
         })();
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_break_kw() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     for i in 1..5 {
  // ^^^
         break$0;
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_continue_kw() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     for i in 1..5 {
  // ^^^
         continue$0;
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_break_kw_for_block() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     'a:{
  // ^^^
         break$0 'a;
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_break_with_label() {
@@ -2992,7 +3055,8 @@ fn foo() {
     }
     #[test]
     fn goto_def_on_return_in_try() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     fn f() {
  // ^^
@@ -3003,11 +3067,13 @@ fn main() {
         return;
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_break_in_try() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     for i in 1..100 {
  // ^^^
@@ -3016,36 +3082,43 @@ fn main() {
         };
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_return_in_async_block() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     async {
  // ^^^^^
         return$0;
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_for_kw() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     for$0 i in 1..5 {}
  // ^^^
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_on_fn_kw() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     fn$0 foo() {}
  // ^^
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn shadow_builtin_macro() {
@@ -3808,7 +3881,8 @@ fn bar() {
     }
     #[test]
     fn regression_20038() {
-        check(r#"
+        check(
+            r#"
 //- minicore: clone, fn
 struct Map<Fut, F>(Fut, F);
 
@@ -3865,6 +3939,7 @@ where
 {
     let _x = inner.is_terminated$0();
 }
-"#)
+"#,
+        )
     }
 }
@@ -154,7 +154,11 @@ mod tests {
     ) {
         let (analysis, position, expected) = fixture::annotations(ra_fixture);
 
-        let navs = analysis.goto_implementation(config, position).unwrap().unwrap().info;
+        let navs = analysis
+            .goto_implementation(config, position)
+            .unwrap()
+            .unwrap()
+            .info;
 
         let cmp = |frange: &FileRange| (frange.file_id, frange.range.start());
 
@@ -116,7 +116,11 @@ mod tests {
     use crate::fixture;
     fn check(#[rust_analyzer::rust_fixture] ra_fixture: &str) {
         let (analysis, position, expected) = fixture::annotations(ra_fixture);
-        let navs = analysis.goto_type_definition(position).unwrap().unwrap().info;
+        let navs = analysis
+            .goto_type_definition(position)
+            .unwrap()
+            .unwrap()
+            .info;
         assert!(!navs.is_empty(), "navigation is empty");
 
         let cmp = |&FileRange { file_id, range }: &_| (file_id, range.start());
@@ -266,21 +270,25 @@ fn foo() {
     }
     #[test]
     fn goto_def_for_self_param() {
-        check(r#"
+        check(
+            r#"
 struct Foo;
      //^^^
 impl Foo {
     fn f(&self$0) {}
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_for_type_fallback() {
-        check(r#"
+        check(
+            r#"
 struct Foo;
      //^^^
 impl Foo$0 {}
-"#)
+"#,
+        )
     }
     #[test]
     fn goto_def_for_struct_field() {
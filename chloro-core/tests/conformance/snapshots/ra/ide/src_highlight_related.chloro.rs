@@ -110,7 +110,10 @@ fn highlight_closure_captures(
     token: SyntaxToken,
     file_id: EditionedFileId,
 ) -> Option<Vec<HighlightedRange>> {
-    let closure = token.parent_ancestors().take(2).find_map(ast::ClosureExpr::cast)?;
+    let closure = token
+        .parent_ancestors()
+        .take(2)
+        .find_map(ast::ClosureExpr::cast)?;
     let search_range = closure.body()?.syntax().text_range();
     let ty = &sema.type_of_expr(&closure.into())?.original;
     let c = ty.as_closure()?;
@@ -121,7 +124,10 @@ fn highlight_closure_captures(
             .flat_map(|local| {
                 let usages = Definition::Local(local)
                     .usages(sema)
-                    .in_scope(&SearchScope::file_range(FileRange { file_id, range: search_range }))
+                    .in_scope(&SearchScope::file_range(FileRange {
+                        file_id,
+                        range: search_range,
+                    }))
                     .include_self_refs()
                     .all()
                     .references
@@ -490,7 +496,9 @@ pub(crate) fn highlight_exit_points(
         merge_map(&mut res, new_map);
     }
 
-    res.into_iter().map(|(file_id, ranges)| (file_id, ranges.into_iter().collect())).collect()
+    res.into_iter()
+        .map(|(file_id, ranges)| (file_id, ranges.into_iter().collect()))
+        .collect()
 }
 
 pub(crate) fn highlight_break_points(
@@ -585,7 +593,9 @@ pub(crate) fn highlight_break_points(
         merge_map(&mut res, new_map);
     }
 
-    res.into_iter().map(|(file_id, ranges)| (file_id, ranges.into_iter().collect())).collect()
+    res.into_iter()
+        .map(|(file_id, ranges)| (file_id, ranges.into_iter().collect()))
+        .collect()
 }
 
 pub(crate) fn highlight_yield_points(
@@ -661,7 +671,9 @@ pub(crate) fn highlight_yield_points(
         merge_map(&mut res, new_map);
     }
 
-    res.into_iter().map(|(file_id, ranges)| (file_id, ranges.into_iter().collect())).collect()
+    res.into_iter()
+        .map(|(file_id, ranges)| (file_id, ranges.into_iter().collect()))
+        .collect()
 }
 
 fn cover_range(r0: Option<TextRange>, r1: Option<TextRange>) -> Option<TextRange> {
@@ -686,7 +698,9 @@ fn original_frange(
     file_id: HirFileId,
     text_range: Option<TextRange>,
 ) -> Option<FileRange> {
-    InFile::new(file_id, text_range?).original_node_file_range_opt(db).map(|(frange, _)| frange)
+    InFile::new(file_id, text_range?)
+        .original_node_file_range_opt(db)
+        .map(|(frange, _)| frange)
 }
 
 fn merge_map(res: &mut HighlightMap, new: Option<HighlightMap>) {
@@ -709,7 +723,11 @@ struct WalkExpandedExprCtx<'a> {
 
 impl<'a> WalkExpandedExprCtx<'a> {
     fn new(sema: &'a Semantics<'a, RootDatabase>) -> Self {
-        Self { sema, depth: 0, check_ctx: &is_closure_or_blk_with_modif }
+        Self {
+            sema,
+            depth: 0,
+            check_ctx: &is_closure_or_blk_with_modif,
+        }
     }
 
     fn with_check_ctx(&self, check_ctx: &'static dyn Fn(&ast::Expr) -> bool) -> Self {
@@ -717,10 +735,7 @@ impl<'a> WalkExpandedExprCtx<'a> {
     }
 
     fn walk(&mut self, expr: &ast::Expr, cb: &mut dyn FnMut(usize, ast::Expr)) {
-        preorder_expr_with_ctx_checker(
-            expr,
-            self.check_ctx,
-            &mut |ev: WalkEvent<ast::Expr>| {
+        preorder_expr_with_ctx_checker(expr, self.check_ctx, &mut |ev: WalkEvent<ast::Expr>| {
             match ev {
                 syntax::WalkEvent::Enter(expr) => {
                     cb(self.depth, expr.clone());
@@ -752,8 +767,7 @@ impl<'a> WalkExpandedExprCtx<'a> {
                 _ => {}
             }
             false
-        },
-        )
+        })
     }
 
     fn handle_expanded(
@@ -854,7 +868,10 @@ mod tests {
     ) {
         let (analysis, pos, annotations) = fixture::annotations(ra_fixture);
 
-        let hls = analysis.highlight_related(config, pos).unwrap().unwrap_or_default();
+        let hls = analysis
+            .highlight_related(config, pos)
+            .unwrap()
+            .unwrap_or_default();
 
         let mut expected =
             annotations.into_iter().map(|(r, access)| (r.range, access)).collect::<Vec<_>>();
@@ -864,7 +881,10 @@ mod tests {
             .map(|hl| {
                 (
                     hl.range,
-                    hl.category.iter_names().map(|(name, _flag)| name.to_lowercase()).join(","),
+                    hl.category
+                    .iter_names()
+                    .map(|(name, _flag)| name.to_lowercase())
+                    .join(","),
                 )
             })
             .collect();
@@ -2040,7 +2060,8 @@ fn test() {
     }
     #[test]
     fn return_in_macros() {
-        check(r#"
+        check(
+            r#"
 macro_rules! N {
     ($i:ident, $x:expr, $blk:expr) => {
         for $i in 0..$x {
@@ -2067,11 +2088,13 @@ fn main() {
         })();
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn return_in_closure() {
-        check(r#"
+        check(
+            r#"
 macro_rules! N {
     ($i:ident, $x:expr, $blk:expr) => {
         for $i in 0..$x {
@@ -2097,11 +2120,13 @@ fn main() {
         })();
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn return_in_try() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     fn f() {
  // ^^
@@ -2114,11 +2139,13 @@ fn main() {
      // ^^^^^^
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn break_in_try() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     for i in 1..100 {
  // ^^^
@@ -2128,11 +2155,13 @@ fn main() {
         };
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn no_highlight_on_return_in_macro_call() {
-        check(r#"
+        check(
+            r#"
 //- minicore:include
 //- /lib.rs
 macro_rules! M {
@@ -2157,11 +2186,13 @@ fn main() {
 {
     return;
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn nested_match() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     match$0 0 {
  // ^^^^^
@@ -2175,11 +2206,13 @@ fn main() {
           // ^
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn single_arm_highlight() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     match 0 {
         0 =>$0 {
@@ -2191,7 +2224,8 @@ fn main() {
         _ => 2,
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn no_branches_when_disabled() {
@@ -2210,7 +2244,8 @@ fn main() {
     }
     #[test]
     fn asm() {
-        check(r#"
+        check(
+            r#"
 //- minicore: asm
 #[inline]
 pub unsafe fn bootstrap() -> ! {
@@ -2233,11 +2268,13 @@ pub unsafe fn bootstrap() -> ! {
         options(noreturn, nomem, nostack),
     );
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn complex_arms_highlight() {
-        check(r#"
+        check(
+            r#"
 fn calculate(n: i32) -> i32 { n * 2 }
 
 fn main() {
@@ -2257,11 +2294,13 @@ fn main() {
         },
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn match_in_macro_highlight() {
-        check(r#"
+        check(
+            r#"
 macro_rules! M {
     ($e:expr) => { $e };
 }
@@ -2277,7 +2316,8 @@ fn main() {
         }
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn match_in_macro_highlight_2() {
@@ -2307,7 +2347,8 @@ fn main() {
     }
     #[test]
     fn nested_if_else() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     if$0 true {
  // ^^
@@ -2323,11 +2364,13 @@ fn main() {
      // ^
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn if_else_if_highlight() {
-        check(r#"
+        check(
+            r#"
 fn main() {
     if$0 true {
  // ^^
@@ -2342,11 +2385,13 @@ fn main() {
      // ^
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn complex_if_branches() {
-        check(r#"
+        check(
+            r#"
 fn calculate(n: i32) -> i32 { n * 2 }
 
 fn main() {
@@ -2366,11 +2411,13 @@ fn main() {
         }
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn if_in_macro_highlight() {
-        check(r#"
+        check(
+            r#"
 macro_rules! M {
     ($e:expr) => { $e };
 }
@@ -2387,12 +2434,14 @@ fn main() {
         }
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn match_in_macro() {
         // We should not highlight the outer `match` expression.
-        check(r#"
+        check(
+            r#"
 macro_rules! M {
     (match) => { 1 };
 }
@@ -2405,7 +2454,8 @@ fn main() {
         }
     }
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn labeled_block_tail_expr() {
@@ -101,14 +101,18 @@ impl HoverAction {
                     mod_path: render::path(
                         db,
                         it.module(db)?,
-                        it.name(db).map(|name| name.display(db, edition).to_string()),
+                        it.name(db)
+                            .map(|name| name.display(db, edition).to_string()),
                         edition,
                     ),
                     nav: it.try_to_nav(sema)?.call_site(),
                 })
             })
             .collect::<Vec<_>>();
-        targets.is_empty().not().then_some(HoverAction::GoToType(targets))
+        targets
+            .is_empty()
+            .not()
+            .then_some(HoverAction::GoToType(targets))
     }
 }
 
@@ -531,13 +535,13 @@ fn notable_traits<'db>(
                 (
                     trait_,
                     trait_
-                        .items(db)
-                        .into_iter()
-                        .filter_map(hir::AssocItem::as_type_alias)
-                        .map(|alias| {
-                            (ty.normalize_trait_assoc_type(db, &[], alias), alias.name(db))
-                        })
-                        .collect::<Vec<_>>(),
+                    .items(db)
+                    .into_iter()
+                    .filter_map(hir::AssocItem::as_type_alias)
+                    .map(|alias| {
+                        (ty.normalize_trait_assoc_type(db, &[], alias), alias.name(db))
+                    })
+                    .collect::<Vec<_>>(),
                 )
             })
         })
@@ -564,7 +568,9 @@ fn show_implementations_action(
         Definition::SelfType(it) => it.self_ty(sema.db).as_adt(),
         _ => None,
     }?;
-    adt.try_to_nav(sema).map(UpmappingResult::call_site).map(to_action)
+    adt.try_to_nav(sema)
+        .map(UpmappingResult::call_site)
+        .map(to_action)
 }
 
 fn show_fn_references_action(
@@ -573,12 +579,14 @@ fn show_fn_references_action(
 ) -> Option<HoverAction> {
     match def {
         Definition::Function(it) => {
-            it.try_to_nav(sema).map(UpmappingResult::call_site).map(|nav_target| {
-                HoverAction::Reference(FilePosition {
-                    file_id: nav_target.file_id,
-                    offset: nav_target.focus_or_full_range().start(),
+            it.try_to_nav(sema)
+                .map(UpmappingResult::call_site)
+                .map(|nav_target| {
+                    HoverAction::Reference(FilePosition {
+                        file_id: nav_target.file_id,
+                        offset: nav_target.focus_or_full_range().start(),
+                    })
                 })
-            })
         }
         _ => None,
     }
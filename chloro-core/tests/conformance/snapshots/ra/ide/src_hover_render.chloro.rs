@@ -56,7 +56,16 @@ pub(super) fn closure_expr(
     display_target: DisplayTarget,
 ) -> Option<HoverResult> {
     let TypeInfo { original, .. } = sema.type_of_expr(&c.into())?;
-    closure_ty(sema, config, &TypeInfo { original, adjusted: None }, edition, display_target)
+    closure_ty(
+        sema,
+        config,
+        &TypeInfo {
+            original,
+            adjusted: None,
+        },
+        edition,
+        display_target,
+    )
 }
 
 pub(super) fn try_expr(
@@ -403,7 +412,9 @@ fn definition_owner_name(db: &RootDatabase, def: Definition, edition: Edition) -
 
             hir::GenericDef::Impl(i) => i.self_ty(db).as_adt().map(|adt| adt.name(db)),
             hir::GenericDef::Function(it) => {
-                let container = it.as_assoc_item(db).and_then(|assoc| match assoc.container(db) {
+                let container = it
+                    .as_assoc_item(db)
+                    .and_then(|assoc| match assoc.container(db) {
                     hir::AssocItemContainer::Trait(t) => Some(t.name(db)),
                     hir::AssocItemContainer::Impl(i) => {
                         i.self_ty(db).as_adt().map(|adt| adt.name(db))
@@ -421,7 +432,9 @@ fn definition_owner_name(db: &RootDatabase, def: Definition, edition: Edition) -
                 }
             }
             hir::GenericDef::Const(it) => {
-                let container = it.as_assoc_item(db).and_then(|assoc| match assoc.container(db) {
+                let container = it
+                    .as_assoc_item(db)
+                    .and_then(|assoc| match assoc.container(db) {
                     hir::AssocItemContainer::Trait(t) => Some(t.name(db)),
                     hir::AssocItemContainer::Impl(i) => {
                         i.self_ty(db).as_adt().map(|adt| adt.name(db))
@@ -454,9 +467,7 @@ fn definition_owner_name(db: &RootDatabase, def: Definition, edition: Edition) -
             }
         }
     }
-    .map(
-        |name| name.display(db, edition).to_string(),
-    )
+    .map(|name| name.display(db, edition).to_string())
 }
 
 pub(super) fn path(
@@ -465,13 +476,21 @@ pub(super) fn path(
     item_name: Option<String>,
     edition: Edition,
 ) -> String {
-    let crate_name = module.krate().display_name(db).as_ref().map(|it| it.to_string());
+    let crate_name = module
+        .krate()
+        .display_name(db)
+        .as_ref()
+        .map(|it| it.to_string());
     let module_path = module
         .path_to_root(db)
         .into_iter()
         .rev()
         .flat_map(|it| it.name(db).map(|name| name.display(db, edition).to_string()));
-    crate_name.into_iter().chain(module_path).chain(item_name).join("::")
+    crate_name
+        .into_iter()
+        .chain(module_path)
+        .chain(item_name)
+        .join("::")
 }
 
 pub(super) fn definition(
@@ -1045,7 +1064,8 @@ fn closure_ty(
     display_target: DisplayTarget,
 ) -> Option<HoverResult> {
     let c = original.as_closure()?;
-    let mut captures_rendered = c.captured_items(sema.db)
+    let mut captures_rendered = c
+        .captured_items(sema.db)
         .into_iter()
         .map(|it| {
             let borrow_kind = match it.kind() {
@@ -1121,7 +1141,8 @@ fn definition_path(db: &RootDatabase, &def: &Definition, edition: Edition) -> Op
         return None;
     }
     let rendered_parent = definition_owner_name(db, def, edition);
-    def.module(db).map(|module| path(db, module, rendered_parent, edition))
+    def.module(db)
+        .map(|module| path(db, module, rendered_parent, edition))
 }
 
 fn markup(
@@ -1270,7 +1291,11 @@ struct KeywordHint {
 
 impl KeywordHint {
     fn new(description: String, keyword_mod: String) -> Self {
-        Self { description, keyword_mod, actions: Vec::default() }
+        Self {
+            description,
+            keyword_mod,
+            actions: Vec::default(),
+        }
     }
 }
 
@@ -1412,8 +1437,7 @@ mod tests {
     const TESTERS: [u128; 10] = [0, 1, 2, 3, 4, 255, 256, 257, u128::MAX - 1, u128::MAX];
     #[test]
     fn test_is_pwr2minus1() {
-        const OUTCOMES: [bool; 10] =
-            [true, true, false, true, false, true, false, false, false, true];
+        const OUTCOMES: [bool; 10] = [true, true, false, true, false, true, false, false, false, true];
         for (test, expected) in TESTERS.iter().zip(OUTCOMES) {
             let actual = is_pwr2minus1(*test);
             assert_eq!(actual, expected, "is_pwr2minu1({test}) gave {actual}, expected {expected}");
@@ -1421,8 +1445,7 @@ mod tests {
     }
     #[test]
     fn test_is_pwr2plus1() {
-        const OUTCOMES: [bool; 10] =
-            [false, false, true, true, false, false, false, true, false, false];
+        const OUTCOMES: [bool; 10] = [false, false, true, true, false, false, false, true, false, false];
         for (test, expected) in TESTERS.iter().zip(OUTCOMES) {
             let actual = is_pwr2plus1(*test);
             assert_eq!(actual, expected, "is_pwr2plus1({test}) gave {actual}, expected {expected}");
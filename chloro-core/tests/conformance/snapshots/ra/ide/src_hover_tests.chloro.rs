@@ -32,8 +32,14 @@ fn check_hover_no_result(#[rust_analyzer::rust_fixture] ra_fixture: &str) {
     let (analysis, position) = fixture::position(ra_fixture);
     let hover = analysis
         .hover(
-            &HoverConfig { links_in_hover: true, ..HOVER_BASE_CONFIG },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            &HoverConfig {
+                links_in_hover: true,
+                ..HOVER_BASE_CONFIG
+            },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap();
     assert!(hover.is_none(), "hover not expected but found: {:?}", hover.unwrap());
@@ -45,8 +51,14 @@ fn check(#[rust_analyzer::rust_fixture] ra_fixture: &str, expect: Expect) {
     let (analysis, position) = fixture::position(ra_fixture);
     let hover = analysis
         .hover(
-            &HoverConfig { links_in_hover: true, ..HOVER_BASE_CONFIG },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            &HoverConfig {
+                links_in_hover: true,
+                ..HOVER_BASE_CONFIG
+            },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap()
         .unwrap();
@@ -72,7 +84,10 @@ fn check_hover_fields_limit(
                 max_fields_count: fields_count.into(),
                 ..HOVER_BASE_CONFIG
             },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap()
         .unwrap();
@@ -98,7 +113,10 @@ fn check_hover_enum_variants_limit(
                 max_enum_variants_count: variants_count.into(),
                 ..HOVER_BASE_CONFIG
             },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap()
         .unwrap();
@@ -124,7 +142,10 @@ fn check_assoc_count(
                 max_trait_assoc_items_count: Some(count),
                 ..HOVER_BASE_CONFIG
             },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap()
         .unwrap();
@@ -139,10 +160,10 @@ fn check_assoc_count(
 fn check_hover_no_links(#[rust_analyzer::rust_fixture] ra_fixture: &str, expect: Expect) {
     let (analysis, position) = fixture::position(ra_fixture);
     let hover = analysis
-        .hover(
-            &HOVER_BASE_CONFIG,
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
-        )
+        .hover(&HOVER_BASE_CONFIG, FileRange {
+            file_id: position.file_id,
+            range: TextRange::empty(position.offset),
+        })
         .unwrap()
         .unwrap();
 
@@ -157,8 +178,14 @@ fn check_hover_no_memory_layout(#[rust_analyzer::rust_fixture] ra_fixture: &str,
     let (analysis, position) = fixture::position(ra_fixture);
     let hover = analysis
         .hover(
-            &HoverConfig { memory_layout: None, ..HOVER_BASE_CONFIG },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            &HoverConfig {
+                memory_layout: None,
+                ..HOVER_BASE_CONFIG
+            },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap()
         .unwrap();
@@ -179,7 +206,10 @@ fn check_hover_no_markdown(#[rust_analyzer::rust_fixture] ra_fixture: &str, expe
                 format: HoverDocFormat::PlainText,
                 ..HOVER_BASE_CONFIG
             },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap()
         .unwrap();
@@ -195,13 +225,23 @@ fn check_actions(#[rust_analyzer::rust_fixture] ra_fixture: &str, expect: Expect
     let (analysis, file_id, position) = fixture::range_or_position(ra_fixture);
     let mut hover = analysis
         .hover(
-            &HoverConfig { links_in_hover: true, ..HOVER_BASE_CONFIG },
-            FileRange { file_id, range: position.range_or_empty() },
+            &HoverConfig {
+                links_in_hover: true,
+                ..HOVER_BASE_CONFIG
+            },
+            FileRange {
+                file_id,
+                range: position.range_or_empty(),
+            },
         )
         .unwrap()
         .unwrap();
     // stub out ranges into minicore as they can change every now and then
-    hover.info.actions.iter_mut().for_each(|action| match action {
+    hover
+        .info
+        .actions
+        .iter_mut()
+        .for_each(|action| match action {
         super::HoverAction::GoToType(act) => act.iter_mut().for_each(|data| {
             if data.nav.file_id == file_id {
                 return;
@@ -225,11 +265,21 @@ fn check_hover_range(#[rust_analyzer::rust_fixture] ra_fixture: &str, expect: Ex
 fn check_hover_range_actions(#[rust_analyzer::rust_fixture] ra_fixture: &str, expect: Expect) {
     let (analysis, range) = fixture::range(ra_fixture);
     let mut hover = analysis
-        .hover(&HoverConfig { links_in_hover: true, ..HOVER_BASE_CONFIG }, range)
+        .hover(
+            &HoverConfig {
+                links_in_hover: true,
+                ..HOVER_BASE_CONFIG
+            },
+            range,
+        )
         .unwrap()
         .unwrap();
     // stub out ranges into minicore as they can change every now and then
-    hover.info.actions.iter_mut().for_each(|action| match action {
+    hover
+        .info
+        .actions
+        .iter_mut()
+        .for_each(|action| match action {
         super::HoverAction::GoToType(act) => act.iter_mut().for_each(|data| {
             if data.nav.file_id == range.file_id {
                 return;
@@ -1772,23 +1822,18 @@ fn main() {
 
 #[test]
 fn hover_for_local_variable() {
-    check(
-        r#"fn func(foo: i32) { fo$0o; }"#,
-        expect![[r#"
+    check(r#"fn func(foo: i32) { fo$0o; }"#, expect![[r#"
             *foo*
 
             ```rust
             foo: i32
             ```
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
 fn hover_for_local_variable_pat() {
-    check(
-        r#"fn func(fo$0o: i32) {}"#,
-        expect![[r#"
+    check(r#"fn func(fo$0o: i32) {}"#, expect![[r#"
             *foo*
 
             ```rust
@@ -1798,29 +1843,23 @@ fn hover_for_local_variable_pat() {
             ---
 
             size = 4, align = 4, no Drop
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
 fn hover_local_var_edge() {
-    check(
-        r#"fn func(foo: i32) { if true { $0foo; }; }"#,
-        expect![[r#"
+    check(r#"fn func(foo: i32) { if true { $0foo; }; }"#, expect![[r#"
             *foo*
 
             ```rust
             foo: i32
             ```
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
 fn hover_for_param_edge() {
-    check(
-        r#"fn func($0foo: i32) {}"#,
-        expect![[r#"
+    check(r#"fn func($0foo: i32) {}"#, expect![[r#"
             *foo*
 
             ```rust
@@ -1830,8 +1869,7 @@ fn hover_for_param_edge() {
             ---
 
             size = 4, align = 4, no Drop
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
@@ -2215,16 +2253,13 @@ fn f() { fo$0o!(); }
 
 #[test]
 fn test_hover_tuple_field() {
-    check(
-        r#"struct TS(String, i32$0);"#,
-        expect![[r#"
+    check(r#"struct TS(String, i32$0);"#, expect![[r#"
             *i32*
 
             ```rust
             i32
             ```
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
@@ -6858,8 +6893,14 @@ fn hover_feature() {
     let (analysis, position) = fixture::position(r#"#![feature(intrinsics$0)]"#);
     analysis
         .hover(
-            &HoverConfig { links_in_hover: true, ..HOVER_BASE_CONFIG },
-            FileRange { file_id: position.file_id, range: TextRange::empty(position.offset) },
+            &HoverConfig {
+                links_in_hover: true,
+                ..HOVER_BASE_CONFIG
+            },
+            FileRange {
+                file_id: position.file_id,
+                range: TextRange::empty(position.offset),
+            },
         )
         .unwrap()
         .unwrap();
@@ -563,10 +563,11 @@ impl InlayHintLabel {
             [InlayHintLabelPart { text, linked_location: None, tooltip: None }, ..] => {
                 text.insert_str(0, s)
             }
-            _ => self.parts.insert(
-                0,
-                InlayHintLabelPart { text: s.into(), linked_location: None, tooltip: None },
-            ),
+            _ => self.parts.insert(0, InlayHintLabelPart {
+                text: s.into(),
+                linked_location: None,
+                tooltip: None,
+            }),
         }
     }
 
@@ -656,8 +657,7 @@ impl fmt::Debug for InlayHintLabelPart {
             Self { text, linked_location: None, tooltip: None | Some(LazyProperty::Lazy) } => {
                 text.fmt(f)
             }
-            Self { text, linked_location, tooltip } => f
-                .debug_struct("InlayHintLabelPart")
+            Self { text, linked_location, tooltip } => f.debug_struct("InlayHintLabelPart")
                 .field("text", text)
                 .field("linked_location", linked_location)
                 .field(
@@ -777,8 +777,7 @@ fn label_of_ty(
                     label_builder.write_str(LABEL_END)?;
                     Ok(())
                 }
-                None => ty
-                    .display_truncated(sema.db, max_length, display_target)
+                None => ty.display_truncated(sema.db, max_length, display_target)
                     .with_closure_style(config.closure_style)
                     .write_to(label_builder),
             }
@@ -846,15 +845,17 @@ fn ty_to_text_edit(
     let rendered = sema
         .scope(node_for_hint)
         .and_then(|scope| ty.display_source_code(scope.db, scope.module().into(), false).ok())?;
-    Some(config.lazy_text_edit(|| {
-        let mut builder = TextEdit::builder();
-        builder.insert(offset_to_insert_ty, prefix.into());
-        builder.insert(offset_to_insert_ty, rendered);
+    Some(
+        config.lazy_text_edit(|| {
+            let mut builder = TextEdit::builder();
+            builder.insert(offset_to_insert_ty, prefix.into());
+            builder.insert(offset_to_insert_ty, rendered);
 
-        additional_edits(&mut builder);
+            additional_edits(&mut builder);
 
-        builder.finish()
-    }))
+            builder.finish()
+        }),
+    )
 }
 
 fn closure_has_block_body(closure: &ast::ClosureExpr) -> bool {
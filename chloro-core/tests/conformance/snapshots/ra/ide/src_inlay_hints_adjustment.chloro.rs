@@ -48,7 +48,9 @@ pub(super) fn hints(
 
     let descended = sema.descend_node_into_attributes(expr.clone()).pop();
     let desc_expr = descended.as_ref().unwrap_or(expr);
-    let mut adjustments = sema.expr_adjustments(desc_expr).filter(|it| !it.is_empty())?;
+    let mut adjustments = sema
+        .expr_adjustments(desc_expr)
+        .filter(|it| !it.is_empty())?;
 
     if config.adjustment_hints_disable_reborrows {
         // Remove consecutive deref-ref, i.e. reborrows.
@@ -554,9 +556,10 @@ impl core::ops::IndexMut for Struct {}
     fn adjustment_hints_prefer_prefix() {
         check_with_config(
             InlayHintsConfig {
-            adjustment_hints: AdjustmentHints::Always,
-            adjustment_hints_mode: AdjustmentHintsMode::PreferPrefix,
-        },
+                adjustment_hints: AdjustmentHints::Always,
+                adjustment_hints_mode: AdjustmentHintsMode::PreferPrefix,
+                ..DISABLED_CONFIG
+            },
             r#"
 fn main() {
     let _: u32         = loop {};
@@ -578,9 +581,10 @@ fn main() {
     fn adjustment_hints_prefer_postfix() {
         check_with_config(
             InlayHintsConfig {
-            adjustment_hints: AdjustmentHints::Always,
-            adjustment_hints_mode: AdjustmentHintsMode::PreferPostfix,
-        },
+                adjustment_hints: AdjustmentHints::Always,
+                adjustment_hints_mode: AdjustmentHintsMode::PreferPostfix,
+                ..DISABLED_CONFIG
+            },
             r#"
 fn main() {
     let _: u32         = loop {};
@@ -602,7 +606,10 @@ fn main() {
     fn never_to_never_is_never_shown() {
         cov_mark::check!(same_type_adjustment);
         check_with_config(
-            InlayHintsConfig { adjustment_hints: AdjustmentHints::Always, ..DISABLED_CONFIG },
+            InlayHintsConfig {
+                adjustment_hints: AdjustmentHints::Always,
+                ..DISABLED_CONFIG
+            },
             r#"
 fn never() -> ! {
     return loop {};
@@ -618,9 +625,10 @@ fn or_else() {
     fn adjustment_hints_unsafe_only() {
         check_with_config(
             InlayHintsConfig {
-            adjustment_hints: AdjustmentHints::Always,
-            adjustment_hints_hide_outside_unsafe: true,
-        },
+                adjustment_hints: AdjustmentHints::Always,
+                adjustment_hints_hide_outside_unsafe: true,
+                ..DISABLED_CONFIG
+            },
             r#"
 unsafe fn enabled() {
     f(&&());
@@ -695,7 +703,10 @@ fn a() {
     #[test]
     fn let_stmt_explicit_ty() {
         check_with_config(
-            InlayHintsConfig { adjustment_hints: AdjustmentHints::Always, ..DISABLED_CONFIG },
+            InlayHintsConfig {
+                adjustment_hints: AdjustmentHints::Always,
+                ..DISABLED_CONFIG
+            },
             r#"
 fn main() {
     let () = return;
@@ -133,7 +133,10 @@ fn is_named_constructor(
         }
     }?;
 
-    let expr = sema.descend_node_into_attributes(expr.clone()).pop().unwrap_or(expr);
+    let expr = sema
+        .descend_node_into_attributes(expr.clone())
+        .pop()
+        .unwrap_or(expr);
     // unwrap postfix expressions
     let expr = match expr {
         ast::Expr::TryExpr(it) => it.expr(),
@@ -150,7 +153,10 @@ fn is_named_constructor(
     };
     let path = expr.path()?;
 
-    let callable = sema.type_of_expr(&ast::Expr::PathExpr(expr))?.original.as_callable(sema.db);
+    let callable = sema
+        .type_of_expr(&ast::Expr::PathExpr(expr))?
+        .original
+        .as_callable(sema.db);
     let callable_kind = callable.map(|it| it.kind());
     let qual_seg = match callable_kind {
         Some(hir::CallableKind::Function(_) | hir::CallableKind::TupleEnumVariant(_)) => {
@@ -299,7 +305,10 @@ fn main(a: SliceIter<'_, Container>) {
         );
         analysis
             .inlay_hints(
-                &InlayHintsConfig { chaining_hints: true, ..DISABLED_CONFIG },
+                &InlayHintsConfig {
+                    chaining_hints: true,
+                    ..DISABLED_CONFIG
+                },
                 file_id,
                 None,
             )
@@ -345,7 +354,8 @@ fn f<'a>() {
     }
     #[test]
     fn fn_hints() {
-        check_types(r#"
+        check_types(
+            r#"
 //- minicore: fn, sized
 fn foo() -> impl Fn() { loop {} }
 fn foo1() -> impl Fn(f64) { loop {} }
@@ -374,7 +384,8 @@ fn main() {
     let foo = foo7();
      // ^^^ *const impl Fn(f64, f64) -> u32
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn check_hint_range_limit() {
@@ -407,7 +418,10 @@ fn main() {
         let expected = extract_annotations(&analysis.file_text(file_id).unwrap());
         let inlay_hints = analysis
             .inlay_hints(
-                &InlayHintsConfig { type_hints: true, ..DISABLED_CONFIG },
+                &InlayHintsConfig {
+                    type_hints: true,
+                    ..DISABLED_CONFIG
+                },
                 file_id,
                 Some(TextRange::new(TextSize::from(491), TextSize::from(640))),
             )
@@ -418,7 +432,8 @@ fn main() {
     }
     #[test]
     fn fn_hints_ptr_rpit_fn_parentheses() {
-        check_types(r#"
+        check_types(
+            r#"
 //- minicore: fn, sized
 trait Trait {}
 
@@ -455,7 +470,8 @@ fn main() {
     let foo = foo10();
     //  ^^^ *const impl Fn()
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn unit_structs_have_no_type_hints() {
@@ -134,7 +134,8 @@ pub(super) fn hints(
     }
 
     let mut lines = 1;
-    node.text().for_each_chunk(|s| lines += s.matches('\n').count());
+    node.text()
+        .for_each_chunk(|s| lines += s.matches('\n').count());
     if lines < min_lines {
         return None;
     }
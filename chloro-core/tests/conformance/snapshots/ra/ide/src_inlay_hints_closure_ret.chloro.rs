@@ -22,7 +22,9 @@ pub(super) fn hints(
         return None;
     }
 
-    let ret_type = closure.ret_type().map(|rt| (rt.thin_arrow_token(), rt.ty().is_some()));
+    let ret_type = closure
+        .ret_type()
+        .map(|rt| (rt.thin_arrow_token(), rt.ty().is_some()));
     let arrow = match ret_type {
         Some((_, true)) => return None,
         Some((arrow, _)) => arrow,
@@ -38,7 +40,9 @@ pub(super) fn hints(
 
     let resolve_parent = Some(closure.syntax().text_range());
     let descended_closure = sema.descend_node_into_attributes(closure.clone()).pop()?;
-    let ty = sema.type_of_expr(&ast::Expr::ClosureExpr(descended_closure.clone()))?.adjusted();
+    let ty = sema
+        .type_of_expr(&ast::Expr::ClosureExpr(descended_closure.clone()))?
+        .adjusted();
     let callable = ty.as_callable(sema.db)?;
     let ty = callable.return_type();
     if arrow.is_none() && ty.is_unit() {
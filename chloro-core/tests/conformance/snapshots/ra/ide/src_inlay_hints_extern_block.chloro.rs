@@ -79,16 +79,18 @@ fn item_hint(
         pad_right: true,
         kind: crate::InlayKind::ExternUnsafety,
         label: crate::InlayHintLabel::from("unsafe"),
-        text_edit: Some(config.lazy_text_edit(|| {
-            let mut builder = TextEdit::builder();
-            builder.insert(token.text_range().start(), "unsafe ".to_owned());
-            if extern_block.unsafe_token().is_none()
+        text_edit: Some(
+            config.lazy_text_edit(|| {
+                let mut builder = TextEdit::builder();
+                builder.insert(token.text_range().start(), "unsafe ".to_owned());
+                if extern_block.unsafe_token().is_none()
                 && let Some(abi) = extern_block.abi()
             {
                 builder.insert(abi.syntax().text_range().start(), "unsafe ".to_owned());
             }
-            builder.finish()
-        })),
+                builder.finish()
+            }),
+        ),
         resolve_parent: Some(extern_block.syntax().text_range()),
     }
 }
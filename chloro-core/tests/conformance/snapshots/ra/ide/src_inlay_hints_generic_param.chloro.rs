@@ -195,7 +195,8 @@ mod tests {
     }
     #[test]
     fn type_only() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 struct A<X, Y> {
     x: X,
     y: Y,
@@ -203,22 +204,26 @@ struct A<X, Y> {
 
 fn foo(a: A<usize,  u32>) {}
           //^^^^^ X ^^^ Y
-"#)
+"#,
+        )
     }
     #[test]
     fn lifetime_and_type() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 struct A<'a, X> {
     x: &'a X
 }
 
 fn foo<'b>(a: A<'b,  u32>) {}
               //^^ 'a^^^ X
-"#)
+"#,
+        )
     }
     #[test]
     fn omit_lifetime() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 struct A<'a, X> {
     x: &'a X
 }
@@ -228,20 +233,24 @@ fn foo() {
     let a: A<i32> = A { x: &x };
           // ^^^ X
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn const_only() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 struct A<const X: usize, const Y: usize> {};
 
 fn foo(a: A<12, 2>) {}
           //^^ X^ Y
-"#)
+"#,
+        )
     }
     #[test]
     fn lifetime_and_type_and_const() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 struct A<'a, X, const LEN: usize> {
     x: &'a [X; LEN],
 }
@@ -254,11 +263,13 @@ fn foo<'b>(a: A<
     3
  // ^ LEN
     >) {}
-"#)
+"#,
+        )
     }
     #[test]
     fn const_only_config() {
-        generic_param_name_hints_const_only(r#"
+        generic_param_name_hints_const_only(
+            r#"
 struct A<'a, X, const LEN: usize> {
     x: &'a [X; LEN],
 }
@@ -269,11 +280,13 @@ fn foo<'b>(a: A<
     3
  // ^ LEN
     >) {}
-"#)
+"#,
+        )
     }
     #[test]
     fn assoc_type() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 trait Trait<T> {
     type Assoc1;
     type Assoc2;
@@ -281,11 +294,13 @@ trait Trait<T> {
 
 fn foo() -> impl Trait<i32, Assoc1 = u32, Assoc2 = u32> {}
                     // ^^^ T
-"#)
+"#,
+        )
     }
     #[test]
     fn hide_similar() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 struct A<'a, X, const N: usize> {
     x: &'a [X; N],
 }
@@ -297,16 +312,19 @@ mod m {
 }
 
 fn foo<'a>(a: A<'a, m::X, N>) {}
-"#)
+"#,
+        )
     }
     #[test]
     fn mismatching_args() {
-        generic_param_name_hints_always(r#"
+        generic_param_name_hints_always(
+            r#"
 struct A<X, const N: usize> {
     x: [X; N]
 }
 
 type InvalidType = A<3, i32>;
-"#)
+"#,
+        )
     }
 }
@@ -32,7 +32,9 @@ pub(super) fn fn_hints(
     let param_list = func.param_list()?;
     let generic_param_list = func.generic_param_list();
     let ret_type = func.ret_type();
-    let self_param = param_list.self_param().filter(|it| it.amp_token().is_some());
+    let self_param = param_list
+        .self_param()
+        .filter(|it| it.amp_token().is_some());
     let gpl_append_range = func.name()?.syntax().text_range();
     hints_(
         acc,
@@ -40,13 +42,15 @@ pub(super) fn fn_hints(
         fd,
         config,
         param_list.params().filter_map(|it| {
-            Some((
-                it.pat().and_then(|it| match it {
+            Some(
+                (
+                    it.pat().and_then(|it| match it {
                     ast::Pat::IdentPat(p) => p.name(),
                     _ => None,
                 }),
-                it.ty()?,
-            ))
+                    it.ty()?,
+                ),
+            )
         }),
         generic_param_list,
         ret_type,
@@ -87,7 +91,9 @@ pub(super) fn fn_ptr_hints(
         .and_then(|it| it.for_binder());
 
     let param_list = func.param_list()?;
-    let generic_param_list = parent_for_binder.as_ref().and_then(|it| it.generic_param_list());
+    let generic_param_list = parent_for_binder
+        .as_ref()
+        .and_then(|it| it.generic_param_list());
     let ret_type = func.ret_type();
     let for_kw = parent_for_binder.as_ref().and_then(|it| it.for_token());
     hints_(
@@ -96,13 +102,15 @@ pub(super) fn fn_ptr_hints(
         fd,
         config,
         param_list.params().filter_map(|it| {
-            Some((
-                it.pat().and_then(|it| match it {
+            Some(
+                (
+                    it.pat().and_then(|it| match it {
                     ast::Pat::IdentPat(p) => p.name(),
                     _ => None,
                 }),
-                it.ty()?,
-            ))
+                    it.ty()?,
+                ),
+            )
         }),
         generic_param_list,
         ret_type,
@@ -153,14 +161,18 @@ pub(super) fn fn_path_hints(
         .find_map(ast::ForType::cast)
         .and_then(|it| it.for_binder());
 
-    let generic_param_list = parent_for_binder.as_ref().and_then(|it| it.generic_param_list());
+    let generic_param_list = parent_for_binder
+        .as_ref()
+        .and_then(|it| it.generic_param_list());
     let for_kw = parent_for_binder.as_ref().and_then(|it| it.for_token());
     hints_(
         acc,
         ctx,
         fd,
         config,
-        param_list.type_args().filter_map(|it| Some((None, it.ty()?))),
+        param_list
+            .type_args()
+            .filter_map(|it| Some((None, it.ty()?))),
         generic_param_list,
         ret_type,
         None,
@@ -190,7 +202,8 @@ pub(super) fn fn_path_hints(
 }
 
 fn path_as_fn(path: &ast::Path) -> Option<(ast::ParenthesizedArgList, Option<ast::RetType>)> {
-    path.segment().and_then(|it| it.parenthesized_arg_list().zip(Some(it.ret_type())))
+    path.segment()
+        .and_then(|it| it.parenthesized_arg_list().zip(Some(it.ret_type())))
 }
 
 fn hints_(
@@ -99,12 +99,16 @@ fn get_callable<'db>(
         ast::Expr::CallExpr(expr) => {
             let descended = sema.descend_node_into_attributes(expr.clone()).pop();
             let expr = descended.as_ref().unwrap_or(expr);
-            sema.type_of_expr(&expr.expr()?)?.original.as_callable(sema.db).zip(expr.arg_list())
+            sema.type_of_expr(&expr.expr()?)?
+                .original
+                .as_callable(sema.db)
+                .zip(expr.arg_list())
         }
         ast::Expr::MethodCallExpr(expr) => {
             let descended = sema.descend_node_into_attributes(expr.clone()).pop();
             let expr = descended.as_ref().unwrap_or(expr);
-            sema.resolve_method_call_as_callable(expr).zip(expr.arg_list())
+            sema.resolve_method_call_as_callable(expr)
+                .zip(expr.arg_list())
         }
         _ => None,
     }
@@ -157,14 +161,13 @@ fn should_hide_param_name_hint(
 /// `fn strip_suffix(suffix)` will be hidden.
 /// `fn stripsuffix(suffix)` will not be hidden.
 fn is_param_name_suffix_of_fn_name(param_name: &str, fn_name: &str) -> bool {
-    fn_name == param_name
-        || fn_name
-            .len()
-            .checked_sub(param_name.len())
-            .and_then(|at| fn_name.is_char_boundary(at).then(|| fn_name.split_at(at)))
-            .is_some_and(|(prefix, suffix)| {
-                suffix.eq_ignore_ascii_case(param_name) && prefix.ends_with('_')
-            })
+    fn_name == param_name || fn_name
+        .len()
+        .checked_sub(param_name.len())
+        .and_then(|at| fn_name.is_char_boundary(at).then(|| fn_name.split_at(at)))
+        .is_some_and(|(prefix, suffix)| {
+            suffix.eq_ignore_ascii_case(param_name) && prefix.ends_with('_')
+        })
 }
 
 fn is_argument_expr_similar_to_param_name(
@@ -177,8 +180,7 @@ fn is_argument_expr_similar_to_param_name(
         Some(Either::Right(path)) => {
             path.segment()
                 .and_then(|it| it.name_ref())
-                .is_some_and(|name_ref| name_ref.text().eq_ignore_ascii_case(param_name))
-                || is_adt_constructor_similar_to_param_name(sema, &path, param_name)
+                .is_some_and(|name_ref| name_ref.text().eq_ignore_ascii_case(param_name)) || is_adt_constructor_similar_to_param_name(sema, &path, param_name)
         }
         None => false,
     }
@@ -193,7 +195,9 @@ pub(super) fn is_argument_similar_to_param_name(
     debug_assert!(!argument.is_empty());
     debug_assert!(!param_name.is_empty());
     let param_name = param_name.split('_');
-    let argument = argument.iter().flat_map(|it| it.text_non_mutable().split('_'));
+    let argument = argument
+        .iter()
+        .flat_map(|it| it.text_non_mutable().split('_'));
 
     let prefix_match = zip(argument.clone(), param_name.clone())
         .all(|(arg, param)| arg.eq_ignore_ascii_case(param));
@@ -223,7 +227,9 @@ pub(super) fn get_segment_representation(
             }))
         }
         ast::Expr::FieldExpr(field_expr) => {
-            let expr = field_expr.expr().and_then(|expr| get_segment_representation(&expr));
+            let expr = field_expr
+                .expr()
+                .and_then(|expr| get_segment_representation(&expr));
             let name_ref = field_expr.name_ref()?;
             let res = match expr {
                 Some(Either::Left(mut left)) => {
@@ -289,9 +295,7 @@ fn is_adt_constructor_similar_to_param_name(
         }
         _ => None,
     })()
-    .unwrap_or(
-        false,
-    )
+    .unwrap_or(false)
 }
 
 #[cfg(test)]
@@ -421,7 +425,8 @@ fn main() {
     }
     #[test]
     fn self_param_hints() {
-        check_params(r#"
+        check_params(
+            r#"
 struct Foo;
 
 impl Foo {
@@ -435,11 +440,13 @@ fn main() {
     Foo::bar(&Foo);
            //^^^^ self
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn param_name_hints_show_for_literals() {
-        check_params(r#"pub fn test(a: i32, b: i32) -> [i32; 2] { [a, b] }
+        check_params(
+            r#"pub fn test(a: i32, b: i32) -> [i32; 2] { [a, b] }
 fn main() {
     test(
         0xa_b,
@@ -447,7 +454,8 @@ fn main() {
         0xa_b,
       //^^^^^ b
     );
-}"#)
+}"#,
+        )
     }
     #[test]
     fn function_call_parameter_hint() {
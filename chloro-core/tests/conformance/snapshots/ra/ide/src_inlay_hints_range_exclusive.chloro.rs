@@ -17,9 +17,12 @@ pub(super) fn hints(
 ) -> Option<()> {
     (config.range_exclusive_hints && range.end().is_some())
         .then(|| {
-            range.op_token().filter(|token| token.kind() == T![..]).map(|token| {
-                acc.push(inlay_hint(token));
-            })
+            range
+                .op_token()
+                .filter(|token| token.kind() == T![..])
+                .map(|token| {
+                    acc.push(inlay_hint(token));
+                })
         })
         .flatten()
 }
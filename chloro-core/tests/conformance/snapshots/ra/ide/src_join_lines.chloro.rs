@@ -313,7 +313,9 @@ mod tests {
         };
 
         let (before_cursor_pos, before) = extract_offset(ra_fixture_before);
-        let file = SourceFile::parse(&before, span::Edition::CURRENT).ok().unwrap();
+        let file = SourceFile::parse(&before, span::Edition::CURRENT)
+            .ok()
+            .unwrap();
 
         let range = TextRange::empty(before_cursor_pos);
         let result = join_lines(&config, &file, range);
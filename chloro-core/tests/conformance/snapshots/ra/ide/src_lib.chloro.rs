@@ -167,7 +167,9 @@ pub struct AnalysisHost {
 
 impl AnalysisHost {
     pub fn new(lru_capacity: Option<u16>) -> AnalysisHost {
-        AnalysisHost { db: RootDatabase::new(lru_capacity) }
+        AnalysisHost {
+            db: RootDatabase::new(lru_capacity),
+        }
     }
 
     pub fn with_database(db: RootDatabase) -> AnalysisHost {
@@ -185,7 +187,9 @@ impl AnalysisHost {
     /// Returns a snapshot of the current state, which you can query for
     /// semantic information.
     pub fn analysis(&self) -> Analysis {
-        Analysis { db: self.db.clone() }
+        Analysis {
+            db: self.db.clone(),
+        }
     }
 
     /// Applies changes to the current state of the world. If there are
@@ -227,6 +231,12 @@ pub struct Analysis {
     db: RootDatabase,
 }
 
+// As a general design guideline, `Analysis` API are intended to be independent
+// from the language server protocol. That is, when exposing some functionality
+// we should think in terms of "what API makes most sense" and not in terms of
+// "what types LSP uses". Although currently LSP is the only consumer of the
+// API, the API should in theory be usable as a library, or via a different
+// protocol.
 impl Analysis {
     // Creates an analysis instance for a single file, without any external
     // dependencies, stdlib support or ability to apply changes. See
@@ -293,7 +303,11 @@ impl Analysis {
     ) -> Option<(Analysis, RaFixtureAnalysis)> {
         let analysis =
             RaFixtureAnalysis::analyze_ra_fixture(sema, literal, expanded, minicore, on_cursor)?;
-        Some((Analysis { db: analysis.db.clone() }, analysis))
+        Some(
+            (Analysis {
+                db: analysis.db.clone(),
+            }, analysis),
+        )
     }
 
     /// Debug info about the current state of the analysis.
@@ -762,11 +776,12 @@ impl Analysis {
         position: FilePosition,
         imports: impl IntoIterator<Item = String> + std::panic::UnwindSafe,
     ) -> Cancellable<Vec<TextEdit>> {
-        Ok(self
-        .with_db(
-            |db| ide_completion::resolve_completion_edits(db, config, position, imports),
-        )?
-        .unwrap_or_default())
+        Ok(
+            self.with_db(
+                |db| ide_completion::resolve_completion_edits(db, config, position, imports),
+            )?
+            .unwrap_or_default(),
+        )
     }
 
     /// Computes the set of parser level diagnostics for the given file.
@@ -27,6 +27,7 @@ pub enum MonikerDescriptorKind {
     Meta,
 }
 
+// Subset of scip_types::SymbolInformation::Kind
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum SymbolInformationKind {
     AssociatedType,
@@ -113,9 +114,7 @@ pub enum MonikerResult {
     Moniker(Moniker),
     /// Specifies that the definition is a local, and so does not have a unique identifier. Provides
     /// a unique identifier for the container.
-    Local {
-        enclosing_moniker: Option<Moniker>,
-    },
+    Local { enclosing_moniker: Option<Moniker> },
 }
 
 impl MonikerResult {
@@ -169,9 +168,12 @@ pub(crate) fn moniker(
         .descend_into_macros_exact(original_token.clone())
         .into_iter()
         .filter_map(|token| {
-            IdentClass::classify_token(sema, &token).map(IdentClass::definitions_no_ops).map(|it| {
-                it.into_iter().flat_map(|def| def_to_moniker(sema.db, def, current_crate))
-            })
+            IdentClass::classify_token(sema, &token)
+                .map(IdentClass::definitions_no_ops)
+                .map(|it| {
+                    it.into_iter()
+                        .flat_map(|def| def_to_moniker(sema.db, def, current_crate))
+                })
         })
         .flatten()
         .unique()
@@ -389,7 +391,11 @@ fn def_to_non_local_moniker(
                     }),
                 ),
             };
-            PackageInformation { name: name.as_str().to_owned(), repo, version }
+            PackageInformation {
+                name: name.as_str().to_owned(),
+                repo,
+                version,
+            }
         },
     })
 }
@@ -399,7 +405,9 @@ fn display<'db, T: HirDisplay<'db>>(db: &'db RootDatabase, module: hir::Module,
         Ok(result) => result,
         // Fallback on display variant that always succeeds
         Err(_) => {
-            let fallback_result = it.display(db, module.krate().to_display_target(db)).to_string();
+            let fallback_result = it
+                .display(db, module.krate().to_display_target(db))
+                .to_string();
             tracing::error!(
                 display = %fallback_result, "`display_source_code` failed; falling back to using display"
             );
@@ -428,7 +436,11 @@ mod tests {
         kind: MonikerKind,
     ) {
         let (analysis, position) = fixture::position(ra_fixture);
-        let x = analysis.moniker(position).unwrap().expect("no moniker found").info;
+        let x = analysis
+            .moniker(position)
+            .unwrap()
+            .expect("no moniker found")
+            .info;
         assert_eq!(x.len(), 1);
         match x.into_iter().next().unwrap() {
             MonikerResult::Local { enclosing_moniker: Some(x) } => {
@@ -452,7 +464,11 @@ mod tests {
         kind: MonikerKind,
     ) {
         let (analysis, position) = fixture::position(ra_fixture);
-        let x = analysis.moniker(position).unwrap().expect("no moniker found").info;
+        let x = analysis
+            .moniker(position)
+            .unwrap()
+            .expect("no moniker found")
+            .info;
         assert_eq!(x.len(), 1);
         match x.into_iter().next().unwrap() {
             MonikerResult::Local { enclosing_moniker } => {
@@ -185,7 +185,10 @@ mod tests {
         direction: Direction,
     ) {
         let (analysis, range) = fixture::range(ra_fixture);
-        let edit = analysis.move_item(range, direction).unwrap().unwrap_or_default();
+        let edit = analysis
+            .move_item(range, direction)
+            .unwrap()
+            .unwrap_or_default();
         let mut file = analysis.file_text(range.file_id).unwrap().to_string();
         edit.apply(&mut file);
         expect.assert_eq(&file);
@@ -67,7 +67,8 @@ impl fmt::Debug for NavigationTarget {
                 }
             )*}
         }
-        f.field("file_id", &self.file_id).field("full_range", &self.full_range);
+        f.field("file_id", &self.file_id)
+            .field("full_range", &self.full_range);
         opt!(focus_range);
         f.field("name", &self.name);
         opt!(kind container_name description docs);
@@ -85,30 +86,22 @@ impl UpmapFromRaFixture for NavigationTarget {
         let virtual_file_id = self.file_id;
         Ok(NavigationTarget {
             file_id: real_file_id,
-            full_range: self.full_range.upmap_from_ra_fixture(
-                analysis,
-                virtual_file_id,
-                real_file_id,
-            )?,
-            focus_range: self.focus_range.upmap_from_ra_fixture(
-                analysis,
-                virtual_file_id,
-                real_file_id,
-            )?,
-            name: self.name.upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
-            kind: self.kind.upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
-            container_name: self.container_name.upmap_from_ra_fixture(
-                analysis,
-                virtual_file_id,
-                real_file_id,
-            )?,
-            description: self.description.upmap_from_ra_fixture(
-                analysis,
-                virtual_file_id,
-                real_file_id,
-            )?,
-            docs: self.docs.upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
-            alias: self.alias.upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            full_range: self.full_range
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            focus_range: self.focus_range
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            name: self.name
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            kind: self.kind
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            container_name: self.container_name
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            description: self.description
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            docs: self.docs
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
+            alias: self.alias
+                .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)?,
         })
     }
 }
@@ -145,7 +138,10 @@ impl NavigationTarget {
         db: &RootDatabase,
         module: hir::Module,
     ) -> UpmappingResult<NavigationTarget> {
-        let name = module.name(db).map(|it| it.symbol().clone()).unwrap_or_else(|| sym::underscore);
+        let name = module
+            .name(db)
+            .map(|it| it.symbol().clone())
+            .unwrap_or_else(|| sym::underscore);
         match module.declaration_source(db) {
             Some(InFile { value, file_id }) => {
                 orig_range_with_focus(db, file_id, value.syntax(), value.name()).map(
@@ -241,8 +237,7 @@ impl TryToNav for FileSymbol {
             .map(|(FileRange { file_id, range: full_range }, focus_range)| {
                 NavigationTarget {
                     file_id,
-                    name: self
-                        .is_alias
+                    name: self.is_alias
                         .then(|| self.def.name(db))
                         .flatten()
                         .map_or_else(|| self.name.clone(), |it| it.symbol().clone()),
@@ -460,7 +455,10 @@ impl ToNav for hir::Module {
     fn to_nav(&self, db: &RootDatabase) -> UpmappingResult<NavigationTarget> {
         let InFile { file_id, value } = self.definition_source(db);
 
-        let name = self.name(db).map(|it| it.symbol().clone()).unwrap_or_else(|| sym::underscore);
+        let name = self
+            .name(db)
+            .map(|it| it.symbol().clone())
+            .unwrap_or_else(|| sym::underscore);
         let (syntax, focus) = match &value {
             ModuleSource::SourceFile(node) => (node.syntax(), None),
             ModuleSource::Module(node) => (node.syntax(), node.name()),
@@ -501,17 +499,19 @@ impl TryToNav for hir::Impl {
             None => (file_id, value.self_ty(), value.syntax()),
         };
 
-        Some(orig_range_with_focus(db, file_id, syntax, focus).map(
-            |(FileRange { file_id, range: full_range }, focus_range)| {
-                NavigationTarget::from_syntax(
-                    file_id,
-                    sym::kw_impl,
-                    focus_range,
-                    full_range,
-                    SymbolKind::Impl,
-                )
-            },
-        ))
+        Some(
+            orig_range_with_focus(db, file_id, syntax, focus).map(
+                |(FileRange { file_id, range: full_range }, focus_range)| {
+                    NavigationTarget::from_syntax(
+                        file_id,
+                        sym::kw_impl,
+                        focus_range,
+                        full_range,
+                        SymbolKind::Impl,
+                    )
+                },
+            ),
+        )
     }
 }
 
@@ -528,9 +528,10 @@ impl TryToNav for hir::ExternCrateDecl {
             .map_or_else(|| value.name_ref().map(Either::Left), |it| it.name().map(Either::Right));
         let krate = self.module(db).krate();
 
-        Some(orig_range_with_focus(db, file_id, value.syntax(), focus).map(
-            |(FileRange { file_id, range: full_range }, focus_range)| {
-                let mut res = NavigationTarget::from_syntax(
+        Some(
+            orig_range_with_focus(db, file_id, value.syntax(), focus).map(
+                |(FileRange { file_id, range: full_range }, focus_range)| {
+                    let mut res = NavigationTarget::from_syntax(
                     file_id,
                     self.alias_or_name(db).unwrap_or_else(|| self.name(db)).symbol().clone(),
                     focus_range,
@@ -538,12 +539,13 @@ impl TryToNav for hir::ExternCrateDecl {
                     SymbolKind::Module,
                 );
 
-                res.docs = self.docs(db);
-                res.description = Some(self.display(db, krate.to_display_target(db)).to_string());
-                res.container_name = container_name(db, *self);
-                res
-            },
-        ))
+                    res.docs = self.docs(db);
+                    res.description = Some(self.display(db, krate.to_display_target(db)).to_string());
+                    res.container_name = container_name(db, *self);
+                    res
+                },
+            ),
+        )
     }
 }
 
@@ -699,8 +701,9 @@ impl TryToNav for hir::Label {
         let InFile { file_id, value } = self.source(db)?;
         let name = self.name(db).symbol().clone();
 
-        Some(orig_range_with_focus(db, file_id, value.syntax(), value.lifetime()).map(
-            |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
+        Some(
+            orig_range_with_focus(db, file_id, value.syntax(), value.lifetime()).map(
+                |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
                 file_id,
                 name: name.clone(),
                 alias: None,
@@ -711,7 +714,8 @@ impl TryToNav for hir::Label {
                 description: None,
                 docs: None,
             },
-        ))
+            ),
+        )
     }
 }
 
@@ -739,8 +743,9 @@ impl TryToNav for hir::TypeParam {
         };
         let focus = value.as_ref().either(|it| it.name(), |it| it.name());
 
-        Some(orig_range_with_focus(db, file_id, syntax, focus).map(
-            |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
+        Some(
+            orig_range_with_focus(db, file_id, syntax, focus).map(
+                |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
                 file_id,
                 name: name.clone(),
                 alias: None,
@@ -751,7 +756,8 @@ impl TryToNav for hir::TypeParam {
                 description: None,
                 docs: None,
             },
-        ))
+            ),
+        )
     }
 }
 
@@ -773,8 +779,9 @@ impl TryToNav for hir::LifetimeParam {
         let InFile { file_id, value } = self.source(db)?;
         let name = self.name(db).symbol().clone();
 
-        Some(orig_range(db, file_id, value.syntax()).map(
-            |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
+        Some(
+            orig_range(db, file_id, value.syntax()).map(
+                |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
                 file_id,
                 name: name.clone(),
                 alias: None,
@@ -785,7 +792,8 @@ impl TryToNav for hir::LifetimeParam {
                 description: None,
                 docs: None,
             },
-        ))
+            ),
+        )
     }
 }
 
@@ -806,8 +814,9 @@ impl TryToNav for hir::ConstParam {
             }
         };
 
-        Some(orig_range_with_focus(db, file_id, value.syntax(), value.name()).map(
-            |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
+        Some(
+            orig_range_with_focus(db, file_id, value.syntax(), value.name()).map(
+                |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
                 file_id,
                 name: name.clone(),
                 alias: None,
@@ -818,7 +827,8 @@ impl TryToNav for hir::ConstParam {
                 description: None,
                 docs: None,
             },
-        ))
+            ),
+        )
     }
 }
 
@@ -830,8 +840,9 @@ impl TryToNav for hir::InlineAsmOperand {
         let db = sema.db;
         let InFile { file_id, value } = &self.source(db)?;
         let file_id = *file_id;
-        Some(orig_range_with_focus(db, file_id, value.syntax(), value.name()).map(
-            |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
+        Some(
+            orig_range_with_focus(db, file_id, value.syntax(), value.name()).map(
+                |(FileRange { file_id, range: full_range }, focus_range)| NavigationTarget {
                 file_id,
                 name:
                     self.name(db).map_or_else(|| sym::underscore.clone(), |it| it.symbol().clone()),
@@ -843,7 +854,8 @@ impl TryToNav for hir::InlineAsmOperand {
                 description: None,
                 docs: None,
             },
-        ))
+            ),
+        )
     }
 }
 
@@ -1017,26 +1029,23 @@ pub(crate) fn orig_range_with_focus_r(
         call_site: (
             call_site_range.into_file_id(db),
             call_site_focus.and_then(|hir::FileRange { file_id, range }| {
-                if call_site_range.file_id == file_id && call_site_range.range.contains_range(range)
-                {
-                    Some(range)
-                } else {
-                    None
-                }
-            }),
+            if call_site_range.file_id == file_id && call_site_range.range.contains_range(range) {
+                Some(range)
+            } else {
+                None
+            }
+        }),
         ),
         def_site: def_site.map(|(def_site_range, def_site_focus)| {
             (
                 def_site_range.into_file_id(db),
                 def_site_focus.and_then(|hir::FileRange { file_id, range }| {
-                    if def_site_range.file_id == file_id
-                        && def_site_range.range.contains_range(range)
-                    {
-                        Some(range)
-                    } else {
-                        None
-                    }
-                }),
+                if def_site_range.file_id == file_id && def_site_range.range.contains_range(range) {
+                    Some(range)
+                } else {
+                    None
+                }
+            }),
             )
         }),
     }
@@ -1049,7 +1058,9 @@ fn orig_range(
 ) -> UpmappingResult<(FileRange, Option<TextRange>)> {
     UpmappingResult {
         call_site: (
-            InFile::new(hir_file, value).original_file_range_rooted(db).into_file_id(db),
+            InFile::new(hir_file, value)
+            .original_file_range_rooted(db)
+            .into_file_id(db),
             None,
         ),
         def_site: None,
@@ -1063,7 +1074,10 @@ fn orig_range_r(
 ) -> UpmappingResult<(FileRange, Option<TextRange>)> {
     UpmappingResult {
         call_site: (
-            InFile::new(hir_file, value).original_node_file_range(db).0.into_file_id(db),
+            InFile::new(hir_file, value)
+            .original_node_file_range(db)
+            .0
+            .into_file_id(db),
             None,
         ),
         def_site: None,
@@ -1083,7 +1097,9 @@ fn foo() { enum FooInner { } }
 "#,
         );
 
-        let navs = analysis.symbol_search(Query::new("FooInner".to_owned()), !0).unwrap();
+        let navs = analysis
+            .symbol_search(Query::new("FooInner".to_owned()), !0)
+            .unwrap();
         expect![[r#"
             [
                 NavigationTarget {
@@ -1120,7 +1136,9 @@ struct Foo;
 "#,
         );
 
-        let navs = analysis.symbol_search(Query::new("foo".to_owned()), !0).unwrap();
+        let navs = analysis
+            .symbol_search(Query::new("foo".to_owned()), !0)
+            .unwrap();
         assert_eq!(navs.len(), 2)
     }
     #[test]
@@ -1134,9 +1152,13 @@ static __FOO_CALLSITE: () = ();
         );
 
         // It doesn't show the hidden symbol
-        let navs = analysis.symbol_search(Query::new("foo".to_owned()), !0).unwrap();
+        let navs = analysis
+            .symbol_search(Query::new("foo".to_owned()), !0)
+            .unwrap();
         assert_eq!(navs.len(), 2);
-        let navs = analysis.symbol_search(Query::new("_foo".to_owned()), !0).unwrap();
+        let navs = analysis
+            .symbol_search(Query::new("_foo".to_owned()), !0)
+            .unwrap();
         assert_eq!(navs.len(), 0);
 
         // Unless we explicitly search for a `__` prefix
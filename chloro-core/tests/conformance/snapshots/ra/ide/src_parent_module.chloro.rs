@@ -39,13 +39,11 @@ pub(crate) fn parent_module(db: &RootDatabase, position: FilePosition) -> Vec<Na
     }
 
     match module {
-        Some(module) => sema
-            .to_def(&module)
+        Some(module) => sema.to_def(&module)
             .into_iter()
             .flat_map(|module| NavigationTarget::from_module_to_decl(db, module))
             .collect(),
-        None => sema
-            .file_to_module_defs(position.file_id)
+        None => sema.file_to_module_defs(position.file_id)
             .flat_map(|module| NavigationTarget::from_module_to_decl(db, module))
             .collect(),
     }
@@ -57,7 +55,10 @@ pub(crate) fn crates_for(db: &RootDatabase, file_id: FileId) -> Vec<Crate> {
         .iter()
         .copied()
         .filter(|&crate_id| {
-            crate_def_map(db, crate_id).modules_for_file(db, file_id).next().is_some()
+            crate_def_map(db, crate_id)
+                .modules_for_file(db, file_id)
+                .next()
+                .is_some()
         })
         .sorted()
         .collect()
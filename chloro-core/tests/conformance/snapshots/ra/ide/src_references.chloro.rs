@@ -202,7 +202,12 @@ pub(crate) fn find_all_refs(
         }
         None => {
             let search = make_searcher(false);
-            Some(find_defs(sema, &syntax, position.offset)?.into_iter().map(search).collect())
+            Some(
+                find_defs(sema, &syntax, position.offset)?
+                    .into_iter()
+                    .map(search)
+                    .collect(),
+            )
         }
     }
 }
@@ -403,15 +408,19 @@ fn path_ends_with(path: Option<ast::Path>, name_ref: &ast::NameRef) -> bool {
 /// # Returns
 /// `true` if the name reference is used as part of a struct/variant literal expression.
 fn is_lit_name_ref(name_ref: &ast::NameRef) -> bool {
-    name_ref.syntax().ancestors().find_map(|ancestor| {
-        match_ast! {
+    name_ref
+        .syntax()
+        .ancestors()
+        .find_map(|ancestor| {
+            match_ast! {
             match ancestor {
                 ast::PathExpr(path_expr) => Some(path_ends_with(path_expr.path(), name_ref)),
                 ast::RecordExpr(record_expr) => Some(path_ends_with(record_expr.path(), name_ref)),
                 _ => None,
             }
         }
-    }).unwrap_or(false)
+        })
+        .unwrap_or(false)
 }
 
 fn handle_control_flow_keywords(
@@ -451,7 +460,10 @@ fn handle_control_flow_keywords(
     })
     .collect();
 
-    Some(ReferenceSearchResult { declaration: None, references })
+    Some(ReferenceSearchResult {
+        declaration: None,
+        references,
+    })
 }
 
 #[cfg(test)]
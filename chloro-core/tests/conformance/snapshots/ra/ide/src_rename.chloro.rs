@@ -209,7 +209,9 @@ pub(crate) fn will_rename_file(
     let sema = Semantics::new(db);
     let module = sema.file_to_module_def(file_id)?;
     let def = Definition::Module(module);
-    let mut change = def.rename(&sema, new_name_stem, RenameDefinition::Yes).ok()?;
+    let mut change = def
+        .rename(&sema, new_name_stem, RenameDefinition::Yes)
+        .ok()?;
     change.file_system_edits.clear();
     Some(change)
 }
@@ -368,11 +370,13 @@ fn find_definitions(
     match res {
         Ok(v) => {
             // remove duplicates, comparing `Definition`s
-            Ok(v.into_iter()
-                .unique_by(|&(.., def, _, _)| def)
-                .map(|(a, b, c, d, e)| (a.into_file_id(sema.db), b, c, d, e))
-                .collect::<Vec<_>>()
-                .into_iter())
+            Ok(
+                v.into_iter()
+                    .unique_by(|&(.., def, _, _)| def)
+                    .map(|(a, b, c, d, e)| (a.into_file_id(sema.db), b, c, d, e))
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            )
         }
         Err(e) => Err(e),
     }
@@ -625,7 +629,9 @@ fn transform_method_call_into_assoc_fn(
                 };
             }
 
-            let needs_comma = method_call.arg_list().is_some_and(|it| it.args().next().is_some());
+            let needs_comma = method_call
+                .arg_list()
+                .is_some_and(|it| it.args().next().is_some());
 
             let self_needs_parens = self_adjust != CallReceiverAdjust::None
                 && self_arg.precedence().needs_parentheses_in(ExprPrecedence::Prefix);
@@ -845,7 +851,10 @@ mod tests {
     #[track_caller]
     fn check_conflicts(new_name: &str, #[rust_analyzer::rust_fixture] ra_fixture: &str) {
         let (analysis, position, conflicts) = fixture::annotations(ra_fixture);
-        let source_change = analysis.rename(position, new_name, &TEST_CONFIG).unwrap().unwrap();
+        let source_change = analysis
+            .rename(position, new_name, &TEST_CONFIG)
+            .unwrap()
+            .unwrap();
         let expected_conflicts = conflicts
             .into_iter()
             .map(|(file_range, _)| (file_range.file_id, file_range.range))
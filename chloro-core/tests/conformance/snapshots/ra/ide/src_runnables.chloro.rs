@@ -58,19 +58,10 @@ impl fmt::Display for TestId {
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum RunnableKind {
-    TestMod {
-        path: String,
-    },
-    Test {
-        test_id: TestId,
-        attr: TestAttr,
-    },
-    Bench {
-        test_id: TestId,
-    },
-    DocTest {
-        test_id: TestId,
-    },
+    TestMod { path: String },
+    Test { test_id: TestId, attr: TestAttr },
+    Bench { test_id: TestId },
+    DocTest { test_id: TestId },
     Bin,
 }
 
@@ -268,8 +259,8 @@ fn find_related_tests(
                 FileReferenceNode::NameRef(name_ref) => name_ref,
                 _ => continue,
             };
-            if let Some(fn_def) =
-                sema.ancestors_with_macros(name_ref.syntax().clone()).find_map(ast::Fn::cast) {
+            if let Some(fn_def) = sema.ancestors_with_macros(name_ref.syntax().clone())
+                .find_map(ast::Fn::cast) {
                 if let Some(runnable) = as_test_runnable(sema, &fn_def) {
                     // direct test
                     tests.insert(runnable);
@@ -366,11 +357,19 @@ pub(crate) fn runnable_fn(
     )
     .call_site();
 
-    let file_range = fn_source.syntax().original_file_range_with_macro_call_input(sema.db);
+    let file_range = fn_source
+        .syntax()
+        .original_file_range_with_macro_call_input(sema.db);
     let update_test = UpdateTest::find_snapshot_macro(sema, file_range);
 
     let cfg = def.attrs(sema.db).cfg();
-    Some(Runnable { use_name_in_title: false, nav, kind, cfg, update_test })
+    Some(Runnable {
+        use_name_in_title: false,
+        nav,
+        kind,
+        cfg,
+        update_test,
+    })
 }
 
 pub(crate) fn runnable_mod(
@@ -387,7 +386,9 @@ pub(crate) fn runnable_mod(
         .rev()
         .filter_map(|module| {
             module.name(sema.db).map(|mod_name| {
-                mod_name.display(sema.db, module.krate().edition(sema.db)).to_string()
+                mod_name
+                    .display(sema.db, module.krate().edition(sema.db))
+                    .to_string()
             })
         })
         .join("::");
@@ -454,7 +455,9 @@ pub(crate) fn runnable_impl(
 }
 
 fn has_cfg_test(attrs: AttrsWithOwner) -> bool {
-    attrs.cfgs().any(|cfg| matches!(&cfg, CfgExpr::Atom(CfgAtom::Flag(s)) if *s == sym::test))
+    attrs
+        .cfgs()
+        .any(|cfg| matches!(&cfg, CfgExpr::Atom(CfgAtom::Flag(s)) if *s == sym::test))
 }
 
 /// Creates a test mod runnable for outline modules at the top of their definition.
@@ -474,7 +477,9 @@ fn runnable_mod_outline_definition(
         .rev()
         .filter_map(|module| {
             module.name(sema.db).map(|mod_name| {
-                mod_name.display(sema.db, module.krate().edition(sema.db)).to_string()
+                mod_name
+                    .display(sema.db, module.krate().edition(sema.db))
+                    .to_string()
             })
         })
         .join("::");
@@ -578,14 +583,15 @@ pub struct TestAttr {
 
 impl TestAttr {
     fn from_fn(db: &dyn HirDatabase, fn_def: hir::Function) -> TestAttr {
-        TestAttr { ignore: fn_def.is_ignore(db) }
+        TestAttr {
+            ignore: fn_def.is_ignore(db),
+        }
     }
 }
 
 fn has_runnable_doc_test(attrs: &hir::Attrs) -> bool {
     const RUSTDOC_FENCES: [&str; 2] = ["```", "~~~"];
-    const RUSTDOC_CODE_BLOCK_ATTRIBUTES_RUNNABLE: &[&str] =
-        &["", "rust", "should_panic", "edition2015", "edition2018", "edition2021"];
+    const RUSTDOC_CODE_BLOCK_ATTRIBUTES_RUNNABLE: &[&str] = &["", "rust", "should_panic", "edition2015", "edition2018", "edition2021"];
 
     docs_from_attrs(attrs).is_some_and(|doc| {
         let mut in_code_block = false;
@@ -794,7 +800,10 @@ mod tests {
     fn check_tests(#[rust_analyzer::rust_fixture] ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
         let tests = analysis.related_tests(position, None).unwrap();
-        let navigation_targets = tests.into_iter().map(|runnable| runnable.nav).collect::<Vec<_>>();
+        let navigation_targets = tests
+            .into_iter()
+            .map(|runnable| runnable.nav)
+            .collect::<Vec<_>>();
         expect.assert_debug_eq(&navigation_targets);
     }
     #[test]
@@ -447,7 +447,10 @@ fn signature_help_for_record_lit(
 ) -> Option<SignatureHelp> {
     signature_help_for_record_(
         sema,
-        record.record_expr_field_list()?.syntax().children_with_tokens(),
+        record
+            .record_expr_field_list()?
+            .syntax()
+            .children_with_tokens(),
         &record.path()?,
         record
             .record_expr_field_list()?
@@ -469,7 +472,10 @@ fn signature_help_for_record_pat(
 ) -> Option<SignatureHelp> {
     signature_help_for_record_(
         sema,
-        record.record_pat_field_list()?.syntax().children_with_tokens(),
+        record
+            .record_pat_field_list()?
+            .syntax()
+            .children_with_tokens(),
         &record.path()?,
         record
             .record_pat_field_list()?
@@ -551,11 +557,11 @@ fn signature_help_for_tuple_pat(
     Some(signature_help_for_tuple_pat_ish(
         db,
         SignatureHelp {
-        doc: None,
-        signature: String::from('('),
-        parameters: vec![],
-        active_parameter: None,
-    },
+            doc: None,
+            signature: String::from('('),
+            parameters: vec![],
+            active_parameter: None,
+        },
         pat.syntax(),
         token,
         field_pats,
@@ -71,14 +71,10 @@ mod tests {
         let mut local_roots = FxHashSet::default();
         local_roots.insert(test_fixture::WORKSPACE);
         LocalRoots::get(&db).set_roots(&mut db).to(local_roots);
-        ssr_assists(
-            &db,
-            &resolve,
-            FileRange {
+        ssr_assists(&db, &resolve, FileRange {
             file_id: file_id.file_id(&db),
             range: range_or_offset.into(),
-        },
-        )
+        })
     }
     #[test]
     fn not_applicable_comment_not_ssr() {
@@ -80,7 +80,10 @@ impl TokenStore {
     }
 
     pub fn iter(self) -> impl Iterator<Item = (TokenId, TokenStaticData)> {
-        self.0.into_iter().enumerate().map(|(id, data)| (TokenId(id), data))
+        self.0
+            .into_iter()
+            .enumerate()
+            .map(|(id, data)| (TokenId(id), data))
     }
 }
 
@@ -143,9 +146,7 @@ fn get_definitions(
 }
 
 pub enum VendoredLibrariesConfig<'a> {
-    Included {
-        workspace_root: &'a VfsPath,
-    },
+    Included { workspace_root: &'a VfsPath },
     Excluded,
 }
 
@@ -297,9 +298,7 @@ impl StaticIndex<'_> {
         vendored_libs_config: VendoredLibrariesConfig<'_>,
     ) -> StaticIndex<'a> {
         let db = &analysis.db;
-        hir::attach_db(
-            db,
-            || {
+        hir::attach_db(db, || {
             let work = all_modules(db).into_iter().filter(|module| {
                 let file_id = module.definition_source_file_id(db).original_file(db);
                 let source_root =
@@ -332,8 +331,7 @@ impl StaticIndex<'_> {
                 visited_files.insert(file_id);
             }
             this
-        },
-        )
+        })
     }
 }
 
@@ -44,7 +44,11 @@ pub(super) fn highlight_escape_char(stack: &mut Highlights, char: &Char) {
         char.syntax().text_range().start() + TextSize::from(1),
         TextSize::from(text.len() as u32),
     );
-    stack.add(HlRange { range, highlight: HlTag::EscapeSequence.into(), binding_hash: None })
+    stack.add(HlRange {
+        range,
+        highlight: HlTag::EscapeSequence.into(),
+        binding_hash: None,
+    })
 }
 
 pub(super) fn highlight_escape_byte(stack: &mut Highlights, byte: &Byte) {
@@ -66,5 +70,9 @@ pub(super) fn highlight_escape_byte(stack: &mut Highlights, byte: &Byte) {
         byte.syntax().text_range().start() + TextSize::from(2),
         TextSize::from(text.len() as u32),
     );
-    stack.add(HlRange { range, highlight: HlTag::EscapeSequence.into(), binding_hash: None })
+    stack.add(HlRange {
+        range,
+        highlight: HlTag::EscapeSequence.into(),
+        binding_hash: None,
+    })
 }
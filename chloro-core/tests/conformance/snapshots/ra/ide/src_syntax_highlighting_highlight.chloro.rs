@@ -172,9 +172,9 @@ fn punctuation(
         }
         (_, ATTR) => HlTag::AttributeBracket.into(),
         (T![>], _) if operator_parent
-                .as_ref()
-                .and_then(SyntaxNode::parent)
-                .is_some_and(|it| it.kind() == MACRO_RULES) => {
+            .as_ref()
+            .and_then(SyntaxNode::parent)
+            .is_some_and(|it| it.kind() == MACRO_RULES) => {
             HlOperator::Other.into()
         }
         (kind, _) => match kind {
@@ -686,7 +686,10 @@ fn highlight_method_call_by_name_ref(
     name_ref: &ast::NameRef,
     is_unsafe_node: &impl Fn(AstPtr<Either<ast::Expr, ast::Pat>>) -> bool,
 ) -> Option<Highlight> {
-    let mc = name_ref.syntax().parent().and_then(ast::MethodCallExpr::cast)?;
+    let mc = name_ref
+        .syntax()
+        .parent()
+        .and_then(ast::MethodCallExpr::cast)?;
     highlight_method_call(sema, krate, &mc, is_unsafe_node)
 }
 
@@ -846,7 +849,11 @@ fn highlight_name_ref_by_syntax(
 
             match parent.kind() {
                 CALL_EXPR => SymbolKind::Function.into(),
-                _ => if name.text().chars().next().unwrap_or_default().is_uppercase() {
+                _ => if name.text()
+                    .chars()
+                    .next()
+                    .unwrap_or_default()
+                    .is_uppercase() {
                     SymbolKind::Struct
                 } else {
                     SymbolKind::Const
@@ -862,8 +869,7 @@ fn highlight_name_ref_by_syntax(
 
 fn is_consumed_lvalue(node: &SyntaxNode, local: &hir::Local, db: &RootDatabase) -> bool {
     // When lvalues are passed as arguments and they're not Copy, then mark them as Consuming.
-    parents_match(node.clone().into(), &[PATH_SEGMENT, PATH, PATH_EXPR, ARG_LIST])
-        && !local.ty(db).is_copy(db)
+    parents_match(node.clone().into(), &[PATH_SEGMENT, PATH, PATH_EXPR, ARG_LIST]) && !local.ty(db).is_copy(db)
 }
 
 /// Returns true if the parent nodes of `node` all match the `SyntaxKind`s in `kinds` exactly.
@@ -19,7 +19,11 @@ struct Node {
 impl Highlights {
     pub(super) fn new(range: TextRange) -> Highlights {
         Highlights {
-            root: Node::new(HlRange { range, highlight: HlTag::None.into(), binding_hash: None }),
+            root: Node::new(HlRange {
+                range,
+                highlight: HlTag::None.into(),
+                binding_hash: None,
+            }),
         }
     }
 
@@ -36,7 +40,10 @@ impl Highlights {
 
 impl Node {
     fn new(hl_range: HlRange) -> Node {
-        Node { hl_range, nested: Vec::new() }
+        Node {
+            hl_range,
+            nested: Vec::new(),
+        }
     }
 
     fn add(&mut self, hl_range: HlRange) {
@@ -62,17 +62,17 @@ pub(crate) fn highlight_as_html(db: &RootDatabase, file_id: FileId, rainbow: boo
     highlight_as_html_with_config(
         db,
         &HighlightConfig {
-        strings: true,
-        comments: true,
-        punctuation: true,
-        specialize_punctuation: true,
-        specialize_operator: true,
-        operator: true,
-        inject_doc_comment: true,
-        macro_bang: true,
-        syntactic_name_ref_highlighting: false,
-        minicore: MiniCore::default(),
-    },
+            strings: true,
+            comments: true,
+            punctuation: true,
+            specialize_punctuation: true,
+            specialize_operator: true,
+            operator: true,
+            inject_doc_comment: true,
+            macro_bang: true,
+            syntactic_name_ref_highlighting: false,
+            minicore: MiniCore::default(),
+        },
         file_id,
         rainbow,
     )
@@ -49,8 +49,10 @@ fn find_crate_by_id(db: &RootDatabase, crate_id: &str) -> Option<base_db::Crate>
     // here, we use display_name as the crate id. This is not super ideal, but it works since we
     // only show tests for the local crates.
     db.all_crates().iter().copied().find(|&id| {
-        id.data(db).origin.is_local()
-            && id.extra_data(db).display_name.as_ref().is_some_and(|x| x.to_string() == crate_id)
+        id.data(db).origin.is_local() && id.extra_data(db)
+            .display_name
+            .as_ref()
+            .is_some_and(|x| x.to_string() == crate_id)
     })
 }
 
@@ -153,7 +155,10 @@ fn find_module_id_and_test_parents(
     let parent = Some(id.clone());
     id += "::";
     let module_name = &module.name(sema.db);
-    let module_name = module_name.as_ref().map(|n| n.as_str()).unwrap_or("[mod without name]");
+    let module_name = module_name
+        .as_ref()
+        .map(|n| n.as_str())
+        .unwrap_or("[mod without name]");
     id += module_name;
     let nav = NavigationTarget::from_module_to_decl(sema.db, module).call_site;
     r.push(TestItem {
@@ -33,6 +33,7 @@ use crate::SourceChange;
 
 pub(crate) use on_enter::on_enter;
 
+// Don't forget to add new trigger characters to `server_capabilities` in `caps.rs`.
 pub(crate) const TRIGGER_CHARS: &[char] = &['.', '=', '<', '>', '{', '(', '|', '+'];
 
 struct ExtendedTextEdit {
@@ -104,13 +105,14 @@ fn on_char_typed_(
         '+' => on_plus_typed(&file.tree(), offset),
         _ => None,
     }
-    .map(
-        conv,
-    )
+    .map(conv)
 }
 
 fn conv(edit: TextEdit) -> ExtendedTextEdit {
-    ExtendedTextEdit { edit, is_snippet: false }
+    ExtendedTextEdit {
+        edit,
+        is_snippet: false,
+    }
 }
 
 /// Inserts a closing delimiter when the user types an opening bracket, wrapping an existing expression in a
@@ -121,7 +123,10 @@ fn on_opening_delimiter_typed(
     opening_bracket: char,
     edition: Edition,
 ) -> Option<TextEdit> {
-    type FilterFn = fn(SyntaxKind) -> bool;
+    type FilterFn =
+        fn(
+            SyntaxKind,
+        ) -> bool;
     let (closing_bracket, expected_ast_bracket, allowed_kinds) = match opening_bracket {
         '{' => ('}', SyntaxKind::L_CURLY, &[ast::Expr::can_cast as FilterFn] as &[FilterFn]),
         '(' => (
@@ -382,9 +387,7 @@ fn on_left_angle_typed(
     if ancestors_at_offset(file.syntax(), offset)
         .take_while(|n| !ast::Item::can_cast(n.kind()))
         .any(|n| {
-            ast::GenericParamList::can_cast(n.kind())
-                || ast::GenericArgList::can_cast(n.kind())
-                || ast::UseBoundGenericArgs::can_cast(n.kind())
+            ast::GenericParamList::can_cast(n.kind()) || ast::GenericArgList::can_cast(n.kind()) || ast::UseBoundGenericArgs::can_cast(n.kind())
         }) {
         // Insert the closing bracket right after
         Some(TextEdit::insert(offset + TextSize::of('<'), '>'.to_string()))
@@ -649,12 +652,15 @@ fn main() {
 }
             "#,
         );
-        type_char_noop('.', r#"
+        type_char_noop(
+            '.',
+            r#"
 fn main() {
     xs.foo()
         $0
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn indents_new_chain_call_with_semi() {
@@ -673,12 +679,15 @@ fn main() {
 }
             "#,
         );
-        type_char_noop('.', r#"
+        type_char_noop(
+            '.',
+            r#"
 fn main() {
     xs.foo()
         $0;
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn indents_new_chain_call_with_let() {
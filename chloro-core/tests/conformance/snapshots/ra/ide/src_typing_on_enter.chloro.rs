@@ -54,7 +54,10 @@ pub(crate) fn on_enter(db: &RootDatabase, position: FilePosition) -> Option<Text
         ide_db::base_db::EditionedFileId::current_edition(db, position.file_id);
     let parse = db.parse(editioned_file_id_wrapper);
     let file = parse.tree();
-    let token = file.syntax().token_at_offset(position.offset).left_biased()?;
+    let token = file
+        .syntax()
+        .token_at_offset(position.offset)
+        .left_biased()?;
 
     if let Some(comment) = ast::Comment::cast(token.clone()) {
         return on_enter_in_comment(&comment, &file, position.offset);
@@ -129,7 +132,8 @@ fn on_enter_in_block(block: ast::BlockExpr, position: FilePosition) -> Option<Te
 
     let indent = IndentLevel::from_node(block.syntax());
     let mut edit = TextEdit::insert(position.offset, format!("\n{}$0", indent + 1));
-    edit.union(TextEdit::insert(contents.text_range().end(), format!("\n{indent}"))).ok()?;
+    edit.union(TextEdit::insert(contents.text_range().end(), format!("\n{indent}")))
+        .ok()?;
     Some(edit)
 }
 
@@ -168,7 +172,10 @@ fn followed_by_comment(comment: &ast::Comment) -> bool {
     if ws.spans_multiple_lines() {
         return false;
     }
-    ws.syntax().next_token().and_then(ast::Comment::cast).is_some()
+    ws.syntax()
+        .next_token()
+        .and_then(ast::Comment::cast)
+        .is_some()
 }
 
 fn node_indent(file: &SourceFile, token: &SyntaxToken) -> Option<SmolStr> {
@@ -26,7 +26,9 @@ pub(crate) fn view_crate_graph(db: &RootDatabase, full: bool) -> Result<String,
                 true
             } else {
                 // Only render workspace crates
-                let root_id = db.file_source_root(crate_data.root_file_id).source_root_id(db);
+                let root_id = db
+                    .file_source_root(crate_data.root_file_id)
+                    .source_root_id(db);
                 !db.source_root(root_id).source_root(db).is_library
             }
         })
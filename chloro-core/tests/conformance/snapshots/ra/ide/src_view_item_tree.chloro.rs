@@ -13,5 +13,6 @@ pub(crate) fn view_item_tree(db: &RootDatabase, file_id: FileId) -> String {
     let file_id = sema
         .attach_first_edition(file_id)
         .unwrap_or_else(|| EditionedFileId::current_edition(db, file_id));
-    db.file_item_tree(file_id.into()).pretty_print(db, file_id.edition(db))
+    db.file_item_tree(file_id.into())
+        .pretty_print(db, file_id.edition(db))
 }
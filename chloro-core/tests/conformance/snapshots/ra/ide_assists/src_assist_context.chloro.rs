@@ -155,7 +155,8 @@ impl<'a> AssistContext<'a> {
     }
 
     pub(crate) fn find_node_at_offset_with_descend<N: AstNode>(&self) -> Option<N> {
-        self.sema.find_node_at_offset_with_descend(self.source_file.syntax(), self.offset())
+        self.sema
+            .find_node_at_offset_with_descend(self.source_file.syntax(), self.offset())
     }
 
     /// Returns the element covered by the selection range, this excludes trailing whitespace in the selection.
@@ -233,7 +234,14 @@ impl Assists {
 
         let label = Label::new(label);
         let group = group.cloned();
-        self.buf.push(Assist { id, label, group, target, source_change, command });
+        self.buf.push(Assist {
+            id,
+            label,
+            group,
+            target,
+            source_change,
+            command,
+        });
         Some(())
     }
 
@@ -147,11 +147,7 @@ fn add_missing_impl_members_inner(
     }
 
     let target = impl_def.syntax().text_range();
-    acc.add(
-        AssistId::quick_fix(assist_id),
-        label,
-        target,
-        |edit| {
+    acc.add(AssistId::quick_fix(assist_id), label, target, |edit| {
         let new_item = add_trait_assoc_items_to_impl(
             &ctx.sema,
             ctx.config,
@@ -220,8 +216,7 @@ fn add_missing_impl_members_inner(
             };
         };
         edit.add_file_edits(ctx.vfs_file_id(), editor);
-    },
-    )
+    })
 }
 
 fn try_gen_trait_body(
@@ -79,7 +79,9 @@ pub(crate) fn add_missing_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>)
 
     let scope = ctx.sema.scope(expr.syntax())?;
     let module = scope.module();
-    let cfg = ctx.config.find_path_config(ctx.sema.is_nightly(scope.krate()));
+    let cfg = ctx
+        .config
+        .find_path_config(ctx.sema.is_nightly(scope.krate()));
     let self_ty = if ctx.config.prefer_self_ty {
         scope
             .containing_function()
@@ -228,15 +230,11 @@ pub(crate) fn add_missing_match_arms(acc: &mut Assists, ctx: &AssistContext<'_>)
                     !hidden
                 })
                 .map(|(pat, _)| {
-                    make.match_arm(
-                        pat,
-                        None,
-                        match ctx.config.expr_fill_default {
-                            ExprFillDefaultMode::Todo => make::ext::expr_todo(),
-                            ExprFillDefaultMode::Underscore => make::ext::expr_underscore(),
-                            ExprFillDefaultMode::Default => make::ext::expr_todo(),
-                        },
-                    )
+                    make.match_arm(pat, None, match ctx.config.expr_fill_default {
+                        ExprFillDefaultMode::Todo => make::ext::expr_todo(),
+                        ExprFillDefaultMode::Underscore => make::ext::expr_underscore(),
+                        ExprFillDefaultMode::Default => make::ext::expr_todo(),
+                    })
                 });
 
             let mut arms: Vec<_> = match_arm_list
@@ -356,7 +354,11 @@ fn cursor_at_trivial_match_arm_list(
     // match { _$0 => {...} }
     let wild_pat = ctx.find_node_at_offset_with_descend::<ast::WildcardPat>()?;
     let arm = wild_pat.syntax().parent().and_then(ast::MatchArm::cast)?;
-    let arm_match_expr = arm.syntax().ancestors().nth(2).and_then(ast::MatchExpr::cast)?;
+    let arm_match_expr = arm
+        .syntax()
+        .ancestors()
+        .nth(2)
+        .and_then(ast::MatchExpr::cast)?;
     if arm_match_expr == *match_expr {
         cov_mark::hit!(add_missing_match_arms_trivial_arm);
         return Some(());
@@ -366,7 +368,9 @@ fn cursor_at_trivial_match_arm_list(
 }
 
 fn is_variant_missing(existing_pats: &[Pat], var: &Pat) -> bool {
-    !existing_pats.iter().any(|pat| does_pat_match_variant(pat, var))
+    !existing_pats
+        .iter()
+        .any(|pat| does_pat_match_variant(pat, var))
 }
 
 // Fixme: this is still somewhat limited, use hir_ty::diagnostics::match_check?
@@ -374,10 +378,14 @@ fn does_pat_match_variant(pat: &Pat, var: &Pat) -> bool {
     match (pat, var) {
         (Pat::WildcardPat(_), _) => true,
         (Pat::SlicePat(spat), Pat::SlicePat(svar)) => {
-            spat.pats().zip(svar.pats()).all(|(p, v)| does_pat_match_variant(&p, &v))
+            spat.pats()
+                .zip(svar.pats())
+                .all(|(p, v)| does_pat_match_variant(&p, &v))
         }
         (Pat::TuplePat(tpat), Pat::TuplePat(tvar)) => {
-            tpat.fields().zip(tvar.fields()).all(|(p, v)| does_pat_match_variant(&p, &v))
+            tpat.fields()
+                .zip(tvar.fields())
+                .all(|(p, v)| does_pat_match_variant(&p, &v))
         }
         (Pat::OrPat(opat), _) => opat.pats().any(|p| does_pat_match_variant(&p, var)),
         _ => utils::does_pat_match_variant(pat, var),
@@ -387,10 +395,7 @@ fn does_pat_match_variant(pat: &Pat, var: &Pat) -> bool {
 #[derive(Eq, PartialEq, Clone)]
 enum ExtendedEnum {
     Bool,
-    Enum {
-        enum_: hir::Enum,
-        use_self: bool,
-    },
+    Enum { enum_: hir::Enum, use_self: bool },
 }
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
@@ -438,8 +443,7 @@ impl ExtendedEnum {
 
     fn variants(&self, db: &RootDatabase) -> Vec<ExtendedVariant> {
         match *self {
-            ExtendedEnum::Enum { enum_: e, use_self } => e
-                .variants(db)
+            ExtendedEnum::Enum { enum_: e, use_self } => e.variants(db)
                 .into_iter()
                 .map(|variant| ExtendedVariant::Variant { variant, use_self })
                 .collect::<Vec<_>>(),
@@ -455,7 +459,10 @@ fn resolve_enum_def(
     expr: &ast::Expr,
     self_ty: Option<&hir::Type<'_>>,
 ) -> Option<ExtendedEnum> {
-    sema.type_of_expr(expr)?.adjusted().autoderef(sema.db).find_map(|ty| match ty.as_adt() {
+    sema.type_of_expr(expr)?
+        .adjusted()
+        .autoderef(sema.db)
+        .find_map(|ty| match ty.as_adt() {
         Some(Adt::Enum(e)) => Some(ExtendedEnum::enum_(sema.db, e, &ty, self_ty)),
         _ => ty.is_bool().then_some(ExtendedEnum::Bool),
     })
@@ -490,12 +497,15 @@ fn resolve_array_of_enum_def(
     expr: &ast::Expr,
     self_ty: Option<&hir::Type<'_>>,
 ) -> Option<(ExtendedEnum, usize)> {
-    sema.type_of_expr(expr)?.adjusted().as_array(sema.db).and_then(|(ty, len)| {
-        ty.autoderef(sema.db).find_map(|ty| match ty.as_adt() {
+    sema.type_of_expr(expr)?
+        .adjusted()
+        .as_array(sema.db)
+        .and_then(|(ty, len)| {
+            ty.autoderef(sema.db).find_map(|ty| match ty.as_adt() {
             Some(Adt::Enum(e)) => Some((ExtendedEnum::enum_(sema.db, e, &ty, self_ty), len)),
             _ => ty.is_bool().then_some((ExtendedEnum::Bool, len)),
         })
-    })
+        })
 }
 
 fn build_pat(
@@ -18,7 +18,10 @@ use crate::{AssistContext, AssistId, Assists};
 pub(crate) fn add_return_type(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let (fn_type, tail_expr, builder_edit_pos) = extract_tail(ctx)?;
     let module = ctx.sema.scope(tail_expr.syntax())?.module();
-    let ty = ctx.sema.type_of_expr(&peel_blocks(tail_expr.clone()))?.adjusted();
+    let ty = ctx
+        .sema
+        .type_of_expr(&peel_blocks(tail_expr.clone()))?
+        .adjusted();
     if ty.is_unit() {
         return None;
     }
@@ -90,9 +93,7 @@ fn ret_ty_to_action(
 
 enum FnType {
     Function,
-    Closure {
-        wrap_expr: bool,
-    },
+    Closure { wrap_expr: bool },
 }
 
 /// If we're looking at a block that is supposed to return `()`, type inference
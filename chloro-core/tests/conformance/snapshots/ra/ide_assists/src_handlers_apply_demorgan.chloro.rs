@@ -260,7 +260,9 @@ fn validate_method_call_expr(
     let krate = module.krate();
 
     let iter_trait = FamousDefs(sema, krate).core_iter_Iterator()?;
-    it_type.impls_trait(sema.db, iter_trait, &[]).then_some((name_ref, arg_expr))
+    it_type
+        .impls_trait(sema.db, iter_trait, &[])
+        .then_some((name_ref, arg_expr))
 }
 
 fn tail_cb_impl(editor: &mut SyntaxEditor, make: &SyntaxFactory, e: &ast::Expr) {
@@ -109,12 +109,17 @@ pub(crate) fn auto_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
     proposed_imports.sort_by(|a, b| a.import_path.cmp(&b.import_path));
     proposed_imports.dedup_by(|a, b| a.import_path == b.import_path);
 
-    let current_module = ctx.sema.scope(scope.as_syntax_node()).map(|scope| scope.module());
+    let current_module = ctx
+        .sema
+        .scope(scope.as_syntax_node())
+        .map(|scope| scope.module());
     // prioritize more relevant imports
     proposed_imports.sort_by_key(|import| {
         Reverse(relevance_score(ctx, import, expected.as_ref(), current_module.as_ref()))
     });
-    let edition = current_module.map(|it| it.krate().edition(ctx.db())).unwrap_or(Edition::CURRENT);
+    let edition = current_module
+        .map(|it| it.krate().edition(ctx.db()))
+        .unwrap_or(Edition::CURRENT);
 
     let group_label = group_label(import_assets.import_candidate());
     for import in proposed_imports {
@@ -206,19 +211,15 @@ pub(super) fn find_importable_node<'a: 'db, 'db>(
         let expected =
             path_under_caret.top_path().syntax().parent().and_then(Either::cast).and_then(expected);
         ImportAssets::for_exact_path(&path_under_caret, &ctx.sema)
-        .map(
-            |it| (it, path_under_caret.syntax().clone(), expected),
-        )
+            .map(|it| (it, path_under_caret.syntax().clone(), expected))
     } else if let Some(method_under_caret) = ctx.find_node_at_offset_with_descend::<ast::MethodCallExpr>() {
         let expected = expected(Either::Left(method_under_caret.clone().into()));
         ImportAssets::for_method_call(&method_under_caret, &ctx.sema)
-        .map(
-            |it| (it, method_under_caret.syntax().clone(), expected),
-        )
-    } else if ctx.find_node_at_offset_with_descend::<ast::Param>().is_some() {
+            .map(|it| (it, method_under_caret.syntax().clone(), expected))
+    } else if ctx.find_node_at_offset_with_descend::<ast::Param>()
+        .is_some() {
         None
-    } else if let Some(pat) = ctx
-        .find_node_at_offset_with_descend::<ast::IdentPat>()
+    } else if let Some(pat) = ctx.find_node_at_offset_with_descend::<ast::IdentPat>()
         .filter(ast::IdentPat::is_simple_ident) {
         let expected = expected(Either::Right(pat.clone().into()));
         ImportAssets::for_ident_pat(&ctx.sema, &pat).map(|it| (it, pat.syntax().clone(), expected))
@@ -311,7 +312,11 @@ fn module_distance_heuristic(db: &dyn HirDatabase, current: &Module, item: &Modu
     item_path.reverse();
 
     // length of the common prefix of the two paths
-    let prefix_length = current_path.iter().zip(&item_path).take_while(|(a, b)| a == b).count();
+    let prefix_length = current_path
+        .iter()
+        .zip(&item_path)
+        .take_while(|(a, b)| a == b)
+        .count();
 
     // how many modules differ between the two paths (all modules, removing any duplicates)
     let distinct_length = current_path.len() + item_path.len() - 2 * prefix_length;
@@ -351,7 +356,10 @@ mod tests {
         auto_import(&mut acc, &ctx);
         let assists = acc.finish();
 
-        let labels = assists.iter().map(|assist| assist.label.to_string()).collect::<Vec<_>>();
+        let labels = assists
+            .iter()
+            .map(|assist| assist.label.to_string())
+            .collect::<Vec<_>>();
 
         assert_eq!(labels, order);
     }
@@ -88,8 +88,7 @@ fn add_vis(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
 }
 
 fn can_add(node: &SyntaxNode) -> bool {
-    const LEGAL: &[SyntaxKind] =
-        &[CONST, STATIC, TYPE_ALIAS, FN, MODULE, STRUCT, ENUM, TRAIT, USE, MACRO_DEF];
+    const LEGAL: &[SyntaxKind] = &[CONST, STATIC, TYPE_ALIAS, FN, MODULE, STRUCT, ENUM, TRAIT, USE, MACRO_DEF];
 
     LEGAL.contains(&node.kind()) && {
         let Some(p) = node.parent() else {
@@ -45,7 +45,9 @@ pub(crate) fn convert_if_to_bool_then(acc: &mut Assists, ctx: &AssistContext<'_>
         return None;
     }
 
-    let cond = expr.condition().filter(|cond| !is_pattern_cond(cond.clone()))?;
+    let cond = expr
+        .condition()
+        .filter(|cond| !is_pattern_cond(cond.clone()))?;
     let then = expr.then_branch()?;
     let else_ = match expr.else_branch()? {
         ast::ElseBranch::Block(b) => b,
@@ -161,7 +163,10 @@ pub(crate) fn convert_if_to_bool_then(acc: &mut Assists, ctx: &AssistContext<'_>
 // ```
 pub(crate) fn convert_bool_then_to_if(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let name_ref = ctx.find_node_at_offset::<ast::NameRef>()?;
-    let mcall = name_ref.syntax().parent().and_then(ast::MethodCallExpr::cast)?;
+    let mcall = name_ref
+        .syntax()
+        .parent()
+        .and_then(ast::MethodCallExpr::cast)?;
     let receiver = mcall.receiver()?;
     let closure_body = mcall.arg_list()?.args().exactly_one().ok()?;
     let closure_body = match closure_body {
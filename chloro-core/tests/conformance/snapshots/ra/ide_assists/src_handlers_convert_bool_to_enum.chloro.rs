@@ -53,7 +53,11 @@ use crate::{
 pub(crate) fn convert_bool_to_enum(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let BoolNodeData { target_node, name, ty_annotation, initializer, definition } =
         find_bool_node(ctx)?;
-    let target_module = ctx.sema.scope(&target_node)?.module().nearest_non_block_module(ctx.db());
+    let target_module = ctx
+        .sema
+        .scope(&target_node)?
+        .module()
+        .nearest_non_block_module(ctx.db());
 
     let target = name.syntax().text_range();
     acc.add(
@@ -101,7 +105,10 @@ fn find_bool_node(ctx: &AssistContext<'_>) -> Option<BoolNodeData> {
         }
 
         let local_definition = Definition::Local(def);
-        match ident_pat.syntax().parent().and_then(Either::<ast::Param, ast::LetStmt>::cast)? {
+        match ident_pat
+            .syntax()
+            .parent()
+            .and_then(Either::<ast::Param, ast::LetStmt>::cast)? {
             Either::Left(param) => Some(BoolNodeData {
                 target_node: param.syntax().clone(),
                 name,
@@ -334,7 +341,9 @@ fn augment_references_with_imports(
         .into_iter()
         .filter_map(|FileReference { range, name, .. }| {
             let name = name.into_name_like()?;
-            ctx.sema.scope(name.syntax()).map(|scope| (range, name, scope.module()))
+            ctx.sema
+                .scope(name.syntax())
+                .map(|scope| (range, name, scope.module()))
         })
         .map(|(range, name, ref_module)| {
             // if the referenced module is not the same as the target one and has not been seen before, add an import
@@ -368,7 +377,11 @@ fn augment_references_with_imports(
                 None
             };
 
-            FileReferenceWithImport { range, name, import_data }
+            FileReferenceWithImport {
+                range,
+                name,
+                import_data,
+            }
         })
         .collect()
 }
@@ -439,7 +452,10 @@ fn find_assoc_const_usage(name: &ast::NameLike) -> Option<(ast::Type, ast::Expr)
 }
 
 fn find_method_call_expr_usage(name: &ast::NameLike) -> Option<ast::Expr> {
-    let method_call = name.syntax().ancestors().find_map(ast::MethodCallExpr::cast)?;
+    let method_call = name
+        .syntax()
+        .ancestors()
+        .find_map(ast::MethodCallExpr::cast)?;
     let receiver = method_call.receiver()?;
 
     if !receiver.syntax().descendants().contains(name.syntax()) {
@@ -519,10 +535,10 @@ fn make_bool_enum(make_pub: bool) -> ast::Enum {
     make::enum_(
         [derive_eq],
         if make_pub {
-        Some(make::visibility_pub())
-    } else {
-        None
-    },
+            Some(make::visibility_pub())
+        } else {
+            None
+        },
         make::name("Bool"),
         None,
         None,
@@ -886,11 +902,14 @@ fn main() {
     #[test]
     fn local_variable_non_bool() {
         cov_mark::check!(not_applicable_non_bool_local);
-        check_assist_not_applicable(convert_bool_to_enum, r#"
+        check_assist_not_applicable(
+            convert_bool_to_enum,
+            r#"
 fn main() {
     let $0foo = 1;
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn local_variable_cursor_not_on_ident() {
@@ -366,7 +366,11 @@ fn compute_closure_type_params(
 
     let all_params = container_params
         .type_or_const_params()
-        .chain(containing_impl.iter().flat_map(|(param_list, _)| param_list.type_or_const_params()))
+        .chain(
+            containing_impl
+                .iter()
+                .flat_map(|(param_list, _)| param_list.type_or_const_params()),
+        )
         .filter_map(|param| Some(param.name()?.text().to_smolstr()))
         .collect::<FxHashSet<_>>();
 
@@ -432,24 +436,30 @@ fn compute_closure_type_params(
                 })
             })
         })
-        .chain(container_params.type_or_const_params().filter(|param| {
-            param
-                .name()
-                .is_some_and(|name| mentioned_names.contains(name.text().trim_start_matches("r#")))
-        }))
+        .chain(
+            container_params.type_or_const_params().filter(|param| {
+                param.name().is_some_and(
+                    |name| mentioned_names.contains(name.text().trim_start_matches("r#")),
+                )
+            }),
+        )
         .map(ast::TypeOrConstParam::into);
     let include_where_bounds = containing_impl
         .as_ref()
         .and_then(|(_, it)| it.as_ref())
         .into_iter()
         .flat_map(|where_| {
-            impl_where_bounds_indices.iter().filter_map(|&index| where_.predicates().nth(index))
-        })
-        .chain(container_where.iter().flat_map(|where_| {
-            container_where_bounds_indices
+            impl_where_bounds_indices
                 .iter()
                 .filter_map(|&index| where_.predicates().nth(index))
-        }))
+        })
+        .chain(
+            container_where.iter().flat_map(|where_| {
+                container_where_bounds_indices
+                    .iter()
+                    .filter_map(|&index| where_.predicates().nth(index))
+            }),
+        )
         .collect::<Vec<_>>();
     let where_clause =
         (!include_where_bounds.is_empty()).then(|| make::where_clause(include_where_bounds));
@@ -570,9 +580,13 @@ fn handle_call(
         ast::CallExpr::cast(peel_blocks_and_refs_and_parens(closure_ref).syntax().parent()?)?;
     let args = call.arg_list()?;
     // The really last token is `)`; we need one before that.
-    let has_trailing_comma = args.syntax().last_token()?.prev_token().is_some_and(|token| {
-        skip_trivia_token(token, Direction::Prev).is_some_and(|token| token.kind() == T![,])
-    });
+    let has_trailing_comma = args
+        .syntax()
+        .last_token()?
+        .prev_token()
+        .is_some_and(|token| {
+            skip_trivia_token(token, Direction::Prev).is_some_and(|token| token.kind() == T![,])
+        });
     let has_existing_args = args.args().next().is_some();
 
     let FileRangeWrapper { file_id, range } = ctx.sema.original_range_opt(args.syntax())?;
@@ -152,9 +152,7 @@ fn impls_core_iter(sema: &hir::Semantics<'_, ide_db::RootDatabase>, iterable: &a
         cov_mark::hit!(test_already_impls_iterator);
         Some(it_typ.impls_trait(sema.db, iter_trait, &[]))
     })()
-    .unwrap_or(
-        false,
-    )
+    .unwrap_or(false)
 }
 
 #[cfg(test)]
@@ -60,7 +60,11 @@ pub(crate) fn convert_from_to_tryfrom(acc: &mut Assists, ctx: &AssistContext<'_>
     let from_fn_name = from_fn.name()?;
     let from_fn_return_type = from_fn.ret_type()?.ty()?;
 
-    let return_exprs = from_fn.body()?.syntax().descendants().filter_map(ast::ReturnExpr::cast);
+    let return_exprs = from_fn
+        .body()?
+        .syntax()
+        .descendants()
+        .filter_map(ast::ReturnExpr::cast);
     let tail_expr = from_fn.body()?.tail_expr()?;
 
     if resolve_target_trait(&ctx.sema, &impl_)?
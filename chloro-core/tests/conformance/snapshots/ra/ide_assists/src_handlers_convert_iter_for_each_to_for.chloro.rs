@@ -50,7 +50,10 @@ pub(crate) fn convert_iter_for_each_to_for(
     let body = closure.body()?;
 
     let stmt = method.syntax().parent().and_then(ast::ExprStmt::cast);
-    let range = stmt.as_ref().map_or(method.syntax(), AstNode::syntax).text_range();
+    let range = stmt
+        .as_ref()
+        .map_or(method.syntax(), AstNode::syntax)
+        .text_range();
 
     acc.add(
         AssistId::refactor_rewrite("convert_iter_for_each_to_for"),
@@ -191,9 +194,7 @@ fn impls_core_iter(sema: &hir::Semantics<'_, ide_db::RootDatabase>, iterable: &a
         cov_mark::hit!(test_already_impls_iterator);
         Some(it_typ.impls_trait(sema.db, iter_trait, &[]))
     })()
-    .unwrap_or(
-        false,
-    )
+    .unwrap_or(false)
 }
 
 fn validate_method_call_expr(
@@ -219,7 +220,9 @@ fn validate_method_call_expr(
     let krate = module.krate();
 
     let iter_trait = FamousDefs(sema, krate).core_iter_Iterator()?;
-    it_type.impls_trait(sema.db, iter_trait, &[]).then_some((expr, receiver))
+    it_type
+        .impls_trait(sema.db, iter_trait, &[])
+        .then_some((expr, receiver))
 }
 
 #[cfg(test)]
@@ -139,84 +139,81 @@ fn remove_mut_and_collect_idents(
             non_mut_pat.into()
         }
         ast::Pat::BoxPat(p) => {
-            make.box_pat(remove_mut_and_collect_idents(make, &p.pat()?, acc)?).into()
+            make.box_pat(remove_mut_and_collect_idents(make, &p.pat()?, acc)?)
+                .into()
         }
-        ast::Pat::OrPat(p) => make
-            .or_pat(
-                p.pats()
-                    .map(|pat| remove_mut_and_collect_idents(make, &pat, acc))
-                    .collect::<Option<Vec<_>>>()?,
-                p.leading_pipe().is_some(),
-            )
-            .into(),
+        ast::Pat::OrPat(p) => make.or_pat(
+            p.pats()
+                .map(|pat| remove_mut_and_collect_idents(make, &pat, acc))
+                .collect::<Option<Vec<_>>>()?,
+            p.leading_pipe().is_some(),
+        )
+        .into(),
         ast::Pat::ParenPat(p) => {
-            make.paren_pat(remove_mut_and_collect_idents(make, &p.pat()?, acc)?).into()
+            make.paren_pat(remove_mut_and_collect_idents(make, &p.pat()?, acc)?)
+                .into()
         }
-        ast::Pat::RangePat(p) => make
-            .range_pat(
-                if let Some(start) = p.start() {
-                    Some(remove_mut_and_collect_idents(make, &start, acc)?)
-                } else {
-                    None
-                },
-                if let Some(end) = p.end() {
-                    Some(remove_mut_and_collect_idents(make, &end, acc)?)
-                } else {
-                    None
-                },
-            )
-            .into(),
-        ast::Pat::RecordPat(p) => make
-            .record_pat_with_fields(
-                p.path()?,
-                make.record_pat_field_list(
-                    p.record_pat_field_list()?
-                        .fields()
-                        .map(|field| {
-                            remove_mut_and_collect_idents(make, &field.pat()?, acc).map(|pat| {
-                                if let Some(name_ref) = field.name_ref() {
-                                    make.record_pat_field(name_ref, pat)
-                                } else {
-                                    make.record_pat_field_shorthand(pat)
-                                }
-                            })
+        ast::Pat::RangePat(p) => make.range_pat(
+            if let Some(start) = p.start() {
+                Some(remove_mut_and_collect_idents(make, &start, acc)?)
+            } else {
+                None
+            },
+            if let Some(end) = p.end() {
+                Some(remove_mut_and_collect_idents(make, &end, acc)?)
+            } else {
+                None
+            },
+        )
+        .into(),
+        ast::Pat::RecordPat(p) => make.record_pat_with_fields(
+            p.path()?,
+            make.record_pat_field_list(
+                p.record_pat_field_list()?
+                    .fields()
+                    .map(|field| {
+                        remove_mut_and_collect_idents(make, &field.pat()?, acc).map(|pat| {
+                            if let Some(name_ref) = field.name_ref() {
+                                make.record_pat_field(name_ref, pat)
+                            } else {
+                                make.record_pat_field_shorthand(pat)
+                            }
                         })
-                        .collect::<Option<Vec<_>>>()?,
-                    p.record_pat_field_list()?.rest_pat(),
-                ),
-            )
-            .into(),
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+                p.record_pat_field_list()?.rest_pat(),
+            ),
+        )
+        .into(),
         ast::Pat::RefPat(p) => {
             let inner = p.pat()?;
             if let ast::Pat::IdentPat(ident) = inner {
                 acc.push(ident);
                 p.clone_for_update().into()
             } else {
-                make.ref_pat(remove_mut_and_collect_idents(make, &inner, acc)?).into()
+                make.ref_pat(remove_mut_and_collect_idents(make, &inner, acc)?)
+                    .into()
             }
         }
-        ast::Pat::SlicePat(p) => make
-            .slice_pat(
-                p.pats()
-                    .map(|pat| remove_mut_and_collect_idents(make, &pat, acc))
-                    .collect::<Option<Vec<_>>>()?,
-            )
-            .into(),
-        ast::Pat::TuplePat(p) => make
-            .tuple_pat(
-                p.fields()
-                    .map(|field| remove_mut_and_collect_idents(make, &field, acc))
-                    .collect::<Option<Vec<_>>>()?,
-            )
-            .into(),
-        ast::Pat::TupleStructPat(p) => make
-            .tuple_struct_pat(
-                p.path()?,
-                p.fields()
-                    .map(|field| remove_mut_and_collect_idents(make, &field, acc))
-                    .collect::<Option<Vec<_>>>()?,
-            )
-            .into(),
+        ast::Pat::SlicePat(p) => make.slice_pat(
+            p.pats()
+                .map(|pat| remove_mut_and_collect_idents(make, &pat, acc))
+                .collect::<Option<Vec<_>>>()?,
+        )
+        .into(),
+        ast::Pat::TuplePat(p) => make.tuple_pat(
+            p.fields()
+                .map(|field| remove_mut_and_collect_idents(make, &field, acc))
+                .collect::<Option<Vec<_>>>()?,
+        )
+        .into(),
+        ast::Pat::TupleStructPat(p) => make.tuple_struct_pat(
+            p.path()?,
+            p.fields()
+                .map(|field| remove_mut_and_collect_idents(make, &field, acc))
+                .collect::<Option<Vec<_>>>()?,
+        )
+        .into(),
         ast::Pat::RestPat(_)
         | ast::Pat::LiteralPat(_)
         | ast::Pat::PathPat(_)
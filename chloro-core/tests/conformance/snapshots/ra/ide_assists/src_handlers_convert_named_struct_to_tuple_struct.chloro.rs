@@ -61,7 +61,9 @@ pub(crate) fn convert_named_struct_to_tuple_struct(
         .find_node_at_offset::<ast::Struct>()
         .map(Either::Left)
         .or_else(|| ctx.find_node_at_offset::<ast::Variant>().map(Either::Right))?;
-    let field_list = strukt_or_variant.as_ref().either(|s| s.field_list(), |v| v.field_list())?;
+    let field_list = strukt_or_variant
+        .as_ref()
+        .either(|s| s.field_list(), |v| v.field_list())?;
 
     if ctx.offset() > field_list.syntax().text_range().start() {
         // Assist could be distracting after the braces
@@ -246,7 +248,11 @@ fn edit_field_references(
             for r in refs {
                 if let Some(name_ref) = r.name.as_name_ref() {
                     // Only edit the field reference if it's part of a `.field` access
-                    if name_ref.syntax().parent().and_then(ast::FieldExpr::cast).is_some() {
+                    if name_ref
+                        .syntax()
+                        .parent()
+                        .and_then(ast::FieldExpr::cast)
+                        .is_some() {
                         edit.replace(r.range, index.to_string());
                     }
                 }
@@ -63,9 +63,14 @@ pub(crate) fn convert_nested_function_to_closure(
 
 /// Returns whether the given function is nested within the body of another function.
 fn is_nested_function(function: &ast::Fn) -> bool {
-    function.syntax().ancestors().skip(1).find_map(ast::Item::cast).is_some_and(|it| {
-        matches!(it, ast::Item::Fn(_) | ast::Item::Static(_) | ast::Item::Const(_))
-    })
+    function
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find_map(ast::Item::cast)
+        .is_some_and(|it| {
+            matches!(it, ast::Item::Fn(_) | ast::Item::Static(_) | ast::Item::Const(_))
+        })
 }
 
 /// Returns whether the given nested function has generic parameters.
@@ -79,9 +84,7 @@ fn is_generic(function: &ast::Fn) -> bool {
 /// - `const` or
 /// - `unsafe`
 fn has_modifiers(function: &ast::Fn) -> bool {
-    function.async_token().is_some()
-        || function.const_token().is_some()
-        || function.unsafe_token().is_some()
+    function.async_token().is_some() || function.const_token().is_some() || function.unsafe_token().is_some()
 }
 
 /// Returns whether the given nested function has a trailing semicolon.
@@ -74,7 +74,11 @@ fn if_expr_to_guarded_return(
     let then_branch = if_expr.then_branch()?;
     let then_block = then_branch.stmt_list()?;
 
-    let parent_block = if_expr.syntax().parent()?.ancestors().find_map(ast::BlockExpr::cast)?;
+    let parent_block = if_expr
+        .syntax()
+        .parent()?
+        .ancestors()
+        .find_map(ast::BlockExpr::cast)?;
 
     if parent_block.tail_expr()? != if_expr.clone().into() {
         return None;
@@ -93,9 +97,15 @@ fn if_expr_to_guarded_return(
         })?
         .reset_indent();
 
-    then_block.syntax().first_child_or_token().map(|t| t.kind() == T!['{'])?;
+    then_block
+        .syntax()
+        .first_child_or_token()
+        .map(|t| t.kind() == T!['{'])?;
 
-    then_block.syntax().last_child_or_token().filter(|t| t.kind() == T!['}'])?;
+    then_block
+        .syntax()
+        .last_child_or_token()
+        .filter(|t| t.kind() == T!['}'])?;
 
     let then_block_items = then_block.dedent(IndentLevel(1));
 
@@ -283,8 +293,10 @@ fn is_early_block(then_block: &ast::StmtList) -> bool {
         ast::Stmt::ExprStmt(expr_stmt) => expr_stmt.expr(),
         _ => None,
     };
-    then_block.tail_expr().is_some_and(is_early_expr)
-        || then_block.statements().filter_map(into_expr).any(is_early_expr)
+    then_block.tail_expr().is_some_and(is_early_expr) || then_block
+        .statements()
+        .filter_map(into_expr)
+        .any(is_early_expr)
 }
 
 #[cfg(test)]
@@ -59,7 +59,11 @@ pub(crate) fn convert_tuple_return_type_to_struct(
     let fn_ = ret_type.syntax().parent().and_then(ast::Fn::cast)?;
     let fn_def = ctx.sema.to_def(&fn_)?;
     let fn_name = fn_.name()?;
-    let target_module = ctx.sema.scope(fn_.syntax())?.module().nearest_non_block_module(ctx.db());
+    let target_module = ctx
+        .sema
+        .scope(fn_.syntax())?
+        .module()
+        .nearest_non_block_module(ctx.db());
 
     let target = type_ref.syntax().text_range();
     acc.add(
@@ -72,7 +76,10 @@ pub(crate) fn convert_tuple_return_type_to_struct(
 
             let usages = Definition::Function(fn_def).usages(&ctx.sema).all();
             let struct_name = format!("{}Result", stdx::to_camel_case(&fn_name.to_string()));
-            let parent = fn_.syntax().ancestors().find_map(<Either<ast::Impl, ast::Trait>>::cast);
+            let parent = fn_
+                .syntax()
+                .ancestors()
+                .find_map(<Either<ast::Impl, ast::Trait>>::cast);
             add_tuple_struct_def(
                 edit,
                 ctx,
@@ -111,8 +118,11 @@ fn replace_usages(
         let refs_with_imports =
             augment_references_with_imports(edit, ctx, references, struct_name, target_module);
 
-        refs_with_imports.into_iter().rev().for_each(|(name, import_data)| {
-            if let Some(fn_) = name.syntax().parent().and_then(ast::Fn::cast) {
+        refs_with_imports
+            .into_iter()
+            .rev()
+            .for_each(|(name, import_data)| {
+                if let Some(fn_) = name.syntax().parent().and_then(ast::Fn::cast) {
                 cov_mark::hit!(replace_trait_impl_fns);
 
                 if let Some(ret_type) = fn_.ret_type() {
@@ -154,11 +164,11 @@ fn replace_usages(
                     );
                 }
             }
-            // add imports across modules where needed
-            if let Some((import_scope, path)) = import_data {
-                insert_use(&import_scope, path, &ctx.config.insert_use);
-            }
-        })
+                // add imports across modules where needed
+                if let Some((import_scope, path)) = import_data {
+                    insert_use(&import_scope, path, &ctx.config.insert_use);
+                }
+            })
     }
 }
 
@@ -188,7 +198,9 @@ fn augment_references_with_imports(
         .iter()
         .filter_map(|FileReference { name, .. }| {
             let name = name.clone().into_name_like()?;
-            ctx.sema.scope(name.syntax()).map(|scope| (name, scope.module()))
+            ctx.sema
+                .scope(name.syntax())
+                .map(|scope| (name, scope.module()))
         })
         .map(|(name, ref_module)| {
             let new_name = edit.make_mut(name);
@@ -58,7 +58,9 @@ pub(crate) fn convert_tuple_struct_to_named_struct(
         .find_node_at_offset::<ast::Struct>()
         .map(Either::Left)
         .or_else(|| ctx.find_node_at_offset::<ast::Variant>().map(Either::Right))?;
-    let field_list = strukt_or_variant.as_ref().either(|s| s.field_list(), |v| v.field_list())?;
+    let field_list = strukt_or_variant
+        .as_ref()
+        .either(|s| s.field_list(), |v| v.field_list())?;
 
     if ctx.offset() > field_list.syntax().text_range().start() {
         // Assist could be distracting after the braces
@@ -73,8 +75,13 @@ pub(crate) fn convert_tuple_struct_to_named_struct(
         Either::Left(s) => Either::Left(ctx.sema.to_def(s)?),
         Either::Right(v) => Either::Right(ctx.sema.to_def(v)?),
     };
-    let target = strukt_or_variant.as_ref().either(|s| s.syntax(), |v| v.syntax()).text_range();
-    let syntax = strukt_or_variant.as_ref().either(|s| s.syntax(), |v| v.syntax());
+    let target = strukt_or_variant
+        .as_ref()
+        .either(|s| s.syntax(), |v| v.syntax())
+        .text_range();
+    let syntax = strukt_or_variant
+        .as_ref()
+        .either(|s| s.syntax(), |v| v.syntax());
     acc.add(
         AssistId::refactor_rewrite("convert_tuple_struct_to_named_struct"),
         "Convert to named struct",
@@ -281,7 +288,9 @@ fn generate_record_pat_list(
 ) -> ast::RecordPatFieldList {
     let pure_fields = pat.fields().filter(|p| !matches!(p, ast::Pat::RestPat(_)));
     let rest_len = names.len().saturating_sub(pure_fields.clone().count());
-    let rest_pat = pat.fields().find_map(|p| ast::RestPat::cast(p.syntax().clone()));
+    let rest_pat = pat
+        .fields()
+        .find_map(|p| ast::RestPat::cast(p.syntax().clone()));
     let rest_idx =
         pat.fields().position(|p| ast::RestPat::can_cast(p.syntax().kind())).unwrap_or(names.len());
     let before_rest = pat.fields().zip(names).take(rest_idx);
@@ -91,13 +91,20 @@ fn collect_data(ident_pat: ast::IdentPat, ctx: &AssistContext<'_>) -> Option<Str
     let hir::Adt::Struct(struct_type) = ty.strip_references().as_adt()? else { return None };
 
     let module = ctx.sema.scope(ident_pat.syntax())?.module();
-    let cfg = ctx.config.find_path_config(ctx.sema.is_nightly(module.krate()));
+    let cfg = ctx
+        .config
+        .find_path_config(ctx.sema.is_nightly(module.krate()));
     let struct_def = hir::ModuleDef::from(struct_type);
     let kind = struct_type.kind(ctx.db());
     let struct_def_path = module.find_path(ctx.db(), struct_def, cfg)?;
 
-    let is_non_exhaustive = struct_def.attrs(ctx.db())?.by_key(sym::non_exhaustive).exists();
-    let is_foreign_crate = struct_def.module(ctx.db()).is_some_and(|m| m.krate() != module.krate());
+    let is_non_exhaustive = struct_def
+        .attrs(ctx.db())?
+        .by_key(sym::non_exhaustive)
+        .exists();
+    let is_foreign_crate = struct_def
+        .module(ctx.db())
+        .is_some_and(|m| m.krate() != module.krate());
 
     let fields = struct_type.fields(ctx.db());
     let n_fields = fields.len();
@@ -240,8 +247,7 @@ fn generate_field_names(
     data: &StructEditData,
 ) -> Vec<(SmolStr, SmolStr)> {
     match data.kind {
-        hir::StructKind::Tuple => data
-            .visible_fields
+        hir::StructKind::Tuple => data.visible_fields
             .iter()
             .enumerate()
             .map(|(index, _)| {
@@ -249,11 +255,13 @@ fn generate_field_names(
                 (index.to_string().into(), new_name)
             })
             .collect(),
-        hir::StructKind::Record => data
-            .visible_fields
+        hir::StructKind::Record => data.visible_fields
             .iter()
             .map(|field| {
-                let field_name = field.name(ctx.db()).display_no_db(data.edition).to_smolstr();
+                let field_name = field
+                    .name(ctx.db())
+                    .display_no_db(data.edition)
+                    .to_smolstr();
                 let new_name = new_field_name(field_name.clone(), &data.names_in_scope);
                 (field_name, new_name)
             })
@@ -297,7 +305,11 @@ fn build_usage_edit(
     usage: &FileReference,
     field_names: &FxHashMap<SmolStr, SmolStr>,
 ) -> Option<(SyntaxNode, SyntaxNode)> {
-    match usage.name.syntax().ancestors().find_map(ast::FieldExpr::cast) {
+    match usage
+        .name
+        .syntax()
+        .ancestors()
+        .find_map(ast::FieldExpr::cast) {
         Some(field_expr) => Some({
             let field_name: SmolStr = field_expr.name_ref()?.to_string().into();
             let new_field_name = field_names.get(&field_name)?;
@@ -314,15 +326,17 @@ fn build_usage_edit(
                 (field_expr.syntax().clone(), new_expr.syntax().clone_for_update())
             }
         }),
-        None => Some((
-            usage.name.syntax().as_node().unwrap().clone(),
-            make.expr_macro(
+        None => Some(
+            (
+                usage.name.syntax().as_node().unwrap().clone(),
+                make.expr_macro(
                 ast::make::ext::ident_path("todo"),
                 make.token_tree(syntax::SyntaxKind::L_PAREN, []),
             )
             .syntax()
             .clone(),
-        )),
+            ),
+        ),
     }
 }
 
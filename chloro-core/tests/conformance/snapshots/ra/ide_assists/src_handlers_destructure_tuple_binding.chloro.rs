@@ -94,7 +94,9 @@ fn destructure_tuple_edit_impl(
 
     assignment_edit.apply();
     if let Some(usages_edit) = current_file_usages_edit {
-        usages_edit.into_iter().for_each(|usage_edit| usage_edit.apply(edit))
+        usages_edit
+            .into_iter()
+            .for_each(|usage_edit| usage_edit.apply(edit))
     }
 }
 
@@ -149,7 +151,12 @@ fn collect_data(ident_pat: IdentPat, ctx: &AssistContext<'_>) -> Option<TupleDat
         })
         .collect::<Vec<_>>();
 
-    Some(TupleData { ident_pat, ref_type, field_names, usages })
+    Some(TupleData {
+        ident_pat,
+        ref_type,
+        field_names,
+        usages,
+    })
 }
 
 enum RefType {
@@ -198,7 +205,12 @@ fn edit_tuple_assignment(
         }
     }
 
-    AssignmentEdit { ident_pat, tuple_pat, in_sub_pattern, is_shorthand_field }
+    AssignmentEdit {
+        ident_pat,
+        tuple_pat,
+        in_sub_pattern,
+        is_shorthand_field,
+    }
 }
 
 struct AssignmentEdit {
@@ -351,7 +363,10 @@ fn detect_tuple_index(usage: &FileReference, data: &TupleData) -> Option<TupleIn
                 return None;
             }
 
-            Some(TupleIndex { index: idx, field_expr })
+            Some(TupleIndex {
+                index: idx,
+                field_expr,
+            })
         } else {
             // tuple index out of range
             None
@@ -373,20 +388,26 @@ mod tests {
     #[test]
     fn dont_trigger_on_unit() {
         cov_mark::check!(destructure_tuple_no_tuple);
-        check_assist_not_applicable(assist, r#"
+        check_assist_not_applicable(
+            assist,
+            r#"
 fn main() {
 let $0v = ();
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn dont_trigger_on_number() {
         cov_mark::check!(destructure_tuple_no_tuple);
-        check_assist_not_applicable(assist, r#"
+        check_assist_not_applicable(
+            assist,
+            r#"
 fn main() {
 let $0v = 32;
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn destructure_3_tuple() {
@@ -699,11 +720,14 @@ static $0TUP: (usize, usize) = (1,2);
     }
     #[test]
     fn dont_trigger_on_wildcard() {
-        check_assist_not_applicable(assist, r#"
+        check_assist_not_applicable(
+            assist,
+            r#"
 fn main() {
     let $0_ = (1,2);
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn dont_trigger_in_struct() {
@@ -1387,6 +1411,7 @@ fn main() {
         #[test]
         fn tuple_usage() {
             check_both_assists(
+                // leading `"foo"` to ensure `$e` doesn't start at position `0`
                 r#"
 macro_rules! m {
     ($e:expr) => { "foo"; $e };
@@ -1467,6 +1492,7 @@ fn main() {
     m!(t.0);
 }
                 "#,
+                // FIXME: replace `t.0` with `_0` (cannot detect range of tuple index in macro call)
                 r#"
 macro_rules! m {
     ($e:expr) => { "foo"; $e };
@@ -1477,6 +1503,7 @@ fn main() {
     m!(/*t*/.0);
 }
                 "#,
+                // FIXME: replace `t.0` with `_0`
                 r#"
 macro_rules! m {
     ($e:expr) => { "foo"; $e };
@@ -1502,6 +1529,7 @@ fn main() {
     m!((t).0);
 }
                 "#,
+                // FIXME: replace `(t).0` with `_0`
                 r#"
 macro_rules! m {
     ($e:expr) => { "foo"; $e };
@@ -1512,6 +1540,7 @@ fn main() {
     m!((/*t*/).0);
 }
                 "#,
+                // FIXME: replace `(t).0` with `_0`
                 r#"
 macro_rules! m {
     ($e:expr) => { "foo"; $e };
@@ -1538,6 +1567,7 @@ fn main() {
     m!(t);
 }
                 "#,
+                // FIXME: macro allows no arg -> is valid. But assist should result in invalid code
                 r#"
 macro_rules! m {
     () => { "foo" };
@@ -1564,6 +1594,7 @@ fn main() {
     m!(t, t.0);
 }
                 "#,
+                // FIXME: replace `t.0` in macro call (not IN macro) with `_0`
                 r#"
 macro_rules! m {
     ($t:expr, $i:expr) => { $t.0 + $i };
@@ -1574,6 +1605,7 @@ fn main() {
     m!(/*t*/, /*t*/.0);
 }
                 "#,
+                // FIXME: replace `t.0` in macro call with `_0`
                 r#"
 macro_rules! m {
     ($t:expr, $i:expr) => { $t.0 + $i };
@@ -1833,6 +1865,7 @@ fn main() {
     let s = t.0.f();
 }
                 "#,
+                // FIXME: doesn't need deref * parens. But `ctx.sema.resolve_method_call` doesn't resolve trait implementations
                 r#"
 trait T {
     fn f(self);
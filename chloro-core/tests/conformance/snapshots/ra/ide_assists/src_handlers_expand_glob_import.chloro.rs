@@ -290,7 +290,13 @@ impl Refs {
     }
 
     fn filter_out_by_defs(&self, defs: Vec<Definition>) -> Refs {
-        Refs(self.0.clone().into_iter().filter(|r| !defs.contains(&r.def)).collect())
+        Refs(
+            self.0
+                .clone()
+                .into_iter()
+                .filter(|r| !defs.contains(&r.def))
+                .collect(),
+        )
     }
 }
 
@@ -327,8 +333,9 @@ fn is_visible_from(ctx: &AssistContext<'_>, expandable: &Expandable, from: Modul
     fn is_mod_visible_from(ctx: &AssistContext<'_>, module: Module, from: Module) -> bool {
         match module.parent(ctx.db()) {
             Some(parent) => {
-                module.visibility(ctx.db()).is_visible_from(ctx.db(), from.into())
-                    && is_mod_visible_from(ctx, parent, from)
+                module
+                    .visibility(ctx.db())
+                    .is_visible_from(ctx.db(), from.into()) && is_mod_visible_from(ctx, parent, from)
             }
             None => true,
         }
@@ -337,15 +344,16 @@ fn is_visible_from(ctx: &AssistContext<'_>, expandable: &Expandable, from: Modul
     match expandable {
         Expandable::Module(module) => match module.parent(ctx.db()) {
             Some(parent) => {
-                module.visibility(ctx.db()).is_visible_from(ctx.db(), from.into())
-                    && is_mod_visible_from(ctx, parent, from)
+                module
+                    .visibility(ctx.db())
+                    .is_visible_from(ctx.db(), from.into()) && is_mod_visible_from(ctx, parent, from)
             }
             None => true,
         },
         Expandable::Enum(enm) => {
             let module = enm.module(ctx.db());
-            enm.visibility(ctx.db()).is_visible_from(ctx.db(), from.into())
-                && is_mod_visible_from(ctx, module, from)
+            enm.visibility(ctx.db())
+                .is_visible_from(ctx.db(), from.into()) && is_mod_visible_from(ctx, module, from)
         }
     }
 }
@@ -368,7 +376,10 @@ fn find_imported_defs(ctx: &AssistContext<'_>, use_item: Use) -> Vec<Definition>
     [Direction::Prev, Direction::Next]
         .into_iter()
         .flat_map(|dir| {
-            use_item.syntax().siblings(dir.to_owned()).filter(|n| ast::Use::can_cast(n.kind()))
+            use_item
+                .syntax()
+                .siblings(dir.to_owned())
+                .filter(|n| ast::Use::can_cast(n.kind()))
         })
         .flat_map(|n| n.descendants().filter_map(ast::NameRef::cast))
         .filter_map(|r| match NameRefClass::classify(&ctx.sema, &r)? {
@@ -391,7 +402,11 @@ fn find_imported_defs(ctx: &AssistContext<'_>, use_item: Use) -> Vec<Definition>
 
 fn find_names_to_import(refs_in_target: Refs, imported_defs: Vec<Definition>) -> Vec<Name> {
     let final_refs = refs_in_target.filter_out_by_defs(imported_defs);
-    final_refs.0.iter().map(|r| r.visible_name.clone()).collect()
+    final_refs
+        .0
+        .iter()
+        .map(|r| r.visible_name.clone())
+        .collect()
 }
 
 #[cfg(test)]
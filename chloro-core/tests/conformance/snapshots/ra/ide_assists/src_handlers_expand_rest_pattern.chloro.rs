@@ -45,7 +45,11 @@ fn expand_record_rest_pattern(
         return None;
     }
 
-    let edition = ctx.sema.scope(record_pat.syntax())?.krate().edition(ctx.db());
+    let edition = ctx
+        .sema
+        .scope(record_pat.syntax())?
+        .krate()
+        .edition(ctx.db());
     acc.add(
         AssistId::refactor_rewrite("expand_record_rest_pattern"),
         "Fill struct fields",
@@ -53,16 +57,18 @@ fn expand_record_rest_pattern(
         |builder| {
             let make = SyntaxFactory::with_mappings();
             let mut editor = builder.make_editor(rest_pat.syntax());
-            let new_fields = old_field_list.fields().chain(missing_fields.iter().map(|(f, _)| {
-                make.record_pat_field_shorthand(
-                    make.ident_pat(
-                        false,
-                        false,
-                        make.name(&f.name(ctx.sema.db).display_no_db(edition).to_smolstr()),
+            let new_fields = old_field_list.fields().chain(
+                missing_fields.iter().map(|(f, _)| {
+                    make.record_pat_field_shorthand(
+                        make.ident_pat(
+                            false,
+                            false,
+                            make.name(&f.name(ctx.sema.db).display_no_db(edition).to_smolstr()),
+                        )
+                        .into(),
                     )
-                    .into(),
-                )
-            }));
+                }),
+            );
             let new_field_list = make.record_pat_field_list(new_fields, None);
 
             editor.replace(old_field_list.syntax(), new_field_list.syntax());
@@ -179,7 +185,11 @@ fn expand_tuple_rest_pattern(
     pat: ast::TuplePat,
     rest_pat: ast::RestPat,
 ) -> Option<()> {
-    let fields = ctx.sema.type_of_pat(&pat.clone().into())?.original.tuple_fields(ctx.db());
+    let fields = ctx
+        .sema
+        .type_of_pat(&pat.clone().into())?
+        .original
+        .tuple_fields(ctx.db());
     let len = fields.len();
 
     let rest_pat = rest_pat.into();
@@ -244,7 +254,11 @@ fn expand_slice_rest_pattern(
     pat: ast::SlicePat,
     rest_pat: ast::RestPat,
 ) -> Option<()> {
-    let (ty, len) = ctx.sema.type_of_pat(&pat.clone().into())?.original.as_array(ctx.db())?;
+    let (ty, len) = ctx
+        .sema
+        .type_of_pat(&pat.clone().into())?
+        .original
+        .as_array(ctx.db())?;
 
     let rest_pat = rest_pat.into();
     let (prefix_count, suffix_count) = calculate_counts(&rest_pat, pat.pats())?;
@@ -307,14 +321,10 @@ fn gen_unnamed_pat(
     ty: &hir::Type<'_>,
     index: usize,
 ) -> ast::Pat {
-    make.ident_pat(
-        false,
-        false,
-        match name_gen.for_type(ty, ctx.sema.db, ctx.edition()) {
-            Some(name) => make.name(&name),
-            None => make.name(&format!("_{index}")),
-        },
-    )
+    make.ident_pat(false, false, match name_gen.for_type(ty, ctx.sema.db, ctx.edition()) {
+        Some(name) => make.name(&name),
+        None => make.name(&format!("_{index}")),
+    })
     .into()
 }
 
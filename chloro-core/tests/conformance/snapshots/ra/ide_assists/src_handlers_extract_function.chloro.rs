@@ -306,7 +306,9 @@ fn extraction_target(node: &SyntaxNode, selection_range: TextRange) -> Option<Fu
         return FunctionBody::from_expr(expr);
     }
 
-    node.ancestors().find_map(ast::Expr::cast).and_then(FunctionBody::from_expr)
+    node.ancestors()
+        .find_map(ast::Expr::cast)
+        .and_then(FunctionBody::from_expr)
 }
 
 #[derive(Debug)]
@@ -356,6 +358,8 @@ enum Anchor {
     Method,
 }
 
+// FIXME: ControlFlow and ContainerInfo both track some function modifiers, feels like these two should
+// probably be merged somehow.
 #[derive(Debug)]
 struct ControlFlow<'db> {
     kind: Option<FlowKind<'db>>,
@@ -391,9 +395,7 @@ struct ContainerInfo<'db> {
 enum FlowKind<'db> {
     /// Return with value (`return $expr;`)
     Return(Option<ast::Expr>),
-    Try {
-        kind: TryKind<'db>,
-    },
+    Try { kind: TryKind<'db> },
     /// Break with label and value (`break 'label $expr;`)
     Break(Option<ast::Lifetime>, Option<ast::Expr>),
     /// Continue with label (`continue 'label;`)
@@ -403,9 +405,7 @@ enum FlowKind<'db> {
 #[derive(Debug, Clone)]
 enum TryKind<'db> {
     Option,
-    Result {
-        ty: hir::Type<'db>,
-    },
+    Result { ty: hir::Type<'db> },
 }
 
 #[derive(Debug)]
@@ -517,7 +517,11 @@ impl<'db> Param<'db> {
         module: hir::Module,
         edition: Edition,
     ) -> ast::Param {
-        let var = self.var.name(ctx.db()).display(ctx.db(), edition).to_string();
+        let var = self
+            .var
+            .name(ctx.db())
+            .display(ctx.db(), edition)
+            .to_string();
         let var_name = make::name(&var);
         let pat = match self.kind() {
             ParamKind::MutValue => make::ident_pat(false, true, var_name),
@@ -668,9 +672,16 @@ impl FunctionBody {
             (Some(first), Some(last)) => first..=last,
         };
 
-        let text_range = elements.start().text_range().cover(elements.end().text_range());
+        let text_range = elements
+            .start()
+            .text_range()
+            .cover(elements.end().text_range());
 
-        Some(Self::Span { parent, elements, text_range })
+        Some(Self::Span {
+            parent,
+            elements,
+            text_range,
+        })
     }
 
     fn indent_level(&self) -> IndentLevel {
@@ -685,7 +696,9 @@ impl FunctionBody {
             FunctionBody::Expr(expr) => Some(expr.clone()),
             FunctionBody::Span { parent, text_range, .. } => {
                 let tail_expr = parent.tail_expr()?;
-                text_range.contains_range(tail_expr.syntax().text_range()).then_some(tail_expr)
+                text_range
+                    .contains_range(tail_expr.syntax().text_range())
+                    .then_some(tail_expr)
             }
         }
     }
@@ -974,11 +987,15 @@ impl FunctionBody {
 
         let parent = self.parent()?;
         let parents = generic_parents(&parent);
-        let generic_param_lists = parents.iter().filter_map(|it| it.generic_param_list()).collect();
+        let generic_param_lists = parents
+            .iter()
+            .filter_map(|it| it.generic_param_list())
+            .collect();
         let where_clauses = parents.iter().filter_map(|it| it.where_clause()).collect();
 
-        Some((
-            ContainerInfo {
+        Some(
+            (
+                ContainerInfo {
                 is_const,
                 parent_loop,
                 ret_type: ty,
@@ -986,13 +1003,17 @@ impl FunctionBody {
                 where_clauses,
                 edition,
             },
-            contains_tail_expr,
-        ))
+                contains_tail_expr,
+            ),
+        )
     }
 
     fn return_ty<'db>(&self, ctx: &AssistContext<'db>) -> Option<RetType<'db>> {
         match self.tail_expr() {
-            Some(expr) => ctx.sema.type_of_expr(&expr).map(TypeInfo::original).map(RetType::Expr),
+            Some(expr) => ctx.sema
+                .type_of_expr(&expr)
+                .map(TypeInfo::original)
+                .map(RetType::Expr),
             None => Some(RetType::Stmt),
         }
     }
@@ -1095,7 +1116,11 @@ impl FunctionBody {
             (None, None, None, None) => None,
         };
 
-        Some(ControlFlow { kind, is_async, is_unsafe: _is_unsafe })
+        Some(ControlFlow {
+            kind,
+            is_async,
+            is_unsafe: _is_unsafe,
+        })
     }
 
     /// find variables that should be extracted as params
@@ -1136,13 +1161,21 @@ impl FunctionBody {
                 // if the var is not used but defined outside a loop we are extracting from we can't move it either
                 // as the function will reuse it in the next iteration.
                 let move_local = (!has_usages && defined_outside_parent_loop) || ty.is_reference();
-                Param { var, ty, move_local, requires_mut, is_copy }
+                Param {
+                    var,
+                    ty,
+                    move_local,
+                    requires_mut,
+                    is_copy,
+                }
             })
             .collect()
     }
 
     fn has_usages_after_body(&self, usages: &LocalUsages) -> bool {
-        usages.iter().any(|reference| self.precedes_range(reference.range))
+        usages
+            .iter()
+            .any(|reference| self.precedes_range(reference.range))
     }
 }
 
@@ -1312,14 +1345,20 @@ fn path_element_of_reference(
     node: &dyn HasTokenAtOffset,
     reference: &FileReference,
 ) -> Option<ast::Expr> {
-    let token = node.token_at_offset(reference.range.start()).right_biased().or_else(|| {
-        stdx::never!(false, "cannot find token at variable usage: {:?}", reference);
-        None
-    })?;
-    let path = token.parent_ancestors().find_map(ast::Expr::cast).or_else(|| {
-        stdx::never!(false, "cannot find path parent of variable usage: {:?}", token);
-        None
-    })?;
+    let token = node
+        .token_at_offset(reference.range.start())
+        .right_biased()
+        .or_else(|| {
+            stdx::never!(false, "cannot find token at variable usage: {:?}", reference);
+            None
+        })?;
+    let path = token
+        .parent_ancestors()
+        .find_map(ast::Expr::cast)
+        .or_else(|| {
+            stdx::never!(false, "cannot find path parent of variable usage: {:?}", token);
+            None
+        })?;
     stdx::always!(
         matches!(path, ast::Expr::PathExpr(_) | ast::Expr::MacroExpr(_)),
         "unexpected expression type for variable usage: {:?}",
@@ -1368,7 +1407,10 @@ fn local_outlives_body(
     if !any_outlives {
         return None;
     }
-    Some(OutlivedLocal { local, mut_usage_outside_body: has_mut_usages })
+    Some(OutlivedLocal {
+        local,
+        mut_usage_outside_body: has_mut_usages,
+    })
 }
 
 /// checks if the relevant local was defined before(outside of) body
@@ -1496,13 +1538,13 @@ fn make_call(ctx: &AssistContext<'_>, fun: &Function<'_>, indent: IndentLevel) -
 
     if let Some(bindings) = outliving_bindings {
         // with bindings that outlive it
-        make::let_stmt(bindings, None, Some(expr)).syntax().clone_for_update()
+        make::let_stmt(bindings, None, Some(expr))
+            .syntax()
+            .clone_for_update()
     } else if parent_match_arm.as_ref().is_some() {
         // as a tail expr for a match arm
         expr.syntax().clone()
-    } else if parent_match_arm.as_ref().is_none()
-        && fun.ret_ty.is_unit()
-        && (!fun.outliving_locals.is_empty() || !expr.is_block_like()) {
+    } else if parent_match_arm.as_ref().is_none() && fun.ret_ty.is_unit() && (!fun.outliving_locals.is_empty() || !expr.is_block_like()) {
         // as an expr stmt
         make::expr_stmt(expr).syntax().clone_for_update()
     } else {
@@ -1513,21 +1555,11 @@ fn make_call(ctx: &AssistContext<'_>, fun: &Function<'_>, indent: IndentLevel) -
 
 enum FlowHandler<'db> {
     None,
-    Try {
-        kind: TryKind<'db>,
-    },
-    If {
-        action: FlowKind<'db>,
-    },
-    IfOption {
-        action: FlowKind<'db>,
-    },
-    MatchOption {
-        none: FlowKind<'db>,
-    },
-    MatchResult {
-        err: FlowKind<'db>,
-    },
+    Try { kind: TryKind<'db> },
+    If { action: FlowKind<'db> },
+    IfOption { action: FlowKind<'db> },
+    MatchOption { none: FlowKind<'db> },
+    MatchResult { err: FlowKind<'db> },
 }
 
 impl<'db> FlowHandler<'db> {
@@ -1769,8 +1801,13 @@ impl<'db> Function<'db> {
                 Some(PathResolution::TypeParam(type_param)) => Some(type_param),
                 _ => None,
             });
-        let type_params_in_params = self.params.iter().filter_map(|p| p.ty.as_type_param(ctx.db()));
-        type_params_in_descendant_paths.chain(type_params_in_params).collect()
+        let type_params_in_params = self
+            .params
+            .iter()
+            .filter_map(|p| p.ty.as_type_param(ctx.db()));
+        type_params_in_descendant_paths
+            .chain(type_params_in_params)
+            .collect()
     }
 
     fn make_param_list(
@@ -1780,7 +1817,10 @@ impl<'db> Function<'db> {
         edition: Edition,
     ) -> ast::ParamList {
         let self_param = self.self_param.clone();
-        let params = self.params.iter().map(|param| param.to_param(ctx, module, edition));
+        let params = self
+            .params
+            .iter()
+            .map(|param| param.to_param(ctx, module, edition));
         make::param_list(self_param, params)
     }
 
@@ -1956,9 +1996,7 @@ fn make_body(
         FlowHandler::None => block,
         FlowHandler::Try { kind } => {
             let block = with_default_tail_expr(block, make::ext::expr_unit());
-            map_tail_expr(
-                block,
-                |tail_expr| {
+            map_tail_expr(block, |tail_expr| {
                 let constructor = match kind {
                     TryKind::Option => "Some",
                     TryKind::Result { .. } => "Ok",
@@ -1966,8 +2004,7 @@ fn make_body(
                 let func = make::expr_path(make::ext::ident_path(constructor));
                 let args = make::arg_list(iter::once(tail_expr));
                 make::expr_call(func, args).into()
-            },
-            )
+            })
         }
         FlowHandler::If { .. } => {
             let controlflow_continue = make::expr_call(
@@ -1981,22 +2018,16 @@ fn make_body(
             let none = make::expr_path(make::ext::ident_path("None"));
             with_tail_expr(block, none)
         }
-        FlowHandler::MatchOption { .. } => map_tail_expr(
-            block,
-            |tail_expr| {
+        FlowHandler::MatchOption { .. } => map_tail_expr(block, |tail_expr| {
             let some = make::expr_path(make::ext::ident_path("Some"));
             let args = make::arg_list(iter::once(tail_expr));
             make::expr_call(some, args).into()
-        },
-        ),
-        FlowHandler::MatchResult { .. } => map_tail_expr(
-            block,
-            |tail_expr| {
+        }),
+        FlowHandler::MatchResult { .. } => map_tail_expr(block, |tail_expr| {
             let ok = make::expr_path(make::ext::ident_path("Ok"));
             let args = make::arg_list(iter::once(tail_expr));
             make::expr_call(ok, args).into()
-        },
-        ),
+        }),
     }
 }
 
@@ -2041,7 +2072,9 @@ fn with_tail_expr(block: ast::BlockExpr, tail_expr: ast::Expr) -> ast::BlockExpr
 }
 
 fn format_type(ty: &hir::Type<'_>, ctx: &AssistContext<'_>, module: hir::Module) -> String {
-    ty.display_source_code(ctx.db(), module.into(), true).ok().unwrap_or_else(|| "_".to_owned())
+    ty.display_source_code(ctx.db(), module.into(), true)
+        .ok()
+        .unwrap_or_else(|| "_".to_owned())
 }
 
 fn make_ty(ty: &hir::Type<'_>, ctx: &AssistContext<'_>, module: hir::Module) -> ast::Type {
@@ -78,7 +78,12 @@ pub(crate) fn extract_module(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opti
     }
 
     let mut old_item_indent = module.body_items[0].indent_level();
-    let old_items: Vec<_> = module.use_items.iter().chain(&module.body_items).cloned().collect();
+    let old_items: Vec<_> = module
+        .use_items
+        .iter()
+        .chain(&module.body_items)
+        .cloned()
+        .collect();
 
     // If the selection is inside impl block, we need to place new module outside impl block,
     // as impl blocks cannot contain modules
@@ -109,17 +114,17 @@ pub(crate) fn extract_module(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opti
             //- Thirdly, resolving all the imports this includes removing paths from imports
             //  outside the module, shifting/cloning them inside new module, or shifting the imports, or making
             //  new import statements
-
             //We are getting item usages and record_fields together, record_fields
             //for change_visibility and usages for first point mentioned above in the process
-
             let (usages_to_be_processed, record_fields, use_stmts_to_be_inserted) =
                 module.get_usages_and_record_fields(ctx, module_text_range);
 
             builder.edit_file(ctx.vfs_file_id());
-            use_stmts_to_be_inserted.into_iter().for_each(|(_, use_stmt)| {
-                builder.insert(ctx.selection_trimmed().end(), format!("\n{use_stmt}"));
-            });
+            use_stmts_to_be_inserted
+                .into_iter()
+                .for_each(|(_, use_stmt)| {
+                    builder.insert(ctx.selection_trimmed().end(), format!("\n{use_stmt}"));
+                });
 
             let import_items = module.resolve_imports(curr_parent_module, ctx);
             module.change_visibility(record_fields);
@@ -203,7 +208,10 @@ fn generate_module_def(
         use_items.iter().chain(body_items).cloned().collect()
     };
 
-    let items = items.into_iter().map(|it| it.reset_indent().indent(IndentLevel(1))).collect_vec();
+    let items = items
+        .into_iter()
+        .map(|it| it.reset_indent().indent(IndentLevel(1)))
+        .collect_vec();
     let module_body = make::item_list(Some(items));
 
     let module_name = make::name(name);
@@ -240,7 +248,11 @@ fn extract_single_target(node: &ast::Item) -> Module {
         (vec![node.clone()], Vec::new())
     };
     let name = "modname";
-    Module { name, body_items, use_items }
+    Module {
+        name,
+        body_items,
+        use_items,
+    }
 }
 
 fn extract_child_target(
@@ -256,7 +268,16 @@ fn extract_child_target(
     let end = selected_nodes.last()?.syntax().clone();
     let (use_items, body_items): (Vec<ast::Item>, Vec<ast::Item>) =
         selected_nodes.into_iter().partition(|item| matches!(item, ast::Item::Use(..)));
-    Some((Module { name: "modname", body_items, use_items }, start..=end))
+    Some(
+        (
+            Module {
+            name: "modname",
+            body_items,
+            use_items,
+        },
+            start..=end,
+        ),
+    )
 }
 
 impl Module {
@@ -396,7 +417,10 @@ impl Module {
 
                 None
             });
-            refs_in_files.entry(file_id.file_id(ctx.db())).or_default().extend(usages);
+            refs_in_files
+                .entry(file_id.file_id(ctx.db()))
+                .or_default()
+                .extend(usages);
         }
     }
 
@@ -489,7 +513,10 @@ impl Module {
         //We only need to find in the current file
         let selection_range = ctx.selection_trimmed();
         let file_id = ctx.file_id();
-        let usage_res = def.usages(&ctx.sema).in_scope(&SearchScope::single_file(file_id)).all();
+        let usage_res = def
+            .usages(&ctx.sema)
+            .in_scope(&SearchScope::single_file(file_id))
+            .all();
 
         let file = ctx.sema.parse(file_id);
 
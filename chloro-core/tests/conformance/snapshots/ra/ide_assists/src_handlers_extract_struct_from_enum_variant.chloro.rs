@@ -107,7 +107,9 @@ pub(crate) fn extract_struct_from_enum_variant(
             let generic_params = enum_ast
                 .generic_param_list()
                 .and_then(|known_generics| extract_generic_params(&known_generics, &field_list));
-            let generics = generic_params.as_ref().map(|generics| generics.clone_for_update());
+            let generics = generic_params
+                .as_ref()
+                .map(|generics| generics.clone_for_update());
 
             // resolve GenericArg in field_list to actual type
             let field_list = if let Some((target_scope, source_scope)) =
@@ -188,7 +190,10 @@ fn extract_generic_params(
     known_generics: &ast::GenericParamList,
     field_list: &Either<ast::RecordFieldList, ast::TupleFieldList>,
 ) -> Option<ast::GenericParamList> {
-    let mut generics = known_generics.generic_params().map(|param| (param, false)).collect_vec();
+    let mut generics = known_generics
+        .generic_params()
+        .map(|param| (param, false))
+        .collect_vec();
 
     let tagged_one = match field_list {
         Either::Left(field_list) => field_list
@@ -201,7 +206,9 @@ fn extract_generic_params(
             .fold(false, |tagged, ty| tag_generics_in_variant(&ty, &mut generics) || tagged),
     };
 
-    let generics = generics.into_iter().filter_map(|(param, tag)| tag.then_some(param));
+    let generics = generics
+        .into_iter()
+        .filter_map(|(param, tag)| tag.then_some(param));
     tagged_one.then(|| make::generic_param_list(generics))
 }
 
@@ -61,7 +61,9 @@ pub(crate) fn extract_type_alias(acc: &mut Assists, ctx: &AssistContext<'_>) ->
                 generics.map(|it| make::generic_param_list(it.into_iter().cloned()));
 
             // Replace original type with the alias
-            let ty_args = generic_params.as_ref().map(|it| it.to_generic_args().generic_args());
+            let ty_args = generic_params
+                .as_ref()
+                .map(|it| it.to_generic_args().generic_args());
             let new_ty = if let Some(ty_args) = ty_args {
                 make::generic_ty_path_segment(make::name_ref("Type"), ty_args)
             } else {
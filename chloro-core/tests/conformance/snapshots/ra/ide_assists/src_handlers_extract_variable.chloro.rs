@@ -85,7 +85,10 @@ pub(crate) fn extract_variable(acc: &mut Assists, ctx: &AssistContext<'_>) -> Op
         }
     };
 
-    let node = node.ancestors().take_while(|anc| anc.text_range() == node.text_range()).last()?;
+    let node = node
+        .ancestors()
+        .take_while(|anc| anc.text_range() == node.text_range())
+        .last()?;
     let range = node.text_range();
 
     let to_extract = node
@@ -284,7 +287,9 @@ fn valid_target_expr(node: SyntaxNode) -> Option<ast::Expr> {
         SyntaxKind::BREAK_EXPR => ast::BreakExpr::cast(node).and_then(|e| e.expr()),
         SyntaxKind::RETURN_EXPR => ast::ReturnExpr::cast(node).and_then(|e| e.expr()),
         SyntaxKind::BLOCK_EXPR => {
-            ast::BlockExpr::cast(node).filter(|it| it.is_standalone()).map(ast::Expr::from)
+            ast::BlockExpr::cast(node)
+                .filter(|it| it.is_standalone())
+                .map(ast::Expr::from)
         }
         _ => ast::Expr::cast(node),
     }
@@ -75,11 +75,7 @@ fn add_vis_to_referenced_module_def(acc: &mut Assists, ctx: &AssistContext<'_>)
         }
     };
 
-    acc.add(
-        AssistId::quick_fix("fix_visibility"),
-        assist_label,
-        target,
-        |edit| {
+    acc.add(AssistId::quick_fix("fix_visibility"), assist_label, target, |edit| {
         edit.edit_file(target_file);
 
         let vis_owner = edit.make_mut(vis_owner);
@@ -88,8 +84,7 @@ fn add_vis_to_referenced_module_def(acc: &mut Assists, ctx: &AssistContext<'_>)
         if let Some((cap, vis)) = ctx.config.snippet_cap.zip(vis_owner.visibility()) {
             edit.add_tabstop_before(cap, vis);
         }
-    },
-    )
+    })
 }
 
 fn target_data_for_def(
@@ -108,11 +103,9 @@ fn target_data_for_def(
         let in_file_syntax = source.syntax();
         let file_id = in_file_syntax.file_id;
         let range = in_file_syntax.value.text_range();
-        Some((
-            ast::AnyHasVisibility::new(source.value),
-            range,
-            file_id.original_file(db).file_id(db),
-        ))
+        Some(
+            (ast::AnyHasVisibility::new(source.value), range, file_id.original_file(db).file_id(db)),
+        )
     }
 
     let target_name;
@@ -33,7 +33,9 @@ pub(crate) fn flip_binexpr(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
 
     let op_token = expr.op_token()?;
     // The assist should be applied only if the cursor is on the operator
-    let cursor_in_range = op_token.text_range().contains_range(ctx.selection_trimmed());
+    let cursor_in_range = op_token
+        .text_range()
+        .contains_range(ctx.selection_trimmed());
     if !cursor_in_range {
         return None;
     }
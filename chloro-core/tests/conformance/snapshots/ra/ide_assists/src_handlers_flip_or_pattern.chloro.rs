@@ -31,17 +31,12 @@ pub(crate) fn flip_or_pattern(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
     let after = non_trivia_sibling(pipe.clone().into(), Direction::Next)?.into_node()?;
 
     let target = pipe.text_range();
-    acc.add(
-        AssistId::refactor_rewrite("flip_or_pattern"),
-        "Flip patterns",
-        target,
-        |builder| {
+    acc.add(AssistId::refactor_rewrite("flip_or_pattern"), "Flip patterns", target, |builder| {
         let mut editor = builder.make_editor(parent.syntax());
         editor.replace(before.clone(), after.clone());
         editor.replace(after, before);
         builder.add_file_edits(ctx.vfs_file_id(), editor);
-    },
-    )
+    })
 }
 
 #[cfg(test)]
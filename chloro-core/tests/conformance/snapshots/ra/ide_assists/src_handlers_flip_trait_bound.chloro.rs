@@ -28,17 +28,12 @@ pub(crate) fn flip_trait_bound(acc: &mut Assists, ctx: &AssistContext<'_>) -> Op
     let after = non_trivia_sibling(plus.clone().into(), Direction::Next)?.into_node()?;
 
     let target = plus.text_range();
-    acc.add(
-        AssistId::refactor_rewrite("flip_trait_bound"),
-        "Flip trait bounds",
-        target,
-        |builder| {
-            let mut editor = builder.make_editor(parent.syntax());
-            editor.replace(before.clone(), after.clone());
-            editor.replace(after, before);
-            builder.add_file_edits(ctx.vfs_file_id(), editor);
-        },
-    )
+    acc.add(AssistId::refactor_rewrite("flip_trait_bound"), "Flip trait bounds", target, |builder| {
+        let mut editor = builder.make_editor(parent.syntax());
+        editor.replace(before.clone(), after.clone());
+        editor.replace(after, before);
+        builder.add_file_edits(ctx.vfs_file_id(), editor);
+    })
 }
 
 #[cfg(test)]
@@ -177,14 +177,17 @@ fn super_traits_has_sized(
 }
 
 fn contained_owned_self_method(item_list: Option<ast::AssocItemList>) -> bool {
-    item_list.into_iter().flat_map(|assoc_item_list| assoc_item_list.assoc_items()).any(|item| {
-        match item {
-            AssocItem::Fn(f) => {
-                has_owned_self(&f) && where_clause_sized(f.where_clause()).is_none()
+    item_list
+        .into_iter()
+        .flat_map(|assoc_item_list| assoc_item_list.assoc_items())
+        .any(|item| {
+            match item {
+                AssocItem::Fn(f) => {
+                    has_owned_self(&f) && where_clause_sized(f.where_clause()).is_none()
+                }
+                _ => false,
             }
-            _ => false,
-        }
-    })
+        })
 }
 
 fn has_owned_self(f: &ast::Fn) -> bool {
@@ -250,15 +253,20 @@ fn this_name(traitd: &ast::Trait) -> ast::Name {
     let mut name_gen =
         suggest_name::NameGenerator::new_with_names(params.iter().map(String::as_str));
 
-    make::name(&name_gen.suggest_name(if has_iter {
+    make::name(
+        &name_gen.suggest_name(if has_iter {
             "I"
         } else {
             "T"
-        }))
+        }),
+    )
 }
 
 fn find_bound(s: &str, bounds: Option<ast::TypeBoundList>) -> Option<ast::TypeBound> {
-    bounds.into_iter().flat_map(|bounds| bounds.bounds()).find(|bound| ty_bound_is(bound, s))
+    bounds
+        .into_iter()
+        .flat_map(|bounds| bounds.bounds())
+        .find(|bound| ty_bound_is(bound, s))
 }
 
 fn ty_bound_is(bound: &ast::TypeBound, s: &str) -> bool {
@@ -270,7 +278,9 @@ fn ty_bound_is(bound: &ast::TypeBound, s: &str) -> bool {
 }
 
 fn todo_fn(f: &ast::Fn, config: &AssistConfig) -> ast::Fn {
-    let params = f.param_list().unwrap_or_else(|| make::param_list(None, None));
+    let params = f
+        .param_list()
+        .unwrap_or_else(|| make::param_list(None, None));
     make::fn_(
         cfg_attrs(f),
         f.visibility(),
@@ -297,7 +307,8 @@ fn default_block(config: &AssistConfig) -> BlockExpr {
 }
 
 fn cfg_attrs(node: &impl HasAttrs) -> impl Iterator<Item = ast::Attr> {
-    node.attrs().filter(|attr| attr.as_simple_call().is_some_and(|(name, _arg)| name == "cfg"))
+    node.attrs()
+        .filter(|attr| attr.as_simple_call().is_some_and(|(name, _arg)| name == "cfg"))
 }
 
 #[cfg(test)]
@@ -41,7 +41,10 @@ pub(crate) fn generate_constant(acc: &mut Assists, ctx: &AssistContext<'_>) -> O
         cov_mark::hit!(already_defined);
         return None;
     }
-    let expr = constant_token.syntax().ancestors().find_map(ast::Expr::cast)?;
+    let expr = constant_token
+        .syntax()
+        .ancestors()
+        .find_map(ast::Expr::cast)?;
     let statement = expr.syntax().ancestors().find_map(ast::Stmt::cast)?;
     let ty = ctx.sema.type_of_expr(&expr)?;
     let scope = ctx.sema.scope(statement.syntax())?;
@@ -49,7 +52,10 @@ pub(crate) fn generate_constant(acc: &mut Assists, ctx: &AssistContext<'_>) -> O
     let type_name =
         ty.original().display_source_code(ctx.db(), constant_module.into(), false).ok()?;
     let target = statement.syntax().parent()?.text_range();
-    let path = constant_token.syntax().ancestors().find_map(ast::Path::cast)?;
+    let path = constant_token
+        .syntax()
+        .ancestors()
+        .find_map(ast::Path::cast)?;
     if path.parent_path().is_some() {
         cov_mark::hit!(not_last_path_segment);
         return None;
@@ -87,17 +93,12 @@ pub(crate) fn generate_constant(acc: &mut Assists, ctx: &AssistContext<'_>) -> O
         );
 
     let text = get_text_for_generate_constant(not_exist_name_ref, indent, outer_exists, type_name)?;
-    acc.add(
-        AssistId::quick_fix("generate_constant"),
-        "Generate constant",
-        target,
-        |builder| {
+    acc.add(AssistId::quick_fix("generate_constant"), "Generate constant", target, |builder| {
         if let Some(file_id) = file_id {
             builder.edit_file(file_id);
         }
         builder.insert(offset, format!("{text}{post_string}"));
-    },
-    )
+    })
 }
 
 fn get_text_for_generate_constant(
@@ -151,7 +151,13 @@ impl Field {
             }
         }
 
-        Some(Field { name, ty, range, impls, edition })
+        Some(Field {
+            name,
+            ty,
+            range,
+            impls,
+            edition,
+        })
     }
 }
 
@@ -353,21 +359,24 @@ fn generate_impl(
 
             // 2.3) Instantiate generics with `transform_impl`, this step also
             // remove unused params.
-            let trait_gen_args = old_impl.trait_()?.generic_arg_list().and_then(|trait_args| {
-                let trait_args = &mut trait_args.clone_for_update();
-                if let Some(new_args) = transform_impl(
-                    ctx,
-                    ast_strukt,
-                    &old_impl,
-                    &transform_args,
-                    trait_args.clone_subtree(),
-                ) {
-                    *trait_args = new_args.clone_subtree();
-                    Some(new_args)
-                } else {
-                    None
-                }
-            });
+            let trait_gen_args = old_impl
+                .trait_()?
+                .generic_arg_list()
+                .and_then(|trait_args| {
+                    let trait_args = &mut trait_args.clone_for_update();
+                    if let Some(new_args) = transform_impl(
+                        ctx,
+                        ast_strukt,
+                        &old_impl,
+                        &transform_args,
+                        trait_args.clone_subtree(),
+                    ) {
+                        *trait_args = new_args.clone_subtree();
+                        Some(new_args)
+                    } else {
+                        None
+                    }
+                });
 
             let type_gen_args = strukt_params.clone().map(|params| params.to_generic_args());
             let path_type =
@@ -487,7 +496,9 @@ fn remove_useless_where_clauses(trait_ty: &ast::Type, self_ty: &ast::Type, wc: a
             .any(|e| e.kind() == SyntaxKind::IDENT && live_generics.contains(&e.to_string()))
             .not()
     };
-    wc.predicates().filter(has_live_generics).for_each(|pred| wc.remove_predicate(pred));
+    wc.predicates()
+        .filter(has_live_generics)
+        .for_each(|pred| wc.remove_predicate(pred));
 
     if wc.predicates().count() == 0 {
         // Remove useless whitespaces
@@ -540,16 +551,15 @@ fn generate_args_for_impl(
 
     let args = old_impl_args
         .map(|old_arg| {
-            arg_substs.get(&old_arg.to_string()).map_or_else(
-                || old_arg.clone(),
-                |replace_with| {
+            arg_substs
+                .get(&old_arg.to_string())
+                .map_or_else(|| old_arg.clone(), |replace_with| {
                     // The old_arg will be replaced, so it becomes redundant
                     if trait_params.is_some() && old_trait_args.contains(&old_arg.to_string()) {
                         trait_params.as_ref().unwrap().remove_generic_arg(&old_arg)
                     }
                     replace_with.clone()
-                },
-            )
+                })
         })
         .collect_vec();
     args.is_empty().not().then(|| make::generic_arg_list(args))
@@ -343,7 +343,11 @@ fn self_name(ast_func: &ast::Fn) -> Option<String> {
 
 /// Helper function to get the name of the type of `self`
 fn self_type(ast_func: &ast::Fn) -> Option<ast::Type> {
-    ast_func.syntax().ancestors().find_map(ast::Impl::cast).and_then(|i| i.self_ty())
+    ast_func
+        .syntax()
+        .ancestors()
+        .find_map(ast::Impl::cast)
+        .and_then(|i| i.self_ty())
 }
 
 /// Output the real name of `Self` like `MyType<T>`, without the lifetimes.
@@ -353,11 +357,14 @@ fn self_type_without_lifetimes(ast_func: &ast::Fn) -> Option<String> {
         _ => return None,
     };
     let mut name = path_segment.name_ref()?.to_string();
-    let generics = path_segment.generic_arg_list().into_iter().flat_map(|list| {
-        list.generic_args()
-            .filter(|generic| matches!(generic, ast::GenericArg::TypeArg(_)))
-            .map(|generic| generic.to_string())
-    });
+    let generics = path_segment
+        .generic_arg_list()
+        .into_iter()
+        .flat_map(|list| {
+            list.generic_args()
+                .filter(|generic| matches!(generic, ast::GenericArg::TypeArg(_)))
+                .map(|generic| generic.to_string())
+        });
     let generics: String = generics.format(", ").to_string();
     if !generics.is_empty() {
         name.push('<');
@@ -465,7 +472,11 @@ fn function_call(
 
 /// Helper function to count the parameters including `self`
 fn count_parameters(param_list: &ast::ParamList) -> usize {
-    param_list.params().count() + if param_list.self_param().is_some() { 1 } else { 0 }
+    param_list.params().count() + if param_list.self_param().is_some() {
+        1
+    } else {
+        0
+    }
 }
 
 /// Helper function to transform lines of documentation into a Rust code documentation
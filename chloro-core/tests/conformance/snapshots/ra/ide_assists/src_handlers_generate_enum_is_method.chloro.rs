@@ -63,7 +63,9 @@ pub(crate) fn generate_enum_is_method(acc: &mut Assists, ctx: &AssistContext<'_>
         "Generate an `is_` method for this enum variant",
         target,
         |builder| {
-            let vis = parent_enum.visibility().map_or(String::new(), |v| format!("{v} "));
+            let vis = parent_enum
+                .visibility()
+                .map_or(String::new(), |v| format!("{v} "));
             let method = format!(
                 "    /// Returns `true` if the {enum_lowercase_name} is [`{variant_name}`].
     ///
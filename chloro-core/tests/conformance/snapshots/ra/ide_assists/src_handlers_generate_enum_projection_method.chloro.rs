@@ -48,13 +48,13 @@ pub(crate) fn generate_enum_try_into_method(
         "generate_enum_try_into_method",
         "Generate a `try_into_` method for this enum variant",
         ProjectionProps {
-        fn_name_prefix: "try_into",
-        self_param: "self",
-        return_prefix: "Result<",
-        return_suffix: ", Self>",
-        happy_case: "Ok",
-        sad_case: "Err(self)",
-    },
+            fn_name_prefix: "try_into",
+            self_param: "self",
+            return_prefix: "Result<",
+            return_suffix: ", Self>",
+            happy_case: "Ok",
+            sad_case: "Err(self)",
+        },
     )
 }
 
@@ -92,13 +92,13 @@ pub(crate) fn generate_enum_as_method(acc: &mut Assists, ctx: &AssistContext<'_>
         "generate_enum_as_method",
         "Generate an `as_` method for this enum variant",
         ProjectionProps {
-        fn_name_prefix: "as",
-        self_param: "&self",
-        return_prefix: "Option<&",
-        return_suffix: ">",
-        happy_case: "Some",
-        sad_case: "None",
-    },
+            fn_name_prefix: "as",
+            self_param: "&self",
+            return_prefix: "Option<&",
+            return_suffix: ">",
+            happy_case: "Some",
+            sad_case: "None",
+        },
     )
 }
 
@@ -159,7 +159,9 @@ fn generate_enum_projection_method(
         assist_description,
         target,
         |builder| {
-            let vis = parent_enum.visibility().map_or(String::new(), |v| format!("{v} "));
+            let vis = parent_enum
+                .visibility()
+                .map_or(String::new(), |v| format!("{v} "));
 
             let field_type_syntax = field_type.syntax();
 
@@ -58,11 +58,7 @@ pub(crate) fn generate_enum_variant(acc: &mut Assists, ctx: &AssistContext<'_>)
     let db = ctx.db();
     let InRealFile { file_id, value: enum_node } = e.source(db)?.original_ast_node_rooted(db)?;
 
-    acc.add(
-        AssistId::generate("generate_enum_variant"),
-        "Generate variant",
-        target,
-        |builder| {
+    acc.add(AssistId::generate("generate_enum_variant"), "Generate variant", target, |builder| {
         let mut editor = builder.make_editor(enum_node.syntax());
         let make = SyntaxFactory::with_mappings();
         let field_list = parent.make_field_list(ctx, &make);
@@ -71,8 +67,7 @@ pub(crate) fn generate_enum_variant(acc: &mut Assists, ctx: &AssistContext<'_>)
             it.add_variant(&mut editor, &variant);
         }
         builder.add_file_edits(file_id.file_id(ctx.db()), editor);
-    },
-    )
+    })
 }
 
 #[derive(Debug)]
@@ -167,7 +162,9 @@ fn expr_ty(
     scope: &hir::SemanticsScope<'_>,
 ) -> Option<ast::Type> {
     let ty = ctx.sema.type_of_expr(&arg).map(|it| it.adjusted())?;
-    let text = ty.display_source_code(ctx.db(), scope.module().into(), false).ok()?;
+    let text = ty
+        .display_source_code(ctx.db(), scope.module().into(), false)
+        .ok()?;
     Some(make.ty(&text))
 }
 
@@ -486,10 +483,13 @@ pub enum Foo {
     }
     #[test]
     fn not_applicable_for_path_type() {
-        check_assist_not_applicable(generate_enum_variant, r"
+        check_assist_not_applicable(
+            generate_enum_variant,
+            r"
 enum Foo {}
 impl Foo::Bar$0 {}
-")
+",
+        )
     }
     #[test]
     fn path_pat() {
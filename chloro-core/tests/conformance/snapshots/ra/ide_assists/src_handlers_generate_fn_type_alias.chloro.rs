@@ -42,7 +42,10 @@ pub(crate) fn generate_fn_type_alias(acc: &mut Assists, ctx: &AssistContext<'_>)
     let func_node = ast::Fn::cast(func.clone())?;
     let param_list = func_node.param_list()?;
 
-    let assoc_owner = func.ancestors().nth(2).and_then(Either::<ast::Trait, ast::Impl>::cast);
+    let assoc_owner = func
+        .ancestors()
+        .nth(2)
+        .and_then(Either::<ast::Trait, ast::Impl>::cast);
     // This is where we'll insert the type alias, since type aliases in `impl`s or `trait`s are not supported
     let insertion_node = assoc_owner
         .as_ref()
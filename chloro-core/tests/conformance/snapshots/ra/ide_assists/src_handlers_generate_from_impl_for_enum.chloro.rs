@@ -94,7 +94,9 @@ fn existing_from_impl(
     let field_ty = variant.fields(sema.db).first()?.ty(sema.db);
     let enum_ty = enum_.ty(sema.db);
     tracing::debug!(?enum_, ?field_ty, ?enum_ty);
-    enum_ty.impls_trait(infcx, from_trait, &[field_ty]).then_some(())
+    enum_ty
+        .impls_trait(infcx, from_trait, &[field_ty])
+        .then_some(())
 }
 
 #[cfg(test)]
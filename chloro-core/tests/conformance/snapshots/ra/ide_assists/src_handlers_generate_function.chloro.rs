@@ -97,7 +97,12 @@ impl TargetInfo {
         target: GeneratedFunctionTarget,
         file: FileId,
     ) -> Self {
-        Self { target_module, adt_info, target, file }
+        Self {
+            target_module,
+            adt_info,
+            target,
+            file,
+        }
     }
 }
 
@@ -139,7 +144,11 @@ fn gen_method(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     }
 
     let fn_name = call.name_ref()?;
-    let receiver_ty = ctx.sema.type_of_expr(&call.receiver()?)?.original().strip_references();
+    let receiver_ty = ctx
+        .sema
+        .type_of_expr(&call.receiver()?)?
+        .original()
+        .strip_references();
     let adt = receiver_ty.as_adt()?;
 
     let target_module = adt.module(ctx.sema.db);
@@ -173,20 +182,14 @@ fn add_func_to_accumulator(
     adt_info: Option<AdtInfo>,
     label: String,
 ) -> Option<()> {
-    acc.add(
-        AssistId::generate("generate_function"),
-        label,
-        text_range,
-        |edit| {
+    acc.add(AssistId::generate("generate_function"), label, text_range, |edit| {
         edit.edit_file(file);
 
         let target = function_builder.target.clone();
         let edition = function_builder.target_edition;
         let func = function_builder.render(ctx.config.snippet_cap, edit);
 
-        if let Some(adt) = adt_info
-            .and_then(|adt_info| if adt_info.impl_exists { None } else { Some(adt_info.adt) })
-        {
+        if let Some(adt) = adt_info.and_then(|adt_info| if adt_info.impl_exists { None } else { Some(adt_info.adt) }) {
             let name = make::ty_path(make::ext::ident_path(&format!(
                 "{}",
                 adt.name(ctx.db()).display(ctx.db(), edition)
@@ -201,8 +204,7 @@ fn add_func_to_accumulator(
         } else {
             target.insert_fn_at(edit, func);
         }
-    },
-    )
+    })
 }
 
 fn get_adt_source(
@@ -210,15 +212,16 @@ fn get_adt_source(
     adt: &hir::Adt,
     fn_name: &str,
 ) -> Option<(Option<ast::Impl>, FileId)> {
-    let range = adt.source(ctx.sema.db)?.syntax().original_file_range_rooted(ctx.sema.db);
+    let range = adt
+        .source(ctx.sema.db)?
+        .syntax()
+        .original_file_range_rooted(ctx.sema.db);
 
     let file = ctx.sema.parse(range.file_id);
     let adt_source =
         ctx.sema.find_node_at_offset_with_macros(file.syntax(), range.range.start())?;
     find_struct_impl(ctx, &adt_source, &[fn_name.to_owned()])
-    .map(
-        |impl_| (impl_, range.file_id.file_id(ctx.db())),
-    )
+        .map(|impl_| (impl_, range.file_id.file_id(ctx.db())))
 }
 
 struct FunctionBuilder {
@@ -706,9 +709,9 @@ fn fn_args(
 
     Some(make::param_list(
         match call {
-        ast::CallableExpr::Call(_) => None,
-        ast::CallableExpr::MethodCall(_) => Some(make::self_param()),
-    },
+            ast::CallableExpr::Call(_) => None,
+            ast::CallableExpr::MethodCall(_) => Some(make::self_param()),
+        },
         params,
     ))
 }
@@ -848,9 +851,19 @@ where
 {
     // This function should be only called with `Impl`, `Trait`, or `Function`, for which it's
     // infallible to get source ast.
-    let node = ctx.sema.source(def).expect("definition's source couldn't be found").value;
-    let generic_params = node.generic_param_list().into_iter().flat_map(|it| it.generic_params());
-    let where_clauses = node.where_clause().into_iter().flat_map(|it| it.predicates());
+    let node = ctx
+        .sema
+        .source(def)
+        .expect("definition's source couldn't be found")
+        .value;
+    let generic_params = node
+        .generic_param_list()
+        .into_iter()
+        .flat_map(|it| it.generic_params());
+    let where_clauses = node
+        .where_clause()
+        .into_iter()
+        .flat_map(|it| it.predicates());
     (generic_params, where_clauses)
 }
 
@@ -920,11 +933,19 @@ fn compute_contained_params_in_generic_param(
                 .filter_map(|node| filter_generic_params(ctx, node))
                 .collect();
 
-            Some(ParamBoundWithParams { node, self_ty_param, other_params })
+            Some(ParamBoundWithParams {
+                node,
+                self_ty_param,
+                other_params,
+            })
         }
         ast::GenericParam::ConstParam(ct) => {
             let self_ty_param = ctx.sema.to_def(ct)?.into();
-            Some(ParamBoundWithParams { node, self_ty_param, other_params: FxHashSet::default() })
+            Some(ParamBoundWithParams {
+                node,
+                self_ty_param,
+                other_params: FxHashSet::default(),
+            })
         }
         ast::GenericParam::LifetimeParam(_) => {
             // FIXME: It might be a good idea to handle lifetime parameters too.
@@ -952,7 +973,11 @@ fn compute_contained_params_in_where_pred(
         .filter_map(|node| filter_generic_params(ctx, node))
         .collect();
 
-    Some(WherePredWithParams { node, self_ty_params, other_params })
+    Some(WherePredWithParams {
+        node,
+        self_ty_params,
+        other_params,
+    })
 }
 
 fn filter_generic_params(ctx: &AssistContext<'_>, node: SyntaxNode) -> Option<hir::GenericParam> {
@@ -1029,7 +1054,9 @@ fn filter_unnecessary_bounds(
         }
     }
 
-    let starting_nodes = necessary_params.iter().flat_map(|param| param_map.get(param).copied());
+    let starting_nodes = necessary_params
+        .iter()
+        .flat_map(|param| param_map.get(param).copied());
     let reachable = graph.compute_reachable_nodes(starting_nodes);
 
     // Not pretty, but effective. If only there were `Vec::retain_index()`...
@@ -1161,14 +1188,13 @@ fn fn_arg_type(
                 })
                 .or_else(|| ty.display_source_code(ctx.db(), target_module.into(), true).ok())
         } else {
-            ty.display_source_code(ctx.db(), target_module.into(), true).ok()
+            ty.display_source_code(ctx.db(), target_module.into(), true)
+                .ok()
         }
     }
 
     maybe_displayed_type(ctx, target_module, fn_arg, generic_params)
-    .unwrap_or_else(
-        || String::from("_"),
-    )
+        .unwrap_or_else(|| String::from("_"))
 }
 
 /// Returns the position inside the current mod or file
@@ -1253,6 +1279,8 @@ fn calculate_necessary_visibility(
     }
 }
 
+// This is never intended to be used as a generic graph structure. If there's ever another need of
+// graph algorithm, consider adding a library for that (and replace the following).
 /// Minimally implemented directed graph structure represented by adjacency list.
 struct Graph {
     edges: Vec<Vec<usize>>,
@@ -1260,7 +1288,9 @@ struct Graph {
 
 impl Graph {
     fn new(node_count: usize) -> Self {
-        Self { edges: vec![Vec::new(); node_count] }
+        Self {
+            edges: vec![Vec::new(); node_count],
+        }
     }
 
     fn add_edge(&mut self, from: usize, to: usize) {
@@ -1297,7 +1327,11 @@ struct Visitor<'g> {
 impl<'g> Visitor<'g> {
     fn new(graph: &'g Graph) -> Self {
         let visited = vec![false; graph.len()];
-        Self { graph, visited, stack: Vec::new() }
+        Self {
+            graph,
+            visited,
+            stack: Vec::new(),
+        }
     }
 
     fn mark_reachable(&mut self, start_idx: usize) {
@@ -2329,17 +2363,23 @@ fn foo() -> u32 {
     }
     #[test]
     fn add_function_not_applicable_if_function_already_exists() {
-        check_assist_not_applicable(generate_function, r"
+        check_assist_not_applicable(
+            generate_function,
+            r"
 fn foo() {
     bar$0();
 }
 
 fn bar() {}
-")
+",
+        )
     }
     #[test]
     fn add_function_not_applicable_if_unresolved_variable_in_call_is_selected() {
         check_assist_not_applicable(
+            // bar is resolved, but baz isn't.
+            // The assist is only active if the cursor is on an unresolved path,
+            // but the assist should only be offered if the path is a function call.
             generate_function,
             r#"
 fn foo() {
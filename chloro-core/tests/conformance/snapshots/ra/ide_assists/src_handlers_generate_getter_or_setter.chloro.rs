@@ -47,7 +47,9 @@ pub(crate) fn generate_setter(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
     }
 
     // Prepend set_ to fn names.
-    fn_names.iter_mut().for_each(|name| *name = format!("set_{name}"));
+    fn_names
+        .iter_mut()
+        .for_each(|name| *name = format!("set_{name}"));
 
     // Return early if we've found an existing fn
     let impl_def = find_struct_impl(ctx, &ast::Adt::Struct(strukt.clone()), &fn_names)?;
@@ -393,7 +395,12 @@ fn parse_record_field(
 
     let target = record_field.syntax().text_range();
 
-    Some(RecordFieldInfo { field_name, field_ty, fn_name, target })
+    Some(RecordFieldInfo {
+        field_name,
+        field_ty,
+        fn_name,
+        target,
+    })
 }
 
 fn build_source_change(
@@ -84,11 +84,17 @@ pub(crate) fn generate_mut_trait_impl(acc: &mut Assists, ctx: &AssistContext<'_>
     ted::replace(self_param.syntax(), mut_self_param.clone_for_update().syntax());
 
     // &Self::Output -> &mut Self::Output
-    let ret_type = impl_def.syntax().descendants().find_map(ast::RetType::cast)?;
+    let ret_type = impl_def
+        .syntax()
+        .descendants()
+        .find_map(ast::RetType::cast)?;
     let new_ret_type = process_ret_type(&ret_type)?;
     ted::replace(ret_type.syntax(), make::ret_type(new_ret_type).clone_for_update().syntax());
 
-    let fn_ = impl_def.assoc_item_list()?.assoc_items().find_map(|it| match it {
+    let fn_ = impl_def
+        .assoc_item_list()?
+        .assoc_items()
+        .find_map(|it| match it {
         ast::AssocItem::Fn(f) => Some(f),
         _ => None,
     })?;
@@ -96,7 +102,9 @@ pub(crate) fn generate_mut_trait_impl(acc: &mut Assists, ctx: &AssistContext<'_>
 
     let assoc_list = make::assoc_item_list(None).clone_for_update();
     ted::replace(impl_def.assoc_item_list()?.syntax(), assoc_list.syntax());
-    impl_def.get_or_create_assoc_item_list().add_item(syntax::ast::AssocItem::Fn(fn_));
+    impl_def
+        .get_or_create_assoc_item_list()
+        .add_item(syntax::ast::AssocItem::Fn(fn_));
 
     let target = impl_def.syntax().text_range();
     acc.add(
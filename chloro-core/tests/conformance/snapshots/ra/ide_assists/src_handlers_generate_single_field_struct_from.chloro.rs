@@ -81,7 +81,9 @@ pub(crate) fn generate_single_field_struct_from(
             let indent = strukt.indent_level();
             let ty_where_clause = strukt.where_clause();
             let type_gen_params = strukt.generic_param_list();
-            let type_gen_args = type_gen_params.as_ref().map(|params| params.to_generic_args());
+            let type_gen_args = type_gen_params
+                .as_ref()
+                .map(|params| params.to_generic_args());
             let trait_gen_args = Some(make::generic_arg_list([ast::GenericArg::TypeArg(
                 make::type_arg(main_field_ty.clone()),
             )]));
@@ -178,7 +180,9 @@ fn make_constructors(
     types: &[ast::Type],
 ) -> Vec<Option<ast::Expr>> {
     let (db, sema) = (ctx.db(), &ctx.sema);
-    let cfg = ctx.config.find_path_config(ctx.sema.is_nightly(module.krate()));
+    let cfg = ctx
+        .config
+        .find_path_config(ctx.sema.is_nightly(module.krate()));
     types
         .iter()
         .map(|ty| {
@@ -200,12 +204,24 @@ fn get_fields(strukt: &ast::Struct) -> Option<(Option<Vec<ast::Name>>, Vec<ast::
     Some(match strukt.kind() {
         ast::StructKind::Unit => return None,
         ast::StructKind::Record(fields) => {
-            let names = fields.fields().map(|field| field.name()).collect::<Option<_>>()?;
-            let types = fields.fields().map(|field| field.ty()).collect::<Option<_>>()?;
+            let names = fields
+                .fields()
+                .map(|field| field.name())
+                .collect::<Option<_>>()?;
+            let types = fields
+                .fields()
+                .map(|field| field.ty())
+                .collect::<Option<_>>()?;
             (Some(names), types)
         }
         ast::StructKind::Tuple(fields) => {
-            (None, fields.fields().map(|field| field.ty()).collect::<Option<_>>()?)
+            (
+                None,
+                fields
+                .fields()
+                .map(|field| field.ty())
+                .collect::<Option<_>>()?,
+            )
         }
     })
 }
@@ -228,7 +244,9 @@ fn from_impl_exists(
     let field_ty = strukt.fields(db).get(main_field_i)?.ty(db);
     let struct_ty = strukt.ty(db);
     tracing::debug!(?strukt, ?field_ty, ?struct_ty);
-    struct_ty.impls_trait(infcx, from_trait, &[field_ty]).then_some(())
+    struct_ty
+        .impls_trait(infcx, from_trait, &[field_ty])
+        .then_some(())
 }
 
 #[cfg(test)]
@@ -234,11 +234,7 @@ pub(crate) fn inline_call(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
     }
 
     let syntax = call_info.node.syntax().clone();
-    acc.add(
-        AssistId::refactor_inline("inline_call"),
-        label,
-        syntax.text_range(),
-        |builder| {
+    acc.add(AssistId::refactor_inline("inline_call"), label, syntax.text_range(), |builder| {
         let replacement = inline(&ctx.sema, file_id, function, &fn_body, &params, &call_info);
         builder.replace_ast(
             match call_info.node {
@@ -247,8 +243,7 @@ pub(crate) fn inline_call(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
             },
             replacement,
         );
-    },
-    )
+    })
 }
 
 struct CallInfo {
@@ -579,13 +574,14 @@ fn inline(
             make::expr_paren(expr).clone_for_update().into()
         }
         Some(expr) if !is_async_fn && no_stmts => expr,
-        _ => match node
-            .syntax()
+        _ => match node.syntax()
             .parent()
             .and_then(ast::BinExpr::cast)
             .and_then(|bin_expr| bin_expr.lhs()) {
             Some(lhs) if lhs.syntax() == node.syntax() => {
-                make::expr_paren(ast::Expr::BlockExpr(body)).clone_for_update().into()
+                make::expr_paren(ast::Expr::BlockExpr(body))
+                    .clone_for_update()
+                    .into()
             }
             _ => ast::Expr::BlockExpr(body),
         },
@@ -68,7 +68,11 @@ fn validate_type_recursively(
             ty.as_reference().map(|(ty, _)| ty).as_ref(),
             true,
             // FIXME: Saving fuel when `&` repeating might not be a good idea if there's no TCO.
-            if refed { fuel } else { fuel - 1 },
+            if refed {
+                fuel
+            } else {
+                fuel - 1
+            },
         ),
         (true, Some(ty)) if ty.is_array() => validate_type_recursively(
             ctx,
@@ -76,8 +80,7 @@ fn validate_type_recursively(
             false,
             fuel - 1,
         ),
-        (true, Some(ty)) if ty.is_tuple() => ty
-            .tuple_fields(ctx.db())
+        (true, Some(ty)) if ty.is_tuple() => ty.tuple_fields(ctx.db())
             .iter()
             .all(|ty| validate_type_recursively(ctx, Some(ty), false, fuel - 1).is_some())
             .then_some(()),
@@ -84,25 +84,22 @@ fn generate_fn_def_assist(
             _ => return None,
         }
     };
-    acc.add(
-        AssistId::refactor(ASSIST_NAME),
-        ASSIST_LABEL,
-        lifetime_loc,
-        |builder| {
+    acc.add(AssistId::refactor(ASSIST_NAME), ASSIST_LABEL, lifetime_loc, |builder| {
         let fn_def = builder.make_mut(fn_def);
         let lifetime = builder.make_mut(lifetime);
         let loc_needing_lifetime =
             loc_needing_lifetime.and_then(|it| it.make_mut(builder).to_position());
 
         fn_def.get_or_create_generic_param_list().add_generic_param(
-            make::lifetime_param(new_lifetime_param.clone()).clone_for_update().into(),
+            make::lifetime_param(new_lifetime_param.clone())
+                .clone_for_update()
+                .into(),
         );
         ted::replace(lifetime.syntax(), new_lifetime_param.clone_for_update().syntax());
         if let Some(position) = loc_needing_lifetime {
             ted::insert(position, new_lifetime_param.clone_for_update().syntax());
         }
-    },
-    )
+    })
 }
 
 /// Generate the assist for the impl def case
@@ -113,20 +110,19 @@ fn generate_impl_def_assist(
     lifetime: ast::Lifetime,
 ) -> Option<()> {
     let new_lifetime_param = generate_unique_lifetime_param_name(impl_def.generic_param_list())?;
-    acc.add(
-        AssistId::refactor(ASSIST_NAME),
-        ASSIST_LABEL,
-        lifetime_loc,
-        |builder| {
+    acc.add(AssistId::refactor(ASSIST_NAME), ASSIST_LABEL, lifetime_loc, |builder| {
         let impl_def = builder.make_mut(impl_def);
         let lifetime = builder.make_mut(lifetime);
 
-        impl_def.get_or_create_generic_param_list().add_generic_param(
-            make::lifetime_param(new_lifetime_param.clone()).clone_for_update().into(),
-        );
+        impl_def
+            .get_or_create_generic_param_list()
+            .add_generic_param(
+                make::lifetime_param(new_lifetime_param.clone())
+                    .clone_for_update()
+                    .into(),
+            );
         ted::replace(lifetime.syntax(), new_lifetime_param.clone_for_update().syntax());
-    },
-    )
+    })
 }
 
 /// Given a type parameter list, generate a unique lifetime parameter name
@@ -138,13 +134,13 @@ fn generate_unique_lifetime_param_name(
         Some(type_params) => {
             let used_lifetime_params: FxHashSet<_> =
                 type_params.lifetime_params().map(|p| p.syntax().text().to_string()).collect();
-            ('a'..='z').map(|it| format!("'{it}")).find(|it| !used_lifetime_params.contains(it))
+            ('a'..='z')
+                .map(|it| format!("'{it}"))
+                .find(|it| !used_lifetime_params.contains(it))
         }
         None => Some("'a".to_owned()),
     }
-    .map(
-        |it| make::lifetime(&it),
-    )
+    .map(|it| make::lifetime(&it))
 }
 
 enum NeedsLifetime {
@@ -20,7 +20,10 @@ pub(crate) fn introduce_named_type_parameter(
     ctx: &AssistContext<'_>,
 ) -> Option<()> {
     let impl_trait_type = ctx.find_node_at_offset::<ast::ImplTraitType>()?;
-    let param = impl_trait_type.syntax().ancestors().find_map(ast::Param::cast)?;
+    let param = impl_trait_type
+        .syntax()
+        .ancestors()
+        .find_map(ast::Param::cast)?;
     let fn_ = param.syntax().ancestors().nth(2).and_then(ast::Fn::cast)?;
     let type_bound_list = impl_trait_type.type_bound_list()?;
 
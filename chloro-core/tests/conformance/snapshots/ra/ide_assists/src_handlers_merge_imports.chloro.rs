@@ -74,11 +74,7 @@ pub(crate) fn merge_imports(acc: &mut Assists, ctx: &AssistContext<'_>) -> Optio
         SyntaxElement::Token(t) => t.parent()?,
     };
 
-    acc.add(
-        AssistId::refactor_rewrite("merge_imports"),
-        "Merge imports",
-        target,
-        |builder| {
+    acc.add(AssistId::refactor_rewrite("merge_imports"), "Merge imports", target, |builder| {
         let make = SyntaxFactory::with_mappings();
         let mut editor = builder.make_editor(&parent_node);
 
@@ -99,8 +95,7 @@ pub(crate) fn merge_imports(acc: &mut Assists, ctx: &AssistContext<'_>) -> Optio
         }
         editor.add_mappings(make.finish_with_mappings());
         builder.add_file_edits(ctx.vfs_file_id(), editor);
-    },
-    )
+    })
 }
 
 trait Merge {
@@ -183,10 +183,7 @@ pub(crate) fn move_arm_cond_to_match_guard(
                 // arm is `_ => ...`
                 cov_mark::hit!(move_guard_ifelse_notail);
                 match match_arm.syntax().next_sibling().and_then(MatchArm::cast) {
-                    Some(next_arm)
-                        if matches!(next_arm.pat(), Some(Pat::WildcardPat(_)))
-                            && next_arm.guard().is_none() =>
-                    {
+                    Some(next_arm) if matches!(next_arm.pat(), Some(Pat::WildcardPat(_))) && next_arm.guard().is_none() => {
                         cov_mark::hit!(move_guard_ifelse_has_wildcard);
                     }
                     _ => edit.insert(then_arm_end, format!("\n{spaces}{match_pat} => {{}}")),
@@ -80,16 +80,18 @@ pub(crate) fn pull_assignment_up(acc: &mut Assists, ctx: &AssistContext<'_>) ->
         .assignments
         .into_iter()
         .filter_map(|(stmt, rhs)| {
-            Some((
-                find_node_at_range::<ast::BinExpr>(
+            Some(
+                (
+                    find_node_at_range::<ast::BinExpr>(
                     &edit_tgt,
                     stmt.syntax().text_range() - target.start(),
                 )?,
-                find_node_at_range::<ast::Expr>(
+                    find_node_at_range::<ast::Expr>(
                     &edit_tgt,
                     rhs.syntax().text_range() - target.start(),
                 )?,
-            ))
+                ),
+            )
         })
         .collect();
 
@@ -155,7 +157,9 @@ impl AssignmentsCollector<'_> {
     }
 
     fn collect_block(&mut self, block: &ast::BlockExpr) -> Option<()> {
-        let last_expr = block.tail_expr().or_else(|| match block.statements().last()? {
+        let last_expr = block
+            .tail_expr()
+            .or_else(|| match block.statements().last()? {
             ast::Stmt::ExprStmt(stmt) => stmt.expr(),
             ast::Stmt::Item(_) | ast::Stmt::LetStmt(_) => None,
         })?;
@@ -48,7 +48,10 @@ pub(crate) fn qualify_path(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
     }
 
     let range = ctx.sema.original_range(&syntax_under_caret).range;
-    let current_module = ctx.sema.scope(&syntax_under_caret).map(|scope| scope.module());
+    let current_module = ctx
+        .sema
+        .scope(&syntax_under_caret)
+        .map(|scope| scope.module());
 
     let candidate = import_assets.import_candidate();
     let qualify_candidate = match candidate {
@@ -132,11 +135,15 @@ impl QualifyCandidate<'_> {
         let import = mod_path_to_ast(import, edition);
         match self {
             QualifyCandidate::QualifierStart(segment, generics) => {
-                let generics = generics.as_ref().map_or_else(String::new, ToString::to_string);
+                let generics = generics
+                    .as_ref()
+                    .map_or_else(String::new, ToString::to_string);
                 replacer(format!("{import}{generics}::{segment}"));
             }
             QualifyCandidate::UnqualifiedName(generics) => {
-                let generics = generics.as_ref().map_or_else(String::new, ToString::to_string);
+                let generics = generics
+                    .as_ref()
+                    .map_or_else(String::new, ToString::to_string);
                 replacer(format!("{import}{generics}"));
             }
             QualifyCandidate::TraitAssocItem(qualifier, segment) => {
@@ -198,12 +205,11 @@ fn find_trait_method(
     trait_: hir::Trait,
     trait_method_name: &ast::NameRef,
 ) -> Option<hir::Function> {
-    if let Some(hir::AssocItem::Function(method)) =
-        trait_.items(db).into_iter().find(|item: &hir::AssocItem| {
-            item.name(db)
-                .map(|name| name.as_str() == trait_method_name.text().trim_start_matches("r#"))
-                .unwrap_or(false)
-        }) {
+    if let Some(hir::AssocItem::Function(method)) = trait_.items(db).into_iter().find(|item: &hir::AssocItem| {
+        item.name(db)
+            .map(|name| name.as_str() == trait_method_name.text().trim_start_matches("r#"))
+            .unwrap_or(false)
+    }) {
         Some(method)
     } else {
         None
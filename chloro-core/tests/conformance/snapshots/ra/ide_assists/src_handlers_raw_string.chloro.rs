@@ -31,18 +31,13 @@ pub(crate) fn make_raw_string(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
     }
     let value = token.value().ok()?;
     let target = token.syntax().text_range();
-    acc.add(
-        AssistId::refactor_rewrite("make_raw_string"),
-        "Rewrite as raw string",
-        target,
-        |edit| {
-            let hashes = "#".repeat(required_hashes(&value).max(1));
-            let raw_prefix = token.raw_prefix();
-            let suffix = string_suffix(token.text()).unwrap_or_default();
-            let new_str = format!("{raw_prefix}{hashes}\"{value}\"{hashes}{suffix}");
-            replace_literal(&token, &new_str, edit, ctx);
-        },
-    )
+    acc.add(AssistId::refactor_rewrite("make_raw_string"), "Rewrite as raw string", target, |edit| {
+        let hashes = "#".repeat(required_hashes(&value).max(1));
+        let raw_prefix = token.raw_prefix();
+        let suffix = string_suffix(token.text()).unwrap_or_default();
+        let new_str = format!("{raw_prefix}{hashes}\"{value}\"{hashes}{suffix}");
+        replace_literal(&token, &new_str, edit, ctx);
+    })
 }
 
 // Assist: make_usual_string
@@ -103,19 +98,14 @@ pub(crate) fn add_hash(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()>
         return None;
     }
     let target = token.syntax().text_range();
-    acc.add(
-        AssistId::refactor("add_hash"),
-        "Add #",
-        target,
-        |edit| {
+    acc.add(AssistId::refactor("add_hash"), "Add #", target, |edit| {
         let str = token.text();
         let suffix = string_suffix(str).unwrap_or_default();
         let raw_prefix = token.raw_prefix();
         let wrap_range = raw_prefix.len()..str.len() - suffix.len();
         let new_str = [raw_prefix, "#", &str[wrap_range], "#", suffix].concat();
         replace_literal(&token, &new_str, edit, ctx);
-    },
-    )
+    })
 }
 
 // Assist: remove_hash
@@ -152,18 +142,13 @@ pub(crate) fn remove_hash(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
         return None;
     }
 
-    acc.add(
-        AssistId::refactor_rewrite("remove_hash"),
-        "Remove #",
-        text_range,
-        |edit| {
+    acc.add(AssistId::refactor_rewrite("remove_hash"), "Remove #", text_range, |edit| {
         let suffix = string_suffix(text).unwrap_or_default();
         let prefix = token.raw_prefix();
         let wrap_range = prefix.len() + 1..text.len() - suffix.len() - 1;
         let new_str = [prefix, &text[wrap_range], suffix].concat();
         replace_literal(&token, &new_str, edit, ctx);
-    },
-    )
+    })
 }
 
 fn replace_literal(
@@ -21,11 +21,7 @@ pub(crate) fn remove_mut(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<(
     let mut_token = ctx.find_token_syntax_at_offset(T![mut])?;
 
     let target = mut_token.text_range();
-    acc.add(
-        AssistId::refactor("remove_mut"),
-        "Remove `mut` keyword",
-        target,
-        |builder| {
+    acc.add(AssistId::refactor("remove_mut"), "Remove `mut` keyword", target, |builder| {
         let mut editor = builder.make_editor(&mut_token.parent().unwrap());
         match mut_token.next_token() {
             Some(it) if it.kind() == SyntaxKind::WHITESPACE => editor.delete(it),
@@ -33,6 +29,5 @@ pub(crate) fn remove_mut(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<(
         }
         editor.delete(mut_token);
         builder.add_file_edits(ctx.vfs_file_id(), editor);
-    },
-    )
+    })
 }
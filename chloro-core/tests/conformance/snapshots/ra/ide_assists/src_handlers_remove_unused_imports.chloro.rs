@@ -58,7 +58,6 @@ pub(crate) fn remove_unused_imports(acc: &mut Assists, ctx: &AssistContext<'_>)
         .filter(|u| u.use_tree_list().is_none())
         .filter_map(|u| {
             // Find any uses trees that are unused
-
             let use_module = ctx.sema.scope(u.syntax()).map(|s| s.module())?;
             let scope = match search_scopes.entry(use_module) {
                 Entry::Occupied(o) => o.into_mut(),
@@ -101,8 +100,7 @@ pub(crate) fn remove_unused_imports(acc: &mut Assists, ctx: &AssistContext<'_>)
                         hir::ScopeDef::ModuleDef(d) => Some(Definition::from(*d)),
                         _ => None,
                     })
-                    .any(|d| used_once_in_scope(ctx, d, u.rename(), scope))
-                {
+                    .any(|d| used_once_in_scope(ctx, d, u.rename(), scope)) {
                     Some(u)
                 } else {
                     None
@@ -156,8 +154,7 @@ fn is_path_unused_in_scope(
     scope: &mut Vec<SearchScope>,
     path: &[Option<PathResolution>],
 ) -> bool {
-    !path
-        .iter()
+    !path.iter()
         .filter_map(|path| *path)
         .filter_map(|res| match res {
             PathResolution::Def(d) => Some(Definition::from(d)),
@@ -173,7 +170,11 @@ fn is_trait_unused_in_scope(
     t: &hir::Trait,
 ) -> bool {
     !std::iter::once((Definition::Trait(*t), u.rename()))
-        .chain(t.items(ctx.db()).into_iter().map(|item| (item.into(), None)))
+        .chain(
+            t.items(ctx.db())
+                .into_iter()
+                .map(|item| (item.into(), None)),
+        )
         .any(|(d, rename)| used_once_in_scope(ctx, d, rename, scope))
 }
 
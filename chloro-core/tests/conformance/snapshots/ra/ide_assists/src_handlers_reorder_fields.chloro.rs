@@ -65,7 +65,10 @@ pub(crate) fn reorder_fields(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opti
         cov_mark::hit!(reorder_sorted_fields);
         return None;
     }
-    let target = record.as_ref().either(AstNode::syntax, AstNode::syntax).text_range();
+    let target = record
+        .as_ref()
+        .either(AstNode::syntax, AstNode::syntax)
+        .text_range();
     acc.add(
         AssistId::refactor_rewrite("reorder_fields"),
         "Reorder record fields",
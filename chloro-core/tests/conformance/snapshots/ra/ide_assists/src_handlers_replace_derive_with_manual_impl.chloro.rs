@@ -71,7 +71,9 @@ pub(crate) fn replace_derive_with_manual_impl(
     let current_module = ctx.sema.scope(adt.syntax())?.module();
     let current_crate = current_module.krate();
     let current_edition = current_crate.edition(ctx.db());
-    let cfg = ctx.config.find_path_config(ctx.sema.is_nightly(current_crate));
+    let cfg = ctx
+        .config
+        .find_path_config(ctx.sema.is_nightly(current_crate));
 
     let found_traits = items_locator::items_with_name(
         ctx.db(),
@@ -126,11 +128,7 @@ fn add_assist(
     let annotated_name = adt.name()?;
     let label = format!("Convert to manual `impl {replace_trait_path} for {annotated_name}`");
 
-    acc.add(
-        AssistId::refactor("replace_derive_with_manual_impl"),
-        label,
-        target,
-        |builder| {
+    acc.add(AssistId::refactor("replace_derive_with_manual_impl"), label, target, |builder| {
         let insert_after = Position::after(adt.syntax());
         let impl_is_unsafe = trait_.map(|s| s.is_unsafe(ctx.db())).unwrap_or(false);
         let impl_def = impl_def_from_trait(
@@ -181,8 +179,7 @@ fn add_assist(
             vec![make::tokens::blank_line().into(), impl_def.syntax().clone().into()],
         );
         builder.add_file_edits(ctx.vfs_file_id(), editor);
-    },
-    )
+    })
 }
 
 fn impl_def_from_trait(
@@ -256,11 +253,14 @@ fn update_attribute(
 
     if has_more_derives {
         // Make the paths into flat lists of tokens in a vec
-        let tt = new_derives.iter().map(|path| path.syntax().clone()).map(|node| {
-            node.descendants_with_tokens()
-                .filter_map(|element| element.into_token())
-                .collect::<Vec<_>>()
-        });
+        let tt = new_derives
+            .iter()
+            .map(|path| path.syntax().clone())
+            .map(|node| {
+                node.descendants_with_tokens()
+                    .filter_map(|element| element.into_token())
+                    .collect::<Vec<_>>()
+            });
         // ...which are interspersed with ", "
         let tt = Itertools::intersperse(tt, vec![make::token(T![,]), make::tokens::single_space()]);
         // ...wrap them into the appropriate `NodeOrToken` variant
@@ -372,8 +372,7 @@ fn binds_name(sema: &hir::Semantics<'_, RootDatabase>, pat: &ast::Pat) -> bool {
         ast::Pat::SlicePat(pat) => pat.pats().any(binds_name_v),
         ast::Pat::TuplePat(it) => it.fields().any(binds_name_v),
         ast::Pat::TupleStructPat(it) => it.fields().any(binds_name_v),
-        ast::Pat::RecordPat(it) => it
-            .record_pat_field_list()
+        ast::Pat::RecordPat(it) => it.record_pat_field_list()
             .is_some_and(|rpfl| rpfl.fields().flat_map(|rpf| rpf.pat()).any(binds_name_v)),
         ast::Pat::RefPat(pat) => pat.pat().is_some_and(binds_name_v),
         ast::Pat::BoxPat(pat) => pat.pat().is_some_and(binds_name_v),
@@ -162,7 +162,10 @@ fn find_usages(
     file_id: EditionedFileId,
 ) -> UsageSearchResult {
     let file_range = FileRange { file_id, range: fn_.syntax().text_range() };
-    type_param_def.usages(sema).in_scope(&SearchScope::file_range(file_range)).all()
+    type_param_def
+        .usages(sema)
+        .in_scope(&SearchScope::file_range(file_range))
+        .all()
 }
 
 fn check_valid_usages(usages: &UsageSearchResult, param_list_range: TextRange) -> bool {
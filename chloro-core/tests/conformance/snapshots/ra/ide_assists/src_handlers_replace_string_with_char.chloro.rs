@@ -23,7 +23,9 @@ use crate::{AssistContext, AssistId, Assists, utils::string_suffix};
 // }
 // ```
 pub(crate) fn replace_string_with_char(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
-    let token = ctx.find_token_syntax_at_offset(STRING).and_then(ast::String::cast)?;
+    let token = ctx
+        .find_token_syntax_at_offset(STRING)
+        .and_then(ast::String::cast)?;
     let value = token.value().ok()?;
     let target = token.syntax().text_range();
 
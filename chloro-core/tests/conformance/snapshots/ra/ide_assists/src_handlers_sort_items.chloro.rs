@@ -125,11 +125,7 @@ impl AddRewrite for Assists {
         new: Vec<T>,
         target: &SyntaxNode,
     ) -> Option<()> {
-        self.add(
-            AssistId::refactor_rewrite("sort_items"),
-            label,
-            target.text_range(),
-            |builder| {
+        self.add(AssistId::refactor_rewrite("sort_items"), label, target.text_range(), |builder| {
             let mut editor = builder.make_editor(target);
 
             old.into_iter()
@@ -137,8 +133,7 @@ impl AddRewrite for Assists {
                 .for_each(|(old, new)| editor.replace(old.syntax(), new.syntax()));
 
             builder.add_file_edits(builder.file_id, editor)
-        },
-        )
+        })
     }
 }
 
@@ -299,44 +294,59 @@ trait Bar {
     fn not_applicable_if_trait_empty() {
         cov_mark::check!(not_applicable_if_sorted_or_empty_or_single);
 
-        check_assist_not_applicable(sort_items, r#"
+        check_assist_not_applicable(
+            sort_items,
+            r#"
 t$0rait Bar$0 {
 }
-        "#)
+        "#,
+        )
     }
     #[test]
     fn not_applicable_if_impl_empty() {
         cov_mark::check!(not_applicable_if_sorted_or_empty_or_single);
 
-        check_assist_not_applicable(sort_items, r#"
+        check_assist_not_applicable(
+            sort_items,
+            r#"
 struct Bar;
 $0impl Bar$0 {
 }
-        "#)
+        "#,
+        )
     }
     #[test]
     fn not_applicable_if_struct_empty() {
         cov_mark::check!(not_applicable_if_sorted_or_empty_or_single);
 
-        check_assist_not_applicable(sort_items, r#"
+        check_assist_not_applicable(
+            sort_items,
+            r#"
 $0struct Bar$0 ;
-        "#)
+        "#,
+        )
     }
     #[test]
     fn not_applicable_if_struct_empty2() {
         cov_mark::check!(not_applicable_if_sorted_or_empty_or_single);
 
-        check_assist_not_applicable(sort_items, r#"
+        check_assist_not_applicable(
+            sort_items,
+            r#"
 $0struct Bar$0 { };
-        "#)
+        "#,
+        )
     }
     #[test]
     fn not_applicable_if_enum_empty() {
         cov_mark::check!(not_applicable_if_sorted_or_empty_or_single);
 
-        check_assist_not_applicable(sort_items, r#"
+        check_assist_not_applicable(
+            sort_items,
+            r#"
 $0enum ZeroVariants$0 {};
-        "#)
+        "#,
+        )
     }
     #[test]
     fn not_applicable_if_trait_sorted() {
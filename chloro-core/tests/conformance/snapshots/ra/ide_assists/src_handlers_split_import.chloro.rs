@@ -17,7 +17,11 @@ pub(crate) fn split_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
     let colon_colon = ctx.find_token_syntax_at_offset(T![::])?;
     let path = ast::Path::cast(colon_colon.parent()?)?.qualifier()?;
 
-    let use_tree = path.top_path().syntax().ancestors().find_map(ast::UseTree::cast)?;
+    let use_tree = path
+        .top_path()
+        .syntax()
+        .ancestors()
+        .find_map(ast::UseTree::cast)?;
 
     let has_errors = use_tree
         .syntax()
@@ -29,16 +33,11 @@ pub(crate) fn split_import(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
     }
 
     let target = colon_colon.text_range();
-    acc.add(
-        AssistId::refactor_rewrite("split_import"),
-        "Split import",
-        target,
-        |edit| {
+    acc.add(AssistId::refactor_rewrite("split_import"), "Split import", target, |edit| {
         let use_tree = edit.make_mut(use_tree.clone());
         let path = edit.make_mut(path);
         use_tree.split_prefix(&path);
-    },
-    )
+    })
 }
 
 #[cfg(test)]
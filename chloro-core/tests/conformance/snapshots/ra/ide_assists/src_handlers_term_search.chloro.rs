@@ -29,7 +29,10 @@ pub(crate) fn term_search(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
         return None;
     }
 
-    let target_ty = ctx.sema.type_of_expr(&ast::Expr::cast(parent.clone())?)?.adjusted();
+    let target_ty = ctx
+        .sema
+        .type_of_expr(&ast::Expr::cast(parent.clone())?)?
+        .adjusted();
 
     let term_search_ctx = TermSearchCtx {
         sema: &ctx.sema,
@@ -56,7 +59,8 @@ pub(crate) fn term_search(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
             path.gen_source_code(
                 &scope,
                 &mut formatter,
-                ctx.config.find_path_config(ctx.sema.is_nightly(scope.module().krate())),
+                ctx.config
+                    .find_path_config(ctx.sema.is_nightly(scope.module().krate())),
                 scope.krate().to_display_target(ctx.db()),
             )
             .ok()
@@ -76,8 +76,7 @@ pub(crate) fn unnecessary_async(acc: &mut Assists, ctx: &AssistContext<'_>) -> O
                         _ => None,
                     })
                     // Keep only references that correspond to await expressions
-                    .filter_map(|nameref| find_await_expression(ctx, &nameref))
-                {
+                    .filter_map(|nameref| find_await_expression(ctx, &nameref)) {
                     if let Some(await_token) = &await_expr.await_token() {
                         edit.replace(await_token.text_range(), "");
                     }
@@ -94,9 +93,14 @@ fn find_all_references(
     ctx: &AssistContext<'_>,
     def: &Definition,
 ) -> impl Iterator<Item = (EditionedFileId, FileReference)> {
-    def.usages(&ctx.sema).all().into_iter().flat_map(|(file_id, references)| {
-        references.into_iter().map(move |reference| (file_id, reference))
-    })
+    def.usages(&ctx.sema)
+        .all()
+        .into_iter()
+        .flat_map(|(file_id, references)| {
+            references
+                .into_iter()
+                .map(move |reference| (file_id, reference))
+        })
 }
 
 /// Finds the await expression for the given `NameRef`.
@@ -184,6 +188,7 @@ pub async fn f3() { a::f2() }"#,
     fn applies_and_removes_await_on_inner_await() {
         check_assist(
             unnecessary_async,
+            // Ensure that it is the first await on the 3rd line that is removed
             r#"
 pub async fn f() { f2().await }
 pub asy$0nc fn f2() -> i32 { 1 }
@@ -200,6 +205,7 @@ pub async fn f4(i: i32) { }"#,
     fn applies_and_removes_await_on_outer_await() {
         check_assist(
             unnecessary_async,
+            // Ensure that it is the second await on the 3rd line that is removed
             r#"
 pub async fn f() { f2().await }
 pub async$0 fn f2(i: i32) { }
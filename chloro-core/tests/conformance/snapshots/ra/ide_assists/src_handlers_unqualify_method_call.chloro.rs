@@ -30,7 +30,10 @@ pub(crate) fn unqualify_method_call(acc: &mut Assists, ctx: &AssistContext<'_>)
     let ast::Expr::PathExpr(path_expr) = call.expr()? else { return None };
     let path = path_expr.path()?;
 
-    let cursor_in_range = path.syntax().text_range().contains_range(ctx.selection_trimmed());
+    let cursor_in_range = path
+        .syntax()
+        .text_range()
+        .contains_range(ctx.selection_trimmed());
     if !cursor_in_range {
         return None;
     }
@@ -56,10 +59,13 @@ pub(crate) fn unqualify_method_call(acc: &mut Assists, ctx: &AssistContext<'_>)
         TextRange::new(path.syntax().text_range().start(), l_paren.text_range().end());
 
     // Parens around `expr` if needed
-    let parens = first_arg.precedence().needs_parentheses_in(ExprPrecedence::Postfix).then(|| {
-        let range = first_arg.syntax().text_range();
-        (range.start(), range.end())
-    });
+    let parens = first_arg
+        .precedence()
+        .needs_parentheses_in(ExprPrecedence::Postfix)
+        .then(|| {
+            let range = first_arg.syntax().text_range();
+            (range.start(), range.end())
+        });
 
     // `, ` -> `.add(`
     let replace_comma = TextRange::new(
@@ -35,14 +35,9 @@ pub(crate) fn unwrap_block(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
 
     let kind = parent.kind();
     if matches!(kind, SyntaxKind::STMT_LIST | SyntaxKind::EXPR_STMT) {
-        acc.add(
-            assist_id,
-            assist_label,
-            target,
-            |builder| {
+        acc.add(assist_id, assist_label, target, |builder| {
             builder.replace(block.syntax().text_range(), update_expr_string(block.to_string()));
-        },
-        )
+        })
     } else if matches!(kind, SyntaxKind::LET_STMT) {
         let parent = ast::LetStmt::cast(parent)?;
         let pattern = ast::Pat::cast(parent.syntax().first_child()?)?;
@@ -65,14 +60,9 @@ pub(crate) fn unwrap_block(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
                 make::let_stmt(pattern, ty, Some(empty_tuple)).to_string()
             }
         };
-        acc.add(
-            assist_id,
-            assist_label,
-            target,
-            |builder| {
+        acc.add(assist_id, assist_label, target, |builder| {
             builder.replace(parent.syntax().text_range(), replaced);
-        },
-        )
+        })
     } else {
         let parent = ast::Expr::cast(parent)?;
         match parent.clone() {
@@ -118,14 +108,9 @@ pub(crate) fn unwrap_block(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
             _ => return None,
         };
 
-        acc.add(
-            assist_id,
-            assist_label,
-            target,
-            |builder| {
+        acc.add(assist_id, assist_label, target, |builder| {
             builder.replace(parent.syntax().text_range(), update_expr_string(block.to_string()));
-        },
-        )
+        })
     }
 }
 
@@ -226,17 +226,19 @@ fn wrapper_alias<'db>(
         iter::once(hir::Name::new_symbol_root(wrapper)),
     );
 
-    ctx.sema.resolve_mod_path(ast_ret_type.syntax(), &wrapper_path).and_then(|def| {
-        def.filter_map(|def| match def.into_module_def() {
+    ctx.sema
+        .resolve_mod_path(ast_ret_type.syntax(), &wrapper_path)
+        .and_then(|def| {
+            def.filter_map(|def| match def.into_module_def() {
             hir::ModuleDef::TypeAlias(alias) => {
                 let enum_ty = alias.ty(ctx.db()).as_adt()?.as_enum()?;
                 (enum_ty == core_wrapper).then_some((alias, enum_ty))
             }
             _ => None,
         })
-        .find_map(|(alias, enum_ty)| {
-            let mut inserted_ret_type = false;
-            let generic_args =
+            .find_map(|(alias, enum_ty)| {
+                let mut inserted_ret_type = false;
+                let generic_args =
                 alias.source(ctx.db())?.value.generic_param_list()?.generic_params().map(|param| {
                     match param {
                         // Replace the very first type parameter with the function's return type.
@@ -251,18 +253,18 @@ fn wrapper_alias<'db>(
                     }
                 });
 
-            let name = alias.name(ctx.db());
-            let generic_arg_list = make.generic_arg_list(generic_args, false);
-            let path = make.path_unqualified(
+                let name = alias.name(ctx.db());
+                let generic_arg_list = make.generic_arg_list(generic_args, false);
+                let path = make.path_unqualified(
                 make.path_segment_generics(make.name_ref(name.as_str()), generic_arg_list),
             );
 
-            let new_ty =
+                let new_ty =
                 hir::Adt::from(enum_ty).ty_with_args(ctx.db(), [semantic_ret_type.clone()]);
 
-            Some((make.ty_path(path), new_ty))
+                Some((make.ty_path(path), new_ty))
+            })
         })
-    })
 }
 
 fn tail_cb_impl(acc: &mut Vec<ast::Expr>, e: &ast::Expr) {
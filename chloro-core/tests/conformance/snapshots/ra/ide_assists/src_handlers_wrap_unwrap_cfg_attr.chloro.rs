@@ -26,10 +26,7 @@ use crate::{AssistContext, AssistId, Assists};
 // ```
 
 enum WrapUnwrapOption {
-    WrapDerive {
-        derive: TextRange,
-        attr: ast::Attr,
-    },
+    WrapDerive { derive: TextRange, attr: ast::Attr },
     WrapAttr(ast::Attr),
 }
 
@@ -102,8 +99,9 @@ fn attempt_get_derive(attr: ast::Attr, ident: SyntaxToken) -> WrapUnwrapOption {
             }
         }
     };
-    if ident.parent().and_then(ast::TokenTree::cast).is_none()
-        || !attr.simple_name().map(|v| v.eq("derive")).unwrap_or_default() {
+    if ident.parent().and_then(ast::TokenTree::cast).is_none() || !attr.simple_name()
+        .map(|v| v.eq("derive"))
+        .unwrap_or_default() {
         WrapUnwrapOption::WrapAttr(attr)
     } else {
         attempt_attr().unwrap_or(WrapUnwrapOption::WrapAttr(attr))
@@ -240,6 +240,7 @@ mod handlers {
     mod wrap_unwrap_cfg_attr;
     pub(crate) fn all() -> &'static [Handler] {
         &[
+            // These are alphabetic for the foolish consistency
             add_braces::add_braces,
             add_explicit_enum_discriminant::add_explicit_enum_discriminant,
             add_explicit_type::add_explicit_type,
@@ -383,19 +384,28 @@ mod handlers {
             unwrap_type_to_generic_arg::unwrap_type_to_generic_arg,
             wrap_return_type::wrap_return_type,
             wrap_unwrap_cfg_attr::wrap_unwrap_cfg_attr,
+            // These are manually sorted for better priorities. By default,
+            // priority is determined by the size of the target range (smaller
+            // target wins). If the ranges are equal, position in this list is
+            // used as a tie-breaker.
             add_missing_impl_members::add_missing_impl_members,
             add_missing_impl_members::add_missing_default_members,
+            //
             replace_string_with_char::replace_string_with_char,
             replace_string_with_char::replace_char_with_string,
             raw_string::make_raw_string,
+            //
             extract_variable::extract_variable,
             extract_function::extract_function,
             extract_module::extract_module,
+            //
             generate_getter_or_setter::generate_getter,
             generate_getter_or_setter::generate_getter_mut,
             generate_getter_or_setter::generate_setter,
             generate_delegate_methods::generate_delegate_methods,
             generate_deref::generate_deref,
+            // Are you sure you want to add new assist here, and not to the
+            // sorted list above?
         ]
     }
 }
@@ -94,7 +94,11 @@ pub fn test_related_attribute_syn(fn_def: &ast::Fn) -> Option<ast::Attr> {
     fn_def.attrs().find_map(|attr| {
         let path = attr.path()?;
         let text = path.syntax().text().to_string();
-        if text.starts_with("test") || text.ends_with("test") { Some(attr) } else { None }
+        if text.starts_with("test") || text.ends_with("test") {
+            Some(attr)
+        } else {
+            None
+        }
     })
 }
 
@@ -103,8 +107,7 @@ pub fn has_test_related_attribute(attrs: &hir::AttrsWithOwner) -> bool {
         let path = attr.path();
         (|| {
             Some(
-                path.segments().first()?.as_str().starts_with("test")
-                    || path.segments().last()?.as_str().ends_with("test"),
+                path.segments().first()?.as_str().starts_with("test") || path.segments().last()?.as_str().ends_with("test"),
             )
         })()
         .unwrap_or_default()
@@ -320,7 +323,10 @@ fn invert_special_case(make: &SyntaxFactory, expr: &ast::Expr) -> Option<ast::Ex
                 _ => return None,
             };
 
-            Some(make.expr_method_call(receiver, make.name_ref(method), arg_list).into())
+            Some(
+                make.expr_method_call(receiver, make.name_ref(method), arg_list)
+                    .into(),
+            )
         }
         ast::Expr::PrefixExpr(pe) if pe.op_kind()? == ast::UnaryOp::Not => match pe.expr()? {
             ast::Expr::ParenExpr(parexpr) => {
@@ -416,9 +422,13 @@ pub(crate) fn does_pat_variant_nested_or_literal(ctx: &AssistContext<'_>, pat: &
 }
 
 fn check_pat_variant_from_enum(ctx: &AssistContext<'_>, pat: &ast::Pat) -> bool {
-    ctx.sema.type_of_pat(pat).is_none_or(|ty: hir::TypeInfo<'_>| {
-        ty.adjusted().as_adt().is_some_and(|adt| matches!(adt, hir::Adt::Enum(_)))
-    })
+    ctx.sema
+        .type_of_pat(pat)
+        .is_none_or(|ty: hir::TypeInfo<'_>| {
+            ty.adjusted()
+                .as_adt()
+                .is_some_and(|adt| matches!(adt, hir::Adt::Enum(_)))
+        })
 }
 
 fn check_pat_variant_nested_or_literal_with_depth(
@@ -509,21 +519,27 @@ pub(crate) fn find_struct_impl(
 
     let struct_def = ctx.sema.to_def(adt)?;
 
-    let block = module.descendants().filter_map(ast::Impl::cast).find_map(|impl_blk| {
-        let blk = ctx.sema.to_def(&impl_blk)?;
-
-        // FIXME: handle e.g. `struct S<T>; impl<U> S<U> {}`
-        // (we currently use the wrong type parameter)
-        // also we wouldn't want to use e.g. `impl S<u32>`
+    let block = module
+        .descendants()
+        .filter_map(ast::Impl::cast)
+        .find_map(|impl_blk| {
+            let blk = ctx.sema.to_def(&impl_blk)?;
 
-        let same_ty = match blk.self_ty(db).as_adt() {
+            // FIXME: handle e.g. `struct S<T>; impl<U> S<U> {}`
+            // (we currently use the wrong type parameter)
+            // also we wouldn't want to use e.g. `impl S<u32>`
+            let same_ty = match blk.self_ty(db).as_adt() {
             Some(def) => def == struct_def,
             None => false,
         };
-        let not_trait_impl = blk.trait_(db).is_none();
+            let not_trait_impl = blk.trait_(db).is_none();
 
-        if !(same_ty && not_trait_impl) { None } else { Some(impl_blk) }
-    });
+            if !(same_ty && not_trait_impl) {
+                None
+            } else {
+                Some(impl_blk)
+            }
+        });
 
     if let Some(ref impl_blk) = block
         && has_any_fn(impl_blk, names)
@@ -879,7 +895,7 @@ impl<'db> ReferenceConversion<'db> {
                     make::expr_ref(expr, false)
                 } else {
                     make::expr_method_call(expr, make::name_ref("as_ref"), make::arg_list([]))
-                    .into()
+                        .into()
                 }
             }
         }
@@ -917,7 +933,8 @@ fn handle_copy(
     ty: &hir::Type<'_>,
     db: &dyn HirDatabase,
 ) -> Option<(ReferenceConversionType, bool)> {
-    ty.is_copy(db).then_some((ReferenceConversionType::Copy, true))
+    ty.is_copy(db)
+        .then_some((ReferenceConversionType::Copy, true))
 }
 
 fn handle_as_ref_str(
@@ -939,10 +956,10 @@ fn handle_as_ref_slice(
     let type_argument = ty.type_arguments().next()?;
     let slice_type = hir::Type::new_slice(type_argument);
 
-    ty.impls_trait(db, famous_defs.core_convert_AsRef()?, slice::from_ref(&slice_type)).then_some((
-        ReferenceConversionType::AsRefSlice,
-        could_deref_to_target(ty, &slice_type, db),
-    ))
+    ty.impls_trait(db, famous_defs.core_convert_AsRef()?, slice::from_ref(&slice_type))
+        .then_some(
+            (ReferenceConversionType::AsRefSlice, could_deref_to_target(ty, &slice_type, db)),
+        )
 }
 
 fn handle_dereferenced(
@@ -953,10 +970,9 @@ fn handle_dereferenced(
     let type_argument = ty.type_arguments().next()?;
 
     ty.impls_trait(db, famous_defs.core_convert_AsRef()?, slice::from_ref(&type_argument))
-        .then_some((
-            ReferenceConversionType::Dereferenced,
-            could_deref_to_target(ty, &type_argument, db),
-        ))
+        .then_some(
+            (ReferenceConversionType::Dereferenced, could_deref_to_target(ty, &type_argument, db)),
+        )
 }
 
 fn handle_option_as_ref(
@@ -1147,7 +1163,10 @@ pub(crate) fn tt_from_syntax(node: SyntaxNode) -> Vec<NodeOrToken<ast::TokenTree
         }
     }
 
-    tt_stack.pop().expect("parent token tree was closed before it was completed").1
+    tt_stack
+        .pop()
+        .expect("parent token tree was closed before it was completed")
+        .1
 }
 
 pub(crate) fn cover_let_chain(mut expr: ast::Expr, range: TextRange) -> Option<ast::Expr> {
@@ -1211,11 +1230,13 @@ pub(crate) fn is_never_block(
     block_expr: &ast::BlockExpr,
 ) -> bool {
     if let Some(tail_expr) = block_expr.tail_expr() {
-        sema.type_of_expr(&tail_expr).is_some_and(|ty| ty.original.is_never())
+        sema.type_of_expr(&tail_expr)
+            .is_some_and(|ty| ty.original.is_never())
     } else if let Some(ast::Stmt::ExprStmt(expr_stmt)) = block_expr.statements().last()
         && let Some(expr) = expr_stmt.expr()
     {
-        sema.type_of_expr(&expr).is_some_and(|ty| ty.original.is_never())
+        sema.type_of_expr(&expr)
+            .is_some_and(|ty| ty.original.is_never())
     } else {
         false
     }
@@ -42,7 +42,10 @@ pub(crate) fn complete_known_attribute_input(
 ) -> Option<()> {
     let attribute = fake_attribute_under_caret;
     let path = attribute.path()?;
-    let segments = path.segments().map(|s| s.name_ref()).collect::<Option<Vec<_>>>()?;
+    let segments = path
+        .segments()
+        .map(|s| s.name_ref())
+        .collect::<Option<Vec<_>>>()?;
     let segments = segments.iter().map(|n| n.text()).collect::<Vec<_>>();
     let segments = segments.iter().map(|t| t.as_str()).collect::<Vec<_>>();
     let tt = attribute.token_tree()?;
@@ -194,7 +197,10 @@ pub(crate) fn complete_attribute_path(
             .flat_map(|idx| ATTRIBUTES.get(idx))
             .for_each(add_completion),
         None if is_inner => ATTRIBUTES.iter().for_each(add_completion),
-        None => ATTRIBUTES.iter().filter(|compl| !compl.prefer_inner).for_each(add_completion),
+        None => ATTRIBUTES
+            .iter()
+            .filter(|compl| !compl.prefer_inner)
+            .for_each(add_completion),
     }
 }
 
@@ -216,7 +222,10 @@ impl AttrCompletion {
     }
 
     const fn prefer_inner(self) -> AttrCompletion {
-        AttrCompletion { prefer_inner: true, ..self }
+        AttrCompletion {
+            prefer_inner: true,
+            ..self
+        }
     }
 }
 
@@ -225,7 +234,13 @@ const fn attr(
     lookup: Option<&'static str>,
     snippet: Option<&'static str>,
 ) -> AttrCompletion {
-    AttrCompletion { label, lookup, snippet, qualifiers: &[], prefer_inner: false }
+    AttrCompletion {
+        label,
+        lookup,
+        snippet,
+        qualifiers: &[],
+        prefer_inner: false,
+    }
 }
 
 macro_rules! attrs {
@@ -322,6 +337,7 @@ static KIND_TO_ATTRIBUTES: LazyLock<FxHashMap<SyntaxKind, &[&str]>> = LazyLock::
 
 const EXPR_ATTRIBUTES: &[&str] = attrs!();
 
+// Keep these sorted for the binary search!
 /// <https://doc.rust-lang.org/reference/attributes.html#built-in-attributes-index>
 const ATTRIBUTES: &[AttrCompletion] = &[
     attr("allow(…)", Some("allow"), Some("allow(${0:lint})")),
@@ -55,7 +55,12 @@ struct ReprCompletion {
 }
 
 const fn attr(label: &'static str, collides: &'static [&'static str]) -> ReprCompletion {
-    ReprCompletion { label, snippet: None, lookup: None, collides }
+    ReprCompletion {
+        label,
+        snippet: None,
+        lookup: None,
+        collides,
+    }
 }
 
 #[rustfmt::skip]
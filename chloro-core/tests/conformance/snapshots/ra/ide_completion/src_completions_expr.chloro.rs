@@ -102,8 +102,7 @@ pub(crate) fn complete_expr_path(
     match qualified {
         // We exclude associated types/consts of excluded traits here together with methods,
         // even though we don't exclude them when completing in type position, because it's easier.
-        Qualified::TypeAnchor { ty: None, trait_: None } => ctx
-            .traits_in_scope()
+        Qualified::TypeAnchor { ty: None, trait_: None } => ctx.traits_in_scope()
             .iter()
             .copied()
             .map(hir::Trait::from)
@@ -114,7 +113,10 @@ pub(crate) fn complete_expr_path(
             .for_each(|item| add_assoc_item(acc, item)),
         Qualified::TypeAnchor { trait_: Some(trait_), .. } => {
             // Don't filter excluded traits here, user requested this specific trait.
-            trait_.items(ctx.sema.db).into_iter().for_each(|item| add_assoc_item(acc, item))
+            trait_
+                .items(ctx.sema.db)
+                .into_iter()
+                .for_each(|item| add_assoc_item(acc, item))
         }
         Qualified::TypeAnchor { ty: Some(ty), trait_: None } => {
             if let Some(hir::Adt::Enum(e)) = ty.as_adt() {
@@ -142,9 +144,10 @@ pub(crate) fn complete_expr_path(
         Qualified::With { resolution: None, .. } => {}
         Qualified::With { resolution: Some(resolution), .. } => {
             // Add associated types on type parameters and `Self`.
-            ctx.scope.assoc_type_shorthand_candidates(resolution, |alias| {
-                acc.add_type_alias(ctx, alias);
-            });
+            ctx.scope
+                .assoc_type_shorthand_candidates(resolution, |alias| {
+                    acc.add_type_alias(ctx, alias);
+                });
             match resolution {
                 hir::PathResolution::Def(hir::ModuleDef::Module(module)) => {
                     let visible_from = if ctx.config.enable_private_editable {
@@ -333,7 +336,9 @@ pub(crate) fn complete_expr_path(
 
             match is_func_update {
                 Some(record_expr) => {
-                    let ty = ctx.sema.type_of_expr(&ast::Expr::RecordExpr(record_expr.clone()));
+                    let ty = ctx
+                        .sema
+                        .type_of_expr(&ast::Expr::RecordExpr(record_expr.clone()));
 
                     match ty.as_ref().and_then(|t| t.original.as_adt()) {
                         Some(hir::Adt::Union(_)) => (),
@@ -9,6 +9,7 @@ use crate::{
     CompletionItem, CompletionItemKind, completions::Completions, context::CompletionContext,
 };
 
+// Most of these are feature gated, we should filter/add feature gate completions once we have them.
 const SUPPORTED_CALLING_CONVENTIONS: &[&str] = &[
     "Rust",
     "C",
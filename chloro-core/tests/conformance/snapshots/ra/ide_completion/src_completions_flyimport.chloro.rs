@@ -143,7 +143,9 @@ pub(crate) fn import_on_the_fly_path(
         ctx,
         path_ctx,
         import_assets,
-        qualifier.map(|it| it.syntax().clone()).or_else(|| ctx.original_token.parent())?,
+        qualifier
+            .map(|it| it.syntax().clone())
+            .or_else(|| ctx.original_token.parent())?,
         potential_import_name,
     )
 }
@@ -265,9 +267,7 @@ fn import_on_the_fly(
         .filter(ns_filter)
         .filter(|import| {
             let original_item = &import.original_item;
-            !ctx.is_item_hidden(&import.item_to_import)
-                && !ctx.is_item_hidden(original_item)
-                && ctx.check_stability(original_item.attrs(ctx.db).as_deref())
+            !ctx.is_item_hidden(&import.item_to_import) && !ctx.is_item_hidden(original_item) && ctx.check_stability(original_item.attrs(ctx.db).as_deref())
         })
         .filter(|import| filter_excluded_flyimport(ctx, import))
         .sorted_by(|a, b| {
@@ -312,9 +312,7 @@ fn import_on_the_fly_pat_(
         .filter(ns_filter)
         .filter(|import| {
             let original_item = &import.original_item;
-            !ctx.is_item_hidden(&import.item_to_import)
-                && !ctx.is_item_hidden(original_item)
-                && ctx.check_stability(original_item.attrs(ctx.db).as_deref())
+            !ctx.is_item_hidden(&import.item_to_import) && !ctx.is_item_hidden(original_item) && ctx.check_stability(original_item.attrs(ctx.db).as_deref())
         })
         .sorted_by(|a, b| {
             let key = |import_path| {
@@ -352,8 +350,7 @@ fn import_on_the_fly_method(
     import_assets
         .search_for_imports(&ctx.sema, cfg, ctx.config.insert_use.prefix_kind)
         .filter(|import| {
-            !ctx.is_item_hidden(&import.item_to_import)
-                && !ctx.is_item_hidden(&import.original_item)
+            !ctx.is_item_hidden(&import.item_to_import) && !ctx.is_item_hidden(&import.original_item)
         })
         .filter(|import| filter_excluded_flyimport(ctx, import))
         .sorted_by(|a, b| {
@@ -112,7 +112,9 @@ fn fill_fn_params(
         self_completion_items.into_iter().for_each(&mut add_new_item_to_acc);
     }
 
-    file_params.keys().for_each(|whole_param| add_new_item_to_acc(whole_param));
+    file_params
+        .keys()
+        .for_each(|whole_param| add_new_item_to_acc(whole_param));
 }
 
 fn params_from_stmt_list_scope(
@@ -124,8 +126,8 @@ fn params_from_stmt_list_scope(
         Some(it) => it,
         None => return,
     };
-    if let Some(scope) =
-        ctx.sema.scope_at_offset(stmt_list.syntax(), syntax_node.text_range().end()) {
+    if let Some(scope) = ctx.sema
+        .scope_at_offset(stmt_list.syntax(), syntax_node.text_range().end()) {
         let module = scope.module().into();
         scope.process_all_names(&mut |name, def| {
             if let hir::ScopeDef::Local(local) = def
@@ -167,7 +169,9 @@ fn should_add_self_completions(
         return false;
     }
     match param_list.params().next() {
-        Some(first) => first.pat().is_some_and(|pat| pat.syntax().text_range().contains(cursor)),
+        Some(first) => first
+            .pat()
+            .is_some_and(|pat| pat.syntax().text_range().contains(cursor)),
         None => true,
     }
 }
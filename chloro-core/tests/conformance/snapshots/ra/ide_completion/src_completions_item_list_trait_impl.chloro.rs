@@ -226,7 +226,10 @@ fn add_function_impl_(
     let mut item = CompletionItem::new(completion_kind, replacement_range, label, ctx.edition);
     item.lookup_by(format!("{}fn {}", async_, fn_name.display(ctx.db, ctx.edition)))
         .set_documentation(func.docs(ctx.db))
-        .set_relevance(CompletionRelevance { exact_name_match: true, ..Default::default() });
+        .set_relevance(CompletionRelevance {
+            exact_name_match: true,
+            ..Default::default()
+        });
 
     if let Some(source) = ctx.sema.source(func)
         && let Some(transformed_fn) = get_transformed_fn(ctx, source.value, impl_def, async_sugaring)
@@ -369,7 +372,10 @@ fn add_type_alias_impl(
         CompletionItem::new(SymbolKind::TypeAlias, replacement_range, label, ctx.edition);
     item.lookup_by(format!("type {alias_name}"))
         .set_documentation(type_alias.docs(ctx.db))
-        .set_relevance(CompletionRelevance { exact_name_match: true, ..Default::default() });
+        .set_relevance(CompletionRelevance {
+            exact_name_match: true,
+            ..Default::default()
+        });
 
     if let Some(source) = ctx.sema.source(type_alias) {
         let assoc_item = ast::AssocItem::TypeAlias(source.value);
@@ -442,7 +448,9 @@ fn add_const_impl(
     const_: hir::Const,
     impl_def: hir::Impl,
 ) {
-    let const_name = const_.name(ctx.db).map(|n| n.display_no_db(ctx.edition).to_smolstr());
+    let const_name = const_
+        .name(ctx.db)
+        .map(|n| n.display_no_db(ctx.edition).to_smolstr());
 
     if let Some(const_name) = const_name
         && let Some(source) = ctx.sema.source(const_)
@@ -1027,7 +1035,7 @@ impl Test for T {{
         // Enumerate some possible next siblings.
         for next_sibling in [
             "",
-            "fn other_fn() {}",
+            "fn other_fn() {}", // `const $0 fn` -> `const fn`
             "type OtherType = i32;",
             "const OTHER_CONST: i32 = 0;",
             "async fn other_fn() {}",
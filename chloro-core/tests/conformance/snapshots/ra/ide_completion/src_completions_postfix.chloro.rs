@@ -427,8 +427,10 @@ fn add_custom_postfix_completions(
     receiver_text: &str,
 ) -> Option<()> {
     ImportScope::find_insert_use_container(&ctx.token.parent()?, &ctx.sema)?;
-    ctx.config.postfix_snippets().filter(|(_, snip)| snip.scope == SnippetScope::Expr).for_each(
-        |(trigger, snippet)| {
+    ctx.config
+        .postfix_snippets()
+        .filter(|(_, snip)| snip.scope == SnippetScope::Expr)
+        .for_each(|(trigger, snippet)| {
             let imports = match snippet.imports(ctx) {
                 Some(imports) => imports,
                 None => return,
@@ -441,8 +443,7 @@ fn add_custom_postfix_completions(
                 builder.add_import(import);
             }
             builder.add_to(acc, ctx.db);
-        },
-    );
+        });
     None
 }
 
@@ -18,7 +18,9 @@ pub(crate) fn complete_record_pattern_fields(
     pattern_ctx: &PatternContext,
 ) {
     if let PatternContext { record_pat: Some(record_pat), .. } = pattern_ctx {
-        let ty = ctx.sema.type_of_pat(&ast::Pat::RecordPat(record_pat.clone()));
+        let ty = ctx
+            .sema
+            .type_of_pat(&ast::Pat::RecordPat(record_pat.clone()));
         let missing_fields = match ty.as_ref().and_then(|t| t.original.as_adt()) {
             Some(hir::Adt::Union(un)) => {
                 // ctx.sema.record_pat_missing_fields will always return
@@ -49,7 +51,9 @@ pub(crate) fn complete_record_expr_fields(
     record_expr: &ast::RecordExpr,
     &dot_prefix: &bool,
 ) {
-    let ty = ctx.sema.type_of_expr(&Expr::RecordExpr(record_expr.clone()));
+    let ty = ctx
+        .sema
+        .type_of_expr(&Expr::RecordExpr(record_expr.clone()));
 
     let missing_fields = match ty.as_ref().and_then(|t| t.original.as_adt()) {
         Some(hir::Adt::Union(un)) => {
@@ -115,10 +119,11 @@ pub(crate) fn add_default_update(
         );
         let completion_text =
             completion_text.strip_prefix(ctx.token.text()).unwrap_or(completion_text);
-        item.insert_text(completion_text).set_relevance(CompletionRelevance {
-            postfix_match: Some(CompletionRelevancePostfixMatch::Exact),
-            ..Default::default()
-        });
+        item.insert_text(completion_text)
+            .set_relevance(CompletionRelevance {
+                postfix_match: Some(CompletionRelevancePostfixMatch::Exact),
+                ..Default::default()
+            });
         item.add_to(acc, ctx.db);
     }
 }
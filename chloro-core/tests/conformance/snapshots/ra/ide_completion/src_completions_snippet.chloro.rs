@@ -131,8 +131,10 @@ fn add_custom_completions(
     scope: SnippetScope,
 ) -> Option<()> {
     ImportScope::find_insert_use_container(&ctx.token.parent()?, &ctx.sema)?;
-    ctx.config.prefix_snippets().filter(|(_, snip)| snip.scope == scope).for_each(
-        |(trigger, snip)| {
+    ctx.config
+        .prefix_snippets()
+        .filter(|(_, snip)| snip.scope == scope)
+        .for_each(|(trigger, snip)| {
             let imports = match snip.imports(ctx) {
                 Some(imports) => imports,
                 None => return,
@@ -145,8 +147,7 @@ fn add_custom_completions(
             }
             builder.set_detail(snip.description.clone());
             builder.add_to(acc, ctx.db);
-        },
-    );
+        });
     None
 }
 
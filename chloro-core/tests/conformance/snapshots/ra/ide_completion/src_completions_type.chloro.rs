@@ -53,13 +53,15 @@ pub(crate) fn complete_type_path(
     };
 
     match qualified {
-        Qualified::TypeAnchor { ty: None, trait_: None } => ctx
-            .traits_in_scope()
+        Qualified::TypeAnchor { ty: None, trait_: None } => ctx.traits_in_scope()
             .iter()
             .flat_map(|&it| hir::Trait::from(it).items(ctx.sema.db))
             .for_each(|item| add_assoc_item(acc, item)),
         Qualified::TypeAnchor { trait_: Some(trait_), .. } => {
-            trait_.items(ctx.sema.db).into_iter().for_each(|item| add_assoc_item(acc, item))
+            trait_
+                .items(ctx.sema.db)
+                .into_iter()
+                .for_each(|item| add_assoc_item(acc, item))
         }
         Qualified::TypeAnchor { ty: Some(ty), trait_: None } => {
             ctx.iterate_path_candidates(ty, |item| {
@@ -77,9 +79,10 @@ pub(crate) fn complete_type_path(
         Qualified::With { resolution: None, .. } => {}
         Qualified::With { resolution: Some(resolution), .. } => {
             // Add associated types on type parameters and `Self`.
-            ctx.scope.assoc_type_shorthand_candidates(resolution, |alias| {
-                acc.add_type_alias(ctx, alias);
-            });
+            ctx.scope
+                .assoc_type_shorthand_candidates(resolution, |alias| {
+                    acc.add_type_alias(ctx, alias);
+                });
 
             match resolution {
                 hir::PathResolution::Def(hir::ModuleDef::Module(module)) => {
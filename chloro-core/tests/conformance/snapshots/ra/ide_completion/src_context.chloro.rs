@@ -57,10 +57,7 @@ pub(crate) struct QualifierCtx {
 
 impl QualifierCtx {
     pub(crate) fn none(&self) -> bool {
-        self.async_tok.is_none()
-            && self.unsafe_tok.is_none()
-            && self.safe_tok.is_none()
-            && self.vis_node.is_none()
+        self.async_tok.is_none() && self.unsafe_tok.is_none() && self.safe_tok.is_none() && self.vis_node.is_none()
     }
 }
 
@@ -75,8 +72,8 @@ pub(crate) struct PathCompletionCtx<'db> {
     pub(crate) qualified: Qualified<'db>,
     /// The parent of the path we are completing.
     pub(crate) parent: Option<ast::Path>,
-    /// The path of which we are completing the segment
     #[allow(dead_code)]
+    /// The path of which we are completing the segment
     pub(crate) path: ast::Path,
     /// The path of which we are completing the segment in the original file
     pub(crate) original_path: Option<ast::Path>,
@@ -106,28 +103,14 @@ impl PathCompletionCtx<'_> {
 /// The kind of path we are completing right now.
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum PathKind<'db> {
-    Expr {
-        expr_ctx: PathExprCtx<'db>,
-    },
-    Type {
-        location: TypeLocation,
-    },
-    Attr {
-        attr_ctx: AttrCtx,
-    },
-    Derive {
-        existing_derives: ExistingDerives,
-    },
+    Expr { expr_ctx: PathExprCtx<'db> },
+    Type { location: TypeLocation },
+    Attr { attr_ctx: AttrCtx },
+    Derive { existing_derives: ExistingDerives },
     /// Path in item position, that is inside an (Assoc)ItemList
-    Item {
-        kind: ItemListKind,
-    },
-    Pat {
-        pat_ctx: PatternContext,
-    },
-    Vis {
-        has_in_token: bool,
-    },
+    Item { kind: ItemListKind },
+    Pat { pat_ctx: PatternContext },
+    Vis { has_in_token: bool },
     Use,
 }
 
@@ -242,9 +225,7 @@ pub(crate) enum ItemListKind {
     Impl,
     TraitImpl(Option<ast::Impl>),
     Trait,
-    ExternBlock {
-        is_unsafe: bool,
-    },
+    ExternBlock { is_unsafe: bool },
 }
 
 #[derive(Debug)]
@@ -699,6 +680,7 @@ impl CompletionContext<'_> {
     }
 }
 
+// CompletionContext construction
 impl<'db> CompletionContext<'db> {
     pub(crate) fn new(
         db: &'db RootDatabase,
@@ -723,7 +705,10 @@ impl<'db> CompletionContext<'db> {
 
         // always pick the token to the immediate left of the cursor, as that is what we are actually
         // completing on
-        let original_token = original_file.syntax().token_at_offset(offset).left_biased()?;
+        let original_token = original_file
+            .syntax()
+            .token_at_offset(offset)
+            .left_biased()?;
 
         // try to skip completions on path with invalid colons
         // this approach works in normal path and inside token tree
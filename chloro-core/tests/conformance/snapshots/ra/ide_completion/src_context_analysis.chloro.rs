@@ -87,7 +87,10 @@ pub(super) fn expand_and_analyze<'db>(
 
     // add the relative offset back, so that left_biased finds the proper token
     let original_offset = expansion.original_offset + relative_offset;
-    let token = expansion.original_file.token_at_offset(original_offset).left_biased()?;
+    let token = expansion
+        .original_file
+        .token_at_offset(original_offset)
+        .left_biased()?;
 
     hir::attach_db(sema.db, || analyze(sema, expansion, original_token, &token)).map(
         |(analysis, expected, qualifier_ctx)| AnalysisResult {
@@ -156,7 +159,7 @@ fn expand_maybe_stop(
     // We can't check whether the fake expansion is inside macro call, because that requires semantic info.
     // But hopefully checking just the real one should be enough.
     if token_at_offset_ignore_whitespace(&original_file.value, original_offset + relative_offset)
-    .is_some_and(|original_token| {
+        .is_some_and(|original_token| {
             !sema.is_inside_macro_call(original_file.with_value(&original_token))
         }) {
         // Recursion base case.
@@ -384,7 +387,10 @@ fn expand(
         spec_tt.syntax().parent().and_then(ast::MacroCall::cast)?,
     );
     let mac_call_path0 = actual_macro_call.path().as_ref().map(|s| s.syntax().text());
-    let mac_call_path1 = macro_call_with_fake_ident.path().as_ref().map(|s| s.syntax().text());
+    let mac_call_path1 = macro_call_with_fake_ident
+        .path()
+        .as_ref()
+        .map(|s| s.syntax().text());
 
     // inconsistent state, stop expanding
     if mac_call_path0 != mac_call_path1 {
@@ -1643,7 +1649,15 @@ fn classify_name_ref<'db>(
             }
         }
     }
-    Some((NameRefContext { nameref, kind: NameRefKind::Path(path_ctx) }, qualifier_ctx))
+    Some(
+        (
+            NameRefContext {
+            nameref,
+            kind: NameRefKind::Path(path_ctx),
+        },
+            qualifier_ctx,
+        ),
+    )
 }
 
 /// When writing in the middle of some code the following situation commonly occurs (`|` denotes the cursor):
@@ -1665,7 +1679,9 @@ fn has_parens(node: &dyn HasArgList) -> bool {
     prev_siblings
         .take_while(|syntax| syntax.kind().is_trivia())
         .filter_map(|syntax| {
-            syntax.into_token().filter(|token| token.kind() == SyntaxKind::WHITESPACE)
+            syntax
+                .into_token()
+                .filter(|token| token.kind() == SyntaxKind::WHITESPACE)
         })
         .all(|whitespace| !whitespace.text().contains('\n'))
 }
@@ -1821,7 +1837,10 @@ fn find_node_in_file<N: AstNode>(syntax: &SyntaxNode, node: &N) -> Option<N> {
     let syntax_range = syntax.text_range();
     let range = node.syntax().text_range();
     let intersection = range.intersect(syntax_range)?;
-    syntax.covering_element(intersection).ancestors().find_map(N::cast)
+    syntax
+        .covering_element(intersection)
+        .ancestors()
+        .find_map(N::cast)
 }
 
 /// Attempts to find `node` inside `syntax` via `node`'s text range while compensating
@@ -1842,7 +1861,9 @@ fn ancestors_in_file_compensated<'sema>(
 ) -> Option<impl Iterator<Item = SyntaxNode> + 'sema> {
     let syntax_range = in_file.text_range();
     let range = node.text_range();
-    let end = range.end().checked_sub(TextSize::try_from(COMPLETION_MARKER.len()).ok()?)?;
+    let end = range
+        .end()
+        .checked_sub(TextSize::try_from(COMPLETION_MARKER.len()).ok()?)?;
     if end < range.start() {
         return None;
     }
@@ -1872,7 +1893,10 @@ fn path_or_use_tree_qualifier(path: &ast::Path) -> Option<(ast::Path, bool)> {
         return Some((qual, false));
     }
     let use_tree_list = path.syntax().ancestors().find_map(ast::UseTreeList::cast)?;
-    let use_tree = use_tree_list.syntax().parent().and_then(ast::UseTree::cast)?;
+    let use_tree = use_tree_list
+        .syntax()
+        .parent()
+        .and_then(ast::UseTree::cast)?;
     Some((use_tree.path()?, true))
 }
 
@@ -1895,9 +1919,7 @@ fn is_in_token_of_for_loop(path: &ast::Path) -> bool {
             }
         })
     })()
-    .unwrap_or(
-        false,
-    )
+    .unwrap_or(false)
 }
 
 fn is_in_breakable(node: &SyntaxNode) -> Option<(BreakableKind, SyntaxNode)> {
@@ -1913,7 +1935,10 @@ fn is_in_breakable(node: &SyntaxNode) -> Option<(BreakableKind, SyntaxNode)> {
                     _ => return None,
                 }
             };
-            loop_body.syntax().text_range().contains_range(node.text_range())
+            loop_body
+                .syntax()
+                .text_range()
+                .contains_range(node.text_range())
                 .then_some((breakable, it))
         })
 }
@@ -1938,13 +1963,16 @@ fn has_in_newline_expr_first(node: &SyntaxNode) -> bool {
         && let Some(NodeOrToken::Token(next)) = node.next_sibling_or_token()
         && next.kind() == SyntaxKind::WHITESPACE
         && next.text().contains('\n')
-        && let Some(stmt_like) = node
-            .ancestors()
-            .take_while(|it| it.text_range().start() == node.text_range().start())
-            .filter_map(Either::<ast::ExprStmt, ast::Expr>::cast)
-            .last()
+        && let Some(stmt_like) = node.ancestors()
+        .take_while(|it| it.text_range().start() == node.text_range().start())
+        .filter_map(Either::<ast::ExprStmt, ast::Expr>::cast)
+        .last()
     {
-        stmt_like.syntax().parent().and_then(ast::StmtList::cast).is_some()
+        stmt_like
+            .syntax()
+            .parent()
+            .and_then(ast::StmtList::cast)
+            .is_some()
     } else {
         false
     }
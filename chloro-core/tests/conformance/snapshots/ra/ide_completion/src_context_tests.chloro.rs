@@ -16,7 +16,8 @@ fn check_expected_type_and_name(#[rust_analyzer::rust_fixture] ra_fixture: &str,
         .expected_type
         .map(|t| {
             hir::attach_db(&db, || {
-                t.display_test(&db, completion_context.krate.to_display_target(&db)).to_string()
+                t.display_test(&db, completion_context.krate.to_display_target(&db))
+                    .to_string()
             })
         })
         .unwrap_or("?".to_owned());
@@ -87,6 +87,7 @@ pub struct CompletionItemLabel {
     pub detail_right: Option<String>,
 }
 
+// We use custom debug for CompletionItem to make snapshot tests more readable.
 impl fmt::Debug for CompletionItem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut s = f.debug_struct("CompletionItem");
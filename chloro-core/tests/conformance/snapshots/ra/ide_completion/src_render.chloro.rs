@@ -80,12 +80,15 @@ impl<'a> RenderContext<'a> {
         CompletionRelevance {
             is_private_editable: self.is_private_editable,
             requires_import: self.import_to_add.is_some(),
+            ..Default::default()
         }
     }
 
     fn is_immediately_after_macro_bang(&self) -> bool {
-        self.completion.token.kind() == SyntaxKind::BANG
-            && self.completion.token.parent().is_some_and(|it| it.kind() == SyntaxKind::MACRO_CALL)
+        self.completion.token.kind() == SyntaxKind::BANG && self.completion
+            .token
+            .parent()
+            .is_some_and(|it| it.kind() == SyntaxKind::MACRO_CALL)
     }
 
     fn is_deprecated(&self, def: impl HasAttrs) -> bool {
@@ -105,11 +108,10 @@ impl<'a> RenderContext<'a> {
             hir::AssocItem::Const(it) => self.is_deprecated(it),
             hir::AssocItem::TypeAlias(it) => self.is_deprecated(it),
         };
-        is_assoc_deprecated
-            || assoc
-                .container_or_implemented_trait(db)
-                .map(|trait_| self.is_deprecated(trait_))
-                .unwrap_or(false)
+        is_assoc_deprecated || assoc
+            .container_or_implemented_trait(db)
+            .map(|trait_| self.is_deprecated(trait_))
+            .unwrap_or(false)
     }
 
     // FIXME: remove this
@@ -192,10 +194,7 @@ pub(crate) fn render_field(
 
 fn field_with_receiver(receiver: Option<&str>, field_name: &str) -> SmolStr {
     receiver
-    .map_or_else(
-        || field_name.into(),
-        |receiver| format_smolstr!("{}.{field_name}", receiver),
-    )
+        .map_or_else(|| field_name.into(), |receiver| format_smolstr!("{}.{field_name}", receiver))
 }
 
 pub(crate) fn render_tuple_field(
@@ -210,8 +209,11 @@ pub(crate) fn render_tuple_field(
         field_with_receiver(receiver.as_deref(), &field.to_string()),
         ctx.completion.edition,
     );
-    item.detail(ty.display(ctx.db(), ctx.completion.display_target).to_string())
-        .lookup_by(field.to_string());
+    item.detail(
+        ty.display(ctx.db(), ctx.completion.display_target)
+            .to_string(),
+    )
+    .lookup_by(field.to_string());
     item.set_relevance(CompletionRelevance {
         is_skipping_completion: receiver.is_some(),
         ..ctx.completion_relevance()
@@ -424,7 +426,9 @@ fn render_resolution_path(
     let config = completion.config;
     let requires_import = import_to_add.is_some();
 
-    let name = local_name.display_no_db(ctx.completion.edition).to_smolstr();
+    let name = local_name
+        .display_no_db(ctx.completion.edition)
+        .to_smolstr();
     let mut item = render_resolution_simple_(ctx, &local_name, import_to_add, resolution);
     if local_name.needs_escape(completion.edition) {
         item.insert_text(local_name.display_no_db(completion.edition).to_smolstr());
@@ -607,7 +611,9 @@ fn compute_type_match(
 }
 
 fn compute_exact_name_match(ctx: &CompletionContext<'_>, completion_name: &str) -> bool {
-    ctx.expected_name.as_ref().is_some_and(|name| name.text() == completion_name)
+    ctx.expected_name
+        .as_ref()
+        .is_some_and(|name| name.text() == completion_name)
 }
 
 fn compute_ref_match(
@@ -1345,10 +1351,10 @@ mod m {
 fn main() { let _: m::Spam = S$0 }
 "#,
             &[
-            CompletionItemKind::SymbolKind(SymbolKind::Function),
-            CompletionItemKind::SymbolKind(SymbolKind::Module),
-            CompletionItemKind::SymbolKind(SymbolKind::Variant),
-        ],
+                CompletionItemKind::SymbolKind(SymbolKind::Function),
+                CompletionItemKind::SymbolKind(SymbolKind::Module),
+                CompletionItemKind::SymbolKind(SymbolKind::Variant),
+            ],
             expect![[r#"
                 [
                     CompletionItem {
@@ -1631,10 +1637,10 @@ enum E {
 use self::E::*;
 "#,
             &[
-            CompletionItemKind::SymbolKind(SymbolKind::Module),
-            CompletionItemKind::SymbolKind(SymbolKind::Variant),
-            CompletionItemKind::SymbolKind(SymbolKind::Enum),
-        ],
+                CompletionItemKind::SymbolKind(SymbolKind::Module),
+                CompletionItemKind::SymbolKind(SymbolKind::Variant),
+                CompletionItemKind::SymbolKind(SymbolKind::Enum),
+            ],
             expect![[r#"
                 [
                     CompletionItem {
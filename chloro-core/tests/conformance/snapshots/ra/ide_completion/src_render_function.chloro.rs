@@ -283,7 +283,9 @@ pub(super) fn add_call_parens<'b>(
             }
         }
     }
-    builder.label(SmolStr::from_iter([&name, label_suffix])).insert_snippet(cap, snippet)
+    builder
+        .label(SmolStr::from_iter([&name, label_suffix]))
+        .insert_snippet(cap, snippet)
 }
 
 fn ref_of_param(ctx: &CompletionContext<'_>, arg: &str, ty: &hir::Type<'_>) -> &'static str {
@@ -125,7 +125,8 @@ fn render(
         None => item.insert_text(rendered.literal),
     };
 
-    item.set_documentation(thing.docs(db)).set_deprecated(thing.is_deprecated(&ctx));
+    item.set_documentation(thing.docs(db))
+        .set_deprecated(thing.is_deprecated(&ctx));
 
     let ty = thing.ty(db);
     item.set_relevance(CompletionRelevance {
@@ -25,8 +25,11 @@ pub(crate) fn render_record_lit(
     if snippet_cap.is_none() {
         return RenderedLiteral { literal: path.to_owned(), detail: path.to_owned() };
     }
-    let completions = fields.iter().enumerate().format_with(", ", |(idx, field), f| {
-        let mut fmt_field = |fill, tab| {
+    let completions = fields
+        .iter()
+        .enumerate()
+        .format_with(", ", |(idx, field), f| {
+            let mut fmt_field = |fill, tab| {
             let field_name = field.name(ctx.db);
 
             if let Some(local) = ctx.locals.get(&field_name)
@@ -39,12 +42,12 @@ pub(crate) fn render_record_lit(
                 f(&format_args!("{}: {fill}", field_name.display(ctx.db, ctx.edition)))
             }
         };
-        if snippet_cap.is_some() {
-            fmt_field(format_args!("${{{}:()}}", idx + 1), format_args!("${}", idx + 1))
-        } else {
-            fmt_field(format_args!("()"), format_args!(""))
-        }
-    });
+            if snippet_cap.is_some() {
+                fmt_field(format_args!("${{{}:()}}", idx + 1), format_args!("${}", idx + 1))
+            } else {
+                fmt_field(format_args!("()"), format_args!(""))
+            }
+        });
 
     let types = fields.iter().format_with(", ", |field, f| {
         f(&format_args!(
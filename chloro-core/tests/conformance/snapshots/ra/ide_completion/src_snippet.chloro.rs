@@ -140,8 +140,16 @@ impl Snippet {
         }
         let (requires, snippet, description) = validate_snippet(snippet, description, requires)?;
         Some(Snippet {
-            postfix_triggers: postfix_triggers.iter().map(String::as_str).map(Into::into).collect(),
-            prefix_triggers: prefix_triggers.iter().map(String::as_str).map(Into::into).collect(),
+            postfix_triggers: postfix_triggers
+                .iter()
+                .map(String::as_str)
+                .map(Into::into)
+                .collect(),
+            prefix_triggers: prefix_triggers
+                .iter()
+                .map(String::as_str)
+                .map(Into::into)
+                .collect(),
             scope,
             snippet,
             description,
@@ -160,7 +160,9 @@ pub(crate) fn position(
     let change_fixture = ChangeFixture::parse(&database, ra_fixture);
     database.enable_proc_attr_macros();
     database.apply_change(change_fixture.change);
-    let (file_id, range_or_offset) = change_fixture.file_position.expect("expected a marker ($0)");
+    let (file_id, range_or_offset) = change_fixture
+        .file_position
+        .expect("expected a marker ($0)");
     let offset = range_or_offset.expect_offset();
     let position = FilePosition { file_id: file_id.file_id(&database), offset };
     (database, position)
@@ -189,11 +191,7 @@ fn render_completion_list(completions: Vec<CompletionItem>) -> String {
     let label_width = completions
         .iter()
         .map(|it| {
-            monospace_width(&it.label.primary)
-                + monospace_width(it.label.detail_left.as_deref().unwrap_or_default())
-                + monospace_width(it.label.detail_right.as_deref().unwrap_or_default())
-                + it.label.detail_left.is_some() as usize
-                + it.label.detail_right.is_some() as usize
+            monospace_width(&it.label.primary) + monospace_width(it.label.detail_left.as_deref().unwrap_or_default()) + monospace_width(it.label.detail_right.as_deref().unwrap_or_default()) + it.label.detail_left.is_some() as usize + it.label.detail_right.is_some() as usize
         })
         .max()
         .unwrap_or_default();
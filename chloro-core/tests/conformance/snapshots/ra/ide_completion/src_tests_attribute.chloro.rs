@@ -133,9 +133,7 @@ struct Foo;
 
 #[test]
 fn with_existing_attr() {
-    check(
-        r#"#[no_mangle] #[$0] mcall!();"#,
-        expect![[r#"
+    check(r#"#[no_mangle] #[$0] mcall!();"#, expect![[r#"
             at allow(…)
             at cfg(…)
             at cfg_attr(…)
@@ -145,8 +143,7 @@ fn with_existing_attr() {
             at warn(…)
             kw crate::
             kw self::
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
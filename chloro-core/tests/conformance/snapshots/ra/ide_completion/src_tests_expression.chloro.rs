@@ -41,6 +41,7 @@ fn baz() {
     let foo = create_foo(&$0);
 }
             "#,
+        // This should not contain `FooDesc {…}`.
         expect![[r#"
             ct CONST                   Unit
             en Enum                    Enum
@@ -425,9 +426,7 @@ fn completes_in_loop_ctx() {
 
 #[test]
 fn completes_in_let_initializer() {
-    check(
-        r#"fn main() { let _ = $0 }"#,
-        expect![[r#"
+    check(r#"fn main() { let _ = $0 }"#, expect![[r#"
             fn main() fn()
             bt u32     u32
             kw const
@@ -444,8 +443,7 @@ fn completes_in_let_initializer() {
             kw unsafe
             kw while
             kw while let
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
@@ -687,9 +685,7 @@ fn completes_after_ref_expr() {
             kw while let
         "#]],
     );
-    check(
-        r#"fn main() { let _ = &mut $0 }"#,
-        expect![[r#"
+    check(r#"fn main() { let _ = &mut $0 }"#, expect![[r#"
             fn main() fn()
             bt u32     u32
             kw const
@@ -706,8 +702,7 @@ fn completes_after_ref_expr() {
             kw unsafe
             kw while
             kw while let
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
@@ -114,9 +114,13 @@ fn outer(text: &str) {
 
 #[test]
 fn trigger_by_l_paren() {
-    check_with_trigger_character(r#"
+    check_with_trigger_character(
+        r#"
 fn foo($0)
-"#, Some('('), expect![[]])
+"#,
+        Some('('),
+        expect![[]],
+    )
 }
 
 #[test]
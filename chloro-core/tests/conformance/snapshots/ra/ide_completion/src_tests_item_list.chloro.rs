@@ -6,9 +6,7 @@ use crate::tests::{check, check_edit, check_with_base_items};
 
 #[test]
 fn in_mod_item_list() {
-    check_with_base_items(
-        r#"mod tests { $0 }"#,
-        expect![[r#"
+    check_with_base_items(r#"mod tests { $0 }"#, expect![[r#"
             ma makro!(…) macro_rules! makro
             kw async
             kw const
@@ -34,15 +32,12 @@ fn in_mod_item_list() {
             sn macro_rules
             sn tfn (Test function)
             sn tmod (Test module)
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
 fn in_source_file_item_list() {
-    check_with_base_items(
-        r#"$0"#,
-        expect![[r#"
+    check_with_base_items(r#"$0"#, expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
             kw async
@@ -68,15 +63,12 @@ fn in_source_file_item_list() {
             sn macro_rules
             sn tfn (Test function)
             sn tmod (Test module)
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
 fn in_item_list_after_attr() {
-    check_with_base_items(
-        r#"#[attr] $0"#,
-        expect![[r#"
+    check_with_base_items(r#"#[attr] $0"#, expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
             kw async
@@ -102,19 +94,15 @@ fn in_item_list_after_attr() {
             sn macro_rules
             sn tfn (Test function)
             sn tmod (Test module)
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
 fn in_qualified_path() {
-    check_with_base_items(
-        r#"crate::$0"#,
-        expect![[r#"
+    check_with_base_items(r#"crate::$0"#, expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
@@ -179,9 +167,7 @@ fn after_visibility_unsafe() {
 
 #[test]
 fn in_impl_assoc_item_list() {
-    check_with_base_items(
-        r#"impl Struct { $0 }"#,
-        expect![[r#"
+    check_with_base_items(r#"impl Struct { $0 }"#, expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
             kw async
@@ -193,15 +179,12 @@ fn in_impl_assoc_item_list() {
             kw pub(super)
             kw self::
             kw unsafe
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
 fn in_impl_assoc_item_list_after_attr() {
-    check_with_base_items(
-        r#"impl Struct { #[attr] $0 }"#,
-        expect![[r#"
+    check_with_base_items(r#"impl Struct { #[attr] $0 }"#, expect![[r#"
             ma makro!(…) macro_rules! makro
             md module
             kw async
@@ -213,8 +196,7 @@ fn in_impl_assoc_item_list_after_attr() {
             kw pub(super)
             kw self::
             kw unsafe
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
@@ -570,13 +552,10 @@ fn inside_extern_blocks() {
         "#]],
     );
 
-    check_with_base_items(
-        r#"unsafe extern { pub unsafe $0 }"#,
-        expect![[r#"
+    check_with_base_items(r#"unsafe extern { pub unsafe $0 }"#, expect![[r#"
             kw fn
             kw static
-        "#]],
-    )
+        "#]])
 }
 
 #[test]
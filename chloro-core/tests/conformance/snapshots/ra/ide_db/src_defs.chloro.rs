@@ -26,6 +26,7 @@ use syntax::{
     match_ast,
 };
 
+// FIXME: a more precise name would probably be `Symbol`?
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum Definition {
     Macro(Macro),
@@ -59,7 +60,8 @@ impl Definition {
         &self,
         db: &RootDatabase,
     ) -> Option<impl Iterator<Item = Module>> {
-        self.module(db).map(|it| it.path_to_root(db).into_iter().rev())
+        self.module(db)
+            .map(|it| it.path_to_root(db).into_iter().rev())
     }
 
     pub fn krate(&self, db: &RootDatabase) -> Option<Crate> {
@@ -207,7 +209,8 @@ impl Definition {
         famous_defs: Option<&FamousDefs<'_, '_>>,
         display_target: DisplayTarget,
     ) -> Option<Documentation> {
-        self.docs_with_rangemap(db, famous_defs, display_target).map(|(docs, _)| docs)
+        self.docs_with_rangemap(db, famous_defs, display_target)
+            .map(|(docs, _)| docs)
     }
 
     pub fn docs_with_rangemap(
@@ -374,10 +377,13 @@ pub fn find_std_module(
     let std_crate = famous_defs.std()?;
     let std_root_module = std_crate.root_module();
     std_root_module.children(db).find(|module| {
-        module.name(db).is_some_and(|module| module.display(db, edition).to_string() == name)
+        module
+            .name(db)
+            .is_some_and(|module| module.display(db, edition).to_string() == name)
     })
 }
 
+// FIXME: IdentClass as a name no longer fits
 #[derive(Debug)]
 pub enum IdentClass<'db> {
     NameClass(NameClass<'db>),
@@ -639,15 +645,15 @@ impl<'db> NameClass<'db> {
         let parent = lifetime.syntax().parent()?;
 
         if let Some(it) = ast::LifetimeParam::cast(parent.clone()) {
-            sema.to_def(&it).map(Into::into).map(Definition::GenericParam)
+            sema.to_def(&it)
+                .map(Into::into)
+                .map(Definition::GenericParam)
         } else if let Some(it) = ast::Label::cast(parent) {
             sema.to_def(&it).map(Definition::Label)
         } else {
             None
         }
-        .map(
-            NameClass::Definition,
-        )
+        .map(NameClass::Definition)
     }
 }
 
@@ -673,21 +679,24 @@ impl OperatorClass {
         sema: &Semantics<'_, RootDatabase>,
         range_expr: &ast::RangeExpr,
     ) -> Option<OperatorClass> {
-        sema.resolve_range_expr(range_expr).map(OperatorClass::Range)
+        sema.resolve_range_expr(range_expr)
+            .map(OperatorClass::Range)
     }
 
     pub fn classify_await(
         sema: &Semantics<'_, RootDatabase>,
         await_expr: &ast::AwaitExpr,
     ) -> Option<OperatorClass> {
-        sema.resolve_await_to_poll(await_expr).map(OperatorClass::Await)
+        sema.resolve_await_to_poll(await_expr)
+            .map(OperatorClass::Await)
     }
 
     pub fn classify_prefix(
         sema: &Semantics<'_, RootDatabase>,
         prefix_expr: &ast::PrefixExpr,
     ) -> Option<OperatorClass> {
-        sema.resolve_prefix_expr(prefix_expr).map(OperatorClass::Prefix)
+        sema.resolve_prefix_expr(prefix_expr)
+            .map(OperatorClass::Prefix)
     }
 
     pub fn classify_try(
@@ -701,7 +710,8 @@ impl OperatorClass {
         sema: &Semantics<'_, RootDatabase>,
         index_expr: &ast::IndexExpr,
     ) -> Option<OperatorClass> {
-        sema.resolve_index_expr(index_expr).map(OperatorClass::Index)
+        sema.resolve_index_expr(index_expr)
+            .map(OperatorClass::Index)
     }
 
     pub fn classify_bin(
@@ -731,10 +741,7 @@ pub enum NameRefClass<'db> {
     /// ```rs
     /// extern crate foo;
     /// ```
-    ExternCrateShorthand {
-        decl: ExternCrateDecl,
-        krate: Crate,
-    },
+    ExternCrateShorthand { decl: ExternCrateDecl, krate: Crate },
 }
 
 impl<'db> NameRefClass<'db> {
@@ -870,8 +877,7 @@ impl<'db> NameRefClass<'db> {
         }
         let parent = lifetime.syntax().parent()?;
         match parent.kind() {
-            SyntaxKind::BREAK_EXPR | SyntaxKind::CONTINUE_EXPR => sema
-                .resolve_label(lifetime)
+            SyntaxKind::BREAK_EXPR | SyntaxKind::CONTINUE_EXPR => sema.resolve_label(lifetime)
                 .map(Definition::Label)
                 .map(|it| NameRefClass::Definition(it, None)),
             SyntaxKind::LIFETIME_ARG
@@ -879,8 +885,7 @@ impl<'db> NameRefClass<'db> {
             | SyntaxKind::SELF_PARAM
             | SyntaxKind::TYPE_BOUND
             | SyntaxKind::WHERE_PRED
-            | SyntaxKind::REF_TYPE => sema
-                .resolve_lifetime_param(lifetime)
+            | SyntaxKind::REF_TYPE => sema.resolve_lifetime_param(lifetime)
                 .map(GenericParam::LifetimeParam)
                 .map(Definition::GenericParam)
                 .map(|it| NameRefClass::Definition(it, None)),
@@ -60,7 +60,10 @@ pub struct DocsRangeMap {
 impl DocsRangeMap {
     /// Maps a [`TextRange`] relative to the documentation string back to its AST range
     pub fn map(&self, range: TextRange) -> Option<(InFile<TextRange>, AttrId)> {
-        let found = self.mapping.binary_search_by(|(probe, ..)| probe.ordering(range)).ok()?;
+        let found = self
+            .mapping
+            .binary_search_by(|(probe, ..)| probe.ordering(range))
+            .ok()?;
         let (line_docs_range, idx, original_line_src_range) = self.mapping[found];
         if !line_docs_range.contains_range(range) {
             return None;
@@ -77,7 +80,15 @@ impl DocsRangeMap {
                     text_range.end() + original_line_src_range.start() + relative_range.start(),
                     string.syntax().text_range().len().min(range.len()),
                 );
-                Some((InFile { file_id, value: range }, idx))
+                Some(
+                    (
+                        InFile {
+                        file_id,
+                        value: range,
+                    },
+                        idx,
+                    ),
+                )
             }
             Either::Right(comment) => {
                 let text_range = comment.syntax().text_range();
@@ -88,7 +99,15 @@ impl DocsRangeMap {
                         + relative_range.start(),
                     text_range.len().min(range.len()),
                 );
-                Some((InFile { file_id, value: range }, idx))
+                Some(
+                    (
+                        InFile {
+                        file_id,
+                        value: range,
+                    },
+                        idx,
+                    ),
+                )
             }
         }
     }
@@ -102,7 +121,10 @@ impl DocsRangeMap {
                 (buf_offset, id, base_offset)
             })
             .collect_vec();
-        DocsRangeMap { source_map: self.source_map, mapping }
+        DocsRangeMap {
+            source_map: self.source_map,
+            mapping,
+        }
     }
 }
 
@@ -148,12 +170,23 @@ pub fn docs_with_rangemap(
     if buf.is_empty() {
         None
     } else {
-        Some((Documentation(buf), DocsRangeMap { mapping, source_map: attrs.source_map(db) }))
+        Some(
+            (
+                Documentation(buf),
+                DocsRangeMap {
+                mapping,
+                source_map: attrs.source_map(db),
+            },
+            ),
+        )
     }
 }
 
 pub fn docs_from_attrs(attrs: &hir::Attrs) -> Option<String> {
-    let docs = attrs.by_key(sym::doc).attrs().filter_map(|attr| attr.string_value_unescape());
+    let docs = attrs
+        .by_key(sym::doc)
+        .attrs()
+        .filter_map(|attr| attr.string_value_unescape());
     let indent = doc_indent(attrs);
     let mut buf = String::new();
     for doc in docs {
@@ -281,9 +314,7 @@ impl HasDocs for hir::ExternCrateDecl {
                 Some(decl_docs)
             }
         }
-        .map(
-            Documentation::new,
-        )
+        .map(Documentation::new)
     }
 
     fn docs_with_rangemap(self, db: &dyn HirDatabase) -> Option<(Documentation, DocsRangeMap)> {
@@ -79,7 +79,10 @@ pub fn visit_file_defs(
         }
         cb(def.into());
     }
-    module.impl_defs(db).into_iter().for_each(|impl_| cb(impl_.into()));
+    module
+        .impl_defs(db)
+        .into_iter()
+        .for_each(|impl_| cb(impl_.into()));
 
     let is_root = module.is_crate_root();
     module
@@ -227,7 +227,12 @@ impl LocatedImport {
         original_item: ItemInNs,
         complete_in_flyimport: CompleteInFlyimport,
     ) -> Self {
-        Self { import_path, item_to_import, original_item, complete_in_flyimport }
+        Self {
+            import_path,
+            item_to_import,
+            original_item,
+            complete_in_flyimport,
+        }
     }
 
     pub fn new_no_completion(
@@ -791,7 +796,9 @@ fn path_import_candidate<'db>(
     Some(match qualifier {
         Some(qualifier) => match sema.resolve_path(&qualifier) {
             Some(PathResolution::Def(ModuleDef::BuiltinType(_))) | None => {
-                if qualifier.first_qualifier().is_none_or(|it| sema.resolve_path(&it).is_none()) {
+                if qualifier
+                    .first_qualifier()
+                    .is_none_or(|it| sema.resolve_path(&it).is_none()) {
                     let qualifier = qualifier
                         .segments()
                         .map(|seg| seg.name_ref().map(|name| Name::new_root(&name.text())))
@@ -820,7 +827,10 @@ fn path_import_candidate<'db>(
             }
             Some(_) => return None,
         },
-        None => ImportCandidate::Path(PathImportCandidate { qualifier: vec![], name }),
+        None => ImportCandidate::Path(PathImportCandidate {
+            qualifier: vec![],
+            name,
+        }),
     })
 }
 
@@ -137,7 +137,10 @@ impl ImportScope {
                 }
                 ImportScopeKind::Block(block) => ImportScopeKind::Block(block.clone_for_update()),
             },
-            required_cfgs: self.required_cfgs.iter().map(|attr| attr.clone_for_update()).collect(),
+            required_cfgs: self.required_cfgs
+                .iter()
+                .map(|attr| attr.clone_for_update())
+                .collect(),
         }
     }
 }
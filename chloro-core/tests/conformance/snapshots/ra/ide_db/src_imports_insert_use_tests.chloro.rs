@@ -424,9 +424,13 @@ use self::fmt;",
 
 #[test]
 fn insert_no_imports() {
-    check_crate("foo::bar", "fn main() {}", r"use foo::bar;
+    check_crate(
+        "foo::bar",
+        "fn main() {}",
+        r"use foo::bar;
 
-fn main() {}")
+fn main() {}",
+    )
 }
 
 #[test]
@@ -944,29 +948,24 @@ fn merge_mod_into_glob() {
         r"use token::TokenKind::*;",
         r"use token::TokenKind::{self, *};",
         &InsertUseConfig {
-        granularity: ImportGranularity::Crate,
-        enforce_granularity: true,
-        prefix_kind: PrefixKind::Plain,
-        group: false,
-        skip_glob_imports: false,
-    },
+            granularity: ImportGranularity::Crate,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: false,
+            skip_glob_imports: false,
+        },
     )
 }
 
 #[test]
 fn merge_self_glob() {
-    check_with_config(
-        "self",
-        r"use self::*;",
-        r"use self::{self, *};",
-        &InsertUseConfig {
+    check_with_config("self", r"use self::*;", r"use self::{self, *};", &InsertUseConfig {
         granularity: ImportGranularity::Crate,
         enforce_granularity: true,
         prefix_kind: PrefixKind::Plain,
         group: false,
         skip_glob_imports: false,
-    },
-    )
+    })
 }
 
 #[test]
@@ -1354,7 +1353,12 @@ fn check_with_config(
         .unwrap();
 
     insert_use(&file, path, config);
-    let result = file.as_syntax_node().ancestors().last().unwrap().to_string();
+    let result = file
+        .as_syntax_node()
+        .ancestors()
+        .last()
+        .unwrap()
+        .to_string();
     assert_eq_text!(&trim_indent(ra_fixture_after), &result);
 }
 
@@ -1364,18 +1368,13 @@ fn check(
     #[rust_analyzer::rust_fixture] ra_fixture_after: &str,
     granularity: ImportGranularity,
 ) {
-    check_with_config(
-        path,
-        ra_fixture_before,
-        ra_fixture_after,
-        &InsertUseConfig {
-            granularity,
-            enforce_granularity: true,
-            prefix_kind: PrefixKind::Plain,
-            group: true,
-            skip_glob_imports: true,
-        },
-    )
+    check_with_config(path, ra_fixture_before, ra_fixture_after, &InsertUseConfig {
+        granularity,
+        enforce_granularity: true,
+        prefix_kind: PrefixKind::Plain,
+        group: true,
+        skip_glob_imports: true,
+    })
 }
 
 fn check_crate(
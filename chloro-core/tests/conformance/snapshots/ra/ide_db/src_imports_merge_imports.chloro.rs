@@ -779,7 +779,11 @@ mod version_sort {
             let zeros = source.chars().take_while(|c| *c == '0').count();
             let value = source.parse::<usize>().ok()?;
 
-            Some(VersionChunk::Number { value, zeros, source })
+            Some(VersionChunk::Number {
+                value,
+                zeros,
+                source,
+            })
         }
 
         fn parse_str_chunk(
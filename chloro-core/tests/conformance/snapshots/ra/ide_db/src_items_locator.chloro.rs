@@ -111,15 +111,12 @@ pub fn items_with_name_in_module<T>(
             local_query
         }
     };
-    local_query.search(
-        &[SymbolIndex::module_symbols(db, module)],
-        |local_candidate| {
+    local_query.search(&[SymbolIndex::module_symbols(db, module)], |local_candidate| {
         cb(match local_candidate.def {
             hir::ModuleDef::Macro(macro_def) => ItemInNs::Macros(macro_def),
             def => ItemInNs::from(def),
         })
-    },
-    )
+    })
 }
 
 fn find_items(
@@ -346,7 +346,11 @@ impl<'a> Ranker<'a> {
 
     pub fn from_token(token: &'a syntax::SyntaxToken) -> Self {
         let kind = token.kind();
-        Ranker { kind, text: token.text(), ident_kind: kind.is_any_identifier() }
+        Ranker {
+            kind,
+            text: token.text(),
+            ident_kind: kind.is_any_identifier(),
+        }
     }
 
     /// A utility function that ranks a token again a given kind and text, returning a number that
@@ -141,7 +141,10 @@ impl<'a> PathTransform<'a> {
         nodes: impl IntoIterator<Item = &'b SyntaxNode>,
     ) -> Vec<SyntaxNode> {
         let ctx = self.build_ctx();
-        nodes.into_iter().map(|node| ctx.apply(&node.clone())).collect()
+        nodes
+            .into_iter()
+            .map(|node| ctx.apply(&node.clone()))
+            .collect()
     }
 
     fn prettify_target_node(&self, node: SyntaxNode) -> SyntaxNode {
@@ -281,12 +284,15 @@ impl Ctx<'_> {
         // so that such operation is safe.
         let item = self.transform_path(item).clone_subtree();
         let mut editor = SyntaxEditor::new(item.clone());
-        preorder_rev(&item).filter_map(ast::Lifetime::cast).for_each(|lifetime| {
-            if let Some(subst) = self.lifetime_substs.get(&lifetime.syntax().text().to_string()) {
-                editor
+        preorder_rev(&item)
+            .filter_map(ast::Lifetime::cast)
+            .for_each(|lifetime| {
+                if let Some(subst) = self.lifetime_substs
+                    .get(&lifetime.syntax().text().to_string()) {
+                    editor
                     .replace(lifetime.syntax(), subst.clone_subtree().clone_for_update().syntax());
-            }
-        });
+                }
+            });
 
         editor.finish().new_root().clone()
     }
@@ -306,7 +312,8 @@ impl Ctx<'_> {
             let new_value = self.transform_path(value);
             match param {
                 Either::Left(k) => {
-                    self.type_substs.insert(k, ast::Type::cast(new_value.clone()).unwrap());
+                    self.type_substs
+                        .insert(k, ast::Type::cast(new_value.clone()).unwrap());
                 }
                 Either::Right(k) => {
                     self.const_substs.insert(k, new_value.clone());
@@ -554,7 +561,9 @@ impl Ctx<'_> {
                     prefer_absolute: false,
                     allow_unstable: true,
                 };
-                let found_path = self.target_module.find_path(self.source_scope.db, def, cfg)?;
+                let found_path = self
+                    .target_module
+                    .find_path(self.source_scope.db, def, cfg)?;
                 let res = mod_path_to_ast(&found_path, self.target_edition).clone_for_update();
                 editor.replace(ident_pat.syntax(), res.syntax());
                 Some(())
@@ -579,7 +588,9 @@ fn get_syntactic_substs(impl_def: ast::Impl) -> Option<AstSubsts> {
 
 fn get_type_args_from_arg_list(generic_arg_list: ast::GenericArgList) -> Option<AstSubsts> {
     let mut result = AstSubsts::default();
-    generic_arg_list.generic_args().for_each(|generic_arg| match generic_arg {
+    generic_arg_list
+        .generic_args()
+        .for_each(|generic_arg| match generic_arg {
         // Const params are marked as consts on definition only,
         // being passed to the trait they are indistguishable from type params;
         // anyway, we don't really need to distinguish them here.
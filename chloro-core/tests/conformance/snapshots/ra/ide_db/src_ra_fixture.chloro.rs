@@ -257,7 +257,9 @@ impl RaFixtureAnalysis {
     }
 
     pub fn map_offset_up(&self, virtual_file: FileId, offset: TextSize) -> Option<TextSize> {
-        self.map_range_up(virtual_file, TextRange::empty(offset)).next().map(|range| range.start())
+        self.map_range_up(virtual_file, TextRange::empty(offset))
+            .next()
+            .map(|range| range.start())
     }
 
     pub fn is_sysroot_file(&self, file_id: FileId) -> bool {
@@ -371,6 +373,7 @@ impl<K: UpmapFromRaFixture + Hash + Eq, V: UpmapFromRaFixture, S: BuildHasher +
     }
 }
 
+// A map of `FileId`s is treated as associating the ranges in the values with the keys.
 #[allow(clippy::disallowed_types)]
 impl<V: UpmapFromRaFixture, S: BuildHasher + Default> UpmapFromRaFixture for std::collections::HashMap<FileId, V, S> {
     fn upmap_from_ra_fixture(
@@ -385,10 +388,14 @@ impl<V: UpmapFromRaFixture, S: BuildHasher + Default> UpmapFromRaFixture for std
         let result = self
             .into_iter()
             .filter_map(|(virtual_file_id, value)| {
-                Some((
-                    real_file_id,
-                    value.upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id).ok()?,
-                ))
+                Some(
+                    (
+                        real_file_id,
+                        value
+                        .upmap_from_ra_fixture(analysis, virtual_file_id, real_file_id)
+                        .ok()?,
+                    ),
+                )
             })
             .collect::<std::collections::HashMap<_, _, _>>();
         if result.is_empty() {
@@ -445,7 +452,10 @@ impl UpmapFromRaFixture for TextRange {
         virtual_file_id: FileId,
         _real_file_id: FileId,
     ) -> Result<Self, ()> {
-        analysis.map_range_up(virtual_file_id, self).next().ok_or(())
+        analysis
+            .map_range_up(virtual_file_id, self)
+            .next()
+            .ok_or(())
     }
 }
 
@@ -471,7 +481,8 @@ impl UpmapFromRaFixture for FilePositionWrapper<FileId> {
     ) -> Result<Self, ()> {
         Ok(FilePositionWrapper {
             file_id: real_file_id,
-            offset: self.offset.upmap_from_ra_fixture(analysis, self.file_id, real_file_id)?,
+            offset: self.offset
+                .upmap_from_ra_fixture(analysis, self.file_id, real_file_id)?,
         })
     }
 }
@@ -485,7 +496,8 @@ impl UpmapFromRaFixture for FileRangeWrapper<FileId> {
     ) -> Result<Self, ()> {
         Ok(FileRangeWrapper {
             file_id: real_file_id,
-            range: self.range.upmap_from_ra_fixture(analysis, self.file_id, real_file_id)?,
+            range: self.range
+                .upmap_from_ra_fixture(analysis, self.file_id, real_file_id)?,
         })
     }
 }
@@ -25,7 +25,8 @@ impl RangeMapper {
     fn add_impl(&mut self, text: &str, source: Option<TextSize>) {
         let len = TextSize::of(text);
         let target_range = TextRange::at(TextSize::of(&self.buf), len);
-        self.ranges.push((target_range, source.map(|it| TextRange::at(it, len))));
+        self.ranges
+            .push((target_range, source.map(|it| TextRange::at(it, len))));
         self.buf.push_str(text);
     }
 
@@ -34,16 +35,13 @@ impl RangeMapper {
     }
 
     pub fn map_range_up(&self, range: TextRange) -> impl Iterator<Item = TextRange> + '_ {
-        equal_range_by(
-            &self.ranges,
-            |&(r, _)| {
+        equal_range_by(&self.ranges, |&(r, _)| {
             if range.is_empty() && r.contains(range.start()) {
                 Ordering::Equal
             } else {
                 TextRange::ordering(r, range)
             }
-        },
-        )
+        })
         .filter_map(move |i| {
             let (target_range, source_range) = self.ranges[i];
             let intersection = target_range.intersect(range).unwrap();
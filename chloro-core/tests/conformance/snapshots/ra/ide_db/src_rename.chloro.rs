@@ -235,7 +235,8 @@ impl Definition {
         {
             let src = sema.source(def)?;
             let name = src.value.name()?;
-            src.with_value(name.syntax()).original_file_range_opt(sema.db)
+            src.with_value(name.syntax())
+                .original_file_range_opt(sema.db)
         }
     }
 }
@@ -46,12 +46,15 @@ impl UsageSearchResult {
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (EditionedFileId, &[FileReference])> + '_ {
-        self.references.iter().map(|(&file_id, refs)| (file_id, &**refs))
+        self.references
+            .iter()
+            .map(|(&file_id, refs)| (file_id, &**refs))
     }
 
     pub fn file_ranges(&self) -> impl Iterator<Item = FileRange> + '_ {
         self.references.iter().flat_map(|(&file_id, refs)| {
-            refs.iter().map(move |&FileReference { range, .. }| FileRange { file_id, range })
+            refs.iter()
+                .map(move |&FileReference { range, .. }| FileRange { file_id, range })
         })
     }
 }
@@ -1075,9 +1078,7 @@ impl<'a> FindUsages<'a> {
 
                 for offset in Self::match_indices(text, finder, search_range) {
                     for name_ref in Self::find_nodes(sema, "self", file_id, &tree, offset)
-                    .filter_map(
-                        ast::NameRef::cast,
-                    ) {
+                        .filter_map(ast::NameRef::cast) {
                         if self.found_self_module_name_ref(&name_ref, sink) {
                             return;
                         }
@@ -1185,7 +1186,9 @@ impl<'a> FindUsages<'a> {
         sink: &mut dyn FnMut(EditionedFileId, FileReference) -> bool,
     ) -> bool {
         match NameRefClass::classify(self.sema, name_ref) {
-            Some(NameRefClass::Definition(def, _)) if self.def == def || matches!(self.assoc_item_container, Some(hir::AssocItemContainer::Trait(_))) && convert_to_def_in_trait(self.sema.db, def) == self.def => {
+            Some(NameRefClass::Definition(def, _)) if self.def == def
+                // is our def a trait assoc item? then we want to find all assoc items from trait impls of our trait
+                || matches!(self.assoc_item_container, Some(hir::AssocItemContainer::Trait(_))) && convert_to_def_in_trait(self.sema.db, def) == self.def => {
                 let FileRange { file_id, range } = self.sema.original_range(name_ref.syntax());
                 let reference = FileReference {
                     range,
@@ -1196,10 +1199,7 @@ impl<'a> FindUsages<'a> {
             }
             // FIXME: special case type aliases, we can't filter between impl and trait defs here as we lack the substitutions
             // so we always resolve all assoc type aliases to both their trait def and impl defs
-            Some(NameRefClass::Definition(def, _)) if self.assoc_item_container.is_some()
-                    && matches!(self.def, Definition::TypeAlias(_))
-                    && convert_to_def_in_trait(self.sema.db, def)
-                        == convert_to_def_in_trait(self.sema.db, self.def) => {
+            Some(NameRefClass::Definition(def, _)) if self.assoc_item_container.is_some() && matches!(self.def, Definition::TypeAlias(_)) && convert_to_def_in_trait(self.sema.db, def) == convert_to_def_in_trait(self.sema.db, self.def) => {
                 let FileRange { file_id, range } = self.sema.original_range(name_ref.syntax());
                 let reference = FileReference {
                     range,
@@ -1339,8 +1339,11 @@ impl ReferenceCategory {
             return result;
         }
 
-        let mode = r.syntax().ancestors().find_map(|node| {
-            match_ast! {
+        let mode = r
+            .syntax()
+            .ancestors()
+            .find_map(|node| {
+                match_ast! {
                 match node {
                     ast::BinExpr(expr) => {
                         if matches!(expr.op_kind()?, ast::BinaryOp::Assignment { .. }) {
@@ -1356,7 +1359,8 @@ impl ReferenceCategory {
                     _ => None,
                 }
             }
-        }).unwrap_or(ReferenceCategory::READ);
+            })
+            .unwrap_or(ReferenceCategory::READ);
 
         result | mode
     }
@@ -1372,7 +1376,10 @@ fn is_name_ref_in_import(name_ref: &ast::NameRef) -> bool {
 }
 
 fn is_name_ref_in_test(sema: &Semantics<'_, RootDatabase>, name_ref: &ast::NameRef) -> bool {
-    name_ref.syntax().ancestors().any(|node| match ast::Fn::cast(node) {
+    name_ref
+        .syntax()
+        .ancestors()
+        .any(|node| match ast::Fn::cast(node) {
         Some(it) => sema.to_def(&it).is_some_and(|func| func.is_test(sema.db)),
         None => false,
     })
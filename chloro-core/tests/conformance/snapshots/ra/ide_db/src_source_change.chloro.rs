@@ -114,7 +114,10 @@ impl SourceChange {
 
 impl Extend<(FileId, TextEdit)> for SourceChange {
     fn extend<T: IntoIterator<Item = (FileId, TextEdit)>>(&mut self, iter: T) {
-        self.extend(iter.into_iter().map(|(file_id, edit)| (file_id, (edit, None))))
+        self.extend(
+            iter.into_iter()
+                .map(|(file_id, edit)| (file_id, (edit, None))),
+        )
     }
 }
 
@@ -123,15 +126,17 @@ impl Extend<(FileId, (TextEdit, Option<SnippetEdit>))> for SourceChange {
         &mut self,
         iter: T,
     ) {
-        iter.into_iter().for_each(|(file_id, (edit, snippet_edit))| {
-            self.insert_source_and_snippet_edit(file_id, edit, snippet_edit)
-        });
+        iter.into_iter()
+            .for_each(|(file_id, (edit, snippet_edit))| {
+                self.insert_source_and_snippet_edit(file_id, edit, snippet_edit)
+            });
     }
 }
 
 impl Extend<FileSystemEdit> for SourceChange {
     fn extend<T: IntoIterator<Item = FileSystemEdit>>(&mut self, iter: T) {
-        iter.into_iter().for_each(|edit| self.push_file_system_edit(edit));
+        iter.into_iter()
+            .for_each(|edit| self.push_file_system_edit(edit));
     }
 }
 
@@ -247,7 +252,10 @@ impl TreeMutator {
     pub fn new(immutable: &SyntaxNode) -> TreeMutator {
         let immutable = immutable.ancestors().last().unwrap();
         let mutable_clone = immutable.clone_for_update();
-        TreeMutator { immutable, mutable_clone }
+        TreeMutator {
+            immutable,
+            mutable_clone,
+        }
     }
 
     pub fn make_mut<N: AstNode>(&self, node: &N) -> N {
@@ -350,7 +358,11 @@ impl SourceChangeBuilder {
         // Apply mutable edits
         let snippet_edit = self.snippet_builder.take().map(|builder| {
             SnippetEdit::new(
-                builder.places.into_iter().flat_map(PlaceSnippet::finalize_position).collect(),
+                builder
+                    .places
+                    .into_iter()
+                    .flat_map(PlaceSnippet::finalize_position)
+                    .collect(),
             )
         });
 
@@ -360,12 +372,15 @@ impl SourceChangeBuilder {
 
         let edit = mem::take(&mut self.edit).finish();
         if !edit.is_empty() || snippet_edit.is_some() {
-            self.source_change.insert_source_and_snippet_edit(self.file_id, edit, snippet_edit);
+            self.source_change
+                .insert_source_and_snippet_edit(self.file_id, edit, snippet_edit);
         }
     }
 
     pub fn make_mut<N: AstNode>(&mut self, node: N) -> N {
-        self.mutated_tree.get_or_insert_with(|| TreeMutator::new(node.syntax())).make_mut(&node)
+        self.mutated_tree
+            .get_or_insert_with(|| TreeMutator::new(node.syntax()))
+            .make_mut(&node)
     }
 
     pub fn make_import_scope_mut(&mut self, scope: ImportScope) -> ImportScope {
@@ -375,7 +390,11 @@ impl SourceChangeBuilder {
                 ImportScopeKind::Module(it) => ImportScopeKind::Module(self.make_mut(it)),
                 ImportScopeKind::Block(it) => ImportScopeKind::Block(self.make_mut(it)),
             },
-            required_cfgs: scope.required_cfgs.iter().map(|it| self.make_mut(it.clone())).collect(),
+            required_cfgs: scope
+                .required_cfgs
+                .iter()
+                .map(|it| self.make_mut(it.clone()))
+                .collect(),
         }
     }
 
@@ -390,7 +409,9 @@ impl SourceChangeBuilder {
     /// phase, and then get their mutable counterparts using `make_mut` in the
     /// mutable state.
     pub fn make_syntax_mut(&mut self, node: SyntaxNode) -> SyntaxNode {
-        self.mutated_tree.get_or_insert_with(|| TreeMutator::new(&node)).make_syntax_mut(&node)
+        self.mutated_tree
+            .get_or_insert_with(|| TreeMutator::new(&node))
+            .make_syntax_mut(&node)
     }
 
     /// Remove specified `range` of text.
@@ -474,13 +495,15 @@ impl SourceChangeBuilder {
     /// through a separate rename step.
     pub fn add_placeholder_snippet_group(&mut self, _cap: SnippetCap, nodes: Vec<SyntaxNode>) {
         assert!(nodes.iter().all(|node| node.parent().is_some()));
-        self.add_snippet(PlaceSnippet::OverGroup(
-            nodes.into_iter().map(|node| node.into()).collect(),
-        ))
+        self.add_snippet(
+            PlaceSnippet::OverGroup(nodes.into_iter().map(|node| node.into()).collect()),
+        )
     }
 
     fn add_snippet(&mut self, snippet: PlaceSnippet) {
-        let snippet_builder = self.snippet_builder.get_or_insert(SnippetBuilder { places: vec![] });
+        let snippet_builder = self
+            .snippet_builder
+            .get_or_insert(SnippetBuilder { places: vec![] });
         snippet_builder.places.push(snippet);
         self.source_change.is_snippet = true;
     }
@@ -515,10 +538,7 @@ pub enum FileSystemEdit {
         dst: AnchoredPathBuf,
         initial_contents: String,
     },
-    MoveFile {
-        src: FileId,
-        dst: AnchoredPathBuf,
-    },
+    MoveFile { src: FileId, dst: AnchoredPathBuf },
     MoveDir {
         src: AnchoredPathBuf,
         src_id: FileId,
@@ -532,6 +552,7 @@ impl From<FileSystemEdit> for SourceChange {
             source_file_edits: Default::default(),
             file_system_edits: vec![edit],
             is_snippet: false,
+            ..SourceChange::default()
         }
     }
 }
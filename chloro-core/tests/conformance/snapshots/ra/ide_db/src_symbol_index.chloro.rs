@@ -120,7 +120,11 @@ pub struct LocalRoots {
 /// The symbol indices of modules that make up a given crate.
 pub fn crate_symbols(db: &dyn HirDatabase, krate: Crate) -> Box<[&SymbolIndex]> {
     let _p = tracing::info_span!("crate_symbols").entered();
-    krate.modules(db).into_iter().map(|module| SymbolIndex::module_symbols(db, module)).collect()
+    krate
+        .modules(db)
+        .into_iter()
+        .map(|module| SymbolIndex::module_symbols(db, module))
+        .collect()
 }
 
 // Feature: Workspace Symbol
@@ -238,11 +242,9 @@ impl SymbolIndex {
             // We call this without attaching because this runs in parallel, so we need to attach here.
             hir::attach_db(db, || {
                 let module: Module = module.id(db).into();
-                SymbolIndex::new(SymbolCollector::new_module(
-                    db,
-                    module,
-                    !module.krate().origin(db).is_local(),
-                ))
+                SymbolIndex::new(
+                    SymbolCollector::new_module(db, module, !module.krate().origin(db).is_local()),
+                )
             })
         }
 
@@ -252,7 +254,9 @@ impl SymbolIndex {
 
 impl fmt::Debug for SymbolIndex {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("SymbolIndex").field("n_symbols", &self.symbols.len()).finish()
+        f.debug_struct("SymbolIndex")
+            .field("n_symbols", &self.symbols.len())
+            .finish()
     }
 }
 
@@ -243,7 +243,8 @@ pub fn lex_format_specifiers(
         chars: &mut std::iter::Peekable<I>,
         emit: FormatSpecifier,
         callback: &mut F,
-    ) where
+    )
+    where
         I: Iterator<Item = (TextRange, char)>,
         F: FnMut(TextRange, FormatSpecifier),
     {
@@ -22,25 +22,32 @@ pub fn expr_as_name_ref(expr: &ast::Expr) -> Option<ast::NameRef> {
 pub fn full_path_of_name_ref(name_ref: &ast::NameRef) -> Option<ast::Path> {
     let mut ancestors = name_ref.syntax().ancestors();
     let _ = ancestors.next()?; // skip self
-    let _ = ancestors.next().filter(|it| ast::PathSegment::can_cast(it.kind()))?; // skip self
-    ancestors.take_while(|it| ast::Path::can_cast(it.kind())).last().and_then(ast::Path::cast)
+    let _ = ancestors
+        .next()
+        .filter(|it| ast::PathSegment::can_cast(it.kind()))?; // skip self
+    ancestors
+        .take_while(|it| ast::Path::can_cast(it.kind()))
+        .last()
+        .and_then(ast::Path::cast)
 }
 
 pub fn block_as_lone_tail(block: &ast::BlockExpr) -> Option<ast::Expr> {
-    block.statements().next().is_none().then(|| block.tail_expr()).flatten()
+    block
+        .statements()
+        .next()
+        .is_none()
+        .then(|| block.tail_expr())
+        .flatten()
 }
 
 /// Preorder walk all the expression's child expressions.
 pub fn walk_expr(expr: &ast::Expr, cb: &mut dyn FnMut(ast::Expr)) {
-    preorder_expr(
-        expr,
-        &mut |ev| {
+    preorder_expr(expr, &mut |ev| {
         if let WalkEvent::Enter(expr) = ev {
             cb(expr);
         }
         false
-    },
-    )
+    })
 }
 
 pub fn is_closure_or_blk_with_modif(expr: &ast::Expr) -> bool {
@@ -234,10 +241,7 @@ pub fn walk_ty(ty: &ast::Type, cb: &mut dyn FnMut(ast::Type) -> bool) {
 pub fn vis_eq(this: &ast::Visibility, other: &ast::Visibility) -> bool {
     match (this.kind(), other.kind()) {
         (VisibilityKind::In(this), VisibilityKind::In(other)) => {
-            stdx::iter_eq_by(
-                this.segments(),
-                other.segments(),
-                |lhs, rhs| {
+            stdx::iter_eq_by(this.segments(), other.segments(), |lhs, rhs| {
                 lhs.kind().zip(rhs.kind()).is_some_and(|it| match it {
                     (PathSegmentKind::CrateKw, PathSegmentKind::CrateKw)
                     | (PathSegmentKind::SelfKw, PathSegmentKind::SelfKw)
@@ -247,8 +251,7 @@ pub fn vis_eq(this: &ast::Visibility, other: &ast::Visibility) -> bool {
                     }
                     _ => false,
                 })
-            },
-            )
+            })
         }
         (VisibilityKind::PubSelf, VisibilityKind::PubSelf)
         | (VisibilityKind::PubSuper, VisibilityKind::PubSuper)
@@ -335,7 +338,9 @@ pub fn for_each_tail_expr(expr: &ast::Expr, cb: &mut dyn FnMut(&ast::Expr)) {
         ast::Expr::ForExpr(f) => walk_loop(cb, f.label(), f.loop_body()),
         ast::Expr::MatchExpr(m) => {
             if let Some(arms) = m.match_arm_list() {
-                arms.arms().filter_map(|arm| arm.expr()).for_each(|e| for_each_tail_expr(&e, cb));
+                arms.arms()
+                    .filter_map(|arm| arm.expr())
+                    .for_each(|e| for_each_tail_expr(&e, cb));
             }
         }
         ast::Expr::ArrayExpr(_)
@@ -381,12 +386,10 @@ pub fn for_each_break_and_continue_expr(
         let tree_depth_iterator = TreeWithDepthIterator::new(b);
         for (expr, depth) in tree_depth_iterator {
             match expr {
-                ast::Expr::BreakExpr(b) if (depth == 0 && b.lifetime().is_none())
-                        || eq_label_lt(&label, &b.lifetime()) => {
+                ast::Expr::BreakExpr(b) if (depth == 0 && b.lifetime().is_none()) || eq_label_lt(&label, &b.lifetime()) => {
                     cb(ast::Expr::BreakExpr(b));
                 }
-                ast::Expr::ContinueExpr(c) if (depth == 0 && c.lifetime().is_none())
-                        || eq_label_lt(&label, &c.lifetime()) => {
+                ast::Expr::ContinueExpr(c) if (depth == 0 && c.lifetime().is_none()) || eq_label_lt(&label, &c.lifetime()) => {
                     cb(ast::Expr::ContinueExpr(c));
                 }
                 _ => (),
@@ -405,8 +408,7 @@ fn for_each_break_expr(
         let tree_depth_iterator = TreeWithDepthIterator::new(b);
         for (expr, depth) in tree_depth_iterator {
             match expr {
-                ast::Expr::BreakExpr(b) if (depth == 0 && b.lifetime().is_none())
-                        || eq_label_lt(&label, &b.lifetime()) => {
+                ast::Expr::BreakExpr(b) if (depth == 0 && b.lifetime().is_none()) || eq_label_lt(&label, &b.lifetime()) => {
                     cb(b);
                 }
                 _ => (),
@@ -416,7 +418,9 @@ fn for_each_break_expr(
 }
 
 pub fn eq_label_lt(lt1: &Option<ast::Lifetime>, lt2: &Option<ast::Lifetime>) -> bool {
-    lt1.as_ref().zip(lt2.as_ref()).is_some_and(|(lt, lbl)| lt.text() == lbl.text())
+    lt1.as_ref()
+        .zip(lt2.as_ref())
+        .is_some_and(|(lt, lbl)| lt.text() == lbl.text())
 }
 
 struct TreeWithDepthIterator {
@@ -497,6 +501,9 @@ pub fn parse_tt_as_comma_sep_paths(
 }
 
 pub fn macro_call_for_string_token(string: &ast::String) -> Option<MacroCall> {
-    let macro_call = string.syntax().parent_ancestors().find_map(ast::MacroCall::cast)?;
+    let macro_call = string
+        .syntax()
+        .parent_ancestors()
+        .find_map(ast::MacroCall::cast)?;
     Some(macro_call)
 }
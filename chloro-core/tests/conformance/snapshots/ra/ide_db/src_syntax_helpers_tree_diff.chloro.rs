@@ -65,15 +65,13 @@ pub fn diff(from: &SyntaxNode, to: &SyntaxNode) -> TreeDiff {
     return diff;
 
     fn syntax_element_eq(lhs: &SyntaxElement, rhs: &SyntaxElement) -> bool {
-        lhs.kind() == rhs.kind()
-            && lhs.text_range().len() == rhs.text_range().len()
-            && match (&lhs, &rhs) {
-                (NodeOrToken::Node(lhs), NodeOrToken::Node(rhs)) => {
-                    lhs == rhs || lhs.text() == rhs.text()
-                }
-                (NodeOrToken::Token(lhs), NodeOrToken::Token(rhs)) => lhs.text() == rhs.text(),
-                _ => false,
+        lhs.kind() == rhs.kind() && lhs.text_range().len() == rhs.text_range().len() && match (&lhs, &rhs) {
+            (NodeOrToken::Node(lhs), NodeOrToken::Node(rhs)) => {
+                lhs == rhs || lhs.text() == rhs.text()
             }
+            (NodeOrToken::Token(lhs), NodeOrToken::Token(rhs)) => lhs.text() == rhs.text(),
+            _ => false,
+        }
     }
 
     // FIXME: this is horribly inefficient. I bet there's a cool algorithm to diff trees properly.
@@ -495,8 +493,14 @@ fn main() {
         )
     }
     fn check_diff(from: &str, to: &str, expected_diff: Expect) {
-        let from_node = SourceFile::parse(from, Edition::CURRENT).tree().syntax().clone();
-        let to_node = SourceFile::parse(to, Edition::CURRENT).tree().syntax().clone();
+        let from_node = SourceFile::parse(from, Edition::CURRENT)
+            .tree()
+            .syntax()
+            .clone();
+        let to_node = SourceFile::parse(to, Edition::CURRENT)
+            .tree()
+            .syntax()
+            .clone();
         let diff = super::diff(&from_node, &to_node);
 
         let line_number =
@@ -203,7 +203,9 @@ impl TextEditBuilder {
     }
 
     pub fn invalidates_offset(&self, offset: TextSize) -> bool {
-        self.indels.iter().any(|indel| indel.delete.contains_inclusive(offset))
+        self.indels
+            .iter()
+            .any(|indel| indel.delete.contains_inclusive(offset))
     }
 
     pub fn indel(&mut self, indel: Indel) {
@@ -227,7 +229,10 @@ fn check_disjoint<'a, I>(indels: &mut I) -> bool
 where
     I: std::iter::Iterator<Item = &'a Indel> + Clone,
 {
-    indels.clone().zip(indels.skip(1)).all(|(l, r)| l.delete.end() <= r.delete.start() || l == r)
+    indels
+        .clone()
+        .zip(indels.skip(1))
+        .all(|(l, r)| l.delete.end() <= r.delete.start() || l == r)
 }
 
 fn coalesce_indels(indels: Vec<Indel>) -> Vec<Indel> {
@@ -19,7 +19,9 @@ macro_rules! format_ty {
 //
 // This diagnostic is triggered if the code contains an illegal cast
 pub(crate) fn invalid_cast(ctx: &DiagnosticsContext<'_>, d: &hir::InvalidCast<'_>) -> Diagnostic {
-    let display_range = ctx.sema.diagnostics_display_range(d.expr.map(|it| it.into()));
+    let display_range = ctx
+        .sema
+        .diagnostics_display_range(d.expr.map(|it| it.into()));
     let (code, message) = match d.error {
         CastError::CastToBool => (
             DiagnosticCode::RustcHardError("E0054"),
@@ -110,7 +112,9 @@ pub(crate) fn cast_to_unsized(
     ctx: &DiagnosticsContext<'_>,
     d: &hir::CastToUnsized<'_>,
 ) -> Diagnostic {
-    let display_range = ctx.sema.diagnostics_display_range(d.expr.map(|it| it.into()));
+    let display_range = ctx
+        .sema
+        .diagnostics_display_range(d.expr.map(|it| it.into()));
     Diagnostic::new(
         DiagnosticCode::RustcHardError("E0620"),
         format_ty!(ctx, "cast to unsized type: `{}`", d.cast_ty),
@@ -15,7 +15,11 @@ pub(crate) fn macro_error(ctx: &DiagnosticsContext<'_>, d: &hir::MacroError) ->
     // Use more accurate position if available.
     let display_range = ctx.resolve_precise_location(&d.node, d.precise_location);
     Diagnostic::new(
-        DiagnosticCode::Ra(d.kind, if d.error { Severity::Error } else { Severity::WeakWarning }),
+        DiagnosticCode::Ra(d.kind, if d.error {
+            Severity::Error
+        } else {
+            Severity::WeakWarning
+        }),
         d.message.clone(),
         display_range,
     )
@@ -213,7 +217,8 @@ fn f() {
     }
     #[test]
     fn dollar_crate_in_builtin_macro() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 #[macro_export]
 #[rustc_builtin_macro]
 macro_rules! format_args {}
@@ -232,11 +237,13 @@ fn f() {
     outer!();
 } //^^^^^^ error: leftover tokens
   //^^^^^^ error: Syntax Error in Expansion: expected expression
-"#)
+"#,
+        )
     }
     #[test]
     fn def_diagnostic() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 macro_rules! foo {
            //^^^ error: expected subtree
     f => {};
@@ -247,11 +254,13 @@ fn f() {
   //^^^ error: macro definition has parse errors
 
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn expansion_syntax_diagnostic() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 macro_rules! foo {
     () => { struct; };
 }
@@ -260,7 +269,8 @@ fn f() {
     foo!();
   //^^^ error: Syntax Error in Expansion: expected a name
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn include_does_not_break_diagnostics() {
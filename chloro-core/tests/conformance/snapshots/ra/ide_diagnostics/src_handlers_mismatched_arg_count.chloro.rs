@@ -52,10 +52,7 @@ fn invalid_args_range(
     expected: usize,
     found: usize,
 ) -> FileRange {
-    adjusted_display_range(
-        ctx,
-        source,
-        &|expr| {
+    adjusted_display_range(ctx, source, &|expr| {
         let (text_range, r_paren_token, expected_arg) = match expr {
             Either::Left(ast::Expr::CallExpr(call)) => {
                 let arg_list = call.arg_list()?;
@@ -103,8 +100,7 @@ fn invalid_args_range(
         }
 
         None
-    },
-    )
+    })
 }
 
 #[cfg(test)]
@@ -211,21 +207,25 @@ fn f() {
     }
     #[test]
     fn tuple_struct() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 struct Tup(u8, u16);
 fn f() {
     Tup(0);
 }      //^ error: expected 2 arguments, found 1
-"#)
+"#,
+        )
     }
     #[test]
     fn enum_variant() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 enum En { Variant(u8, u16), }
 fn f() {
     En::Variant(0);
 }              //^ error: expected 2 arguments, found 1
-"#)
+"#,
+        )
     }
     #[test]
     fn enum_variant_type_macro() {
@@ -319,7 +319,8 @@ fn test() {
     }
     #[test]
     fn varargs() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 extern "C" {
     fn fixed(fixed: u8);
     fn varargs(fixed: u8, ...);
@@ -338,11 +339,13 @@ fn f() {
         varargs2(0, 1);
     }
 }
-        "#)
+        "#,
+        )
     }
     #[test]
     fn arg_count_lambda() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 fn main() {
     let f = |()| ();
     f();
@@ -351,7 +354,8 @@ fn main() {
     f((), ());
         //^^^ error: expected 1 argument, found 2
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn cfgd_out_call_arguments() {
@@ -377,7 +381,8 @@ fn main() {
     }
     #[test]
     fn cfgd_out_fn_params() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 fn foo(#[cfg(NEVER)] x: ()) {}
 
 struct S;
@@ -404,11 +409,13 @@ fn main() {
         varargs(1, 2, 3);
     }
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn legacy_const_generics() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 #[rustc_legacy_const_generics(1, 3)]
 fn mixed<const N1: &'static str, const N2: bool>(
     _a: u8,
@@ -433,11 +440,13 @@ fn g() {
     b(0, 1, 2);
            //^ error: expected 4 arguments, found 3
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn tuple_struct_pat() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 struct S(u32, u32);
 fn f(
     S(a, b, c): S,
@@ -447,7 +456,8 @@ fn f(
     S(e, f, .., g, d): S
   //        ^^^^^^^^^ error: this pattern has 4 fields, but the corresponding tuple struct has 2 fields
 ) { _ = (a, b, c, d, e, f, g); }
-"#)
+"#,
+        )
     }
     #[test]
     fn no_type_mismatches_when_arg_count_mismatch() {
@@ -100,11 +100,13 @@ fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::MissingFields) -> Option<Vec<Ass
             let missing_fields = ctx.sema.record_literal_missing_fields(field_list_parent);
 
             let mut locals = FxHashMap::default();
-            ctx.sema.scope(field_list_parent.syntax())?.process_all_names(&mut |name, def| {
-                if let hir::ScopeDef::Local(local) = def {
-                    locals.insert(name, local);
-                }
-            });
+            ctx.sema
+                .scope(field_list_parent.syntax())?
+                .process_all_names(&mut |name, def| {
+                    if let hir::ScopeDef::Local(local) = def {
+                        locals.insert(name, local);
+                    }
+                });
 
             let generate_fill_expr = |ty: &Type<'_>| match ctx.config.expr_fill_default {
                 ExprFillDefaultMode::Todo => make::ext::expr_todo(),
@@ -268,33 +270,39 @@ fn baz(s: S) {
     }
     #[test]
     fn missing_record_pat_field_no_diagnostic_if_not_exhaustive() {
-        check_diagnostics(r"
+        check_diagnostics(
+            r"
 struct S { foo: i32, bar: () }
 fn baz(s: S) -> i32 {
     match s {
         S { foo, .. } => foo,
     }
 }
-")
+",
+        )
     }
     #[test]
     fn missing_record_pat_field_box() {
-        check_diagnostics(r"
+        check_diagnostics(
+            r"
 struct S { s: Box<u32> }
 fn x(a: S) {
     let S { box s } = a;
 }
-")
+",
+        )
     }
     #[test]
     fn missing_record_pat_field_ref() {
-        check_diagnostics(r"
+        check_diagnostics(
+            r"
 struct S { s: u32 }
 fn x(a: S) {
     let S { ref s } = a;
     _ = s;
 }
-")
+",
+        )
     }
     #[test]
     fn missing_record_expr_in_assignee_expr() {
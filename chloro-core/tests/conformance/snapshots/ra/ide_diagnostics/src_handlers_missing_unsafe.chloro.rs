@@ -133,14 +133,16 @@ mod tests {
     use crate::tests::{check_diagnostics, check_fix, check_no_fix};
     #[test]
     fn missing_unsafe_diagnostic_with_raw_ptr() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 //- minicore: sized
 fn main() {
     let x = &5_usize as *const usize;
     unsafe { let _y = *x; }
     let _z = *x;
 }          //^^💡 error: dereference of raw pointer is unsafe and requires an unsafe function or block
-"#)
+"#,
+        )
     }
     #[test]
     fn missing_unsafe_diagnostic_with_unsafe_call() {
@@ -442,7 +444,8 @@ fn main() {
     }
     #[test]
     fn raw_deref_on_union_field() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 fn main() {
 
     union U {
@@ -478,7 +481,8 @@ fn main() {
        // ^^^ 💡 error: access to union field is unsafe and requires an unsafe function or block
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn unsafe_expr_as_an_argument_of_a_method_call() {
@@ -622,7 +626,8 @@ fn main() {
     }
     #[test]
     fn unsafe_expr_in_macro_call() {
-        check_no_fix(r#"
+        check_no_fix(
+            r#"
 unsafe fn foo() -> u8 {
     0
 }
@@ -630,11 +635,13 @@ unsafe fn foo() -> u8 {
 fn main() {
     let x = format!("foo: {}", foo$0());
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn rustc_deprecated_safe_2024() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 //- /ed2021.rs crate:ed2021 edition:2021
 #[rustc_deprecated_safe_2024]
 unsafe fn deprecated_safe() -> u8 {
@@ -670,7 +677,8 @@ fn main() {
     ed2024::deprecated_safe();
  // ^^^^^^^^^^^^^^^^^^^^^^^^^💡 warn: call to unsafe function is unsafe and requires an unsafe function or block
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn orphan_unsafe_format_args() {
@@ -741,13 +749,15 @@ unsafe fn foo(p: *mut i32) {
     }
     #[test]
     fn unsafe_op_in_unsafe_fn() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 #![warn(unsafe_op_in_unsafe_fn)]
 unsafe fn foo(p: *mut i32) {
     *p = 123;
   //^^💡 warn: dereference of raw pointer is unsafe and requires an unsafe function or block
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn no_unsafe_diagnostic_with_safe_kw() {
@@ -851,15 +861,18 @@ fn foo() {
     }
     #[test]
     fn unsafe_op_in_unsafe_fn_dismissed_in_signature() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 #![warn(unsafe_op_in_unsafe_fn)]
 union Union { field: u32 }
 unsafe fn foo(Union { field: _field }: Union) {}
-            "#)
+            "#,
+        )
     }
     #[test]
     fn union_assignment_allowed() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 union Union { field: u32 }
 fn foo(mut v: Union) {
     v.field = 123;
@@ -873,11 +886,13 @@ fn bar(mut v: Union2) {
     v.field.field = 123;
 }
 
-            "#)
+            "#,
+        )
     }
     #[test]
     fn raw_ref_reborrow_is_safe() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 fn main() {
     let ptr: *mut i32;
     let _addr = &raw const *ptr;
@@ -886,7 +901,8 @@ fn main() {
     let ptr = &local as *const i32;
     let _addr = &raw const *ptr;
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn target_feature() {
@@ -200,16 +200,19 @@ fn foo(mut slice: &[u32]) -> usize {
     }
     #[test]
     fn regression_16564() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 //- minicore: copy
 fn test() {
     let _x = (&(&mut (),)).0 as *const ();
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn regression_18201() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 //- minicore: copy
 struct NotCopy;
 struct S(NotCopy);
@@ -221,11 +224,13 @@ impl S {
         };
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn regression_20155() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 //- minicore: copy, option
 struct Box(i32);
 fn test() {
@@ -236,6 +241,7 @@ fn test() {
         }
     };
 }
-"#)
+"#,
+        )
     }
 }
@@ -351,18 +351,21 @@ pub struct Foo {
     }
     #[test]
     fn test_tuple_field_on_record_struct() {
-        check_no_fix(r#"
+        check_no_fix(
+            r#"
 struct Struct {}
 fn main() {
     Struct {
         0$0: 0
     }
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn test_struct_field_private() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 mod m {
     pub struct Struct {
         field: u32,
@@ -389,7 +392,8 @@ fn f(s@m::Struct {
       //^^^^^^ 💡 error: field is private
     };
 }
-"#)
+"#,
+        )
     }
     #[test]
     fn test_struct_field_private_same_crate_fix() {
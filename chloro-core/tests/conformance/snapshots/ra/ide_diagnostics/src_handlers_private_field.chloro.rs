@@ -24,7 +24,9 @@ pub(crate) fn private_field(ctx: &DiagnosticsContext<'_>, d: &hir::PrivateField)
         &ctx.sema,
         d.expr.file_id.original_file(ctx.sema.db),
         d.field,
-        ctx.sema.original_range(d.expr.to_node(ctx.sema.db).syntax()).range,
+        ctx.sema
+            .original_range(d.expr.to_node(ctx.sema.db).syntax())
+            .range,
     ))
 }
 
@@ -35,7 +37,9 @@ pub(crate) fn field_is_private_fixes(
     fix_range: TextRange,
 ) -> Option<Vec<Assist>> {
     let def_crate = private_field.krate(sema.db);
-    let usage_crate = sema.file_to_module_def(usage_file_id.file_id(sema.db))?.krate();
+    let usage_crate = sema
+        .file_to_module_def(usage_file_id.file_id(sema.db))?
+        .krate();
     let mut visibility_text = if usage_crate == def_crate { "pub(crate) " } else { "pub " };
 
     let source = private_field.source(sema.db)?;
@@ -13,17 +13,17 @@ pub(crate) fn trait_impl_incorrect_safety(
     Diagnostic::new(
         DiagnosticCode::Ra("trait-impl-incorrect-safety", Severity::Error),
         if d.should_be_safe {
-        "unsafe impl for safe trait"
-    } else {
-        "impl for unsafe trait needs to be unsafe"
-    },
+            "unsafe impl for safe trait"
+        } else {
+            "impl for unsafe trait needs to be unsafe"
+        },
         adjusted_display_range::<ast::Impl>(
-        ctx,
-        InFile {
-        file_id: d.file_id,
-        value: d.impl_,
-    },
-        &|impl_| {
+            ctx,
+            InFile {
+                file_id: d.file_id,
+                value: d.impl_,
+            },
+            &|impl_| {
                 if d.should_be_safe {
                     Some(match (impl_.unsafe_token(), impl_.impl_token()) {
                         (None, None) => return None,
@@ -34,7 +34,7 @@ pub(crate) fn trait_impl_incorrect_safety(
                     impl_.impl_token().map(|t| t.text_range())
                 }
             },
-    ),
+        ),
     )
     .stable()
 }
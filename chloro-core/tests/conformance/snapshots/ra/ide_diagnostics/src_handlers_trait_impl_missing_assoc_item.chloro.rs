@@ -24,13 +24,13 @@ pub(crate) fn trait_impl_missing_assoc_item(
         DiagnosticCode::RustcHardError("E0046"),
         format!("not all trait items implemented, missing: {missing}"),
         adjusted_display_range::<ast::Impl>(
-        ctx,
-        InFile {
-        file_id: d.file_id,
-        value: d.impl_,
-    },
-        &|impl_| impl_.trait_().map(|t| t.syntax().text_range()),
-    ),
+            ctx,
+            InFile {
+                file_id: d.file_id,
+                value: d.impl_,
+            },
+            &|impl_| impl_.trait_().map(|t| t.syntax().text_range()),
+        ),
     )
     .stable()
 }
@@ -40,13 +40,15 @@ mod tests {
     use crate::tests::check_diagnostics;
     #[test]
     fn trait_with_default_value() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 trait Marker {
     const FLAG: bool = false;
 }
 struct Foo;
 impl Marker for Foo {}
-            "#)
+            "#,
+        )
     }
     #[test]
     fn simple() {
@@ -113,18 +115,21 @@ impl Trait for () {
     }
     #[test]
     fn negative_impl() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 trait Trait {
     fn item();
 }
 
 // Negative impls don't require any items (in fact, the forbid providing any)
 impl !Trait for () {}
-"#)
+"#,
+        )
     }
     #[test]
     fn impl_sized_for_unsized() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 //- minicore: sized
 trait Trait {
     type Item
@@ -146,6 +151,7 @@ impl Trait for () {
 // Items with Self: Sized bound not required to be implemented for unsized types.
 impl Trait for str {}
 impl Trait for dyn OtherTrait {}
- "#)
+ "#,
+        )
     }
 }
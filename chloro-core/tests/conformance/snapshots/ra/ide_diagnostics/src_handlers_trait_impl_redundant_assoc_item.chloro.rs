@@ -59,15 +59,13 @@ pub(crate) fn trait_impl_redundant_assoc_item(
     Diagnostic::new(
         DiagnosticCode::RustcHardError("E0407"),
         format!("{redundant_item_name} is not a member of trait `{trait_name}`"),
-        ide_db::FileRange { file_id: file_id.file_id(ctx.sema.db), range },
+        ide_db::FileRange {
+            file_id: file_id.file_id(ctx.sema.db),
+            range,
+        },
     )
     .stable()
-    .with_fixes(quickfix_for_redundant_assoc_item(
-        ctx,
-        d,
-        redundant_item_def,
-        diagnostic_range,
-    ))
+    .with_fixes(quickfix_for_redundant_assoc_item(ctx, d, redundant_item_def, diagnostic_range))
 }
 
 /// add assoc item into the trait def body
@@ -189,7 +187,8 @@ impl Marker for Foo {
     }
     #[test]
     fn quickfix_dont_work() {
-        check_no_fix(r#"
+        check_no_fix(
+            r#"
             //- /dep.rs crate:dep
             trait Marker {
             }
@@ -198,11 +197,13 @@ impl Marker for Foo {
             impl dep::Marker for Foo {
                 type T = i32;$0
             }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn trait_with_default_value() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 trait Marker {
     const FLAG: bool = false;
     fn boo();
@@ -220,11 +221,13 @@ impl Marker for Foo {
 
     fn boo() {}
 }
-            "#)
+            "#,
+        )
     }
     #[test]
     fn dont_work_for_negative_impl() {
-        check_diagnostics(r#"
+        check_diagnostics(
+            r#"
 trait Marker {
     const FLAG: bool = false;
     fn boo();
@@ -237,6 +240,7 @@ impl !Marker for Foo {
     fn bar() {}
     fn boo() {}
 }
-            "#)
+            "#,
+        )
     }
 }
@@ -21,7 +21,9 @@ use syntax::AstNode;
 //
 // This diagnostic is triggered when an underscore expression is used in an invalid position.
 pub(crate) fn typed_hole(ctx: &DiagnosticsContext<'_>, d: &hir::TypedHole<'_>) -> Diagnostic {
-    let display_range = ctx.sema.diagnostics_display_range(d.expr.map(|it| it.into()));
+    let display_range = ctx
+        .sema
+        .diagnostics_display_range(d.expr.map(|it| it.into()));
     let (message, fixes) = if d.expected.is_unknown() {
         ("`_` expressions may only appear on the left-hand side of an assignment".to_owned(), None)
     } else {
@@ -69,20 +71,22 @@ fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::TypedHole<'_>) -> Option<Vec<Ass
         .not()
         .then(|| "todo!()".to_owned())
         .into_iter()
-        .chain(paths.into_iter().filter_map(|path| {
-            path.gen_source_code(
-                &scope,
-                &mut formatter,
-                FindPathConfig {
-                    prefer_no_std: ctx.config.prefer_no_std,
-                    prefer_prelude: ctx.config.prefer_prelude,
-                    prefer_absolute: ctx.config.prefer_absolute,
-                    allow_unstable: ctx.is_nightly,
-                },
-                ctx.display_target,
-            )
-            .ok()
-        }))
+        .chain(
+            paths.into_iter().filter_map(|path| {
+                path.gen_source_code(
+                    &scope,
+                    &mut formatter,
+                    FindPathConfig {
+                        prefer_no_std: ctx.config.prefer_no_std,
+                        prefer_prelude: ctx.config.prefer_prelude,
+                        prefer_absolute: ctx.config.prefer_absolute,
+                        allow_unstable: ctx.is_nightly,
+                    },
+                    ctx.display_target,
+                )
+                .ok()
+            }),
+        )
         .unique()
         .map(|code| Assist {
             id: AssistId::quick_fix("typed-hole"),
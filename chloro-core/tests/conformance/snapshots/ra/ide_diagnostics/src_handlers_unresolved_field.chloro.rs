@@ -37,10 +37,7 @@ pub(crate) fn unresolved_field(
             d.name.display(ctx.sema.db, ctx.edition),
             d.receiver.display(ctx.sema.db, ctx.display_target)
         ),
-        adjusted_display_range(
-        ctx,
-        d.expr,
-        &|expr| {
+        adjusted_display_range(ctx, d.expr, &|expr| {
             Some(
                 match expr.left()? {
                     ast::Expr::MethodCallExpr(it) => it.name_ref(),
@@ -50,12 +47,9 @@ pub(crate) fn unresolved_field(
                 .syntax()
                 .text_range(),
             )
-        },
-    ),
-    )
-    .with_fixes(
-        fixes(ctx, d),
+        }),
     )
+    .with_fixes(fixes(ctx, d))
 }
 
 fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::UnresolvedField<'_>) -> Option<Vec<Assist>> {
@@ -512,7 +506,8 @@ fn foo() {
     }
     #[test]
     fn no_fix_when_indexed() {
-        check_no_fix(r#"
+        check_no_fix(
+            r#"
             struct Kek {}
 impl Kek {
     pub fn foo(self) {
@@ -521,11 +516,13 @@ impl Kek {
 }
 
 fn main() {}
-            "#)
+            "#,
+        )
     }
     #[test]
     fn no_fix_when_without_field() {
-        check_no_fix(r#"
+        check_no_fix(
+            r#"
             struct Kek {}
 impl Kek {
     pub fn foo(self) {
@@ -534,7 +531,8 @@ impl Kek {
 }
 
 fn main() {}
-            "#)
+            "#,
+        )
     }
     #[test]
     fn regression_18683() {
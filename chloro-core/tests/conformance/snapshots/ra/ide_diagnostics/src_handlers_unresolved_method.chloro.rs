@@ -34,10 +34,7 @@ pub(crate) fn unresolved_method(
             d.name.display(ctx.sema.db, ctx.edition),
             d.receiver.display(ctx.sema.db, ctx.display_target)
         ),
-        adjusted_display_range(
-        ctx,
-        d.expr,
-        &|expr| {
+        adjusted_display_range(ctx, d.expr, &|expr| {
             Some(
                 match expr.left()? {
                     ast::Expr::MethodCallExpr(it) => it.name_ref(),
@@ -47,12 +44,9 @@ pub(crate) fn unresolved_method(
                 .syntax()
                 .text_range(),
             )
-        },
-    ),
-    )
-    .with_fixes(
-        fixes(ctx, d),
+        }),
     )
+    .with_fixes(fixes(ctx, d))
 }
 
 fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::UnresolvedMethodCall<'_>) -> Option<Vec<Assist>> {
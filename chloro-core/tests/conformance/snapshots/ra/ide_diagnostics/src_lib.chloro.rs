@@ -104,6 +104,7 @@ use syntax::{
     ast::{self, AstNode, HasAttrs},
 };
 
+// FIXME: Make this an enum
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DiagnosticCode {
     RustcHardError(&'static str),
@@ -325,11 +326,10 @@ pub fn syntax_diagnostics(
         .flatten()
         .take(128)
         .map(|err| {
-            Diagnostic::new(
-                DiagnosticCode::SyntaxError,
-                format!("Syntax Error: {err}"),
-                FileRange { file_id, range: err.range() },
-            )
+            Diagnostic::new(DiagnosticCode::SyntaxError, format!("Syntax Error: {err}"), FileRange {
+                file_id,
+                range: err.range(),
+            })
         })
         .collect()
 }
@@ -499,12 +499,14 @@ pub fn semantic_diagnostics(
         .iter_mut()
         .filter(|it| matches!(it.code, DiagnosticCode::Clippy(_) | DiagnosticCode::RustcLint(_)))
         .filter_map(|it| {
-            Some((
-                it.main_node.map(|ptr| {
+            Some(
+                (
+                    it.main_node.map(|ptr| {
                     ptr.map(|node| node.to_node(&ctx.sema.parse_or_expand(ptr.file_id)))
                 })?,
-                it,
-            ))
+                    it,
+                ),
+            )
         })
         .collect::<Vec<_>>();
 
@@ -586,6 +588,7 @@ static CLIPPY_LINTS: LazyLock<FxHashMap<&str, BuiltLint>> = LazyLock::new(|| {
     build_lints_map(ide_db::generated::lints::CLIPPY_LINTS, CLIPPY_LINT_GROUPS, "clippy::")
 });
 
+// FIXME: Autogenerate this instead of enumerating by hand.
 static LINTS_TO_REPORT_IN_EXTERNAL_MACROS: LazyLock<FxHashSet<&str>> = LazyLock::new(|| FxHashSet::from_iter([]));
 
 fn build_lints_map(
@@ -610,7 +613,10 @@ fn build_lints_map(
             add_children("bad_style");
         }
     }
-    map_with_prefixes.into_iter().map(|(k, v)| (k.strip_prefix(prefix).unwrap(), v)).collect()
+    map_with_prefixes
+        .into_iter()
+        .map(|(k, v)| (k.strip_prefix(prefix).unwrap(), v))
+        .collect()
 }
 
 fn handle_lints(
@@ -709,7 +715,8 @@ fn lint_attrs<'a>(
     ancestor
         .attrs_including_inner()
         .filter_map(|attr| {
-            attr.as_simple_call().and_then(|(name, value)| match &*name {
+            attr.as_simple_call()
+                .and_then(|(name, value)| match &*name {
                 "allow" | "expect" => Some(Either::Left(iter::once((Severity::Allow, value)))),
                 "warn" => Some(Either::Left(iter::once((Severity::Warning, value)))),
                 "forbid" | "deny" => Some(Either::Left(iter::once((Severity::Error, value)))),
@@ -723,15 +730,20 @@ fn lint_attrs<'a>(
         })
         .flatten()
         .flat_map(move |(severity, lints)| {
-            parse_tt_as_comma_sep_paths(lints, edition).into_iter().flat_map(move |lints| {
-                // Rejoin the idents with `::`, so we have no spaces in between.
-                lints.into_iter().map(move |lint| {
-                    (
-                        lint.segments().filter_map(|segment| segment.name_ref()).join("::").into(),
-                        severity,
-                    )
+            parse_tt_as_comma_sep_paths(lints, edition)
+                .into_iter()
+                .flat_map(move |lints| {
+                    // Rejoin the idents with `::`, so we have no spaces in between.
+                    lints.into_iter().map(move |lint| {
+                        (
+                            lint.segments()
+                            .filter_map(|segment| segment.name_ref())
+                            .join("::")
+                            .into(),
+                            severity,
+                        )
+                    })
                 })
-            })
         })
 }
 
@@ -813,7 +825,10 @@ fn lint_groups(lint: &DiagnosticCode, edition: Edition) -> LintGroups {
         }
         _ => panic!("non-lint passed to `handle_lints()`"),
     };
-    LintGroups { groups, inside_warnings }
+    LintGroups {
+        groups,
+        inside_warnings,
+    }
 }
 
 fn fix(id: &'static str, label: &str, source_change: SourceChange, target: TextRange) -> Assist {
@@ -844,5 +859,8 @@ fn adjusted_display_range<N: AstNode>(
     let hir::FileRange { file_id, range } = diag_ptr
         .with_value(adj(node).unwrap_or_else(|| diag_ptr.value.text_range()))
         .original_node_file_range_rooted(ctx.sema.db);
-    ide_db::FileRange { file_id: file_id.file_id(ctx.sema.db), range }
+    ide_db::FileRange {
+        file_id: file_id.file_id(ctx.sema.db),
+        range,
+    }
 }
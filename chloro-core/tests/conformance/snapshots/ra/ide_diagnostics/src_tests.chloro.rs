@@ -197,7 +197,9 @@ pub(crate) fn check_diagnostics_with_disabled(
     disabled: &[&str],
 ) {
     let mut config = DiagnosticsConfig::test_sample();
-    config.disabled.extend(disabled.iter().map(|&s| s.to_owned()));
+    config
+        .disabled
+        .extend(disabled.iter().map(|&s| s.to_owned()));
     check_diagnostics_with_config(config, ra_fixture)
 }
 
@@ -193,6 +193,9 @@ xflags::xflags! {
     }
 }
 
+// generated start
+// The following code is generated by `xflags` macro.
+// Run `env UPDATE_XFLAGS=1 cargo build` to regenerate.
 #[derive(Debug)]
 pub struct RustAnalyzer {
     pub verbose: u32,
@@ -2,8 +2,8 @@
 
 use std::env;
 use std::time::Instant;
-use ide_db::{LineIndexDatabase, line_index::WideEncoding};
 
+use ide_db::{LineIndexDatabase, line_index::WideEncoding};
 use ide::{
     Analysis, AnalysisHost, FileId, FileRange, MonikerKind, MonikerResult, PackageInformation,
     RootDatabase, StaticIndex, StaticIndexedFile, TokenId, TokenStaticData,
@@ -213,7 +213,9 @@ impl LsifManager<'_, '_> {
             let mut edges = token.references.iter().fold(
                 FxHashMap::<_, Vec<lsp_types::NumberOrString>>::default(),
                 |mut edges, it| {
-                    let entry = edges.entry((it.range.file_id, it.is_definition)).or_default();
+                    let entry = edges
+                        .entry((it.range.file_id, it.is_definition))
+                        .or_default();
                     entry.push((*self.range_map.get(&it.range).unwrap()).into());
                     edges
                 },
@@ -263,7 +265,8 @@ impl LsifManager<'_, '_> {
                     range: to_proto::range(&line_index, range),
                     tag: None,
                 });
-                self.range_map.insert(FileRange { file_id, range }, range_id);
+                self.range_map
+                    .insert(FileRange { file_id, range }, range_id);
                 let result_set_id = self.get_token_id(id);
                 self.add_edge(lsif::Edge::Next(lsif::EdgeData {
                     in_v: result_set_id.into(),
@@ -16,7 +16,14 @@ pub(crate) struct ProgressReport<'a> {
 
 impl<'a> ProgressReport<'a> {
     pub(crate) fn new(len: usize) -> ProgressReport<'a> {
-        ProgressReport { curr: 0.0, text: String::new(), hidden: false, len, pos: 0, msg: None }
+        ProgressReport {
+            curr: 0.0,
+            text: String::new(),
+            hidden: false,
+            len,
+            pos: 0,
+            msg: None,
+        }
     }
 
     pub(crate) fn hidden() -> ProgressReport<'a> {
@@ -1,7 +1,6 @@
 //! Run all tests in a project, similar to `cargo test`, but using the mir interpreter.
 
 use hir_ty::db::HirDatabase;
-
 use hir::{Crate, Module};
 use ide_db::{LineIndexDatabase, base_db::SourceDatabase};
 use profile::StopWatch;
@@ -109,7 +109,11 @@ impl Tester {
         let host = AnalysisHost::with_database(db);
         let db = host.raw_database();
         let krates = Crate::all(db);
-        let root_crate = krates.iter().cloned().find(|krate| krate.origin(db).is_local()).unwrap();
+        let root_crate = krates
+            .iter()
+            .cloned()
+            .find(|krate| krate.origin(db).is_local())
+            .unwrap();
         let root_file = root_crate.root_file(db);
         Ok(Self {
             host,
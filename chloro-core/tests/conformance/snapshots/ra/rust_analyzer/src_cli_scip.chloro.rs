@@ -1,8 +1,8 @@
 //! SCIP generator
 
 use std::{path::PathBuf, time::Instant};
-use ide_db::LineIndexDatabase;
 
+use ide_db::LineIndexDatabase;
 use ide::{
     AnalysisHost, LineCol, Moniker, MonikerDescriptorKind, MonikerIdentifier, MonikerResult,
     RootDatabase, StaticIndex, StaticIndexedFile, SymbolInformationKind, TextRange, TokenId,
@@ -282,6 +282,7 @@ impl flags::Scip {
     }
 }
 
+// FIXME: Known buggy cases are described here.
 const DUPLICATE_SYMBOLS_MESSAGE: &str = "
 Encountered duplicate scip symbols, indicating an internal rust-analyzer bug. These duplicates are
 included in the output, but this causes information lookup to be ambiguous and so information about
@@ -333,7 +334,13 @@ fn get_relative_filepath(
     rootpath: &vfs::AbsPathBuf,
     file_id: ide::FileId,
 ) -> Option<String> {
-    Some(vfs.file_path(file_id).as_path()?.strip_prefix(rootpath)?.as_str().to_owned())
+    Some(
+        vfs.file_path(file_id)
+            .as_path()?
+            .strip_prefix(rootpath)?
+            .as_str()
+            .to_owned(),
+    )
 }
 
 fn get_line_index(db: &RootDatabase, file_id: FileId) -> LineIndex {
@@ -440,8 +447,7 @@ impl SymbolGenerator {
                         is_inherent_impl: match &moniker.identifier.description[..] {
                             // inherent impls are represented as impl#[SelfType]
                             [.., descriptor, _] => {
-                                descriptor.desc == MonikerDescriptorKind::Type
-                                    && descriptor.name == "impl"
+                                descriptor.desc == MonikerDescriptorKind::Type && descriptor.name == "impl"
                             }
                             _ => false,
                         },
@@ -472,7 +478,11 @@ fn moniker_to_symbol(moniker: &Moniker) -> scip_types::Symbol {
         package: Some(scip_types::Package {
             manager: "cargo".to_owned(),
             name: moniker.package_information.name.clone(),
-            version: moniker.package_information.version.clone().unwrap_or_else(|| ".".to_owned()),
+            version: moniker
+                .package_information
+                .version
+                .clone()
+                .unwrap_or_else(|| ".".to_owned()),
             special_fields: Default::default(),
         })
         .into(),
@@ -487,19 +497,16 @@ fn moniker_descriptors(identifier: &MonikerIdentifier) -> Vec<scip_types::Descri
         .description
         .iter()
         .map(|desc| {
-            new_descriptor_str(
-                &desc.name,
-                match desc.desc {
-                    MonikerDescriptorKind::Namespace => Namespace,
-                    MonikerDescriptorKind::Type => Type,
-                    MonikerDescriptorKind::Term => Term,
-                    MonikerDescriptorKind::Method => Method,
-                    MonikerDescriptorKind::TypeParameter => TypeParameter,
-                    MonikerDescriptorKind::Parameter => Parameter,
-                    MonikerDescriptorKind::Macro => Macro,
-                    MonikerDescriptorKind::Meta => Meta,
-                },
-            )
+            new_descriptor_str(&desc.name, match desc.desc {
+                MonikerDescriptorKind::Namespace => Namespace,
+                MonikerDescriptorKind::Type => Type,
+                MonikerDescriptorKind::Term => Term,
+                MonikerDescriptorKind::Method => Method,
+                MonikerDescriptorKind::TypeParameter => TypeParameter,
+                MonikerDescriptorKind::Parameter => Parameter,
+                MonikerDescriptorKind::Macro => Macro,
+                MonikerDescriptorKind::Meta => Meta,
+            })
         })
         .collect()
 }
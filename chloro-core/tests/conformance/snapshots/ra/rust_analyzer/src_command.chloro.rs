@@ -38,7 +38,12 @@ impl<T: Sized + Send + 'static> CargoActor<T> {
         stderr: ChildStderr,
     ) -> Self {
         let parser = Box::new(parser);
-        CargoActor { parser, sender, stdout, stderr }
+        CargoActor {
+            parser,
+            sender,
+            stdout,
+            stderr,
+        }
     }
 }
 
@@ -151,10 +156,16 @@ impl<T: Sized + Send + 'static> CommandHandle<T> {
         sender: Sender<T>,
         out_file: Option<Utf8PathBuf>,
     ) -> std::io::Result<Self> {
-        command.stdout(Stdio::piped()).stderr(Stdio::piped()).stdin(Stdio::null());
+        command
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(Stdio::null());
 
         let program = command.get_program().into();
-        let arguments = command.get_args().map(|arg| arg.into()).collect::<Vec<OsString>>();
+        let arguments = command
+            .get_args()
+            .map(|arg| arg.into())
+            .collect::<Vec<OsString>>();
         let current_dir = command.get_current_dir().map(|arg| arg.to_path_buf());
 
         let mut child = StdCommandWrap::from(command);
@@ -172,7 +183,14 @@ impl<T: Sized + Send + 'static> CommandHandle<T> {
             stdx::thread::Builder::new(stdx::thread::ThreadIntent::Worker, "CommandHandle")
                 .spawn(move || actor.run(out_file))
                 .expect("failed to spawn thread");
-        Ok(CommandHandle { program, arguments, current_dir, child, thread, _phantom: PhantomData })
+        Ok(CommandHandle {
+            program,
+            arguments,
+            current_dir,
+            child,
+            thread,
+            _phantom: PhantomData,
+        })
     }
 
     pub(crate) fn cancel(mut self) {
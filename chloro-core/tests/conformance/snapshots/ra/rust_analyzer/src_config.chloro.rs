@@ -1030,6 +1030,7 @@ impl fmt::Debug for Config {
     }
 }
 
+// Delegate capability fetching methods
 impl std::ops::Deref for Config {
     type Target = ClientCapabilities;
 
@@ -1304,7 +1305,8 @@ impl Config {
             }
         }
 
-        self.discovered_projects_from_command.push(ProjectJsonFromCommand { data, buildfile });
+        self.discovered_projects_from_command
+            .push(ProjectJsonFromCommand { data, buildfile });
     }
 }
 
@@ -1522,10 +1524,7 @@ pub enum RustfmtConfig {
         extra_args: Vec<String>,
         enable_range_formatting: bool,
     },
-    CustomCommand {
-        command: String,
-        args: Vec<String>,
-    },
+    CustomCommand { command: String, args: Vec<String> },
 }
 
 /// Configuration for runnable items, such as `main` function or tests.
@@ -1572,9 +1571,7 @@ pub enum ConfigErrorInner {
         config_key: String,
         error: toml::de::Error,
     },
-    ParseError {
-        reason: String,
-    },
+    ParseError { reason: String },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -1665,10 +1662,12 @@ impl Config {
         fn sort_objects_by_field(json: &mut serde_json::Value) {
             if let serde_json::Value::Object(object) = json {
                 let old = std::mem::take(object);
-                old.into_iter().sorted_by(|(k, _), (k2, _)| k.cmp(k2)).for_each(|(k, mut v)| {
-                    sort_objects_by_field(&mut v);
-                    object.insert(k, v);
-                });
+                old.into_iter()
+                    .sorted_by(|(k, _), (k2, _)| k.cmp(k2))
+                    .for_each(|(k, mut v)| {
+                        sort_objects_by_field(&mut v);
+                        object.insert(k, v);
+                    });
             }
         }
         sort_objects_by_field(&mut s);
@@ -1715,7 +1714,10 @@ impl Config {
     }
 
     pub fn call_hierarchy<'a>(&self, minicore: MiniCore<'a>) -> CallHierarchyConfig<'a> {
-        CallHierarchyConfig { exclude_tests: self.references_excludeTests().to_owned(), minicore }
+        CallHierarchyConfig {
+            exclude_tests: self.references_excludeTests().to_owned(),
+            minicore,
+        }
     }
 
     pub fn completion<'a>(
@@ -1726,14 +1728,13 @@ impl Config {
         let client_capability_fields = self.completion_resolve_support_properties();
         CompletionConfig {
             enable_postfix_completions: self.completion_postfix_enable(source_root).to_owned(),
-            enable_imports_on_the_fly: self.completion_autoimport_enable(source_root).to_owned()
-                && self.caps.has_completion_item_resolve_additionalTextEdits(),
+            enable_imports_on_the_fly: self.completion_autoimport_enable(source_root).to_owned() && self.caps.has_completion_item_resolve_additionalTextEdits(),
             enable_self_on_the_fly: self.completion_autoself_enable(source_root).to_owned(),
             enable_auto_iter: *self.completion_autoIter_enable(source_root),
             enable_auto_await: *self.completion_autoAwait_enable(source_root),
-            enable_private_editable: self.completion_privateEditable_enable(source_root).to_owned(),
-            full_function_signatures: self
-                .completion_fullFunctionSignatures_enable(source_root)
+            enable_private_editable: self.completion_privateEditable_enable(source_root)
+                .to_owned(),
+            full_function_signatures: self.completion_fullFunctionSignatures_enable(source_root)
                 .to_owned(),
             callable: match self.completion_callable_snippets(source_root) {
                 CallableCompletionDef::FillArguments => Some(CallableSnippets::FillArguments),
@@ -1755,8 +1756,7 @@ impl Config {
             } else {
                 CompletionFieldsToResolve::from_client_capabilities(&client_capability_fields)
             },
-            exclude_flyimport: self
-                .completion_autoimport_exclude(source_root)
+            exclude_flyimport: self.completion_autoimport_exclude(source_root)
                 .iter()
                 .map(|it| match it {
                     AutoImportExclusion::Path(path) => {
@@ -1818,6 +1818,7 @@ impl Config {
         DiagnosticsConfig {
             enabled: true,
             disable_experimental: false,
+            ..self.diagnostics(source_root)
         }
     }
 
@@ -1843,9 +1844,7 @@ impl Config {
             references: enable && self.hover_actions_references_enable().to_owned(),
             run: enable && self.hover_actions_run_enable().to_owned(),
             debug: enable && self.hover_actions_debug_enable().to_owned(),
-            update_test: enable
-                && self.hover_actions_run_enable().to_owned()
-                && self.hover_actions_updateTest_enable().to_owned(),
+            update_test: enable && self.hover_actions_run_enable().to_owned() && self.hover_actions_updateTest_enable().to_owned(),
             goto_type_def: enable && self.hover_actions_gotoTypeDef_enable().to_owned(),
         }
     }
@@ -1858,13 +1857,14 @@ impl Config {
         };
         HoverConfig {
             links_in_hover: self.hover_links_enable().to_owned(),
-            memory_layout: self.hover_memoryLayout_enable().then_some(MemoryLayoutHoverConfig {
-                size: self.hover_memoryLayout_size().map(mem_kind),
-                offset: self.hover_memoryLayout_offset().map(mem_kind),
-                alignment: self.hover_memoryLayout_alignment().map(mem_kind),
-                padding: self.hover_memoryLayout_padding().map(mem_kind),
-                niches: self.hover_memoryLayout_niches().unwrap_or_default(),
-            }),
+            memory_layout: self.hover_memoryLayout_enable()
+                .then_some(MemoryLayoutHoverConfig {
+                    size: self.hover_memoryLayout_size().map(mem_kind),
+                    offset: self.hover_memoryLayout_offset().map(mem_kind),
+                    alignment: self.hover_memoryLayout_alignment().map(mem_kind),
+                    padding: self.hover_memoryLayout_padding().map(mem_kind),
+                    niches: self.hover_memoryLayout_niches().unwrap_or_default(),
+                }),
             documentation: self.hover_documentation_enable().to_owned(),
             format: {
                 if self.caps.hover_markdown_support() {
@@ -1900,9 +1900,12 @@ impl Config {
             sized_bound: self.inlayHints_implicitSizedBoundHints_enable().to_owned(),
             parameter_hints: self.inlayHints_parameterHints_enable().to_owned(),
             generic_parameter_hints: GenericParameterHints {
-                type_hints: self.inlayHints_genericParameterHints_type_enable().to_owned(),
-                lifetime_hints: self.inlayHints_genericParameterHints_lifetime_enable().to_owned(),
-                const_hints: self.inlayHints_genericParameterHints_const_enable().to_owned(),
+                type_hints: self.inlayHints_genericParameterHints_type_enable()
+                    .to_owned(),
+                lifetime_hints: self.inlayHints_genericParameterHints_lifetime_enable()
+                    .to_owned(),
+                const_hints: self.inlayHints_genericParameterHints_const_enable()
+                    .to_owned(),
             },
             chaining_hints: self.inlayHints_chainingHints_enable().to_owned(),
             discriminant_hints: match self.inlayHints_discriminantHints_enable() {
@@ -1920,15 +1923,10 @@ impl Config {
                 LifetimeElisionDef::Never => ide::LifetimeElisionHints::Never,
                 LifetimeElisionDef::SkipTrivial => ide::LifetimeElisionHints::SkipTrivial,
             },
-            hide_named_constructor_hints: self
-                .inlayHints_typeHints_hideNamedConstructor()
-                .to_owned(),
-            hide_closure_initialization_hints: self
-                .inlayHints_typeHints_hideClosureInitialization()
-                .to_owned(),
-            hide_closure_parameter_hints: self
-                .inlayHints_typeHints_hideClosureParameter()
+            hide_named_constructor_hints: self.inlayHints_typeHints_hideNamedConstructor().to_owned(),
+            hide_closure_initialization_hints: self.inlayHints_typeHints_hideClosureInitialization()
                 .to_owned(),
+            hide_closure_parameter_hints: self.inlayHints_typeHints_hideClosureParameter().to_owned(),
             closure_style: match self.inlayHints_closureStyle() {
                 ClosureStyle::ImplFn => hir::ClosureStyle::ImplFn,
                 ClosureStyle::RustAnalyzer => hir::ClosureStyle::RANotation,
@@ -1946,31 +1944,25 @@ impl Config {
                 },
                 AdjustmentHintsDef::Borrows => ide::AdjustmentHints::BorrowsOnly,
             },
-            adjustment_hints_disable_reborrows: *self
-                .inlayHints_expressionAdjustmentHints_disableReborrows(),
+            adjustment_hints_disable_reborrows: *self.inlayHints_expressionAdjustmentHints_disableReborrows(),
             adjustment_hints_mode: match self.inlayHints_expressionAdjustmentHints_mode() {
                 AdjustmentHintsModeDef::Prefix => ide::AdjustmentHintsMode::Prefix,
                 AdjustmentHintsModeDef::Postfix => ide::AdjustmentHintsMode::Postfix,
                 AdjustmentHintsModeDef::PreferPrefix => ide::AdjustmentHintsMode::PreferPrefix,
                 AdjustmentHintsModeDef::PreferPostfix => ide::AdjustmentHintsMode::PreferPostfix,
             },
-            adjustment_hints_hide_outside_unsafe: self
-                .inlayHints_expressionAdjustmentHints_hideOutsideUnsafe()
+            adjustment_hints_hide_outside_unsafe: self.inlayHints_expressionAdjustmentHints_hideOutsideUnsafe()
                 .to_owned(),
             binding_mode_hints: self.inlayHints_bindingModeHints_enable().to_owned(),
-            param_names_for_lifetime_elision_hints: self
-                .inlayHints_lifetimeElisionHints_useParameterNames()
+            param_names_for_lifetime_elision_hints: self.inlayHints_lifetimeElisionHints_useParameterNames()
                 .to_owned(),
             max_length: self.inlayHints_maxLength().to_owned(),
-            closing_brace_hints_min_lines: if self.inlayHints_closingBraceHints_enable().to_owned()
-            {
+            closing_brace_hints_min_lines: if self.inlayHints_closingBraceHints_enable().to_owned() {
                 Some(self.inlayHints_closingBraceHints_minLines().to_owned())
             } else {
                 None
             },
-            fields_to_resolve: InlayFieldsToResolve::from_client_capabilities(
-                &client_capability_fields,
-            ),
+            fields_to_resolve: InlayFieldsToResolve::from_client_capabilities(&client_capability_fields),
             implicit_drop_hints: self.inlayHints_implicitDrops_enable().to_owned(),
             range_exclusive_hints: self.inlayHints_rangeExclusiveHints_enable().to_owned(),
             minicore,
@@ -2016,15 +2008,15 @@ impl Config {
             strings: self.semanticHighlighting_strings_enable().to_owned(),
             comments: self.semanticHighlighting_comments_enable().to_owned(),
             punctuation: self.semanticHighlighting_punctuation_enable().to_owned(),
-            specialize_punctuation: self
-                .semanticHighlighting_punctuation_specialization_enable()
+            specialize_punctuation: self.semanticHighlighting_punctuation_specialization_enable()
+                .to_owned(),
+            macro_bang: self.semanticHighlighting_punctuation_separate_macro_bang()
                 .to_owned(),
-            macro_bang: self.semanticHighlighting_punctuation_separate_macro_bang().to_owned(),
             operator: self.semanticHighlighting_operator_enable().to_owned(),
-            specialize_operator: self
-                .semanticHighlighting_operator_specialization_enable()
+            specialize_operator: self.semanticHighlighting_operator_specialization_enable()
+                .to_owned(),
+            inject_doc_comment: self.semanticHighlighting_doc_comment_inject_enable()
                 .to_owned(),
-            inject_doc_comment: self.semanticHighlighting_doc_comment_inject_enable().to_owned(),
             syntactic_name_ref_highlighting: false,
             minicore,
         }
@@ -2157,7 +2149,10 @@ impl Config {
     }
 
     pub fn lru_query_capacities_config(&self) -> Option<&FxHashMap<Box<str>, u16>> {
-        self.lru_query_capacities().is_empty().not().then(|| self.lru_query_capacities())
+        self.lru_query_capacities()
+            .is_empty()
+            .not()
+            .then(|| self.lru_query_capacities())
     }
 
     pub fn proc_macro_srv(&self) -> Option<AbsPathBuf> {
@@ -2194,7 +2189,9 @@ impl Config {
     }
 
     pub fn excluded(&self) -> impl Iterator<Item = AbsPathBuf> + use<'_> {
-        self.files_exclude().iter().map(|it| self.root_path.join(it))
+        self.files_exclude()
+            .iter()
+            .map(|it| self.root_path.join(it))
     }
 
     pub fn notifications(&self) -> NotificationsConfig {
@@ -2301,7 +2298,8 @@ impl Config {
     }
 
     pub(crate) fn completion_snippets_default() -> FxIndexMap<String, SnippetDef> {
-        serde_json::from_str(r#"{
+        serde_json::from_str(
+            r#"{
             "Ok": {
                 "postfix": "ok",
                 "body": "Ok(${receiver})",
@@ -2341,7 +2339,8 @@ impl Config {
                 "description": "Put the expression into an `Rc`",
                 "scope": "expr"
             }
-        }"#)
+        }"#,
+        )
         .unwrap()
     }
 
@@ -2401,8 +2400,7 @@ impl Config {
             Some(_) | None => FlycheckConfig::CargoCommand {
                 command: self.check_command(source_root).clone(),
                 options: CargoOptions {
-                    target_tuples: self
-                        .check_targets(source_root)
+                    target_tuples: self.check_targets(source_root)
                         .clone()
                         .and_then(|targets| match &targets.0[..] {
                             [] => None,
@@ -2411,11 +2409,9 @@ impl Config {
                         .unwrap_or_else(|| {
                             self.cargo_target(source_root).clone().into_iter().collect()
                         }),
-                    all_targets: self
-                        .check_allTargets(source_root)
+                    all_targets: self.check_allTargets(source_root)
                         .unwrap_or(*self.cargo_allTargets(source_root)),
-                    no_default_features: self
-                        .check_noDefaultFeatures(source_root)
+                    no_default_features: self.check_noDefaultFeatures(source_root)
                         .unwrap_or(*self.cargo_noDefaultFeatures(source_root)),
                     all_features: matches!(
                         self.check_features(source_root)
@@ -2423,8 +2419,7 @@ impl Config {
                             .unwrap_or(self.cargo_features(source_root)),
                         CargoFeaturesDef::All
                     ),
-                    features: match self
-                        .check_features(source_root)
+                    features: match self.check_features(source_root)
                         .clone()
                         .unwrap_or_else(|| self.cargo_features(source_root).clone()) {
                         CargoFeaturesDef::All => vec![],
@@ -2498,15 +2493,13 @@ impl Config {
             refs_trait: *self.lens_enable() && *self.lens_references_trait_enable(),
             enum_variant_refs: *self.lens_enable() && *self.lens_references_enumVariant_enable(),
             location: *self.lens_location(),
-            filter_adjacent_derive_implementations: *self
-            .gotoImplementations_filterAdjacentDerives(),
+            filter_adjacent_derive_implementations: *self.gotoImplementations_filterAdjacentDerives(),
         }
     }
 
     pub fn goto_implementation(&self) -> GotoImplementationConfig {
         GotoImplementationConfig {
-            filter_adjacent_derive_implementations: *self
-                .gotoImplementations_filterAdjacentDerives(),
+            filter_adjacent_derive_implementations: *self.gotoImplementations_filterAdjacentDerives(),
         }
     }
 
@@ -2580,7 +2573,10 @@ impl Config {
     }
 
     pub fn client_is_neovim(&self) -> bool {
-        self.client_info.as_ref().map(|it| it.name == "Neovim").unwrap_or_default()
+        self.client_info
+            .as_ref()
+            .map(|it| it.name == "Neovim")
+            .unwrap_or_default()
     }
 }
 // Deserialization definitions
@@ -2761,9 +2757,7 @@ where
     let path = String::deserialize(de)?;
 
     AbsPathBuf::try_from(path.as_ref())
-    .map_err(
-        |err| serde::de::Error::custom(format!("invalid path name: {err:?}")),
-    )
+        .map_err(|err| serde::de::Error::custom(format!("invalid path name: {err:?}")))
 }
 
 fn serialize_abs_pathbuf<S>(path: &AbsPathBuf, se: S) -> Result<S::Ok, S::Error>
@@ -2834,7 +2828,7 @@ pub(crate) enum InvocationStrategy {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-struct CheckOnSaveTargets(Vec<String>);
+struct CheckOnSaveTargets(#[serde(with = "single_or_array")] Vec<String>);
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -3408,13 +3402,15 @@ fn toml_pointer<'a>(toml: &'a toml::Table, pointer: &str) -> Option<&'a toml::Va
     let mut parts = pointer.split('/').skip(1);
     let first = parts.next()?;
     let init = toml.get(first)?;
-    parts.map(|x| x.replace("~1", "/").replace("~0", "~")).try_fold(init, |target, token| {
-        match target {
-            toml::Value::Table(table) => table.get(&token),
-            toml::Value::Array(list) => parse_index(&token).and_then(move |x| list.get(x)),
-            _ => None,
-        }
-    })
+    parts
+        .map(|x| x.replace("~1", "/").replace("~0", "~"))
+        .try_fold(init, |target, token| {
+            match target {
+                toml::Value::Table(table) => table.get(&token),
+                toml::Value::Array(list) => parse_index(&token).and_then(move |x| list.get(x)),
+                _ => None,
+            }
+        })
 }
 
 type SchemaField = (&'static str, &'static str, &'static [&'static str], String);
@@ -3947,19 +3943,21 @@ fn validate_toml_table(
 
 #[cfg(test)]
 fn manual(fields: &[SchemaField]) -> String {
-    fields.iter().fold(String::new(), |mut acc, (field, _ty, doc, default)| {
-        let id = field.replace('_', ".");
-        let name = format!("rust-analyzer.{id}");
-        let doc = doc_comment_to_string(doc);
-        if default.contains('\n') {
-            format_to_acc!(
+    fields
+        .iter()
+        .fold(String::new(), |mut acc, (field, _ty, doc, default)| {
+            let id = field.replace('_', ".");
+            let name = format!("rust-analyzer.{id}");
+            let doc = doc_comment_to_string(doc);
+            if default.contains('\n') {
+                format_to_acc!(
                 acc,
                 "## {name} {{#{id}}}\n\nDefault:\n```json\n{default}\n```\n\n{doc}\n\n"
             )
-        } else {
-            format_to_acc!(acc, "## {name} {{#{id}}}\n\nDefault: `{default}`\n\n{doc}\n\n")
-        }
-    })
+            } else {
+                format_to_acc!(acc, "## {name} {{#{id}}}\n\nDefault: `{default}`\n\n{doc}\n\n")
+            }
+        })
 }
 
 fn doc_comment_to_string(doc: &[&str]) -> String {
@@ -65,7 +65,12 @@ impl DiagnosticCollection {
         let Some(check) = self.check.get_mut(flycheck_id) else {
             return;
         };
-        self.changes.extend(check.per_package.drain().flat_map(|(_, v)| v.per_file.into_keys()));
+        self.changes.extend(
+            check
+                .per_package
+                .drain()
+                .flat_map(|(_, v)| v.per_file.into_keys()),
+        );
         if let Some(fixes) = Arc::make_mut(&mut self.check_fixes).get_mut(flycheck_id) {
             fixes.clear();
         }
@@ -162,9 +167,15 @@ impl DiagnosticCollection {
         }
 
         let check = &mut self.check[flycheck_id];
-        let package = check.per_package.entry(package_id.clone()).or_insert_with(|| {
-            PackageFlycheckDiagnostic { generation, per_file: FxHashMap::default() }
-        });
+        let package = check
+            .per_package
+            .entry(package_id.clone())
+            .or_insert_with(|| {
+                PackageFlycheckDiagnostic {
+                    generation,
+                    per_file: FxHashMap::default(),
+                }
+            });
         // Getting message from old generation. Might happen in restarting checks.
         if package.generation > generation {
             return;
@@ -234,8 +245,16 @@ impl DiagnosticCollection {
         &self,
         file_id: FileId,
     ) -> impl Iterator<Item = &lsp_types::Diagnostic> {
-        let native_syntax = self.native_syntax.get(&file_id).into_iter().flat_map(|(_, d)| d);
-        let native_semantic = self.native_semantic.get(&file_id).into_iter().flat_map(|(_, d)| d);
+        let native_syntax = self
+            .native_syntax
+            .get(&file_id)
+            .into_iter()
+            .flat_map(|(_, d)| d);
+        let native_semantic = self
+            .native_semantic
+            .get(&file_id)
+            .into_iter()
+            .flat_map(|(_, d)| d);
         let check = self
             .check
             .iter()
@@ -351,7 +370,8 @@ pub(crate) fn convert_diagnostic(
         source: Some("rust-analyzer".to_owned()),
         message: d.message,
         related_information: None,
-        tags: d.unused.then(|| vec![lsp_types::DiagnosticTag::UNNECESSARY]),
+        tags: d.unused
+            .then(|| vec![lsp_types::DiagnosticTag::UNNECESSARY]),
         data: None,
     }
 }
@@ -488,9 +488,7 @@ pub(crate) fn map_rust_diagnostic_to_lsp(
 fn rustc_code_description(code: Option<&str>) -> Option<lsp_types::CodeDescription> {
     code.filter(|code| {
         let mut chars = code.chars();
-        chars.next() == Some('E')
-            && chars.by_ref().take(4).all(|c| c.is_ascii_digit())
-            && chars.next().is_none()
+        chars.next() == Some('E') && chars.by_ref().take(4).all(|c| c.is_ascii_digit()) && chars.next().is_none()
     })
     .and_then(|code| {
         lsp_types::Url::parse(&format!("https://doc.rust-lang.org/error-index.html#{code}"))
@@ -540,7 +538,9 @@ mod tests {
         );
         let snap = state.snapshot();
         let mut actual = map_rust_diagnostic_to_lsp(&config, diagnostic, workspace_root, &snap);
-        actual.iter_mut().for_each(|diag| diag.diagnostic.data = None);
+        actual
+            .iter_mut()
+            .for_each(|diag| diag.diagnostic.data = None);
         expect.assert_debug_eq(&actual)
     }
     #[test]
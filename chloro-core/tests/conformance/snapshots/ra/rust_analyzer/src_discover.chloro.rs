@@ -25,8 +25,8 @@ pub(crate) struct DiscoverCommand {
 #[derive(PartialEq, Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) enum DiscoverArgument {
-    Path(AbsPathBuf),
-    Buildfile(AbsPathBuf),
+    Path(#[serde(serialize_with = "serialize_abs_pathbuf")] AbsPathBuf),
+    Buildfile(#[serde(serialize_with = "serialize_abs_pathbuf")] AbsPathBuf),
 }
 
 fn serialize_abs_pathbuf<S>(path: &AbsPathBuf, se: S) -> Result<S::Ok, S::Error>
@@ -97,9 +97,7 @@ enum DiscoverProjectData {
         error: String,
         source: Option<String>,
     },
-    Progress {
-        message: String,
-    },
+    Progress { message: String },
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -112,9 +110,7 @@ pub(crate) enum DiscoverProjectMessage {
         error: String,
         source: Option<String>,
     },
-    Progress {
-        message: String,
-    },
+    Progress { message: String },
 }
 
 impl DiscoverProjectMessage {
@@ -176,7 +176,12 @@ impl FlycheckHandle {
             stdx::thread::Builder::new(stdx::thread::ThreadIntent::Worker, format!("Flycheck{id}"))
                 .spawn(move || actor.run(receiver))
                 .expect("failed to spawn thread");
-        FlycheckHandle { id, generation: generation.into(), sender, _thread: thread }
+        FlycheckHandle {
+            id,
+            generation: generation.into(),
+            sender,
+            _thread: thread,
+        }
     }
 
     /// Schedule a re-start of the cargo check worker to do a workspace wide check.
@@ -203,7 +208,10 @@ impl FlycheckHandle {
         self.sender
             .send(StateChange::Restart {
                 generation,
-                scope: FlycheckScope::Package { package, workspace_deps },
+                scope: FlycheckScope::Package {
+                    package,
+                    workspace_deps,
+                },
                 saved_file: None,
                 target,
             })
@@ -269,8 +277,7 @@ impl fmt::Debug for FlycheckMessage {
                 workspace_root,
                 diagnostic,
                 package_id,
-            } => f
-                .debug_struct("AddDiagnostic")
+            } => f.debug_struct("AddDiagnostic")
                 .field("id", id)
                 .field("generation", generation)
                 .field("workspace_root", workspace_root)
@@ -278,10 +285,16 @@ impl fmt::Debug for FlycheckMessage {
                 .field("diagnostic_code", &diagnostic.code.as_ref().map(|it| &it.code))
                 .finish(),
             FlycheckMessage::ClearDiagnostics { id, kind } => {
-                f.debug_struct("ClearDiagnostics").field("id", id).field("kind", kind).finish()
+                f.debug_struct("ClearDiagnostics")
+                    .field("id", id)
+                    .field("kind", kind)
+                    .finish()
             }
             FlycheckMessage::Progress { id, progress } => {
-                f.debug_struct("Progress").field("id", id).field("progress", progress).finish()
+                f.debug_struct("Progress")
+                    .field("id", id)
+                    .field("progress", progress)
+                    .finish()
             }
         }
     }
@@ -65,6 +65,7 @@ pub(crate) struct FetchBuildDataResponse {
     pub(crate) build_scripts: Vec<anyhow::Result<WorkspaceBuildScripts>>,
 }
 
+// Enforces drop order
 pub(crate) struct Handle<H, C> {
     pub(crate) handle: H,
     pub(crate) receiver: C,
@@ -186,6 +187,7 @@ pub(crate) struct GlobalState {
     pub(crate) minicore: MiniCoreRustAnalyzerInternalOnly,
 }
 
+// FIXME: This should move to the VFS once the rewrite is done.
 #[derive(Debug, Clone, Default)]
 pub(crate) struct MiniCoreRustAnalyzerInternalOnly {
     pub(crate) minicore_text: Option<String>,
@@ -558,8 +560,10 @@ impl GlobalState {
             check_fixes: Arc::clone(&self.diagnostics.check_fixes),
             mem_docs: self.mem_docs.clone(),
             semantic_tokens_cache: Arc::clone(&self.semantic_tokens_cache),
-            proc_macros_loaded: !self.config.expand_proc_macros()
-                || self.fetch_proc_macros_queue.last_op_result().copied().unwrap_or(false),
+            proc_macros_loaded: !self.config.expand_proc_macros() || self.fetch_proc_macros_queue
+                .last_op_result()
+                .copied()
+                .unwrap_or(false),
             flycheck: self.flycheck.clone(),
         }
     }
@@ -569,7 +573,10 @@ impl GlobalState {
         params: R::Params,
         handler: ReqHandler,
     ) {
-        let request = self.req_queue.outgoing.register(R::METHOD.to_owned(), params, handler);
+        let request = self
+            .req_queue
+            .outgoing
+            .register(R::METHOD.to_owned(), params, handler);
         self.send(request.into());
     }
 
@@ -636,25 +643,26 @@ impl GlobalState {
         mut diagnostics: Vec<lsp_types::Diagnostic>,
     ) {
         // We put this on a separate thread to avoid blocking the main thread with serialization work
-        self.task_pool.handle.spawn_with_sender(stdx::thread::ThreadIntent::Worker, {
-            let sender = self.sender.clone();
-            move |_| {
-                // VSCode assumes diagnostic messages to be non-empty strings, so we need to patch
-                // empty diagnostics. Neither the docs of VSCode nor the LSP spec say whether
-                // diagnostic messages are actually allowed to be empty or not and patching this
-                // in the VSCode client does not work as the assertion happens in the protocol
-                // conversion. So this hack is here to stay, and will be considered a hack
-                // until the LSP decides to state that empty messages are allowed.
-
-                // See https://github.com/rust-lang/rust-analyzer/issues/11404
-                // See https://github.com/rust-lang/rust-analyzer/issues/13130
-                let patch_empty = |message: &mut String| {
+        self.task_pool
+            .handle
+            .spawn_with_sender(stdx::thread::ThreadIntent::Worker, {
+                let sender = self.sender.clone();
+                move |_| {
+                    // VSCode assumes diagnostic messages to be non-empty strings, so we need to patch
+                    // empty diagnostics. Neither the docs of VSCode nor the LSP spec say whether
+                    // diagnostic messages are actually allowed to be empty or not and patching this
+                    // in the VSCode client does not work as the assertion happens in the protocol
+                    // conversion. So this hack is here to stay, and will be considered a hack
+                    // until the LSP decides to state that empty messages are allowed.
+                    // See https://github.com/rust-lang/rust-analyzer/issues/11404
+                    // See https://github.com/rust-lang/rust-analyzer/issues/13130
+                    let patch_empty = |message: &mut String| {
                     if message.is_empty() {
                         " ".clone_into(message);
                     }
                 };
 
-                for d in &mut diagnostics {
+                    for d in &mut diagnostics {
                     patch_empty(&mut d.message);
                     if let Some(dri) = &mut d.related_information {
                         for dri in dri {
@@ -663,13 +671,13 @@ impl GlobalState {
                     }
                 }
 
-                let not = lsp_server::Notification::new(
+                    let not = lsp_server::Notification::new(
                     <lsp_types::notification::PublishDiagnostics as lsp_types::notification::Notification>::METHOD.to_owned(),
                     lsp_types::PublishDiagnosticsParams { uri, diagnostics, version },
                 );
-                _ = sender.send(not.into());
-            }
-        });
+                    _ = sender.send(not.into());
+                }
+            });
     }
 
     pub(crate) fn check_workspaces_msrv(&self) -> impl Iterator<Item = String> + '_ {
@@ -752,7 +760,11 @@ impl GlobalStateSnapshot {
     }
 
     pub(crate) fn file_version(&self, file_id: FileId) -> Option<i32> {
-        Some(self.mem_docs.get(self.vfs_read().file_path(file_id))?.version)
+        Some(
+            self.mem_docs
+                .get(self.vfs_read().file_path(file_id))?
+                .version,
+        )
     }
 
     pub(crate) fn url_file_version(&self, url: &Url) -> Option<i32> {
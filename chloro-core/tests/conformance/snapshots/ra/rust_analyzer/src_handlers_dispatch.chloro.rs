@@ -357,7 +357,11 @@ where
             };
 
             Ok(
-                lsp_server::Response::new_err(id, lsp_server::ErrorCode::InternalError as i32, message),
+                lsp_server::Response::new_err(
+                    id,
+                    lsp_server::ErrorCode::InternalError as i32,
+                    message,
+                ),
             )
         }
     }
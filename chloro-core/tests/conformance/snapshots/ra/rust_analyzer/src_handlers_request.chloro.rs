@@ -61,7 +61,9 @@ pub(crate) fn handle_workspace_reload(state: &mut GlobalState, _: ()) -> anyhow:
     state.build_deps_changed = false;
 
     let req = FetchWorkspaceRequest { path: None, force_crate_graph_reload: false };
-    state.fetch_workspaces_queue.request_op("reload workspace request".to_owned(), req);
+    state
+        .fetch_workspaces_queue
+        .request_op("reload workspace request".to_owned(), req);
     Ok(())
 }
 
@@ -69,7 +71,9 @@ pub(crate) fn handle_proc_macros_rebuild(state: &mut GlobalState, _: ()) -> anyh
     state.proc_macro_clients = Arc::from_iter([]);
     state.build_deps_changed = false;
 
-    state.fetch_build_data_queue.request_op("rebuild proc macros request".to_owned(), ());
+    state
+        .fetch_build_data_queue
+        .request_op("rebuild proc macros request".to_owned(), ());
     Ok(())
 }
 
@@ -224,21 +228,24 @@ pub(crate) fn handle_view_item_tree(
 //   underscores. cargo test requires the real name.
 // - the target kind e.g. bin or lib
 fn all_test_targets(cargo: &CargoWorkspace) -> impl Iterator<Item = TestTarget> {
-    cargo.packages().filter(|p| cargo[*p].is_member).flat_map(|p| {
-        let package = &cargo[p];
-        package.targets.iter().filter_map(|t| {
-            let target = &cargo[*t];
-            if target.kind == TargetKind::BuildScript {
-                None
-            } else {
-                Some(TestTarget {
-                    package: package.name.clone(),
-                    target: target.name.clone(),
-                    kind: target.kind,
-                })
-            }
+    cargo
+        .packages()
+        .filter(|p| cargo[*p].is_member)
+        .flat_map(|p| {
+            let package = &cargo[p];
+            package.targets.iter().filter_map(|t| {
+                let target = &cargo[*t];
+                if target.kind == TargetKind::BuildScript {
+                    None
+                } else {
+                    Some(TestTarget {
+                        package: package.name.clone(),
+                        target: target.name.clone(),
+                        kind: target.kind,
+                    })
+                }
+            })
         })
-    })
 }
 
 fn find_test_target(namespace_root: &str, cargo: &CargoWorkspace) -> Option<TestTarget> {
@@ -330,7 +337,10 @@ pub(crate) fn handle_view_crate_graph(
     params: ViewCrateGraphParams,
 ) -> anyhow::Result<String> {
     let _p = tracing::info_span!("handle_view_crate_graph").entered();
-    let dot = snap.analysis.view_crate_graph(params.full)?.map_err(anyhow::Error::msg)?;
+    let dot = snap
+        .analysis
+        .view_crate_graph(params.full)?
+        .map_err(anyhow::Error::msg)?;
     Ok(dot)
 }
 
@@ -343,7 +353,9 @@ pub(crate) fn handle_expand_macro(
     let line_index = snap.file_line_index(file_id)?;
     let offset = from_proto::offset(&line_index, params.position)?;
 
-    let res = snap.analysis.expand_macro(FilePosition { file_id, offset })?;
+    let res = snap
+        .analysis
+        .expand_macro(FilePosition { file_id, offset })?;
     Ok(res.map(|it| lsp_ext::ExpandedMacro { name: it.name, expansion: it.expansion }))
 }
 
@@ -501,13 +513,15 @@ pub(crate) fn handle_on_type_formatting(
 }
 
 pub(crate) fn empty_diagnostic_report() -> lsp_types::DocumentDiagnosticReportResult {
-    lsp_types::DocumentDiagnosticReportResult::Report(lsp_types::DocumentDiagnosticReport::Full(lsp_types::RelatedFullDocumentDiagnosticReport {
-        related_documents: None,
-        full_document_diagnostic_report: lsp_types::FullDocumentDiagnosticReport {
-            result_id: Some("rust-analyzer".to_owned()),
-            items: vec![],
-        },
-    }))
+    lsp_types::DocumentDiagnosticReportResult::Report(
+        lsp_types::DocumentDiagnosticReport::Full(lsp_types::RelatedFullDocumentDiagnosticReport {
+            related_documents: None,
+            full_document_diagnostic_report: lsp_types::FullDocumentDiagnosticReport {
+                result_id: Some("rust-analyzer".to_owned()),
+                items: vec![],
+            },
+        }),
+    )
 }
 
 pub(crate) fn handle_document_diagnostics(
@@ -528,7 +542,9 @@ pub(crate) fn handle_document_diagnostics(
         return Ok(empty_diagnostic_report());
     }
     let line_index = snap.file_line_index(file_id)?;
-    let supports_related = snap.config.text_document_diagnostic_related_document_support();
+    let supports_related = snap
+        .config
+        .text_document_diagnostic_related_document_support();
 
     let mut related_documents = FxHashMap::default();
     let diagnostics = snap
@@ -550,28 +566,30 @@ pub(crate) fn handle_document_diagnostics(
             }
             None
         });
-    Ok(lsp_types::DocumentDiagnosticReportResult::Report(lsp_types::DocumentDiagnosticReport::Full(lsp_types::RelatedFullDocumentDiagnosticReport {
-        full_document_diagnostic_report: lsp_types::FullDocumentDiagnosticReport {
-            result_id: Some("rust-analyzer".to_owned()),
-            items: diagnostics.collect(),
-        },
-        related_documents: related_documents.is_empty().not().then(|| {
+    Ok(lsp_types::DocumentDiagnosticReportResult::Report(
+        lsp_types::DocumentDiagnosticReport::Full(lsp_types::RelatedFullDocumentDiagnosticReport {
+            full_document_diagnostic_report: lsp_types::FullDocumentDiagnosticReport {
+                result_id: Some("rust-analyzer".to_owned()),
+                items: diagnostics.collect(),
+            },
+            related_documents: related_documents.is_empty().not().then(|| {
                 related_documents
                     .into_iter()
                     .map(|(id, (items, _))| {
                         (
                             to_proto::url(&snap, id),
                             lsp_types::DocumentDiagnosticReportKind::Full(
-                                lsp_types::FullDocumentDiagnosticReport {
-                                    result_id: Some("rust-analyzer".to_owned()),
-                                    items,
-                                },
-                            ),
+                            lsp_types::FullDocumentDiagnosticReport {
+                                result_id: Some("rust-analyzer".to_owned()),
+                                items,
+                            },
+                        ),
                         )
                     })
                     .collect()
             }),
-    })))
+        }),
+    ))
 }
 
 pub(crate) fn handle_document_symbol(
@@ -587,7 +605,9 @@ pub(crate) fn handle_document_symbol(
     let config = snap.config.document_symbol(None);
 
     let structure_nodes = snap.analysis.file_structure(
-        &FileStructureConfig { exclude_locals: config.search_exclude_locals },
+        &FileStructureConfig {
+            exclude_locals: config.search_exclude_locals,
+        },
         file_id,
     )?;
 
@@ -784,10 +804,9 @@ pub(crate) fn handle_will_rename_files(
 
                         let imitate_from_url = from_with_trailing_slash.join("mod.rs").ok()?;
                         let new_file_name = to_path.file_name()?.to_str()?;
-                        Some((
-                            snap.url_to_file_id(&imitate_from_url).ok()?,
-                            new_file_name.to_owned(),
-                        ))
+                        Some(
+                            (snap.url_to_file_id(&imitate_from_url).ok()?, new_file_name.to_owned()),
+                        )
                     } else {
                         let old_name = from_path.file_stem()?.to_str()?;
                         let new_name = to_path.file_stem()?.to_str()?;
@@ -981,7 +1000,9 @@ pub(crate) fn handle_runnables(
     let file_id = try_default!(from_proto::file_id(&snap, &params.text_document.uri)?);
     let source_root = snap.analysis.source_root_id(file_id).ok();
     let line_index = snap.file_line_index(file_id)?;
-    let offset = params.position.and_then(|it| from_proto::offset(&line_index, it).ok());
+    let offset = params
+        .position
+        .and_then(|it| from_proto::offset(&line_index, it).ok());
     let target_spec = TargetSpec::for_file(&snap, file_id)?;
 
     let mut res = Vec::new();
@@ -1327,7 +1348,10 @@ pub(crate) fn handle_prepare_rename(
     let _p = tracing::info_span!("handle_prepare_rename").entered();
     let position = try_default!(from_proto::file_position(&snap, params)?);
 
-    let change = snap.analysis.prepare_rename(position)?.map_err(to_proto::rename_error)?;
+    let change = snap
+        .analysis
+        .prepare_rename(position)?
+        .map_err(to_proto::rename_error)?;
 
     let line_index = snap.file_line_index(position.file_id)?;
     let range = to_proto::range(&line_index, change.range);
@@ -1408,8 +1432,7 @@ pub(crate) fn handle_references(
                 .flat_map(|(file_id, refs)| {
                     refs.into_iter()
                         .filter(|&(_, category)| {
-                            (!exclude_imports || !category.contains(ReferenceCategory::IMPORT))
-                                && (!exclude_tests || !category.contains(ReferenceCategory::TEST))
+                            (!exclude_imports || !category.contains(ReferenceCategory::IMPORT)) && (!exclude_tests || !category.contains(ReferenceCategory::TEST))
                         })
                         .map(move |(range, _)| FileRange { file_id, range })
                 })
@@ -1614,14 +1637,16 @@ fn parse_action_id(action_id: &str) -> anyhow::Result<(usize, SingleResolve), St
                 Err(e) => return Err(format!("Incorrect index string: {e}")),
             };
             let assist_subtype = subtype_str.parse::<usize>().ok();
-            Ok((
-                index,
-                SingleResolve {
+            Ok(
+                (
+                    index,
+                    SingleResolve {
                     assist_id: assist_id_string.to_string(),
                     assist_kind,
                     assist_subtype,
                 },
-            ))
+                ),
+            )
         }
         _ => Err("Action id contains incorrect number of segments".to_owned()),
     }
@@ -1676,7 +1701,10 @@ pub(crate) fn handle_code_lens_resolve(
     let Some(annotation) = from_proto::annotation(&snap, code_lens.range, resolve)? else {
         return Ok(code_lens);
     };
-    let config = snap.config.lens().into_annotation_config(false, snap.minicore());
+    let config = snap
+        .config
+        .lens()
+        .into_annotation_config(false, snap.minicore());
     let annotation = snap.analysis.resolve_annotation(&config, annotation)?;
 
     let mut acc = Vec::new();
@@ -1816,20 +1844,22 @@ pub(crate) fn handle_inlay_hints_resolve(
         },
     )?;
 
-    Ok(resolve_hints
-        .and_then(|it| {
-            to_proto::inlay_hint(
-                &snap,
-                &forced_resolve_inlay_hints_config.fields_to_resolve,
-                &line_index,
-                file_id,
-                it,
-            )
-            .ok()
-        })
-        .filter(|hint| hint.position == original_hint.position)
-        .filter(|hint| hint.kind == original_hint.kind)
-        .unwrap_or(original_hint))
+    Ok(
+        resolve_hints
+            .and_then(|it| {
+                to_proto::inlay_hint(
+                    &snap,
+                    &forced_resolve_inlay_hints_config.fields_to_resolve,
+                    &line_index,
+                    file_id,
+                    it,
+                )
+                .ok()
+            })
+            .filter(|hint| hint.position == original_hint.position)
+            .filter(|hint| hint.kind == original_hint.kind)
+            .unwrap_or(original_hint),
+    )
 }
 
 pub(crate) fn handle_call_hierarchy_prepare(
@@ -1956,7 +1986,9 @@ pub(crate) fn handle_semantic_tokens_full(
     );
 
     // Unconditionally cache the tokens
-    snap.semantic_tokens_cache.lock().insert(params.text_document.uri, semantic_tokens.clone());
+    snap.semantic_tokens_cache
+        .lock()
+        .insert(params.text_document.uri, semantic_tokens.clone());
 
     Ok(Some(semantic_tokens.into()))
 }
@@ -1985,7 +2017,10 @@ pub(crate) fn handle_semantic_tokens_full_delta(
         snap.config.highlighting_non_standard_tokens(),
     );
 
-    let cached_tokens = snap.semantic_tokens_cache.lock().remove(&params.text_document.uri);
+    let cached_tokens = snap
+        .semantic_tokens_cache
+        .lock()
+        .remove(&params.text_document.uri);
 
     if let Some(cached_tokens @ lsp_types::SemanticTokens { result_id: Some(prev_id), .. }) =
         &cached_tokens
@@ -1998,7 +2033,9 @@ pub(crate) fn handle_semantic_tokens_full_delta(
 
     // Clone first to keep the lock short
     let semantic_tokens_clone = semantic_tokens.clone();
-    snap.semantic_tokens_cache.lock().insert(params.text_document.uri, semantic_tokens_clone);
+    snap.semantic_tokens_cache
+        .lock()
+        .insert(params.text_document.uri, semantic_tokens_clone);
 
     Ok(Some(semantic_tokens.into()))
 }
@@ -2051,7 +2088,9 @@ pub(crate) fn handle_open_docs(
     };
 
     let sysroot = sysroot.and_then(|p| p.root()).map(|it| it.as_str());
-    let target_dir = cargo.map(|cargo| cargo.target_directory()).map(|p| p.as_str());
+    let target_dir = cargo
+        .map(|cargo| cargo.target_directory())
+        .map(|p| p.as_str());
 
     let Ok(remote_urls) = snap.analysis.external_docs(position, target_dir, sysroot) else {
         return if snap.config.local_docs() {
@@ -2125,8 +2164,11 @@ pub(crate) fn handle_view_recursive_memory_layout(
     let line_index = snap.file_line_index(file_id)?;
     let offset = from_proto::offset(&line_index, params.position)?;
 
-    let res = snap.analysis.get_recursive_memory_layout(FilePosition { file_id, offset })?;
-    Ok(res.map(|it| lsp_ext::RecursiveMemoryLayout {
+    let res = snap
+        .analysis
+        .get_recursive_memory_layout(FilePosition { file_id, offset })?;
+    Ok(
+        res.map(|it| lsp_ext::RecursiveMemoryLayout {
         nodes: it
             .nodes
             .iter()
@@ -2141,11 +2183,15 @@ pub(crate) fn handle_view_recursive_memory_layout(
                 children_len: n.children_len,
             })
             .collect(),
-    }))
+    }),
+    )
 }
 
 fn to_command_link(command: lsp_types::Command, tooltip: String) -> lsp_ext::CommandLink {
-    lsp_ext::CommandLink { tooltip: Some(tooltip), command }
+    lsp_ext::CommandLink {
+        tooltip: Some(tooltip),
+        command,
+    }
 }
 
 fn show_impl_command_link(
@@ -2532,14 +2578,18 @@ pub(crate) fn fetch_dependency_list(
         .into_iter()
         .filter_map(|it| {
             let root_file_path = state.file_id_to_file_path(it.root_file_id);
-            crate_path(&root_file_path).and_then(to_url).map(|path| CrateInfoResult {
+            crate_path(&root_file_path)
+                .and_then(to_url)
+                .map(|path| CrateInfoResult {
                 name: it.name,
                 version: it.version,
                 path,
             })
         })
         .collect();
-    Ok(FetchDependencyListResult { crates: crate_infos })
+    Ok(FetchDependencyListResult {
+        crates: crate_infos,
+    })
 }
 
 pub(crate) fn internal_testing_fetch_config(
@@ -36,6 +36,7 @@ pub enum InternalTestingFetchConfigResponse {
 impl Request for InternalTestingFetchConfig {
     type Params = InternalTestingFetchConfigParams;
 
+    // Option is solely to circumvent Default bound.
     type Result = Option<InternalTestingFetchConfigResponse>;
 
     const METHOD: &'static str = "rust-analyzer-internal/internalTestingFetchConfig";
@@ -328,9 +329,7 @@ impl Notification for AbortRunTest {
 #[serde(rename_all = "camelCase", tag = "tag")]
 pub enum TestState {
     Passed,
-    Failed {
-        message: String,
-    },
+    Failed { message: String },
     Skipped,
     Started,
     Enqueued,
@@ -794,6 +793,7 @@ pub struct CommandLinkGroup {
     pub commands: Vec<CommandLink>,
 }
 
+// LSP v3.15 Command does not have a `tooltip` field, vscode supports one.
 #[derive(Debug, PartialEq, Clone, Default, Deserialize, Serialize)]
 pub struct CommandLink {
     #[serde(flatten)]
@@ -14,7 +14,9 @@ use crate::{
 };
 
 pub(crate) fn abs_path(url: &lsp_types::Url) -> anyhow::Result<AbsPathBuf> {
-    let path = url.to_file_path().map_err(|()| anyhow::format_err!("url is not a file"))?;
+    let path = url
+        .to_file_path()
+        .map_err(|()| anyhow::format_err!("url is not a file"))?;
     Ok(AbsPathBuf::try_from(Utf8PathBuf::from_path_buf(path).unwrap()).unwrap())
 }
 
@@ -152,7 +154,5 @@ pub(crate) fn annotation(
             })
         }
     }
-    .map(
-        Some,
-    )
+    .map(Some)
 }
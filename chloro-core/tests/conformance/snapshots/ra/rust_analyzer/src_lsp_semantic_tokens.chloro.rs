@@ -178,7 +178,10 @@ impl ModifierSet {
 
 impl ops::BitOrAssign<SemanticTokenModifier> for ModifierSet {
     fn bitor_assign(&mut self, rhs: SemanticTokenModifier) {
-        let idx = SUPPORTED_MODIFIERS.iter().position(|it| it == &rhs).unwrap();
+        let idx = SUPPORTED_MODIFIERS
+            .iter()
+            .position(|it| it == &rhs)
+            .unwrap();
         self.0 |= 1 << idx;
     }
 }
@@ -195,7 +198,12 @@ pub(crate) struct SemanticTokensBuilder {
 
 impl SemanticTokensBuilder {
     pub(crate) fn new(id: String) -> Self {
-        SemanticTokensBuilder { id, prev_line: 0, prev_char: 0, data: Default::default() }
+        SemanticTokensBuilder {
+            id,
+            prev_line: 0,
+            prev_char: 0,
+            data: Default::default(),
+        }
     }
 
     /// Push a new token onto the builder
@@ -239,7 +247,11 @@ pub(crate) fn diff_tokens(
     old: &[SemanticToken],
     new: &[SemanticToken],
 ) -> Vec<SemanticTokensEdit> {
-    let offset = new.iter().zip(old.iter()).take_while(|&(n, p)| n == p).count();
+    let offset = new
+        .iter()
+        .zip(old.iter())
+        .take_while(|&(n, p)| n == p)
+        .count();
 
     let (_, old) = old.split_at(offset);
     let (_, new) = new.split_at(offset);
@@ -205,7 +205,9 @@ pub(crate) fn snippet_text_edit(
     annotation: Option<ChangeAnnotationId>,
     client_supports_annotations: bool,
 ) -> lsp_ext::SnippetTextEdit {
-    let annotation_id = annotation.filter(|_| client_supports_annotations).map(|it| it.to_string());
+    let annotation_id = annotation
+        .filter(|_| client_supports_annotations)
+        .map(|it| it.to_string());
     let text_edit = text_edit(line_index, indel);
     let insert_text_format =
         if is_snippet { Some(lsp_types::InsertTextFormat::SNIPPET) } else { None };
@@ -221,7 +223,10 @@ pub(crate) fn text_edit_vec(
     line_index: &LineIndex,
     text_edit: TextEdit,
 ) -> Vec<lsp_types::TextEdit> {
-    text_edit.into_iter().map(|indel| self::text_edit(line_index, indel)).collect()
+    text_edit
+        .into_iter()
+        .map(|indel| self::text_edit(line_index, indel))
+        .collect()
 }
 
 pub(crate) fn snippet_text_edit_vec(
@@ -258,7 +263,11 @@ pub(crate) fn completion_items(
         items.retain(|item| !item.deprecated);
     }
 
-    let max_relevance = items.iter().map(|it| it.relevance.score()).max().unwrap_or_default();
+    let max_relevance = items
+        .iter()
+        .map(|it| it.relevance.score())
+        .max()
+        .unwrap_or_default();
     let mut res = Vec::with_capacity(items.len());
     let client_commands = config.client_commands();
     for item in items {
@@ -340,7 +349,9 @@ fn completion_item(
         Some(text_edit.unwrap())
     };
 
-    let insert_text_format = item.is_snippet.then_some(lsp_types::InsertTextFormat::SNIPPET);
+    let insert_text_format = item
+        .is_snippet
+        .then_some(lsp_types::InsertTextFormat::SNIPPET);
     let tags = if fields_to_resolve.resolve_tags {
         something_to_resolve |= item.deprecated;
         None
@@ -738,7 +749,9 @@ pub(crate) fn semantic_tokens(
     semantics_tokens_augments_syntax_tokens: bool,
     non_standard_tokens: bool,
 ) -> lsp_types::SemanticTokens {
-    let id = TOKEN_RESULT_COUNTER.fetch_add(1, Ordering::SeqCst).to_string();
+    let id = TOKEN_RESULT_COUNTER
+        .fetch_add(1, Ordering::SeqCst)
+        .to_string();
     let mut builder = semantic_tokens::SemanticTokensBuilder::new(id);
 
     for highlight_range in highlights {
@@ -1290,7 +1303,10 @@ pub(crate) fn snippet_text_document_edit(
             edit.annotation_id = Some(outside_workspace_annotation_id())
         }
     }
-    Ok(lsp_ext::SnippetTextDocumentEdit { text_document, edits })
+    Ok(lsp_ext::SnippetTextDocumentEdit {
+        text_document,
+        edits,
+    })
 }
 
 pub(crate) fn snippet_text_document_ops(
@@ -1476,7 +1492,10 @@ pub(crate) fn call_hierarchy_item(
 ) -> Cancellable<lsp_types::CallHierarchyItem> {
     let name = target.name.to_string();
     let detail = target.description.clone();
-    let kind = target.kind.map(symbol_kind).unwrap_or(lsp_types::SymbolKind::FUNCTION);
+    let kind = target
+        .kind
+        .map(symbol_kind)
+        .unwrap_or(lsp_types::SymbolKind::FUNCTION);
     let (uri, range, selection_range) = location_info(snap, target)?;
     Ok(lsp_types::CallHierarchyItem {
         name,
@@ -1585,8 +1604,7 @@ pub(crate) fn runnable(
                     cargo_args,
                     cwd: cwd.into(),
                     executable_args,
-                    environment: spec
-                        .sysroot_root
+                    environment: spec.sysroot_root
                         .map(|root| ("RUSTC_TOOLCHAIN".to_owned(), root.to_string()))
                         .into_iter()
                         .collect(),
@@ -1839,7 +1857,10 @@ pub(crate) fn test_item(
             .file
             .map(|f| lsp_types::TextDocumentIdentifier { uri: url(snap, f) }),
         range: line_index.and_then(|l| Some(range(l, test_item.text_range?))),
-        runnable: test_item.runnable.and_then(|r| runnable(snap, r).ok()).flatten(),
+        runnable: test_item
+            .runnable
+            .and_then(|r| runnable(snap, r).ok())
+            .flatten(),
     })
 }
 
@@ -1888,6 +1909,7 @@ pub(crate) mod command {
         lsp_types::Command {
             title: "Interpret".into(),
             command: "rust-analyzer.interpretFunction".into(),
+            // FIXME: use the `_runnable` here.
             arguments: Some(vec![]),
         }
     }
@@ -1939,7 +1961,12 @@ pub(crate) fn make_update_runnable(
         return None;
     };
 
-    r.environment.extend(update_test.env().iter().map(|(k, v)| (k.to_string(), v.to_string())));
+    r.environment.extend(
+        update_test
+            .env()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string())),
+    );
 
     if update_test.insta {
         r.cargo_args.insert(0, "insta".to_owned());
@@ -2988,11 +3015,7 @@ struct ProcMacro {
         let edit = edit.finish();
         let snippets = SnippetEdit::new(vec![Snippet::Tabstop(10.into())]);
 
-        check_rendered_snippets_in_source(
-            "yeah\r\n<-tabstop here",
-            edit,
-            snippets,
-            expect![[r#"
+        check_rendered_snippets_in_source("yeah\r\n<-tabstop here", edit, snippets, expect![[r#"
             [
                 SnippetTextEdit {
                     range: Range {
@@ -3012,8 +3035,7 @@ struct ProcMacro {
                     annotation_id: None,
                 },
             ]
-        "#]],
-        )
+        "#]])
     }
     // `Url` is not able to parse windows paths on unix machines.
     #[test]
@@ -14,7 +14,10 @@ use crate::{
 };
 
 pub(crate) fn invalid_params_error(message: String) -> LspError {
-    LspError { code: lsp_server::ErrorCode::InvalidParams as i32, message }
+    LspError {
+        code: lsp_server::ErrorCode::InvalidParams as i32,
+        message,
+    }
 }
 
 pub(crate) fn notification_is<N: lsp_types::notification::Notification>(
@@ -59,18 +62,13 @@ impl GlobalState {
                     else { return };
                     if let Ok(Some(_item)) = crate::from_json::<
                         <lsp_types::request::ShowMessageRequest as lsp_types::request::Request>::Result,
-                    >(
-                        lsp_types::request::ShowMessageRequest::METHOD, &result
-                    ) {
+                    >(lsp_types::request::ShowMessageRequest::METHOD, &result) {
                         this.send_notification::<lsp_ext::OpenServerLogs>(());
                     }
                 },
             ),
             false => self.send_notification::<lsp_types::notification::ShowMessage>(
-                lsp_types::ShowMessageParams {
-                    typ,
-                    message,
-                },
+                lsp_types::ShowMessageParams { typ, message },
             ),
         }
     }
@@ -130,9 +130,7 @@ pub(crate) enum DiscoverProjectParam {
 pub(crate) enum PrimeCachesProgress {
     Begin,
     Report(ide::ParallelPrimeCachesProgress),
-    End {
-        cancelled: bool,
-    },
+    End { cancelled: bool },
 }
 
 impl fmt::Debug for Event {
@@ -298,9 +296,7 @@ impl GlobalState {
                 Ok(Event::FetchWorkspaces(self.fetch_ws_receiver.take().unwrap().1))
             },
         }
-        .map(
-            Some,
-        )
+        .map(Some)
     }
 
     fn handle_event(&mut self, event: Event) {
@@ -548,19 +544,25 @@ impl GlobalState {
         tracing::debug!(%cause, "will prime caches");
         let num_worker_threads = self.config.prime_caches_num_threads();
 
-        self.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, {
-            let analysis = AssertUnwindSafe(self.snapshot().analysis);
-            move |sender| {
-                sender.send(Task::PrimeCaches(PrimeCachesProgress::Begin)).unwrap();
-                let res = analysis.parallel_prime_caches(num_worker_threads, |progress| {
+        self.task_pool
+            .handle
+            .spawn_with_sender(ThreadIntent::Worker, {
+                let analysis = AssertUnwindSafe(self.snapshot().analysis);
+                move |sender| {
+                    sender
+                        .send(Task::PrimeCaches(PrimeCachesProgress::Begin))
+                        .unwrap();
+                    let res = analysis.parallel_prime_caches(num_worker_threads, |progress| {
                     let report = PrimeCachesProgress::Report(progress);
                     sender.send(Task::PrimeCaches(report)).unwrap();
                 });
-                sender
-                    .send(Task::PrimeCaches(PrimeCachesProgress::End { cancelled: res.is_err() }))
-                    .unwrap();
-            }
-        });
+                    sender
+                        .send(Task::PrimeCaches(PrimeCachesProgress::End {
+                            cancelled: res.is_err(),
+                        }))
+                        .unwrap();
+                }
+            });
     }
 
     fn update_diagnostics(&mut self) {
@@ -604,16 +606,18 @@ impl GlobalState {
             // Diagnostics are triggered by the user typing
             // so we run them on a latency sensitive thread.
             let snapshot = self.snapshot();
-            self.task_pool.handle.spawn_with_sender(ThreadIntent::LatencySensitive, {
-                let subscriptions = subscriptions.clone();
-                // Do not fetch semantic diagnostics (and populate query results) if we haven't even
-                // loaded the initial workspace yet.
-                let fetch_semantic =
+            self.task_pool
+                .handle
+                .spawn_with_sender(ThreadIntent::LatencySensitive, {
+                    let subscriptions = subscriptions.clone();
+                    // Do not fetch semantic diagnostics (and populate query results) if we haven't even
+                    // loaded the initial workspace yet.
+                    let fetch_semantic =
                     self.vfs_done && self.fetch_workspaces_queue.last_op_result().is_some();
-                move |sender| {
-                    // We aren't observing the semantics token cache here
-                    let snapshot = AssertUnwindSafe(&snapshot);
-                    let Ok(diags) = std::panic::catch_unwind(|| {
+                    move |sender| {
+                        // We aren't observing the semantics token cache here
+                        let snapshot = AssertUnwindSafe(&snapshot);
+                        let Ok(diags) = std::panic::catch_unwind(|| {
                         fetch_native_diagnostics(
                             &snapshot,
                             subscriptions.clone(),
@@ -623,12 +627,12 @@ impl GlobalState {
                     }) else {
                         return;
                     };
-                    sender
-                        .send(Task::Diagnostics(DiagnosticsTaskKind::Syntax(generation, diags)))
-                        .unwrap();
+                        sender
+                            .send(Task::Diagnostics(DiagnosticsTaskKind::Syntax(generation, diags)))
+                            .unwrap();
 
-                    if fetch_semantic {
-                        let Ok(diags) = std::panic::catch_unwind(|| {
+                        if fetch_semantic {
+                            let Ok(diags) = std::panic::catch_unwind(|| {
                             fetch_native_diagnostics(
                                 &snapshot,
                                 subscriptions.clone(),
@@ -638,14 +642,16 @@ impl GlobalState {
                         }) else {
                             return;
                         };
-                        sender
-                            .send(Task::Diagnostics(DiagnosticsTaskKind::Semantic(
-                                generation, diags,
-                            )))
-                            .unwrap();
+                            sender
+                                .send(
+                                    Task::Diagnostics(
+                                        DiagnosticsTaskKind::Semantic(generation, diags),
+                                    ),
+                                )
+                                .unwrap();
+                        }
                     }
-                }
-            });
+                });
             start = end;
         }
     }
@@ -672,34 +678,40 @@ impl GlobalState {
 
         // Updating tests are triggered by the user typing
         // so we run them on a latency sensitive thread.
-        self.task_pool.handle.spawn(ThreadIntent::LatencySensitive, {
-            let snapshot = self.snapshot();
-            move || {
-                let tests = subscriptions
-                    .iter()
-                    .copied()
-                    .filter_map(|f| snapshot.analysis.discover_tests_in_file(f).ok())
-                    .flatten()
-                    .collect::<Vec<_>>();
-
-                Task::DiscoverTest(lsp_ext::DiscoverTestResults {
-                    tests: tests
-                        .into_iter()
-                        .filter_map(|t| {
-                            let line_index = t.file.and_then(|f| snapshot.file_line_index(f).ok());
-                            to_proto::test_item(&snapshot, t, line_index.as_ref())
-                        })
-                        .collect(),
-                    scope: None,
-                    scope_file: Some(
-                        subscriptions
+        self.task_pool
+            .handle
+            .spawn(ThreadIntent::LatencySensitive, {
+                let snapshot = self.snapshot();
+                move || {
+                    let tests = subscriptions
+                        .iter()
+                        .copied()
+                        .filter_map(|f| snapshot.analysis.discover_tests_in_file(f).ok())
+                        .flatten()
+                        .collect::<Vec<_>>();
+
+                    Task::DiscoverTest(lsp_ext::DiscoverTestResults {
+                        tests: tests
                             .into_iter()
-                            .map(|f| TextDocumentIdentifier { uri: to_proto::url(&snapshot, f) })
+                            .filter_map(|t| {
+                                let line_index = t
+                                    .file
+                                    .and_then(|f| snapshot.file_line_index(f).ok());
+                                to_proto::test_item(&snapshot, t, line_index.as_ref())
+                            })
                             .collect(),
-                    ),
-                })
-            }
-        });
+                        scope: None,
+                        scope_file: Some(
+                            subscriptions
+                                .into_iter()
+                                .map(
+                                    |f| TextDocumentIdentifier { uri: to_proto::url(&snapshot, f) },
+                                )
+                                .collect(),
+                        ),
+                    })
+                }
+            });
     }
 
     fn update_status_or_notify(&mut self) {
@@ -864,8 +876,7 @@ impl GlobalState {
                     let path = VfsPath::from(path);
                     // if the file is in mem docs, it's managed by the client via notifications
                     // so only set it if its not in there
-                    if !self.mem_docs.contains(&path)
-                        && (is_changed || vfs.file_id(&path).is_none()) {
+                    if !self.mem_docs.contains(&path) && (is_changed || vfs.file_id(&path).is_none()) {
                         vfs.set_file_contents(path, contents);
                     }
                 }
@@ -917,44 +928,48 @@ impl GlobalState {
             QueuedTask::CheckIfIndexed(uri) => {
                 let snap = self.snapshot();
 
-                self.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, move |sender| {
-                    let _p = tracing::info_span!("GlobalState::check_if_indexed").entered();
-                    tracing::debug!(?uri, "handling uri");
-                    let Some(id) = from_proto::file_id(&snap, &uri).expect("unable to get FileId")
+                self.task_pool
+                    .handle
+                    .spawn_with_sender(ThreadIntent::Worker, move |sender| {
+                        let _p = tracing::info_span!("GlobalState::check_if_indexed").entered();
+                        tracing::debug!(?uri, "handling uri");
+                        let Some(id) = from_proto::file_id(&snap, &uri).expect("unable to get FileId")
                     else {
                         return;
                     };
-                    if let Ok(crates) = &snap.analysis.crates_for(id) {
-                        if crates.is_empty() {
-                            if snap.config.discover_workspace_config().is_some() {
-                                let path =
+                        if let Ok(crates) = &snap.analysis.crates_for(id) {
+                            if crates.is_empty() {
+                                if snap.config.discover_workspace_config().is_some() {
+                                    let path =
                                     from_proto::abs_path(&uri).expect("Unable to get AbsPath");
-                                let arg = DiscoverProjectParam::Path(path);
-                                sender.send(Task::DiscoverLinkedProjects(arg)).unwrap();
+                                    let arg = DiscoverProjectParam::Path(path);
+                                    sender.send(Task::DiscoverLinkedProjects(arg)).unwrap();
+                                }
+                            } else {
+                                tracing::debug!(?uri, "is indexed");
                             }
-                        } else {
-                            tracing::debug!(?uri, "is indexed");
                         }
-                    }
-                });
+                    });
             }
             QueuedTask::CheckProcMacroSources(modified_rust_files) => {
                 let analysis = AssertUnwindSafe(self.snapshot().analysis);
-                self.task_pool.handle.spawn_with_sender(stdx::thread::ThreadIntent::Worker, {
-                    move |sender| {
-                        if modified_rust_files.into_iter().any(|file_id| {
-                            // FIXME: Check whether these files could be build script related
-                            match analysis.crates_for(file_id) {
-                                Ok(crates) => crates.iter().any(|&krate| {
-                                    analysis.is_proc_macro_crate(krate).is_ok_and(|it| it)
-                                }),
-                                _ => false,
+                self.task_pool
+                    .handle
+                    .spawn_with_sender(stdx::thread::ThreadIntent::Worker, {
+                        move |sender| {
+                            if modified_rust_files.into_iter().any(|file_id| {
+                                // FIXME: Check whether these files could be build script related
+                                match analysis.crates_for(file_id) {
+                                    Ok(crates) => crates.iter().any(|&krate| {
+                                        analysis.is_proc_macro_crate(krate).is_ok_and(|it| it)
+                                    }),
+                                    _ => false,
+                                }
+                            }) {
+                                sender.send(Task::BuildDepsHaveChanged).unwrap();
                             }
-                        }) {
-                            sender.send(Task::BuildDepsHaveChanged).unwrap();
                         }
-                    }
-                });
+                    });
             }
         }
     }
@@ -1070,7 +1085,8 @@ impl GlobalState {
             FlycheckMessage::ClearDiagnostics {
                 id,
                 kind: ClearDiagnosticsKind::OlderThan(generation, ClearScope::Package(package_id)),
-            } => self.diagnostics.clear_check_older_than_for_package(id, package_id, generation),
+            } => self.diagnostics
+                .clear_check_older_than_for_package(id, package_id, generation),
             FlycheckMessage::Progress { id, progress } => {
                 let (state, message) = match progress {
                     flycheck::Progress::DidStart => (Progress::Begin, None),
@@ -1240,26 +1256,25 @@ impl GlobalState {
         use crate::handlers::notification as handlers;
         use lsp_types::notification as notifs;
 
-        NotificationDispatcher { not: Some(not), global_state: self }
-            .on_sync_mut::<notifs::Cancel>(handlers::handle_cancel)
-            .on_sync_mut::<notifs::WorkDoneProgressCancel>(
-                handlers::handle_work_done_progress_cancel,
-            )
-            .on_sync_mut::<notifs::DidOpenTextDocument>(handlers::handle_did_open_text_document)
-            .on_sync_mut::<notifs::DidChangeTextDocument>(handlers::handle_did_change_text_document)
-            .on_sync_mut::<notifs::DidCloseTextDocument>(handlers::handle_did_close_text_document)
-            .on_sync_mut::<notifs::DidSaveTextDocument>(handlers::handle_did_save_text_document)
-            .on_sync_mut::<notifs::DidChangeConfiguration>(
-                handlers::handle_did_change_configuration,
-            )
-            .on_sync_mut::<notifs::DidChangeWorkspaceFolders>(
-                handlers::handle_did_change_workspace_folders,
-            )
-            .on_sync_mut::<notifs::DidChangeWatchedFiles>(handlers::handle_did_change_watched_files)
-            .on_sync_mut::<lsp_ext::CancelFlycheck>(handlers::handle_cancel_flycheck)
-            .on_sync_mut::<lsp_ext::ClearFlycheck>(handlers::handle_clear_flycheck)
-            .on_sync_mut::<lsp_ext::RunFlycheck>(handlers::handle_run_flycheck)
-            .on_sync_mut::<lsp_ext::AbortRunTest>(handlers::handle_abort_run_test)
-            .finish();
+        NotificationDispatcher {
+            not: Some(not),
+            global_state: self,
+        }
+        .on_sync_mut::<notifs::Cancel>(handlers::handle_cancel)
+        .on_sync_mut::<notifs::WorkDoneProgressCancel>(handlers::handle_work_done_progress_cancel)
+        .on_sync_mut::<notifs::DidOpenTextDocument>(handlers::handle_did_open_text_document)
+        .on_sync_mut::<notifs::DidChangeTextDocument>(handlers::handle_did_change_text_document)
+        .on_sync_mut::<notifs::DidCloseTextDocument>(handlers::handle_did_close_text_document)
+        .on_sync_mut::<notifs::DidSaveTextDocument>(handlers::handle_did_save_text_document)
+        .on_sync_mut::<notifs::DidChangeConfiguration>(handlers::handle_did_change_configuration)
+        .on_sync_mut::<notifs::DidChangeWorkspaceFolders>(
+            handlers::handle_did_change_workspace_folders,
+        )
+        .on_sync_mut::<notifs::DidChangeWatchedFiles>(handlers::handle_did_change_watched_files)
+        .on_sync_mut::<lsp_ext::CancelFlycheck>(handlers::handle_cancel_flycheck)
+        .on_sync_mut::<lsp_ext::ClearFlycheck>(handlers::handle_clear_flycheck)
+        .on_sync_mut::<lsp_ext::RunFlycheck>(handlers::handle_run_flycheck)
+        .on_sync_mut::<lsp_ext::AbortRunTest>(handlers::handle_abort_run_test)
+        .finish();
     }
 }
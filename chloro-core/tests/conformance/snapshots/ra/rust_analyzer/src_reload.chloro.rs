@@ -70,13 +70,7 @@ impl GlobalState {
     /// This indicates that we've fully loaded the projects and
     /// are ready to do semantic work.
     pub(crate) fn is_quiescent(&self) -> bool {
-        self.vfs_done
-            && self.fetch_ws_receiver.is_none()
-            && !self.fetch_workspaces_queue.op_in_progress()
-            && !self.fetch_build_data_queue.op_in_progress()
-            && !self.fetch_proc_macros_queue.op_in_progress()
-            && !self.discover_workspace_queue.op_in_progress()
-            && self.vfs_progress_config_version >= self.vfs_config_version
+        self.vfs_done && self.fetch_ws_receiver.is_none() && !self.fetch_workspaces_queue.op_in_progress() && !self.fetch_build_data_queue.op_in_progress() && !self.fetch_proc_macros_queue.op_in_progress() && !self.discover_workspace_queue.op_in_progress() && self.vfs_progress_config_version >= self.vfs_config_version
     }
 
     /// Is the server ready to respond to analysis dependent LSP requests?
@@ -124,7 +118,8 @@ impl GlobalState {
 
         if self.config.cfg_set_test(None) != old_config.cfg_set_test(None) {
             let req = FetchWorkspaceRequest { path: None, force_crate_graph_reload: false };
-            self.fetch_workspaces_queue.request_op("cfg_set_test config changed".to_owned(), req)
+            self.fetch_workspaces_queue
+                .request_op("cfg_set_test config changed".to_owned(), req)
         }
     }
 
@@ -286,24 +281,26 @@ impl GlobalState {
     ) {
         info!(%cause, "will fetch workspaces");
 
-        self.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, {
-            let linked_projects = self.config.linked_or_discovered_projects();
-            let detached_files: Vec<_> = self
-                .config
-                .detached_files()
-                .iter()
-                .cloned()
-                .map(ManifestPath::try_from)
-                .filter_map(Result::ok)
-                .collect();
-            let cargo_config = self.config.cargo(None);
-            let discover_command = self.config.discover_workspace_config().cloned();
-            let is_quiescent = !(self.discover_workspace_queue.op_in_progress()
+        self.task_pool
+            .handle
+            .spawn_with_sender(ThreadIntent::Worker, {
+                let linked_projects = self.config.linked_or_discovered_projects();
+                let detached_files: Vec<_> = self
+                    .config
+                    .detached_files()
+                    .iter()
+                    .cloned()
+                    .map(ManifestPath::try_from)
+                    .filter_map(Result::ok)
+                    .collect();
+                let cargo_config = self.config.cargo(None);
+                let discover_command = self.config.discover_workspace_config().cloned();
+                let is_quiescent = !(self.discover_workspace_queue.op_in_progress()
                 || self.vfs_progress_config_version < self.vfs_config_version
                 || !self.vfs_done);
 
-            move |sender| {
-                let progress = {
+                move |sender| {
+                    let progress = {
                     let sender = sender.clone();
                     move |msg| {
                         sender
@@ -312,9 +309,11 @@ impl GlobalState {
                     }
                 };
 
-                sender.send(Task::FetchWorkspace(ProjectWorkspaceProgress::Begin)).unwrap();
+                    sender
+                        .send(Task::FetchWorkspace(ProjectWorkspaceProgress::Begin))
+                        .unwrap();
 
-                if let (Some(_command), Some(path)) = (&discover_command, &path) {
+                    if let (Some(_command), Some(path)) = (&discover_command, &path) {
                     let build = linked_projects.iter().find_map(|project| match project {
                         LinkedProject::InlineProjectJson(it) => it.crate_by_buildfile(path),
                         _ => None,
@@ -330,9 +329,9 @@ impl GlobalState {
                     }
                 }
 
-                let mut workspaces: Vec<_> = linked_projects
-                    .iter()
-                    .map(|project| match project {
+                    let mut workspaces: Vec<_> = linked_projects
+                        .iter()
+                        .map(|project| match project {
                         LinkedProject::ProjectManifest(manifest) => {
                             debug!(path = %manifest, "loading project from manifest");
 
@@ -351,10 +350,10 @@ impl GlobalState {
                             Ok(workspace)
                         }
                     })
-                    .collect();
+                        .collect();
 
-                let mut i = 0;
-                while i < workspaces.len() {
+                    let mut i = 0;
+                    while i < workspaces.len() {
                     if let Ok(w) = &workspaces[i] {
                         let dupes: Vec<_> = workspaces[i + 1..]
                             .iter()
@@ -367,22 +366,21 @@ impl GlobalState {
                     i += 1;
                 }
 
-                if !detached_files.is_empty() {
+                    if !detached_files.is_empty() {
                     workspaces.extend(project_model::ProjectWorkspace::load_detached_files(
                         detached_files,
                         &cargo_config,
                     ));
                 }
 
-                info!(?workspaces, "did fetch workspaces");
-                sender
-                    .send(Task::FetchWorkspace(ProjectWorkspaceProgress::End(
-                        workspaces,
-                        force_crate_graph_reload,
-                    )))
-                    .unwrap();
-            }
-        });
+                    info!(?workspaces, "did fetch workspaces");
+                    sender
+                        .send(Task::FetchWorkspace(
+                            ProjectWorkspaceProgress::End(workspaces, force_crate_graph_reload),
+                        ))
+                        .unwrap();
+                }
+            });
     }
 
     pub(crate) fn fetch_build_data(&mut self, cause: Cause) {
@@ -391,24 +389,30 @@ impl GlobalState {
         let config = self.config.cargo(None);
         let root_path = self.config.root_path().clone();
 
-        self.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, move |sender| {
-            sender.send(Task::FetchBuildData(BuildDataProgress::Begin)).unwrap();
+        self.task_pool
+            .handle
+            .spawn_with_sender(ThreadIntent::Worker, move |sender| {
+                sender
+                    .send(Task::FetchBuildData(BuildDataProgress::Begin))
+                    .unwrap();
 
-            let progress = {
+                let progress = {
                 let sender = sender.clone();
                 move |msg| {
                     sender.send(Task::FetchBuildData(BuildDataProgress::Report(msg))).unwrap()
                 }
             };
-            let res = ProjectWorkspace::run_all_build_scripts(
+                let res = ProjectWorkspace::run_all_build_scripts(
                 &workspaces,
                 &config,
                 &progress,
                 &root_path,
             );
 
-            sender.send(Task::FetchBuildData(BuildDataProgress::End((workspaces, res)))).unwrap();
-        });
+                sender
+                    .send(Task::FetchBuildData(BuildDataProgress::End((workspaces, res))))
+                    .unwrap();
+            });
     }
 
     pub(crate) fn fetch_proc_macros(
@@ -421,20 +425,24 @@ impl GlobalState {
         let ignored_proc_macros = self.config.ignored_proc_macros(None).clone();
         let proc_macro_clients = self.proc_macro_clients.clone();
 
-        self.task_pool.handle.spawn_with_sender(ThreadIntent::Worker, move |sender| {
-            sender.send(Task::LoadProcMacros(ProcMacroProgress::Begin)).unwrap();
+        self.task_pool
+            .handle
+            .spawn_with_sender(ThreadIntent::Worker, move |sender| {
+                sender
+                    .send(Task::LoadProcMacros(ProcMacroProgress::Begin))
+                    .unwrap();
 
-            let ignored_proc_macros = &ignored_proc_macros;
-            let progress = {
+                let ignored_proc_macros = &ignored_proc_macros;
+                let progress = {
                 let sender = sender.clone();
                 &move |msg| {
                     sender.send(Task::LoadProcMacros(ProcMacroProgress::Report(msg))).unwrap()
                 }
             };
 
-            let mut builder = ProcMacrosBuilder::default();
-            let proc_macro_clients = proc_macro_clients.iter().chain(iter::repeat(&None));
-            for (client, paths) in proc_macro_clients.zip(paths) {
+                let mut builder = ProcMacrosBuilder::default();
+                let proc_macro_clients = proc_macro_clients.iter().chain(iter::repeat(&None));
+                for (client, paths) in proc_macro_clients.zip(paths) {
                 for (crate_id, res) in paths.iter() {
                     let expansion_res = match client {
                         Some(Ok(client)) => match res {
@@ -462,9 +470,11 @@ impl GlobalState {
                 }
             }
 
-            change.set_proc_macros(builder);
-            sender.send(Task::LoadProcMacros(ProcMacroProgress::End(change))).unwrap();
-        });
+                change.set_proc_macros(builder);
+                sender
+                    .send(Task::LoadProcMacros(ProcMacroProgress::End(change)))
+                    .unwrap();
+            });
     }
 
     pub(crate) fn switch_workspaces(&mut self, cause: Cause) {
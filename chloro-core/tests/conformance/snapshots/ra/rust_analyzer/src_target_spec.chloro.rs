@@ -246,7 +246,9 @@ fn required_features(cfg_expr: &CfgExpr, features: &mut Vec<String>) {
             features.push(value.to_string())
         }
         CfgExpr::All(preds) => {
-            preds.iter().for_each(|cfg| required_features(cfg, features));
+            preds
+                .iter()
+                .for_each(|cfg| required_features(cfg, features));
         }
         CfgExpr::Any(preds) => {
             for cfg in preds.iter() {
@@ -42,9 +42,7 @@ pub(crate) enum CargoTestOutput {
     },
     Suite,
     Finished,
-    Custom {
-        text: String,
-    },
+    Custom { text: String },
 }
 
 pub(crate) struct CargoTestOutputParser {
@@ -53,7 +51,9 @@ pub(crate) struct CargoTestOutputParser {
 
 impl CargoTestOutputParser {
     pub(crate) fn new(test_target: &TestTarget) -> Self {
-        Self { target: test_target.clone() }
+        Self {
+            target: test_target.clone(),
+        }
     }
 }
 
@@ -67,7 +67,9 @@ impl CargoParser<CargoTestMessage> for CargoTestOutputParser {
             output: if let Ok(message) = CargoTestOutput::deserialize(&mut deserializer) {
                 message
             } else {
-                CargoTestOutput::Custom { text: line.to_owned() }
+                CargoTestOutput::Custom {
+                    text: line.to_owned(),
+                }
             },
         })
     }
@@ -140,12 +142,7 @@ impl CargoTestHandle {
         }
 
         Ok(Self {
-            _handle: CommandHandle::spawn(
-                cmd,
-                CargoTestOutputParser::new(&test_target),
-                sender,
-                None,
-            )?,
+            _handle: CommandHandle::spawn(cmd, CargoTestOutputParser::new(&test_target), sender, None)?,
         })
     }
 }
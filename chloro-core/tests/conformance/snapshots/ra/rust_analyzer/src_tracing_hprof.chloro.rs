@@ -86,7 +86,12 @@ where
                 && !metadata.target().starts_with("chalk")
         });
 
-        Self { aggregate: true, write_filter, _inner: PhantomData }.with_filter(profile_filter)
+        Self {
+            aggregate: true,
+            write_filter,
+            _inner: PhantomData,
+        }
+        .with_filter(profile_filter)
     }
 }
 
@@ -146,7 +151,12 @@ where
 
         match span.parent() {
             Some(parent_span) => {
-                parent_span.extensions_mut().get_mut::<Data>().unwrap().children.push(node);
+                parent_span
+                    .extensions_mut()
+                    .get_mut::<Data>()
+                    .unwrap()
+                    .children
+                    .push(node);
             }
             None => {
                 if self.aggregate {
@@ -24,7 +24,10 @@ struct JsonData {
 
 impl JsonData {
     fn new(name: &'static str) -> Self {
-        Self { name, start: Instant::now() }
+        Self {
+            name,
+            start: Instant::now(),
+        }
     }
 }
 
@@ -36,7 +39,10 @@ pub(crate) struct TimingLayer<S, W> {
 
 impl<S, W> TimingLayer<S, W> {
     pub(crate) fn new(writer: W) -> Self {
-        Self { writer, _inner: PhantomData }
+        Self {
+            writer,
+            _inner: PhantomData,
+        }
     }
 }
 
@@ -69,7 +75,10 @@ where
         let data = JsonDataInner { name: data.name, elapsed_ms: data.start.elapsed().as_millis() };
         let mut out = serde_json::to_string(&data).expect("Unable to serialize data");
         out.push('\n');
-        self.writer.make_writer().write_all(out.as_bytes()).expect("Unable to write data");
+        self.writer
+            .make_writer()
+            .write_all(out.as_bytes())
+            .expect("Unable to write data");
     }
 }
 
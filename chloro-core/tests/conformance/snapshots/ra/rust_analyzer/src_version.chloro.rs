@@ -53,5 +53,9 @@ pub const fn version() -> VersionInfo {
         _ => None,
     };
 
-    VersionInfo { version, release_channel, commit_info }
+    VersionInfo {
+        version,
+        release_channel,
+        commit_info,
+    }
 }
@@ -102,7 +102,9 @@ fn f() {
         text_document: server.doc_id("src/lib.rs"),
         work_done_progress_params: WorkDoneProgressParams::default(),
     });
-    let mut hints = serde_json::from_value::<Option<Vec<InlayHint>>>(res).unwrap().unwrap();
+    let mut hints = serde_json::from_value::<Option<Vec<InlayHint>>>(res)
+        .unwrap()
+        .unwrap();
     let hint = hints.pop().unwrap();
     assert!(hint.data.is_some());
     assert!(
@@ -1236,7 +1238,13 @@ pub fn foo(_input: TokenStream) -> TokenStream {
         ),
         work_done_progress_params: Default::default(),
     });
-    let value = res.get("contents").unwrap().get("value").unwrap().as_str().unwrap();
+    let value = res
+        .get("contents")
+        .unwrap()
+        .get("value")
+        .unwrap()
+        .as_str()
+        .unwrap();
 
     expect![[r#"
 
@@ -47,10 +47,15 @@ impl RatomlTest {
             project = project.with_config(client_config);
         }
 
-        let server = project.server_with_lock(true).wait_until_workspace_is_loaded();
+        let server = project
+            .server_with_lock(true)
+            .wait_until_workspace_is_loaded();
 
         let mut case = Self { urls: vec![], server, tmp_path };
-        let urls = fixtures.iter().map(|fixture| case.fixture_path(fixture)).collect::<Vec<_>>();
+        let urls = fixtures
+            .iter()
+            .map(|fixture| case.fixture_path(fixture))
+            .collect::<Vec<_>>();
         case.urls = urls;
         case
     }
@@ -82,71 +87,84 @@ impl RatomlTest {
             path = path.join(piece);
         }
 
-        Url::parse(format!("file://{}", path.into_string().replace("C:\\", "/c:/").replace('\\', "/"))
-        .as_str())
+        Url::parse(
+            format!("file://{}", path.into_string().replace("C:\\", "/c:/").replace('\\', "/"))
+                .as_str(),
+        )
         .unwrap()
     }
 
     fn create(&mut self, fixture_path: &str, text: String) {
         let url = self.fixture_path(fixture_path);
 
-        self.server.notification::<DidOpenTextDocument>(DidOpenTextDocumentParams {
-            text_document: TextDocumentItem {
-                uri: url.clone(),
-                language_id: "rust".to_owned(),
-                version: 0,
-                text: String::new(),
-            },
-        });
-
-        self.server.notification::<DidChangeTextDocument>(DidChangeTextDocumentParams {
-            text_document: VersionedTextDocumentIdentifier { uri: url, version: 0 },
-            content_changes: vec![TextDocumentContentChangeEvent {
+        self.server
+            .notification::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: url.clone(),
+                    language_id: "rust".to_owned(),
+                    version: 0,
+                    text: String::new(),
+                },
+            });
+
+        self.server
+            .notification::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier {
+                    uri: url,
+                    version: 0,
+                },
+                content_changes: vec![TextDocumentContentChangeEvent {
                 range: None,
                 range_length: None,
                 text,
             }],
-        });
+            });
     }
 
     fn delete(&mut self, file_idx: usize) {
-        self.server.notification::<DidOpenTextDocument>(DidOpenTextDocumentParams {
-            text_document: TextDocumentItem {
-                uri: self.urls[file_idx].clone(),
-                language_id: "rust".to_owned(),
-                version: 0,
-                text: "".to_owned(),
-            },
-        });
+        self.server
+            .notification::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: self.urls[file_idx].clone(),
+                    language_id: "rust".to_owned(),
+                    version: 0,
+                    text: "".to_owned(),
+                },
+            });
 
         // See if deleting ratoml file will make the config of interest to return to its default value.
-        self.server.notification::<DidSaveTextDocument>(DidSaveTextDocumentParams {
-            text_document: TextDocumentIdentifier { uri: self.urls[file_idx].clone() },
-            text: Some("".to_owned()),
-        });
+        self.server
+            .notification::<DidSaveTextDocument>(DidSaveTextDocumentParams {
+                text_document: TextDocumentIdentifier {
+                    uri: self.urls[file_idx].clone(),
+                },
+                text: Some("".to_owned()),
+            });
     }
 
     fn edit(&mut self, file_idx: usize, text: String) {
-        self.server.notification::<DidOpenTextDocument>(DidOpenTextDocumentParams {
-            text_document: TextDocumentItem {
-                uri: self.urls[file_idx].clone(),
-                language_id: "rust".to_owned(),
-                version: 0,
-                text: String::new(),
-            },
-        });
-
-        self.server.notification::<DidChangeTextDocument>(DidChangeTextDocumentParams {
-            text_document: VersionedTextDocumentIdentifier {
-                uri: self.urls[file_idx].clone(),
-                version: 0,
-            },
-            content_changes: vec![TextDocumentContentChangeEvent {
+        self.server
+            .notification::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: self.urls[file_idx].clone(),
+                    language_id: "rust".to_owned(),
+                    version: 0,
+                    text: String::new(),
+                },
+            });
+
+        self.server
+            .notification::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier {
+                    uri: self.urls[file_idx].clone(),
+                    version: 0,
+                },
+                content_changes: vec![TextDocumentContentChangeEvent {
                 range: None,
                 range_length: None,
                 text,
             }],
-        });
+            });
     }
 
     fn query(
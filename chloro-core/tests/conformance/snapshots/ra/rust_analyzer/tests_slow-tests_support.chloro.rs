@@ -88,7 +88,11 @@ impl Project<'_> {
 
     pub(crate) fn run_lsif(self) -> String {
         let tmp_dir = self.tmp_dir.unwrap_or_else(|| {
-            if self.root_dir_contains_symlink { TestDir::new_symlink() } else { TestDir::new() }
+            if self.root_dir_contains_symlink {
+                TestDir::new_symlink()
+            } else {
+                TestDir::new()
+            }
         });
 
         let FixtureWithProjectMeta {
@@ -156,7 +160,11 @@ impl Project<'_> {
         };
 
         let tmp_dir = self.tmp_dir.unwrap_or_else(|| {
-            if self.root_dir_contains_symlink { TestDir::new_symlink() } else { TestDir::new() }
+            if self.root_dir_contains_symlink {
+                TestDir::new_symlink()
+            } else {
+                TestDir::new()
+            }
         });
 
         static INIT: Once = Once::new();
@@ -316,7 +324,9 @@ impl Server {
 
     pub(crate) fn doc_id(&self, rel_path: &str) -> TextDocumentIdentifier {
         let path = self.dir.path().join(rel_path);
-        TextDocumentIdentifier { uri: Url::from_file_path(path).unwrap() }
+        TextDocumentIdentifier {
+            uri: Url::from_file_path(path).unwrap(),
+        }
     }
 
     pub(crate) fn notification<N>(&self, params: N::Params)
@@ -86,7 +86,9 @@ impl Drop for TestDir {
         }
 
         let filetype = fs::symlink_metadata(&self.path).unwrap().file_type();
-        let actual_path = filetype.is_symlink().then(|| fs::read_link(&self.path).unwrap());
+        let actual_path = filetype
+            .is_symlink()
+            .then(|| fs::read_link(&self.path).unwrap());
 
         if let Some(actual_path) = actual_path {
             remove_dir_all(Utf8Path::from_path(&actual_path).unwrap()).unwrap_or_else(|err| {
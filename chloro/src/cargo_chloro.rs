@@ -0,0 +1,149 @@
+//! cargo-chloro: Format crates with chloro as `cargo chloro`
+//!
+//! Finds the root module of each target of a package from its manifest and formats the crates
+//! with the `chloro` binary.
+
+#![allow(clippy::multiple_crate_versions)]
+
+#[cfg(test)]
+mod test_support;
+
+/// The `cargo chloro` subcommand.
+#[cfg(feature = "cli")]
+pub mod cargo_chloro {
+    pub mod args;
+    pub mod manifest;
+
+    use args::{parse_args, print_usage};
+    use manifest::{Package, Workspace, find_manifest, load_workspace};
+    use std::io;
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    /// Entry point for `cargo chloro`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if command-line argument parsing fails.
+    ///
+    /// The process exits with the status of `chloro`, which is non-zero in check mode if files
+    /// need formatting.
+    pub fn main() -> io::Result<()> {
+        let (args, passthrough) = parse_args()?;
+
+        if args.help {
+            print_usage();
+            std::process::exit(0);
+        }
+
+        let manifest_path = if args.manifest_path.is_empty() {
+            std::env::current_dir()
+                .and_then(|dir| find_manifest(&dir))
+                .unwrap_or_else(|e| exit_with_error(&e))
+        } else {
+            PathBuf::from(&args.manifest_path)
+        };
+        let workspace = load_workspace(&manifest_path).unwrap_or_else(|e| exit_with_error(&e));
+
+        let mut roots: Vec<&PathBuf> = Vec::new();
+        for package in select_packages(&workspace, &args.package, args.all) {
+            if args.verbose {
+                eprintln!("Package: {} ({})", package.name, package.dir.display());
+            }
+            for target in &package.targets {
+                if !roots.contains(&target) {
+                    roots.push(target);
+                }
+            }
+        }
+
+        if roots.is_empty() {
+            if args.verbose {
+                eprintln!("No crate targets found.");
+            }
+            return Ok(());
+        }
+
+        let mut chloro = Command::new(chloro_binary());
+        chloro.arg(if args.check { "--check" } else { "--write" });
+        if args.verbose {
+            chloro.arg("--verbose");
+        }
+        for root in roots {
+            chloro.arg("--crate-root").arg(root);
+        }
+        let status = chloro.args(&passthrough).status().unwrap_or_else(|e| {
+            exit_with_error(&io::Error::new(
+                e.kind(),
+                format!("failed to run chloro: {e}"),
+            ))
+        });
+        std::process::exit(status.code().unwrap_or(1));
+    }
+
+    /// The packages named by `-p`, every package with `--all`, or else the current package, or
+    /// every package of a virtual workspace.
+    fn select_packages<'a>(
+        workspace: &'a Workspace,
+        names: &[String],
+        all: bool,
+    ) -> Vec<&'a Package> {
+        if !names.is_empty() {
+            return names
+                .iter()
+                .map(|name| {
+                    workspace
+                        .packages
+                        .iter()
+                        .find(|package| &package.name == name)
+                        .unwrap_or_else(|| {
+                            let known: Vec<&str> = workspace
+                                .packages
+                                .iter()
+                                .map(|package| package.name.as_str())
+                                .collect();
+                            exit_with_error(&io::Error::new(
+                                io::ErrorKind::NotFound,
+                                format!(
+                                    "package `{name}` not found in workspace (packages: {})",
+                                    known.join(", ")
+                                ),
+                            ))
+                        })
+                })
+                .collect();
+        }
+        match workspace.current {
+            Some(current) if !all => vec![&workspace.packages[current]],
+            _ => workspace.packages.iter().collect(),
+        }
+    }
+
+    /// The `chloro` binary installed alongside this one, or else the one on `PATH`.
+    fn chloro_binary() -> PathBuf {
+        let name = format!("chloro{}", std::env::consts::EXE_SUFFIX);
+        std::env::current_exe()
+            .ok()
+            .map(|exe| exe.with_file_name(&name))
+            .filter(|path| path.is_file())
+            .unwrap_or_else(|| PathBuf::from(name))
+    }
+
+    fn exit_with_error(error: &io::Error) -> ! {
+        eprintln!("Error: {error}");
+        std::process::exit(1);
+    }
+}
+
+/// Hint replacement for when the subcommand is used without building the cli feature.
+#[cfg(not(feature = "cli"))]
+pub mod cargo_chloro {
+    /// Provide a hint to the user that they did not build this crate with the cli feature.
+    pub fn main() {
+        eprintln!("Please build with the cli feature to run cargo chloro");
+        eprintln!("Example: cargo install chloro --features cli");
+        std::process::exit(1);
+    }
+}
+
+pub use cargo_chloro::main;
@@ -0,0 +1,75 @@
+use facet::Facet;
+use std::io;
+
+#[derive(Facet)]
+pub struct CargoArgs {
+    /// Package(s) to format (repeatable)
+    #[facet(named, short = 'p', long, default)]
+    pub package: Vec<String>,
+
+    /// Format every package in the workspace
+    #[facet(named, long, default)]
+    pub all: bool,
+
+    /// Check mode: exit with error if files need formatting
+    #[facet(named, short = 'c', long, default)]
+    pub check: bool,
+
+    /// Path to the Cargo.toml of the package or workspace to format
+    #[facet(named, long, default)]
+    pub manifest_path: String,
+
+    /// Show verbose output
+    #[facet(named, short = 'v', long, default)]
+    pub verbose: bool,
+
+    /// Show this help message
+    #[facet(named, short = 'h', long, default)]
+    pub help: bool,
+}
+
+/// Parse the command-line arguments, returning those after `--` to pass through to chloro.
+///
+/// Cargo runs `cargo chloro` as `cargo-chloro chloro ...`, so a leading `chloro` is skipped.
+pub fn parse_args() -> io::Result<(CargoArgs, Vec<String>)> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "chloro") {
+        args.remove(0);
+    }
+    let passthrough = match args.iter().position(|arg| arg == "--") {
+        Some(index) => args.split_off(index).split_off(1),
+        None => Vec::new(),
+    };
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let args = facet_args::from_slice(&args)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{e}")))?;
+    Ok((args, passthrough))
+}
+
+pub fn print_usage() {
+    println!("Usage: cargo chloro [OPTIONS] [-- <CHLORO_OPTIONS>...]");
+    println!();
+    println!("Format the source files of the current crate with chloro.");
+    println!();
+    println!("The files are found by following `mod` declarations from the root module of");
+    println!("each target, so files that are not part of the crate are left alone.");
+    println!();
+    println!("Options:");
+    println!("  -p, --package <NAME>");
+    println!("                     Package to format (repeatable)");
+    println!("      --all          Format every package in the workspace");
+    println!("  -c, --check        Check if files need formatting (exit 1 if so)");
+    println!("      --manifest-path <PATH>");
+    println!("                     Path to Cargo.toml");
+    println!("  -v, --verbose      Show verbose output");
+    println!("  -h, --help         Show this help message");
+    println!();
+    println!("Options after '--' are passed to chloro.");
+    println!();
+    println!("Examples:");
+    println!("  # Format the current crate in place");
+    println!("  cargo chloro");
+    println!();
+    println!("  # Check every package of the workspace, showing diffs");
+    println!("  cargo chloro --all --check -- --diff");
+}
@@ -0,0 +1,403 @@
+//! Reading packages and their targets from `Cargo.toml` manifests, without invoking cargo.
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A package of a workspace.
+#[derive(Debug, Clone)]
+pub struct Package {
+    pub name: String,
+    /// Directory containing the package's `Cargo.toml`
+    pub dir: PathBuf,
+    /// Root modules of the package's targets: library, binaries, examples, tests, benches and
+    /// build script
+    pub targets: Vec<PathBuf>,
+}
+
+/// The packages of a workspace, and which of them the manifest that was looked up belongs to.
+#[derive(Debug)]
+pub struct Workspace {
+    pub packages: Vec<Package>,
+    /// Index of the package of the manifest that was looked up, unless it is a virtual manifest
+    pub current: Option<usize>,
+}
+
+/// Find the `Cargo.toml` in `dir` or the nearest ancestor that has one.
+pub fn find_manifest(dir: &Path) -> io::Result<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "could not find Cargo.toml in {} or any parent directory",
+                    dir.display()
+                ),
+            )
+        })
+}
+
+/// Load the workspace that the manifest at `manifest_path` belongs to.
+pub fn load_workspace(manifest_path: &Path) -> io::Result<Workspace> {
+    let manifest_path = manifest_path.canonicalize()?;
+    let manifest_dir = parent_dir(&manifest_path);
+    let manifest = read_manifest(&manifest_path)?;
+
+    let root = if manifest.contains_key("workspace") {
+        Some((manifest_dir.clone(), manifest.clone()))
+    } else {
+        find_workspace_root(&manifest_dir)?
+    };
+
+    let mut dirs = Vec::new();
+    match &root {
+        Some((root_dir, root_manifest)) => {
+            if root_manifest.contains_key("package") {
+                dirs.push(root_dir.clone());
+            }
+            for member in workspace_members(root_dir, root_manifest) {
+                if !dirs.contains(&member) {
+                    dirs.push(member);
+                }
+            }
+            if manifest.contains_key("package") && !dirs.contains(&manifest_dir) {
+                dirs.push(manifest_dir.clone());
+            }
+        }
+        None => dirs.push(manifest_dir.clone()),
+    }
+
+    let mut packages = Vec::new();
+    for dir in dirs {
+        let manifest = read_manifest(&dir.join("Cargo.toml"))?;
+        if let Some(package) = package(&dir, &manifest) {
+            packages.push(package);
+        }
+    }
+    let current = packages
+        .iter()
+        .position(|package| package.dir == manifest_dir);
+    Ok(Workspace { packages, current })
+}
+
+fn read_manifest(path: &Path) -> io::Result<toml::Table> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    text.parse().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+fn parent_dir(path: &Path) -> PathBuf {
+    path.parent().unwrap_or(Path::new(".")).to_path_buf()
+}
+
+/// The nearest manifest above `dir` with a `[workspace]` table, and its directory.
+fn find_workspace_root(dir: &Path) -> io::Result<Option<(PathBuf, toml::Table)>> {
+    for ancestor in dir.ancestors().skip(1) {
+        let path = ancestor.join("Cargo.toml");
+        if path.is_file() {
+            let manifest = read_manifest(&path)?;
+            if manifest.contains_key("workspace") {
+                return Ok(Some((ancestor.to_path_buf(), manifest)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// The directories of the `workspace.members` of a root manifest, less its `workspace.exclude`.
+fn workspace_members(root: &Path, manifest: &toml::Table) -> Vec<PathBuf> {
+    let workspace = manifest.get("workspace").and_then(toml::Value::as_table);
+    let exclude: Vec<PathBuf> = string_list(workspace, "exclude")
+        .iter()
+        .map(|path| root.join(path))
+        .collect();
+    let mut members = Vec::new();
+    for pattern in string_list(workspace, "members") {
+        for dir in expand_glob(root, &pattern) {
+            if dir.join("Cargo.toml").is_file() && !exclude.iter().any(|ex| dir.starts_with(ex)) {
+                members.push(dir);
+            }
+        }
+    }
+    members
+}
+
+fn string_list(table: Option<&toml::Table>, key: &str) -> Vec<String> {
+    table
+        .and_then(|table| table.get(key))
+        .and_then(toml::Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|value| value.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The directories under `root` matching a path pattern whose components may use `*` and `?`.
+fn expand_glob(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut dirs = vec![root.to_path_buf()];
+    for component in pattern.split('/').filter(|c| !c.is_empty() && *c != ".") {
+        if !component.contains(['*', '?']) {
+            dirs = dirs.into_iter().map(|dir| dir.join(component)).collect();
+            continue;
+        }
+        let mut matched = Vec::new();
+        for dir in dirs {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            let mut names: Vec<_> = entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| wildcard_match(component, name))
+                .collect();
+            names.sort();
+            matched.extend(names.into_iter().map(|name| dir.join(name)));
+        }
+        dirs = matched;
+    }
+    dirs
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any run of characters and `?` any one.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// The package described by the manifest in `dir`, unless it is a virtual manifest.
+fn package(dir: &Path, manifest: &toml::Table) -> Option<Package> {
+    let package = manifest.get("package")?.as_table()?;
+    let name = package.get("name")?.as_str()?.to_string();
+    let mut targets = Vec::new();
+    let mut add = |path: PathBuf| {
+        if path.is_file() && !targets.contains(&path) {
+            targets.push(path);
+        }
+    };
+
+    match manifest.get("lib").and_then(|lib| lib.get("path")) {
+        Some(path) => add(dir.join(path.as_str().unwrap_or_default())),
+        None => add(dir.join("src/lib.rs")),
+    }
+    add(dir.join("src/main.rs"));
+    for (kind, auto_dir) in [
+        ("bin", "src/bin"),
+        ("example", "examples"),
+        ("test", "tests"),
+        ("bench", "benches"),
+    ] {
+        for target in manifest
+            .get(kind)
+            .and_then(toml::Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(path) = target.get("path").and_then(toml::Value::as_str) {
+                add(dir.join(path));
+            } else if let Some(name) = target.get("name").and_then(toml::Value::as_str) {
+                add(dir.join(auto_dir).join(format!("{name}.rs")));
+                add(dir.join(auto_dir).join(name).join("main.rs"));
+            }
+        }
+        for path in auto_targets(&dir.join(auto_dir)) {
+            add(path);
+        }
+    }
+    match package.get("build") {
+        Some(toml::Value::String(path)) => add(dir.join(path)),
+        Some(_) => {}
+        None => add(dir.join("build.rs")),
+    }
+
+    Some(Package {
+        name,
+        dir: dir.to_path_buf(),
+        targets,
+    })
+}
+
+/// The targets cargo discovers automatically in `dir`: `*.rs` files and `*/main.rs`.
+fn auto_targets(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut targets: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            if path.is_dir() {
+                Some(path.join("main.rs")).filter(|main| main.is_file())
+            } else {
+                Some(path).filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            }
+        })
+        .collect();
+    targets.sort();
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::write;
+    use tempfile::TempDir;
+
+    fn parse(text: &str) -> toml::Table {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn wildcard_match_stars_and_question_marks() {
+        assert!(wildcard_match("crates", "crates"));
+        assert!(!wildcard_match("crates", "crate"));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("chloro-*", "chloro-core"));
+        assert!(!wildcard_match("chloro-*", "chloro"));
+        assert!(wildcard_match("*-core", "chloro-core"));
+        assert!(wildcard_match("a*b*c", "aXbYbZc"));
+        assert!(!wildcard_match("a*b*c", "aXbYbZ"));
+        assert!(wildcard_match("v?", "v1"));
+        assert!(!wildcard_match("v?", "v"));
+        assert!(!wildcard_match("v?", "v10"));
+        assert!(wildcard_match("é?", "éé"));
+    }
+
+    #[test]
+    fn expand_glob_matches_directories_per_component() {
+        let tmp = TempDir::new().unwrap();
+        for dir in ["crates/a", "crates/b", "crates/c/nested", "tools/x"] {
+            fs::create_dir_all(tmp.path().join(dir)).unwrap();
+        }
+        write(tmp.path(), "crates/file.rs", "");
+        let root = tmp.path();
+
+        assert_eq!(
+            expand_glob(root, "crates/*"),
+            [
+                root.join("crates/a"),
+                root.join("crates/b"),
+                root.join("crates/c")
+            ]
+        );
+        assert_eq!(expand_glob(root, "./tools/x"), [root.join("tools/x")]);
+        assert_eq!(expand_glob(root, "*/c/*"), [root.join("crates/c/nested")]);
+        assert_eq!(expand_glob(root, "crates/?"), expand_glob(root, "crates/*"));
+        assert!(expand_glob(root, "missing/*").is_empty());
+    }
+
+    #[test]
+    fn package_finds_declared_and_automatic_targets() {
+        let tmp = TempDir::new().unwrap();
+        for path in [
+            "src/lib.rs",
+            "src/main.rs",
+            "src/bin/one.rs",
+            "src/bin/two/main.rs",
+            "src/bin/two/util.rs",
+            "examples/demo.rs",
+            "tests/it.rs",
+            "benches/speed.rs",
+            "tools/gen.rs",
+            "build.rs",
+        ] {
+            write(tmp.path(), path, "");
+        }
+        let manifest = parse(
+            r#"
+            [package]
+            name = "demo"
+
+            [[bin]]
+            name = "gen"
+            path = "tools/gen.rs"
+
+            [[bin]]
+            name = "one"
+            "#,
+        );
+        let package = package(tmp.path(), &manifest).unwrap();
+        assert_eq!(package.name, "demo");
+        assert_eq!(package.dir, tmp.path());
+        let targets: Vec<_> = package
+            .targets
+            .iter()
+            .map(|path| path.strip_prefix(tmp.path()).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                "src/lib.rs",
+                "src/main.rs",
+                "tools/gen.rs",
+                "src/bin/one.rs",
+                "src/bin/two/main.rs",
+                "examples/demo.rs",
+                "tests/it.rs",
+                "benches/speed.rs",
+                "build.rs",
+            ]
+            .map(PathBuf::from)
+        );
+    }
+
+    #[test]
+    fn package_follows_lib_and_build_paths() {
+        let tmp = TempDir::new().unwrap();
+        write(tmp.path(), "lib/root.rs", "");
+        write(tmp.path(), "src/lib.rs", "");
+        write(tmp.path(), "build.rs", "");
+        let manifest = parse(
+            r#"
+            [package]
+            name = "paths"
+            build = false
+
+            [lib]
+            path = "lib/root.rs"
+            "#,
+        );
+        let package = package(tmp.path(), &manifest).unwrap();
+        assert_eq!(package.targets, [tmp.path().join("lib/root.rs")]);
+    }
+
+    #[test]
+    fn package_of_virtual_manifest_is_none() {
+        let tmp = TempDir::new().unwrap();
+        let manifest = parse("[workspace]\nmembers = [\"crates/*\"]\n");
+        assert!(package(tmp.path(), &manifest).is_none());
+    }
+}
@@ -8,16 +8,31 @@
 #[cfg(feature = "cli")]
 pub mod cli {
     pub mod args;
+    pub mod config;
+    pub mod diff;
+    pub mod git;
+    pub mod hook;
     pub mod logs;
+    pub mod message_format;
     pub mod orchestrate;
     pub mod report;
+    pub mod rustfmt;
     pub mod worker;
 
-    use args::{Args, print_usage};
-    use orchestrate::{discover_rust_files, format_all};
-    use report::{aggregate_results, print_summary};
+    use crate::vlog;
+    use args::{
+        Command, ConfigArgs, HookArgs, parse_args, print_config_usage, print_hook_usage,
+        print_usage,
+    };
+    use config::{ConfigResolver, print_config};
+    use git::ChangeSet;
+    use message_format::{MessageFormat, write_report};
+    use orchestrate::{Job, crate_root_files, discover_rust_files, format_all};
+    use report::{ColorChoice, aggregate_results, print_diffs, print_summary};
+    use std::collections::HashMap;
     use std::io;
     use std::path::Path;
+    use worker::format_stdin;
 
     /// Entry point for the chloro CLI
     ///
@@ -33,16 +48,61 @@ pub mod cli {
     ///
     /// The process will also exit with a non-zero status if migration fails.
     pub fn main() -> io::Result<()> {
-        let args: Args = facet_args::from_std_args()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{e}")))?;
+        let mut args = match parse_args()? {
+            Command::Format(args) => args,
+            Command::Config(args) => return run_config(&args),
+            Command::Hook(args) => return run_hook(&args),
+        };
 
         if args.help {
             print_usage();
             std::process::exit(0);
         }
 
+        let Some(color) = ColorChoice::parse(&args.color) else {
+            eprintln!(
+                "Error: Invalid --color value: {} (expected auto, always or never)",
+                args.color
+            );
+            std::process::exit(1);
+        };
+
+        let Some(message_format) = MessageFormat::parse(&args.message_format) else {
+            eprintln!(
+                "Error: Invalid --message-format value: {} (expected human, json, junit, checkstyle or sarif)",
+                args.message_format
+            );
+            std::process::exit(1);
+        };
+
+        let mut resolver = ConfigResolver::new(
+            &args.config_path,
+            &args.config,
+            &args.exclude,
+            args.strict_config,
+        )
+        .unwrap_or_else(|e| exit_with_error(&e));
+
+        if args.stdin {
+            if args.write {
+                eprintln!("Error: --write cannot be used with --stdin");
+                std::process::exit(1);
+            }
+            if !args.sources.is_empty() {
+                eprintln!("Error: Source paths cannot be used with --stdin");
+                std::process::exit(1);
+            }
+        } else if args.sources.is_empty() {
+            args.sources.push(".".to_string());
+        }
+
         if args.verbose {
-            eprintln!("Sources: {}", args.sources.join(", "));
+            let sources = if args.stdin {
+                "<stdin>".to_string()
+            } else {
+                args.sources.join(", ")
+            };
+            eprintln!("Sources: {}", sources);
             eprintln!(
                 "Mode: {}",
                 if args.check {
@@ -56,49 +116,150 @@ pub mod cli {
             eprintln!();
         }
 
-        // Collect all files from all source paths
-        let mut all_files = Vec::new();
+        let results = if args.stdin {
+            let stdin_path = Path::new(&args.stdin_filepath);
+            let ignored = !args.stdin_filepath.is_empty()
+                && resolver
+                    .is_ignored(stdin_path, false)
+                    .unwrap_or_else(|e| exit_with_error(&e));
+            let resolved = if args.stdin_filepath.is_empty() {
+                resolver.for_dir(Path::new("."))
+            } else {
+                resolver.for_file(stdin_path)
+            }
+            .unwrap_or_else(|e| exit_with_error(&e));
+            if ignored {
+                vlog!(args, {"Skipping ignored file: {}", args.stdin_filepath});
+            }
+            vec![format_stdin((!ignored).then_some(&*resolved.config), &args)]
+        } else {
+            if !args.stdin_filepath.is_empty() {
+                eprintln!("Error: --stdin-filepath requires --stdin");
+                std::process::exit(1);
+            }
 
-        for source in &args.sources {
-            let source_path = Path::new(source);
-            if !source_path.exists() {
-                eprintln!("Error: Source path does not exist: {}", source);
+            let from_git = args.staged || !args.changed_since.is_empty();
+            if args.changed_lines_only && !from_git {
+                eprintln!("Error: --changed-lines-only requires --changed-since or --staged");
+                std::process::exit(1);
+            }
+            if from_git && !args.crate_root.is_empty() {
+                eprintln!("Error: --crate-root cannot be used with --changed-since or --staged");
                 std::process::exit(1);
             }
 
-            if source_path.is_file() {
-                all_files.push(source_path.to_path_buf());
+            // Collect all files from all source paths, from the crate roots or from git
+            let mut all_files = Vec::new();
+            let mut unresolved = Vec::new();
+            let mut changed_lines = HashMap::new();
+
+            if from_git {
+                let changes = ChangeSet {
+                    since: Some(args.changed_since.as_str()).filter(|rev| !rev.is_empty()),
+                    staged: args.staged,
+                };
+                let changed = changes
+                    .changed_files(&args.sources, args.changed_lines_only)
+                    .unwrap_or_else(|e| exit_with_error(&e));
+                for file in changed {
+                    if resolver
+                        .is_ignored(&file.path, false)
+                        .unwrap_or_else(|e| exit_with_error(&e))
+                    {
+                        vlog!(args, {"Skipping ignored file: {}", file.path.display()});
+                        continue;
+                    }
+                    if let Some(lines) = file.lines {
+                        changed_lines.insert(file.path.clone(), lines);
+                    }
+                    all_files.push(file.path);
+                }
+            } else if args.crate_root.is_empty() {
+                for source in &args.sources {
+                    let source_path = Path::new(source);
+                    if !source_path.exists() {
+                        eprintln!("Error: Source path does not exist: {}", source);
+                        std::process::exit(1);
+                    }
+
+                    if source_path.is_file() {
+                        if resolver
+                            .is_ignored(source_path, false)
+                            .unwrap_or_else(|e| exit_with_error(&e))
+                        {
+                            vlog!(args, {"Skipping ignored file: {}", source});
+                        } else {
+                            all_files.push(source_path.to_path_buf());
+                        }
+                    } else {
+                        all_files.extend(
+                            discover_rust_files(source_path, &mut resolver, args.hidden)
+                                .unwrap_or_else(|e| exit_with_error(&e)),
+                        );
+                    }
+                }
             } else {
-                all_files.extend(discover_rust_files(source_path)?);
+                for root in &args.crate_root {
+                    if !Path::new(root).is_file() {
+                        eprintln!("Error: Crate root does not exist: {}", root);
+                        std::process::exit(1);
+                    }
+                }
+                let (files, errors) = crate_root_files(&args.crate_root, &mut resolver)
+                    .unwrap_or_else(|e| exit_with_error(&e));
+                all_files = files;
+                unresolved = errors;
+            }
+
+            if all_files.is_empty() {
+                if args.verbose {
+                    eprintln!("No Rust files found.");
+                }
+                return Ok(());
             }
-        }
 
-        if all_files.is_empty() {
             if args.verbose {
-                eprintln!("No Rust files found.");
+                eprintln!("Found {} Rust file(s)", all_files.len());
+                let num_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+
+                // Calculate chunk info for display
+                let oversubscribe = 4;
+                let total_chunks = num_threads * oversubscribe;
+                let chunk_size = all_files.len().div_ceil(total_chunks);
+
+                eprintln!(
+                    "Processing with {} threads ({} chunks of ~{} files)",
+                    num_threads, total_chunks, chunk_size
+                );
+                eprintln!();
             }
-            return Ok(());
-        }
 
-        if args.verbose {
-            eprintln!("Found {} Rust file(s)", all_files.len());
-            let num_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            let jobs: Vec<_> = all_files
+                .into_iter()
+                .map(|file| {
+                    let resolved = resolver
+                        .for_file(&file)
+                        .unwrap_or_else(|e| exit_with_error(&e));
+                    Job {
+                        lines: changed_lines.remove(&file),
+                        path: file,
+                        config: resolved.config,
+                    }
+                })
+                .collect();
 
-            // Calculate chunk info for display
-            let oversubscribe = 4;
-            let total_chunks = num_threads * oversubscribe;
-            let chunk_size = all_files.len().div_ceil(total_chunks);
+            // Format files in parallel
+            let mut results = format_all(&jobs, &args);
+            results.extend(unresolved);
+            results
+        };
 
-            eprintln!(
-                "Processing with {} threads ({} chunks of ~{} files)",
-                num_threads, total_chunks, chunk_size
-            );
-            eprintln!();
+        if args.diff && message_format == MessageFormat::Human {
+            print_diffs(&results, color)?;
         }
 
-        // Format files in parallel
-        let results = format_all(&all_files, &args);
-        let agg = aggregate_results(results);
+        let agg = aggregate_results(&results);
+        write_report(&results, &agg, message_format)?;
 
         // Print summary
         print_summary(&agg, &args);
@@ -116,6 +277,68 @@ pub mod cli {
 
         Ok(())
     }
+
+    /// Run `chloro config`, printing the configuration that applies to a path.
+    fn run_config(args: &ConfigArgs) -> io::Result<()> {
+        if args.help || !args.print {
+            print_config_usage();
+            std::process::exit(if args.help { 0 } else { 1 });
+        }
+
+        let mut resolver =
+            ConfigResolver::new(&args.config_path, &args.config, &[], args.strict_config)
+                .unwrap_or_else(|e| exit_with_error(&e));
+        let path = Path::new(&args.path);
+        let resolved = if path.is_dir() {
+            resolver.for_dir(path)
+        } else {
+            resolver.for_file(path)
+        }
+        .unwrap_or_else(|e| exit_with_error(&e));
+
+        print_config(&resolved, &mut io::stdout().lock())
+    }
+
+    /// Run `chloro hook`, formatting the index in a pre-commit hook or installing the hook.
+    fn run_hook(args: &HookArgs) -> io::Result<()> {
+        match args.action.as_str() {
+            _ if args.help => {
+                print_hook_usage();
+                std::process::exit(0);
+            }
+            "pre-commit" => {
+                let mut resolver =
+                    ConfigResolver::new(&args.config_path, &args.config, &[], args.strict_config)
+                        .unwrap_or_else(|e| exit_with_error(&e));
+                let formatted =
+                    hook::pre_commit(&mut resolver).unwrap_or_else(|e| exit_with_error(&e));
+                for file in formatted {
+                    if file.mirrored {
+                        eprintln!("Formatted {}", file.path.display());
+                    } else {
+                        eprintln!(
+                            "Formatted {} in the index (unstaged changes left unformatted)",
+                            file.path.display()
+                        );
+                    }
+                }
+            }
+            "install" => {
+                let path = hook::install(args.force).unwrap_or_else(|e| exit_with_error(&e));
+                eprintln!("Installed pre-commit hook at {}", path.display());
+            }
+            _ => {
+                print_hook_usage();
+                std::process::exit(1);
+            }
+        }
+        Ok(())
+    }
+
+    fn exit_with_error(error: &io::Error) -> ! {
+        eprintln!("Error: {error}");
+        std::process::exit(1);
+    }
 }
 
 /// Hint replacement CLI for when the cli module is used without building the cli feature.
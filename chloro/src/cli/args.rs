@@ -1,9 +1,10 @@
 use facet::Facet;
+use std::io;
 
 #[derive(Facet)]
 pub struct Args {
-    /// Path(s) to source file(s) or directory to format
-    #[facet(positional, default = vec![".".to_string()])]
+    /// Path(s) to source file(s) or directory to format, none meaning the current directory
+    #[facet(positional, default)]
     pub sources: Vec<String>,
 
     /// Check mode: exit with error if files need formatting
@@ -14,6 +15,64 @@ pub struct Args {
     #[facet(named, short = 'w', long, default)]
     pub write: bool,
 
+    /// Print a unified diff of the changes each file needs instead of the formatted source
+    #[facet(named, short = 'd', long, default)]
+    pub diff: bool,
+
+    /// When to colour diff output: auto, always or never
+    #[facet(named, long, default = "auto".to_string())]
+    pub color: String,
+
+    /// Report format: human, json, junit, checkstyle or sarif
+    #[facet(named, long, default = "human".to_string())]
+    pub message_format: String,
+
+    /// Read source from stdin and write the formatted source to stdout (also given as `-`)
+    #[facet(named, long, default)]
+    pub stdin: bool,
+
+    /// Path to treat the source read from stdin as coming from
+    #[facet(named, long, default)]
+    pub stdin_filepath: String,
+
+    /// Configuration file to use instead of discovering `chloro.toml` files
+    #[facet(named, long, default)]
+    pub config_path: String,
+
+    /// Options overriding the configuration files, as comma-separated `key=value` pairs
+    #[facet(named, long, default)]
+    pub config: String,
+
+    /// Treat rustfmt options that chloro does not support as errors rather than warnings
+    #[facet(named, long, default)]
+    pub strict_config: bool,
+
+    /// Skip files and directories matching this `.gitignore`-style pattern (repeatable)
+    #[facet(named, long, default)]
+    pub exclude: Vec<String>,
+
+    /// Look in hidden directories when discovering files
+    #[facet(named, long, default)]
+    pub hidden: bool,
+
+    /// Format the files of the crate with this root module, found by following its `mod`
+    /// declarations, instead of the sources (repeatable)
+    #[facet(named, long, default)]
+    pub crate_root: Vec<String>,
+
+    /// Format only the Rust files that git reports as changed since this revision, in the
+    /// working tree or untracked
+    #[facet(named, long, default)]
+    pub changed_since: String,
+
+    /// Format only the Rust files with changes staged in the git index
+    #[facet(named, long, default)]
+    pub staged: bool,
+
+    /// With `--changed-since` or `--staged`, format only the lines that changed
+    #[facet(named, long, default)]
+    pub changed_lines_only: bool,
+
     /// Show verbose output
     #[facet(named, short = 'v', long, default)]
     pub verbose: bool,
@@ -23,20 +82,190 @@ pub struct Args {
     pub help: bool,
 }
 
+/// Arguments of the `chloro config` subcommand.
+#[derive(Facet)]
+pub struct ConfigArgs {
+    /// File or directory to show the configuration for
+    #[facet(positional, default = ".".to_string())]
+    pub path: String,
+
+    /// Print the effective configuration
+    #[facet(named, long, default)]
+    pub print: bool,
+
+    /// Configuration file to use instead of discovering `chloro.toml` files
+    #[facet(named, long, default)]
+    pub config_path: String,
+
+    /// Options overriding the configuration files, as comma-separated `key=value` pairs
+    #[facet(named, long, default)]
+    pub config: String,
+
+    /// Treat rustfmt options that chloro does not support as errors rather than warnings
+    #[facet(named, long, default)]
+    pub strict_config: bool,
+
+    /// Show this help message
+    #[facet(named, short = 'h', long, default)]
+    pub help: bool,
+}
+
+/// Arguments of the `chloro hook` subcommand.
+#[derive(Facet)]
+pub struct HookArgs {
+    /// The hook to run, `pre-commit`, or `install` to install it
+    #[facet(positional, default)]
+    pub action: String,
+
+    /// Replace an existing pre-commit hook that chloro did not install
+    #[facet(named, long, default)]
+    pub force: bool,
+
+    /// Configuration file to use instead of discovering `chloro.toml` files
+    #[facet(named, long, default)]
+    pub config_path: String,
+
+    /// Options overriding the configuration files, as comma-separated `key=value` pairs
+    #[facet(named, long, default)]
+    pub config: String,
+
+    /// Treat rustfmt options that chloro does not support as errors rather than warnings
+    #[facet(named, long, default)]
+    pub strict_config: bool,
+
+    /// Show this help message
+    #[facet(named, short = 'h', long, default)]
+    pub help: bool,
+}
+
+/// A parsed command line: formatting, or a subcommand.
+pub enum Command {
+    Format(Args),
+    Config(ConfigArgs),
+    Hook(HookArgs),
+}
+
+/// Parse the command-line arguments, taking a `-` source to mean `--stdin`.
+pub fn parse_args() -> io::Result<Command> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("config") => {
+            let args: Vec<&str> = args[1..].iter().map(String::as_str).collect();
+            return facet_args::from_slice(&args)
+                .map(Command::Config)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{e}")));
+        }
+        Some("hook") => {
+            let args: Vec<&str> = args[1..].iter().map(String::as_str).collect();
+            return facet_args::from_slice(&args)
+                .map(Command::Hook)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{e}")));
+        }
+        _ => {}
+    }
+    let args = stdin_dash_to_flag(args);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    facet_args::from_slice(&args)
+        .map(Command::Format)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{e}")))
+}
+
+/// Long options of [`Args`] that take a value, which may itself be `-`.
+const VALUE_OPTIONS: &[&str] = &[
+    "--color",
+    "--message-format",
+    "--stdin-filepath",
+    "--config-path",
+    "--config",
+    "--exclude",
+    "--crate-root",
+    "--changed-since",
+];
+
+/// Replace each `-` given as a source with `--stdin`, leaving alone a `-` that is the value of
+/// an option.
+fn stdin_dash_to_flag(args: Vec<String>) -> Vec<String> {
+    let mut is_value = false;
+    args.into_iter()
+        .map(|arg| {
+            let is_source = arg == "-" && !is_value;
+            is_value = VALUE_OPTIONS.contains(&arg.as_str());
+            if is_source {
+                "--stdin".to_string()
+            } else {
+                arg
+            }
+        })
+        .collect()
+}
+
+impl Args {
+    /// Whether formatted sources go to stdout, which they do unless it has another use.
+    pub fn prints_source(&self) -> bool {
+        !self.write && !self.check && !self.diff && self.message_format == "human"
+    }
+}
+
 pub fn print_usage() {
     println!("Usage: chloro [OPTIONS] [SOURCES]...");
+    println!("       chloro config --print [PATH]");
+    println!("       chloro hook <pre-commit|install>");
     println!();
     println!("A minimal Rust code formatter.");
     println!();
     println!("Arguments:");
     println!("  [SOURCES]...       Path(s) to file(s) or directory to format (default: 'src')");
+    println!("                     Use '-' to read from stdin");
     println!();
     println!("Options:");
     println!("  -c, --check        Check if files need formatting (exit 1 if so)");
     println!("  -w, --write        Write formatted output back to files");
+    println!("  -d, --diff         Print a diff of the changes needed instead of the source");
+    println!("      --color <WHEN> Colour the diff: auto, always or never (default: auto)");
+    println!("      --message-format <FORMAT>");
+    println!("                     Report results to stdout as human, json, junit,");
+    println!("                     checkstyle or sarif (default: human)");
+    println!("      --stdin        Format stdin and write the result to stdout");
+    println!("      --stdin-filepath <PATH>");
+    println!("                     Path to treat stdin as coming from");
+    println!("      --config-path <FILE>");
+    println!("                     Use this config file instead of discovering chloro.toml");
+    println!("      --config <KEY=VALUE,...>");
+    println!("                     Override options from the config files");
+    println!("      --strict-config");
+    println!("                     Fail on rustfmt.toml options chloro does not support");
+    println!("      --exclude <GLOB>");
+    println!("                     Skip paths matching a .gitignore-style pattern (repeatable)");
+    println!("      --hidden       Look in hidden directories when discovering files");
+    println!("      --crate-root <FILE>");
+    println!("                     Format the files of the crate with this root module,");
+    println!("                     following its mod declarations (repeatable)");
+    println!("      --changed-since <REV>");
+    println!("                     Format only the .rs files git reports as changed since");
+    println!("                     REV, including uncommitted and untracked files");
+    println!("      --staged       Format only the .rs files with changes staged in git");
+    println!("      --changed-lines-only");
+    println!("                     With --changed-since or --staged, format only the lines");
+    println!("                     that changed");
     println!("  -v, --verbose      Show verbose output");
     println!("  -h, --help         Show this help message");
     println!();
+    println!("Configuration:");
+    println!("  Options are read from chloro.toml or .chloro.toml in each file's directory");
+    println!("  and its ancestors, with files nearer the source taking precedence. The options");
+    println!("  chloro supports are also read from rustfmt.toml or .rustfmt.toml, which a");
+    println!("  chloro.toml in the same directory overrides.");
+    println!("  'chloro config --print [PATH]' shows the configuration used for PATH.");
+    println!();
+    println!("Git hooks:");
+    println!("  'chloro hook install' installs a pre-commit hook running 'chloro hook");
+    println!("  pre-commit', which formats the staged contents of .rs files in the index.");
+    println!();
+    println!("Discovery:");
+    println!("  Directories are searched for .rs files, skipping paths matched by .gitignore,");
+    println!("  .ignore and .chloroignore files or the 'ignore' list of a config file,");
+    println!("  hidden directories and target/.");
+    println!();
     println!("Examples:");
     println!("  # Format a single file and print to stdout");
     println!("  chloro src/lib.rs");
@@ -47,6 +276,79 @@ pub fn print_usage() {
     println!("  # Check if files need formatting");
     println!("  chloro --check src/");
     println!();
+    println!("  # Show what needs formatting in CI");
+    println!("  chloro --check --diff --color always src/");
+    println!();
     println!("  # Format files in-place");
     println!("  chloro --write src/");
+    println!();
+    println!("  # Format exactly the files of a crate");
+    println!("  chloro --write --crate-root src/lib.rs");
+    println!();
+    println!("  # Format the lines changed on a branch");
+    println!("  chloro --write --changed-since main --changed-lines-only");
+    println!();
+    println!("  # Format an editor buffer");
+    println!("  chloro - --stdin-filepath src/lib.rs < src/lib.rs");
+    println!();
+    println!("  # Format with an option overridden");
+    println!("  chloro --write --config merge_derives=true src/");
+}
+
+pub fn print_config_usage() {
+    println!("Usage: chloro config --print [OPTIONS] [PATH]");
+    println!();
+    println!("Print the configuration used to format PATH (default: '.').");
+    println!();
+    println!("Options:");
+    println!("      --print        Print the effective configuration as TOML");
+    println!("      --config-path <FILE>");
+    println!("                     Use this config file instead of discovering chloro.toml");
+    println!("      --config <KEY=VALUE,...>");
+    println!("                     Override options from the config files");
+    println!("      --strict-config");
+    println!("                     Fail on rustfmt.toml options chloro does not support");
+    println!("  -h, --help         Show this help message");
+}
+
+pub fn print_hook_usage() {
+    println!("Usage: chloro hook pre-commit [OPTIONS]");
+    println!("       chloro hook install [--force]");
+    println!();
+    println!("pre-commit: Format the staged contents of each staged .rs file and stage the");
+    println!("            result, leaving unstaged changes alone. The working tree file is");
+    println!("            formatted too if it matches the staged contents.");
+    println!("install:    Write a pre-commit hook running 'chloro hook pre-commit' to the");
+    println!("            repository's hooks directory.");
+    println!();
+    println!("Options:");
+    println!("      --force        Replace a pre-commit hook that chloro did not install");
+    println!("      --config-path <FILE>");
+    println!("                     Use this config file instead of discovering chloro.toml");
+    println!("      --config <KEY=VALUE,...>");
+    println!("                     Override options from the config files");
+    println!("      --strict-config");
+    println!("                     Fail on rustfmt.toml options chloro does not support");
+    println!("  -h, --help         Show this help message");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn dash_source_means_stdin() {
+        let args = stdin_dash_to_flag(to_strings(&["-", "--stdin-filepath", "src/lib.rs"]));
+        assert_eq!(args, ["--stdin", "--stdin-filepath", "src/lib.rs"]);
+    }
+
+    #[test]
+    fn dash_option_value_is_kept() {
+        let args = stdin_dash_to_flag(to_strings(&["--exclude", "-", "x.rs"]));
+        assert_eq!(args, ["--exclude", "-", "x.rs"]);
+    }
 }
@@ -0,0 +1,289 @@
+//! Discovery and loading of `chloro.toml` and `rustfmt.toml` configuration files.
+use super::rustfmt::{self, RUSTFMT_FILE_NAMES};
+use chloro_core::Config;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Names of chloro's configuration files, in order of preference within a directory. These are
+/// applied on top of any rustfmt configuration file in the same directory.
+pub const CONFIG_FILE_NAMES: [&str; 2] = ["chloro.toml", ".chloro.toml"];
+
+/// A configuration and the files it was loaded from, outermost first.
+#[derive(Debug, Clone, Default)]
+pub struct Resolved {
+    pub config: Arc<Config>,
+    pub sources: Vec<PathBuf>,
+    /// The `ignore` lists of the configuration files, outermost first
+    pub ignores: Vec<Arc<IgnoreList>>,
+}
+
+/// Paths that a configuration file's `ignore` option excludes from formatting.
+#[derive(Debug)]
+pub struct IgnoreList {
+    /// The configuration file the list is from
+    pub source: PathBuf,
+    /// The patterns, in `.gitignore` syntax relative to the configuration file
+    pub patterns: Vec<String>,
+    matcher: Gitignore,
+}
+
+/// Finds the configuration for each formatted file, caching it per directory.
+pub struct ConfigResolver {
+    /// The configuration from `--config-path`, which replaces discovery
+    forced: Option<Resolved>,
+    /// Options from `--config`, applied on top of every configuration file
+    overrides: Vec<(String, String)>,
+    /// Patterns from `--exclude`, relative to the working directory
+    exclude: Gitignore,
+    /// Whether rustfmt options that chloro does not support are errors rather than warnings
+    strict: bool,
+    cache: HashMap<PathBuf, Resolved>,
+}
+
+impl ConfigResolver {
+    /// Create a resolver from the `--config-path`, `--config`, `--exclude` and
+    /// `--strict-config` arguments.
+    pub fn new(
+        config_path: &str,
+        overrides: &str,
+        exclude: &[String],
+        strict: bool,
+    ) -> io::Result<Self> {
+        let mut resolver = Self {
+            forced: None,
+            overrides: parse_overrides(overrides)?,
+            exclude: exclude_matcher(exclude)?,
+            strict,
+            cache: HashMap::new(),
+        };
+        if !config_path.is_empty() {
+            let mut forced = Resolved::default();
+            resolver.load(Path::new(config_path), &mut forced)?;
+            resolver.apply_overrides(&mut forced)?;
+            resolver.forced = Some(forced);
+        }
+        Ok(resolver)
+    }
+
+    /// The configuration for the file at `path`.
+    pub fn for_file(&mut self, path: &Path) -> io::Result<Resolved> {
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        self.for_dir(dir)
+    }
+
+    /// The configuration for files in the directory `dir`.
+    pub fn for_dir(&mut self, dir: &Path) -> io::Result<Resolved> {
+        if let Some(forced) = &self.forced {
+            return Ok(forced.clone());
+        }
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        let mut resolved = self.discovered(&dir)?;
+        self.apply_overrides(&mut resolved)?;
+        Ok(resolved)
+    }
+
+    /// Whether `--exclude` or the `ignore` option of a configuration file excludes `path`.
+    pub fn is_ignored(&mut self, path: &Path, is_dir: bool) -> io::Result<bool> {
+        // The path given for stdin need not exist
+        let path = path
+            .canonicalize()
+            .or_else(|_| std::path::absolute(path))
+            .unwrap_or_else(|_| path.to_path_buf());
+        if is_ignored_by(&self.exclude, &path, is_dir) {
+            return Ok(true);
+        }
+        let resolved = self.for_file(&path)?;
+        Ok(resolved
+            .ignores
+            .iter()
+            .any(|list| is_ignored_by(&list.matcher, &path, is_dir)))
+    }
+
+    /// The configuration from the files in `dir` and its ancestors, nearer files taking
+    /// precedence.
+    fn discovered(&mut self, dir: &Path) -> io::Result<Resolved> {
+        if let Some(resolved) = self.cache.get(dir) {
+            return Ok(resolved.clone());
+        }
+        let mut resolved = match dir.parent() {
+            Some(parent) => self.discovered(parent)?,
+            None => Resolved::default(),
+        };
+        for names in [&RUSTFMT_FILE_NAMES, &CONFIG_FILE_NAMES] {
+            if let Some(path) = find_file(dir, names) {
+                self.load(&path, &mut resolved)?;
+            }
+        }
+        self.cache.insert(dir.to_path_buf(), resolved.clone());
+        Ok(resolved)
+    }
+
+    /// Apply the configuration file at `path` on top of `resolved`.
+    fn load(&self, path: &Path, resolved: &mut Resolved) -> io::Result<()> {
+        let mut table = read_table(path)?;
+        if let Some(ignore) = table.remove("ignore") {
+            resolved.ignores.push(Arc::new(ignore_list(path, &ignore)?));
+        }
+        let config = Arc::make_mut(&mut resolved.config);
+        if is_rustfmt_file(path) {
+            rustfmt::apply(path, &table, config, self.strict)?;
+        } else {
+            apply_file(path, &table, config)?;
+        }
+        resolved.sources.push(path.to_path_buf());
+        Ok(())
+    }
+
+    fn apply_overrides(&self, resolved: &mut Resolved) -> io::Result<()> {
+        if self.overrides.is_empty() {
+            return Ok(());
+        }
+        let config = Arc::make_mut(&mut resolved.config);
+        for (key, value) in &self.overrides {
+            config.set(key, value).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidInput, format!("--config: {e}"))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// The first of the files named `names` directly in `dir`, if there is one.
+fn find_file(dir: &Path, names: &[&str]) -> Option<PathBuf> {
+    names
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn is_rustfmt_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| RUSTFMT_FILE_NAMES.contains(&name))
+}
+
+/// Apply the options of the chloro configuration file at `path` to `config`.
+fn apply_file(path: &Path, table: &toml::Table, config: &mut Config) -> io::Result<()> {
+    for (key, value) in table {
+        let value = option_string(key, value).map_err(|message| invalid(path, message))?;
+        config
+            .set(key, &value)
+            .map_err(|e| invalid(path, e.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Parse the `ignore` option of the configuration file at `path`.
+fn ignore_list(path: &Path, value: &toml::Value) -> io::Result<IgnoreList> {
+    let patterns = value
+        .as_array()
+        .and_then(|values| {
+            values
+                .iter()
+                .map(|value| value.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| invalid(path, "`ignore` must be a list of strings".to_string()))?;
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let root = path.parent().unwrap_or(Path::new("."));
+    let matcher = build_matcher(root, &patterns).map_err(|e| invalid(&path, e.to_string()))?;
+    Ok(IgnoreList {
+        source: path,
+        patterns,
+        matcher,
+    })
+}
+
+/// A matcher for the `--exclude` patterns, relative to the working directory.
+fn exclude_matcher(patterns: &[String]) -> io::Result<Gitignore> {
+    let root = std::env::current_dir()?;
+    build_matcher(&root, patterns)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("--exclude: {e}")))
+}
+
+fn build_matcher(root: &Path, patterns: &[String]) -> Result<Gitignore, ignore::Error> {
+    let mut builder = GitignoreBuilder::new(root);
+    for pattern in patterns {
+        builder.add_line(None, pattern)?;
+    }
+    builder.build()
+}
+
+/// Whether `matcher` ignores `path` or one of its parents, if `path` is under its root.
+pub(super) fn is_ignored_by(matcher: &Gitignore, path: &Path, is_dir: bool) -> bool {
+    path.starts_with(matcher.path())
+        && matcher
+            .matched_path_or_any_parents(path, is_dir)
+            .is_ignore()
+}
+
+/// Read the TOML file at `path`.
+pub(super) fn read_table(path: &Path) -> io::Result<toml::Table> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    text.parse().map_err(|e| invalid(path, format!("{e}")))
+}
+
+/// An error in the configuration file at `path`.
+pub(super) fn invalid(path: &Path, message: String) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), message),
+    )
+}
+
+/// The value of the option `key` as written for [`Config::set`].
+pub(super) fn option_string(key: &str, value: &toml::Value) -> Result<String, String> {
+    match value {
+        toml::Value::Boolean(value) => Ok(value.to_string()),
+        toml::Value::Integer(value) => Ok(value.to_string()),
+        toml::Value::String(value) => Ok(value.clone()),
+        _ => Err(format!("`{key}` must be a boolean, integer or string")),
+    }
+}
+
+/// Parse the `key=value` pairs of `--config`, separated by commas.
+fn parse_overrides(overrides: &str) -> io::Result<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    for pair in overrides.split(',').filter(|pair| !pair.trim().is_empty()) {
+        let Some((key, value)) = pair.split_once('=') else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("--config: expected key=value, found `{pair}`"),
+            ));
+        };
+        let (key, value) = (key.trim().to_string(), value.trim().to_string());
+        Config::default()
+            .set(&key, &value)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("--config: {e}")))?;
+        pairs.push((key, value));
+    }
+    Ok(pairs)
+}
+
+/// Write the effective configuration as TOML, noting the files it came from.
+pub fn print_config(resolved: &Resolved, out: &mut impl io::Write) -> io::Result<()> {
+    if resolved.sources.is_empty() {
+        writeln!(out, "# No configuration file found, using defaults")?;
+    }
+    for source in &resolved.sources {
+        writeln!(out, "# Loaded from {}", source.display())?;
+    }
+    for key in Config::OPTIONS {
+        if let Some(value) = resolved.config.get(key) {
+            writeln!(out, "{key} = {value}")?;
+        }
+    }
+    for list in &resolved.ignores {
+        writeln!(out, "# From {}:", list.source.display())?;
+        writeln!(out, "# ignore = {:?}", list.patterns)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,569 @@
+//! Line diffs of original and formatted sources, grouped into unified diff hunks.
+
+use std::collections::HashSet;
+
+/// Number of unchanged lines shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+/// Whether a diff line is in both sources, only the original, or only the formatted one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Context,
+    Removed,
+    Added,
+}
+
+/// A diff line, including its line break if it has one.
+#[derive(Debug, Clone)]
+pub struct DiffLine {
+    pub kind: LineKind,
+    pub text: String,
+}
+
+/// A run of changes with the context around them, as in a `@@ -1,4 +1,5 @@` block.
+///
+/// Starts are 1-based, and for an empty side they are the line before the change, as in
+/// the unified diff format.
+#[derive(Debug, Clone)]
+pub struct Hunk {
+    pub old_start: usize,
+    pub old_len: usize,
+    pub new_start: usize,
+    pub new_len: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+impl Hunk {
+    /// The `@@ -a,b +c,d @@` header line.
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_len, self.new_start, self.new_len
+        )
+    }
+
+    /// Number of lines removed from the original.
+    pub fn removed(&self) -> usize {
+        self.count(LineKind::Removed)
+    }
+
+    /// Number of lines added in the formatted source.
+    pub fn added(&self) -> usize {
+        self.count(LineKind::Added)
+    }
+
+    /// The first and last lines of the original that the hunk changes. Lines added without
+    /// removing any are located at the original line they follow.
+    pub fn original_range(&self) -> (usize, usize) {
+        // The original line number of the next context or removed line
+        let mut line = self.old_start + usize::from(self.old_len == 0);
+        let mut range: Option<(usize, usize)> = None;
+        for diff_line in &self.lines {
+            let changed = match diff_line.kind {
+                LineKind::Context => None,
+                LineKind::Removed => Some(line),
+                LineKind::Added => Some(line.saturating_sub(1).max(1)),
+            };
+            if let Some(changed) = changed {
+                range = Some(match range {
+                    Some((first, last)) => (first.min(changed), last.max(changed)),
+                    None => (changed, changed),
+                });
+            }
+            if diff_line.kind != LineKind::Added {
+                line += 1;
+            }
+        }
+        range.unwrap_or((self.old_start, self.old_start))
+    }
+
+    fn count(&self, kind: LineKind) -> usize {
+        self.lines.iter().filter(|line| line.kind == kind).count()
+    }
+}
+
+/// An edit turning the original lines into the formatted ones, by line index (of the
+/// original for equal lines).
+#[derive(Debug, Clone, Copy)]
+enum Edit {
+    Equal(usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Diff two sources line by line into unified diff hunks.
+pub fn diff_hunks(original: &str, formatted: &str) -> Vec<Hunk> {
+    let old: Vec<&str> = original.split_inclusive('\n').collect();
+    let new: Vec<&str> = formatted.split_inclusive('\n').collect();
+    let edits = line_edits(&old, &new);
+    group_hunks(&edits, &old, &new)
+}
+
+/// Apply only the changes from `original` to `formatted` that touch the given 1-based,
+/// inclusive ranges of original lines, keeping the original text everywhere else.
+///
+/// Lines added without removing any touch the original lines either side of them. A change
+/// that moves lines, such as reordered imports, is applied or kept as a whole: changes
+/// elsewhere that add back a line an applied change removes, or remove a line it adds, are
+/// applied with it, so that no code is lost or duplicated.
+pub fn apply_in_lines(original: &str, formatted: &str, lines: &[(usize, usize)]) -> String {
+    let old: Vec<&str> = original.split_inclusive('\n').collect();
+    let new: Vec<&str> = formatted.split_inclusive('\n').collect();
+    let edits = line_edits(&old, &new);
+
+    let touches = |first: usize, last: usize| {
+        lines
+            .iter()
+            .any(|&(start, end)| start <= last && first <= end)
+    };
+    // Ranges of edits of each run of changes, and whether it touches the lines
+    let mut changes = Vec::new();
+    // The original line the next edit is at, 0-based
+    let mut line = 0;
+    let mut i = 0;
+    while i < edits.len() {
+        if let Edit::Equal(index) = edits[i] {
+            line = index + 1;
+            i += 1;
+            continue;
+        }
+        let end = edits[i..]
+            .iter()
+            .position(|edit| matches!(edit, Edit::Equal(..)))
+            .map_or(edits.len(), |len| i + len);
+        let removed: Vec<usize> = edits[i..end]
+            .iter()
+            .filter_map(|edit| match *edit {
+                Edit::Delete(index) => Some(index),
+                _ => None,
+            })
+            .collect();
+        let (first, last) = match (removed.first(), removed.last()) {
+            (Some(&first), Some(&last)) => (first + 1, last + 1),
+            _ => (line.max(1), line + 1),
+        };
+        changes.push((i..end, touches(first, last)));
+        line += removed.len();
+        i = end;
+    }
+    link_moved_lines(&mut changes, &edits, &old, &new);
+
+    let mut result = String::with_capacity(formatted.len());
+    let mut changes = changes.into_iter().peekable();
+    let mut i = 0;
+    while i < edits.len() {
+        let Some((range, apply)) = changes.next_if(|(range, _)| range.start == i) else {
+            if let Edit::Equal(index) = edits[i] {
+                result.push_str(old[index]);
+            }
+            i += 1;
+            continue;
+        };
+        for edit in &edits[range.clone()] {
+            match *edit {
+                Edit::Delete(index) if !apply => result.push_str(old[index]),
+                Edit::Insert(index) if apply => result.push_str(new[index]),
+                _ => {}
+            }
+        }
+        i = range.end;
+    }
+    result
+}
+
+/// Mark changes to apply until no applied change removes a line that a change left alone
+/// adds, or adds a line that one left alone removes.
+///
+/// Lines are compared whole, ignoring indentation, and only lines with some word in them
+/// count, so that changes sharing just a blank line or a closing brace stay apart.
+fn link_moved_lines(
+    changes: &mut [(std::ops::Range<usize>, bool)],
+    edits: &[Edit],
+    old: &[&str],
+    new: &[&str],
+) {
+    // Each line removed (positive) or added (negative) by the edits
+    let lines = |edits: &[Edit]| -> Vec<(&str, isize)> {
+        edits
+            .iter()
+            .filter_map(|edit| match *edit {
+                Edit::Equal(..) => None,
+                Edit::Delete(index) => Some((old[index].trim(), 1)),
+                Edit::Insert(index) => Some((new[index].trim(), -1)),
+            })
+            .filter(|(line, _)| line.chars().any(char::is_alphanumeric))
+            .collect()
+    };
+    loop {
+        let mut moved: HashSet<(&str, isize)> = HashSet::new();
+        for (range, _) in changes.iter().filter(|(_, apply)| *apply) {
+            moved.extend(lines(&edits[range.clone()]));
+        }
+        let mut linked = false;
+        for (range, apply) in changes.iter_mut().filter(|(_, apply)| !*apply) {
+            // A change that adds a line the applied ones removed, or removes one they added
+            *apply = lines(&edits[range.clone()])
+                .into_iter()
+                .any(|(line, sign)| moved.contains(&(line, -sign)));
+            linked |= *apply;
+        }
+        if !linked {
+            break;
+        }
+    }
+}
+
+/// The edits turning `old` into `new`, with the lines each run of changes removes before the
+/// ones it adds, as in unified diffs.
+fn line_edits(old: &[&str], new: &[&str]) -> Vec<Edit> {
+    let mut edits = Vec::new();
+    diff_range(old, new, 0, 0, &mut edits);
+    for run in edits.split_mut(|edit| matches!(edit, Edit::Equal(..))) {
+        run.sort_by_key(|edit| matches!(edit, Edit::Insert(..)));
+    }
+    edits
+}
+
+/// Append the edits turning `old` into `new` to `edits`, with line indices offset by
+/// `old_offset` and `new_offset`.
+///
+/// This is Myers' linear space diff: the middle snake of an optimal edit path splits the
+/// problem in two, so memory stays linear in the number of lines however much changed.
+fn diff_range(
+    old: &[&str],
+    new: &[&str],
+    old_offset: usize,
+    new_offset: usize,
+    edits: &mut Vec<Edit>,
+) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    for i in 0..prefix {
+        edits.push(Edit::Equal(old_offset + i));
+    }
+    let (old_rest, new_rest) = (&old[prefix..], &new[prefix..]);
+    let suffix = old_rest
+        .iter()
+        .rev()
+        .zip(new_rest.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old_rest[..old_rest.len() - suffix];
+    let new_mid = &new_rest[..new_rest.len() - suffix];
+    let (old_start, new_start) = (old_offset + prefix, new_offset + prefix);
+
+    if old_mid.is_empty() {
+        edits.extend((0..new_mid.len()).map(|j| Edit::Insert(new_start + j)));
+    } else if new_mid.is_empty() {
+        edits.extend((0..old_mid.len()).map(|i| Edit::Delete(old_start + i)));
+    } else {
+        let (x0, y0, x1, y1) = middle_snake(old_mid, new_mid);
+        diff_range(&old_mid[..x0], &new_mid[..y0], old_start, new_start, edits);
+        for i in 0..x1 - x0 {
+            edits.push(Edit::Equal(old_start + x0 + i));
+        }
+        diff_range(
+            &old_mid[x1..],
+            &new_mid[y1..],
+            old_start + x1,
+            new_start + y1,
+            edits,
+        );
+    }
+
+    let old_end = old_start + old_mid.len();
+    for i in 0..suffix {
+        edits.push(Edit::Equal(old_end + i));
+    }
+}
+
+/// Find the middle snake of the shortest edit path between two sequences that differ at both
+/// ends, as `(x0, y0, x1, y1)` where `old[x0..x1]` equals `new[y0..y1]`.
+fn middle_snake(old: &[&str], new: &[&str]) -> (usize, usize, usize, usize) {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let delta = n - m;
+    let max = (n + m + 1) / 2;
+    let offset = max + 1;
+    let index = |k: isize| (k + offset) as usize;
+    // Furthest x reached on each diagonal k = x - y, going forwards and going backwards
+    let mut forward = vec![0isize; (2 * max + 3) as usize];
+    let mut backward = vec![0isize; (2 * max + 3) as usize];
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && forward[index(k - 1)] < forward[index(k + 1)]) {
+                forward[index(k + 1)]
+            } else {
+                forward[index(k - 1)] + 1
+            };
+            let mut y = x - k;
+            let (x0, y0) = (x, y);
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[index(k)] = x;
+            let back_k = delta - k;
+            if delta % 2 != 0
+                && (-(d - 1)..=d - 1).contains(&back_k)
+                && x + backward[index(back_k)] >= n
+            {
+                return (x0 as usize, y0 as usize, x as usize, y as usize);
+            }
+        }
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && backward[index(k - 1)] < backward[index(k + 1)]) {
+                backward[index(k + 1)]
+            } else {
+                backward[index(k - 1)] + 1
+            };
+            let mut y = x - k;
+            let (x0, y0) = (x, y);
+            while x < n && y < m && old[(n - x - 1) as usize] == new[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+            backward[index(k)] = x;
+            let forward_k = delta - k;
+            if delta % 2 == 0 && (-d..=d).contains(&forward_k) && x + forward[index(forward_k)] >= n
+            {
+                return (
+                    (n - x) as usize,
+                    (m - y) as usize,
+                    (n - x0) as usize,
+                    (m - y0) as usize,
+                );
+            }
+        }
+    }
+    unreachable!("the forward and backward paths always meet")
+}
+
+/// Group edits into hunks with up to `CONTEXT_LINES` unchanged lines around each change,
+/// merging changes whose context would overlap.
+fn group_hunks(edits: &[Edit], old: &[&str], new: &[&str]) -> Vec<Hunk> {
+    let changes: Vec<usize> = (0..edits.len())
+        .filter(|&i| !matches!(edits[i], Edit::Equal(..)))
+        .collect();
+    let Some(&first) = changes.first() else {
+        return Vec::new();
+    };
+
+    // Ranges of edits, each running from the context before a change to the context after
+    let mut ranges = vec![(first.saturating_sub(CONTEXT_LINES), first)];
+    for &i in &changes[1..] {
+        let last = ranges.last_mut().expect("ranges start non-empty");
+        if i - last.1 <= 2 * CONTEXT_LINES + 1 {
+            last.1 = i;
+        } else {
+            ranges.push((i.saturating_sub(CONTEXT_LINES), i));
+        }
+    }
+
+    // Number of lines of each side before each edit
+    let mut before = Vec::with_capacity(edits.len());
+    let (mut old_line, mut new_line) = (0, 0);
+    for edit in edits {
+        before.push((old_line, new_line));
+        match edit {
+            Edit::Equal(..) => {
+                old_line += 1;
+                new_line += 1;
+            }
+            Edit::Delete(_) => old_line += 1,
+            Edit::Insert(_) => new_line += 1,
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, last_change)| {
+            let end = (last_change + CONTEXT_LINES + 1).min(edits.len());
+            let lines: Vec<DiffLine> = edits[start..end]
+                .iter()
+                .map(|edit| match *edit {
+                    Edit::Equal(i) => (LineKind::Context, old[i]),
+                    Edit::Delete(i) => (LineKind::Removed, old[i]),
+                    Edit::Insert(j) => (LineKind::Added, new[j]),
+                })
+                .map(|(kind, text)| DiffLine {
+                    kind,
+                    text: text.to_string(),
+                })
+                .collect();
+            let old_len = lines.iter().filter(|l| l.kind != LineKind::Added).count();
+            let new_len = lines.iter().filter(|l| l.kind != LineKind::Removed).count();
+            let (old_before, new_before) = before[start];
+            Hunk {
+                old_start: old_before + usize::from(old_len > 0),
+                old_len,
+                new_start: new_before + usize::from(new_len > 0),
+                new_len,
+                lines,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(hunk: &Hunk) -> Vec<(LineKind, &str)> {
+        hunk.lines
+            .iter()
+            .map(|line| (line.kind, line.text.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn diff_identical_sources_has_no_hunks() {
+        assert!(diff_hunks("a\nb\n", "a\nb\n").is_empty());
+    }
+
+    #[test]
+    fn diff_changed_line_with_context() {
+        let original = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let formatted = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        let hunks = diff_hunks(original, formatted);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].header(), "@@ -2,7 +2,7 @@");
+        assert_eq!(
+            kinds(&hunks[0]),
+            [
+                (LineKind::Context, "2\n"),
+                (LineKind::Context, "3\n"),
+                (LineKind::Context, "4\n"),
+                (LineKind::Removed, "5\n"),
+                (LineKind::Added, "five\n"),
+                (LineKind::Context, "6\n"),
+                (LineKind::Context, "7\n"),
+                (LineKind::Context, "8\n"),
+            ]
+        );
+        assert_eq!((hunks[0].removed(), hunks[0].added()), (1, 1));
+        assert_eq!(hunks[0].original_range(), (5, 5));
+    }
+
+    #[test]
+    fn diff_multi_line_replacement_removes_before_adding() {
+        let hunks = diff_hunks("struct  S;\nfn  a() {}\n", "struct S;\nfn a() {}\n");
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].header(), "@@ -1,2 +1,2 @@");
+        assert_eq!(
+            kinds(&hunks[0]),
+            [
+                (LineKind::Removed, "struct  S;\n"),
+                (LineKind::Removed, "fn  a() {}\n"),
+                (LineKind::Added, "struct S;\n"),
+                (LineKind::Added, "fn a() {}\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_distant_changes_in_separate_hunks() {
+        let original: String = (1..=20).map(|i| format!("{i}\n")).collect();
+        let formatted: String = (1..=20)
+            .map(|i| match i {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                _ => format!("{i}\n"),
+            })
+            .collect();
+        let hunks = diff_hunks(&original, &formatted);
+        let headers: Vec<String> = hunks.iter().map(Hunk::header).collect();
+        assert_eq!(headers, ["@@ -1,5 +1,5 @@", "@@ -16,5 +16,5 @@"]);
+        assert_eq!(hunks[0].original_range(), (2, 2));
+        assert_eq!(hunks[1].original_range(), (19, 19));
+    }
+
+    #[test]
+    fn diff_nearby_changes_share_a_hunk() {
+        let original: String = (1..=10).map(|i| format!("{i}\n")).collect();
+        let formatted = original.replace("3\n", "three\n").replace("8\n", "eight\n");
+        let hunks = diff_hunks(&original, &formatted);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].header(), "@@ -1,10 +1,10 @@");
+        assert_eq!(hunks[0].original_range(), (3, 8));
+    }
+
+    #[test]
+    fn diff_empty_side_starts_at_line_before() {
+        let hunks = diff_hunks("", "a\nb\n");
+        assert_eq!(hunks[0].header(), "@@ -0,0 +1,2 @@");
+        assert_eq!(hunks[0].original_range(), (1, 1));
+
+        let hunks = diff_hunks("a\nb\n", "");
+        assert_eq!(hunks[0].header(), "@@ -1,2 +0,0 @@");
+        assert_eq!(hunks[0].original_range(), (1, 2));
+    }
+
+    #[test]
+    fn original_range_of_pure_insertion_is_the_line_before() {
+        let hunks = diff_hunks("a\nb\nc\n", "a\nb\nnew\nc\n");
+        assert_eq!(hunks[0].header(), "@@ -1,3 +1,4 @@");
+        assert_eq!((hunks[0].removed(), hunks[0].added()), (0, 1));
+        assert_eq!(hunks[0].original_range(), (2, 2));
+    }
+
+    #[test]
+    fn diff_missing_newline_at_end_of_file() {
+        let hunks = diff_hunks("a\nb", "a\nb\n");
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].header(), "@@ -1,2 +1,2 @@");
+        assert_eq!(
+            kinds(&hunks[0]),
+            [
+                (LineKind::Context, "a\n"),
+                (LineKind::Removed, "b"),
+                (LineKind::Added, "b\n"),
+            ]
+        );
+        assert_eq!(hunks[0].original_range(), (2, 2));
+    }
+
+    #[test]
+    fn apply_in_lines_keeps_changes_elsewhere() {
+        let original = "fn  a() {}\n\nfn  b() {}\n\nfn  c() {}\n";
+        let formatted = "fn a() {}\n\nfn b() {}\n\nfn c() {}\n";
+        assert_eq!(
+            apply_in_lines(original, formatted, &[(3, 3)]),
+            "fn  a() {}\n\nfn b() {}\n\nfn  c() {}\n"
+        );
+        assert_eq!(apply_in_lines(original, formatted, &[]), original);
+    }
+
+    #[test]
+    fn apply_in_lines_moves_changed_line_whole() {
+        // `use zeta;` was changed to `use zeta_two;`, which sorting moves after `use alpha;`
+        let original = "fn  a() {}\n\nuse zeta_two;\nuse alpha;\n";
+        let formatted = "fn a() {}\n\nuse alpha;\nuse zeta_two;\n";
+        assert_eq!(
+            apply_in_lines(original, formatted, &[(3, 3)]),
+            "fn  a() {}\n\nuse alpha;\nuse zeta_two;\n"
+        );
+    }
+
+    #[test]
+    fn apply_in_lines_leaves_change_sharing_only_words() {
+        let original = "fn  h() -> u32 { 0xFF }\n\nconst B: u32 = 0xFF;\n";
+        let formatted = "fn h() -> u32 { 0xff }\n\nconst B: u32 = 0xff;\n";
+        assert_eq!(
+            apply_in_lines(original, formatted, &[(3, 3)]),
+            "fn  h() -> u32 { 0xFF }\n\nconst B: u32 = 0xff;\n"
+        );
+    }
+
+    #[test]
+    fn apply_in_lines_moves_unchanged_line_past_changed_one() {
+        // `use alpha_two;` was added, which sorting moves before `use beta;`
+        let original = "use beta;\nuse alpha_two;\n";
+        let formatted = "use alpha_two;\nuse beta;\n";
+        for line in [1, 2] {
+            assert_eq!(
+                apply_in_lines(original, formatted, &[(line, line)]),
+                formatted
+            );
+        }
+    }
+}
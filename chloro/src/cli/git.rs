@@ -0,0 +1,272 @@
+//! Finding changed files and lines with the local `git` binary.
+use std::ffi::OsStr;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// A Rust file that git reports as changed.
+#[derive(Debug, Clone)]
+pub struct ChangedFile {
+    /// Path of the file, relative to the working directory
+    pub path: PathBuf,
+    /// 1-based, inclusive ranges of the lines that changed, or `None` if the whole file is new
+    pub lines: Option<Vec<(usize, usize)>>,
+}
+
+/// Which changes to look for: those in the working tree or only those staged in the index,
+/// relative to a revision (by default `HEAD`).
+#[derive(Debug, Clone, Copy)]
+pub struct ChangeSet<'a> {
+    pub since: Option<&'a str>,
+    pub staged: bool,
+}
+
+impl ChangeSet<'_> {
+    /// The Rust files under `paths` that have changed, not counting deleted files, with the
+    /// changed lines of each if `with_lines`.
+    ///
+    /// Untracked files that are not ignored count as changed unless only staged changes are
+    /// looked for.
+    pub fn changed_files(
+        &self,
+        paths: &[String],
+        with_lines: bool,
+    ) -> io::Result<Vec<ChangedFile>> {
+        let cdup = git(&["rev-parse", "--show-cdup"])?;
+        let root = Path::new(cdup.trim_end());
+
+        let mut diff = vec![
+            "diff",
+            "--name-only",
+            "-z",
+            "--no-renames",
+            "--diff-filter=ACMRT",
+        ];
+        diff.extend(self.diff_args());
+        let mut names = git_paths(&diff, paths)?;
+        let untracked = if self.staged {
+            Vec::new()
+        } else {
+            git_paths(
+                &[
+                    "ls-files",
+                    "--others",
+                    "--exclude-standard",
+                    "--full-name",
+                    "-z",
+                ],
+                paths,
+            )?
+        };
+        names.extend(untracked.iter().cloned());
+
+        let mut files = Vec::new();
+        for name in names {
+            let path = root.join(&name);
+            if path.extension() != Some(OsStr::new("rs")) || !path.is_file() {
+                continue;
+            }
+            if files.iter().any(|file: &ChangedFile| file.path == path) {
+                continue;
+            }
+            let lines = if with_lines && !untracked.contains(&name) {
+                Some(self.changed_lines(&path)?)
+            } else {
+                None
+            };
+            files.push(ChangedFile { path, lines });
+        }
+        Ok(files)
+    }
+
+    /// The ranges of lines of the file at `path` that changed, from a diff without context.
+    ///
+    /// Staged changes are found in the index, so their lines are mapped past any unstaged
+    /// changes to the lines of the working tree file.
+    fn changed_lines(&self, path: &Path) -> io::Result<Vec<(usize, usize)>> {
+        let mut diff = vec!["diff", "-U0", "--no-renames"];
+        diff.extend(self.diff_args());
+        let path = path.to_string_lossy();
+        diff.extend(["--", path.as_ref()]);
+        let output = git(&diff)?;
+        let lines = output.lines().filter_map(hunk_lines);
+        if !self.staged {
+            return Ok(lines.collect());
+        }
+        let unstaged = git(&[
+            "diff",
+            "-U0",
+            "--no-renames",
+            "--no-color",
+            "--no-ext-diff",
+            "--",
+            path.as_ref(),
+        ])?;
+        let unstaged: Vec<_> = unstaged.lines().filter_map(hunk_sides).collect();
+        Ok(lines
+            .map(|(first, last)| {
+                (
+                    working_line(first, &unstaged, false),
+                    working_line(last, &unstaged, true),
+                )
+            })
+            .collect())
+    }
+
+    fn diff_args(&self) -> Vec<&str> {
+        let mut args = vec!["--no-color", "--no-ext-diff"];
+        if self.staged {
+            args.push("--cached");
+        }
+        args.extend(self.since);
+        args
+    }
+}
+
+/// The lines of the new side of a `@@ -a,b +c,d @@` hunk header. A hunk that only removes
+/// lines touches the lines either side of where they were.
+fn hunk_lines(line: &str) -> Option<(usize, usize)> {
+    hunk_sides(line).map(|[_, new]| side_lines(new))
+}
+
+/// The start and length of the old and new sides of a `@@ -a,b +c,d @@` hunk header.
+fn hunk_sides(line: &str) -> Option<[(usize, usize); 2]> {
+    let mut sides = line.strip_prefix("@@ -")?.split(' ');
+    let side = |side: &str| match side.split_once(',') {
+        Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+        None => Some((side.parse().ok()?, 1)),
+    };
+    let old = side(sides.next()?)?;
+    let new = side(sides.next()?.strip_prefix('+')?)?;
+    Some([old, new])
+}
+
+/// The 1-based, inclusive range of lines of one side of a hunk, given its start and length.
+fn side_lines((start, len): (usize, usize)) -> (usize, usize) {
+    if len == 0 {
+        (start.max(1), start + 1)
+    } else {
+        (start, start + len - 1)
+    }
+}
+
+/// The line of the working tree file that the index line `line` became, given the hunks of the
+/// diff between them. A line changed in the working tree maps to the first line of its hunk, or
+/// to the last if it ends a range.
+fn working_line(line: usize, hunks: &[[(usize, usize); 2]], end: bool) -> usize {
+    let mut offset = 0isize;
+    for &[(old_start, old_len), new] in hunks {
+        // Lines added without removing any follow the line `old_start`
+        let after = if old_len == 0 {
+            old_start
+        } else {
+            old_start - 1
+        };
+        if line <= after {
+            break;
+        }
+        if line < old_start + old_len {
+            let (first, last) = side_lines(new);
+            return if end { last } else { first };
+        }
+        offset += new.1 as isize - old_len as isize;
+    }
+    line.saturating_add_signed(offset)
+}
+/// The NUL-separated paths, relative to the repository root, output by `git args -- paths`.
+pub(super) fn git_paths(args: &[&str], paths: &[String]) -> io::Result<Vec<String>> {
+    let mut args = args.to_vec();
+    args.push("--");
+    args.extend(paths.iter().map(String::as_str));
+    Ok(git(&args)?
+        .split('\0')
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Run `git` in the working directory, returning what it writes to stdout.
+pub(super) fn git(args: &[&str]) -> io::Result<String> {
+    String::from_utf8(git_bytes(args, None)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("git output: {e}")))
+}
+
+/// Run `git` in the working directory with `input` on stdin, returning what it writes to
+/// stdout.
+pub(super) fn git_bytes(args: &[&str], input: Option<&[u8]>) -> io::Result<Vec<u8>> {
+    let spawn_error = |e: io::Error| io::Error::new(e.kind(), format!("failed to run git: {e}"));
+    let mut child = Command::new("git")
+        .args(args)
+        .stdin(if input.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_error)?;
+    if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+        stdin.write_all(input).map_err(spawn_error)?;
+    }
+    let output = child.wait_with_output().map_err(spawn_error)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            stderr.trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hunk_lines_of_new_side() {
+        assert_eq!(hunk_lines("@@ -3,2 +4,3 @@ fn main() {"), Some((4, 6)));
+        assert_eq!(hunk_lines("@@ -3 +4 @@"), Some((4, 4)));
+        assert_eq!(hunk_lines("@@ -1,0 +2,1 @@"), Some((2, 2)));
+    }
+
+    #[test]
+    fn hunk_lines_of_removal_touch_lines_either_side() {
+        assert_eq!(hunk_lines("@@ -5,2 +4,0 @@"), Some((4, 5)));
+        // Lines removed from the start of the file
+        assert_eq!(hunk_lines("@@ -1,2 +0,0 @@"), Some((1, 1)));
+    }
+
+    #[test]
+    fn hunk_lines_ignores_other_lines() {
+        assert_eq!(hunk_lines("diff --git a/src/lib.rs b/src/lib.rs"), None);
+        assert_eq!(hunk_lines("+@@ -1 +1 @@"), None);
+        assert_eq!(hunk_lines("@@ -1 +x @@"), None);
+    }
+
+    #[test]
+    fn working_line_follows_unstaged_changes() {
+        let hunks: Vec<_> = [
+            // Two lines added at the top
+            "@@ -0,0 +1,2 @@",
+            // Line 5 changed into three lines
+            "@@ -5 +7,3 @@",
+            // Lines 8 and 9 removed
+            "@@ -8,2 +11,0 @@",
+        ]
+        .into_iter()
+        .filter_map(hunk_sides)
+        .collect();
+        assert_eq!(working_line(1, &hunks, false), 3);
+        assert_eq!(working_line(4, &hunks, false), 6);
+        assert_eq!(working_line(5, &hunks, false), 7);
+        assert_eq!(working_line(5, &hunks, true), 9);
+        assert_eq!(working_line(6, &hunks, false), 10);
+        assert_eq!(working_line(8, &hunks, false), 11);
+        assert_eq!(working_line(9, &hunks, true), 12);
+        assert_eq!(working_line(10, &hunks, false), 12);
+        assert_eq!(working_line(3, &[], false), 3);
+    }
+}
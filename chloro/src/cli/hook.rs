@@ -0,0 +1,120 @@
+//! The git pre-commit hook, which formats the staged contents of files in the index.
+use super::config::ConfigResolver;
+use super::git::{git, git_bytes};
+use chloro_core::format_source_with_config;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The line marking a hook script as installed by chloro, so it may be replaced.
+const HOOK_MARKER: &str = "# Installed by `chloro hook install`";
+
+/// File mode of a symbolic link in the index, whose blob is the link target.
+const SYMLINK_MODE: &str = "120000";
+
+/// A staged file that the hook formatted.
+#[derive(Debug, Clone)]
+pub struct Formatted {
+    /// Path of the file, relative to the working directory
+    pub path: PathBuf,
+    /// Whether the working tree file matched the index and was formatted too
+    pub mirrored: bool,
+}
+
+/// Format the staged version of each staged Rust file in the index, leaving unstaged changes
+/// alone so that partially staged files are committed as staged.
+///
+/// The working tree file is formatted too when it is identical to the staged version.
+pub fn pre_commit(resolver: &mut ConfigResolver) -> io::Result<Vec<Formatted>> {
+    let cdup = git(&["rev-parse", "--show-cdup"])?;
+    let root = Path::new(cdup.trim_end());
+    let raw = git(&[
+        "diff",
+        "--cached",
+        "--raw",
+        "-z",
+        "--no-renames",
+        "--no-ext-diff",
+        "--diff-filter=ACMRT",
+    ])?;
+
+    let mut formatted = Vec::new();
+    for (mode, name) in staged_entries(&raw) {
+        let path = root.join(name);
+        if mode == SYMLINK_MODE || !name.ends_with(".rs") || resolver.is_ignored(&path, false)? {
+            continue;
+        }
+        let config = resolver.for_file(&path)?.config;
+        let staged = git_bytes(&["show", &format!(":{name}")], None)?;
+        let Ok(source) = std::str::from_utf8(&staged) else {
+            eprintln!("Warning: {}: not valid UTF-8, skipping", path.display());
+            continue;
+        };
+        let result = format_source_with_config(source, &config);
+        if result == source {
+            continue;
+        }
+
+        let blob = git_bytes(&["hash-object", "-w", "--stdin"], Some(result.as_bytes()))?;
+        let blob = String::from_utf8_lossy(&blob);
+        git(&[
+            "update-index",
+            "--cacheinfo",
+            &format!("{mode},{},{name}", blob.trim()),
+        ])?;
+
+        let mirrored = fs::read(&path).is_ok_and(|working| working == staged);
+        if mirrored {
+            fs::write(&path, &result)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        }
+        formatted.push(Formatted { path, mirrored });
+    }
+    Ok(formatted)
+}
+
+/// The new mode and path, relative to the repository root, of each entry of
+/// `git diff --raw -z` output.
+fn staged_entries(raw: &str) -> Vec<(&str, &str)> {
+    let mut fields = raw.split('\0');
+    let mut entries = Vec::new();
+    // Each entry is `:old_mode new_mode old_blob new_blob status` followed by the path
+    while let (Some(status), Some(name)) = (fields.next(), fields.next()) {
+        if let Some(mode) = status.split(' ').nth(1) {
+            entries.push((mode, name));
+        }
+    }
+    entries
+}
+
+/// Write a pre-commit hook that runs `chloro hook pre-commit` into the repository's hooks
+/// directory, returning its path.
+///
+/// An existing hook is only replaced if chloro installed it or `force` is set.
+pub fn install(force: bool) -> io::Result<PathBuf> {
+    let path = PathBuf::from(git(&["rev-parse", "--git-path", "hooks/pre-commit"])?.trim_end());
+    if path.exists()
+        && !force
+        && !fs::read_to_string(&path).is_ok_and(|script| script.contains(HOOK_MARKER))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "{} already exists (pass --force to replace it)",
+                path.display()
+            ),
+        ));
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let script = format!("#!/bin/sh\n{HOOK_MARKER}\nexec chloro hook pre-commit\n");
+    fs::write(&path, script)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(path)
+}
@@ -0,0 +1,454 @@
+//! Machine-readable reports of a run, for `--message-format`.
+//!
+//! Each report lists every file with whether it needs formatting, the lines that change
+//! and any errors, including syntax errors found while parsing.
+//!
+//! The JSON report carries a `version`, which only changes if a field is removed or changes
+//! meaning. Fields may be added within a version. Version 1 looks like this:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "files": [
+//!     {
+//!       "path": "src/lib.rs",
+//!       "changed": true,
+//!       "original_len": 120,
+//!       "formatted_len": 118,
+//!       "error": null,
+//!       "diagnostics": [{ "line": 3, "column": 5, "message": "expected SEMICOLON" }],
+//!       "hunks": [{ "start_line": 7, "end_line": 8, "lines_added": 1, "lines_removed": 2 }]
+//!     }
+//!   ],
+//!   "summary": {
+//!     "files_processed": 1,
+//!     "files_changed": 1,
+//!     "files_unchanged": 0,
+//!     "lines_added": 1,
+//!     "lines_removed": 2,
+//!     "errors": 0
+//!   }
+//! }
+//! ```
+//!
+//! `original_len` and `formatted_len` are in bytes, and are `null` for a file that couldn't
+//! be formatted, whose `error` says why. Hunk lines are the lines of the original source that
+//! change, 1-based and inclusive.
+
+use super::diff::Hunk;
+use super::report::AggregatedResults;
+use super::worker::ProcessResult;
+use chloro_core::ParseDiagnostic;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+/// Version of the JSON report schema.
+const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Output format for `--message-format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    Human,
+    Json,
+    Junit,
+    Checkstyle,
+    Sarif,
+}
+
+impl MessageFormat {
+    /// Parse a `--message-format` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "human" => Some(Self::Human),
+            "json" => Some(Self::Json),
+            "junit" => Some(Self::Junit),
+            "checkstyle" => Some(Self::Checkstyle),
+            "sarif" => Some(Self::Sarif),
+            _ => None,
+        }
+    }
+}
+
+/// The parts of a result that reports describe, for formatted files and failures alike.
+struct FileReport<'a> {
+    path: &'a str,
+    changed: bool,
+    lens: Option<(usize, usize)>,
+    hunks: &'a [Hunk],
+    diagnostics: &'a [ParseDiagnostic],
+    error: Option<&'a str>,
+}
+
+impl<'a> FileReport<'a> {
+    fn new(result: &'a ProcessResult) -> Self {
+        match result {
+            ProcessResult::Formatted {
+                path,
+                changed,
+                original_len,
+                formatted_len,
+                hunks,
+                diagnostics,
+            } => Self {
+                path,
+                changed: *changed,
+                lens: Some((*original_len, *formatted_len)),
+                hunks,
+                diagnostics,
+                error: None,
+            },
+            ProcessResult::Error { path, message } => Self {
+                path,
+                changed: false,
+                lens: None,
+                hunks: &[],
+                diagnostics: &[],
+                error: Some(message),
+            },
+        }
+    }
+}
+
+/// Write the report of a run to stdout in the given format. Nothing is written for `Human`.
+pub fn write_report(
+    results: &[ProcessResult],
+    agg: &AggregatedResults,
+    format: MessageFormat,
+) -> io::Result<()> {
+    let files: Vec<FileReport> = results.iter().map(FileReport::new).collect();
+    let report = match format {
+        MessageFormat::Human => return Ok(()),
+        MessageFormat::Json => json_report(&files, agg),
+        MessageFormat::Junit => junit_report(&files),
+        MessageFormat::Checkstyle => checkstyle_report(&files),
+        MessageFormat::Sarif => sarif_report(&files),
+    };
+    let mut out = io::stdout().lock();
+    out.write_all(report.as_bytes())?;
+    out.flush()
+}
+
+fn json_report(files: &[FileReport], agg: &AggregatedResults) -> String {
+    let mut buf = format!("{{\"version\":{},\"files\":[", JSON_SCHEMA_VERSION);
+    for (i, file) in files.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        let (original_len, formatted_len) = match file.lens {
+            Some((original, formatted)) => (original.to_string(), formatted.to_string()),
+            None => ("null".to_string(), "null".to_string()),
+        };
+        let error = file.error.map_or("null".to_string(), json_string);
+        let _ = write!(
+            buf,
+            "{{\"path\":{},\"changed\":{},\"original_len\":{},\"formatted_len\":{},\"error\":{},\"diagnostics\":[",
+            json_string(file.path),
+            file.changed,
+            original_len,
+            formatted_len,
+            error
+        );
+        for (j, diagnostic) in file.diagnostics.iter().enumerate() {
+            if j > 0 {
+                buf.push(',');
+            }
+            let _ = write!(
+                buf,
+                "{{\"line\":{},\"column\":{},\"message\":{}}}",
+                diagnostic.line,
+                diagnostic.column,
+                json_string(&diagnostic.message)
+            );
+        }
+        buf.push_str("],\"hunks\":[");
+        for (j, hunk) in file.hunks.iter().enumerate() {
+            if j > 0 {
+                buf.push(',');
+            }
+            let (start, end) = hunk.original_range();
+            let _ = write!(
+                buf,
+                "{{\"start_line\":{},\"end_line\":{},\"lines_added\":{},\"lines_removed\":{}}}",
+                start,
+                end,
+                hunk.added(),
+                hunk.removed()
+            );
+        }
+        buf.push_str("]}");
+    }
+    let _ = writeln!(
+        buf,
+        "],\"summary\":{{\"files_processed\":{},\"files_changed\":{},\"files_unchanged\":{},\"lines_added\":{},\"lines_removed\":{},\"errors\":{}}}}}",
+        agg.files_processed,
+        agg.files_changed,
+        agg.files_unchanged,
+        agg.lines_added,
+        agg.lines_removed,
+        agg.errors.len()
+    );
+    buf
+}
+
+/// One test case per file, failing if it needs formatting and erroring if it has errors.
+fn junit_report(files: &[FileReport]) -> String {
+    let failures = files.iter().filter(|f| f.changed).count();
+    let errors = files
+        .iter()
+        .filter(|f| f.error.is_some() || !f.diagnostics.is_empty())
+        .count();
+    let counts = format!(
+        "tests=\"{}\" failures=\"{}\" errors=\"{}\"",
+        files.len(),
+        failures,
+        errors
+    );
+
+    let mut buf = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(buf, "<testsuites name=\"chloro\" {}>", counts);
+    let _ = writeln!(buf, "  <testsuite name=\"chloro\" {}>", counts);
+    for file in files {
+        let _ = write!(
+            buf,
+            "    <testcase name=\"{}\" classname=\"chloro\"",
+            xml_escape(file.path)
+        );
+        if !file.changed && file.error.is_none() && file.diagnostics.is_empty() {
+            buf.push_str("/>\n");
+            continue;
+        }
+        buf.push_str(">\n");
+        if file.changed {
+            let _ = writeln!(
+                buf,
+                "      <failure type=\"formatting\" message=\"File needs formatting\">{}</failure>",
+                xml_escape(&hunk_summary(file.hunks))
+            );
+        }
+        if let Some(error) = file.error {
+            let _ = writeln!(
+                buf,
+                "      <error type=\"io\" message=\"{}\"/>",
+                xml_escape(error)
+            );
+        }
+        if !file.diagnostics.is_empty() {
+            let details: Vec<String> = file
+                .diagnostics
+                .iter()
+                .map(|d| format!("{}:{}: {}", d.line, d.column, d.message))
+                .collect();
+            let _ = writeln!(
+                buf,
+                "      <error type=\"parse\" message=\"{} syntax error(s)\">{}</error>",
+                file.diagnostics.len(),
+                xml_escape(&details.join("\n"))
+            );
+        }
+        buf.push_str("    </testcase>\n");
+    }
+    buf.push_str("  </testsuite>\n</testsuites>\n");
+    buf
+}
+
+/// The changed lines of each hunk, one per line, for JUnit failure details.
+fn hunk_summary(hunks: &[Hunk]) -> String {
+    let lines: Vec<String> = hunks
+        .iter()
+        .map(|hunk| {
+            let (start, end) = hunk.original_range();
+            format!(
+                "{}: +{} -{}",
+                line_span(start, end),
+                hunk.added(),
+                hunk.removed()
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
+
+fn line_span(start: usize, end: usize) -> String {
+    if start == end {
+        format!("line {}", start)
+    } else {
+        format!("lines {}-{}", start, end)
+    }
+}
+
+/// One `<error>` per hunk and per error, in the format read by Checkstyle consumers.
+fn checkstyle_report(files: &[FileReport]) -> String {
+    let mut buf = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    buf.push_str("<checkstyle version=\"4.3\">\n");
+    for file in files {
+        let _ = writeln!(buf, "  <file name=\"{}\">", xml_escape(file.path));
+        for hunk in file.hunks {
+            let (start, end) = hunk.original_range();
+            let message = format!(
+                "Incorrect formatting, {} (+{} -{})",
+                line_span(start, end),
+                hunk.added(),
+                hunk.removed()
+            );
+            let _ = writeln!(
+                buf,
+                "    <error line=\"{}\" severity=\"warning\" message=\"{}\" source=\"chloro.formatting\"/>",
+                start,
+                xml_escape(&message)
+            );
+        }
+        for diagnostic in file.diagnostics {
+            let _ = writeln!(
+                buf,
+                "    <error line=\"{}\" column=\"{}\" severity=\"error\" message=\"{}\" source=\"chloro.parse\"/>",
+                diagnostic.line,
+                diagnostic.column,
+                xml_escape(&diagnostic.message)
+            );
+        }
+        if let Some(error) = file.error {
+            let _ = writeln!(
+                buf,
+                "    <error line=\"1\" severity=\"error\" message=\"{}\" source=\"chloro.io\"/>",
+                xml_escape(error)
+            );
+        }
+        buf.push_str("  </file>\n");
+    }
+    buf.push_str("</checkstyle>\n");
+    buf
+}
+
+/// A SARIF 2.1.0 log with one run, and a result per hunk and per error.
+fn sarif_report(files: &[FileReport]) -> String {
+    let mut results = Vec::new();
+    for file in files {
+        let uri = json_string(&sarif_uri(file.path));
+        for hunk in file.hunks {
+            let (start, end) = hunk.original_range();
+            let message = format!(
+                "Incorrect formatting (+{} -{})",
+                hunk.added(),
+                hunk.removed()
+            );
+            results.push(sarif_result(
+                "formatting",
+                "warning",
+                &message,
+                &uri,
+                &format!("\"startLine\":{},\"endLine\":{}", start, end),
+            ));
+        }
+        for diagnostic in file.diagnostics {
+            results.push(sarif_result(
+                "parse-error",
+                "error",
+                &diagnostic.message,
+                &uri,
+                &format!(
+                    "\"startLine\":{},\"startColumn\":{}",
+                    diagnostic.line, diagnostic.column
+                ),
+            ));
+        }
+        if let Some(error) = file.error {
+            results.push(sarif_result(
+                "io-error",
+                "error",
+                error,
+                &uri,
+                "\"startLine\":1",
+            ));
+        }
+    }
+
+    let rules = [
+        ("formatting", "Source is not formatted"),
+        ("parse-error", "Source has a syntax error"),
+        ("io-error", "Source could not be read or written"),
+    ]
+    .map(|(id, text)| {
+        format!(
+            "{{\"id\":\"{}\",\"shortDescription\":{{\"text\":\"{}\"}}}}",
+            id, text
+        )
+    })
+    .join(",");
+    format!(
+        "{{\"$schema\":\"https://json.schemastore.org/sarif-2.1.0.json\",\"version\":\"2.1.0\",\"runs\":[{{\"tool\":{{\"driver\":{{\"name\":\"chloro\",\"version\":\"{}\",\"informationUri\":\"https://github.com/lmmx/chloro\",\"rules\":[{}]}}}},\"results\":[{}]}}]}}\n",
+        env!("CARGO_PKG_VERSION"),
+        rules,
+        results.join(",")
+    )
+}
+
+fn sarif_result(rule: &str, level: &str, message: &str, uri: &str, region: &str) -> String {
+    format!(
+        "{{\"ruleId\":\"{}\",\"level\":\"{}\",\"message\":{{\"text\":{}}},\"locations\":[{{\"physicalLocation\":{{\"artifactLocation\":{{\"uri\":{}}},\"region\":{{{}}}}}}}]}}",
+        rule,
+        level,
+        json_string(message),
+        uri,
+        region
+    )
+}
+
+/// A SARIF artifact URI: relative to the working directory where possible, as code scanning
+/// services expect paths relative to the repository, and otherwise a `file://` URI.
+fn sarif_uri(path: &str) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            std::path::Path::new(path)
+                .strip_prefix(cwd)
+                .ok()
+                .map(|p| p.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| path.to_string());
+    let relative = relative.replace('\\', "/").replace(' ', "%20");
+    if relative.starts_with('/') {
+        format!("file://{}", relative)
+    } else {
+        relative
+    }
+}
+
+/// A JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len() + 2);
+    buf.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(buf, "\\u{:04x}", c as u32);
+            }
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+    buf
+}
+
+/// Escape text for an XML attribute or element, replacing characters XML can't contain.
+fn xml_escape(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&apos;"),
+            '\n' => buf.push_str("&#10;"),
+            '\t' | '\r' => buf.push(c),
+            c if (c as u32) < 0x20 => buf.push('\u{fffd}'),
+            c => buf.push(c),
+        }
+    }
+    buf
+}
@@ -1,32 +1,205 @@
 use super::args::Args;
+use super::config::ConfigResolver;
 use super::worker::{ProcessResult, format_file};
-use std::path::PathBuf;
+use chloro::modules::crate_files;
+use chloro_core::Config;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::thread::{available_parallelism, scope};
 
-/// Discover all Rust files in a directory recursively
-pub fn discover_rust_files(dir: &std::path::Path) -> std::io::Result<Vec<PathBuf>> {
-    let mut files = Vec::new();
-    discover_recursive(dir, &mut files)?;
+/// Names of the ignore files honoured in each directory, later files taking precedence.
+pub const IGNORE_FILE_NAMES: [&str; 3] = [".gitignore", ".ignore", ".chloroignore"];
+
+/// Names of directories that are never formatted.
+const SKIPPED_DIRS: [&str; 1] = [".git"];
+
+/// Name of the directory Cargo writes build output to, which is skipped when Cargo made it.
+const TARGET_DIR: &str = "target";
+
+/// Discover all Rust files in a directory recursively.
+///
+/// Files and directories matched by ignore files in the directory, its subdirectories or its
+/// ancestors are skipped, as are those excluded by `resolver`, Cargo's `target` directories and,
+/// unless `hidden`, directories whose names start with a dot.
+pub fn discover_rust_files(
+    dir: &Path,
+    resolver: &mut ConfigResolver,
+    hidden: bool,
+) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.canonicalize()?;
+    let mut ignores: Vec<Gitignore> = dir
+        .ancestors()
+        .skip(1)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .filter_map(ignore_files)
+        .collect();
+    let mut walk = Walk {
+        resolver,
+        hidden,
+        ancestors: Vec::new(),
+        files: Vec::new(),
+    };
+    walk.visit(&dir, &mut ignores)?;
+    let mut files = walk.files;
     files.sort();
+    files.dedup();
     Ok(files)
 }
 
-fn discover_recursive(dir: &std::path::Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
+/// Find the files of the crates with the root modules `roots` by following their `mod`
+/// declarations, leaving out files excluded by `resolver`.
+///
+/// Declared modules whose files cannot be found are returned as errors at the declaration.
+pub fn crate_root_files(
+    roots: &[String],
+    resolver: &mut ConfigResolver,
+) -> io::Result<(Vec<PathBuf>, Vec<ProcessResult>)> {
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+    for root in roots {
+        let found = crate_files(Path::new(root))?;
+        for file in found.files {
+            let file = file.canonicalize()?;
+            if !files.contains(&file) && !resolver.is_ignored(&file, false)? {
+                files.push(file);
+            }
+        }
+        errors.extend(found.unresolved.into_iter().map(|module| {
+            let candidates: Vec<String> = module
+                .candidates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            ProcessResult::Error {
+                path: module.declared_in.display().to_string(),
+                message: format!(
+                    "{}:{}: cannot find module `{}` (looked for {})",
+                    module.declared_in.display(),
+                    module.line,
+                    module.name,
+                    candidates.join(", ")
+                ),
+            }
+        }));
+    }
+    Ok((files, errors))
+}
+
+/// State of a directory walk.
+struct Walk<'a> {
+    resolver: &'a mut ConfigResolver,
+    hidden: bool,
+    /// Canonical paths of the directories being visited, to detect symlink loops
+    ancestors: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+impl Walk<'_> {
+    /// Visit `dir`, with `ignores` holding the matchers of the ignore files above it, outermost
+    /// first.
+    fn visit(&mut self, dir: &Path, ignores: &mut Vec<Gitignore>) -> io::Result<()> {
+        let canonical = dir.canonicalize()?;
+        if self.ancestors.contains(&canonical) {
+            eprintln!("Warning: Skipping symlink loop at {}", dir.display());
+            return Ok(());
+        }
+        self.ancestors.push(canonical);
+        let own_ignores = ignore_files(dir);
+        let pushed = own_ignores.is_some();
+        ignores.extend(own_ignores);
+
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let is_dir = path.is_dir();
+            if is_dir && !self.visits_dir(&path) {
+                continue;
+            }
+            if !is_dir && path.extension() != Some(OsStr::new("rs")) {
+                continue;
+            }
+            if is_ignored(ignores, &path, is_dir) || self.resolver.is_ignored(&path, is_dir)? {
+                continue;
+            }
+            if is_dir {
+                self.visit(&path, ignores)?;
+            } else {
+                self.files.push(path.canonicalize()?);
+            }
+        }
+
+        if pushed {
+            ignores.pop();
+        }
+        self.ancestors.pop();
+        Ok(())
+    }
+
+    /// Whether to look in the subdirectory at `path`, going by its name.
+    fn visits_dir(&self, path: &Path) -> bool {
+        let Some(name) = path.file_name().and_then(OsStr::to_str) else {
+            return true;
+        };
+        if SKIPPED_DIRS.contains(&name) || name == TARGET_DIR && is_cargo_target(path) {
+            return false;
+        }
+        self.hidden || !name.starts_with('.')
+    }
+}
+
+/// Whether the `target` directory at `path` holds Cargo's build output: Cargo tags it as a cache
+/// directory, and it is the default target directory of a package or workspace otherwise.
+fn is_cargo_target(path: &Path) -> bool {
+    path.join("CACHEDIR.TAG").is_file()
+        || path
+            .parent()
+            .is_some_and(|parent| parent.join("Cargo.toml").is_file())
+}
 
-        if path.is_dir() {
-            discover_recursive(&path, files)?;
-        } else if path.extension() == Some(std::ffi::OsStr::new("rs")) {
-            files.push(path.canonicalize()?);
+/// A matcher for the ignore files directly in `dir`, if it has any.
+fn ignore_files(dir: &Path) -> Option<Gitignore> {
+    let mut builder = GitignoreBuilder::new(dir);
+    let mut found = false;
+    for name in IGNORE_FILE_NAMES {
+        let path = dir.join(name);
+        if path.is_file() {
+            if let Some(e) = builder.add(&path) {
+                eprintln!("Warning: {}: {}", path.display(), e);
+            }
+            found = true;
         }
     }
-    Ok(())
+    if !found {
+        return None;
+    }
+    builder.build().ok()
+}
+
+/// Whether the nearest ignore file with a matching pattern ignores `path`.
+fn is_ignored(ignores: &[Gitignore], path: &Path, is_dir: bool) -> bool {
+    ignores
+        .iter()
+        .rev()
+        .map(|matcher| matcher.matched(path, is_dir))
+        .find(|matched| !matched.is_none())
+        .is_some_and(|matched| matched.is_ignore())
+}
+
+/// A file to format, with its configuration.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub path: PathBuf,
+    pub config: Arc<Config>,
+    /// 1-based, inclusive ranges of the lines to format, or `None` for the whole file
+    pub lines: Option<Vec<(usize, usize)>>,
 }
 
-/// Format all files in parallel
-pub fn format_all(files: &[PathBuf], args: &Args) -> Vec<ProcessResult> {
+/// Format all files in parallel, each with its own configuration
+pub fn format_all(files: &[Job], args: &Args) -> Vec<ProcessResult> {
     let num_threads = available_parallelism().map_or(1, |n| n.get());
 
     if args.verbose {
@@ -46,7 +219,7 @@ pub fn format_all(files: &[PathBuf], args: &Args) -> Vec<ProcessResult> {
                 s.spawn(|| {
                     chunk
                         .iter()
-                        .map(|file| format_file(file, args))
+                        .map(|job| format_file(&job.path, &job.config, job.lines.as_deref(), args))
                         .collect::<Vec<_>>()
                 })
             })
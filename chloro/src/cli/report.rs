@@ -1,12 +1,52 @@
 //! Aggregation and reporting for `chloro` CLI.
 use super::args::Args;
+use super::diff::{Hunk, LineKind};
 use super::worker::ProcessResult;
+use std::io::{self, IsTerminal, Write};
+
+const BOLD: &str = "\x1b[1m";
+const CYAN: &str = "\x1b[36m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// When to colour diff output, from `--color`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Parse a `--color` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Whether to colour output to stdout, which `auto` does for a terminal unless
+    /// `NO_COLOR` is set.
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Auto => io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
 
 /// Aggregated results of a CLI run.
 pub struct AggregatedResults {
     pub files_processed: usize,
     pub files_changed: usize,
     pub files_unchanged: usize,
+    pub lines_added: usize,
+    pub lines_removed: usize,
     pub errors: Vec<String>,
 }
 
@@ -22,27 +62,31 @@ impl AggregatedResults {
             files_processed: 0,
             files_changed: 0,
             files_unchanged: 0,
+            lines_added: 0,
+            lines_removed: 0,
             errors: Vec::new(),
         }
     }
 }
 
 /// Aggregate results from all files
-pub fn aggregate_results(results: Vec<ProcessResult>) -> AggregatedResults {
+pub fn aggregate_results(results: &[ProcessResult]) -> AggregatedResults {
     let mut agg = AggregatedResults::new();
 
     for result in results {
         match result {
-            ProcessResult::Formatted { changed, .. } => {
+            ProcessResult::Formatted { changed, hunks, .. } => {
                 agg.files_processed += 1;
-                if changed {
+                agg.lines_added += hunks.iter().map(Hunk::added).sum::<usize>();
+                agg.lines_removed += hunks.iter().map(Hunk::removed).sum::<usize>();
+                if *changed {
                     agg.files_changed += 1;
                 } else {
                     agg.files_unchanged += 1;
                 }
             }
-            ProcessResult::Error(e) => {
-                agg.errors.push(e);
+            ProcessResult::Error { message, .. } => {
+                agg.errors.push(message.clone());
             }
         }
     }
@@ -61,6 +105,12 @@ pub fn print_summary(agg: &AggregatedResults, args: &Args) {
     eprintln!("Files processed: {}", agg.files_processed);
     eprintln!("Files changed: {}", agg.files_changed);
     eprintln!("Files unchanged: {}", agg.files_unchanged);
+    eprintln!(
+        "Lines changed: {} (+{} -{})",
+        agg.lines_added + agg.lines_removed,
+        agg.lines_added,
+        agg.lines_removed
+    );
 
     if !agg.errors.is_empty() {
         eprintln!();
@@ -74,3 +124,47 @@ pub fn print_summary(agg: &AggregatedResults, args: &Args) {
         }
     }
 }
+
+/// Print a unified diff to stdout for each changed file, in the order given
+pub fn print_diffs(results: &[ProcessResult], color: ColorChoice) -> io::Result<()> {
+    let color = color.enabled();
+    let paint = |code: &'static str| if color { code } else { "" };
+    let reset = paint(RESET);
+    let mut out = io::stdout().lock();
+
+    for result in results {
+        let ProcessResult::Formatted { path, hunks, .. } = result else {
+            continue;
+        };
+        if hunks.is_empty() {
+            continue;
+        }
+        writeln!(out, "{}--- {}{}", paint(BOLD), path, reset)?;
+        writeln!(out, "{}+++ {}{}", paint(BOLD), path, reset)?;
+        for hunk in hunks {
+            writeln!(out, "{}{}{}", paint(CYAN), hunk.header(), reset)?;
+            for line in &hunk.lines {
+                let (marker, code) = match line.kind {
+                    LineKind::Context => (' ', ""),
+                    LineKind::Removed => ('-', RED),
+                    LineKind::Added => ('+', GREEN),
+                };
+                let text = line.text.strip_suffix('\n');
+                let code = paint(code);
+                let end = if code.is_empty() { "" } else { reset };
+                writeln!(
+                    out,
+                    "{}{}{}{}",
+                    code,
+                    marker,
+                    text.unwrap_or(&line.text),
+                    end
+                )?;
+                if text.is_none() {
+                    writeln!(out, "\\ No newline at end of file")?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
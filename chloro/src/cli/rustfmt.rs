@@ -0,0 +1,95 @@
+//! Mapping of `rustfmt.toml` options onto chloro's configuration.
+use super::config::{invalid, option_string};
+use chloro_core::Config;
+use std::io;
+use std::path::Path;
+
+/// Names of rustfmt's configuration files, in order of preference within a directory.
+pub const RUSTFMT_FILE_NAMES: [&str; 2] = ["rustfmt.toml", ".rustfmt.toml"];
+
+/// How chloro handles a rustfmt option.
+enum Support {
+    /// A chloro option with the same name and values.
+    Same,
+    /// A chloro option with a different form, given the rustfmt value as a string.
+    Converted(fn(&str) -> Option<(&'static str, &'static str)>),
+    /// Chloro always formats as rustfmt does with the option set to this value.
+    Fixed(&'static str),
+}
+
+/// The rustfmt options chloro understands. Any other option is unsupported.
+const OPTIONS: &[(&str, Support)] = &[
+    ("max_width", Support::Same),
+    ("newline_style", Support::Same),
+    ("edition", Support::Same),
+    ("use_field_init_shorthand", Support::Same),
+    ("enum_discrim_align_threshold", Support::Same),
+    ("format_code_in_doc_comments", Support::Same),
+    ("wrap_comments", Support::Same),
+    ("normalize_comments", Support::Same),
+    ("comment_width", Support::Same),
+    ("merge_derives", Support::Same),
+    ("hex_literal_case", Support::Same),
+    ("float_literal_trailing_zero", Support::Same),
+    ("format_strings", Support::Same),
+    ("reorder_modules", Support::Same),
+    ("reorder_impl_items", Support::Same),
+    (
+        "normalize_doc_attributes",
+        Support::Converted(|value| match value {
+            "true" => Some(("normalize_doc_attributes", "Comments")),
+            "false" => Some(("normalize_doc_attributes", "Preserve")),
+            _ => None,
+        }),
+    ),
+    ("tab_spaces", Support::Fixed("4")),
+    ("hard_tabs", Support::Fixed("false")),
+    ("reorder_imports", Support::Fixed("true")),
+    ("imports_granularity", Support::Fixed("Preserve")),
+    ("group_imports", Support::Fixed("Preserve")),
+];
+
+/// Apply the options of the rustfmt configuration file at `path` to `config`.
+///
+/// Options chloro does not support are reported as warnings, or as errors if `strict`.
+pub fn apply(
+    path: &Path,
+    table: &toml::Table,
+    config: &mut Config,
+    strict: bool,
+) -> io::Result<()> {
+    for (key, value) in table {
+        let value = option_string(key, value).map_err(|message| invalid(path, message))?;
+        let unsupported = match OPTIONS.iter().find(|(name, _)| name == key) {
+            Some((_, Support::Same)) => {
+                config
+                    .set(key, &value)
+                    .map_err(|e| invalid(path, e.to_string()))?;
+                None
+            }
+            Some((_, Support::Converted(convert))) => {
+                let Some((key, value)) = convert(&value) else {
+                    return Err(invalid(
+                        path,
+                        format!("invalid value `{value}` for `{key}`"),
+                    ));
+                };
+                config
+                    .set(key, value)
+                    .map_err(|e| invalid(path, e.to_string()))?;
+                None
+            }
+            Some((_, Support::Fixed(fixed))) => (value != *fixed).then(|| {
+                format!("`{key} = {value}` is not supported, chloro always uses `{key} = {fixed}`")
+            }),
+            None => Some(format!("`{key}` is not supported by chloro and is ignored")),
+        };
+        if let Some(message) = unsupported {
+            if strict {
+                return Err(invalid(path, message));
+            }
+            eprintln!("Warning: {}: {}", path.display(), message);
+        }
+    }
+    Ok(())
+}
@@ -1,8 +1,11 @@
 use super::args::Args;
+use super::diff::{Hunk, apply_in_lines, diff_hunks};
 use crate::vlog;
-use chloro_core::format_source;
+use chloro_core::{
+    Config, ParseDiagnostic, format_source_with_config, parse_diagnostics_with_config,
+};
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 use std::path::Path;
 
 /// Result of processing a single file
@@ -14,25 +17,40 @@ pub enum ProcessResult {
         changed: bool,
         original_len: usize,
         formatted_len: usize,
+        /// Changes from the original to the formatted source, empty if unchanged
+        hunks: Vec<Hunk>,
+        /// Syntax errors in the original source
+        diagnostics: Vec<ParseDiagnostic>,
     },
     /// File formatting failed
-    Error(String),
+    Error { path: String, message: String },
 }
 
-/// Process a single Rust source file
-pub fn format_file(file_path: &Path, args: &Args) -> ProcessResult {
+/// Process a single Rust source file, changing only the given 1-based ranges of lines if any
+pub fn format_file(
+    file_path: &Path,
+    config: &Config,
+    lines: Option<&[(usize, usize)]>,
+    args: &Args,
+) -> ProcessResult {
     vlog!(args, {"Processing: {}", file_path.display()});
 
     // Read the file
     let original = match fs::read_to_string(file_path) {
         Ok(content) => content,
         Err(e) => {
-            return ProcessResult::Error(format!("Failed to read {}: {}", file_path.display(), e));
+            return ProcessResult::Error {
+                path: file_path.display().to_string(),
+                message: format!("Failed to read {}: {}", file_path.display(), e),
+            };
         }
     };
 
     // Format it
-    let formatted = format_source(&original);
+    let mut formatted = format_source_with_config(&original, config);
+    if let Some(lines) = lines {
+        formatted = apply_in_lines(&original, &formatted, lines);
+    }
 
     let changed = original != formatted;
     let original_len = original.len();
@@ -42,21 +60,22 @@ pub fn format_file(file_path: &Path, args: &Args) -> ProcessResult {
     if args.write {
         if changed {
             if let Err(e) = fs::write(file_path, &formatted) {
-                return ProcessResult::Error(format!(
-                    "Failed to write {}: {}",
-                    file_path.display(),
-                    e
-                ));
+                return ProcessResult::Error {
+                    path: file_path.display().to_string(),
+                    message: format!("Failed to write {}: {}", file_path.display(), e),
+                };
             }
             vlog!(args, { "  Wrote formatted output" });
         } else {
             vlog!(args, { "  No changes needed" });
         }
-    } else if !args.check {
-        // Print to stdout (only if not in check mode)
-        if let Err(e) = io::stdout().write_all(formatted.as_bytes()) {
-            return ProcessResult::Error(format!("Failed to write to stdout: {}", e));
-        }
+    } else if args.prints_source()
+        && let Err(e) = io::stdout().write_all(formatted.as_bytes())
+    {
+        return ProcessResult::Error {
+            path: file_path.display().to_string(),
+            message: format!("Failed to write to stdout: {}", e),
+        };
     }
 
     ProcessResult::Formatted {
@@ -64,5 +83,54 @@ pub fn format_file(file_path: &Path, args: &Args) -> ProcessResult {
         changed,
         original_len,
         formatted_len,
+        hunks: diff_hunks(&original, &formatted),
+        diagnostics: parse_diagnostics_with_config(&original, config),
+    }
+}
+
+/// Format source read from stdin, writing the result to stdout unless in check mode
+///
+/// Without a `config`, as when the path given for stdin is ignored, the source is written back
+/// unchanged.
+pub fn format_stdin(config: Option<&Config>, args: &Args) -> ProcessResult {
+    let path = if args.stdin_filepath.is_empty() {
+        "<stdin>".to_string()
+    } else {
+        args.stdin_filepath.clone()
+    };
+    vlog!(args, {"Processing: {}", path});
+
+    let mut original = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut original) {
+        return ProcessResult::Error {
+            path,
+            message: format!("Failed to read stdin: {}", e),
+        };
+    }
+
+    let formatted = match config {
+        Some(config) => format_source_with_config(&original, config),
+        None => original.clone(),
+    };
+    let changed = original != formatted;
+
+    if args.prints_source()
+        && let Err(e) = io::stdout().write_all(formatted.as_bytes())
+    {
+        return ProcessResult::Error {
+            path,
+            message: format!("Failed to write to stdout: {}", e),
+        };
+    }
+
+    ProcessResult::Formatted {
+        path,
+        changed,
+        original_len: original.len(),
+        formatted_len: formatted.len(),
+        hunks: diff_hunks(&original, &formatted),
+        diagnostics: config
+            .map(|config| parse_diagnostics_with_config(&original, config))
+            .unwrap_or_default(),
     }
 }
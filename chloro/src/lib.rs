@@ -11,4 +11,13 @@
 //! with both library and CLI interfaces.
 
 // Re-export the core formatting functionality
-pub use chloro_core::{chloro_debug, format_source};
+pub use chloro_core::{
+    Config, ParseDiagnostic, chloro_debug, format_source, format_source_with_config,
+    parse_diagnostics, parse_diagnostics_with_config,
+};
+
+#[cfg(feature = "cli")]
+pub mod modules;
+
+#[cfg(all(test, feature = "cli"))]
+mod test_support;
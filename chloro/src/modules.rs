@@ -0,0 +1,405 @@
+//! Discovery of the source files of a crate by following its `mod` declarations.
+use ra_ap_syntax::ast::{self, HasAttrs, HasModuleItem, HasName};
+use ra_ap_syntax::{AstNode, AstToken, Edition, NodeOrToken, SourceFile, SyntaxKind};
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The source files of a crate, found by following `mod` declarations from its root module.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CrateFiles {
+    /// The files of the crate, starting with the root module, in the order they were found
+    pub files: Vec<PathBuf>,
+    /// Declared modules whose files could not be found
+    pub unresolved: Vec<UnresolvedModule>,
+}
+
+/// A `mod foo;` declaration whose file could not be found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedModule {
+    /// The file containing the declaration
+    pub declared_in: PathBuf,
+    /// The 1-based line of the declaration
+    pub line: usize,
+    /// The name of the module
+    pub name: String,
+    /// The paths the module's file was looked for at
+    pub candidates: Vec<PathBuf>,
+}
+
+/// Find the files of the crate whose root module is at `root`, such as `src/lib.rs`.
+///
+/// `mod foo;` is looked for at `foo.rs` and `foo/mod.rs`, `#[path = "..."]` attributes are
+/// honoured and inline `mod foo { ... }` modules are searched for further declarations. As cfgs
+/// are not evaluated, every file named by a `#[cfg_attr(..., path = "...")]` attribute that
+/// exists is included, along with the file the module has without it.
+///
+/// # Errors
+///
+/// Returns an [`io::Error`] if a file of the crate cannot be read.
+pub fn crate_files(root: &Path) -> io::Result<CrateFiles> {
+    let mut crate_files = CrateFiles::default();
+    let mut seen = HashSet::new();
+    // Files to visit, with whether they own their directory as `mod.rs` files do
+    let mut pending = vec![(root.to_path_buf(), true)];
+
+    while let Some((file, owns_dir)) = pending.pop() {
+        if !seen.insert(file.canonicalize().unwrap_or_else(|_| file.clone())) {
+            continue;
+        }
+        let source = fs::read_to_string(&file)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", file.display(), e)))?;
+        let parse = SourceFile::parse(&source, Edition::CURRENT);
+        let file_dir = file.parent().unwrap_or(Path::new("")).to_path_buf();
+        let module_dir = if owns_dir {
+            file_dir.clone()
+        } else {
+            file_dir.join(file.file_stem().unwrap_or_default())
+        };
+        let mut found = Vec::new();
+        let scope = Scope {
+            file: &file,
+            source: &source,
+            file_dir: &file_dir,
+            module_dir,
+            inline: false,
+        };
+        scope.collect(
+            parse.tree().items(),
+            &mut found,
+            &mut crate_files.unresolved,
+        );
+        crate_files.files.push(file);
+        // Visit in declaration order
+        pending.extend(found.into_iter().rev());
+    }
+    Ok(crate_files)
+}
+
+/// Where the modules declared in a file, or in an inline module within it, are looked for.
+struct Scope<'a> {
+    file: &'a Path,
+    source: &'a str,
+    /// Directory containing the file, which `#[path]` is relative to outside inline modules
+    file_dir: &'a Path,
+    /// Directory that `mod foo;` is looked for in
+    module_dir: PathBuf,
+    /// Whether this is an inline module rather than the file itself
+    inline: bool,
+}
+
+impl Scope<'_> {
+    fn collect(
+        &self,
+        items: impl Iterator<Item = ast::Item>,
+        found: &mut Vec<(PathBuf, bool)>,
+        unresolved: &mut Vec<UnresolvedModule>,
+    ) {
+        for item in items {
+            let ast::Item::Module(module) = item else {
+                continue;
+            };
+            let Some(name) = module.name() else {
+                continue;
+            };
+            let name = name.text();
+            let name = name.trim_start_matches("r#");
+            let (path, cfg_paths) = path_attributes(&module);
+            let path_base = if self.inline {
+                &self.module_dir
+            } else {
+                self.file_dir
+            };
+
+            if let Some(item_list) = module.item_list() {
+                let module_dir = match path.or_else(|| cfg_paths.into_iter().next()) {
+                    Some(path) => path_base.join(path),
+                    None => self.module_dir.join(name),
+                };
+                let scope = Scope {
+                    file: self.file,
+                    source: self.source,
+                    file_dir: self.file_dir,
+                    module_dir,
+                    inline: true,
+                };
+                scope.collect(item_list.items(), found, unresolved);
+                continue;
+            }
+
+            // A file named by `#[path]` owns its directory, like a `mod.rs` file.
+            let mut candidates: Vec<_> = cfg_paths
+                .iter()
+                .map(|path| (path_base.join(path), true))
+                .collect();
+            match path {
+                Some(path) => candidates.push((path_base.join(path), true)),
+                None => candidates.extend([
+                    (self.module_dir.join(format!("{name}.rs")), false),
+                    (self.module_dir.join(name).join("mod.rs"), true),
+                ]),
+            }
+            let (conditional, unconditional) = candidates.split_at(cfg_paths.len());
+            let mut resolved: Vec<_> = conditional
+                .iter()
+                .filter(|(path, _)| path.is_file())
+                .cloned()
+                .collect();
+            resolved.extend(
+                unconditional
+                    .iter()
+                    .find(|(path, _)| path.is_file())
+                    .cloned(),
+            );
+            if resolved.is_empty() {
+                let offset = module
+                    .mod_token()
+                    .map_or(module.syntax().text_range().start(), |token| {
+                        token.text_range().start()
+                    });
+                unresolved.push(UnresolvedModule {
+                    declared_in: self.file.to_path_buf(),
+                    line: self.source[..usize::from(offset)].matches('\n').count() + 1,
+                    name: name.to_string(),
+                    candidates: candidates.into_iter().map(|(path, _)| path).collect(),
+                });
+            }
+            found.extend(resolved);
+        }
+    }
+}
+
+/// The value of the `#[path = "..."]` attribute of a module, and the paths given by its
+/// `#[cfg_attr(..., path = "...")]` attributes.
+fn path_attributes(module: &ast::Module) -> (Option<String>, Vec<String>) {
+    let mut path = None;
+    let mut cfg_paths = Vec::new();
+    for attr in module.attrs() {
+        let Some(name) = attr.simple_name() else {
+            continue;
+        };
+        let Some(meta) = attr.meta() else {
+            continue;
+        };
+        if name == "path" {
+            path = meta.expr().and_then(string_value).or(path);
+        } else if name == "cfg_attr"
+            && let Some(tt) = meta.token_tree()
+        {
+            cfg_paths.extend(cfg_attr_path(&tt));
+        }
+    }
+    (path, cfg_paths)
+}
+
+/// The path of a `path = "..."` attribute among the attributes of a `cfg_attr`.
+fn cfg_attr_path(tt: &ast::TokenTree) -> Option<String> {
+    let tokens: Vec<_> = tt
+        .syntax()
+        .children_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|token| !token.kind().is_trivia())
+        .collect();
+    tokens.windows(3).find_map(|window| {
+        let [name, eq, value] = window else {
+            return None;
+        };
+        if name.kind() != SyntaxKind::IDENT || name.text() != "path" || eq.kind() != SyntaxKind::EQ
+        {
+            return None;
+        }
+        Some(ast::String::cast(value.clone())?.value().ok()?.to_string())
+    })
+}
+
+/// The value of a string literal expression.
+fn string_value(expr: ast::Expr) -> Option<String> {
+    let ast::Expr::Literal(literal) = expr else {
+        return None;
+    };
+    let string = ast::String::cast(literal.token())?;
+    Some(string.value().ok()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::write;
+    use tempfile::TempDir;
+
+    /// The crate files found from the root module at `root`, relative to the directory.
+    fn found_files(tmp: &TempDir, root: &str) -> (Vec<String>, Vec<UnresolvedModule>) {
+        let found = crate_files(&tmp.path().join(root)).unwrap();
+        let files = found.files.iter().map(|path| relative(tmp, path)).collect();
+        (files, found.unresolved)
+    }
+
+    fn relative(tmp: &TempDir, path: &Path) -> String {
+        path.strip_prefix(tmp.path())
+            .unwrap()
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    #[test]
+    fn modules_in_named_files_and_mod_rs() {
+        let tmp = TempDir::new().unwrap();
+        write(tmp.path(), "src/lib.rs", "mod a;\nmod b;\n");
+        write(tmp.path(), "src/a.rs", "mod inner;\n");
+        write(tmp.path(), "src/a/inner.rs", "");
+        write(tmp.path(), "src/b/mod.rs", "pub mod c;\n");
+        write(tmp.path(), "src/b/c.rs", "");
+        write(tmp.path(), "src/stray.rs", "");
+
+        let (files, unresolved) = found_files(&tmp, "src/lib.rs");
+        assert_eq!(
+            files,
+            [
+                "src/lib.rs",
+                "src/a.rs",
+                "src/a/inner.rs",
+                "src/b/mod.rs",
+                "src/b/c.rs"
+            ]
+        );
+        assert!(unresolved.is_empty());
+    }
+
+    #[test]
+    fn path_attributes_outside_and_inside_inline_modules() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            tmp.path(),
+            "src/lib.rs",
+            "mod a;\n\
+             mod outer {\n    #[path = \"renamed.rs\"]\n    mod x;\n    mod y;\n}\n\
+             #[path = \"elsewhere\"]\nmod inline {\n    mod z;\n}\n",
+        );
+        // `#[path]` in a non-mod.rs file is relative to the file's own directory
+        write(tmp.path(), "src/a.rs", "#[path = \"sibling.rs\"]\nmod b;\n");
+        write(tmp.path(), "src/sibling.rs", "mod c;\n");
+        // A file named by `#[path]` owns its directory
+        write(tmp.path(), "src/c.rs", "");
+        write(tmp.path(), "src/outer/renamed.rs", "");
+        write(tmp.path(), "src/outer/y.rs", "");
+        write(tmp.path(), "src/elsewhere/z.rs", "");
+
+        let (files, unresolved) = found_files(&tmp, "src/lib.rs");
+        assert_eq!(
+            files,
+            [
+                "src/lib.rs",
+                "src/a.rs",
+                "src/sibling.rs",
+                "src/c.rs",
+                "src/outer/renamed.rs",
+                "src/outer/y.rs",
+                "src/elsewhere/z.rs",
+            ]
+        );
+        assert!(unresolved.is_empty());
+    }
+
+    #[test]
+    fn cfg_attr_paths_are_all_included() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            tmp.path(),
+            "src/lib.rs",
+            "#[cfg_attr(unix, path = \"sys/unix.rs\")]\n\
+             #[cfg_attr(windows, path = \"sys/windows.rs\")]\nmod sys;\n\
+             #[cfg_attr(feature = \"x\", path = \"only.rs\")]\nmod conditional;\n",
+        );
+        write(tmp.path(), "src/sys/unix.rs", "");
+        write(tmp.path(), "src/sys/windows.rs", "");
+        write(tmp.path(), "src/sys.rs", "");
+        write(tmp.path(), "src/only.rs", "");
+
+        let (files, unresolved) = found_files(&tmp, "src/lib.rs");
+        assert_eq!(
+            files,
+            [
+                "src/lib.rs",
+                "src/sys/unix.rs",
+                "src/sys/windows.rs",
+                "src/sys.rs",
+                "src/only.rs",
+            ]
+        );
+        assert!(unresolved.is_empty());
+    }
+
+    #[test]
+    fn raw_identifier_module_names() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            tmp.path(),
+            "src/lib.rs",
+            "mod r#match;\nmod r#type {\n    mod r#fn;\n}\n",
+        );
+        write(tmp.path(), "src/match.rs", "");
+        write(tmp.path(), "src/type/fn.rs", "");
+
+        let (files, unresolved) = found_files(&tmp, "src/lib.rs");
+        assert_eq!(files, ["src/lib.rs", "src/match.rs", "src/type/fn.rs"]);
+        assert!(unresolved.is_empty());
+    }
+
+    #[test]
+    fn unresolved_modules_report_file_and_line() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            tmp.path(),
+            "src/main.rs",
+            "mod found;\n\n/// Docs\npub mod missing;\n",
+        );
+        write(
+            tmp.path(),
+            "src/found.rs",
+            "mod gone {\n    mod deeper;\n}\n",
+        );
+
+        let (files, unresolved) = found_files(&tmp, "src/main.rs");
+        assert_eq!(files, ["src/main.rs", "src/found.rs"]);
+        let reported: Vec<_> = unresolved
+            .iter()
+            .map(|module| {
+                let candidates: Vec<_> = module
+                    .candidates
+                    .iter()
+                    .map(|path| relative(&tmp, path))
+                    .collect();
+                (
+                    relative(&tmp, &module.declared_in),
+                    module.line,
+                    module.name.as_str(),
+                    candidates,
+                )
+            })
+            .collect();
+        assert_eq!(
+            reported,
+            [
+                (
+                    "src/main.rs".to_string(),
+                    4,
+                    "missing",
+                    vec![
+                        "src/missing.rs".to_string(),
+                        "src/missing/mod.rs".to_string()
+                    ]
+                ),
+                (
+                    "src/found.rs".to_string(),
+                    2,
+                    "deeper",
+                    vec![
+                        "src/found/gone/deeper.rs".to_string(),
+                        "src/found/gone/deeper/mod.rs".to_string()
+                    ]
+                ),
+            ]
+        );
+    }
+}
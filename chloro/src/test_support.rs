@@ -0,0 +1,11 @@
+//! Helpers shared by the unit tests of the library and the binaries.
+
+use std::fs;
+use std::path::Path;
+
+/// Write `text` to the file at `path` relative to `dir`, creating its parents.
+pub fn write(dir: &Path, path: &str, text: &str) {
+    let path = dir.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, text).unwrap();
+}